@@ -0,0 +1,37 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+//! Embeds the git commit and the build time, shown on the software info
+//! page. Builds outside of a git checkout, such as from crates.io, get no
+//! commit and fall back to "unknown" at run time.
+
+use std::process::Command;
+use std::time::{ SystemTime, UNIX_EPOCH };
+
+fn main()
+{
+    let commit = Command::new("git")
+                    .args(["rev-parse", "--short", "HEAD"])
+                    .output()
+                    .ok()
+                    .filter(|output| output.status.success())
+                    .and_then(|output| String::from_utf8(output.stdout).ok())
+                    .map(|commit| commit.trim().to_string())
+                    .filter(|commit| !commit.is_empty());
+    if let Some(commit) = commit
+        { println!("cargo:rustc-env=QRATE_GIT_COMMIT={}", commit); }
+
+    if let Ok(elapsed) = SystemTime::now().duration_since(UNIX_EPOCH)
+        { println!("cargo:rustc-env=QRATE_BUILD_TIMESTAMP={}", elapsed.as_secs()); }
+
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/index");
+    println!("cargo:rerun-if-changed=build.rs");
+}
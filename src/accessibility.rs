@@ -0,0 +1,166 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use rust_i18n::t;
+
+use crate::{ get_submenu_keys, MENU_KEYS };
+
+/// What kind of interactive element an accessible name belongs to, as
+/// announced by a screen reader after the name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessibleRole
+{
+    /// A button of the main menu bar.
+    MenuBarItem,
+
+    /// An item of an open submenu.
+    MenuItem,
+
+    /// A modal dialog, named by its title.
+    Dialog,
+}
+
+impl AccessibleRole
+{
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key of the role's spoken name.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AccessibleRole;
+    /// assert_eq!(AccessibleRole::Dialog.get_key(), "role-dialog");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            AccessibleRole::MenuBarItem => "role-menu-bar-item",
+            AccessibleRole::MenuItem => "role-menu-item",
+            AccessibleRole::Dialog => "role-dialog",
+        }
+    }
+}
+
+/// The accessible name and role of one interactive element, translated into
+/// the current locale when it is created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessibleLabel
+{
+    role: AccessibleRole,
+    key: &'static str,
+    name: String,
+}
+
+impl AccessibleLabel
+{
+    // pub fn new(role: AccessibleRole, key: &'static str) -> Self
+    /// Creates the label of an element whose visible text is the
+    /// translation of `key`, so that what is heard matches what is shown.
+    ///
+    /// # Arguments
+    /// * `role` - The kind of element.
+    /// * `key` - The locale key of its visible text.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AccessibleLabel, AccessibleRole };
+    ///
+    /// rust_i18n::set_locale("en");
+    /// let label = AccessibleLabel::new(AccessibleRole::MenuBarItem, "settings");
+    /// assert_eq!(label.get_key(), "settings");
+    /// assert_eq!(label.get_name(), "Settings");
+    /// assert_eq!(label.get_role(), AccessibleRole::MenuBarItem);
+    /// assert_eq!(label.get_announcement(), "Settings, menu");
+    ///
+    /// rust_i18n::set_locale("ko");
+    /// assert_eq!(AccessibleLabel::new(AccessibleRole::MenuBarItem, "settings").get_name(), "설정");
+    /// rust_i18n::set_locale("en");
+    /// ```
+    pub fn new(role: AccessibleRole, key: &'static str) -> Self
+    {
+        Self { role, key, name: t!(key).to_string() }
+    }
+
+    // pub fn get_role(&self) -> AccessibleRole
+    /// Returns the kind of element.
+    ///
+    /// # Examples
+    /// See `AccessibleLabel::new`.
+    pub fn get_role(&self) -> AccessibleRole
+    {
+        self.role
+    }
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the locale key the name was translated from.
+    ///
+    /// # Examples
+    /// See `AccessibleLabel::new`.
+    pub fn get_key(&self) -> &'static str
+    {
+        self.key
+    }
+
+    // pub fn get_name(&self) -> &str
+    /// Returns the translated accessible name.
+    ///
+    /// # Examples
+    /// See `AccessibleLabel::new`.
+    pub fn get_name(&self) -> &str
+    {
+        &self.name
+    }
+
+    // pub fn get_announcement(&self) -> String
+    /// Returns what a screen reader says when the element gets the focus:
+    /// the name followed by the role, in the current locale.
+    ///
+    /// # Examples
+    /// See `AccessibleLabel::new`.
+    pub fn get_announcement(&self) -> String
+    {
+        t!("accessible-announcement", name = self.name, role = t!(self.role.get_key())).to_string()
+    }
+}
+
+// pub fn menu_bar_labels() -> Vec<AccessibleLabel>
+/// Returns the labels of the main menu buttons, from left to right.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ menu_bar_labels, AccessibleRole, MENU_KEYS };
+/// let labels = menu_bar_labels();
+/// assert_eq!(labels.len(), MENU_KEYS.len());
+/// assert!(labels.iter().all(|label| label.get_role() == AccessibleRole::MenuBarItem));
+/// ```
+pub fn menu_bar_labels() -> Vec<AccessibleLabel>
+{
+    MENU_KEYS.into_iter().map(|key| AccessibleLabel::new(AccessibleRole::MenuBarItem, key)).collect()
+}
+
+// pub fn submenu_labels(menu_key: &str) -> Vec<AccessibleLabel>
+/// Returns the labels of the items of the submenu opened by `menu_key`,
+/// from top to bottom.
+///
+/// # Arguments
+/// * `menu_key` - The key of the main menu button.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ get_submenu_keys, submenu_labels };
+/// rust_i18n::set_locale("en");
+/// let labels = submenu_labels("information");
+/// assert_eq!(labels.len(), get_submenu_keys("information").len());
+/// assert_eq!(labels[0].get_name(), "Help");
+/// ```
+pub fn submenu_labels(menu_key: &str) -> Vec<AccessibleLabel>
+{
+    get_submenu_keys(menu_key).iter().map(|key| AccessibleLabel::new(AccessibleRole::MenuItem, key)).collect()
+}
@@ -0,0 +1,180 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fs;
+use std::path::Path;
+
+use qrate::{ Header, QBank, Question };
+
+use crate::{ Category, ExportError };
+
+/// The header lines Anki reads before the notes: tab-separated fields with
+/// HTML, and the tags in the third column.
+pub const ANKI_DECK_HEADER: &str = "#separator:tab\n#html:true\n#tags column:3\n";
+
+// pub fn escape_anki_field(text: &str) -> String
+/// Escapes `text` for one field of an Anki deck in HTML mode.
+///
+/// HTML special characters become entities, line breaks become `<br>` and
+/// tabs become `&#9;`, so that every note stays on one line with exactly
+/// three tab-separated fields.
+///
+/// # Arguments
+/// * `text` - The raw text.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::escape_anki_field;
+/// assert_eq!(escape_anki_field("a < b && \"c\""), "a &lt; b &amp;&amp; &quot;c&quot;");
+/// assert_eq!(escape_anki_field("line 1\r\nline 2\nx\ty"), "line 1<br>line 2<br>x&#9;y");
+/// ```
+pub fn escape_anki_field(text: &str) -> String
+{
+    let mut escaped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next()
+    {
+        match c
+        {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' => escaped.push_str("&#9;"),
+            '\r' => {
+                if chars.peek() == Some(&'\n')
+                    { chars.next(); }
+                escaped.push_str("<br>");
+            },
+            '\n' => escaped.push_str("<br>"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// pub fn get_anki_tag(category: &Category) -> Option<String>
+/// Returns the Anki tag of a category. Anki separates tags with spaces, so
+/// spaces inside the category name become underscores.
+///
+/// # Arguments
+/// * `category` - The category of a question.
+///
+/// # Output
+/// The tag, or `None` for uncategorized questions.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ get_anki_tag, Category };
+/// assert_eq!(get_anki_tag(&Category::Named("Linear Algebra".to_string())), Some("Linear_Algebra".to_string()));
+/// assert_eq!(get_anki_tag(&Category::Uncategorized), None);
+/// ```
+pub fn get_anki_tag(category: &Category) -> Option<String>
+{
+    match category
+    {
+        Category::Named(name) => Some(name.split_whitespace().collect::<Vec<_>>().join("_")),
+        Category::Uncategorized => None,
+    }
+}
+
+// pub fn to_anki_note(header: &Header, question: &Question) -> String
+/// Writes one question as one line of an Anki deck: the prompt, the correct
+/// choices and the category tag, separated by tabs.
+///
+/// # Arguments
+/// * `header` - The header of the bank, which names the categories.
+/// * `question` - The question.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, Question };
+/// use qrate_gui::to_anki_note;
+///
+/// let mut header = Header::new_empty();
+/// header.set_categories(vec!["Geometry".to_string()]);
+/// let choices = vec![("3".to_string(), true), ("4".to_string(), false), ("three".to_string(), true)];
+/// let question = Question::new(1, 1, 1, "Sides of a triangle?".to_string(), choices);
+/// assert_eq!(to_anki_note(&header, &question), "Sides of a triangle?\t3<br>three\tGeometry");
+/// ```
+pub fn to_anki_note(header: &Header, question: &Question) -> String
+{
+    let back = question.get_choices().iter()
+                    .filter(|(_, correct)| *correct)
+                    .map(|(text, _)| escape_anki_field(text))
+                    .collect::<Vec<_>>()
+                    .join("<br>");
+    let tag = get_anki_tag(&Category::of(header, question)).unwrap_or_default();
+    format!("{}\t{}\t{}", escape_anki_field(question.get_question()), back, tag)
+}
+
+// pub fn to_anki_deck(qbank: &QBank) -> String
+/// Writes every question of `qbank` as an Anki-importable deck: the
+/// `ANKI_DECK_HEADER` followed by one note per line.
+///
+/// # Arguments
+/// * `qbank` - The question bank.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, QBank, Question };
+/// use qrate_gui::{ to_anki_deck, ANKI_DECK_HEADER };
+///
+/// let mut header = Header::new_empty();
+/// header.set_categories(vec!["Algebra".to_string()]);
+/// let mut qbank = QBank::new_with_header(header);
+/// qbank.push_question(Question::new(1, 1, 1, "2 + 2?".to_string(), vec![("4".to_string(), true)]));
+/// qbank.push_question(Question::new(2, 2, 1, "Tab\there?\nAnd <b>".to_string(), vec![("Yes\tand\nno".to_string(), true)]));
+/// qbank.push_question(Question::new(3, 3, 0, "No category".to_string(), vec![]));
+///
+/// let deck = to_anki_deck(&qbank);
+/// let notes: Vec<&str> = deck.strip_prefix(ANKI_DECK_HEADER).unwrap().lines().collect();
+/// assert_eq!(notes.len(), 3);
+/// assert!(notes.iter().all(|note| note.split('\t').count() == 3));
+/// assert_eq!(notes[0], "2 + 2?\t4\tAlgebra");
+/// assert_eq!(notes[1], "Tab&#9;here?<br>And &lt;b&gt;\tYes&#9;and<br>no\tAlgebra");
+/// assert_eq!(notes[2], "No category\t\t");
+/// ```
+pub fn to_anki_deck(qbank: &QBank) -> String
+{
+    let mut deck = ANKI_DECK_HEADER.to_string();
+    for question in qbank.get_questions()
+    {
+        deck.push_str(&to_anki_note(qbank.get_header(), question));
+        deck.push('\n');
+    }
+    deck
+}
+
+// pub fn export_anki_deck(qbank: &QBank, path: &Path) -> Result<(), ExportError>
+/// Writes the Anki deck of `qbank` to `path`.
+///
+/// # Arguments
+/// * `qbank` - The question bank.
+/// * `path` - The target file path, usually ending in `.txt`.
+///
+/// # Output
+/// `Ok(())` on success, or `ExportError::PermissionDenied` or
+/// `ExportError::Write`.
+///
+/// # Examples
+/// ```
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::export_anki_deck;
+/// let mut qbank = QBank::new_with_default();
+/// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![("A".to_string(), true)]));
+/// let path = std::env::temp_dir().join("qrate_gui_anki_deck.txt");
+/// export_anki_deck(&qbank, &path).unwrap();
+/// assert!(std::fs::read_to_string(&path).unwrap().ends_with("Q1\tA\tType_A\n"));
+/// ```
+pub fn export_anki_deck(qbank: &QBank, path: &Path) -> Result<(), ExportError>
+{
+    fs::write(path, to_anki_deck(qbank)).map_err(|e| ExportError::from_io(&e, path))
+}
@@ -0,0 +1,126 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::BTreeMap;
+
+use serde::{ Serialize, Deserialize };
+
+use crate::QuestionKind;
+
+/// The most blank lines that can be left after one question.
+pub const MAX_ANSWER_SPACE_LINES: usize = 30;
+
+/// The blank writing space left after each question of an exported exam,
+/// in lines, for all questions or for the questions of one kind.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct AnswerSpace
+{
+    #[serde(default)]
+    lines: usize,
+
+    #[serde(default)]
+    per_kind: BTreeMap<QuestionKind, usize>,
+}
+
+impl AnswerSpace
+{
+    // pub fn new() -> Self
+    /// Creates an answer space that leaves no extra lines after any question.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AnswerSpace, QuestionKind };
+    /// assert_eq!(AnswerSpace::new().get_lines_for(QuestionKind::MultiLine), 0);
+    /// ```
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // pub fn get_lines(&self) -> usize
+    /// Returns the number of blank lines left after questions whose kind
+    /// has no number of its own.
+    ///
+    /// # Examples
+    /// See `AnswerSpace::get_lines_for`.
+    pub fn get_lines(&self) -> usize
+    {
+        self.lines
+    }
+
+    // pub fn set_lines(&mut self, lines: usize)
+    /// Sets the number of blank lines left after questions whose kind has
+    /// no number of its own.
+    ///
+    /// # Arguments
+    /// * `lines` - The number of lines, capped at `MAX_ANSWER_SPACE_LINES`.
+    ///
+    /// # Examples
+    /// See `AnswerSpace::get_lines_for`.
+    pub fn set_lines(&mut self, lines: usize)
+    {
+        self.lines = lines.min(MAX_ANSWER_SPACE_LINES);
+    }
+
+    // pub fn get_kind_lines(&self, kind: QuestionKind) -> Option<usize>
+    /// Returns the number of blank lines set for the questions of `kind`,
+    /// or `None` if they follow `AnswerSpace::get_lines`.
+    ///
+    /// # Examples
+    /// See `AnswerSpace::get_lines_for`.
+    pub fn get_kind_lines(&self, kind: QuestionKind) -> Option<usize>
+    {
+        self.per_kind.get(&kind).copied()
+    }
+
+    // pub fn set_kind_lines(&mut self, kind: QuestionKind, lines: Option<usize>)
+    /// Sets the number of blank lines left after the questions of `kind`.
+    ///
+    /// # Arguments
+    /// * `kind` - The question kind.
+    /// * `lines` - The number of lines, capped at `MAX_ANSWER_SPACE_LINES`,
+    ///   or `None` to follow `AnswerSpace::get_lines`.
+    ///
+    /// # Examples
+    /// See `AnswerSpace::get_lines_for`.
+    pub fn set_kind_lines(&mut self, kind: QuestionKind, lines: Option<usize>)
+    {
+        match lines
+        {
+            Some(lines) => { self.per_kind.insert(kind, lines.min(MAX_ANSWER_SPACE_LINES)); },
+            None => { self.per_kind.remove(&kind); },
+        }
+    }
+
+    // pub fn get_lines_for(&self, kind: QuestionKind) -> usize
+    /// Returns the number of blank lines left after a question of `kind`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AnswerSpace, QuestionKind, MAX_ANSWER_SPACE_LINES };
+    /// let mut space = AnswerSpace::new();
+    /// space.set_lines(2);
+    /// space.set_kind_lines(QuestionKind::MultiLine, Some(12));
+    /// assert_eq!(space.get_lines(), 2);
+    /// assert_eq!(space.get_kind_lines(QuestionKind::MultiLine), Some(12));
+    /// assert_eq!(space.get_kind_lines(QuestionKind::ShortText), None);
+    /// assert_eq!(space.get_lines_for(QuestionKind::MultiLine), 12);
+    /// assert_eq!(space.get_lines_for(QuestionKind::ShortText), 2);
+    ///
+    /// space.set_kind_lines(QuestionKind::MultiLine, None);
+    /// assert_eq!(space.get_lines_for(QuestionKind::MultiLine), 2);
+    /// space.set_lines(1000);
+    /// assert_eq!(space.get_lines(), MAX_ANSWER_SPACE_LINES);
+    /// ```
+    pub fn get_lines_for(&self, kind: QuestionKind) -> usize
+    {
+        self.get_kind_lines(kind).unwrap_or(self.lines)
+    }
+}
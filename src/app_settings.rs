@@ -0,0 +1,1033 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{ Path, PathBuf };
+
+use serde::{ Serialize, Deserialize };
+
+use crate::{ hash_pin, AnswerSpace, SeededRng, FileDialogKind, GradeDisplay, LetterScale, NumericTolerance, Page, PageTemplate, ThemeChoice, DEFAULT_DELETE_CONFIRM_THRESHOLD, DEFAULT_PROMPT_LENGTH, DEFAULT_QUESTION_PERCENT };
+
+/// The file the settings are stored in, relative to the working directory.
+const SETTINGS_FILE: &str = "./qrate-gui.settings.json";
+
+/// The autosave intervals offered, in minutes.
+pub const AUTOSAVE_MINUTES: [u64; 3] = [ 1, 5, 10 ];
+
+/// User preferences that persist between launches.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AppSettings
+{
+    #[serde(default)]
+    restore_last_page: bool,
+
+    #[serde(default)]
+    last_page: String,
+
+    #[serde(default)]
+    reduce_motion: bool,
+
+    #[serde(default)]
+    keep_menu_open: bool,
+
+    #[serde(default)]
+    theme: ThemeChoice,
+
+    /// Whether files are opened with the native dialog or the in-app browser.
+    #[serde(default)]
+    file_dialog: FileDialogKind,
+
+    /// The number of prompt characters shown in the questions table.
+    #[serde(default)]
+    prompt_length: Option<usize>,
+
+    /// The number of questions deleted at once from which confirmation is asked.
+    #[serde(default)]
+    delete_confirm_threshold: Option<usize>,
+
+    /// The share of a freshly loaded bank drawn into an exam, in percent.
+    #[serde(default)]
+    question_percent: Option<u8>,
+
+    /// The minutes of inactivity after which the screen locks.
+    #[serde(default)]
+    idle_lock_minutes: Option<u64>,
+
+    /// The hash of the PIN that unlocks the screen.
+    #[serde(default)]
+    idle_lock_pin: Option<u64>,
+
+    /// The random salt hashed with the PIN; `None` for a PIN set before
+    /// salts were kept.
+    #[serde(default)]
+    idle_lock_salt: Option<u64>,
+
+    /// The minutes between automatic saves of an edited bank.
+    #[serde(default)]
+    autosave_minutes: Option<u64>,
+
+    #[serde(default)]
+    last_file: Option<PathBuf>,
+
+    /// The question banks open in any window when the application closed.
+    #[serde(default)]
+    open_banks: Vec<PathBuf>,
+
+    #[serde(default)]
+    storage_path: Option<PathBuf>,
+
+    #[serde(default)]
+    grade_display: GradeDisplay,
+
+    #[serde(default)]
+    letter_scale: LetterScale,
+
+    /// How far typed numbers may be from the correct ones in self-study.
+    #[serde(default)]
+    numeric_tolerance: NumericTolerance,
+
+    /// The number of answer choices per printed line, by locale code.
+    #[serde(default)]
+    choices_per_row: BTreeMap<String, usize>,
+
+    #[serde(default)]
+    export_locale: Option<String>,
+
+    #[serde(default)]
+    answer_space: AnswerSpace,
+
+    #[serde(default)]
+    page_template: PageTemplate,
+
+    #[serde(default)]
+    locale: Option<String>,
+}
+
+impl AppSettings
+{
+    // pub fn new() -> Self
+    /// Creates the default settings: nothing is restored on launch.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// assert!(!AppSettings::new().get_restore_last_page());
+    /// ```
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // pub fn get_restore_last_page(&self) -> bool
+    /// Returns whether the last page and question bank are restored on launch.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// assert!(!AppSettings::new().get_restore_last_page());
+    /// ```
+    pub fn get_restore_last_page(&self) -> bool
+    {
+        self.restore_last_page
+    }
+
+    // pub fn set_restore_last_page(&mut self, restore: bool)
+    /// Sets whether the last page and question bank are restored on launch.
+    ///
+    /// # Arguments
+    /// * `restore` - `true` to restore them.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// let mut settings = AppSettings::new();
+    /// settings.set_restore_last_page(true);
+    /// assert!(settings.get_restore_last_page());
+    /// ```
+    pub fn set_restore_last_page(&mut self, restore: bool)
+    {
+        self.restore_last_page = restore;
+    }
+
+    // pub fn get_reduce_motion(&self) -> bool
+    /// Returns whether the interface avoids hover highlights and live
+    /// updates, for slow computers and users sensitive to motion.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// let mut settings = AppSettings::new();
+    /// assert!(!settings.get_reduce_motion());
+    /// settings.set_reduce_motion(true);
+    /// assert!(settings.get_reduce_motion());
+    /// ```
+    pub fn get_reduce_motion(&self) -> bool
+    {
+        self.reduce_motion
+    }
+
+    // pub fn set_reduce_motion(&mut self, reduce: bool)
+    /// Sets whether the interface avoids hover highlights and live updates.
+    ///
+    /// # Arguments
+    /// * `reduce` - `true` to reduce motion.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_reduce_motion`.
+    pub fn set_reduce_motion(&mut self, reduce: bool)
+    {
+        self.reduce_motion = reduce;
+    }
+
+    // pub fn get_keep_menu_open(&self) -> bool
+    /// Returns whether a submenu stays open after one of its items is
+    /// chosen, so that several actions can be taken from it in a row.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// let mut settings = AppSettings::new();
+    /// assert!(!settings.get_keep_menu_open());
+    /// settings.set_keep_menu_open(true);
+    /// assert!(settings.get_keep_menu_open());
+    /// ```
+    pub fn get_keep_menu_open(&self) -> bool
+    {
+        self.keep_menu_open
+    }
+
+    // pub fn set_keep_menu_open(&mut self, keep: bool)
+    /// Sets whether a submenu stays open after one of its items is chosen.
+    ///
+    /// # Arguments
+    /// * `keep` - `true` to keep it open, `false` to close it as before.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_keep_menu_open`.
+    pub fn set_keep_menu_open(&mut self, keep: bool)
+    {
+        self.keep_menu_open = keep;
+    }
+
+    // pub fn get_theme(&self) -> ThemeChoice
+    /// Returns the theme picked by the user.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AppSettings, ThemeChoice };
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_theme(), ThemeChoice::System);
+    /// settings.set_theme(ThemeChoice::Dark);
+    /// assert_eq!(settings.get_theme(), ThemeChoice::Dark);
+    /// ```
+    pub fn get_theme(&self) -> ThemeChoice
+    {
+        self.theme
+    }
+
+    // pub fn set_theme(&mut self, theme: ThemeChoice)
+    /// Sets the theme, or lets it follow the operating system.
+    ///
+    /// # Arguments
+    /// * `theme` - The theme.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_theme`.
+    pub fn set_theme(&mut self, theme: ThemeChoice)
+    {
+        self.theme = theme;
+    }
+
+    // pub fn get_file_dialog(&self) -> FileDialogKind
+    /// Returns which file dialog is used to open files.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AppSettings, FileDialogKind };
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_file_dialog(), FileDialogKind::Native);
+    /// settings.set_file_dialog(FileDialogKind::InApp);
+    /// assert_eq!(settings.get_file_dialog(), FileDialogKind::InApp);
+    /// ```
+    pub fn get_file_dialog(&self) -> FileDialogKind
+    {
+        self.file_dialog
+    }
+
+    // pub fn set_file_dialog(&mut self, kind: FileDialogKind)
+    /// Sets which file dialog is used to open files.
+    ///
+    /// # Arguments
+    /// * `kind` - The native dialog or the in-app browser.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_file_dialog`.
+    pub fn set_file_dialog(&mut self, kind: FileDialogKind)
+    {
+        self.file_dialog = kind;
+    }
+
+    // pub fn get_prompt_length(&self) -> usize
+    /// Returns the number of prompt characters shown in the questions
+    /// table before the rest is cut off.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AppSettings, DEFAULT_PROMPT_LENGTH };
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_prompt_length(), DEFAULT_PROMPT_LENGTH);
+    /// settings.set_prompt_length(200);
+    /// assert_eq!(settings.get_prompt_length(), 200);
+    /// settings.set_prompt_length(0);
+    /// assert_eq!(settings.get_prompt_length(), 1);
+    /// ```
+    pub fn get_prompt_length(&self) -> usize
+    {
+        self.prompt_length.unwrap_or(DEFAULT_PROMPT_LENGTH)
+    }
+
+    // pub fn set_prompt_length(&mut self, length: usize)
+    /// Sets the number of prompt characters shown in the questions table.
+    ///
+    /// # Arguments
+    /// * `length` - The number of characters; `0` is treated as `1`.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_prompt_length`.
+    pub fn set_prompt_length(&mut self, length: usize)
+    {
+        self.prompt_length = Some(length.max(1));
+    }
+
+    // pub fn get_delete_confirm_threshold(&self) -> usize
+    /// Returns the number of questions deleted at once from which the
+    /// deletion is confirmed first.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AppSettings, DEFAULT_DELETE_CONFIRM_THRESHOLD };
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_delete_confirm_threshold(), DEFAULT_DELETE_CONFIRM_THRESHOLD);
+    /// settings.set_delete_confirm_threshold(20);
+    /// assert_eq!(settings.get_delete_confirm_threshold(), 20);
+    /// settings.set_delete_confirm_threshold(0);
+    /// assert_eq!(settings.get_delete_confirm_threshold(), 1);
+    /// ```
+    pub fn get_delete_confirm_threshold(&self) -> usize
+    {
+        self.delete_confirm_threshold.unwrap_or(DEFAULT_DELETE_CONFIRM_THRESHOLD)
+    }
+
+    // pub fn set_delete_confirm_threshold(&mut self, threshold: usize)
+    /// Sets the number of questions deleted at once from which the deletion
+    /// is confirmed first.
+    ///
+    /// # Arguments
+    /// * `threshold` - The smallest confirmed count; `0` is treated as `1`,
+    ///   which confirms every deletion.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_delete_confirm_threshold`.
+    pub fn set_delete_confirm_threshold(&mut self, threshold: usize)
+    {
+        self.delete_confirm_threshold = Some(threshold.max(1));
+    }
+
+    // pub fn get_question_percent(&self) -> u8
+    /// Returns the share of a freshly loaded bank, in percent, from which
+    /// the default number of exam questions is derived.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AppSettings, DEFAULT_QUESTION_PERCENT };
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_question_percent(), DEFAULT_QUESTION_PERCENT);
+    /// settings.set_question_percent(50);
+    /// assert_eq!(settings.get_question_percent(), 50);
+    /// settings.set_question_percent(0);
+    /// assert_eq!(settings.get_question_percent(), 1);
+    /// settings.set_question_percent(250);
+    /// assert_eq!(settings.get_question_percent(), 100);
+    /// ```
+    pub fn get_question_percent(&self) -> u8
+    {
+        self.question_percent.unwrap_or(DEFAULT_QUESTION_PERCENT)
+    }
+
+    // pub fn set_question_percent(&mut self, percent: u8)
+    /// Sets the share of a freshly loaded bank drawn into an exam.
+    ///
+    /// # Arguments
+    /// * `percent` - The share in percent, kept between `1` and `100`.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_question_percent`.
+    pub fn set_question_percent(&mut self, percent: u8)
+    {
+        self.question_percent = Some(percent.clamp(1, 100));
+    }
+
+    // pub fn get_idle_lock_minutes(&self) -> Option<u64>
+    /// Returns the minutes of inactivity after which the screen locks, or
+    /// `None` if it never locks by itself.
+    ///
+    /// # Examples
+    /// See `AppSettings::is_idle_lock_enabled`.
+    pub fn get_idle_lock_minutes(&self) -> Option<u64>
+    {
+        self.idle_lock_minutes
+    }
+
+    // pub fn set_idle_lock_minutes(&mut self, minutes: Option<u64>)
+    /// Sets the minutes of inactivity after which the screen locks.
+    ///
+    /// # Arguments
+    /// * `minutes` - The idle period, at least one minute, or `None` to
+    ///   turn the lock off.
+    ///
+    /// # Examples
+    /// See `AppSettings::is_idle_lock_enabled`.
+    pub fn set_idle_lock_minutes(&mut self, minutes: Option<u64>)
+    {
+        self.idle_lock_minutes = minutes.map(|minutes| minutes.max(1));
+    }
+
+    // pub fn set_idle_lock_pin(&mut self, pin: &str)
+    /// Sets the PIN that unlocks the screen. Only its hash is kept, with a
+    /// fresh random salt. See `hash_pin` for how little the lock protects.
+    ///
+    /// # Arguments
+    /// * `pin` - The PIN, or an empty string to remove it.
+    ///
+    /// # Examples
+    /// See `AppSettings::is_idle_lock_enabled`.
+    pub fn set_idle_lock_pin(&mut self, pin: &str)
+    {
+        self.idle_lock_salt = (!pin.is_empty()).then(|| SeededRng::from_time().next_u64());
+        self.idle_lock_pin = (!pin.is_empty()).then(|| hash_pin(pin, self.idle_lock_salt));
+    }
+
+    // pub fn has_idle_lock_pin(&self) -> bool
+    /// Returns whether a PIN for unlocking the screen is set.
+    ///
+    /// # Examples
+    /// See `AppSettings::is_idle_lock_enabled`.
+    pub fn has_idle_lock_pin(&self) -> bool
+    {
+        self.idle_lock_pin.is_some()
+    }
+
+    // pub fn check_idle_lock_pin(&self, pin: &str) -> bool
+    /// Returns whether `pin` is the PIN that unlocks the screen.
+    ///
+    /// # Arguments
+    /// * `pin` - The PIN as typed.
+    ///
+    /// # Examples
+    /// See `AppSettings::is_idle_lock_enabled`.
+    pub fn check_idle_lock_pin(&self, pin: &str) -> bool
+    {
+        self.idle_lock_pin == Some(hash_pin(pin, self.idle_lock_salt))
+    }
+
+    // pub fn is_idle_lock_enabled(&self) -> bool
+    /// Returns whether the screen locks after a period of inactivity, which
+    /// needs both the period and a PIN to be set.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ hash_pin, AppSettings };
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_idle_lock_minutes(), None);
+    /// settings.set_idle_lock_minutes(Some(5));
+    /// assert!(!settings.is_idle_lock_enabled());
+    ///
+    /// settings.set_idle_lock_pin("2468");
+    /// assert!(settings.has_idle_lock_pin());
+    /// assert!(settings.is_idle_lock_enabled());
+    /// assert!(settings.check_idle_lock_pin("2468"));
+    /// assert!(!settings.check_idle_lock_pin("1357"));
+    ///
+    /// // A PIN saved before salts were kept still unlocks the screen.
+    /// let json = format!(r#"{{ "idle_lock_pin": {} }}"#, hash_pin("2468", None));
+    /// let legacy: AppSettings = serde_json::from_str(&json).unwrap();
+    /// assert!(legacy.check_idle_lock_pin("2468"));
+    /// assert!(!legacy.check_idle_lock_pin("1357"));
+    ///
+    /// settings.set_idle_lock_pin("");
+    /// assert!(!settings.is_idle_lock_enabled());
+    /// assert!(!settings.check_idle_lock_pin(""));
+    /// settings.set_idle_lock_minutes(Some(0));
+    /// assert_eq!(settings.get_idle_lock_minutes(), Some(1));
+    /// ```
+    pub fn is_idle_lock_enabled(&self) -> bool
+    {
+        self.idle_lock_minutes.is_some() && self.has_idle_lock_pin()
+    }
+
+    // pub fn get_autosave_minutes(&self) -> Option<u64>
+    /// Returns the minutes between automatic saves of an edited question
+    /// bank, or `None` if it is only saved by hand.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_autosave_minutes(), None);
+    /// settings.set_autosave_minutes(Some(5));
+    /// assert_eq!(settings.get_autosave_minutes(), Some(5));
+    /// settings.set_autosave_minutes(Some(0));
+    /// assert_eq!(settings.get_autosave_minutes(), Some(1));
+    /// settings.set_autosave_minutes(None);
+    /// assert_eq!(settings.get_autosave_minutes(), None);
+    /// ```
+    pub fn get_autosave_minutes(&self) -> Option<u64>
+    {
+        self.autosave_minutes
+    }
+
+    // pub fn set_autosave_minutes(&mut self, minutes: Option<u64>)
+    /// Sets the minutes between automatic saves of an edited question bank.
+    ///
+    /// # Arguments
+    /// * `minutes` - The interval, at least one minute, or `None` to turn
+    ///   autosave off.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_autosave_minutes`.
+    pub fn set_autosave_minutes(&mut self, minutes: Option<u64>)
+    {
+        self.autosave_minutes = minutes.map(|minutes| minutes.max(1));
+    }
+
+    // pub fn get_last_page(&self) -> &str
+    /// Returns the page that was open last.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// assert_eq!(AppSettings::new().get_last_page(), "");
+    /// ```
+    pub fn get_last_page(&self) -> &str
+    {
+        &self.last_page
+    }
+
+    // pub fn set_last_page(&mut self, page: String)
+    /// Records the page that is open.
+    ///
+    /// # Arguments
+    /// * `page` - The page identifier.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// let mut settings = AppSettings::new();
+    /// settings.set_last_page("question-bank-edit".to_string());
+    /// assert_eq!(settings.get_last_page(), "question-bank-edit");
+    /// ```
+    pub fn set_last_page(&mut self, page: String)
+    {
+        self.last_page = page;
+    }
+
+    // pub fn get_last_file(&self) -> Option<&Path>
+    /// Returns the question bank that was loaded last.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// assert_eq!(AppSettings::new().get_last_file(), None);
+    /// ```
+    pub fn get_last_file(&self) -> Option<&Path>
+    {
+        self.last_file.as_deref()
+    }
+
+    // pub fn set_last_file(&mut self, file: Option<PathBuf>)
+    /// Records the question bank that is loaded.
+    ///
+    /// # Arguments
+    /// * `file` - The path of the bank, or `None` if no bank is loaded.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::{ Path, PathBuf };
+    /// use qrate_gui::AppSettings;
+    /// let mut settings = AppSettings::new();
+    /// settings.set_last_file(Some(PathBuf::from("math.qbdb")));
+    /// assert_eq!(settings.get_last_file(), Some(Path::new("math.qbdb")));
+    /// ```
+    pub fn set_last_file(&mut self, file: Option<PathBuf>)
+    {
+        self.last_file = file;
+    }
+
+    // pub fn get_open_banks(&self) -> &[PathBuf]
+    /// Returns the question banks that were open when the application
+    /// closed last, whether or not they still exist.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_restorable_banks`.
+    pub fn get_open_banks(&self) -> &[PathBuf]
+    {
+        &self.open_banks
+    }
+
+    // pub fn set_open_banks(&mut self, banks: Vec<PathBuf>)
+    /// Records the question banks open when the application closes.
+    ///
+    /// # Arguments
+    /// * `banks` - The paths of the open banks, main window first.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_restorable_banks`.
+    pub fn set_open_banks(&mut self, banks: Vec<PathBuf>)
+    {
+        self.open_banks = banks;
+    }
+
+    // pub fn get_restorable_banks(&self) -> Vec<PathBuf>
+    /// Returns the question banks of the last session that can be opened
+    /// again, leaving out those that were moved or deleted since.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::AppSettings;
+    ///
+    /// let existing = std::env::temp_dir().join("qrate_gui_restorable_bank.qbdb");
+    /// std::fs::write(&existing, b"").unwrap();
+    /// let missing = PathBuf::from("/definitely/not/here.qbdb");
+    ///
+    /// let mut settings = AppSettings::new();
+    /// assert!(settings.get_restorable_banks().is_empty());
+    /// settings.set_open_banks(vec![missing.clone(), existing.clone()]);
+    /// assert_eq!(settings.get_open_banks(), [missing, existing.clone()]);
+    /// assert_eq!(settings.get_restorable_banks(), vec![existing]);
+    /// ```
+    pub fn get_restorable_banks(&self) -> Vec<PathBuf>
+    {
+        self.open_banks.iter().filter(|bank| bank.is_file()).cloned().collect()
+    }
+
+    // pub fn get_storage_path(&self) -> Option<&Path>
+    /// Returns the folder the question banks are kept in, if configured.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// assert_eq!(AppSettings::new().get_storage_path(), None);
+    /// ```
+    pub fn get_storage_path(&self) -> Option<&Path>
+    {
+        self.storage_path.as_deref()
+    }
+
+    // pub fn set_storage_path(&mut self, path: Option<PathBuf>)
+    /// Sets the folder the question banks are kept in.
+    ///
+    /// # Arguments
+    /// * `path` - The folder, or `None` to forget it.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_dialog_directory`.
+    pub fn set_storage_path(&mut self, path: Option<PathBuf>)
+    {
+        self.storage_path = path;
+    }
+
+    // pub fn get_dialog_directory(&self) -> PathBuf
+    /// Returns the directory the question bank file dialog starts in.
+    ///
+    /// # Output
+    /// The configured storage path, or else the folder of the question bank
+    /// loaded last, or else the working directory `"."`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::AppSettings;
+    ///
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_dialog_directory(), PathBuf::from("."));
+    ///
+    /// settings.set_last_file(Some(PathBuf::from("/banks/math/algebra.qbdb")));
+    /// assert_eq!(settings.get_dialog_directory(), PathBuf::from("/banks/math"));
+    ///
+    /// settings.set_storage_path(Some(PathBuf::from("/school/banks")));
+    /// assert_eq!(settings.get_dialog_directory(), PathBuf::from("/school/banks"));
+    ///
+    /// // A bare file name has no folder to start in.
+    /// settings.set_storage_path(None);
+    /// settings.set_last_file(Some(PathBuf::from("algebra.qbdb")));
+    /// assert_eq!(settings.get_dialog_directory(), PathBuf::from("."));
+    /// ```
+    pub fn get_dialog_directory(&self) -> PathBuf
+    {
+        let last_folder = self.last_file.as_deref()
+                                .and_then(Path::parent)
+                                .filter(|folder| !folder.as_os_str().is_empty());
+        self.storage_path.as_deref()
+            .or(last_folder)
+            .unwrap_or(Path::new("."))
+            .to_path_buf()
+    }
+
+    // pub fn get_grade_display(&self) -> GradeDisplay
+    /// Returns how grades are shown in the grade report.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AppSettings, GradeDisplay };
+    /// assert_eq!(AppSettings::new().get_grade_display(), GradeDisplay::Points);
+    /// ```
+    pub fn get_grade_display(&self) -> GradeDisplay
+    {
+        self.grade_display
+    }
+
+    // pub fn set_grade_display(&mut self, display: GradeDisplay)
+    /// Sets how grades are shown in the grade report.
+    ///
+    /// # Arguments
+    /// * `display` - Points, percentage or letter.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AppSettings, GradeDisplay };
+    /// let mut settings = AppSettings::new();
+    /// settings.set_grade_display(GradeDisplay::Letter);
+    /// assert_eq!(settings.get_grade_display(), GradeDisplay::Letter);
+    /// ```
+    pub fn set_grade_display(&mut self, display: GradeDisplay)
+    {
+        self.grade_display = display;
+    }
+
+    // pub fn get_letter_scale(&self) -> &LetterScale
+    /// Returns the scale letter grades are read from.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AppSettings, LetterScale };
+    /// assert_eq!(AppSettings::new().get_letter_scale(), &LetterScale::default());
+    /// ```
+    pub fn get_letter_scale(&self) -> &LetterScale
+    {
+        &self.letter_scale
+    }
+
+    // pub fn set_letter_scale(&mut self, scale: LetterScale)
+    /// Sets the scale letter grades are read from.
+    ///
+    /// # Arguments
+    /// * `scale` - The letter scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AppSettings, LetterScale };
+    /// let mut settings = AppSettings::new();
+    /// settings.set_letter_scale(LetterScale::parse("Pass=50, Fail=0").unwrap());
+    /// assert_eq!(settings.get_letter_scale().to_text(), "Pass=50, Fail=0");
+    /// ```
+    pub fn set_letter_scale(&mut self, scale: LetterScale)
+    {
+        self.letter_scale = scale;
+    }
+
+    // pub fn get_numeric_tolerance(&self) -> NumericTolerance
+    /// Returns how far typed numbers may be from the correct ones in
+    /// self-study.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AppSettings, NumericTolerance };
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_numeric_tolerance(), NumericTolerance::default());
+    /// settings.set_numeric_tolerance(NumericTolerance::new(0.01, 2.0));
+    /// assert_eq!(settings.get_numeric_tolerance().get_percent(), 2.0);
+    /// ```
+    pub fn get_numeric_tolerance(&self) -> NumericTolerance
+    {
+        self.numeric_tolerance
+    }
+
+    // pub fn set_numeric_tolerance(&mut self, tolerance: NumericTolerance)
+    /// Sets how far typed numbers may be from the correct ones in self-study.
+    ///
+    /// # Arguments
+    /// * `tolerance` - The absolute and percentage tolerance.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_numeric_tolerance`.
+    pub fn set_numeric_tolerance(&mut self, tolerance: NumericTolerance)
+    {
+        self.numeric_tolerance = tolerance;
+    }
+
+    // pub fn get_choices_per_row(&self, locale: &str) -> usize
+    /// Returns how many answer choices are printed side by side in exams
+    /// exported while `locale` is selected.
+    ///
+    /// # Arguments
+    /// * `locale` - The locale code, such as `"ko"`.
+    ///
+    /// # Output
+    /// The stored number, or `1` if none is stored for `locale`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_choices_per_row("ko"), 1);
+    /// settings.set_choices_per_row("ko", 2);
+    /// assert_eq!(settings.get_choices_per_row("ko"), 2);
+    /// assert_eq!(settings.get_choices_per_row("en"), 1);
+    /// ```
+    pub fn get_choices_per_row(&self, locale: &str) -> usize
+    {
+        self.choices_per_row.get(locale).copied().unwrap_or(1)
+    }
+
+    // pub fn set_choices_per_row(&mut self, locale: &str, count: usize)
+    /// Sets how many answer choices are printed side by side in exams
+    /// exported while `locale` is selected.
+    ///
+    /// # Arguments
+    /// * `locale` - The locale code.
+    /// * `count` - The number of columns.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_choices_per_row`.
+    pub fn set_choices_per_row(&mut self, locale: &str, count: usize)
+    {
+        self.choices_per_row.insert(locale.to_string(), count);
+    }
+
+    // pub fn get_locale(&self) -> Option<&str>
+    /// Returns the locale of the interface chosen last, or `None` if none
+    /// was chosen yet.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_locale(), None);
+    /// settings.set_locale(Some("ko".to_string()));
+    /// assert_eq!(settings.get_locale(), Some("ko"));
+    /// ```
+    pub fn get_locale(&self) -> Option<&str>
+    {
+        self.locale.as_deref()
+    }
+
+    // pub fn set_locale(&mut self, locale: Option<String>)
+    /// Sets the locale of the interface restored on launch.
+    ///
+    /// # Arguments
+    /// * `locale` - The locale code, or `None` for the default.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_locale`.
+    pub fn set_locale(&mut self, locale: Option<String>)
+    {
+        self.locale = locale;
+    }
+
+    // pub fn get_export_locale(&self) -> Option<&str>
+    /// Returns the locale the labels of exported exams are printed in, or
+    /// `None` to follow the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_export_locale(), None);
+    /// settings.set_export_locale(Some("ko".to_string()));
+    /// assert_eq!(settings.get_export_locale(), Some("ko"));
+    /// ```
+    pub fn get_export_locale(&self) -> Option<&str>
+    {
+        self.export_locale.as_deref()
+    }
+
+    // pub fn set_export_locale(&mut self, locale: Option<String>)
+    /// Sets the locale the labels of exported exams are printed in.
+    ///
+    /// # Arguments
+    /// * `locale` - The locale code, or `None` to follow the interface.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_export_locale`.
+    pub fn set_export_locale(&mut self, locale: Option<String>)
+    {
+        self.export_locale = locale;
+    }
+
+    // pub fn get_answer_space(&self) -> &AnswerSpace
+    /// Returns the blank writing space left after each question of
+    /// exported exams.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AnswerSpace, AppSettings, QuestionKind };
+    /// let mut settings = AppSettings::new();
+    /// assert_eq!(settings.get_answer_space(), &AnswerSpace::new());
+    /// let mut space = AnswerSpace::new();
+    /// space.set_kind_lines(QuestionKind::MultiLine, Some(8));
+    /// settings.set_answer_space(space.clone());
+    /// assert_eq!(settings.get_answer_space(), &space);
+    /// ```
+    pub fn get_answer_space(&self) -> &AnswerSpace
+    {
+        &self.answer_space
+    }
+
+    // pub fn set_answer_space(&mut self, space: AnswerSpace)
+    /// Sets the blank writing space left after each question of exported
+    /// exams.
+    ///
+    /// # Arguments
+    /// * `space` - The number of lines, for all questions or by kind.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_answer_space`.
+    pub fn set_answer_space(&mut self, space: AnswerSpace)
+    {
+        self.answer_space = space;
+    }
+
+    // pub fn get_page_template(&self) -> &PageTemplate
+    /// Returns the header and footer printed on every page of exported PDF
+    /// exams.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AppSettings, PageTemplate };
+    /// let mut settings = AppSettings::new();
+    /// assert!(settings.get_page_template().is_empty());
+    /// let mut template = PageTemplate::new();
+    /// template.set_footer("Page {page} of {total_pages}".to_string());
+    /// settings.set_page_template(template.clone());
+    /// assert_eq!(settings.get_page_template(), &template);
+    /// ```
+    pub fn get_page_template(&self) -> &PageTemplate
+    {
+        &self.page_template
+    }
+
+    // pub fn set_page_template(&mut self, template: PageTemplate)
+    /// Sets the header and footer printed on every page of exported PDF
+    /// exams.
+    ///
+    /// # Arguments
+    /// * `template` - The header and footer templates and the logo.
+    ///
+    /// # Examples
+    /// See `AppSettings::get_page_template`.
+    pub fn set_page_template(&mut self, template: PageTemplate)
+    {
+        self.page_template = template;
+    }
+
+    // pub fn get_startup_page(&self) -> &str
+    /// Returns the page to open on launch.
+    ///
+    /// # Output
+    /// The last page if restoring is enabled and the page is not transient,
+    /// otherwise `"main"`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AppSettings;
+    /// let mut settings = AppSettings::new();
+    /// settings.set_last_page("question-bank-edit".to_string());
+    /// assert_eq!(settings.get_startup_page(), "main");
+    ///
+    /// settings.set_restore_last_page(true);
+    /// assert_eq!(settings.get_startup_page(), "question-bank-edit");
+    ///
+    /// // An exam in progress is not restored.
+    /// settings.set_last_page("learning".to_string());
+    /// assert_eq!(settings.get_startup_page(), "main");
+    /// ```
+    pub fn get_startup_page(&self) -> &str
+    {
+        if self.restore_last_page && !self.last_page.is_empty() && !Page::from_id(&self.last_page).is_transient()
+            { &self.last_page }
+        else
+            { "main" }
+    }
+}
+
+// pub fn get_settings_path() -> PathBuf
+/// Returns the path of the settings file in the working directory.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::get_settings_path;
+/// assert!(get_settings_path().ends_with("qrate-gui.settings.json"));
+/// ```
+pub fn get_settings_path() -> PathBuf
+{
+    PathBuf::from(SETTINGS_FILE)
+}
+
+// pub fn load_settings(path: &Path) -> AppSettings
+/// Loads the settings stored at `path`.
+///
+/// # Arguments
+/// * `path` - The settings file.
+///
+/// # Output
+/// The stored settings, or the defaults if the file is missing or cannot be
+/// parsed.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use qrate_gui::{ load_settings, AppSettings };
+/// assert_eq!(load_settings(Path::new("/definitely/not/here.json")), AppSettings::new());
+/// ```
+pub fn load_settings(path: &Path) -> AppSettings
+{
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+// pub fn save_settings(path: &Path, settings: &AppSettings) -> io::Result<()>
+/// Saves `settings` to `path`.
+///
+/// # Arguments
+/// * `path` - The settings file.
+/// * `settings` - The settings to store.
+///
+/// # Output
+/// An `io::Result` reporting write failures.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ load_settings, save_settings, AppSettings };
+/// let path = std::env::temp_dir().join("qrate_gui_save_settings.json");
+/// let mut settings = AppSettings::new();
+/// settings.set_restore_last_page(true);
+/// save_settings(&path, &settings).unwrap();
+/// assert_eq!(load_settings(&path), settings);
+/// ```
+pub fn save_settings(path: &Path, settings: &AppSettings) -> io::Result<()>
+{
+    let json = serde_json::to_string_pretty(settings).map_err(io::Error::other)?;
+    fs::write(path, json)
+}
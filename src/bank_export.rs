@@ -0,0 +1,207 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use qrate::{ Excel, Header, QBank, QBDB, Question, SQLiteDB };
+
+use crate::{ save_question_metas, ExportError, QuestionMetas };
+
+/// Which questions of the open bank are written when it is exported as a
+/// new bank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportSource
+{
+    /// Every question of the bank.
+    #[default]
+    All,
+
+    /// The questions the search, category and difficulty filters of the
+    /// questions table let through, such as one topic of a large bank.
+    Filtered,
+
+    /// The questions picked by hand in the questions table.
+    Selected,
+}
+
+impl ExportSource
+{
+    /// All export sources, in the order they are offered to the user.
+    pub const ALL: [ExportSource; 3] = [ ExportSource::All, ExportSource::Filtered, ExportSource::Selected ];
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key describing this export source.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExportSource;
+    /// assert_eq!(ExportSource::Filtered.get_key(), "export-filtered");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            ExportSource::All => "export-all",
+            ExportSource::Filtered => "export-filtered",
+            ExportSource::Selected => "export-selected",
+        }
+    }
+}
+
+// pub fn resolve_export_path(chosen: &Path, open_bank: &Path) -> Result<PathBuf, ExportError>
+/// Turns the path picked in the save dialog into the path a new bank is
+/// written to, and refuses the file of the open bank.
+///
+/// A workbook must be named `*.qb.xlsx` to load again, so a plain `*.xlsx`
+/// gets the `.qb` added. Any other name that is neither `*.qbdb` nor
+/// `*.qb.xlsx` gets `.qbdb` appended.
+///
+/// # Arguments
+/// * `chosen` - The path picked by the user.
+/// * `open_bank` - The file of the open bank, which may be empty.
+///
+/// # Output
+/// The target path, or `ExportError::Write` if it is the open bank, which
+/// the export would replace with a part of its questions.
+///
+/// # Examples
+/// ```
+/// use std::path::{ Path, PathBuf };
+/// use qrate_gui::resolve_export_path;
+///
+/// let none = Path::new("");
+/// assert_eq!(resolve_export_path(Path::new("/banks/topic.xlsx"), none), Ok(PathBuf::from("/banks/topic.qb.xlsx")));
+/// assert_eq!(resolve_export_path(Path::new("/banks/topic.qb.xlsx"), none), Ok(PathBuf::from("/banks/topic.qb.xlsx")));
+/// assert_eq!(resolve_export_path(Path::new("/banks/topic.qbdb"), none), Ok(PathBuf::from("/banks/topic.qbdb")));
+/// assert_eq!(resolve_export_path(Path::new("/banks/topic"), none), Ok(PathBuf::from("/banks/topic.qbdb")));
+///
+/// let open_bank = Path::new("/banks/algebra.qbdb");
+/// assert!(resolve_export_path(Path::new("/banks/algebra.qbdb"), open_bank).is_err());
+/// assert!(resolve_export_path(Path::new("/banks/../banks/algebra.qbdb"), open_bank).is_err());
+/// assert!(resolve_export_path(Path::new("/banks/algebra-part.qbdb"), open_bank).is_ok());
+/// ```
+pub fn resolve_export_path(chosen: &Path, open_bank: &Path) -> Result<PathBuf, ExportError>
+{
+    let chosen_str = chosen.to_string_lossy();
+    let path = if chosen_str.ends_with(".qbdb") || chosen_str.ends_with(".qb.xlsx")
+        { chosen.to_path_buf() }
+    else if let Some(base) = chosen_str.strip_suffix(".xlsx")
+        { PathBuf::from(format!("{}.qb.xlsx", base)) }
+    else
+        { PathBuf::from(format!("{}.qbdb", chosen_str)) };
+
+    // Either path may not exist yet, so they are compared both resolved and
+    // as they are, with `..` and `.` components dropped.
+    let normalize = |path: &Path| -> PathBuf {
+        fs::canonicalize(path).unwrap_or_else(|_| {
+            let mut normal = PathBuf::new();
+            for component in path.components()
+            {
+                match component
+                {
+                    std::path::Component::ParentDir => { normal.pop(); },
+                    std::path::Component::CurDir => {},
+                    other => normal.push(other),
+                }
+            }
+            normal
+        })
+    };
+    if !open_bank.as_os_str().is_empty() && normalize(&path) == normalize(open_bank)
+        { return Err(ExportError::Write("The open question bank cannot be replaced by an export; choose another file.".to_string())); }
+    Ok(path)
+}
+
+// pub fn export_questions(header: &Header, questions: &[&Question], metas: &QuestionMetas, path: &Path) -> Result<(), ExportError>
+/// Writes `questions` as a new question bank with `header` to `path`,
+/// together with the metadata of those questions. An existing bank at
+/// `path` is replaced.
+///
+/// # Arguments
+/// * `header` - The header of the new bank, usually that of the open bank.
+/// * `questions` - The questions to write, in bank order.
+/// * `metas` - The question metadata; only that of `questions` is kept.
+/// * `path` - The target path, ending in `.qbdb` or `.qb.xlsx`.
+///
+/// # Output
+/// `Ok(())` on success, or `ExportError::Write` if there are no questions,
+/// the extension is not supported or the bank cannot be written.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, QBank, Question };
+/// use qrate_gui::{ export_questions, load_question_metas, LoadFile, QuestionMeta, QuestionMetas, ResultLoadFile };
+///
+/// let mut header = Header::new_with_default();
+/// header.set_categories(vec!["Algebra".to_string()]);
+/// let mut qbank = QBank::new_with_header(header);
+/// for id in 1..=4
+///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![("a".to_string(), true), ("b".to_string(), false)])); }
+/// let mut metas = QuestionMetas::new();
+/// for id in [1, 3]
+/// {
+///     let mut meta = QuestionMeta::new();
+///     meta.set_difficulty(2);
+///     metas.insert(id, meta);
+/// }
+///
+/// let questions: Vec<&Question> = qbank.get_questions().iter().filter(|q| q.get_id() >= 3).collect();
+/// let dir = std::env::temp_dir();
+/// for path in [dir.join("qrate_gui_export_questions.qbdb"), dir.join("qrate_gui_export_questions.qb.xlsx")]
+/// {
+///     // Exporting twice replaces the bank rather than adding to it.
+///     export_questions(qbank.get_header(), &questions, &metas, &path).unwrap();
+///     export_questions(qbank.get_header(), &questions, &metas, &path).unwrap();
+///     let ResultLoadFile::Success(exported) = LoadFile::read_qbank_from_path(&path)
+///         else { panic!("the exported bank should load") };
+///     let ids: Vec<u16> = exported.get_questions().iter().map(|q| q.get_id()).collect();
+///     assert_eq!(ids, [3, 4]);
+///     assert_eq!(exported.get_header().get_categories(), &["Algebra".to_string()]);
+///     let exported_metas = load_question_metas(&path);
+///     assert_eq!(exported_metas.keys().copied().collect::<Vec<u16>>(), [3]);
+/// }
+///
+/// assert!(export_questions(qbank.get_header(), &[], &metas, &dir.join("qrate_gui_export_none.qbdb")).is_err());
+/// assert!(export_questions(qbank.get_header(), &questions, &metas, &dir.join("qrate_gui_export.txt")).is_err());
+/// ```
+pub fn export_questions(header: &Header, questions: &[&Question], metas: &QuestionMetas, path: &Path) -> Result<(), ExportError>
+{
+    if questions.is_empty()
+        { return Err(ExportError::Write("There are no questions to export.".to_string())); }
+
+    let mut qbank = QBank::new_with_header(header.clone());
+    for question in questions.iter()
+        { qbank.push_question((*question).clone()); }
+    let path_str = path.to_string_lossy().into_owned();
+    if path_str.ends_with(".qbdb")
+    {
+        // SQLite would add the questions to those already in the file.
+        if path.exists()
+            { fs::remove_file(path).map_err(|e| ExportError::from_io(&e, path))?; }
+        let mut db = SQLiteDB::open(path_str).ok_or_else(|| ExportError::Write("Failed to open QBDB file.".to_string()))?;
+        db.write_qbank(&qbank).map_err(ExportError::Write)?;
+    }
+    else if let Some(base) = path_str.strip_suffix(".qb.xlsx")
+    {
+        let mut excel = Excel::open(base.to_string()).ok_or_else(|| ExportError::Write("Failed to open Excel file.".to_string()))?;
+        excel.write_qbank(&qbank).map_err(ExportError::Write)?;
+    }
+    else
+    {
+        return Err(ExportError::Write("Unsupported file extension.".to_string()));
+    }
+
+    let kept: QuestionMetas = metas.iter()
+                                    .filter(|(id, _)| questions.iter().any(|question| question.get_id() == **id))
+                                    .map(|(id, meta)| (*id, meta.clone()))
+                                    .collect();
+    save_question_metas(path, &kept).map_err(|e| ExportError::from_io(&e, path))
+}
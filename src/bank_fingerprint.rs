@@ -0,0 +1,141 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::BTreeMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{ Hash, Hasher };
+use std::path::Path;
+
+use qrate::QBank;
+
+use crate::{ LoadFile, ResultLoadFile, BankMetadata, QuestionMetas };
+use crate::{ load_bank_metadata, load_question_metas };
+
+// pub fn fingerprint_bank(qbank: &QBank, metas: &QuestionMetas, metadata: &BankMetadata) -> u64
+/// Hashes everything that is saved for a question bank: its header and
+/// questions, the question metadata and the bank metadata.
+///
+/// Two banks with the same content get the same fingerprint within one run
+/// of the program, whatever order the question metadata was inserted in.
+///
+/// # Arguments
+/// * `qbank` - The question bank.
+/// * `metas` - The metadata of its questions.
+/// * `metadata` - The bank metadata.
+///
+/// # Examples
+/// ```
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::{ fingerprint_bank, BankMetadata, QuestionMetas };
+///
+/// let mut qbank = QBank::new_with_default();
+/// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![("Yes".to_string(), true)]));
+/// let metas = QuestionMetas::new();
+/// let metadata = BankMetadata::new();
+/// let before = fingerprint_bank(&qbank, &metas, &metadata);
+/// assert_eq!(fingerprint_bank(&qbank.clone(), &metas, &metadata), before);
+///
+/// qbank.push_question(Question::new(2, 2, 1, "Q2".to_string(), vec![]));
+/// assert_ne!(fingerprint_bank(&qbank, &metas, &metadata), before);
+/// ```
+pub fn fingerprint_bank(qbank: &QBank, metas: &QuestionMetas, metadata: &BankMetadata) -> u64
+{
+    let mut hasher = DefaultHasher::new();
+    let header = qbank.get_header();
+    header.get_title().hash(&mut hasher);
+    header.get_name().hash(&mut hasher);
+    header.get_id().hash(&mut hasher);
+    header.get_categories().hash(&mut hasher);
+    header.get_notice().hash(&mut hasher);
+    for question in qbank.get_questions()
+    {
+        question.get_id().hash(&mut hasher);
+        question.get_group().hash(&mut hasher);
+        question.get_category().hash(&mut hasher);
+        question.get_question().hash(&mut hasher);
+        question.get_choices().hash(&mut hasher);
+    }
+    // The metadata types are not `Hash`; their JSON form is, and sorting by
+    // id keeps the result independent of the order of the map.
+    let metas: BTreeMap<_, _> = metas.iter().collect();
+    serde_json::to_string(&metas).unwrap_or_default().hash(&mut hasher);
+    serde_json::to_string(metadata).unwrap_or_default().hash(&mut hasher);
+    hasher.finish()
+}
+
+// pub fn fingerprint_on_disk(bank_path: &Path) -> Option<u64>
+/// Reads a question bank and its sidecars from disk and hashes them with
+/// `fingerprint_bank`.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+///
+/// # Output
+/// The fingerprint, or `None` if the bank cannot be read.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use qrate_gui::fingerprint_on_disk;
+/// assert_eq!(fingerprint_on_disk(Path::new("/definitely/not/here.qbdb")), None);
+/// ```
+pub fn fingerprint_on_disk(bank_path: &Path) -> Option<u64>
+{
+    match LoadFile::read_qbank_from_path(bank_path)
+    {
+        ResultLoadFile::Success(qbank) => {
+            let metadata = load_bank_metadata(bank_path, qbank.get_header());
+            Some(fingerprint_bank(&qbank, &load_question_metas(bank_path), &metadata))
+        },
+        _ => None,
+    }
+}
+
+// pub fn matches_disk(bank_path: &Path, qbank: &QBank, metas: &QuestionMetas, metadata: &BankMetadata) -> bool
+/// Tells whether saving a question bank would write what is already on
+/// disk, by comparing the fingerprint of the bank in memory with that of the
+/// saved files.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+/// * `qbank` - The question bank in memory.
+/// * `metas` - The metadata of its questions.
+/// * `metadata` - The bank metadata.
+///
+/// # Output
+/// `true` if the fingerprints match; `false` if they differ or the bank on
+/// disk cannot be read.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Excel, QBDB, QBank, Question };
+/// use qrate_gui::{ matches_disk, load_bank_metadata, BankMetadata, QuestionMetas };
+///
+/// let dir = std::env::temp_dir();
+/// let bank_path = dir.join("qrate_gui_matches_disk.qb.xlsx");
+/// let _ = std::fs::remove_file(&bank_path);
+/// let _ = std::fs::remove_file(qrate_gui::get_bank_metadata_path(&bank_path));
+/// let mut qbank = QBank::new_with_default();
+/// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+/// let mut excel = Excel::open(dir.join("qrate_gui_matches_disk").to_string_lossy().into_owned()).unwrap();
+/// excel.write_qbank(&qbank).unwrap();
+///
+/// let metadata = load_bank_metadata(&bank_path, qbank.get_header());
+/// assert!(matches_disk(&bank_path, &qbank, &QuestionMetas::new(), &metadata));
+///
+/// let mut edited = metadata.clone();
+/// edited.set_subject("Mathematics".to_string());
+/// assert!(!matches_disk(&bank_path, &qbank, &QuestionMetas::new(), &edited));
+/// assert!(!matches_disk(&dir.join("qrate_gui_not_there.qbdb"), &qbank, &QuestionMetas::new(), &metadata));
+/// ```
+pub fn matches_disk(bank_path: &Path, qbank: &QBank, metas: &QuestionMetas, metadata: &BankMetadata) -> bool
+{
+    fingerprint_on_disk(bank_path) == Some(fingerprint_bank(qbank, metas, metadata))
+}
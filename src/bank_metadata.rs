@@ -0,0 +1,381 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use qrate::{ Excel, Header, QBank, QBDB, SQLiteDB };
+use serde::{ Serialize, Deserialize };
+
+use crate::{ save_question_metas, BankFormat, LoadFile, QuestionMetas };
+
+/// Descriptive details of a question bank, edited on the bank metadata page.
+///
+/// The title and the author are stored in the header of the bank itself, so
+/// the SQLite and Excel writers keep them. The subject and the description
+/// have no place in the header and are kept in a sidecar file next to the
+/// bank, like the question metadata.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct BankMetadata
+{
+    #[serde(default)]
+    title: String,
+
+    #[serde(default)]
+    author: String,
+
+    #[serde(default)]
+    subject: String,
+
+    #[serde(default)]
+    description: String,
+}
+
+impl BankMetadata
+{
+    // pub fn new() -> Self
+    /// Creates metadata with every field empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::BankMetadata;
+    /// assert!(BankMetadata::new().get_title().is_empty());
+    /// ```
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // pub fn from_header(header: &Header) -> Self
+    /// Creates metadata holding the title and the author of `header`.
+    ///
+    /// # Arguments
+    /// * `header` - The header of the bank.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::BankMetadata;
+    /// let metadata = BankMetadata::from_header(&Header::new_with_default());
+    /// assert_eq!(metadata.get_title(), "Examination");
+    /// assert_eq!(metadata.get_author(), "Name");
+    /// assert!(metadata.get_subject().is_empty());
+    /// ```
+    pub fn from_header(header: &Header) -> Self
+    {
+        Self
+        {
+            title: header.get_title().clone(),
+            author: header.get_name().clone(),
+            ..Self::default()
+        }
+    }
+
+    // pub fn apply_to(&self, header: &mut Header)
+    /// Writes the title and the author into `header`, leaving its other
+    /// fields untouched.
+    ///
+    /// # Arguments
+    /// * `header` - The header of the bank.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::BankMetadata;
+    /// let mut metadata = BankMetadata::new();
+    /// metadata.set_title("Algebra I".to_string());
+    /// metadata.set_author("Kim".to_string());
+    /// let mut header = Header::new_with_default();
+    /// metadata.apply_to(&mut header);
+    /// assert_eq!(header.get_title(), "Algebra I");
+    /// assert_eq!(header.get_name(), "Kim");
+    /// assert!(!header.get_categories().is_empty());
+    /// ```
+    pub fn apply_to(&self, header: &mut Header)
+    {
+        header.set_title(self.title.clone());
+        header.set_name(self.author.clone());
+    }
+
+    // pub fn get_title(&self) -> &str
+    /// Returns the title of the bank.
+    ///
+    /// # Examples
+    /// See `BankMetadata::from_header`.
+    pub fn get_title(&self) -> &str
+    {
+        &self.title
+    }
+
+    // pub fn set_title(&mut self, title: String)
+    /// Sets the title of the bank.
+    ///
+    /// # Arguments
+    /// * `title` - The new title.
+    ///
+    /// # Examples
+    /// See `BankMetadata::apply_to`.
+    pub fn set_title(&mut self, title: String)
+    {
+        self.title = title;
+    }
+
+    // pub fn get_author(&self) -> &str
+    /// Returns the author of the bank.
+    ///
+    /// # Examples
+    /// See `BankMetadata::from_header`.
+    pub fn get_author(&self) -> &str
+    {
+        &self.author
+    }
+
+    // pub fn set_author(&mut self, author: String)
+    /// Sets the author of the bank.
+    ///
+    /// # Arguments
+    /// * `author` - The new author.
+    ///
+    /// # Examples
+    /// See `BankMetadata::apply_to`.
+    pub fn set_author(&mut self, author: String)
+    {
+        self.author = author;
+    }
+
+    // pub fn get_subject(&self) -> &str
+    /// Returns the subject the bank covers.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::BankMetadata;
+    /// let mut metadata = BankMetadata::new();
+    /// metadata.set_subject("Mathematics".to_string());
+    /// assert_eq!(metadata.get_subject(), "Mathematics");
+    /// ```
+    pub fn get_subject(&self) -> &str
+    {
+        &self.subject
+    }
+
+    // pub fn set_subject(&mut self, subject: String)
+    /// Sets the subject the bank covers.
+    ///
+    /// # Arguments
+    /// * `subject` - The new subject.
+    ///
+    /// # Examples
+    /// See `BankMetadata::get_subject`.
+    pub fn set_subject(&mut self, subject: String)
+    {
+        self.subject = subject;
+    }
+
+    // pub fn get_description(&self) -> &str
+    /// Returns the free-form description of the bank.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::BankMetadata;
+    /// let mut metadata = BankMetadata::new();
+    /// metadata.set_description("Chapters 1 to 3.".to_string());
+    /// assert_eq!(metadata.get_description(), "Chapters 1 to 3.");
+    /// ```
+    pub fn get_description(&self) -> &str
+    {
+        &self.description
+    }
+
+    // pub fn set_description(&mut self, description: String)
+    /// Sets the free-form description of the bank.
+    ///
+    /// # Arguments
+    /// * `description` - The new description.
+    ///
+    /// # Examples
+    /// See `BankMetadata::get_description`.
+    pub fn set_description(&mut self, description: String)
+    {
+        self.description = description;
+    }
+}
+
+// pub fn get_bank_metadata_path(bank_path: &Path) -> PathBuf
+/// Returns the path of the bank metadata sidecar file for a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+///
+/// # Examples
+/// ```
+/// use std::path::{ Path, PathBuf };
+/// use qrate_gui::get_bank_metadata_path;
+/// assert_eq!(get_bank_metadata_path(Path::new("/banks/math.qbdb")), PathBuf::from("/banks/math.qbdb.bank.json"));
+/// ```
+pub fn get_bank_metadata_path(bank_path: &Path) -> PathBuf
+{
+    let mut name = bank_path.as_os_str().to_owned();
+    name.push(".bank.json");
+    PathBuf::from(name)
+}
+
+// pub fn load_bank_metadata(bank_path: &Path, header: &Header) -> BankMetadata
+/// Loads the metadata of a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+/// * `header` - The header read from the bank, which holds the title and
+///   the author.
+///
+/// # Output
+/// The metadata. The subject and the description are empty if the sidecar
+/// is missing or cannot be parsed.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use qrate::Header;
+/// use qrate_gui::load_bank_metadata;
+/// let metadata = load_bank_metadata(Path::new("/definitely/not/here.qbdb"), &Header::new_with_default());
+/// assert_eq!(metadata.get_title(), "Examination");
+/// assert!(metadata.get_description().is_empty());
+/// ```
+pub fn load_bank_metadata(bank_path: &Path, header: &Header) -> BankMetadata
+{
+    let stored: BankMetadata = fs::read_to_string(get_bank_metadata_path(bank_path))
+                                    .ok()
+                                    .and_then(|json| serde_json::from_str(&json).ok())
+                                    .unwrap_or_default();
+    BankMetadata
+    {
+        subject: stored.subject,
+        description: stored.description,
+        ..BankMetadata::from_header(header)
+    }
+}
+
+// pub fn save_bank(bank_path: &Path, qbank: &QBank, metas: &QuestionMetas, metadata: &BankMetadata) -> Result<(), String>
+/// Saves a whole question bank: its header and questions through the writer
+/// of the bank's format, then the bank metadata and the question metadata
+/// into their sidecars. The bank on disk is replaced, so deleted, moved and
+/// recategorized questions are saved as they are in memory. A `*.qbdb`
+/// bank keeps its questions in id order; a `*.qb.xlsx` bank keeps the
+/// order they have in memory.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file, `*.qbdb` or `*.qb.xlsx`.
+/// * `qbank` - The question bank to write.
+/// * `metas` - The metadata of its questions.
+/// * `metadata` - The bank metadata, applied to the header before writing.
+///
+/// # Output
+/// `Ok(())`, or a description of the failure. On failure the bank on disk
+/// is left as it was.
+///
+/// # Examples
+/// ```
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::{ save_bank, load_bank_metadata, BankMetadata, LoadFile, QuestionMetas, ResultLoadFile };
+///
+/// let mut qbank = QBank::new_with_default();
+/// for id in 1..=3
+///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![("a".to_string(), true)])); }
+/// let mut metadata = BankMetadata::new();
+/// metadata.set_subject("Mathematics".to_string());
+///
+/// let dir = std::env::temp_dir();
+/// for path in [dir.join("qrate_gui_save_bank.qbdb"), dir.join("qrate_gui_save_bank.qb.xlsx")]
+/// {
+///     save_bank(&path, &qbank, &QuestionMetas::new(), &metadata).unwrap();
+///
+///     // Question 2 is deleted and question 3 reworded.
+///     let mut edited = qbank.clone();
+///     let mut questions = vec![qbank.get_questions()[0].clone(), qbank.get_questions()[2].clone()];
+///     questions[1].set_question("Q3, reworded".to_string());
+///     edited.set_questions(questions);
+///     save_bank(&path, &edited, &QuestionMetas::new(), &metadata).unwrap();
+///
+///     let ResultLoadFile::Success(reloaded) = LoadFile::read_qbank_from_path(&path)
+///         else { panic!("the saved bank should reload") };
+///     let ids: Vec<u16> = reloaded.get_questions().iter().map(|q| q.get_id()).collect();
+///     assert_eq!(ids, [1, 3]);
+///     assert_eq!(reloaded.get_questions()[1].get_question(), "Q3, reworded");
+///     assert_eq!(load_bank_metadata(&path, reloaded.get_header()), metadata);
+/// }
+/// assert!(save_bank(&dir.join("qrate_gui_save_bank.txt"), &qbank, &QuestionMetas::new(), &metadata).is_err());
+///
+/// // A SQLite bank named `*.xlsx` loads, and is saved as SQLite again.
+/// let mislabeled = dir.join("qrate_gui_save_bank_sqlite.xlsx");
+/// std::fs::copy(dir.join("qrate_gui_save_bank.qbdb"), &mislabeled).unwrap();
+/// save_bank(&mislabeled, &qbank, &QuestionMetas::new(), &metadata).unwrap();
+/// let ResultLoadFile::Success(reloaded) = LoadFile::read_qbank_from_path(&mislabeled)
+///     else { panic!("the saved bank should reload") };
+/// assert_eq!(reloaded.get_questions().len(), 3);
+///
+/// // A bank without questions is saved with its header.
+/// let empty_path = dir.join("qrate_gui_save_bank_empty.qbdb");
+/// let mut draft = BankMetadata::new();
+/// draft.set_title("Draft".to_string());
+/// save_bank(&empty_path, &QBank::new_with_default(), &QuestionMetas::new(), &draft).unwrap();
+/// let ResultLoadFile::Success(reloaded) = LoadFile::read_qbank_from_path(&empty_path)
+///     else { panic!("the saved bank should reload") };
+/// assert!(reloaded.get_questions().is_empty());
+/// assert_eq!(reloaded.get_header().get_title(), "Draft");
+/// ```
+pub fn save_bank(bank_path: &Path, qbank: &QBank, metas: &QuestionMetas, metadata: &BankMetadata) -> Result<(), String>
+{
+    let mut qbank = qbank.clone();
+    let mut header = qbank.get_header().clone();
+    metadata.apply_to(&mut header);
+    qbank.set_header(header);
+    // A bank is written in the format it was loaded in, even if its
+    // extension claims the other one.
+    let extension = bank_path.extension().and_then(|s| s.to_str()).unwrap_or("");
+    let path_str = bank_path.to_string_lossy().into_owned();
+    let format = LoadFile::sniff_format(bank_path).or_else(|| BankFormat::from_extension(extension));
+    match format
+    {
+        Some(BankFormat::SQLite) => {
+            // SQLite would add the questions to those already in the file, so
+            // the bank is written to a new file that then replaces the old one.
+            let new_path = bank_path.with_extension("saving.qbdb");
+            let _ = fs::remove_file(&new_path);
+            let written = match SQLiteDB::open(new_path.to_string_lossy().into_owned())
+            {
+                // The questions table is created even for a bank without choices
+                // or questions, which could not be loaded again otherwise.
+                // `write_qbank` reports a bank without questions as an error
+                // after writing its header, so only the header is written then.
+                Some(mut db) => db.make_tables(qbank.get_header().get_categories().len() as u8, qbank.get_max_choices().max(1) as u8)
+                                    .and_then(|()| if qbank.get_questions().is_empty()
+                                        { db.write_header(qbank.get_header()) }
+                                    else
+                                        { db.write_qbank(&qbank) }),
+                None => Err("Failed to open QBDB file.".to_string()),
+            };
+            if let Err(e) = written.and_then(|()| fs::rename(&new_path, bank_path).map_err(|e| e.to_string()))
+            {
+                let _ = fs::remove_file(&new_path);
+                return Err(e);
+            }
+        },
+        // A plain `*.xlsx` workbook would not load again.
+        Some(BankFormat::Excel) if !extension.is_empty() && (extension != "xlsx" || path_str.ends_with(".qb.xlsx")) => {
+            // qrate keeps a path that already has the given extension as it is.
+            let mut excel = Excel::open_with_ext(path_str, extension).ok_or("Failed to open Excel file.")?;
+            excel.write_qbank(&qbank)?;
+        },
+        _ => return Err("Unsupported file extension.".to_string()),
+    }
+
+    let json = serde_json::to_string_pretty(metadata).map_err(|e| e.to_string())?;
+    fs::write(get_bank_metadata_path(bank_path), json).map_err(|e| e.to_string())?;
+    save_question_metas(bank_path, metas).map_err(|e| e.to_string())
+}
@@ -0,0 +1,254 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use qrate::QBank;
+
+use crate::{ Category, ExportError, QuestionMetas, MAX_DIFFICULTY };
+
+/// The composition of a question bank: how many questions it holds in
+/// total, per category and per difficulty level, as compiled for a report
+/// on the coverage of the bank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QBankStats
+{
+    total: usize,
+
+    /// In the order of `Category`, `Uncategorized` last.
+    categories: Vec<(Category, usize)>,
+
+    /// Index `0` counts the unrated questions.
+    difficulties: [usize; MAX_DIFFICULTY as usize + 1],
+}
+
+impl QBankStats
+{
+    // pub fn get_total(&self) -> usize
+    /// Returns the number of questions of the bank.
+    ///
+    /// # Examples
+    /// See `bank_stats`.
+    pub fn get_total(&self) -> usize
+    {
+        self.total
+    }
+
+    // pub fn get_categories(&self) -> &[(Category, usize)]
+    /// Returns the categories used by the bank with the number of questions
+    /// filed under each, `Category::Uncategorized` last.
+    ///
+    /// # Examples
+    /// See `bank_stats`.
+    pub fn get_categories(&self) -> &[(Category, usize)]
+    {
+        &self.categories
+    }
+
+    // pub fn get_difficulty_count(&self, level: u8) -> usize
+    /// Returns the number of questions rated with `level`, or unrated ones
+    /// for `0`.
+    ///
+    /// # Output
+    /// The count, or `0` for levels above `MAX_DIFFICULTY`.
+    ///
+    /// # Examples
+    /// See `bank_stats`.
+    pub fn get_difficulty_count(&self, level: u8) -> usize
+    {
+        self.difficulties.get(level as usize).copied().unwrap_or_default()
+    }
+
+    // pub fn to_csv(&self) -> String
+    /// Writes the statistics as CSV with the columns `section`, `name` and
+    /// `questions`: one `total` row, one `category` row per category and
+    /// one `difficulty` row per level, `0` standing for unrated.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, QBank, Question };
+    /// use qrate_gui::{ bank_stats, QuestionMetas };
+    ///
+    /// rust_i18n::set_locale("en");
+    /// let mut header = Header::new_with_default();
+    /// header.set_categories(vec!["Algebra".to_string(), "Geometry, plane".to_string()]);
+    /// let mut qbank = QBank::new_with_header(header);
+    /// for (id, category) in [(1, 1), (2, 2), (3, 1), (4, 0), (5, 1), (6, 2)]
+    ///     { qbank.push_question(Question::new(id, id, category, format!("Q{}", id), vec![])); }
+    ///
+    /// let csv = bank_stats(&qbank, &QuestionMetas::new()).to_csv();
+    /// let lines: Vec<&str> = csv.lines().collect();
+    /// assert_eq!(lines[0], "section,name,questions");
+    /// assert_eq!(lines[1], "total,,6");
+    ///
+    /// // One row per category, with counts adding up to the total.
+    /// let categories: Vec<&str> = lines.iter().copied().filter(|line| line.starts_with("category,")).collect();
+    /// assert_eq!(categories, ["category,Algebra,3", "category,\"Geometry, plane\",2", "category,Uncategorized,1"]);
+    /// let sum: usize = categories.iter().map(|line| line.rsplit(',').next().unwrap().parse::<usize>().unwrap()).sum();
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(lines.last(), Some(&"difficulty,5,0"));
+    /// ```
+    pub fn to_csv(&self) -> String
+    {
+        let mut csv = String::from("section,name,questions\n");
+        csv.push_str(&format!("total,,{}\n", self.total));
+        for (category, count) in self.categories.iter()
+            { csv.push_str(&format!("category,{},{}\n", to_csv_field(&category.get_label()), count)); }
+        for (level, count) in self.difficulties.iter().enumerate()
+            { csv.push_str(&format!("difficulty,{},{}\n", level, count)); }
+        csv
+    }
+
+    // pub fn to_json(&self) -> String
+    /// Writes the statistics as pretty-printed JSON with the fields `total`,
+    /// `categories` and `difficulties`. The uncategorized questions are
+    /// listed under the name `null`, and the unrated ones under level `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ bank_stats, QuestionMeta, QuestionMetas };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=3
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// let mut metas = QuestionMetas::new();
+    /// let mut meta = QuestionMeta::new();
+    /// meta.set_difficulty(4);
+    /// metas.insert(2, meta);
+    ///
+    /// let json: serde_json::Value = serde_json::from_str(&bank_stats(&qbank, &metas).to_json()).unwrap();
+    /// assert_eq!(json["total"], 3);
+    /// assert_eq!(json["categories"][0]["questions"], 3);
+    /// assert_eq!(json["difficulties"][0], serde_json::json!({ "level": 0, "questions": 2 }));
+    /// assert_eq!(json["difficulties"][4]["questions"], 1);
+    /// ```
+    pub fn to_json(&self) -> String
+    {
+        let categories: Vec<serde_json::Value> = self.categories.iter()
+                                                    .map(|(category, count)| {
+                                                        let name = match category
+                                                        {
+                                                            Category::Named(name) => Some(name),
+                                                            Category::Uncategorized => None,
+                                                        };
+                                                        serde_json::json!({ "name": name, "questions": count })
+                                                    })
+                                                    .collect();
+        let difficulties: Vec<serde_json::Value> = self.difficulties.iter()
+                                                        .enumerate()
+                                                        .map(|(level, count)| serde_json::json!({ "level": level, "questions": count }))
+                                                        .collect();
+        let json = serde_json::json!({ "total": self.total, "categories": categories, "difficulties": difficulties });
+        serde_json::to_string_pretty(&json).unwrap_or_default()
+    }
+}
+
+/// Quotes `field` for CSV if it holds a comma, a quote or a line break.
+fn to_csv_field(field: &str) -> String
+{
+    if field.contains([',', '"', '\n', '\r'])
+        { format!("\"{}\"", field.replace('"', "\"\"")) }
+    else
+        { field.to_string() }
+}
+
+// pub fn bank_stats(qbank: &QBank, metas: &QuestionMetas) -> QBankStats
+/// Counts the questions of `qbank` in total, per category and per
+/// difficulty level.
+///
+/// # Arguments
+/// * `qbank` - The question bank.
+/// * `metas` - The question metadata holding the difficulty levels.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, QBank, Question };
+/// use qrate_gui::{ bank_stats, Category, QuestionMeta, QuestionMetas, MAX_DIFFICULTY };
+///
+/// let mut header = Header::new_with_default();
+/// header.set_categories(vec!["Geometry".to_string(), "Algebra".to_string()]);
+/// let mut qbank = QBank::new_with_header(header);
+/// for (id, category) in [(1, 1), (2, 2), (3, 1), (4, 0), (5, 1)]
+///     { qbank.push_question(Question::new(id, id, category, format!("Q{}", id), vec![])); }
+/// let mut metas = QuestionMetas::new();
+/// for (id, level) in [(1, 2), (2, 2), (3, 5)]
+/// {
+///     let mut meta = QuestionMeta::new();
+///     meta.set_difficulty(level);
+///     metas.insert(id, meta);
+/// }
+///
+/// let stats = bank_stats(&qbank, &metas);
+/// assert_eq!(stats.get_total(), 5);
+/// assert_eq!(stats.get_categories(), [
+///     (Category::Named("Algebra".to_string()), 1),
+///     (Category::Named("Geometry".to_string()), 3),
+///     (Category::Uncategorized, 1),
+/// ]);
+/// assert_eq!(stats.get_difficulty_count(2), 2);
+/// assert_eq!(stats.get_difficulty_count(5), 1);
+/// assert_eq!(stats.get_difficulty_count(0), 2);
+/// let levels: usize = (0..=MAX_DIFFICULTY).map(|level| stats.get_difficulty_count(level)).sum();
+/// assert_eq!(levels, stats.get_total());
+/// ```
+pub fn bank_stats(qbank: &QBank, metas: &QuestionMetas) -> QBankStats
+{
+    let mut categories = BTreeMap::new();
+    let mut difficulties = [0; MAX_DIFFICULTY as usize + 1];
+    for question in qbank.get_questions().iter()
+    {
+        *categories.entry(Category::of(qbank.get_header(), question)).or_insert(0) += 1;
+        let level = metas.get(&question.get_id()).map(|meta| meta.get_difficulty()).unwrap_or_default();
+        difficulties[level.min(MAX_DIFFICULTY) as usize] += 1;
+    }
+    QBankStats
+    {
+        total: qbank.get_questions().len(),
+        categories: categories.into_iter().collect(),
+        difficulties,
+    }
+}
+
+// pub fn export_bank_stats(stats: &QBankStats, path: &Path) -> Result<(), ExportError>
+/// Writes `stats` to `path`, as JSON if the path ends in `.json` and as CSV
+/// otherwise.
+///
+/// # Arguments
+/// * `stats` - The statistics from `bank_stats`.
+/// * `path` - The target file path, usually ending in `.csv` or `.json`.
+///
+/// # Output
+/// `Ok(())` on success, or `ExportError::PermissionDenied` or
+/// `ExportError::Write`.
+///
+/// # Examples
+/// ```
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::{ bank_stats, export_bank_stats, QuestionMetas };
+///
+/// let mut qbank = QBank::new_with_default();
+/// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+/// let stats = bank_stats(&qbank, &QuestionMetas::new());
+/// let dir = std::env::temp_dir();
+///
+/// export_bank_stats(&stats, &dir.join("qrate_gui_bank_stats.csv")).unwrap();
+/// assert_eq!(std::fs::read_to_string(dir.join("qrate_gui_bank_stats.csv")).unwrap(), stats.to_csv());
+/// export_bank_stats(&stats, &dir.join("qrate_gui_bank_stats.JSON")).unwrap();
+/// assert_eq!(std::fs::read_to_string(dir.join("qrate_gui_bank_stats.JSON")).unwrap(), stats.to_json());
+/// ```
+pub fn export_bank_stats(stats: &QBankStats, path: &Path) -> Result<(), ExportError>
+{
+    let is_json = path.extension().and_then(|extension| extension.to_str()).is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    let contents = if is_json { stats.to_json() } else { stats.to_csv() };
+    fs::write(path, contents).map_err(|e| ExportError::from_io(&e, path))
+}
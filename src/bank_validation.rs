@@ -0,0 +1,176 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::HashSet;
+
+use qrate::QBank;
+
+use crate::{ QuestionKind, QuestionMetas, MAX_DIFFICULTY };
+
+/// One problem of a question bank that should be fixed before exams are
+/// drawn from it, such as it comes from a carelessly imported spreadsheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationIssue
+{
+    /// The question has no prompt, or only blanks.
+    EmptyPrompt(u16),
+
+    /// None of the choices of the question is marked correct, so it cannot
+    /// be graded.
+    MissingAnswer(u16),
+
+    /// The difficulty stored for the question is above `MAX_DIFFICULTY`.
+    DifficultyOutOfRange { id: u16, difficulty: u8 },
+
+    /// Another question earlier in the bank has the same id.
+    DuplicateId(u16),
+}
+
+impl ValidationIssue
+{
+    // pub fn get_id(&self) -> u16
+    /// Returns the id of the question the issue was found in.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ValidationIssue;
+    /// assert_eq!(ValidationIssue::MissingAnswer(4).get_id(), 4);
+    /// assert_eq!(ValidationIssue::DifficultyOutOfRange { id: 7, difficulty: 9 }.get_id(), 7);
+    /// ```
+    pub fn get_id(&self) -> u16
+    {
+        match self
+        {
+            ValidationIssue::EmptyPrompt(id)
+            | ValidationIssue::MissingAnswer(id)
+            | ValidationIssue::DifficultyOutOfRange { id, .. }
+            | ValidationIssue::DuplicateId(id) => *id,
+        }
+    }
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the key of the description of the issue in the locale files.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ValidationIssue;
+    /// assert_eq!(ValidationIssue::EmptyPrompt(1).get_key(), "issue-empty-prompt");
+    /// assert_eq!(ValidationIssue::DuplicateId(1).get_key(), "issue-duplicate-id");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            ValidationIssue::EmptyPrompt(_) => "issue-empty-prompt",
+            ValidationIssue::MissingAnswer(_) => "issue-missing-answer",
+            ValidationIssue::DifficultyOutOfRange { .. } => "issue-difficulty-out-of-range",
+            ValidationIssue::DuplicateId(_) => "issue-duplicate-id",
+        }
+    }
+}
+
+// pub fn validate_qbank(qbank: &QBank) -> Vec<ValidationIssue>
+/// Checks every question of `qbank` for an empty prompt, a missing answer
+/// and an id used before. Every question is taken to be graded by its
+/// choices; use `validate_qbank_with_metas` to check the difficulties and
+/// leave out the questions answered in writing.
+///
+/// # Arguments
+/// * `qbank` - The question bank to check.
+///
+/// # Output
+/// The issues in bank order, the issues of one question in the order of
+/// `ValidationIssue`. A clean bank yields none.
+///
+/// # Examples
+/// ```
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::{ validate_qbank, ValidationIssue };
+///
+/// let answer = || vec![("4".to_string(), true), ("5".to_string(), false)];
+/// let mut qbank = QBank::new_with_default();
+/// qbank.push_question(Question::new(1, 1, 1, "What is 2 + 2?".to_string(), answer()));
+/// qbank.push_question(Question::new(2, 2, 1, "What is 3 + 1?".to_string(), answer()));
+/// assert!(validate_qbank(&qbank).is_empty());
+///
+/// // An empty prompt, a question without a correct choice, a question
+/// // without any choices and a reused id.
+/// qbank.push_question(Question::new(3, 3, 1, "  ".to_string(), answer()));
+/// qbank.push_question(Question::new(4, 4, 1, "Pick one".to_string(), vec![("a".to_string(), false)]));
+/// qbank.push_question(Question::new(5, 5, 1, "Explain".to_string(), vec![]));
+/// qbank.push_question(Question::new(2, 6, 1, "".to_string(), answer()));
+/// assert_eq!(validate_qbank(&qbank), [
+///     ValidationIssue::EmptyPrompt(3),
+///     ValidationIssue::MissingAnswer(4),
+///     ValidationIssue::MissingAnswer(5),
+///     ValidationIssue::EmptyPrompt(2),
+///     ValidationIssue::DuplicateId(2),
+/// ]);
+/// assert!(validate_qbank(&QBank::new_with_default()).is_empty());
+/// ```
+pub fn validate_qbank(qbank: &QBank) -> Vec<ValidationIssue>
+{
+    validate_qbank_with_metas(qbank, &QuestionMetas::new())
+}
+
+// pub fn validate_qbank_with_metas(qbank: &QBank, metas: &QuestionMetas) -> Vec<ValidationIssue>
+/// Checks `qbank` like `validate_qbank`, and also the difficulties stored
+/// in `metas`, which a hand-edited metadata file may hold above
+/// `MAX_DIFFICULTY`. Questions of the kind `QuestionKind::MultiLine` are
+/// answered in writing and may have no correct choice.
+///
+/// # Arguments
+/// * `qbank` - The question bank to check.
+/// * `metas` - The question metadata of the bank.
+///
+/// # Output
+/// The issues, as in `validate_qbank`.
+///
+/// # Examples
+/// ```
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::{ validate_qbank_with_metas, QuestionKind, QuestionMeta, QuestionMetas, ValidationIssue };
+///
+/// let mut qbank = QBank::new_with_default();
+/// qbank.push_question(Question::new(1, 1, 1, "Describe the water cycle.".to_string(), vec![]));
+/// qbank.push_question(Question::new(2, 2, 1, "Is water wet?".to_string(), vec![("Yes".to_string(), true)]));
+/// let mut metas = QuestionMetas::new();
+/// let mut essay = QuestionMeta::new();
+/// essay.set_kind(QuestionKind::MultiLine);
+/// metas.insert(1, essay);
+/// let mut rated = QuestionMeta::new();
+/// rated.set_difficulty(5);
+/// metas.insert(2, rated);
+/// assert!(validate_qbank_with_metas(&qbank, &metas).is_empty());
+///
+/// // A difficulty of 9 can only come from an edited metadata file.
+/// metas.insert(2, serde_json::from_str(r#"{ "difficulty": 9 }"#).unwrap());
+/// assert_eq!(validate_qbank_with_metas(&qbank, &metas), [ValidationIssue::DifficultyOutOfRange { id: 2, difficulty: 9 }]);
+/// ```
+pub fn validate_qbank_with_metas(qbank: &QBank, metas: &QuestionMetas) -> Vec<ValidationIssue>
+{
+    let mut seen = HashSet::new();
+    let mut issues = Vec::new();
+    for question in qbank.get_questions().iter()
+    {
+        let id = question.get_id();
+        let meta = metas.get(&id);
+        if question.get_question().trim().is_empty()
+            { issues.push(ValidationIssue::EmptyPrompt(id)); }
+        let written = meta.is_some_and(|meta| meta.get_kind() == QuestionKind::MultiLine);
+        if !written && !question.get_choices().iter().any(|(_, correct)| *correct)
+            { issues.push(ValidationIssue::MissingAnswer(id)); }
+        if let Some(difficulty) = meta.map(|meta| meta.get_difficulty()).filter(|&difficulty| difficulty > MAX_DIFFICULTY)
+            { issues.push(ValidationIssue::DifficultyOutOfRange { id, difficulty }); }
+        if !seen.insert(id)
+            { issues.push(ValidationIssue::DuplicateId(id)); }
+    }
+    issues
+}
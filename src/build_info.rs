@@ -0,0 +1,71 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use crate::format_timestamp;
+
+/// The version of the package, from `Cargo.toml`.
+pub const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Shown in place of build details the build script could not provide.
+pub const UNKNOWN_BUILD_FIELD: &str = "unknown";
+
+// pub fn get_git_commit() -> &'static str
+/// Returns the short hash of the git commit the program was built from.
+///
+/// # Output
+/// The hash, or `UNKNOWN_BUILD_FIELD` if the build script did not run
+/// inside a git checkout.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::get_git_commit;
+/// assert!(!get_git_commit().is_empty());
+/// ```
+pub fn get_git_commit() -> &'static str
+{
+    option_env!("QRATE_GIT_COMMIT").unwrap_or(UNKNOWN_BUILD_FIELD)
+}
+
+// pub fn get_build_date() -> String
+/// Returns when the program was built, as a UTC date and time.
+///
+/// # Output
+/// The date, or `UNKNOWN_BUILD_FIELD` if the build script did not run.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ get_build_date, UNKNOWN_BUILD_FIELD };
+/// let date = get_build_date();
+/// assert!(date == UNKNOWN_BUILD_FIELD || date.ends_with(" UTC"));
+/// ```
+pub fn get_build_date() -> String
+{
+    option_env!("QRATE_BUILD_TIMESTAMP")
+        .and_then(|seconds| seconds.parse::<u64>().ok())
+        .map(format_timestamp)
+        .unwrap_or_else(|| UNKNOWN_BUILD_FIELD.to_string())
+}
+
+// pub fn about_text() -> String
+/// Returns the build details shown on the software info page and meant to
+/// be pasted into bug reports.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ about_text, get_git_commit, PACKAGE_VERSION };
+/// let about = about_text();
+/// assert!(about.starts_with(&format!("qrate-gui {}", PACKAGE_VERSION)));
+/// assert!(about.contains(&format!("commit {}", get_git_commit())));
+/// assert!(about.contains("built "));
+/// ```
+pub fn about_text() -> String
+{
+    format!("{} {} (commit {}, built {})", env!("CARGO_PKG_NAME"), PACKAGE_VERSION, get_git_commit(), get_build_date())
+}
@@ -0,0 +1,76 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::BTreeSet;
+
+use qrate::QBank;
+
+/// The thresholds offered on the questions page.
+pub const DELETE_CONFIRM_THRESHOLDS: [usize; 5] = [2, 5, 10, 20, 50];
+
+/// The number of questions deleted at once from which confirmation is
+/// asked, unless the user picks another threshold.
+pub const DEFAULT_DELETE_CONFIRM_THRESHOLD: usize = 5;
+
+// pub fn needs_delete_confirmation(count: usize, threshold: usize) -> bool
+/// Returns whether deleting `count` questions at once should be confirmed
+/// first, so that routine cleanups go through directly while mass deletes
+/// do not.
+///
+/// # Arguments
+/// * `count` - The number of questions to delete.
+/// * `threshold` - The smallest count that is confirmed.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::needs_delete_confirmation;
+/// assert!(!needs_delete_confirmation(1, 5));
+/// assert!(!needs_delete_confirmation(4, 5));
+/// assert!(needs_delete_confirmation(5, 5));
+/// assert!(needs_delete_confirmation(200, 5));
+/// ```
+pub fn needs_delete_confirmation(count: usize, threshold: usize) -> bool
+{
+    count >= threshold
+}
+
+// pub fn remove_questions(qbank: &mut QBank, ids: &BTreeSet<u16>) -> usize
+/// Removes the questions with the given ids from `qbank`, keeping the
+/// order of the others.
+///
+/// # Arguments
+/// * `qbank` - The bank to remove the questions from.
+/// * `ids` - The ids of the questions to remove.
+///
+/// # Output
+/// The number of questions removed; ids not in the bank are ignored.
+///
+/// # Examples
+/// ```
+/// use std::collections::BTreeSet;
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::remove_questions;
+///
+/// let mut qbank = QBank::new_with_default();
+/// for id in 1..=4
+///     { qbank.push_question(Question::new(id, 1, 1, format!("Q{}", id), vec![])); }
+/// assert_eq!(remove_questions(&mut qbank, &BTreeSet::from([2, 3, 9])), 2);
+/// let ids: Vec<u16> = qbank.get_questions().iter().map(|q| q.get_id()).collect();
+/// assert_eq!(ids, vec![1, 4]);
+/// ```
+pub fn remove_questions(qbank: &mut QBank, ids: &BTreeSet<u16>) -> usize
+{
+    let mut questions = qbank.get_questions().clone();
+    let before = questions.len();
+    questions.retain(|question| !ids.contains(&question.get_id()));
+    let removed = before - questions.len();
+    qbank.set_questions(questions);
+    removed
+}
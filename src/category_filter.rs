@@ -0,0 +1,156 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::BTreeSet;
+
+use qrate::{ Header, QBank, Question };
+use rust_i18n::t;
+
+use crate::locale_sort_by_key;
+
+/// The category a question is filed under, as offered on the criteria page.
+///
+/// Named categories sort alphabetically, and `Uncategorized` sorts after
+/// all of them.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Category
+{
+    /// A category listed in the header of the bank.
+    Named(String),
+
+    /// The question refers to no category of the header, or to a blank one.
+    Uncategorized,
+}
+
+impl Category
+{
+    // pub fn of(header: &Header, question: &Question) -> Self
+    /// Returns the category of `question` according to `header`.
+    ///
+    /// # Arguments
+    /// * `header` - The header holding the category names of the bank.
+    /// * `question` - The question whose 1-based category is looked up.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::Category;
+    ///
+    /// let mut header = Header::new_empty();
+    /// header.set_categories(vec!["Algebra".to_string(), " ".to_string()]);
+    /// let category = |c| Category::of(&header, &Question::new(1, 1, c, "Q".to_string(), vec![]));
+    /// assert_eq!(category(1), Category::Named("Algebra".to_string()));
+    /// assert_eq!(category(2), Category::Uncategorized);
+    /// assert_eq!(category(0), Category::Uncategorized);
+    /// assert_eq!(category(9), Category::Uncategorized);
+    /// ```
+    pub fn of(header: &Header, question: &Question) -> Self
+    {
+        match header.get_category(question.get_category()).map(|name| name.trim())
+        {
+            Some(name) if !name.is_empty() => Category::Named(name.to_string()),
+            _ => Category::Uncategorized,
+        }
+    }
+
+    // pub fn get_label(&self) -> String
+    /// Returns the text shown next to the checkbox of the category.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::Category;
+    /// assert_eq!(Category::Named("Algebra".to_string()).get_label(), "Algebra");
+    /// ```
+    pub fn get_label(&self) -> String
+    {
+        match self
+        {
+            Category::Named(name) => name.clone(),
+            Category::Uncategorized => t!("uncategorized").to_string(),
+        }
+    }
+}
+
+// pub fn distinct_categories(qbank: &QBank) -> Vec<Category>
+/// Collects the categories actually used by the questions of `qbank`.
+///
+/// # Arguments
+/// * `qbank` - The question bank.
+///
+/// # Output
+/// The categories, sorted and without duplicates. `Category::Uncategorized`
+/// is listed last if any question has no category.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, QBank, Question };
+/// use qrate_gui::{ distinct_categories, Category };
+///
+/// let mut header = Header::new_empty();
+/// header.set_categories(vec!["Geometry".to_string(), "Algebra".to_string(), "Unused".to_string()]);
+/// let mut qbank = QBank::new_with_header(header);
+/// for (id, category) in [(1, 1), (2, 2), (3, 1), (4, 0), (5, 2)]
+///     { qbank.push_question(Question::new(id, id, category, format!("Q{}", id), vec![])); }
+///
+/// assert_eq!(distinct_categories(&qbank), vec![
+///     Category::Named("Algebra".to_string()),
+///     Category::Named("Geometry".to_string()),
+///     Category::Uncategorized,
+/// ]);
+/// assert!(distinct_categories(&QBank::new_empty()).is_empty());
+/// ```
+pub fn distinct_categories(qbank: &QBank) -> Vec<Category>
+{
+    qbank.get_questions().iter()
+        .map(|question| Category::of(qbank.get_header(), question))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+// pub fn sort_categories(categories: &mut [Category], locale: &str)
+/// Sorts `categories` for display in the alphabetical order of `locale`,
+/// such as 가나다 order for Korean, instead of the code point order of
+/// `distinct_categories`. `Category::Uncategorized` stays last. Korean
+/// lists Hangul names before Latin ones.
+///
+/// # Arguments
+/// * `categories` - The categories to sort.
+/// * `locale` - The locale whose order to use. See `locale_sort`.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, QBank, Question };
+/// use qrate_gui::{ distinct_categories, sort_categories, Category };
+///
+/// let mut header = Header::new_with_default();
+/// header.set_categories(vec!["함수".to_string(), "기하".to_string(), "Algebra".to_string(), "도형".to_string()]);
+/// let mut qbank = QBank::new_with_header(header);
+/// for (id, category) in [(1, 1), (2, 2), (3, 0), (4, 3), (5, 4)]
+///     { qbank.push_question(Question::new(id, id, category, format!("Q{}", id), vec![])); }
+///
+/// let mut categories = distinct_categories(&qbank);
+/// sort_categories(&mut categories, "ko");
+/// let named = |name: &str| Category::Named(name.to_string());
+/// assert_eq!(categories, vec![named("기하"), named("도형"), named("함수"), named("Algebra"), Category::Uncategorized]);
+///
+/// sort_categories(&mut categories, "en");
+/// assert_eq!(categories[0], named("Algebra"));
+/// assert_eq!(categories[4], Category::Uncategorized);
+/// ```
+pub fn sort_categories(categories: &mut [Category], locale: &str)
+{
+    locale_sort_by_key(categories, locale, |category| match category
+    {
+        Category::Named(name) => name,
+        Category::Uncategorized => "",
+    });
+    categories.sort_by_key(|category| *category == Category::Uncategorized);
+}
@@ -0,0 +1,158 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use qrate::QBank;
+
+/// Describes why a category could not be renamed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CategoryRenameError
+{
+    /// The new name is blank.
+    EmptyName,
+
+    /// No category of the bank has the contained name.
+    NotFound(String),
+
+    /// Another category already has the contained name; renaming would
+    /// merge the two.
+    Collision(String),
+}
+
+impl CategoryRenameError
+{
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key describing this error.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::CategoryRenameError;
+    /// assert_eq!(CategoryRenameError::Collision("Algebra".to_string()).get_key(), "error-category-exists");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            CategoryRenameError::EmptyName => "error-empty-category-name",
+            CategoryRenameError::NotFound(_) => "error-category-not-found",
+            CategoryRenameError::Collision(_) => "error-category-exists",
+        }
+    }
+}
+
+// pub fn rename_category(qbank: &mut QBank, from: &str, to: &str, merge: bool) -> Result<usize, CategoryRenameError>
+/// Renames the category `from` to `to` for every question filed under it.
+///
+/// The questions refer to the categories listed in the header of the bank,
+/// so renaming the header entry moves all of them at once. If `to` is the
+/// name of another category, the two are merged when `merge` is set: the
+/// questions of `from` move to `to` and `from` is removed from the header.
+/// Names are compared without surrounding whitespace.
+///
+/// # Arguments
+/// * `qbank` - The bank to change.
+/// * `from` - The current name of the category.
+/// * `to` - The new name.
+/// * `merge` - Whether a collision with another category merges the two
+///   instead of failing.
+///
+/// # Output
+/// The number of questions whose category changed, or a
+/// `CategoryRenameError` if nothing was changed.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, QBank, Question };
+/// use qrate_gui::{ distinct_categories, rename_category, Category, CategoryRenameError };
+///
+/// let mut header = Header::new_empty();
+/// header.set_categories(vec!["Ch3".to_string(), "Algebra".to_string(), "Trig".to_string()]);
+/// let mut qbank = QBank::new_with_header(header);
+/// for (id, category) in [(1, 1), (2, 2), (3, 1), (4, 3), (5, 0)]
+///     { qbank.push_question(Question::new(id, id, category, format!("Q{}", id), vec![])); }
+/// let category_of = |qbank: &QBank, id: usize| Category::of(qbank.get_header(), &qbank.get_questions()[id - 1]);
+///
+/// // Renaming moves every question of the category.
+/// assert_eq!(rename_category(&mut qbank, "Ch3", " Trigonometry ", false), Ok(2));
+/// assert_eq!(category_of(&qbank, 1), Category::Named("Trigonometry".to_string()));
+/// assert_eq!(category_of(&qbank, 3), Category::Named("Trigonometry".to_string()));
+/// assert_eq!(category_of(&qbank, 2), Category::Named("Algebra".to_string()));
+///
+/// // A name in use is refused unless the categories are merged.
+/// assert_eq!(rename_category(&mut qbank, "Trig", "Trigonometry", false),
+///            Err(CategoryRenameError::Collision("Trigonometry".to_string())));
+/// assert_eq!(rename_category(&mut qbank, "Trig", "Trigonometry", true), Ok(1));
+/// for id in [1, 3, 4]
+///     { assert_eq!(category_of(&qbank, id), Category::Named("Trigonometry".to_string())); }
+/// assert_eq!(category_of(&qbank, 2), Category::Named("Algebra".to_string()));
+/// assert_eq!(category_of(&qbank, 5), Category::Uncategorized);
+/// assert_eq!(qbank.get_header().get_categories(), &vec!["Trigonometry".to_string(), "Algebra".to_string()]);
+/// assert_eq!(distinct_categories(&qbank).len(), 3);
+///
+/// // Saved and loaded again, every question keeps its category.
+/// use qrate_gui::{ save_bank, BankMetadata, LoadFile, QuestionMetas, ResultLoadFile };
+/// let dir = std::env::temp_dir();
+/// for path in [dir.join("qrate_gui_rename_category.qbdb"), dir.join("qrate_gui_rename_category.qb.xlsx")]
+/// {
+///     save_bank(&path, &qbank, &QuestionMetas::new(), &BankMetadata::from_header(qbank.get_header())).unwrap();
+///     let ResultLoadFile::Success(saved) = LoadFile::read_qbank_from_path(&path)
+///         else { panic!("the saved bank should reload") };
+///     for id in 1..=5
+///         { assert_eq!(category_of(&saved, id), category_of(&qbank, id)); }
+///     assert_eq!(category_of(&saved, 4), Category::Named("Trigonometry".to_string()));
+/// }
+///
+/// assert_eq!(rename_category(&mut qbank, "Ch3", "Geometry", false), Err(CategoryRenameError::NotFound("Ch3".to_string())));
+/// assert_eq!(rename_category(&mut qbank, "Algebra", " ", false), Err(CategoryRenameError::EmptyName));
+/// ```
+pub fn rename_category(qbank: &mut QBank, from: &str, to: &str, merge: bool) -> Result<usize, CategoryRenameError>
+{
+    let (from, to) = (from.trim(), to.trim());
+    if to.is_empty()
+        { return Err(CategoryRenameError::EmptyName); }
+    let mut header = qbank.get_header().clone();
+    let mut categories = header.get_categories().clone();
+    // The 1-based positions of the header entries, as the questions refer to them.
+    let positions = |name: &str| -> Vec<usize> {
+        (1..=categories.len()).filter(|&position| categories[position - 1].trim() == name).collect()
+    };
+    let sources = positions(from);
+    if sources.is_empty()
+        { return Err(CategoryRenameError::NotFound(from.to_string())); }
+    let target = if from == to { None } else { positions(to).first().copied() };
+    let mut questions = qbank.get_questions().clone();
+    let moved = questions.iter().filter(|question| sources.contains(&(question.get_category() as usize))).count();
+
+    match target
+    {
+        None => {
+            for &position in sources.iter()
+                { categories[position - 1] = to.to_string(); }
+        },
+        Some(_) if !merge => return Err(CategoryRenameError::Collision(to.to_string())),
+        Some(target) => {
+            // Removing the merged entries shifts the positions after them.
+            let new_position = |position: usize| position - sources.iter().filter(|&&source| source < position).count();
+            for question in questions.iter_mut()
+            {
+                let position = question.get_category() as usize;
+                if sources.contains(&position)
+                    { question.set_category(new_position(target) as u8); }
+                else if (1..=categories.len()).contains(&position)
+                    { question.set_category(new_position(position) as u8); }
+            }
+            for &position in sources.iter().rev()
+                { categories.remove(position - 1); }
+            qbank.set_questions(questions);
+        },
+    }
+    header.set_categories(categories);
+    qbank.set_header(header);
+    Ok(moved)
+}
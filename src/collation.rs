@@ -0,0 +1,80 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use icu_collator::{ Collator, CollatorOptions };
+use icu_provider::DataLocale;
+
+// pub fn locale_sort<T: AsRef<str>>(items: &mut [T], locale: &str)
+/// Sorts `items` in the alphabetical order of `locale`, such as 가나다 order
+/// for Korean and the order of the Russian alphabet for Cyrillic, rather
+/// than in the order of their code points.
+///
+/// # Arguments
+/// * `items` - The strings to sort.
+/// * `locale` - The locale whose order to use, such as `"ko"` or `"ru-RU"`.
+///   A locale that cannot be parsed sorts by code point.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::locale_sort;
+///
+/// let mut korean = vec!["하늘", "다람쥐", "가방", "나무", "까치"];
+/// locale_sort(&mut korean, "ko");
+/// assert_eq!(korean, ["가방", "까치", "나무", "다람쥐", "하늘"]);
+///
+/// // By code point, ё comes after я and capitals before all small letters.
+/// let mut russian = vec!["ёж", "яблоко", "Жук", "арбуз", "волк"];
+/// locale_sort(&mut russian, "ru");
+/// assert_eq!(russian, ["арбуз", "волк", "ёж", "Жук", "яблоко"]);
+///
+/// let mut english = vec!["banana", "Cherry", "apple"];
+/// locale_sort(&mut english, "not a locale!");
+/// assert_eq!(english, ["Cherry", "apple", "banana"]);
+/// locale_sort(&mut english, "en");
+/// assert_eq!(english, ["apple", "banana", "Cherry"]);
+/// ```
+pub fn locale_sort<T: AsRef<str>>(items: &mut [T], locale: &str)
+{
+    locale_sort_by_key(items, locale, |item| item.as_ref());
+}
+
+// pub fn locale_sort_by_key<T>(items: &mut [T], locale: &str, key: impl Fn(&T) -> &str)
+/// Sorts `items` by the string `key` returns for each, in the alphabetical
+/// order of `locale`. The sort is stable. See `locale_sort`.
+///
+/// # Arguments
+/// * `items` - The items to sort.
+/// * `locale` - The locale whose order to use.
+/// * `key` - Returns the string to sort an item by.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::locale_sort_by_key;
+///
+/// let mut students = vec![("20260003", "최민수"), ("20260001", "김하나"), ("20260002", "박서준")];
+/// locale_sort_by_key(&mut students, "ko-KR", |student| student.1);
+/// assert_eq!(students, [("20260001", "김하나"), ("20260002", "박서준"), ("20260003", "최민수")]);
+/// ```
+pub fn locale_sort_by_key<T>(items: &mut [T], locale: &str, key: impl Fn(&T) -> &str)
+{
+    match get_collator(locale)
+    {
+        Some(collator) => items.sort_by(|a, b| collator.compare(key(a), key(b))),
+        None => items.sort_by(|a, b| key(a).cmp(key(b))),
+    }
+}
+
+/// Returns the collator of `locale`, or `None` if the locale cannot be
+/// parsed or has no collation data.
+fn get_collator(locale: &str) -> Option<Collator>
+{
+    let locale: DataLocale = locale.parse().ok()?;
+    Collator::try_new(&locale, CollatorOptions::new()).ok()
+}
@@ -8,17 +8,209 @@
 ///////////////////////////////////////////////////////////////////////////////
 
 
-use std::path::PathBuf;
+use std::path::{ Path, PathBuf };
+use std::collections::{ BTreeSet, HashMap };
+use std::time::SystemTime;
 
-use qrate::{ QBank, SBank };
-use iced::{ application, Element, Task, Length, Theme, Color, Padding };
-use iced::widget::{ column, row, center, text, button, container, stack };
+use qrate::{ QBank, Question, SBank };
+use iced::{ Border, Element, Task, Subscription, Length, Theme, Color, Padding, Font };
+use iced::time::{ self, Duration, Instant };
+use iced::theme::Mode;
+use iced::keyboard::{ self, key::Named, Key };
+use iced::widget::{ column, row, center, text, button, container, stack, radio, scrollable, text_input, text_editor, image, checkbox, pick_list, progress_bar, tooltip };
+use iced::widget::operation::RelativeOffset;
 use rust_i18n::t;
 use include_dir::{ include_dir, Dir };
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::{ LoadFile, ResultLoadFile };
+use crate::{ LoadFile, ResultLoadFile, ResultLoadSBank, SaveFile, ResultSaveFile, SelfCheckError };
+use crate::{ default_question_count, parse_sections, Exam, ExamCriteria, ExamInfo, ExportError, ExportFormat, NumberingStyle, PageTemplate, PaperSize, QuestionOrder, MAX_ANSWER_SPACE_LINES, MAX_CHOICES_PER_ROW, PAGE_MARGINS, PAGE_PLACEHOLDERS, QUESTION_PERCENTS };
+use crate::{ StudentForm, add_student, update_student, delete_student };
+use crate::{ find_duplicate_students, resolve_duplicate_students, DuplicateKey, DuplicateResolution, DuplicateStudents };
+use crate::{ ImageRef, QuestionMetas, load_question_metas, save_question_metas };
+use crate::primary_font;
+use crate::{ generate_student_papers, generate_variants_with_metas, ExamVariant, ExportBatch, VariantReport };
+use crate::{ format_timestamp, get_summary_path, save_summary, GenerationSummary };
+use crate::{ LearningSession, GradingMode, QuestionKind, QuestionMeta, AnswerInput };
+use crate::{ discard_learning_progress, load_learning_progress, save_learning_progress, LearningProgress };
+use crate::{ NumericTolerance, ABSOLUTE_TOLERANCES, PERCENT_TOLERANCES };
+use crate::{ item_analysis, load_attempts, save_attempts, to_attempt, Attempt, ItemStats };
+use crate::{ load_usage_history, save_usage_history, UsageHistory, MAX_AVOID_RECENT };
+use crate::Toasts;
+use crate::{ localized_menu_button, MenuButton, get_containing_folder, open_containing_folder };
+use crate::{ QuestionFilter, SortColumn, SortOrder, MAX_DIFFICULTY };
+use crate::{ validate_qbank_with_metas, ValidationIssue };
+use crate::bank_stats;
+use crate::ExportSource;
+use crate::locale_sort_by_key;
+use crate::{ clamp_question_number, page_count, page_slice, DEFAULT_PAGE_SIZE, PAGE_SIZES };
+use crate::{ truncate_prompt, PROMPT_LENGTHS };
+use crate::question_clipboard_text;
+use crate::{ difficulty_summary, BalanceReport, BALANCE_ATTEMPTS };
+use crate::{ needs_delete_confirmation, remove_questions, DELETE_CONFIRM_THRESHOLDS };
+use crate::{ TagFilter, TagMode };
+use crate::parse_pins;
+use crate::{ distinct_categories, rename_category, sort_categories, Category, CategoryRenameError };
+use crate::{ generate_quick, GenerateError };
+use crate::Workspace;
+use crate::{ format_grade, GradeDisplay, LetterScale };
+use crate::{ load_settings, save_settings, AppSettings };
+use crate::{ is_idle, IDLE_LOCK_MINUTES };
+use crate::AUTOSAVE_MINUTES;
+use crate::{ ThemeChoice, THEME_POLL_SECONDS };
+use crate::{ BrowsePurpose, BrowserEntry, FileBrowser, FileDialogKind, SaveTarget };
+use crate::{ export_anki_deck, export_bank_stats, export_item_analysis, export_study_guide_to_pdf, resolve_export_path };
+use crate::Page;
+use crate::{ load_bank_metadata, save_bank, BankMetadata };
+use crate::{ fingerprint_bank, matches_disk };
+use crate::{ about_text, load_sample_bank };
+use crate::{ locales_from_file_names, next_locale };
+use crate::{ diagnostics_text, log_event };
+use crate::{ cycle_focus, get_focus_ring_color, with_focus_ring, ModalControl, FOCUS_RING_WIDTH };
+use crate::{ get_submenu_keys, get_tab_order, FocusStop, MENU_KEYS, STATUS_BAR_FOCUS };
+use crate::{ menu_bar_labels, submenu_labels, AccessibleLabel, AccessibleRole };
+use crate::tab_order::{ BANK_AUTHOR_INPUT, BANK_DESCRIPTION_INPUT, BANK_SUBJECT_INPUT, BANK_TITLE_INPUT, CATEGORY_NAME_INPUT, EXAM_COURSE_INPUT, EXAM_DATE_INPUT, EXAM_INSTRUCTIONS_INPUT,
+                        EXAM_TITLE_INPUT, IDLE_LOCK_PIN_INPUT, LEARNING_ANSWER_INPUT, LETTER_SCALE_INPUT, PAGE_FOOTER_INPUT, PAGE_HEADER_INPUT, PAGE_LOGO_INPUT, PINNED_QUESTIONS_INPUT, QUESTION_COUNT_INPUT, SECTIONS_INPUT, QUESTION_JUMP_INPUT, QUESTION_SEARCH_INPUT, SEED_INPUT, TAG_FILTER_INPUT, VARIANT_COUNT_INPUT };
+use crate::modal::{ confirm, modal, ConfirmDialog };
 
-static LOCALES_DIR: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/assets/locales");
+pub(crate) static LOCALES_DIR: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/assets/locales");
+
+/// The widget ids of the student form inputs, which the focus trap of the
+/// form moves the keyboard focus to.
+const STUDENT_NAME_INPUT: &str = "student-name";
+const STUDENT_ID_INPUT: &str = "student-id";
+const STUDENT_GROUP_INPUT: &str = "student-group";
+const FILE_NAME_INPUT: &str = "file-name";
+const MODAL_BUTTON_FOCUS: &str = "modal-button";
+const QUESTION_LIST_SCROLL: &str = "question-list";
+
+/// A question bank waiting to replace a bank with unsaved changes.
+#[derive(Debug, Clone)]
+enum PendingLoad
+{
+    File(PathBuf),
+    Sample,
+    /// No bank at all, going back to the empty state.
+    Unload,
+}
+
+/// An entry of the exam language list on the language page.
+#[derive(Debug, Clone, PartialEq)]
+struct ExportLanguage
+{
+    name: String,
+    locale: Option<String>,
+}
+
+impl std::fmt::Display for ExportLanguage
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// An entry of the answer-space list of a question kind on the criteria
+/// page; `None` follows the number of lines chosen for all questions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct KindAnswerSpace
+{
+    lines: Option<usize>,
+}
+
+impl std::fmt::Display for KindAnswerSpace
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self.lines
+        {
+            Some(lines) => write!(f, "{}", lines),
+            None => write!(f, "{}", t!("same-as-all-questions")),
+        }
+    }
+}
+
+/// An entry of the list of how many recent exams the questions are kept off
+/// the new exam for; `0` turns the avoidance off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AvoidRecentEntry
+{
+    exams: usize,
+}
+
+impl std::fmt::Display for AvoidRecentEntry
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self.exams
+        {
+            0 => write!(f, "{}", t!("avoid-recent-off")),
+            exams => write!(f, "{}", t!("avoid-recent-exams", count = exams)),
+        }
+    }
+}
+
+/// An entry of the category list above the questions table.
+#[derive(Debug, Clone, PartialEq)]
+struct CategoryFilterEntry
+{
+    category: Category,
+}
+
+impl std::fmt::Display for CategoryFilterEntry
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{}", self.category.get_label())
+    }
+}
+
+/// An entry of the idle period list on the idle lock page.
+#[derive(Debug, Clone, PartialEq)]
+struct IdleLockPeriod
+{
+    minutes: Option<u64>,
+}
+
+impl std::fmt::Display for IdleLockPeriod
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self.minutes
+        {
+            Some(minutes) => write!(f, "{}", t!("idle-lock-after", minutes = minutes)),
+            None => write!(f, "{}", t!("idle-lock-off")),
+        }
+    }
+}
+
+/// An entry of the autosave interval list on the storage page.
+#[derive(Debug, Clone, PartialEq)]
+struct AutosavePeriod
+{
+    minutes: Option<u64>,
+}
+
+impl std::fmt::Display for AutosavePeriod
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self.minutes
+        {
+            Some(minutes) => write!(f, "{}", t!("autosave-every", minutes = minutes)),
+            None => write!(f, "{}", t!("autosave-off")),
+        }
+    }
+}
+
+/// An imported roster whose duplicate students wait to be resolved.
+#[derive(Debug, Clone)]
+struct PendingRoster
+{
+    sbank: SBank,
+    groups: HashMap<String, String>,
+    duplicates: Vec<DuplicateStudents>,
+}
 
 /// Defines the messages sent to the `ControlTower`'s `update` function.
 ///
@@ -47,8 +239,567 @@ pub enum Message
     SetLocale(String),
     
     /// Triggered to navigate to a different page within the application.
-    /// The `String` contains the identifier for the target page.
-    GoToPage(String),
+    /// The `Page` is the target page.
+    GoToPage(Page),
+
+    /// Triggered when the user picks a numbering style on the criteria page.
+    SetNumberingStyle(NumberingStyle),
+
+    /// Triggered when the user picks how many answer choices are printed on
+    /// one line; remembered for the current locale.
+    SetChoicesPerRow(usize),
+
+    /// Sets how many blank lines exported exams leave after each question.
+    SetAnswerSpaceLines(usize),
+
+    /// Sets how many blank lines exported exams leave after the questions
+    /// of a kind, or `None` to use the number for all questions.
+    SetKindAnswerSpace(QuestionKind, Option<usize>),
+
+    /// Sets the header template printed at the top of every page of
+    /// exported PDF exams.
+    SetPageHeader(String),
+
+    /// Sets the footer template printed at the bottom of every page of
+    /// exported PDF exams.
+    SetPageFooter(String),
+
+    /// Sets the path of the logo printed above the page header; a blank
+    /// path prints no logo.
+    SetPageLogo(String),
+
+    /// Sets the paper exported PDF documents are laid out for.
+    SetPaperSize(PaperSize),
+
+    /// Sets the margin of the pages of exported PDF documents, in
+    /// millimetres.
+    SetPageMargin(u8),
+
+    /// Sets the locale the labels of exported exams are printed in; `None`
+    /// follows the interface.
+    SetExportLocale(Option<String>),
+
+    /// Triggered when the user toggles shuffling of the answer choices.
+    SetShuffleChoices(bool),
+
+    /// Triggered when the user picks whether the drawn questions are
+    /// shuffled together or grouped by category.
+    SetQuestionOrder(QuestionOrder),
+
+    /// Moves the category at the 0-based position `from` of the category
+    /// order to `to`, changing where its questions come in exams grouped
+    /// by category.
+    MoveCategory { from: usize, to: usize },
+
+    /// Triggered when the user toggles the answer key at the end of
+    /// exported exams.
+    SetAnswerKey(bool),
+
+    /// Triggered when the user toggles the QR code printed on exported
+    /// papers.
+    SetPrintPaperCode(bool),
+
+    /// Sets the seed the next exams are drawn with; `None` draws each
+    /// exam with a new random seed.
+    SetSeed(Option<u64>),
+
+    /// Triggered when the user asks to export the previewed exam.
+    ExportExam(ExportFormat),
+
+    /// Triggered when an exam export has finished, been cancelled, or failed.
+    ExamExported(ResultSaveFile),
+
+    /// Triggered when the path of an exam export has been chosen. `None`
+    /// means the dialog was cancelled. An existing file is only overwritten
+    /// once the user confirms it.
+    ExportPathChosen(ExportFormat, Option<PathBuf>),
+
+    /// Overwrites the existing file the pending export was about to replace.
+    ConfirmOverwrite,
+
+    /// Drops the pending export and keeps the existing file.
+    CancelOverwrite,
+
+    /// Discards the unsaved edits and loads the bank that was waiting for
+    /// confirmation.
+    ConfirmReplaceBank,
+
+    /// Keeps the current bank and its unsaved edits.
+    CancelReplaceBank,
+
+    /// Opens the question banks of the last session again, the first in
+    /// this window and, in a `Workspace`, each other one in a new window.
+    RestoreSessionBanks,
+
+    /// Declines to open the question banks of the last session.
+    DismissSessionBanks,
+
+    /// Continues the self-study session left unfinished in the loaded bank.
+    ResumeLearning,
+
+    /// Deletes the self-study session left unfinished in the loaded bank.
+    DiscardLearningProgress,
+
+    /// Closes the offer to resume self-study, keeping the session for later.
+    DismissLearningProgress,
+
+    /// Imports the roster that was waiting for its duplicate students to be
+    /// resolved the given way.
+    ResolveDuplicateStudents(DuplicateResolution),
+
+    /// Drops the imported roster and keeps the current student list.
+    CancelRosterImport,
+
+    /// Toggles the "don't ask again this session" checkbox of the overwrite
+    /// confirmation.
+    SetDontAskOverwrite(bool),
+
+    /// Triggered when the number of variants field on the criteria page changes.
+    SetVariantCount(String),
+
+    /// Generates the requested number of exam variants and exports them
+    /// into a folder chosen by the user.
+    ExportVariants(ExportFormat),
+
+    /// Generates one paper per student of the loaded list and exports them
+    /// into a folder chosen by the user.
+    ExportStudentPapers(ExportFormat),
+
+    /// Chooses between papers pre-filled with the name and id of each
+    /// student and anonymous papers with blank fields.
+    SetNamedPapers(bool),
+
+    /// Triggered when the folder of a batch export has been chosen. `None`
+    /// means the folder dialog was cancelled.
+    ExportBatchReady(Option<ExportBatch>),
+
+    /// Reports that `done` of `total` papers of the running batch export
+    /// have been written; the next one is written when it is handled.
+    ExportProgress(usize, usize),
+
+    /// Asks for a path and saves the summary of the last batch export there.
+    SaveSummary,
+
+    /// Triggered when the summary has been saved. `None` means the dialog
+    /// was cancelled or writing failed.
+    SummarySaved(Option<PathBuf>),
+
+    /// Triggered when an Anki deck of the loaded bank has been written.
+    /// `None` means the dialog was cancelled or writing failed.
+    AnkiDeckExported(Option<PathBuf>),
+
+    /// Triggered when a study guide of the loaded bank has been written.
+    /// `None` means the dialog was cancelled or writing failed.
+    StudyGuideExported(Option<PathBuf>),
+
+    /// Triggered when an item-analysis report of the loaded bank has been
+    /// written. `None` means the dialog was cancelled or writing failed.
+    ItemAnalysisExported(Option<PathBuf>),
+
+    /// Triggered when the statistics of the loaded bank have been written.
+    /// `None` means the dialog was cancelled or writing failed.
+    BankStatsExported(Option<PathBuf>),
+
+    /// Triggered when the questions chosen by the export source have been
+    /// written as a new bank, the dialog was cancelled or writing failed.
+    QuestionsExported(ResultSaveFile),
+
+    /// Opens the student form: `None` to add a student, or `Some(index)`
+    /// to edit the student at that 0-based index.
+    OpenStudentForm(Option<usize>),
+
+    /// Triggered when the name field of the student form changes.
+    StudentNameChanged(String),
+
+    /// Triggered when the id field of the student form changes.
+    StudentIdChanged(String),
+
+    /// Triggered when the group field of the student form changes.
+    StudentGroupChanged(String),
+
+    /// Validates the student form and applies it to the `SBank`.
+    SubmitStudentForm,
+
+    /// Closes the student form without applying it.
+    CancelStudentForm,
+
+    /// Removes the student at the given 0-based index from the `SBank`.
+    DeleteStudent(usize),
+
+    /// Opens a file dialog to pick an image for the question with the given id.
+    PickQuestionImage(u16),
+
+    /// Triggered when an image has been picked for the question with the
+    /// given id. An empty path means the dialog was cancelled.
+    QuestionImagePicked(u16, PathBuf),
+
+    /// Detaches the image from the question with the given id.
+    RemoveQuestionImage(u16),
+
+    /// Sets the answer format of the question with the given id.
+    SetQuestionKind(u16, QuestionKind),
+
+    /// Sets how multiple-select questions are scored in self-study.
+    SetGradingMode(GradingMode),
+
+    /// Produced every second while a learning session is running.
+    LearningTick(Instant),
+
+    /// Moves the learning session to the question at the given 0-based index.
+    LearningGoTo(usize),
+
+    /// Selects or deselects the choice at the given 0-based index of the
+    /// current learning question.
+    ToggleLearningChoice(usize),
+
+    /// Triggered when the one-line answer of the current learning question is edited.
+    SetLearningAnswer(String),
+
+    /// Triggered when the multi-line answer of the current learning question is edited.
+    LearningEditorAction(text_editor::Action),
+
+    /// Flags the learning question at the given 0-based index for review,
+    /// or removes its flag.
+    ToggleFlag(usize),
+
+    /// Hands in the learning session and shows the grade report.
+    FinishLearning,
+
+    /// Moves the keyboard focus to the next stop of the Tab order (Tab).
+    FocusNextMenu,
+
+    /// Moves the keyboard focus to the previous stop of the Tab order
+    /// (Shift+Tab).
+    FocusPreviousMenu,
+
+    /// Opens the main menu button holding the keyboard focus (Enter or Space).
+    ActivateFocusedMenu,
+
+    /// Opens the folder of the selected file in the file manager.
+    OpenContainingFolder,
+
+    /// Triggered when the exam title field changes.
+    ExamTitleChanged(String),
+
+    /// Triggered when the course field of the exam header changes.
+    ExamCourseChanged(String),
+
+    /// Triggered when the date field of the exam header changes.
+    ExamDateChanged(String),
+
+    /// Triggered when the instructions field of the exam header changes.
+    ExamInstructionsChanged(String),
+
+    /// Occurs when the user picks a roster file; an empty path means the
+    /// dialog was cancelled.
+    RosterFileSelected(PathBuf),
+
+    /// Triggered when a student roster has been loaded from a file.
+    SBankLoaded(ResultLoadSBank),
+
+    /// Sorts the questions table by the given column, or flips the order
+    /// when it is already sorted by that column.
+    SortQuestions(SortColumn),
+
+    /// Triggered when the search text above the questions table changes.
+    SetQuestionSearch(String),
+
+    /// Shows only the questions of a category in the questions table.
+    SetQuestionCategoryFilter(Option<Category>),
+
+    /// Shows only the questions of a difficulty level in the questions table.
+    SetQuestionDifficultyFilter(Option<u8>),
+
+    /// Resets the search, category, difficulty and sort of the questions
+    /// table at once.
+    ClearQuestionFilters,
+
+    /// Shows the given 0-based page of the questions table.
+    GoToQuestionPage(usize),
+
+    /// Edits the number typed into the "go to" box of the questions table
+    /// or the learning page.
+    SetJumpText(String),
+
+    /// Goes to the question whose number is typed into the "go to" box:
+    /// in the questions table the row labelled with that id, or the shown
+    /// row with the nearest id; in the learning mode the question at that
+    /// position, the first or the last one if it is out of range.
+    JumpToQuestion,
+
+    /// Moves the keyboard focus to the "go to" box (Ctrl+J).
+    FocusJumpBox,
+
+    /// Sets how many questions the questions table shows per page.
+    SetQuestionPageSize(usize),
+
+    /// Sets how many characters of each prompt the questions table shows.
+    SetPromptLength(usize),
+
+    /// Sets the difficulty level of the question with the given id.
+    SetQuestionDifficulty(u16, u8),
+
+    /// Moves the question at the 0-based position `from` of the bank to
+    /// `to`, changing the default order of exams that are not shuffled.
+    MoveQuestion { from: usize, to: usize },
+
+    /// Picks or unpicks the question with the given id in the questions
+    /// table, for an exam of hand-picked questions.
+    SelectQuestion(u16, bool),
+
+    /// Unpicks every question in the questions table.
+    ClearQuestionSelection,
+
+    /// Creates an exam of exactly the picked questions, ignoring the
+    /// extraction criteria, and opens its preview.
+    GenerateFromSelection,
+
+    /// Sets which questions are written when the bank is exported.
+    SetExportSource(ExportSource),
+
+    /// Writes the questions chosen by the export source as a new bank.
+    ExportQuestions,
+
+    /// Deletes the picked questions from the bank, asking first when there
+    /// are at least as many as the confirmation threshold.
+    DeleteSelectedQuestions,
+
+    /// Triggered when the user confirms deleting the picked questions.
+    ConfirmDeleteQuestions,
+
+    /// Triggered when the user keeps the picked questions after all.
+    CancelDeleteQuestions,
+
+    /// Sets the number of questions deleted at once from which the deletion
+    /// is confirmed first.
+    SetDeleteConfirmThreshold(usize),
+
+    /// Loads the bundled sample question bank, offered on the main page
+    /// while no bank is loaded.
+    LoadSampleBank,
+
+    /// Closes the loaded question bank and student list, going back to the
+    /// empty state after asking if there are unsaved changes.
+    UnloadBank,
+
+    /// Replaces the difficulty of every question with enough graded
+    /// attempts by the level suggested from its success rate.
+    RecalibrateDifficulty,
+
+    /// A background task failed unexpectedly; the message is shown in an
+    /// error dialog.
+    TaskFailed(String),
+
+    /// Closes the error dialog.
+    DismissError,
+
+    /// Closes the error dialog if it is open, otherwise the top toast;
+    /// sent by Esc.
+    DismissNotice,
+
+    /// Closes a toast, `0` being the top one.
+    DismissToast(usize),
+
+    /// Removes the toasts that have been shown long enough.
+    ToastTick(Instant),
+
+    /// Sets whether the last page and question bank are restored on launch.
+    SetRestoreLastPage(bool),
+
+    /// Sets whether hover highlights and live updates are left out.
+    SetReduceMotion(bool),
+
+    /// Sets whether a submenu stays open after one of its items is chosen.
+    SetKeepMenuOpen(bool),
+
+    /// Sets the theme, or lets it follow the operating system.
+    SetTheme(ThemeChoice),
+
+    /// Sets whether files are opened with the native dialog or the in-app
+    /// file browser.
+    SetFileDialog(FileDialogKind),
+
+    /// Shows the given directory in the in-app file browser.
+    BrowseDirectory(PathBuf),
+
+    /// Shows the parent of the directory in the in-app file browser.
+    BrowseParent,
+
+    /// Picks the given file or folder in the in-app file browser.
+    BrowsePick(PathBuf),
+
+    /// Sets the name of the file the in-app file browser saves to.
+    SetBrowserFileName(String),
+
+    /// Triggered when the in-app file browser has picked where to write the
+    /// given target. An existing file is only overwritten once the user
+    /// confirms it.
+    SavePathChosen(SaveTarget, PathBuf),
+
+    /// Closes the in-app file browser without picking anything.
+    CancelFileBrowser,
+
+    /// Triggered when the operating system reports its light or dark
+    /// appearance, at startup, on a change or when polled.
+    SystemThemeChanged(Mode),
+
+    /// Asks the operating system for its appearance again, for systems
+    /// that do not report changes by themselves.
+    PollSystemTheme(Instant),
+
+    /// Checks whether the user has been away long enough for the screen
+    /// to lock.
+    IdleTick(Instant),
+
+    /// Sets the minutes of inactivity after which the screen locks, or
+    /// turns the lock off.
+    SetIdleLockMinutes(Option<u64>),
+
+    /// Saves the question bank if it has unsaved changes.
+    AutosaveTick(Instant),
+
+    /// Sets the minutes between automatic saves of the question bank, or
+    /// turns autosave off.
+    SetAutosaveMinutes(Option<u64>),
+
+    /// Edits the PIN typed on the lock screen or on the idle lock page.
+    PinTextChanged(String),
+
+    /// Makes the typed PIN the one that unlocks the screen.
+    SetIdleLockPin,
+
+    /// Returns to the page the screen locked on if the typed PIN is right.
+    Unlock,
+
+    /// Opens the folder dialog for the storage path.
+    PickStoragePath,
+
+    /// Triggered when the folder dialog for the storage path closes; `None`
+    /// means it was cancelled.
+    StoragePathChosen(Option<PathBuf>),
+
+    /// Forgets the storage path, so that file dialogs start in the folder
+    /// of the last question bank again.
+    ClearStoragePath,
+
+    /// Triggered when the comma-separated tags of the question with the
+    /// given id are edited.
+    SetQuestionTags(u16, String),
+
+    /// Triggered when the private note of the question with the given id
+    /// is edited.
+    SetQuestionNote(u16, String),
+
+    /// Triggered when the comma-separated tag filter of the criteria changes.
+    SetTagFilterText(String),
+
+    /// Sets whether the tag filter requires all or any of its tags.
+    SetTagMode(TagMode),
+
+    /// Triggered when the pinned questions of the criteria, typed as
+    /// `id@position`, change.
+    SetPinnedText(String),
+
+    /// Triggered when the sections of the criteria, typed as
+    /// `start-end:count`, change.
+    SetSectionsText(String),
+
+    /// Triggered when the number of questions of the exam changes.
+    SetQuestionCount(String),
+
+    /// Sets the share of a freshly loaded bank drawn into an exam.
+    SetQuestionPercent(u8),
+
+    /// Includes or excludes the questions of a category from the exam.
+    SetCategoryIncluded(Category, bool),
+
+    /// Sets the lowest difficulty level of the questions drawn.
+    SetMinDifficulty(u8),
+
+    /// Sets the highest difficulty level of the questions drawn.
+    SetMaxDifficulty(u8),
+
+    /// Draws questions regardless of their difficulty again.
+    ClearDifficultyRange,
+
+    /// Sets the weight of a difficulty level in the wanted difficulty mix.
+    SetDifficultyWeight(u8, u8),
+
+    /// Takes the difficulty mix the draw happens to give again.
+    ClearDifficultyTarget,
+
+    /// Sets how many draws are made to match the wanted difficulty mix.
+    SetBalanceAttempts(usize),
+
+    /// Keeps the questions of the given number of last exported exams off
+    /// the new exam; `0` allows them again.
+    SetAvoidRecent(usize),
+
+    /// Generates an exam with default criteria and previews it (Ctrl+G).
+    QuickExam,
+
+    /// Copies the recent events of the diagnostics page to the clipboard.
+    CopyDiagnostics,
+
+    /// Copies the prompt and answer of the question with the given id to
+    /// the clipboard as plain text.
+    CopyQuestion(u16),
+
+    /// Opens the questions table at the question with the given id, with
+    /// the filters cleared so that it is shown.
+    ShowQuestion(u16),
+
+    /// Switches the interface to the next available locale, wrapping
+    /// around after the last one (Ctrl+L).
+    CycleLocale,
+
+    /// Opens another window with its own state, handled by the `Workspace`.
+    OpenNewWindow,
+
+    /// Triggered when the title field of the bank metadata page changes.
+    SetBankTitle(String),
+
+    /// Triggered when the author field of the bank metadata page changes.
+    SetBankAuthor(String),
+
+    /// Triggered when the subject field of the bank metadata page changes.
+    SetBankSubject(String),
+
+    /// Triggered when the description field of the bank metadata page changes.
+    SetBankDescription(String),
+
+    /// Writes the loaded bank, its questions and its metadata back to its
+    /// file and sidecars.
+    SaveBankMetadata,
+
+    /// Picks the category to rename on the bank metadata page.
+    SelectCategoryToRename(String),
+
+    /// Triggered when the new name of the category to rename changes.
+    SetCategoryNewName(String),
+
+    /// Renames the picked category for every question filed under it.
+    RenameCategory,
+
+    /// Merges the renamed category into the existing one of the same name.
+    ConfirmMergeCategory,
+
+    /// Leaves both categories as they are.
+    CancelMergeCategory,
+
+    /// Sets how grades are shown in the grade report.
+    SetGradeDisplay(GradeDisplay),
+
+    /// Triggered when the letter scale field of the grading criteria page
+    /// changes; the scale is applied once the text parses.
+    SetLetterScaleText(String),
+
+    /// Sets the largest difference accepted for typed numbers.
+    SetAbsoluteTolerance(f64),
+
+    /// Sets the largest difference accepted for typed numbers, as a
+    /// percentage of the correct number.
+    SetPercentTolerance(f64),
 }
 
 /// Manages the state and UI logic for the `qrate-gui` application.
@@ -63,20 +814,92 @@ pub struct ControlTower
     qbank: QBank,
     sbank: SBank,
     selected_file_path: PathBuf,
+    /// The bank file in use when a load started, restored if it fails.
+    previous_file_path: Option<PathBuf>,
     current_menu_key: String,
     menu_font_size_in_pixel: f32,
     current_locale: String,
-    current_page: String,
+    current_page: Page,
+    exam_criteria: ExamCriteria,
+    exam: Option<Exam>,
+    balance_report: Option<BalanceReport>,
+    student_groups: HashMap<String, String>,
+    student_form: Option<StudentForm>,
+    dirty: bool,
+    question_metas: QuestionMetas,
+    bank_metadata: BankMetadata,
+    bank_changed: bool,
+    autosave_paused: bool,
+    saved_bank: Option<(QBank, BankMetadata)>,
+    saved_fingerprint: Option<u64>,
+    roster_edited: bool,
+    variant_count: usize,
+    named_papers: bool,
+    variant_reports: Vec<VariantReport>,
+    export_batch: Option<ExportBatch>,
+    export_progress: Option<(usize, usize)>,
+    generation_summary: Option<GenerationSummary>,
+    learning: Option<LearningSession>,
+    answer_editor: text_editor::Content,
+    grading_mode: GradingMode,
+    focus_stop: Option<usize>,
+    modal_focus: Option<usize>,
+    question_filter: QuestionFilter,
+    question_page: usize,
+    question_page_size: usize,
+    jump_text: String,
+    highlighted_question: Option<u16>,
+    selected_questions: BTreeSet<u16>,
+    export_source: ExportSource,
+    attempts: Vec<Attempt>,
+    usage_history: UsageHistory,
+    error_message: Option<String>,
+    toasts: Toasts,
+    pending_overwrite: Option<(PathBuf, SaveTarget)>,
+    pending_batch: Option<(ExportBatch, Vec<PathBuf>)>,
+    pending_load: Option<PendingLoad>,
+    pending_delete: Option<BTreeSet<u16>>,
+    pending_merge: Option<(String, String)>,
+    pending_roster: Option<PendingRoster>,
+    session_banks: Vec<PathBuf>,
+    pending_resume: Option<LearningProgress>,
+    file_browser: Option<FileBrowser>,
+    difficulty_range_swapped: bool,
+    last_activity: Instant,
+    system_theme: Mode,
+    locked_page: Option<Page>,
+    pin_text: String,
+    pin_rejected: bool,
+    font_failures: Vec<PathBuf>,
+    dont_ask_overwrite: bool,
+    skip_overwrite_confirm: bool,
+    settings: AppSettings,
+    settings_path: Option<PathBuf>,
+    tag_filter_text: String,
+    pinned_text: String,
+    sections_text: String,
+    question_count_pending: bool,
+    categories: Vec<Category>,
+    category_to_rename: Option<String>,
+    category_new_name: String,
+    letter_scale_text: String,
+    tag_drafts: HashMap<u16, String>,
 }
 
 impl ControlTower
 {
+    /// The narrowest the submenu gets, even for short labels.
+    pub const SUBMENU_MIN_WIDTH: f32 = 220.0;
+
+    /// The widest the submenu gets; longer labels wrap.
+    pub const SUBMENU_MAX_WIDTH: f32 = 480.0;
+
     // pub fn run() -> iced::Result
     /// Runs the `ControlTower` application using the `iced` framework.
     ///
-    /// This function initializes the application with `ControlTower::new`,
-    /// defines its update logic with `ControlTower::update`, and specifies
-    /// its view with `ControlTower::view`.
+    /// The application runs as a `Workspace`, whose main window is a
+    /// `ControlTower` restored from the settings file; more windows, each
+    /// with its own `ControlTower`, can be opened from the menu.
     /// It then executes the application,
     /// taking over the main thread until the application exits.
     ///
@@ -86,7 +909,7 @@ impl ControlTower
     ///
     /// # Examples
     /// ```no_run
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     ///
     /// fn main() -> iced::Result
     /// {
@@ -95,10 +918,7 @@ impl ControlTower
     /// ```
     pub fn run() -> iced::Result
     {
-        // To prevent lifetime errors, .title() and .theme() have been removed.
-        // Only the basic form of application().run() remains.
-        application(ControlTower::new, ControlTower::update, ControlTower::view)
-        .run()
+        Workspace::run()
     }
 
     // pub fn new() -> (Self, Task<Message>)
@@ -110,18 +930,18 @@ impl ControlTower
     /// # Examples
     /// ```
     /// use iced::Task;
-    /// use crate::control_tower::{ControlTower, Message};
+    /// use qrate_gui::{ ControlTower, Message };
     /// use std::path::PathBuf;
     ///
     /// let (control_tower, task) = ControlTower::new();
-    /// assert!(control_tower.get_qbank().is_empty());
+    /// assert!(control_tower.get_qbank().get_questions().is_empty());
     /// assert!(control_tower.get_sbank().is_empty());
     /// assert_eq!(control_tower.get_selected_file_path(), &PathBuf::new());
     /// assert_eq!(control_tower.get_current_menu_key(), "");
     /// assert_eq!(control_tower.get_menu_font_size_in_pixel(), 24.0);
     /// assert_eq!(control_tower.get_current_locale(), "en");
     /// assert_eq!(control_tower.get_current_page(), "main");
-    /// assert!(task.is_none());
+    /// assert_eq!(task.units(), 0);
     /// ```
     pub fn new() -> (Self, Task<Message>)
     {
@@ -132,51 +952,307 @@ impl ControlTower
                 qbank: QBank::new_empty(),
                 sbank: SBank::new(),
                 selected_file_path: PathBuf::new(),
+                previous_file_path: None,
                 current_menu_key: String::new(),
                 menu_font_size_in_pixel: 24.0,
                 current_locale: "en".to_string(), // Initialize current_locale field
-                current_page: "main".to_string(), // Initialize current_page field
+                current_page: Page::Main, // Initialize current_page field
+                exam_criteria: ExamCriteria::new(),
+                exam: None,
+                balance_report: None,
+                student_groups: HashMap::new(),
+                student_form: None,
+                dirty: false,
+                question_metas: QuestionMetas::new(),
+                bank_metadata: BankMetadata::new(),
+                bank_changed: false,
+                autosave_paused: false,
+                saved_bank: None,
+                saved_fingerprint: None,
+                roster_edited: false,
+                variant_count: 4,
+                named_papers: true,
+                variant_reports: Vec::new(),
+                export_batch: None,
+                export_progress: None,
+                generation_summary: None,
+                learning: None,
+                answer_editor: text_editor::Content::new(),
+                grading_mode: GradingMode::default(),
+                focus_stop: None,
+                modal_focus: None,
+                question_filter: QuestionFilter::new(),
+                question_page: 0,
+                question_page_size: DEFAULT_PAGE_SIZE,
+                jump_text: String::new(),
+                highlighted_question: None,
+                selected_questions: BTreeSet::new(),
+                export_source: ExportSource::default(),
+                attempts: Vec::new(),
+                usage_history: UsageHistory::new(),
+                error_message: None,
+                toasts: Toasts::new(),
+                pending_overwrite: None,
+                pending_batch: None,
+                pending_load: None,
+                pending_delete: None,
+                pending_merge: None,
+                session_banks: Vec::new(),
+                pending_resume: None,
+                file_browser: None,
+                difficulty_range_swapped: false,
+                last_activity: Instant::now(),
+                system_theme: Mode::None,
+                locked_page: None,
+                pin_text: String::new(),
+                pin_rejected: false,
+                pending_roster: None,
+                font_failures: Vec::new(),
+                dont_ask_overwrite: false,
+                skip_overwrite_confirm: false,
+                settings: AppSettings::new(),
+                settings_path: None,
+                tag_filter_text: String::new(),
+                pinned_text: String::new(),
+                sections_text: String::new(),
+                question_count_pending: false,
+                categories: Vec::new(),
+                category_to_rename: None,
+                category_new_name: String::new(),
+                letter_scale_text: LetterScale::default().to_text(),
+                tag_drafts: HashMap::new(),
             },
             Task::none(),
         )
     }
 
-    // pub fn get_qbank(&self) -> &QBank
-    /// Returns a reference to the question bank.
+    // pub fn new_with_settings_file(path: PathBuf) -> (Self, Task<Message>)
+    /// Creates a new instance of [ControlTower] with the settings stored in
+    /// `path`, which is also where later changes to them are saved.
+    ///
+    /// If restoring is enabled, the last question bank is loaded again and
+    /// the last page is opened, unless it was transient such as an exam in
+    /// progress. The other question banks of the last session that still
+    /// exist are offered to be opened again.
+    ///
+    /// # Arguments
+    /// * `path` - The settings file; it need not exist yet.
     ///
     /// # Output
-    /// A reference to the `QBank` instance.
+    /// A tuple containing the new [ControlTower] instance and the [iced::Task]
+    /// loading the restored question bank, if any.
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
-    /// let (control_tower, _) = ControlTower::new();
-    /// let qbank = control_tower.get_qbank();
-    /// assert!(qbank.is_empty());
+    /// use qrate_gui::{ save_settings, AppSettings, ControlTower };
+    ///
+    /// let path = std::env::temp_dir().join("qrate_gui_restore_settings.json");
+    /// let mut settings = AppSettings::new();
+    /// settings.set_last_page("question-bank-edit".to_string());
+    ///
+    /// // With the setting off, the application starts on the main page.
+    /// save_settings(&path, &settings).unwrap();
+    /// let (control_tower, _) = ControlTower::new_with_settings_file(path.clone());
+    /// assert_eq!(control_tower.get_current_page(), "main");
+    ///
+    /// // With the setting on, the saved page is restored.
+    /// settings.set_restore_last_page(true);
+    /// save_settings(&path, &settings).unwrap();
+    /// let (control_tower, _) = ControlTower::new_with_settings_file(path);
+    /// assert_eq!(control_tower.get_current_page(), "question-bank-edit");
     /// ```
-    pub fn get_qbank(&self) -> &QBank
+    pub fn new_with_settings_file(path: PathBuf) -> (Self, Task<Message>)
     {
-        &self.qbank
+        Self::new_with_launch_file(path, None)
     }
 
-    // pub fn set_qbank(&mut self, qbank: QBank)
-    /// Sets the question bank to a new value.
+    // pub fn new_with_launch_file(path: PathBuf, launch_file: Option<PathBuf>) -> (Self, Task<Message>)
+    /// Creates a new instance of [ControlTower] like `new_with_settings_file`,
+    /// but opens `launch_file`, given on the command line, instead of the
+    /// last question bank.
     ///
     /// # Arguments
-    /// * `qbank` - The `QBank` instance to set.
+    /// * `path` - The settings file; it need not exist yet.
+    /// * `launch_file` - The question bank to open, if any.
+    ///
+    /// # Output
+    /// A tuple containing the new [ControlTower] instance and the [iced::Task]
+    /// selecting the launch file. A launch file that does not exist is
+    /// reported as an error instead.
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
-    /// use qrate::QBank;
-    /// let (mut control_tower, _) = ControlTower::new();
-    /// let new_qbank = QBank::new_empty();
-    /// control_tower.set_qbank(new_qbank);
-    /// assert!(control_tower.get_qbank().is_empty());
+    /// use qrate_gui::ControlTower;
+    ///
+    /// let path = std::env::temp_dir().join("qrate_gui_launch_settings.json");
+    /// let bank = std::env::temp_dir().join("qrate_gui_launch_file.qbdb");
+    /// std::fs::write(&bank, b"").unwrap();
+    /// let (_, plain) = ControlTower::new_with_launch_file(path.clone(), None);
+    ///
+    /// // A file that exists is loaded on startup.
+    /// let (control_tower, task) = ControlTower::new_with_launch_file(path.clone(), Some(bank.clone()));
+    /// assert_eq!(control_tower.get_selected_file_path(), &bank);
+    /// assert_eq!(task.units(), plain.units() + 1);
+    /// assert!(control_tower.get_error_message().is_none());
+    ///
+    /// // One that does not is reported, and nothing is loaded.
+    /// let missing = std::env::temp_dir().join("qrate_gui_launch_missing.qbdb");
+    /// let (control_tower, task) = ControlTower::new_with_launch_file(path, Some(missing));
+    /// assert!(control_tower.get_selected_file_path().as_os_str().is_empty());
+    /// assert_eq!(task.units(), plain.units());
+    /// assert!(control_tower.get_error_message().unwrap().contains("qrate_gui_launch_missing.qbdb"));
+    /// ```
+    pub fn new_with_launch_file(path: PathBuf, launch_file: Option<PathBuf>) -> (Self, Task<Message>)
+    {
+        let (mut control_tower, _) = Self::new();
+        let settings = load_settings(&path);
+        control_tower.current_page = Page::from_id(settings.get_startup_page());
+        let mut missing_file = None;
+        let task = match (launch_file, settings.get_last_file())
+        {
+            (Some(file), _) if file.is_file() => {
+                control_tower.selected_file_path = file.clone();
+                control_tower.previous_file_path = Some(PathBuf::new());
+                Task::done(Message::FileSelected(file))
+            },
+            (Some(file), _) => {
+                missing_file = Some(file);
+                Task::none()
+            },
+            (None, Some(file)) if settings.get_restore_last_page() && file.exists() => {
+                control_tower.selected_file_path = file.to_path_buf();
+                control_tower.previous_file_path = Some(PathBuf::new());
+                LoadFile::perform_load_qbank_task(file.to_path_buf())
+            },
+            _ => Task::none(),
+        };
+        control_tower.session_banks = settings.get_restorable_banks().into_iter()
+                                        .filter(|bank| *bank != control_tower.selected_file_path)
+                                        .collect();
+        control_tower.letter_scale_text = settings.get_letter_scale().to_text();
+        control_tower.settings = settings;
+        control_tower.settings_path = Some(path);
+        control_tower.restore_locale();
+        // The error is shown in the restored locale.
+        if let Some(file) = missing_file
+            { let _ = control_tower.show_error(t!("launch-file-not-found", path = file.display()).to_string()); }
+        (control_tower, Task::batch([ task, iced::system::theme().map(Message::SystemThemeChanged) ]))
+    }
+
+    // pub fn new_secondary(path: PathBuf) -> Self
+    /// Creates the state of a secondary window, which shares the settings
+    /// stored in `path` but starts on the main page without a bank.
+    ///
+    /// # Arguments
+    /// * `path` - The settings file shared with the other windows.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ save_settings, AppSettings, ControlTower };
+    ///
+    /// let path = std::env::temp_dir().join("qrate_gui_secondary_settings.json");
+    /// let mut settings = AppSettings::new();
+    /// settings.set_restore_last_page(true);
+    /// settings.set_last_page("question-bank-edit".to_string());
+    /// save_settings(&path, &settings).unwrap();
+    ///
+    /// let control_tower = ControlTower::new_secondary(path);
+    /// assert_eq!(control_tower.get_current_page(), "main");
+    /// assert!(control_tower.get_settings().get_restore_last_page());
+    /// ```
+    pub fn new_secondary(path: PathBuf) -> Self
+    {
+        let (mut control_tower, _) = Self::new();
+        control_tower.settings = load_settings(&path);
+        control_tower.letter_scale_text = control_tower.settings.get_letter_scale().to_text();
+        control_tower.settings_path = Some(path);
+        control_tower.restore_locale();
+        control_tower
+    }
+
+    // fn restore_locale(&mut self)
+    /// Switches to the locale saved in the settings, if any, and applies the
+    /// export settings of that locale.
+    fn restore_locale(&mut self)
+    {
+        match self.settings.get_locale()
+        {
+            Some(locale) => self.use_locale(locale.to_string()),
+            None => self.apply_export_settings(),
+        }
+    }
+
+    // pub(crate) fn sync_settings(&mut self, settings: AppSettings)
+    /// Adopts settings changed in another window; they were already saved there.
+    pub(crate) fn sync_settings(&mut self, settings: AppSettings)
+    {
+        if LetterScale::parse(&self.letter_scale_text).as_ref() != Some(settings.get_letter_scale())
+            { self.letter_scale_text = settings.get_letter_scale().to_text(); }
+        if let Some(session) = &mut self.learning
+            { session.set_numeric_tolerance(settings.get_numeric_tolerance()); }
+        self.settings = settings;
+        self.apply_export_settings();
+    }
+
+    // pub fn get_settings(&self) -> &AppSettings
+    /// Returns the persisted user preferences.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let _ = control_tower.update(Message::SetRestoreLastPage(true));
+    /// assert!(control_tower.get_settings().get_restore_last_page());
+    /// ```
+    pub fn get_settings(&self) -> &AppSettings
+    {
+        &self.settings
+    }
+
+    // pub fn get_qbank(&self) -> &QBank
+    /// Returns a reference to the question bank.
+    ///
+    /// # Output
+    /// A reference to the `QBank` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ControlTower;
+    /// use qrate_gui::Message;
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let qbank = control_tower.get_qbank();
+    /// assert!(qbank.get_questions().is_empty());
+    ///
+    /// // First-time users can explore with the bundled sample bank.
+    /// let _ = control_tower.update(Message::LoadSampleBank);
+    /// assert!(!control_tower.get_qbank().get_questions().is_empty());
+    /// assert!(!control_tower.get_categories().is_empty());
+    /// ```
+    pub fn get_qbank(&self) -> &QBank
+    {
+        &self.qbank
+    }
+
+    // pub fn set_qbank(&mut self, qbank: QBank)
+    /// Sets the question bank to a new value.
+    ///
+    /// # Arguments
+    /// * `qbank` - The `QBank` instance to set.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ControlTower;
+    /// use qrate::QBank;
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let new_qbank = QBank::new_empty();
+    /// control_tower.set_qbank(new_qbank);
+    /// assert!(control_tower.get_qbank().get_questions().is_empty());
     /// ```
     pub fn set_qbank(&mut self, qbank: QBank)
     {
         self.qbank = qbank;
+        self.question_page = 0;
+        self.selected_questions.clear();
     }
 
     // pub fn get_sbank(&self) -> &SBank
@@ -187,7 +1263,7 @@ impl ControlTower
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     /// let (control_tower, _) = ControlTower::new();
     /// let sbank = control_tower.get_sbank();
     /// assert!(sbank.is_empty());
@@ -205,7 +1281,7 @@ impl ControlTower
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     /// use qrate::SBank;
     /// let (mut control_tower, _) = ControlTower::new();
     /// let new_sbank = SBank::new();
@@ -225,11 +1301,42 @@ impl ControlTower
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     /// use std::path::PathBuf;
     /// let (control_tower, _) = ControlTower::new();
     /// assert_eq!(control_tower.get_selected_file_path(), &PathBuf::new());
     /// ```
+    ///
+    /// A file that fails to load leaves the bank with the file it came from:
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message, ResultLoadFile };
+    ///
+    /// let dir = std::env::temp_dir().join("qrate_gui_failed_load");
+    /// let _ = std::fs::remove_dir_all(&dir);
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// let (a, b) = (dir.join("a.qbdb"), dir.join("b.qbdb"));
+    /// std::fs::write(&b, "not a question bank").unwrap();
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+    /// let _ = control_tower.update(Message::FileSelected(a.clone()));
+    /// let _ = control_tower.update(Message::QBankLoaded(ResultLoadFile::Success(qbank)));
+    ///
+    /// let _ = control_tower.update(Message::FileSelected(b.clone()));
+    /// let _ = control_tower.update(Message::QBankLoaded(ResultLoadFile::FailedToOpenSQLite));
+    /// assert_eq!(control_tower.get_selected_file_path(), &a);
+    /// assert!(control_tower.get_error_message().unwrap().contains("b.qbdb"));
+    ///
+    /// // Autosave writes the edited bank back to its own file only.
+    /// let _ = control_tower.update(Message::DismissError);
+    /// control_tower.set_dirty(true);
+    /// let _ = control_tower.update(Message::AutosaveTick(Instant::now()));
+    /// assert_eq!(std::fs::read_to_string(&b).unwrap(), "not a question bank");
+    /// assert!(a.exists());
+    /// ```
     pub fn get_selected_file_path(&self) -> &PathBuf
     {
         &self.selected_file_path
@@ -243,7 +1350,7 @@ impl ControlTower
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     /// use std::path::PathBuf;
     /// let (mut control_tower, _) = ControlTower::new();
     /// let new_path = PathBuf::from("/test/path");
@@ -263,8 +1370,27 @@ impl ControlTower
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
-    /// let (control_tower, _) = ControlTower::new();
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert_eq!(control_tower.get_current_menu_key(), "");
+    ///
+    /// // By default, choosing a submenu item closes the menu.
+    /// let _ = control_tower.update(Message::MenuClicked("information".to_string()));
+    /// assert_eq!(control_tower.get_current_menu_key(), "information");
+    /// let _ = control_tower.update(Message::SubMenuClicked("software-info".to_string()));
+    /// assert_eq!(control_tower.get_current_menu_key(), "");
+    ///
+    /// // With the setting on, it stays open for the next action.
+    /// let _ = control_tower.update(Message::SetKeepMenuOpen(true));
+    /// let _ = control_tower.update(Message::MenuClicked("information".to_string()));
+    /// let _ = control_tower.update(Message::SubMenuClicked("software-info".to_string()));
+    /// assert_eq!(control_tower.get_current_menu_key(), "information");
+    /// assert_eq!(control_tower.get_current_page(), "software-info");
+    /// let _ = control_tower.update(Message::SubMenuClicked("diagnostics".to_string()));
+    /// assert_eq!(control_tower.get_current_menu_key(), "information");
+    ///
+    /// let _ = control_tower.update(Message::SetKeepMenuOpen(false));
+    /// let _ = control_tower.update(Message::SubMenuClicked("software-info".to_string()));
     /// assert_eq!(control_tower.get_current_menu_key(), "");
     /// ```
     pub fn get_current_menu_key(&self) -> &str
@@ -280,7 +1406,7 @@ impl ControlTower
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     /// let (mut control_tower, _) = ControlTower::new();
     /// control_tower.set_current_menu_key("new_key".to_string());
     /// assert_eq!(control_tower.get_current_menu_key(), "new_key");
@@ -298,7 +1424,7 @@ impl ControlTower
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     /// let (control_tower, _) = ControlTower::new();
     /// assert_eq!(control_tower.get_menu_font_size_in_pixel(), 24.0);
     /// ```
@@ -315,7 +1441,7 @@ impl ControlTower
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     /// let (mut control_tower, _) = ControlTower::new();
     /// control_tower.set_menu_font_size_in_pixel(30.0);
     /// assert_eq!(control_tower.get_menu_font_size_in_pixel(), 30.0);
@@ -333,9 +1459,26 @@ impl ControlTower
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     /// let (control_tower, _) = ControlTower::new();
     /// assert_eq!(control_tower.get_current_locale(), "en");
+    ///
+    /// // Ctrl+L cycles through the locales, wraps around after the last one
+    /// // and remembers the choice.
+    /// use qrate_gui::{ load_settings, Message };
+    /// let path = std::env::temp_dir().join("qrate_gui_cycle_locale.json");
+    /// let _ = std::fs::remove_file(&path);
+    /// let (mut control_tower, _) = ControlTower::new_with_settings_file(path.clone());
+    /// let _ = control_tower.update(Message::CycleLocale);
+    /// assert_ne!(control_tower.get_current_locale(), "en");
+    /// assert_eq!(&*rust_i18n::locale(), control_tower.get_current_locale());
+    /// assert_eq!(load_settings(&path).get_locale(), Some(control_tower.get_current_locale()));
+    /// let _ = control_tower.update(Message::CycleLocale);
+    /// let _ = control_tower.update(Message::CycleLocale);
+    /// assert_eq!(control_tower.get_current_locale(), "en");
+    ///
+    /// let (control_tower, _) = ControlTower::new_with_settings_file(path);
+    /// assert_eq!(control_tower.get_current_locale(), "en");
     /// ```
     pub fn get_current_locale(&self) -> &str
     {
@@ -350,7 +1493,7 @@ impl ControlTower
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     /// let (mut control_tower, _) = ControlTower::new();
     /// control_tower.set_current_locale("ko-KR".to_string());
     /// assert_eq!(control_tower.get_current_locale(), "ko-KR");
@@ -368,214 +1511,4368 @@ impl ControlTower
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     /// let (control_tower, _) = ControlTower::new();
     /// assert_eq!(control_tower.get_current_page(), "main");
     /// ```
     pub fn get_current_page(&self) -> &str
     {
-        &self.current_page
+        self.current_page.get_id()
+    }
+
+    // pub fn get_page(&self) -> Page
+    /// Returns the page shown in the main content area.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, Message, Page };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert_eq!(control_tower.get_page(), Page::Main);
+    /// let _ = control_tower.update(Message::GoToPage(Page::Diagnostics));
+    /// assert_eq!(control_tower.get_page(), Page::Diagnostics);
+    /// assert_eq!(control_tower.get_current_page(), "diagnostics");
+    /// ```
+    pub fn get_page(&self) -> Page
+    {
+        self.current_page
     }
 
     // pub fn set_current_page(&mut self, page: String)
     /// Sets the current page identifier.
     ///
     /// # Arguments
-    /// * `page` - The `String` instance representing the new page.
+    /// * `page` - The `String` instance representing the new page; unknown
+    ///   ids show `Page::ComingSoon`.
     ///
     /// # Examples
     /// ```
-    /// use crate::control_tower::ControlTower;
+    /// use qrate_gui::ControlTower;
     /// let (mut control_tower, _) = ControlTower::new();
+    /// control_tower.set_current_page("student-list".to_string());
+    /// assert_eq!(control_tower.get_current_page(), "student-list");
+    ///
+    /// // Unknown ids fall back to the placeholder page.
     /// control_tower.set_current_page("settings".to_string());
-    /// assert_eq!(control_tower.get_current_page(), "settings");
+    /// assert_eq!(control_tower.get_current_page(), "coming-soon");
     /// ```
     pub fn set_current_page(&mut self, page: String)
     {
-        self.current_page = page;
+        self.show_page(Page::from_id(&page));
     }
 
-    // pub fn update(&mut self, message: Message) -> Task<Message>
-    /// Handles incoming messages and updates the state of the [ControlTower].
-    ///
-    /// # Arguments
-    /// * `message` - The [Message] to be processed.
+    // pub fn get_exam_criteria(&self) -> &ExamCriteria
+    /// Returns the criteria used to extract questions for an exam.
     ///
     /// # Output
-    /// An [iced::Task] that may produce further messages.
+    /// A reference to the `ExamCriteria` instance.
     ///
     /// # Examples
     /// ```
-    /// use iced::Task;
-    /// use crate::control_tower::{ControlTower, Message};
-    ///
+    /// use qrate_gui::{ ControlTower, Message, NumberingStyle };
     /// let (mut control_tower, _) = ControlTower::new();
+    /// assert_eq!(control_tower.get_exam_criteria().get_numbering_style(), NumberingStyle::Arabic);
     ///
-    /// // Test Message::MenuClicked
-    /// control_tower.update(Message::MenuClicked("settings".to_string()));
-    /// assert_eq!(control_tower.get_current_menu_key(), "settings");
+    /// // The number of choices per line is remembered for each locale.
+    /// let _ = control_tower.update(Message::SetLocale("ko".to_string()));
+    /// let _ = control_tower.update(Message::SetChoicesPerRow(2));
+    /// assert_eq!(control_tower.get_exam_criteria().get_choices_per_row(), 2);
+    /// let _ = control_tower.update(Message::SetLocale("en".to_string()));
+    /// assert_eq!(control_tower.get_exam_criteria().get_choices_per_row(), 1);
+    /// let _ = control_tower.update(Message::SetLocale("ko".to_string()));
+    /// assert_eq!(control_tower.get_exam_criteria().get_choices_per_row(), 2);
     ///
-    /// control_tower.update(Message::MenuClicked("settings".to_string())); // Click again to close
-    /// assert!(control_tower.get_current_menu_key().is_empty());
+    /// // Exams can be exported in another language than the interface.
+    /// let _ = control_tower.update(Message::SetExportLocale(Some("ru".to_string())));
+    /// assert_eq!(control_tower.get_exam_criteria().get_export_locale(), Some("ru"));
+    /// assert_eq!(control_tower.get_settings().get_export_locale(), Some("ru"));
     ///
-    /// // Test Message::SetLocale
-    /// control_tower.update(Message::SetLocale("ko-KR".to_string()));
-    /// assert_eq!(control_tower.get_current_locale(), "ko-KR");
+    /// // Essays can be given more writing space than the other questions.
+    /// use qrate_gui::QuestionKind;
+    /// let _ = control_tower.update(Message::SetAnswerSpaceLines(2));
+    /// let _ = control_tower.update(Message::SetKindAnswerSpace(QuestionKind::MultiLine, Some(12)));
+    /// let space = control_tower.get_exam_criteria().get_answer_space();
+    /// assert_eq!(space.get_lines_for(QuestionKind::SingleChoice), 2);
+    /// assert_eq!(space.get_lines_for(QuestionKind::MultiLine), 12);
+    /// assert_eq!(control_tower.get_settings().get_answer_space(), space);
     ///
-    /// // Test Message::GoToPage
-    /// control_tower.update(Message::GoToPage("language-settings".to_string()));
-    /// assert_eq!(control_tower.get_current_page(), "language-settings");
+    /// // Every page of a PDF exam carries the header and footer.
+    /// let _ = control_tower.update(Message::SetPageFooter("Page {page} of {total_pages}".to_string()));
+    /// let _ = control_tower.update(Message::SetPageLogo("logo.png".to_string()));
+    /// let template = control_tower.get_exam_criteria().get_page_template();
+    /// assert_eq!(template.get_footer(), "Page {page} of {total_pages}");
+    /// assert_eq!(template.get_logo(), Some(std::path::Path::new("logo.png")));
+    /// let _ = control_tower.update(Message::SetPageLogo(" ".to_string()));
+    /// assert_eq!(control_tower.get_settings().get_page_template().get_logo(), None);
+    ///
+    /// // The paper size and margin are kept for every PDF export.
+    /// use qrate_gui::PaperSize;
+    /// let _ = control_tower.update(Message::SetPaperSize(PaperSize::Letter));
+    /// let _ = control_tower.update(Message::SetPageMargin(20));
+    /// let template = control_tower.get_settings().get_page_template();
+    /// assert_eq!((template.get_paper_size(), template.get_margin()), (PaperSize::Letter, 20));
+    /// assert_eq!(control_tower.get_exam_criteria().get_page_template(), template);
+    ///
+    /// // Opening the criteria of a freshly loaded bank derives the question
+    /// // count from its size; a count typed afterwards is kept.
+    /// use qrate_gui::{ default_question_count, load_sample_bank, Page, DEFAULT_QUESTION_PERCENT };
+    /// let size = load_sample_bank().get_questions().len();
+    /// let _ = control_tower.update(Message::LoadSampleBank);
+    /// let _ = control_tower.update(Message::GoToPage(Page::Criteria));
+    /// assert_eq!(control_tower.get_exam_criteria().get_selected(), default_question_count(size, DEFAULT_QUESTION_PERCENT));
+    /// let _ = control_tower.update(Message::SetQuestionCount("3".to_string()));
+    /// let _ = control_tower.update(Message::GoToPage(Page::Main));
+    /// let _ = control_tower.update(Message::GoToPage(Page::Criteria));
+    /// assert_eq!(control_tower.get_exam_criteria().get_selected(), 3);
+    ///
+    /// // The share is kept in the settings.
+    /// let _ = control_tower.update(Message::SetQuestionPercent(100));
+    /// assert_eq!(control_tower.get_settings().get_question_percent(), 100);
+    /// assert_eq!(control_tower.get_exam_criteria().get_selected(), size);
+    ///
+    /// // Sections typed on the criteria page; a half-typed entry keeps the last valid ones.
+    /// let _ = control_tower.update(Message::SetSectionsText("1-3:3!, 4-0:2".to_string()));
+    /// let sections = control_tower.get_exam_criteria().get_sections();
+    /// assert_eq!(sections.len(), 2);
+    /// assert!(!sections[0].get_shuffle() && sections[1].get_shuffle());
+    /// let _ = control_tower.update(Message::SetSectionsText("1-3:3!, 4-".to_string()));
+    /// assert_eq!(control_tower.get_exam_criteria().get_sections().len(), 2);
+    /// let _ = control_tower.update(Message::SetSectionsText(String::new()));
+    /// assert!(control_tower.get_exam_criteria().get_sections().is_empty());
     /// ```
-    pub fn update(&mut self, message: Message) -> Task<Message>
+    pub fn get_exam_criteria(&self) -> &ExamCriteria
     {
-        match message
-        {
-            Message::MenuClicked(menu_key) => self.click_menu(menu_key),
-            Message::SubMenuClicked(sub_item_key) => self.click_submenu(sub_item_key),
-            Message::FileSelected(path) => self.select_file(path),
-            Message::QBankLoaded(result) => self.load_qbank(result),
-            Message::SetLocale(locale) => self.set_locale(locale),
-            Message::GoToPage(page_name) => self.go_to_page(page_name),
-        }
+        &self.exam_criteria
     }
 
-    fn click_menu(&mut self, menu_key: String) -> Task<Message>
+    // pub fn set_exam_criteria(&mut self, criteria: ExamCriteria)
+    /// Sets the criteria used to extract questions for an exam.
+    ///
+    /// # Arguments
+    /// * `criteria` - The new `ExamCriteria`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, ExamCriteria };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(3);
+    /// control_tower.set_exam_criteria(criteria);
+    /// assert_eq!(control_tower.get_exam_criteria().get_selected(), 3);
+    /// ```
+    pub fn set_exam_criteria(&mut self, criteria: ExamCriteria)
     {
-        if self.current_menu_key == menu_key
-            { self.current_menu_key.clear(); }
-        else
-            { self.current_menu_key = menu_key; }
-        Task::none()
+        self.exam_criteria = criteria;
     }
 
-    fn click_submenu(&mut self, sub_item_key: String) -> Task<Message>
+    // pub fn get_exam(&self) -> Option<&Exam>
+    /// Returns the most recently generated exam, if any.
+    ///
+    /// # Output
+    /// An `Option<&Exam>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert!(control_tower.get_exam().is_none());
+    ///
+    /// // The one-click exam needs a loaded bank.
+    /// let _ = control_tower.update(Message::QuickExam);
+    /// assert!(control_tower.get_exam().is_none());
+    /// assert!(control_tower.get_error_message().is_some());
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=12
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// control_tower.set_qbank(qbank);
+    /// let _ = control_tower.update(Message::QuickExam);
+    /// assert_eq!(control_tower.get_exam().unwrap().get_questions().len(), 10);
+    /// assert_eq!(control_tower.get_current_page(), "exam-preview");
+    ///
+    /// // Exported questions can be kept off the next exams.
+    /// use std::collections::BTreeSet;
+    /// use std::path::PathBuf;
+    /// use qrate_gui::ResultSaveFile;
+    /// let used: BTreeSet<u16> = control_tower.get_exam().unwrap().get_questions().iter().map(|q| q.get_id()).collect();
+    /// let _ = control_tower.update(Message::ExamExported(ResultSaveFile::Success(PathBuf::from("quiz.pdf"))));
+    /// let _ = control_tower.update(Message::SetAvoidRecent(1));
+    /// assert_eq!(control_tower.get_exam_criteria().get_avoided(), &used);
+    /// let _ = control_tower.update(Message::SetAvoidRecent(0));
+    /// assert!(control_tower.get_exam_criteria().get_avoided().is_empty());
+    ///
+    /// // A fixed seed draws the same paper again; the seed is shown with it.
+    /// let ids = |control_tower: &ControlTower| control_tower.get_exam().unwrap().get_questions().iter().map(|q| q.get_id()).collect::<Vec<u16>>();
+    /// let _ = control_tower.update(Message::SetQuestionCount("5".to_string()));
+    /// let _ = control_tower.update(Message::SetSeed(Some(2026)));
+    /// let _ = control_tower.update(Message::SubMenuClicked("export-exam-paper".to_string()));
+    /// let first = ids(&control_tower);
+    /// assert_eq!(control_tower.get_exam().unwrap().get_seed(), Some(2026));
+    /// let _ = control_tower.update(Message::SubMenuClicked("export-exam-paper".to_string()));
+    /// assert_eq!(ids(&control_tower), first);
+    ///
+    /// // An empty seed draws a new random paper each time.
+    /// let _ = control_tower.update(Message::SetSeed(None));
+    /// assert_eq!(control_tower.get_exam_criteria().get_seed(), None);
+    /// let papers: Vec<Vec<u16>> = (0..5).map(|_| {
+    ///     let _ = control_tower.update(Message::SubMenuClicked("export-exam-paper".to_string()));
+    ///     ids(&control_tower)
+    /// }).collect();
+    /// assert!(papers.iter().any(|paper| paper != &first));
+    /// assert_ne!(control_tower.get_exam().unwrap().get_seed(), None);
+    /// ```
+    pub fn get_exam(&self) -> Option<&Exam>
     {
-        self.current_menu_key.clear();
-        match sub_item_key.as_str()
-        {
-            "load-question-bank" => LoadFile::perform_pick_qbank_task(),
-            // "create-new-question-bank" => Task::none(),
-            _ => Task::none(),
-        }
+        self.exam.as_ref()
     }
 
-    fn select_file(&mut self, path: PathBuf) -> Task<Message>
+    // pub fn get_bank_metadata(&self) -> &BankMetadata
+    /// Returns the metadata of the loaded question bank.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Excel, QBDB, QBank, Question };
+    /// use qrate_gui::{ ControlTower, LoadFile, Message };
+    ///
+    /// let dir = std::env::temp_dir();
+    /// let bank_path = dir.join("qrate_gui_control_tower_bank_metadata.qb.xlsx");
+    /// let _ = std::fs::remove_file(&bank_path);
+    /// let _ = std::fs::remove_file(qrate_gui::get_bank_metadata_path(&bank_path));
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+    /// qbank.push_question(Question::new(2, 2, 1, "Q2".to_string(), vec![]));
+    /// let mut excel = Excel::open(dir.join("qrate_gui_control_tower_bank_metadata").to_string_lossy().into_owned()).unwrap();
+    /// excel.write_qbank(&qbank).unwrap();
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// control_tower.set_selected_file_path(bank_path.clone());
+    /// let _ = control_tower.update(Message::QBankLoaded(LoadFile::read_qbank_from_path(&bank_path)));
+    /// assert_eq!(control_tower.get_bank_metadata().get_title(), "Examination");
+    ///
+    /// // Editing the metadata marks the state dirty; saving clears it.
+    /// let _ = control_tower.update(Message::SetBankTitle("Algebra I".to_string()));
+    /// let _ = control_tower.update(Message::SetBankSubject("Mathematics".to_string()));
+    /// assert!(control_tower.is_dirty());
+    /// let _ = control_tower.update(Message::SaveBankMetadata);
+    /// assert!(!control_tower.is_dirty());
+    ///
+    /// // The metadata survives reloading the bank.
+    /// let (mut reopened, _) = ControlTower::new();
+    /// reopened.set_selected_file_path(bank_path.clone());
+    /// let _ = reopened.update(Message::QBankLoaded(LoadFile::read_qbank_from_path(&bank_path)));
+    /// assert_eq!(reopened.get_bank_metadata(), control_tower.get_bank_metadata());
+    /// assert_eq!(reopened.get_bank_metadata().get_subject(), "Mathematics");
+    ///
+    /// // Saving writes the questions as well, so a deletion is not lost.
+    /// let _ = reopened.update(Message::SelectQuestion(2, true));
+    /// let _ = reopened.update(Message::DeleteSelectedQuestions);
+    /// let _ = reopened.update(Message::ConfirmDeleteQuestions);
+    /// let _ = reopened.update(Message::SaveBankMetadata);
+    /// assert!(!reopened.is_dirty());
+    /// let qrate_gui::ResultLoadFile::Success(saved) = LoadFile::read_qbank_from_path(&bank_path)
+    ///     else { panic!("the saved bank should reload") };
+    /// assert_eq!(saved.get_questions().len(), 1);
+    /// assert_eq!(saved.get_header().get_title(), "Algebra I");
+    /// ```
+    pub fn get_bank_metadata(&self) -> &BankMetadata
     {
-        self.selected_file_path = path.clone();
-        self.current_menu_key.clear();
-        if path.as_os_str().is_empty()
-            { Task::none() }
-        else
-            { LoadFile::perform_load_qbank_task(path) }
+        &self.bank_metadata
     }
 
-    fn load_qbank(&mut self, result: ResultLoadFile) -> Task<Message>
+    // pub fn has_real_changes(&self) -> bool
+    /// Compares the loaded bank with the files on disk.
+    ///
+    /// Unlike `is_dirty`, which is set by any edit, this reports only edits
+    /// that leave the bank different from what is saved, so that typing a
+    /// value and then restoring it does not count as a change. The files
+    /// are fingerprinted when the bank is loaded or saved rather than read
+    /// again on every edit.
+    ///
+    /// # Output
+    /// `true` if the bank, its question metadata or its bank metadata differ
+    /// from the saved files. For a bank without a file, `is_dirty`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Excel, QBDB, QBank, Question };
+    /// use qrate_gui::{ ControlTower, LoadFile, Message };
+    ///
+    /// let dir = std::env::temp_dir();
+    /// let bank_path = dir.join("qrate_gui_has_real_changes.qb.xlsx");
+    /// let _ = std::fs::remove_file(&bank_path);
+    /// let _ = std::fs::remove_file(qrate_gui::get_bank_metadata_path(&bank_path));
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+    /// let mut excel = Excel::open(dir.join("qrate_gui_has_real_changes").to_string_lossy().into_owned()).unwrap();
+    /// excel.write_qbank(&qbank).unwrap();
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// control_tower.set_selected_file_path(bank_path.clone());
+    /// let _ = control_tower.update(Message::QBankLoaded(LoadFile::read_qbank_from_path(&bank_path)));
+    /// assert!(!control_tower.has_real_changes());
+    ///
+    /// // A no-op edit: the title is changed and then restored.
+    /// let _ = control_tower.update(Message::SetBankTitle("Draft".to_string()));
+    /// let _ = control_tower.update(Message::SetBankTitle("Examination".to_string()));
+    /// assert!(control_tower.is_dirty());
+    /// assert!(!control_tower.has_real_changes());
+    ///
+    /// // Saving after a no-op edit leaves the files untouched.
+    /// let modified = |path: &std::path::Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    /// let sidecar = qrate_gui::get_bank_metadata_path(&bank_path);
+    /// let (bank_before, sidecar_before) = (modified(&bank_path), modified(&sidecar));
+    /// let _ = control_tower.update(Message::SaveBankMetadata);
+    /// assert!(!control_tower.is_dirty());
+    /// assert_eq!(modified(&bank_path), bank_before);
+    /// assert_eq!(modified(&sidecar), sidecar_before);
+    ///
+    /// // A genuine edit.
+    /// let _ = control_tower.update(Message::SetBankSubject("Mathematics".to_string()));
+    /// assert!(control_tower.has_real_changes());
+    /// let _ = control_tower.update(Message::SaveBankMetadata);
+    /// assert!(!control_tower.has_real_changes());
+    /// assert!(modified(&sidecar).is_some() && modified(&sidecar) != sidecar_before);
+    ///
+    /// // Question metadata is saved as it is edited, so it is no change either.
+    /// let _ = control_tower.update(Message::SetQuestionDifficulty(1, 4));
+    /// assert!(!control_tower.has_real_changes());
+    /// ```
+    pub fn has_real_changes(&self) -> bool
     {
-        match result
+        match self.saved_fingerprint
         {
-            ResultLoadFile::Success(qbank) => self.qbank = qbank,   // TODO: Add a success message for the user.
-            ResultLoadFile::FileNotFound => eprintln!("Error loading QBank: File does not exist."),
-            ResultLoadFile::FailedToOpenSQLite => eprintln!("Error loading QBank: Failed to open QBDB file."),
-            ResultLoadFile::FailedToReadSQLite => eprintln!("Error loading QBank: Failed to read QBank from QBDB."),
-            ResultLoadFile::FailedToOpenExcel => eprintln!("Error loading QBank: Failed to open Excel file."),
-            ResultLoadFile::FailedToReadExcel => eprintln!("Error loading QBank: Failed to read QBank from Excel."),
-            ResultLoadFile::InvalidExcelExtension => eprintln!("Error loading QBank: Not a valid *.qb.xlsx file. Expecting .qb.xlsx extension for Excel QBank."),
-            ResultLoadFile::UnsupportedExtension => eprintln!("Error loading QBank: Unsupported file extension."),
+            Some(saved) => saved != fingerprint_bank(&self.qbank, &self.question_metas, &self.bank_metadata),
+            None => self.dirty,
         }
-        Task::none()
     }
 
-    fn set_locale(&mut self, locale: String) -> Task<Message>
+    // pub fn is_load_pending(&self) -> bool
+    /// Returns whether a question bank is waiting for the user to confirm
+    /// that it replaces the current bank and its unsaved changes.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    ///
+    /// // Over a clean bank, the load proceeds directly.
+    /// let _ = control_tower.update(Message::LoadSampleBank);
+    /// assert!(!control_tower.is_load_pending());
+    /// assert!(!control_tower.get_qbank().get_questions().is_empty());
+    ///
+    /// // Over a dirty bank, it waits for confirmation.
+    /// control_tower.set_dirty(true);
+    /// let _ = control_tower.update(Message::FileSelected(PathBuf::from("/banks/other.qbdb")));
+    /// assert!(control_tower.is_load_pending());
+    /// assert!(control_tower.get_selected_file_path().as_os_str().is_empty());
+    ///
+    /// // Cancelling keeps the bank and its edits.
+    /// let _ = control_tower.update(Message::CancelReplaceBank);
+    /// assert!(!control_tower.is_load_pending());
+    /// assert!(control_tower.is_dirty());
+    ///
+    /// // Confirming loads the new bank and drops the edits.
+    /// let _ = control_tower.update(Message::FileSelected(PathBuf::from("/banks/other.qbdb")));
+    /// let _ = control_tower.update(Message::ConfirmReplaceBank);
+    /// assert!(!control_tower.is_load_pending());
+    /// assert!(!control_tower.is_dirty());
+    /// assert_eq!(control_tower.get_selected_file_path(), &PathBuf::from("/banks/other.qbdb"));
+    ///
+    /// // Unloading a dirty bank asks first as well, then leaves nothing loaded.
+    /// let _ = control_tower.update(Message::LoadSampleBank);
+    /// control_tower.set_dirty(true);
+    /// let _ = control_tower.update(Message::UnloadBank);
+    /// assert!(control_tower.is_load_pending());
+    /// assert!(!control_tower.get_qbank().get_questions().is_empty());
+    /// let _ = control_tower.update(Message::ConfirmReplaceBank);
+    /// assert!(!control_tower.is_load_pending());
+    /// assert!(!control_tower.is_dirty());
+    /// assert!(control_tower.get_qbank().get_questions().is_empty());
+    /// assert!(control_tower.get_sbank().is_empty());
+    /// assert!(control_tower.get_selected_file_path().as_os_str().is_empty());
+    ///
+    /// // An edited roster is not saved with the bank, so unloading asks too.
+    /// let _ = control_tower.update(Message::OpenStudentForm(None));
+    /// let _ = control_tower.update(Message::StudentNameChanged("Alice".to_string()));
+    /// let _ = control_tower.update(Message::StudentIdChanged("s1".to_string()));
+    /// let _ = control_tower.update(Message::SubmitStudentForm);
+    /// control_tower.set_dirty(false);
+    /// let _ = control_tower.update(Message::UnloadBank);
+    /// assert!(control_tower.is_load_pending());
+    /// let _ = control_tower.update(Message::CancelReplaceBank);
+    /// assert_eq!(control_tower.get_sbank().len(), 1);
+    /// let _ = control_tower.update(Message::UnloadBank);
+    /// let _ = control_tower.update(Message::ConfirmReplaceBank);
+    /// assert!(control_tower.get_sbank().is_empty());
+    ///
+    /// // Without edits, unloading goes ahead at once.
+    /// let _ = control_tower.update(Message::UnloadBank);
+    /// assert!(!control_tower.is_load_pending());
+    /// ```
+    pub fn is_load_pending(&self) -> bool
     {
-        rust_i18n::set_locale(&locale);
-        self.current_locale = locale;
-        Task::none()
+        self.pending_load.is_some()
     }
 
-    fn go_to_page(&mut self, page_name: String) -> Task<Message>
+    // pub fn get_session_banks(&self) -> &[PathBuf]
+    /// Returns the question banks of the last session offered to be opened
+    /// again, which is empty once the offer is answered.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::{ save_settings, AppSettings, ControlTower, Message };
+    ///
+    /// let existing = std::env::temp_dir().join("qrate_gui_session_bank.qbdb");
+    /// std::fs::write(&existing, b"").unwrap();
+    /// let path = std::env::temp_dir().join("qrate_gui_session_banks.json");
+    /// let mut settings = AppSettings::new();
+    /// settings.set_open_banks(vec![PathBuf::from("/definitely/not/here.qbdb"), existing.clone()]);
+    /// save_settings(&path, &settings).unwrap();
+    ///
+    /// // Only the banks that still exist are offered.
+    /// let (mut control_tower, _) = ControlTower::new_with_settings_file(path.clone());
+    /// assert_eq!(control_tower.get_session_banks(), [existing.clone()]);
+    /// let _ = control_tower.update(Message::DismissSessionBanks);
+    /// assert!(control_tower.get_session_banks().is_empty());
+    ///
+    /// let (mut control_tower, _) = ControlTower::new_with_settings_file(path);
+    /// let _ = control_tower.update(Message::RestoreSessionBanks);
+    /// assert!(control_tower.get_session_banks().is_empty());
+    /// assert_eq!(control_tower.get_selected_file_path(), &existing);
+    /// ```
+    pub fn get_session_banks(&self) -> &[PathBuf]
     {
-        self.current_page = page_name;
-        Task::none()
+        &self.session_banks
     }
 
-    // fn calculate_text_width_estimate(&self, name: &str) -> f32
-    /// Calculates the estimated width of a given string `name` based on character type and font size.
-    ///
-    /// # Arguments
-    /// * `name` - The string whose width is to be estimated.
-    ///
-    /// # Output
-    /// The estimated width of the string as an `f32`.
+    // pub fn get_pending_resume(&self) -> Option<&LearningProgress>
+    /// Returns the self-study session left unfinished in the loaded bank,
+    /// while the offer to resume it is open.
     ///
     /// # Examples
     /// ```
-    /// use iced::Task;
-    /// use crate::control_tower::{ControlTower, Message};
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ get_learning_progress_path, ControlTower, Message, ResultLoadFile };
+    ///
+    /// let bank_path = std::env::temp_dir().join("qrate_gui_pending_resume.qbdb");
+    /// let _ = std::fs::remove_file(get_learning_progress_path(&bank_path));
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=10
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![("a".to_string(), true), ("b".to_string(), false)])); }
+    /// let open = |control_tower: &mut ControlTower| {
+    ///     let _ = control_tower.update(Message::FileSelected(bank_path.clone()));
+    ///     let _ = control_tower.update(Message::QBankLoaded(ResultLoadFile::Success(qbank.clone())));
+    /// };
     ///
+    /// // A student answers two questions and is on the third when the application closes.
     /// let (mut control_tower, _) = ControlTower::new();
-    /// control_tower.set_menu_font_size_in_pixel(20.0);
+    /// open(&mut control_tower);
+    /// assert!(control_tower.get_pending_resume().is_none());
+    /// let _ = control_tower.update(Message::SubMenuClicked("take-exam".to_string()));
+    /// let _ = control_tower.update(Message::ToggleLearningChoice(1));
+    /// let _ = control_tower.update(Message::LearningGoTo(1));
+    /// let _ = control_tower.update(Message::ToggleLearningChoice(0));
+    /// let _ = control_tower.update(Message::LearningGoTo(2));
+    /// let asked: Vec<u16> = control_tower.get_learning_session().unwrap().get_exam().get_questions().iter().map(|q| q.get_id()).collect();
+    /// drop(control_tower);
+    ///
+    /// // On the next launch, the same questions come back with the answers and position intact.
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// open(&mut control_tower);
+    /// assert_eq!(control_tower.get_pending_resume().unwrap().get_current(), 2);
+    /// let _ = control_tower.update(Message::ResumeLearning);
+    /// assert!(control_tower.get_pending_resume().is_none());
+    /// let session = control_tower.get_learning_session().unwrap();
+    /// let resumed: Vec<u16> = session.get_exam().get_questions().iter().map(|q| q.get_id()).collect();
+    /// assert_eq!(resumed, asked);
+    /// assert_eq!(session.get_current(), 2);
+    /// assert_eq!(session.get_answer(0), &[1]);
+    /// assert_eq!(session.get_answer(1), &[0]);
     ///
-    /// let name = "문제은행 관리";
-    /// assert_eq!(control_tower.calculate_text_width_estimate(name), 102.0);
-    /// let name = "File Menu";
-    /// assert_eq!(control_tower.calculate_text_width_estimate(name), 97.2);
-    /// let name = "한글 abc";
-    /// assert_eq!(control_tower.calculate_text_width_estimate(name), 77.2);
+    /// // Finishing the session deletes the saved progress.
+    /// let _ = control_tower.update(Message::FinishLearning);
+    /// assert!(!get_learning_progress_path(&bank_path).exists());
     /// ```
-    fn calculate_text_width_estimate(&self, name: &str) -> f32
+    pub fn get_pending_resume(&self) -> Option<&LearningProgress>
     {
-        let mut width = 0.0;
-        for c in name.chars()
-        {
-            // Check for Korean (Hangul Syllables), Hanja (CJK Unified Ideographs), Hiragana, Katakana
-            if (c >= '\u{AC00}' && c <= '\u{D7AF}') || // Hangul Syllables
-               (c >= '\u{4E00}' && c <= '\u{9FFF}') || // CJK Unified Ideographs (Hanja)
-               (c >= '\u{3040}' && c <= '\u{30FF}')    // Hiragana & Katakana
-            {
-                width += self.menu_font_size_in_pixel * 0.85;
-            }
-            else if c >= '\u{0400}' && c <= '\u{04FF}' // Cyrillic
-            {
-                width += self.menu_font_size_in_pixel * 0.56;
-            }
-            else // Assume Basic Latin and others
-            {
-                width += self.menu_font_size_in_pixel * 0.54;
-            }
-        }
-        width
+        self.pending_resume.as_ref()
     }
 
-    // pub fn view(&self) -> Element<'_, Message>
-    /// Returns the current view of the application as an [iced::Element].
+    // pub fn get_accessible_labels(&self) -> Vec<AccessibleLabel>
+    /// Returns the accessible names and roles of the interactive elements
+    /// on screen: the main menu buttons, the items of the open submenu and
+    /// the open dialog, translated into the current locale.
     ///
-    /// # Output
-    /// An [iced::Element] representing the user interface.
+    /// Iced does not expose its widgets to the accessibility API of the
+    /// operating system, so these labels reach a screen reader through the
+    /// window title; see `ControlTower::get_focus_announcement`.
     ///
     /// # Examples
     /// ```
-    /// use iced::widget::text;
-    /// use iced::Element;
-    /// use crate::control_tower::{ControlTower, Message};
+    /// use qrate_gui::{ AccessibleRole, ControlTower, Message, MENU_KEYS };
     ///
-    /// let (control_tower, _) = ControlTower::new();
-    /// let view_element: Element<'_, Message> = control_tower.view();
-    /// // In a real application, you would inspect the generated Element.
-    /// // For this conceptual example, we just ensure it compiles and returns an Element.
-    /// // More detailed assertions would require inspecting the widget tree.
-    /// assert_eq!(view_element.as_widget().is_some(), true);
-    /// ```
-    pub fn view(&self) -> Element<'_, Message>
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// for locale in ["en", "ko", "ru"]
+    /// {
+    ///     let _ = control_tower.update(Message::SetLocale(locale.to_string()));
+    ///     for menu in MENU_KEYS
+    ///     {
+    ///         let _ = control_tower.update(Message::MenuClicked(menu.to_string()));
+    ///         let labels = control_tower.get_accessible_labels();
+    ///         assert!(labels.iter().any(|label| label.get_role() == AccessibleRole::MenuItem));
+    ///         // Every element has a name, translated rather than the bare key.
+    ///         for label in labels.iter()
+    ///         {
+    ///             assert!(!label.get_name().trim().is_empty());
+    ///             assert_ne!(label.get_name(), label.get_key(), "{} in {}", label.get_key(), locale);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // The names follow the locale.
+    /// let _ = control_tower.update(Message::SetLocale("ko".to_string()));
+    /// assert_eq!(control_tower.get_accessible_labels()[4].get_name(), "설정");
+    /// let _ = control_tower.update(Message::SetLocale("en".to_string()));
+    /// assert_eq!(control_tower.get_accessible_labels()[4].get_name(), "Settings");
+    ///
+    /// // An open dialog is named by its title.
+    /// let _ = control_tower.update(Message::OpenStudentForm(None));
+    /// let dialog = control_tower.get_accessible_labels().pop().unwrap();
+    /// assert_eq!(dialog.get_role(), AccessibleRole::Dialog);
+    /// assert_eq!(dialog.get_name(), "Add Student");
+    /// ```
+    pub fn get_accessible_labels(&self) -> Vec<AccessibleLabel>
+    {
+        let mut labels = menu_bar_labels();
+        if !self.current_menu_key.is_empty()
+            { labels.extend(submenu_labels(&self.current_menu_key)); }
+        if let Some(key) = self.get_dialog_key()
+            { labels.push(AccessibleLabel::new(AccessibleRole::Dialog, key)); }
+        labels
+    }
+
+    // pub fn get_focus_announcement(&self) -> Option<String>
+    /// Returns what a screen reader should say for the element holding the
+    /// keyboard focus: the open dialog, which takes the focus, or the
+    /// focused main menu button. `ControlTower::title` puts it in front of
+    /// the window title, which screen readers read out when it changes.
+    ///
+    /// # Output
+    /// The name and role in the current locale, or `None` if neither a
+    /// dialog nor a menu button has the focus.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, Message };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let _ = control_tower.update(Message::SetLocale("en".to_string()));
+    /// assert_eq!(control_tower.get_focus_announcement(), None);
+    /// let _ = control_tower.update(Message::FocusNextMenu);
+    /// assert_eq!(control_tower.get_focus_announcement().unwrap(), "Question Bank Management, menu");
+    ///
+    /// let _ = control_tower.update(Message::OpenStudentForm(None));
+    /// assert_eq!(control_tower.get_focus_announcement().unwrap(), "Add Student, dialog");
+    /// ```
+    pub fn get_focus_announcement(&self) -> Option<String>
+    {
+        let labels = self.get_accessible_labels();
+        // The dialog comes last, after the menu bar and the open submenu.
+        let focused = match labels.last()
+        {
+            Some(label) if label.get_role() == AccessibleRole::Dialog => labels.last(),
+            _ => self.get_focused_menu().and_then(|index| labels.get(index)),
+        };
+        focused.map(|label| label.get_announcement())
+    }
+
+    // fn get_dialog_key(&self) -> Option<&'static str>
+    /// Returns the locale key of the title of the topmost open dialog.
+    fn get_dialog_key(&self) -> Option<&'static str>
+    {
+        if self.error_message.is_some()
+            { return Some("error"); }
+        if self.is_overwrite_pending()
+            { return Some("overwrite-title"); }
+        if self.pending_roster.is_some()
+            { return Some("duplicate-students-title"); }
+        if self.pending_delete.is_some()
+            { return Some("delete-questions-title"); }
+        if self.pending_merge.is_some()
+            { return Some("merge-category-title"); }
+        if let Some(pending) = &self.pending_load
+        {
+            return Some(match pending
+            {
+                PendingLoad::Unload => "unload-bank-title",
+                _ => "replace-bank-title",
+            });
+        }
+        if self.pending_resume.is_some()
+            { return Some("resume-learning-title"); }
+        if !self.session_banks.is_empty()
+            { return Some("restore-session-title"); }
+        if let Some(browser) = &self.file_browser
+        {
+            return Some(match browser.get_purpose()
+            {
+                BrowsePurpose::Save(_) => "save-file-title",
+                _ => "file-browser-title",
+            });
+        }
+        self.student_form.as_ref().map(|form| if form.get_editing().is_some() { "edit-student" } else { "add-student" })
+    }
+
+    // pub fn get_file_browser(&self) -> Option<&FileBrowser>
+    /// Returns the in-app file browser, while it is open in place of a
+    /// native file dialog.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::fs;
+    /// use qrate_gui::{ BrowsePurpose, ControlTower, FileDialogKind, Message, SaveTarget };
+    ///
+    /// let dir = std::env::temp_dir().join("qrate_gui_get_file_browser");
+    /// let _ = fs::remove_dir_all(&dir);
+    /// fs::create_dir_all(dir.join("banks")).unwrap();
+    /// fs::write(dir.join("banks").join("math.qbdb"), b"").unwrap();
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let _ = control_tower.update(Message::SetFileDialog(FileDialogKind::Native));
+    /// let _ = control_tower.update(Message::SubMenuClicked("load-question-bank".to_string()));
+    /// assert!(control_tower.get_file_browser().is_none());
+    ///
+    /// // With the built-in browser, loading a bank opens it instead of the native dialog.
+    /// let _ = control_tower.update(Message::SetFileDialog(FileDialogKind::InApp));
+    /// let _ = control_tower.update(Message::SubMenuClicked("load-question-bank".to_string()));
+    /// let browser = control_tower.get_file_browser().unwrap();
+    /// assert_eq!(browser.get_purpose(), BrowsePurpose::QuestionBank);
+    ///
+    /// let _ = control_tower.update(Message::BrowseDirectory(dir.join("banks")));
+    /// let names: Vec<&str> = control_tower.get_file_browser().unwrap().get_entries().iter().map(|entry| entry.get_name()).collect();
+    /// assert_eq!(names, ["math.qbdb"]);
+    ///
+    /// // Picking a file closes the browser; cancelling does too.
+    /// let _ = control_tower.update(Message::BrowsePick(dir.join("banks").join("math.qbdb")));
+    /// assert!(control_tower.get_file_browser().is_none());
+    /// let _ = control_tower.update(Message::PickStoragePath);
+    /// assert!(control_tower.get_file_browser().unwrap().get_purpose().picks_folder());
+    /// let _ = control_tower.update(Message::CancelFileBrowser);
+    /// assert!(control_tower.get_file_browser().is_none());
+    ///
+    /// // Saving opens it as well, with the file name to write to.
+    /// let _ = control_tower.update(Message::SubMenuClicked("export-bank-stats".to_string()));
+    /// let browser = control_tower.get_file_browser().unwrap();
+    /// assert_eq!(browser.get_purpose(), BrowsePurpose::Save(SaveTarget::BankStats));
+    /// assert_eq!(browser.get_file_name(), "bank-stats.csv");
+    /// let _ = control_tower.update(Message::BrowseDirectory(dir.clone()));
+    /// let _ = control_tower.update(Message::SetBrowserFileName("stats.csv".to_string()));
+    /// let path = control_tower.get_file_browser().unwrap().get_save_path().unwrap();
+    /// let _ = control_tower.update(Message::BrowsePick(path.clone()));
+    /// assert!(control_tower.get_file_browser().is_none());
+    /// let _ = control_tower.update(Message::SavePathChosen(SaveTarget::BankStats, path.clone()));
+    /// assert!(path.exists());
+    ///
+    /// // Saving over an existing file asks first.
+    /// let _ = control_tower.update(Message::SavePathChosen(SaveTarget::BankStats, path.clone()));
+    /// assert_eq!(control_tower.get_pending_overwrite(), Some(path.as_path()));
+    /// let _ = control_tower.update(Message::CancelOverwrite);
+    ///
+    /// let _ = control_tower.update(Message::SetFileDialog(FileDialogKind::Native));
+    /// fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn get_file_browser(&self) -> Option<&FileBrowser>
+    {
+        self.file_browser.as_ref()
+    }
+
+    // pub(crate) fn remember_open_banks(&mut self, banks: Vec<PathBuf>)
+    /// Saves the question banks open in the windows of the closing
+    /// application, to be offered again on the next launch.
+    pub(crate) fn remember_open_banks(&mut self, banks: Vec<PathBuf>)
+    {
+        let _ = self.edit_settings(|settings| settings.set_open_banks(banks));
+    }
+
+    // pub fn get_pending_duplicates(&self) -> &[DuplicateStudents]
+    /// Returns the duplicate students of an imported roster that wait for
+    /// the user to keep the first of each or merge them.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, DuplicateResolution, LoadFile, Message, ResultLoadSBank };
+    /// let (mut control_tower, _) = ControlTower::new();
+    ///
+    /// let (sbank, groups) = LoadFile::parse_roster_text("Alice, s1\nBob, s2\nAlicia, s1\n");
+    /// let _ = control_tower.update(Message::SBankLoaded(ResultLoadSBank::Success(sbank, groups)));
+    /// assert_eq!(control_tower.get_pending_duplicates()[0].get_indices(), &[0, 2]);
+    /// assert!(control_tower.get_sbank().is_empty());
+    ///
+    /// let _ = control_tower.update(Message::ResolveDuplicateStudents(DuplicateResolution::KeepFirst));
+    /// assert!(control_tower.get_pending_duplicates().is_empty());
+    /// assert_eq!(control_tower.get_sbank().len(), 2);
+    /// assert_eq!(control_tower.get_sbank()[0].get_name(), "Alice");
+    /// ```
+    pub fn get_pending_duplicates(&self) -> &[DuplicateStudents]
+    {
+        self.pending_roster.as_ref().map(|pending| pending.duplicates.as_slice()).unwrap_or_default()
+    }
+
+    // pub fn get_pending_overwrite(&self) -> Option<&Path>
+    /// Returns the existing file an export is waiting to overwrite until
+    /// the user confirms it; for a batch export, the first of the files.
+    ///
+    /// # Output
+    /// The path, or `None` if no confirmation is pending.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, ExportFormat, Message };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=12
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// control_tower.set_qbank(qbank);
+    /// let _ = control_tower.update(Message::QuickExam);
+    ///
+    /// let dir = std::env::temp_dir().join("qrate_gui_pending_overwrite");
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// let existing = dir.join("existing.xlsx");
+    /// std::fs::write(&existing, "last week's exam").unwrap();
+    /// let new = dir.join("new.xlsx");
+    /// let _ = std::fs::remove_file(&new);
+    ///
+    /// // An existing file waits for confirmation and is left untouched.
+    /// let _ = control_tower.update(Message::ExportPathChosen(ExportFormat::Excel, Some(existing.clone())));
+    /// assert_eq!(control_tower.get_pending_overwrite(), Some(existing.as_path()));
+    /// assert_eq!(std::fs::read_to_string(&existing).unwrap(), "last week's exam");
+    /// let _ = control_tower.update(Message::CancelOverwrite);
+    /// assert_eq!(control_tower.get_pending_overwrite(), None);
+    ///
+    /// // A new path is exported immediately.
+    /// let _ = control_tower.update(Message::ExportPathChosen(ExportFormat::Excel, Some(new.clone())));
+    /// assert_eq!(control_tower.get_pending_overwrite(), None);
+    /// assert!(new.exists());
+    ///
+    /// // Once confirmed with "don't ask again", later overwrites go through.
+    /// let _ = control_tower.update(Message::ExportPathChosen(ExportFormat::Excel, Some(existing.clone())));
+    /// let _ = control_tower.update(Message::SetDontAskOverwrite(true));
+    /// let _ = control_tower.update(Message::ConfirmOverwrite);
+    /// assert_ne!(std::fs::read(&existing).unwrap(), b"last week's exam");
+    /// let _ = control_tower.update(Message::ExportPathChosen(ExportFormat::Excel, Some(new.clone())));
+    /// assert_eq!(control_tower.get_pending_overwrite(), None);
+    /// ```
+    pub fn get_pending_overwrite(&self) -> Option<&Path>
+    {
+        self.pending_overwrite.as_ref().map(|(path, _)| path.as_path())
+            .or_else(|| self.pending_batch.as_ref().and_then(|(_, files)| files.first()).map(PathBuf::as_path))
+    }
+
+    /// Returns whether an export waits for the user to confirm overwriting.
+    fn is_overwrite_pending(&self) -> bool
+    {
+        self.pending_overwrite.is_some() || self.pending_batch.is_some()
+    }
+
+    // pub fn get_student_group(&self, id: &str) -> &str
+    /// Returns the group of the student with the given id.
+    ///
+    /// # Arguments
+    /// * `id` - The student id.
+    ///
+    /// # Output
+    /// The group name, or an empty string if the student has no group.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// control_tower.update(Message::OpenStudentForm(None));
+    /// control_tower.update(Message::StudentNameChanged("Alice".to_string()));
+    /// control_tower.update(Message::StudentIdChanged("s1".to_string()));
+    /// control_tower.update(Message::StudentGroupChanged("A".to_string()));
+    /// control_tower.update(Message::SubmitStudentForm);
+    /// assert_eq!(control_tower.get_student_group("s1"), "A");
+    /// assert_eq!(control_tower.get_student_group("s2"), "");
+    /// ```
+    pub fn get_student_group(&self, id: &str) -> &str
+    {
+        self.student_groups.get(id).map(String::as_str).unwrap_or("")
+    }
+
+    // pub fn get_student_form(&self) -> Option<&StudentForm>
+    /// Returns the open student form, if the modal is shown.
+    ///
+    /// # Output
+    /// An `Option<&StudentForm>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert!(control_tower.get_student_form().is_none());
+    /// control_tower.update(Message::OpenStudentForm(None));
+    /// assert!(control_tower.get_student_form().is_some());
+    /// control_tower.update(Message::CancelStudentForm);
+    /// assert!(control_tower.get_student_form().is_none());
+    /// ```
+    pub fn get_student_form(&self) -> Option<&StudentForm>
+    {
+        self.student_form.as_ref()
+    }
+
+    // pub fn is_dirty(&self) -> bool
+    /// Returns whether the loaded data has unsaved changes.
+    ///
+    /// # Output
+    /// `true` if there are unsaved changes.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, Message, RosterError };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert!(!control_tower.is_dirty());
+    ///
+    /// // Adding a student grows the list by one and marks the state dirty.
+    /// control_tower.update(Message::OpenStudentForm(None));
+    /// control_tower.update(Message::StudentNameChanged("Alice".to_string()));
+    /// control_tower.update(Message::StudentIdChanged("s1".to_string()));
+    /// control_tower.update(Message::SubmitStudentForm);
+    /// assert_eq!(control_tower.get_sbank().len(), 1);
+    /// assert!(control_tower.is_dirty());
+    ///
+    /// // A duplicate id is rejected and the form stays open with the error.
+    /// control_tower.update(Message::OpenStudentForm(None));
+    /// control_tower.update(Message::StudentNameChanged("Bob".to_string()));
+    /// control_tower.update(Message::StudentIdChanged("s1".to_string()));
+    /// control_tower.update(Message::SubmitStudentForm);
+    /// assert_eq!(control_tower.get_sbank().len(), 1);
+    /// let error = control_tower.get_student_form().unwrap().get_error();
+    /// assert_eq!(error, Some(&RosterError::DuplicateId("s1".to_string())));
+    /// control_tower.update(Message::CancelStudentForm);
+    ///
+    /// // Deleting removes the student.
+    /// control_tower.update(Message::DeleteStudent(0));
+    /// assert!(control_tower.get_sbank().is_empty());
+    /// ```
+    pub fn is_dirty(&self) -> bool
+    {
+        self.dirty
+    }
+
+    // pub fn set_dirty(&mut self, dirty: bool)
+    /// Sets whether the loaded data has unsaved changes.
+    ///
+    /// # Arguments
+    /// * `dirty` - `true` to mark unsaved changes.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ControlTower;
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// control_tower.set_dirty(true);
+    /// assert!(control_tower.is_dirty());
+    /// ```
+    pub fn set_dirty(&mut self, dirty: bool)
+    {
+        self.dirty = dirty;
+    }
+
+    // pub fn get_question_metas(&self) -> &QuestionMetas
+    /// Returns the metadata of the loaded questions, keyed by question id.
+    ///
+    /// # Output
+    /// A reference to the `QuestionMetas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::{ ControlTower, ImageRef, Message };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert!(control_tower.get_question_metas().is_empty());
+    ///
+    /// let image_path = PathBuf::from("figures/triangle.png");
+    /// control_tower.update(Message::QuestionImagePicked(3, image_path.clone()));
+    /// let image = control_tower.get_question_metas()[&3].get_image();
+    /// assert_eq!(image, Some(&ImageRef::Path(image_path)));
+    ///
+    /// control_tower.update(Message::RemoveQuestionImage(3));
+    /// assert!(control_tower.get_question_metas()[&3].get_image().is_none());
+    ///
+    /// // Finished self-study attempts are recorded per question.
+    /// use qrate::{ QBank, Question };
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=10
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![("a".to_string(), true)])); }
+    /// control_tower.set_qbank(qbank);
+    /// for _ in 0..5
+    /// {
+    ///     control_tower.update(Message::SubMenuClicked("take-exam".to_string()));
+    ///     control_tower.update(Message::FinishLearning);
+    ///     control_tower.update(Message::FinishLearning);
+    /// }
+    /// let attempted = control_tower.get_question_metas().values().filter(|meta| meta.get_attempts() > 0).collect::<Vec<_>>();
+    /// assert!(attempted.iter().all(|meta| meta.get_attempts() <= 5));
+    /// let per_session = control_tower.get_learning_session().unwrap().get_exam().get_questions().len() as u32;
+    /// assert_eq!(attempted.iter().map(|meta| meta.get_attempts()).sum::<u32>(), 5 * per_session);
+    ///
+    /// // Nothing was answered, so every question with enough attempts is rated hardest.
+    /// control_tower.update(Message::RecalibrateDifficulty);
+    /// assert!(control_tower.get_question_metas().values()
+    ///             .filter(|meta| meta.get_attempts() >= 5)
+    ///             .all(|meta| meta.get_difficulty() == 5));
+    /// ```
+    pub fn get_question_metas(&self) -> &QuestionMetas
+    {
+        &self.question_metas
+    }
+
+    // pub fn get_variant_count(&self) -> usize
+    /// Returns how many variants a batch export generates.
+    ///
+    /// # Output
+    /// The number of variants.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert_eq!(control_tower.get_variant_count(), 4);
+    /// control_tower.update(Message::SetVariantCount("6".to_string()));
+    /// assert_eq!(control_tower.get_variant_count(), 6);
+    /// control_tower.update(Message::SetVariantCount("six".to_string()));
+    /// assert_eq!(control_tower.get_variant_count(), 6);
+    /// ```
+    pub fn get_variant_count(&self) -> usize
+    {
+        self.variant_count
+    }
+
+    // pub fn is_named_papers(&self) -> bool
+    /// Returns whether the papers printed per student are pre-filled with
+    /// the name and id of the student, rather than left anonymous.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert!(control_tower.is_named_papers());
+    /// let _ = control_tower.update(Message::SetNamedPapers(false));
+    /// assert!(!control_tower.is_named_papers());
+    /// ```
+    pub fn is_named_papers(&self) -> bool
+    {
+        self.named_papers
+    }
+
+    // pub fn get_variant_reports(&self) -> &[VariantReport]
+    /// Returns the per-variant results of the last batch export.
+    ///
+    /// # Output
+    /// A slice of `VariantReport`s, empty before the first batch export.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ControlTower;
+    /// let (control_tower, _) = ControlTower::new();
+    /// assert!(control_tower.get_variant_reports().is_empty());
+    /// ```
+    pub fn get_variant_reports(&self) -> &[VariantReport]
+    {
+        &self.variant_reports
+    }
+
+    // pub fn get_export_progress(&self) -> Option<(usize, usize)>
+    /// Returns the progress `(done, total)` of the running or last batch export.
+    ///
+    /// # Output
+    /// The progress, or `None` before the first batch export.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ generate_variants, ControlTower, ExamCriteria, ExportBatch, ExportFormat, Message };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=3
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(2);
+    /// let variants = generate_variants(&qbank, &criteria, 3).unwrap();
+    /// let dir = std::env::temp_dir().join("qrate_gui_export_progress");
+    /// let _ = std::fs::remove_dir_all(&dir);
+    /// std::fs::create_dir_all(&dir).unwrap();
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert_eq!(control_tower.get_export_progress(), None);
+    /// let _ = control_tower.update(Message::ExportBatchReady(Some(ExportBatch::new(variants.clone(), dir.clone(), ExportFormat::Excel))));
+    ///
+    /// // The runtime hands each progress message back; one paper is written per message.
+    /// for done in 0..=3
+    /// {
+    ///     let _ = control_tower.update(Message::ExportProgress(done, 3));
+    ///     assert_eq!(control_tower.get_export_progress(), Some((done, 3)));
+    /// }
+    /// assert_eq!(control_tower.get_variant_reports().len(), 3);
+    /// assert_eq!(control_tower.get_generation_summary().unwrap().get_papers().len(), 3);
+    ///
+    /// // Exporting into the same folder again asks before replacing the papers.
+    /// let paper = dir.join("exam-A.xlsx");
+    /// std::fs::write(&paper, "marked paper").unwrap();
+    /// let _ = control_tower.update(Message::ExportBatchReady(Some(ExportBatch::new(variants.clone(), dir.clone(), ExportFormat::Excel))));
+    /// assert_eq!(control_tower.get_pending_overwrite(), Some(paper.as_path()));
+    /// let _ = control_tower.update(Message::CancelOverwrite);
+    /// assert_eq!(control_tower.get_pending_overwrite(), None);
+    /// assert_eq!(std::fs::read_to_string(&paper).unwrap(), "marked paper");
+    ///
+    /// let _ = control_tower.update(Message::ExportBatchReady(Some(ExportBatch::new(variants, dir, ExportFormat::Excel))));
+    /// let _ = control_tower.update(Message::ConfirmOverwrite);
+    /// assert_eq!(control_tower.get_pending_overwrite(), None);
+    /// for done in 0..=3
+    ///     { let _ = control_tower.update(Message::ExportProgress(done, 3)); }
+    /// assert_ne!(std::fs::read(&paper).unwrap(), b"marked paper");
+    /// ```
+    pub fn get_export_progress(&self) -> Option<(usize, usize)>
+    {
+        self.export_progress
+    }
+
+    // pub fn get_generation_summary(&self) -> Option<&GenerationSummary>
+    /// Returns the summary of the last batch export.
+    ///
+    /// # Output
+    /// The summary, or `None` before the first batch export.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ControlTower;
+    /// let (control_tower, _) = ControlTower::new();
+    /// assert!(control_tower.get_generation_summary().is_none());
+    /// ```
+    pub fn get_generation_summary(&self) -> Option<&GenerationSummary>
+    {
+        self.generation_summary.as_ref()
+    }
+
+    // pub fn get_learning_session(&self) -> Option<&LearningSession>
+    /// Returns the running or finished learning session, if any.
+    ///
+    /// # Output
+    /// An `Option<&LearningSession>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert!(control_tower.get_learning_session().is_none());
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=10
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![("a".to_string(), true)])); }
+    /// control_tower.set_qbank(qbank);
+    /// control_tower.update(Message::SubMenuClicked("take-exam".to_string()));
+    /// assert_eq!(control_tower.get_current_page(), "learning");
+    ///
+    /// // Flagged questions are listed for jumping back to them.
+    /// control_tower.update(Message::ToggleFlag(3));
+    /// control_tower.update(Message::ToggleFlag(1));
+    /// assert_eq!(control_tower.get_learning_session().unwrap().get_flagged(), vec![1, 3]);
+    /// control_tower.update(Message::ToggleFlag(3));
+    /// assert_eq!(control_tower.get_learning_session().unwrap().get_flagged(), vec![1]);
+    ///
+    /// control_tower.update(Message::ToggleLearningChoice(0));
+    /// control_tower.update(Message::FinishLearning);
+    /// assert_eq!(control_tower.get_current_page(), "grade-report");
+    /// assert_eq!(control_tower.get_learning_session().unwrap().grade().get_total(), 1.0);
+    /// ```
+    pub fn get_learning_session(&self) -> Option<&LearningSession>
+    {
+        self.learning.as_ref()
+    }
+
+    // pub fn get_grading_mode(&self) -> GradingMode
+    /// Returns how multiple-select questions are scored in self-study.
+    ///
+    /// # Output
+    /// The current `GradingMode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, GradingMode, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert_eq!(control_tower.get_grading_mode(), GradingMode::AllOrNothing);
+    /// control_tower.update(Message::SetGradingMode(GradingMode::PartialCredit));
+    /// assert_eq!(control_tower.get_grading_mode(), GradingMode::PartialCredit);
+    /// ```
+    pub fn get_grading_mode(&self) -> GradingMode
+    {
+        self.grading_mode
+    }
+
+    // pub fn get_categories(&self) -> &[Category]
+    /// Returns the categories of the loaded bank offered on the criteria page.
+    ///
+    /// # Output
+    /// The distinct categories, sorted, recomputed whenever a bank is loaded.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, QBank, Question };
+    /// use qrate_gui::{ Category, ControlTower, Message, ResultLoadFile };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert!(control_tower.get_categories().is_empty());
+    ///
+    /// let mut header = Header::new_empty();
+    /// header.set_categories(vec!["Chapter 2".to_string(), "Chapter 1".to_string()]);
+    /// let mut qbank = QBank::new_with_header(header);
+    /// for (id, category) in [(1, 2), (2, 1), (3, 0)]
+    ///     { qbank.push_question(Question::new(id, id, category, format!("Q{}", id), vec![])); }
+    /// let _ = control_tower.update(Message::SetCategoryIncluded(Category::Uncategorized, false));
+    /// let _ = control_tower.update(Message::QBankLoaded(ResultLoadFile::Success(qbank)));
+    ///
+    /// assert_eq!(control_tower.get_categories(), [
+    ///     Category::Named("Chapter 1".to_string()),
+    ///     Category::Named("Chapter 2".to_string()),
+    ///     Category::Uncategorized,
+    /// ]);
+    /// assert!(control_tower.get_exam_criteria().is_category_included(&Category::Uncategorized));
+    /// ```
+    pub fn get_categories(&self) -> &[Category]
+    {
+        &self.categories
+    }
+
+    // pub fn get_arranged_categories(&self) -> Vec<Category>
+    /// Returns the categories of the loaded bank in the order exams grouped
+    /// by category print them.
+    ///
+    /// # Output
+    /// The categories of `get_categories`, in exam order.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, QBank, Question };
+    /// use qrate_gui::{ Category, ControlTower, Message, ResultLoadFile };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut header = Header::new_empty();
+    /// header.set_categories(vec!["Chapter 2".to_string(), "Chapter 1".to_string()]);
+    /// let mut qbank = QBank::new_with_header(header);
+    /// for (id, category) in [(1, 2), (2, 1), (3, 0)]
+    ///     { qbank.push_question(Question::new(id, id, category, format!("Q{}", id), vec![])); }
+    /// let _ = control_tower.update(Message::QBankLoaded(ResultLoadFile::Success(qbank)));
+    ///
+    /// let chapter = |name: &str| Category::Named(name.to_string());
+    /// assert_eq!(control_tower.get_arranged_categories(), [chapter("Chapter 2"), chapter("Chapter 1"), Category::Uncategorized]);
+    /// let _ = control_tower.update(Message::MoveCategory { from: 1, to: 0 });
+    /// assert_eq!(control_tower.get_arranged_categories(), [chapter("Chapter 1"), chapter("Chapter 2"), Category::Uncategorized]);
+    /// let _ = control_tower.update(Message::MoveCategory { from: 0, to: 2 });
+    /// assert_eq!(control_tower.get_arranged_categories(), [chapter("Chapter 2"), Category::Uncategorized, chapter("Chapter 1")]);
+    /// ```
+    pub fn get_arranged_categories(&self) -> Vec<Category>
+    {
+        self.exam_criteria.arrange_categories(self.qbank.get_header(), &self.categories)
+    }
+
+    // pub fn is_difficulty_range_swapped(&self) -> bool
+    /// Returns whether the bounds of the difficulty range were last entered
+    /// backwards and put in order, which the criteria page points out.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Exam, Message };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=5
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// control_tower.set_qbank(qbank);
+    /// for id in 1..=5
+    ///     { let _ = control_tower.update(Message::SetQuestionDifficulty(id, id as u8)); }
+    ///
+    /// // Valid bounds are kept as they are.
+    /// let _ = control_tower.update(Message::SetMinDifficulty(2));
+    /// let _ = control_tower.update(Message::SetMaxDifficulty(3));
+    /// assert_eq!(control_tower.get_exam_criteria().get_difficulty_range(), Some((2, 3)));
+    /// assert!(!control_tower.is_difficulty_range_swapped());
+    ///
+    /// // A lower bound above the upper one is swapped, with a hint.
+    /// let _ = control_tower.update(Message::SetMinDifficulty(5));
+    /// assert_eq!(control_tower.get_exam_criteria().get_difficulty_range(), Some((3, 5)));
+    /// assert!(control_tower.is_difficulty_range_swapped());
+    ///
+    /// // The exam is drawn from the levels in the range only.
+    /// let mut criteria = control_tower.get_exam_criteria().clone();
+    /// criteria.set_selected(3);
+    /// let exam = Exam::generate_with_metas(control_tower.get_qbank(), &criteria, control_tower.get_question_metas()).unwrap();
+    /// let mut ids: Vec<u16> = exam.get_questions().iter().map(|q| q.get_id()).collect();
+    /// ids.sort();
+    /// assert_eq!(ids, vec![3, 4, 5]);
+    ///
+    /// let _ = control_tower.update(Message::ClearDifficultyRange);
+    /// assert_eq!(control_tower.get_exam_criteria().get_difficulty_range(), None);
+    /// assert!(!control_tower.is_difficulty_range_swapped());
+    /// ```
+    pub fn is_difficulty_range_swapped(&self) -> bool
+    {
+        self.difficulty_range_swapped
+    }
+
+    // pub fn get_error_message(&self) -> Option<&str>
+    /// Returns the message of the error dialog, if one is shown.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ catch_panic, ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert_eq!(control_tower.get_error_message(), None);
+    ///
+    /// let failed: Result<(), String> = catch_panic(|| panic!("bad cell"));
+    /// let _ = control_tower.update(Message::TaskFailed(failed.unwrap_err()));
+    /// assert_eq!(control_tower.get_error_message(), Some("bad cell"));
+    ///
+    /// let _ = control_tower.update(Message::DismissError);
+    /// assert_eq!(control_tower.get_error_message(), None);
+    ///
+    /// // Student list > Export does not export the (empty) question bank.
+    /// let _ = control_tower.update(Message::MenuClicked("student-list-management".to_string()));
+    /// let _ = control_tower.update(Message::SubMenuClicked("export".to_string()));
+    /// assert_eq!(control_tower.get_error_message(), None);
+    /// assert!(control_tower.get_file_browser().is_none());
+    /// let _ = control_tower.update(Message::MenuClicked("question-bank-management".to_string()));
+    /// let _ = control_tower.update(Message::SubMenuClicked("export".to_string()));
+    /// assert!(control_tower.get_error_message().is_some());
+    /// ```
+    pub fn get_error_message(&self) -> Option<&str>
+    {
+        self.error_message.as_deref()
+    }
+
+    // pub fn get_toasts(&self) -> &Toasts
+    /// Returns the toasts shown above the status bar.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::{ ControlTower, FocusStop, Message, ResultSaveFile };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let _ = control_tower.update(Message::ExamExported(ResultSaveFile::Unchecked(PathBuf::from("quiz.pdf"))));
+    /// let _ = control_tower.update(Message::ExamExported(ResultSaveFile::Unverified(PathBuf::from("quiz.xlsx"))));
+    /// let _ = control_tower.update(Message::TaskFailed("bad cell".to_string()));
+    /// assert_eq!(control_tower.get_toasts().get_messages().len(), 2);
+    /// assert!(control_tower.get_toasts().get_messages()[0].contains("quiz.xlsx"));
+    ///
+    /// // Esc sends the dismiss message, which clears the error banner first,
+    /// let _ = control_tower.update(Message::DismissNotice);
+    /// assert_eq!(control_tower.get_error_message(), None);
+    /// assert_eq!(control_tower.get_toasts().get_messages().len(), 2);
+    ///
+    /// // then removes the top toast.
+    /// let _ = control_tower.update(Message::DismissNotice);
+    /// assert_eq!(control_tower.get_toasts().get_messages().len(), 1);
+    /// assert!(control_tower.get_toasts().get_messages()[0].contains("quiz.pdf"));
+    ///
+    /// // The close button of the top toast is a stop of the Tab order,
+    /// // right before the status bar.
+    /// let _ = control_tower.update(Message::FocusPreviousMenu);
+    /// let _ = control_tower.update(Message::FocusPreviousMenu);
+    /// assert_eq!(control_tower.get_focus_stop(), Some(FocusStop::Toast));
+    /// let _ = control_tower.update(Message::ActivateFocusedMenu);
+    /// assert!(control_tower.get_toasts().is_empty());
+    /// ```
+    pub fn get_toasts(&self) -> &Toasts
+    {
+        &self.toasts
+    }
+
+    // pub fn get_sort(&self) -> (SortColumn, SortOrder)
+    /// Returns the column and direction the questions table is sorted by.
+    /// Sorting only affects the view; the `QBank` keeps its order.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message, SortColumn, SortOrder };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert_eq!(control_tower.get_sort(), (SortColumn::Id, SortOrder::Ascending));
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=3
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// control_tower.set_qbank(qbank);
+    ///
+    /// let _ = control_tower.update(Message::SortQuestions(SortColumn::Difficulty));
+    /// assert_eq!(control_tower.get_sort(), (SortColumn::Difficulty, SortOrder::Ascending));
+    /// let _ = control_tower.update(Message::SortQuestions(SortColumn::Difficulty));
+    /// assert_eq!(control_tower.get_sort(), (SortColumn::Difficulty, SortOrder::Descending));
+    /// let _ = control_tower.update(Message::SortQuestions(SortColumn::Prompt));
+    /// assert_eq!(control_tower.get_sort(), (SortColumn::Prompt, SortOrder::Ascending));
+    ///
+    /// let ids: Vec<u16> = control_tower.get_qbank().get_questions().iter().map(|q| q.get_id()).collect();
+    /// assert_eq!(ids, vec![1, 2, 3]);
+    /// ```
+    pub fn get_sort(&self) -> (SortColumn, SortOrder)
+    {
+        self.question_filter.get_sort()
+    }
+
+    // pub fn get_question_filter(&self) -> &QuestionFilter
+    /// Returns the search, category, difficulty and sort of the questions
+    /// table. None of them changes the bank.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, QBank, Question };
+    /// use qrate_gui::{ Category, ControlTower, Message, ResultLoadFile, SortColumn };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut header = Header::new_empty();
+    /// header.set_categories(vec!["Algebra".to_string(), "Geometry".to_string()]);
+    /// let mut qbank = QBank::new_with_header(header);
+    /// for id in 1..=30
+    ///     { qbank.push_question(Question::new(id, id, (id % 2 + 1) as u8, format!("Question {}", id), vec![])); }
+    /// let _ = control_tower.update(Message::QBankLoaded(ResultLoadFile::Success(qbank)));
+    /// let _ = control_tower.update(Message::SetQuestionPageSize(25));
+    /// let ids = |control_tower: &ControlTower| -> Vec<u16> {
+    ///     control_tower.get_visible_questions().iter().map(|q| q.get_id()).collect()
+    /// };
+    ///
+    /// let _ = control_tower.update(Message::SetQuestionSearch("question 1".to_string()));
+    /// let _ = control_tower.update(Message::SetQuestionCategoryFilter(Some(Category::Named("Geometry".to_string()))));
+    /// let _ = control_tower.update(Message::SortQuestions(SortColumn::Id));
+    /// assert_eq!(ids(&control_tower), [19, 17, 15, 13, 11, 1]);
+    /// let _ = control_tower.update(Message::SetQuestionDifficultyFilter(Some(4)));
+    /// assert!(ids(&control_tower).is_empty());
+    /// assert!(!control_tower.can_move_questions());
+    ///
+    /// // One click shows the whole bank again, unsorted, from the first page.
+    /// let _ = control_tower.update(Message::ClearQuestionFilters);
+    /// assert!(control_tower.get_question_filter().is_cleared());
+    /// assert_eq!(ids(&control_tower), (1..=25).collect::<Vec<u16>>());
+    /// let _ = control_tower.update(Message::GoToQuestionPage(1));
+    /// assert_eq!(ids(&control_tower), (26..=30).collect::<Vec<u16>>());
+    /// assert!(control_tower.can_move_questions());
+    /// assert_eq!(control_tower.get_qbank().get_questions().len(), 30);
+    /// assert!(!control_tower.is_dirty());
+    /// ```
+    pub fn get_question_filter(&self) -> &QuestionFilter
+    {
+        &self.question_filter
+    }
+
+    // pub fn get_question_page(&self) -> usize
+    /// Returns the 0-based page of the questions table being shown.
+    ///
+    /// # Examples
+    /// See `ControlTower::get_visible_questions`.
+    pub fn get_question_page(&self) -> usize
+    {
+        self.question_page
+    }
+
+    // pub fn get_visible_questions(&self) -> Vec<&Question>
+    /// Returns the questions on the current page of the questions table.
+    ///
+    /// The whole bank is sorted first and then split into pages, so that the
+    /// sort order carries across pages.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message, SortColumn };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=60
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// control_tower.set_qbank(qbank);
+    /// let _ = control_tower.update(Message::SetQuestionPageSize(25));
+    ///
+    /// let ids = |control_tower: &ControlTower| -> Vec<u16> {
+    ///     control_tower.get_visible_questions().iter().map(|q| q.get_id()).collect()
+    /// };
+    /// assert_eq!(ids(&control_tower), (1..=25).collect::<Vec<u16>>());
+    ///
+    /// // Sorted descending, the second page continues where the first stopped.
+    /// let _ = control_tower.update(Message::SortQuestions(SortColumn::Id));
+    /// let _ = control_tower.update(Message::GoToQuestionPage(1));
+    /// assert_eq!(ids(&control_tower), (11..=35).rev().collect::<Vec<u16>>());
+    ///
+    /// // The last page holds the remaining ten; pages past it are clamped.
+    /// let _ = control_tower.update(Message::GoToQuestionPage(7));
+    /// assert_eq!(control_tower.get_question_page(), 2);
+    /// assert_eq!(ids(&control_tower), (1..=10).rev().collect::<Vec<u16>>());
+    /// ```
+    pub fn get_visible_questions(&self) -> Vec<&Question>
+    {
+        let shown = self.question_filter.apply(&self.qbank, &self.question_metas);
+        page_slice(&shown, self.question_page, self.question_page_size).to_vec()
+    }
+
+    // pub fn get_highlighted_question(&self) -> Option<u16>
+    /// Returns the id of the question the questions table last jumped to,
+    /// which is highlighted and scrolled into view.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message, Page, SortColumn };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=300
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![("a".to_string(), true)])); }
+    /// control_tower.set_qbank(qbank);
+    /// let _ = control_tower.update(Message::GoToPage(Page::QuestionBankEdit));
+    /// let _ = control_tower.update(Message::SetQuestionPageSize(50));
+    /// assert_eq!(control_tower.get_highlighted_question(), None);
+    ///
+    /// // Question 237 is on the fifth page.
+    /// let _ = control_tower.update(Message::SetJumpText("237".to_string()));
+    /// let _ = control_tower.update(Message::JumpToQuestion);
+    /// assert_eq!(control_tower.get_highlighted_question(), Some(237));
+    /// assert_eq!(control_tower.get_question_page(), 4);
+    /// assert!(control_tower.get_visible_questions().iter().any(|q| q.get_id() == 237));
+    ///
+    /// // Numbers past the end go to the last question, and are corrected in the box.
+    /// let _ = control_tower.update(Message::SetJumpText("1000".to_string()));
+    /// let _ = control_tower.update(Message::JumpToQuestion);
+    /// assert_eq!(control_tower.get_highlighted_question(), Some(300));
+    /// assert_eq!(control_tower.get_question_page(), 5);
+    /// assert_eq!(control_tower.get_jump_text(), "300");
+    /// let _ = control_tower.update(Message::SetJumpText("0".to_string()));
+    /// let _ = control_tower.update(Message::JumpToQuestion);
+    /// assert_eq!(control_tower.get_highlighted_question(), Some(1));
+    /// assert_eq!(control_tower.get_question_page(), 0);
+    ///
+    /// // The number is the id the row is labelled with, also once the table
+    /// // is sorted and has gaps; a missing id goes to the nearest one.
+    /// let _ = control_tower.update(Message::SelectQuestion(100, true));
+    /// let _ = control_tower.update(Message::DeleteSelectedQuestions);
+    /// let _ = control_tower.update(Message::ConfirmDeleteQuestions);
+    /// let _ = control_tower.update(Message::SortQuestions(SortColumn::Id));
+    /// assert_eq!(control_tower.get_visible_questions()[0].get_id(), 300);
+    /// let _ = control_tower.update(Message::SetJumpText("237".to_string()));
+    /// let _ = control_tower.update(Message::JumpToQuestion);
+    /// assert_eq!(control_tower.get_highlighted_question(), Some(237));
+    /// assert_eq!(control_tower.get_question_page(), 1);
+    /// assert!(control_tower.get_visible_questions().iter().any(|q| q.get_id() == 237));
+    /// let _ = control_tower.update(Message::SetJumpText("100".to_string()));
+    /// let _ = control_tower.update(Message::JumpToQuestion);
+    /// assert_eq!(control_tower.get_highlighted_question(), Some(99));
+    /// assert_eq!(control_tower.get_jump_text(), "99");
+    /// assert_eq!(control_tower.get_question_page(), 4);
+    ///
+    /// // In the learning mode, the box moves to the question of the exam.
+    /// let _ = control_tower.update(Message::SubMenuClicked("take-exam".to_string()));
+    /// let count = control_tower.get_learning_session().unwrap().get_exam().get_questions().len();
+    /// let _ = control_tower.update(Message::SetJumpText("3".to_string()));
+    /// let _ = control_tower.update(Message::JumpToQuestion);
+    /// assert_eq!(control_tower.get_learning_session().unwrap().get_current(), 2);
+    /// let _ = control_tower.update(Message::SetJumpText("999".to_string()));
+    /// let _ = control_tower.update(Message::JumpToQuestion);
+    /// assert_eq!(control_tower.get_learning_session().unwrap().get_current(), count - 1);
+    /// ```
+    pub fn get_highlighted_question(&self) -> Option<u16>
+    {
+        self.highlighted_question
+    }
+
+    // pub fn get_jump_text(&self) -> &str
+    /// Returns the number typed into the "go to" box.
+    ///
+    /// # Examples
+    /// See `ControlTower::get_highlighted_question`.
+    pub fn get_jump_text(&self) -> &str
+    {
+        &self.jump_text
+    }
+
+    // pub fn get_validation_issues(&self) -> Vec<ValidationIssue>
+    /// Returns the problems of the loaded bank, checked afresh, so that an
+    /// issue disappears from the checklist as soon as it is fixed.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message, Page, ValidationIssue };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=30
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![("a".to_string(), id != 25)])); }
+    /// control_tower.set_qbank(qbank);
+    /// assert_eq!(control_tower.get_validation_issues(), [ValidationIssue::MissingAnswer(25)]);
+    ///
+    /// // Going to the question clears the filters and turns to its page.
+    /// let _ = control_tower.update(Message::SetQuestionPageSize(10));
+    /// let _ = control_tower.update(Message::SetQuestionSearch("Q1".to_string()));
+    /// let _ = control_tower.update(Message::ShowQuestion(25));
+    /// assert_eq!(control_tower.get_page(), Page::QuestionBankEdit);
+    /// assert!(control_tower.get_visible_questions().iter().any(|q| q.get_id() == 25));
+    /// ```
+    pub fn get_validation_issues(&self) -> Vec<ValidationIssue>
+    {
+        validate_qbank_with_metas(&self.qbank, &self.question_metas)
+    }
+
+    // pub fn get_item_analysis(&self) -> Vec<ItemStats>
+    /// Returns the difficulty and discrimination of the questions of the
+    /// loaded bank over every finished self-study sitting.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=10
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![("right".to_string(), true)])); }
+    /// control_tower.set_qbank(qbank);
+    /// assert!(control_tower.get_item_analysis().is_empty());
+    ///
+    /// let _ = control_tower.update(Message::SubMenuClicked("take-exam".to_string()));
+    /// let _ = control_tower.update(Message::FinishLearning);
+    /// let stats = control_tower.get_item_analysis();
+    /// assert!(!stats.is_empty());
+    /// assert!(stats.iter().all(|item| item.attempts == 1 && item.difficulty_index == 0.0));
+    /// ```
+    pub fn get_item_analysis(&self) -> Vec<ItemStats>
+    {
+        item_analysis(&self.attempts)
+    }
+
+    // pub fn get_selected_questions(&self) -> &BTreeSet<u16>
+    /// Returns the ids of the questions picked by hand in the questions
+    /// table.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=10
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// control_tower.set_qbank(qbank);
+    ///
+    /// for id in [9, 2, 7, 4, 5, 3]
+    ///     { let _ = control_tower.update(Message::SelectQuestion(id, true)); }
+    /// let _ = control_tower.update(Message::SelectQuestion(3, false));
+    /// assert_eq!(control_tower.get_selected_questions().len(), 5);
+    ///
+    /// // The exam holds exactly the five picked questions, in bank order.
+    /// let _ = control_tower.update(Message::GenerateFromSelection);
+    /// let exam = control_tower.get_exam().unwrap();
+    /// let ids: Vec<u16> = exam.get_questions().iter().map(|q| q.get_id()).collect();
+    /// assert_eq!(ids, [2, 4, 5, 7, 9]);
+    ///
+    /// let _ = control_tower.update(Message::ClearQuestionSelection);
+    /// assert!(control_tower.get_selected_questions().is_empty());
+    /// ```
+    pub fn get_selected_questions(&self) -> &BTreeSet<u16>
+    {
+        &self.selected_questions
+    }
+
+    // pub fn get_export_source(&self) -> ExportSource
+    /// Returns which questions are written when the bank is exported.
+    ///
+    /// # Examples
+    /// See `ControlTower::get_export_questions`.
+    pub fn get_export_source(&self) -> ExportSource
+    {
+        self.export_source
+    }
+
+    // pub fn get_export_questions(&self) -> Vec<&Question>
+    /// Returns the questions the export source chooses, in bank order:
+    /// every question, those the filters of the questions table let
+    /// through, or those picked by hand.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ export_questions, ControlTower, ExportError, ExportSource, LoadFile, Message, ResultLoadFile, ResultSaveFile };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut qbank = QBank::new_with_default();
+    /// for (id, prompt) in [(1, "Solve x"), (2, "Area of a circle"), (3, "Solve for y"), (4, "Volume of a cube")]
+    ///     { qbank.push_question(Question::new(id, id, 1, prompt.to_string(), vec![("a".to_string(), true)])); }
+    /// control_tower.set_qbank(qbank);
+    /// let ids = |control_tower: &ControlTower| -> Vec<u16> {
+    ///     control_tower.get_export_questions().iter().map(|q| q.get_id()).collect()
+    /// };
+    /// assert_eq!(control_tower.get_export_source(), ExportSource::All);
+    /// assert_eq!(ids(&control_tower), [1, 2, 3, 4]);
+    ///
+    /// let _ = control_tower.update(Message::SetQuestionSearch("solve".to_string()));
+    /// let _ = control_tower.update(Message::SetExportSource(ExportSource::Filtered));
+    /// assert_eq!(ids(&control_tower), [1, 3]);
+    ///
+    /// // The exported bank holds only the filtered questions.
+    /// let path = std::env::temp_dir().join("qrate_gui_export_filtered.qbdb");
+    /// let questions = control_tower.get_export_questions();
+    /// export_questions(control_tower.get_qbank().get_header(), &questions, control_tower.get_question_metas(), &path).unwrap();
+    /// let ResultLoadFile::Success(exported) = LoadFile::read_qbank_from_path(&path)
+    ///     else { panic!("the exported bank should load") };
+    /// let exported_ids: Vec<u16> = exported.get_questions().iter().map(|q| q.get_id()).collect();
+    /// assert_eq!(exported_ids, [1, 3]);
+    ///
+    /// for id in [4, 2]
+    ///     { let _ = control_tower.update(Message::SelectQuestion(id, true)); }
+    /// let _ = control_tower.update(Message::SetExportSource(ExportSource::Selected));
+    /// assert_eq!(ids(&control_tower), [2, 4]);
+    ///
+    /// // A failed export is reported rather than only logged.
+    /// let failure = ExportError::Write("Unsupported file extension.".to_string());
+    /// let _ = control_tower.update(Message::QuestionsExported(ResultSaveFile::Failed(failure)));
+    /// assert!(control_tower.get_error_message().is_some());
+    /// ```
+    pub fn get_export_questions(&self) -> Vec<&Question>
+    {
+        let questions = self.qbank.get_questions().iter();
+        match self.export_source
+        {
+            ExportSource::All => questions.collect(),
+            ExportSource::Filtered => questions.filter(|question| self.question_filter.matches(&self.qbank, question, &self.question_metas)).collect(),
+            ExportSource::Selected => questions.filter(|question| self.selected_questions.contains(&question.get_id())).collect(),
+        }
+    }
+
+    // pub fn is_delete_pending(&self) -> bool
+    /// Returns whether the picked questions wait for the user to confirm
+    /// that they are deleted.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=20
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// control_tower.set_qbank(qbank);
+    /// let _ = control_tower.update(Message::SetDeleteConfirmThreshold(5));
+    ///
+    /// // Below the threshold, the questions go directly.
+    /// for id in 1..=4
+    ///     { let _ = control_tower.update(Message::SelectQuestion(id, true)); }
+    /// let _ = control_tower.update(Message::DeleteSelectedQuestions);
+    /// assert!(!control_tower.is_delete_pending());
+    /// assert_eq!(control_tower.get_qbank().get_questions().len(), 16);
+    /// assert!(control_tower.get_selected_questions().is_empty());
+    /// assert!(control_tower.is_dirty());
+    ///
+    /// // At the threshold, the deletion waits for confirmation.
+    /// for id in 5..=9
+    ///     { let _ = control_tower.update(Message::SelectQuestion(id, true)); }
+    /// let _ = control_tower.update(Message::DeleteSelectedQuestions);
+    /// assert!(control_tower.is_delete_pending());
+    /// assert_eq!(control_tower.get_qbank().get_questions().len(), 16);
+    ///
+    /// // Cancelling keeps the questions and the selection.
+    /// let _ = control_tower.update(Message::CancelDeleteQuestions);
+    /// assert!(!control_tower.is_delete_pending());
+    /// assert_eq!(control_tower.get_selected_questions().len(), 5);
+    ///
+    /// let _ = control_tower.update(Message::DeleteSelectedQuestions);
+    /// let _ = control_tower.update(Message::ConfirmDeleteQuestions);
+    /// assert!(!control_tower.is_delete_pending());
+    /// assert_eq!(control_tower.get_qbank().get_questions().len(), 11);
+    /// assert_eq!(control_tower.get_qbank().get_questions()[0].get_id(), 10);
+    /// ```
+    pub fn is_delete_pending(&self) -> bool
+    {
+        self.pending_delete.is_some()
+    }
+
+    // pub fn is_merge_pending(&self) -> bool
+    /// Returns whether a category is being renamed to the name of another
+    /// one and the user is asked whether to merge the two.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, QBank, Question };
+    /// use qrate_gui::{ Category, ControlTower, Message, ResultLoadFile };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut header = Header::new_empty();
+    /// header.set_categories(vec!["Ch3".to_string(), "Algebra".to_string(), "Trig".to_string()]);
+    /// let mut qbank = QBank::new_with_header(header);
+    /// for (id, category) in [(1, 1), (2, 2), (3, 1), (4, 3)]
+    ///     { qbank.push_question(Question::new(id, id, category, format!("Q{}", id), vec![])); }
+    /// let _ = control_tower.update(Message::QBankLoaded(ResultLoadFile::Success(qbank)));
+    /// let named = |name: &str| Category::Named(name.to_string());
+    /// let category_of = |control_tower: &ControlTower, id: usize| {
+    ///     let qbank = control_tower.get_qbank();
+    ///     Category::of(qbank.get_header(), &qbank.get_questions()[id - 1])
+    /// };
+    ///
+    /// // Every question of the category is renamed at once.
+    /// let _ = control_tower.update(Message::SelectCategoryToRename("Ch3".to_string()));
+    /// let _ = control_tower.update(Message::SetCategoryNewName("Trigonometry".to_string()));
+    /// let _ = control_tower.update(Message::RenameCategory);
+    /// assert!(control_tower.is_dirty());
+    /// assert_eq!(category_of(&control_tower, 1), named("Trigonometry"));
+    /// assert_eq!(category_of(&control_tower, 3), named("Trigonometry"));
+    /// assert_eq!(control_tower.get_categories(), [named("Algebra"), named("Trig"), named("Trigonometry")]);
+    ///
+    /// // A name in use asks whether to merge the two categories.
+    /// let _ = control_tower.update(Message::SelectCategoryToRename("Trig".to_string()));
+    /// let _ = control_tower.update(Message::SetCategoryNewName("Trigonometry".to_string()));
+    /// let _ = control_tower.update(Message::RenameCategory);
+    /// assert!(control_tower.is_merge_pending());
+    /// let _ = control_tower.update(Message::CancelMergeCategory);
+    /// assert!(!control_tower.is_merge_pending());
+    /// assert_eq!(category_of(&control_tower, 4), named("Trig"));
+    ///
+    /// let _ = control_tower.update(Message::RenameCategory);
+    /// let _ = control_tower.update(Message::ConfirmMergeCategory);
+    /// assert!(!control_tower.is_merge_pending());
+    /// for id in [1, 3, 4]
+    ///     { assert_eq!(category_of(&control_tower, id), named("Trigonometry")); }
+    /// assert_eq!(control_tower.get_categories(), [named("Algebra"), named("Trigonometry")]);
+    /// ```
+    pub fn is_merge_pending(&self) -> bool
+    {
+        self.pending_merge.is_some()
+    }
+
+    // pub fn get_prompt_preview(&self, prompt: &str) -> Option<String>
+    /// Returns the shortened prompt shown in the questions table, if the
+    /// prompt is longer than the configured length. The full prompt is
+    /// then shown as a tooltip.
+    ///
+    /// # Arguments
+    /// * `prompt` - The full prompt.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, Message, DEFAULT_PROMPT_LENGTH };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let prompt = "x".repeat(100);
+    /// let preview = control_tower.get_prompt_preview(&prompt).unwrap();
+    /// assert_eq!(preview, format!("{}…", "x".repeat(DEFAULT_PROMPT_LENGTH)));
+    ///
+    /// let _ = control_tower.update(Message::SetPromptLength(120));
+    /// assert_eq!(control_tower.get_settings().get_prompt_length(), 120);
+    /// assert_eq!(control_tower.get_prompt_preview(&prompt), None);
+    /// ```
+    pub fn get_prompt_preview(&self, prompt: &str) -> Option<String>
+    {
+        let preview = truncate_prompt(prompt, self.settings.get_prompt_length());
+        (preview != prompt).then_some(preview)
+    }
+
+    // pub fn can_move_questions(&self) -> bool
+    /// Returns whether the questions table shows the order of the bank, so
+    /// that its move-up and move-down buttons are offered.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message, SortColumn };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=4
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// control_tower.set_qbank(qbank);
+    /// assert!(control_tower.can_move_questions());
+    ///
+    /// let ids = |control_tower: &ControlTower| -> Vec<u16> {
+    ///     control_tower.get_qbank().get_questions().iter().map(|q| q.get_id()).collect()
+    /// };
+    /// let _ = control_tower.update(Message::MoveQuestion { from: 3, to: 0 });
+    /// assert_eq!(ids(&control_tower), vec![4, 1, 2, 3]);
+    /// assert!(control_tower.is_dirty());
+    /// let _ = control_tower.update(Message::MoveQuestion { from: 1, to: 2 });
+    /// assert_eq!(ids(&control_tower), vec![4, 2, 1, 3]);
+    ///
+    /// // Positions past the end of the bank are ignored.
+    /// let _ = control_tower.update(Message::MoveQuestion { from: 0, to: 4 });
+    /// let _ = control_tower.update(Message::MoveQuestion { from: 7, to: 0 });
+    /// assert_eq!(ids(&control_tower), vec![4, 2, 1, 3]);
+    ///
+    /// // The table follows the new order, and other sorts hide the buttons.
+    /// let visible: Vec<u16> = control_tower.get_visible_questions().iter().map(|q| q.get_id()).collect();
+    /// assert_eq!(visible, vec![4, 2, 1, 3]);
+    /// let _ = control_tower.update(Message::SortQuestions(SortColumn::Prompt));
+    /// assert!(!control_tower.can_move_questions());
+    /// ```
+    pub fn can_move_questions(&self) -> bool
+    {
+        self.question_filter.is_cleared()
+    }
+
+    // pub fn can_open_containing_folder(&self) -> bool
+    /// Returns whether the "open containing folder" action is available,
+    /// which requires a selected file.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert!(!control_tower.can_open_containing_folder());
+    ///
+    /// let _ = control_tower.update(Message::FileSelected(PathBuf::from("/banks/math.qbdb")));
+    /// assert!(control_tower.can_open_containing_folder());
+    /// ```
+    pub fn can_open_containing_folder(&self) -> bool
+    {
+        get_containing_folder(&self.selected_file_path).is_some()
+    }
+
+    // pub fn get_focused_menu(&self) -> Option<usize>
+    /// Returns the 0-based index of the main menu button holding the
+    /// keyboard focus, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert_eq!(control_tower.get_focused_menu(), None);
+    ///
+    /// let _ = control_tower.update(Message::FocusNextMenu);
+    /// let _ = control_tower.update(Message::FocusNextMenu);
+    /// assert_eq!(control_tower.get_focused_menu(), Some(1));
+    /// let _ = control_tower.update(Message::ActivateFocusedMenu);
+    /// assert_eq!(control_tower.get_current_menu_key(), "generate-exam-paper");
+    ///
+    /// // Focus wraps around in both directions, through the status bar.
+    /// let _ = control_tower.update(Message::FocusPreviousMenu);
+    /// let _ = control_tower.update(Message::FocusPreviousMenu);
+    /// assert_eq!(control_tower.get_focused_menu(), None);
+    /// let _ = control_tower.update(Message::FocusPreviousMenu);
+    /// assert_eq!(control_tower.get_focused_menu(), Some(5));
+    /// ```
+    pub fn get_focused_menu(&self) -> Option<usize>
+    {
+        self.focus_stop.filter(|&index| index < MENU_KEYS.len())
+    }
+
+    // pub fn get_focus_stop(&self) -> Option<FocusStop>
+    /// Returns the stop of the Tab order of the current page holding the
+    /// keyboard focus, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ get_tab_order, ControlTower, FocusStop, Message, Page };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let _ = control_tower.update(Message::GoToPage(Page::GradingCriteria));
+    ///
+    /// // Tab visits the menu bar, the letter scale field, then the status bar.
+    /// let mut visited = Vec::new();
+    /// for _ in 0..get_tab_order(Page::GradingCriteria).len()
+    /// {
+    ///     let _ = control_tower.update(Message::FocusNextMenu);
+    ///     visited.push(control_tower.get_focus_stop().unwrap());
+    /// }
+    /// assert_eq!(visited, get_tab_order(Page::GradingCriteria));
+    /// assert_eq!(visited[6], FocusStop::Input("letter-scale"));
+    ///
+    /// // Leaving the page keeps the focus only on the menu bar.
+    /// let _ = control_tower.update(Message::GoToPage(Page::Main));
+    /// assert_eq!(control_tower.get_focus_stop(), None);
+    /// let _ = control_tower.update(Message::FocusPreviousMenu);
+    /// assert_eq!(control_tower.get_focus_stop(), Some(FocusStop::StatusBar));
+    /// let _ = control_tower.update(Message::FocusPreviousMenu);
+    /// let _ = control_tower.update(Message::GoToPage(Page::Diagnostics));
+    /// assert_eq!(control_tower.get_focus_stop(), Some(FocusStop::Menu("information")));
+    /// ```
+    pub fn get_focus_stop(&self) -> Option<FocusStop>
+    {
+        self.focus_stop.and_then(|index| self.get_current_tab_order().get(index).copied())
+    }
+
+    // fn get_current_tab_order(&self) -> Vec<FocusStop>
+    /// Returns the Tab order of the current page, with the close button of
+    /// the top toast before the status bar while a toast is shown.
+    fn get_current_tab_order(&self) -> Vec<FocusStop>
+    {
+        let mut order = get_tab_order(self.current_page);
+        if !self.toasts.is_empty()
+            { order.insert(order.len() - 1, FocusStop::Toast); }
+        order
+    }
+
+    // pub fn get_modal_focus(&self) -> Option<usize>
+    /// Returns the 0-based index, in tab order, of the control of the open
+    /// modal holding the keyboard focus, if any.
+    ///
+    /// While a modal is open, Tab and Shift+Tab cycle through its controls
+    /// only and never reach the main menu underneath it.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let _ = control_tower.update(Message::OpenStudentForm(None));
+    /// assert_eq!(control_tower.get_modal_focus(), None);
+    ///
+    /// // Three inputs, then Save and Cancel: Tab from Cancel wraps to the first input.
+    /// for expected in [0, 1, 2, 3, 4, 0]
+    /// {
+    ///     let _ = control_tower.update(Message::FocusNextMenu);
+    ///     assert_eq!(control_tower.get_modal_focus(), Some(expected));
+    ///     assert_eq!(control_tower.get_focused_menu(), None);
+    /// }
+    /// let _ = control_tower.update(Message::FocusPreviousMenu);
+    /// assert_eq!(control_tower.get_modal_focus(), Some(4));
+    ///
+    /// // Activating Cancel closes the form and releases the trap.
+    /// let _ = control_tower.update(Message::ActivateFocusedMenu);
+    /// assert!(control_tower.get_student_form().is_none());
+    /// assert_eq!(control_tower.get_modal_focus(), None);
+    /// let _ = control_tower.update(Message::FocusNextMenu);
+    /// assert_eq!(control_tower.get_focused_menu(), Some(0));
+    /// ```
+    pub fn get_modal_focus(&self) -> Option<usize>
+    {
+        self.modal_focus
+    }
+
+    // pub fn subscription(&self) -> Subscription<Message>
+    /// Returns the subscriptions of the application: a one-second tick
+    /// while a learning session is running, a slower tick checking for
+    /// inactivity while the idle lock is on, the autosave tick while
+    /// autosave is on, and the keys that move the keyboard focus through
+    /// the main menu.
+    ///
+    /// # Output
+    /// A `Subscription<Message>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ControlTower;
+    /// let (control_tower, _) = ControlTower::new();
+    /// let _subscription = control_tower.subscription();
+    /// ```
+    pub fn subscription(&self) -> Subscription<Message>
+    {
+        Subscription::batch([ self.tick_subscription(), keyboard::listen().filter_map(ControlTower::get_focus_message) ])
+    }
+
+    // pub fn tick_subscription(&self) -> Subscription<Message>
+    /// Returns the one-second tick of a running learning session, the tick
+    /// of the idle lock and the autosave tick, without the keyboard
+    /// navigation, which a `Workspace` routes per window.
+    ///
+    /// # Output
+    /// A `Subscription<Message>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ControlTower;
+    /// let (control_tower, _) = ControlTower::new();
+    /// let _tick = control_tower.tick_subscription();
+    /// ```
+    pub fn tick_subscription(&self) -> Subscription<Message>
+    {
+        let learning = if self.is_timer_live()
+            { time::every(Duration::from_secs(1)).map(Message::LearningTick) }
+        else
+            { Subscription::none() };
+        let idle = if self.settings.is_idle_lock_enabled() && !self.is_locked()
+            { time::every(Duration::from_secs(10)).map(Message::IdleTick) }
+        else
+            { Subscription::none() };
+        // The operating system reports changes where it can; polling catches the rest.
+        let appearance = if self.settings.get_theme() == ThemeChoice::System
+            {
+                Subscription::batch([
+                    iced::system::theme_changes().map(Message::SystemThemeChanged),
+                    time::every(Duration::from_secs(THEME_POLL_SECONDS)).map(Message::PollSystemTheme),
+                ])
+            }
+        else
+            { Subscription::none() };
+        let toasts = if !self.toasts.is_empty()
+            { time::every(Duration::from_secs(1)).map(Message::ToastTick) }
+        else
+            { Subscription::none() };
+        let autosave = match self.get_autosave_interval()
+        {
+            Some(interval) => time::every(interval).map(Message::AutosaveTick),
+            None => Subscription::none(),
+        };
+        Subscription::batch([ learning, idle, appearance, toasts, autosave ])
+    }
+
+    // pub fn get_autosave_interval(&self) -> Option<Duration>
+    /// Returns how often `tick_subscription` saves an edited question bank,
+    /// as set in the settings.
+    ///
+    /// # Output
+    /// The interval, or `None` if autosave is off and no autosave tick is
+    /// subscribed to.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::{ Duration, Instant };
+    /// use qrate_gui::{ ControlTower, Message };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert_eq!(control_tower.get_autosave_interval(), None);
+    /// let _ = control_tower.update(Message::SetAutosaveMinutes(Some(5)));
+    /// assert_eq!(control_tower.get_autosave_interval(), Some(Duration::from_secs(300)));
+    /// assert_eq!(control_tower.get_settings().get_autosave_minutes(), Some(5));
+    /// let _ = control_tower.update(Message::SetAutosaveMinutes(Some(1)));
+    /// assert_eq!(control_tower.get_autosave_interval(), Some(Duration::from_secs(60)));
+    /// let _ = control_tower.update(Message::SetAutosaveMinutes(None));
+    /// assert_eq!(control_tower.get_autosave_interval(), None);
+    ///
+    /// // Without a bank file to write to, the changes stay unsaved.
+    /// control_tower.set_dirty(true);
+    /// let _ = control_tower.update(Message::AutosaveTick(Instant::now()));
+    /// assert!(control_tower.is_dirty());
+    ///
+    /// // A bank that cannot be written pauses autosave without a dialog.
+    /// control_tower.set_selected_file_path(std::env::temp_dir().join("qrate_gui_autosave.txt"));
+    /// let _ = control_tower.update(Message::AutosaveTick(Instant::now()));
+    /// assert!(control_tower.is_dirty());
+    /// assert!(control_tower.is_autosave_paused());
+    /// assert_eq!(control_tower.get_error_message(), None);
+    /// assert!(!control_tower.get_toasts().is_empty());
+    /// ```
+    pub fn get_autosave_interval(&self) -> Option<Duration>
+    {
+        self.settings.get_autosave_minutes().map(|minutes| Duration::from_secs(minutes * 60))
+    }
+
+    // pub fn is_autosave_paused(&self) -> bool
+    /// Returns whether autosave has stopped after failing to write the bank.
+    /// It resumes once the bank is saved by hand or another bank is loaded.
+    ///
+    /// See `ControlTower::get_autosave_interval`.
+    pub fn is_autosave_paused(&self) -> bool
+    {
+        self.autosave_paused
+    }
+
+    // pub fn get_theme(&self) -> Theme
+    /// Returns the theme the window is drawn with: the one picked in the
+    /// settings, or the appearance of the operating system for
+    /// `ThemeChoice::System`.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::Theme;
+    /// use iced::theme::Mode;
+    /// use qrate_gui::{ ControlTower, Message, ThemeChoice };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let _ = control_tower.update(Message::SetTheme(ThemeChoice::System));
+    /// let _ = control_tower.update(Message::SystemThemeChanged(Mode::Dark));
+    /// assert_eq!(control_tower.get_system_theme(), Mode::Dark);
+    /// assert_eq!(control_tower.get_theme(), Theme::Dark);
+    ///
+    /// // The operating system switches to light at sunrise.
+    /// let _ = control_tower.update(Message::SystemThemeChanged(Mode::Light));
+    /// assert_eq!(control_tower.get_theme(), Theme::Light);
+    ///
+    /// // An explicit choice ignores the operating system.
+    /// let _ = control_tower.update(Message::SetTheme(ThemeChoice::Dark));
+    /// assert_eq!(control_tower.get_theme(), Theme::Dark);
+    /// assert_eq!(control_tower.get_settings().get_theme(), ThemeChoice::Dark);
+    /// ```
+    pub fn get_theme(&self) -> Theme
+    {
+        self.settings.get_theme().resolve(self.system_theme)
+    }
+
+    // pub fn get_system_theme(&self) -> Mode
+    /// Returns the last known appearance of the operating system, or
+    /// `Mode::None` before it has been reported.
+    ///
+    /// # Examples
+    /// See `ControlTower::get_theme`.
+    pub fn get_system_theme(&self) -> Mode
+    {
+        self.system_theme
+    }
+
+    // pub fn set_system_theme(&mut self, mode: Mode)
+    /// Sets the known appearance of the operating system, such as for a
+    /// new window that has not been told yet.
+    ///
+    /// # Arguments
+    /// * `mode` - The appearance.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::Theme;
+    /// use iced::theme::Mode;
+    /// use qrate_gui::ControlTower;
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// control_tower.set_system_theme(Mode::Dark);
+    /// assert_eq!(control_tower.get_theme(), Theme::Dark);
+    /// ```
+    pub fn set_system_theme(&mut self, mode: Mode)
+    {
+        self.system_theme = mode;
+    }
+
+    // pub fn is_locked(&self) -> bool
+    /// Returns whether the screen is locked after a period of inactivity,
+    /// hiding everything until the PIN is typed.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::{ Duration, Instant };
+    /// use qrate_gui::{ ControlTower, Message, Page };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let _ = control_tower.update(Message::SetIdleLockMinutes(Some(5)));
+    /// let _ = control_tower.update(Message::PinTextChanged("2468".to_string()));
+    /// let _ = control_tower.update(Message::SetIdleLockPin);
+    /// let _ = control_tower.update(Message::GoToPage(Page::StudentList));
+    /// let start = Instant::now();
+    ///
+    /// // Four minutes of quiet are not enough.
+    /// let _ = control_tower.update(Message::IdleTick(start + Duration::from_secs(240)));
+    /// assert!(!control_tower.is_locked());
+    ///
+    /// // Any activity starts the countdown again.
+    /// std::thread::sleep(std::time::Duration::from_millis(10));
+    /// let _ = control_tower.update(Message::MenuClicked("settings".to_string()));
+    /// let _ = control_tower.update(Message::IdleTick(start + Duration::from_secs(300)));
+    /// assert!(!control_tower.is_locked());
+    ///
+    /// // Five quiet minutes after the last activity lock the screen.
+    /// let _ = control_tower.update(Message::IdleTick(Instant::now() + Duration::from_secs(300)));
+    /// assert!(control_tower.is_locked());
+    /// assert_eq!(control_tower.get_page(), Page::Locked);
+    ///
+    /// // The menus stay shut, and only the right PIN unlocks.
+    /// let _ = control_tower.update(Message::GoToPage(Page::Main));
+    /// let _ = control_tower.update(Message::PinTextChanged("1357".to_string()));
+    /// let _ = control_tower.update(Message::Unlock);
+    /// assert!(control_tower.is_locked());
+    /// let _ = control_tower.update(Message::PinTextChanged("2468".to_string()));
+    /// let _ = control_tower.update(Message::Unlock);
+    /// assert!(!control_tower.is_locked());
+    /// assert_eq!(control_tower.get_page(), Page::StudentList);
+    /// ```
+    pub fn is_locked(&self) -> bool
+    {
+        self.locked_page.is_some()
+    }
+
+    // pub fn is_timer_live(&self) -> bool
+    /// Returns whether the timer of the learning page counts up every
+    /// second. With reduced motion it only moves when the learner goes to
+    /// another question; the time spent is counted all the same.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, Message };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=12
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// control_tower.set_qbank(qbank);
+    /// assert!(!control_tower.is_timer_live());
+    ///
+    /// let _ = control_tower.update(Message::SubMenuClicked("take-exam".to_string()));
+    /// assert!(control_tower.is_timer_live());
+    ///
+    /// // Leaving the learning page stops the clock until the learner is back.
+    /// use qrate_gui::Page;
+    /// let _ = control_tower.update(Message::GoToPage(Page::Main));
+    /// assert!(!control_tower.is_timer_live());
+    /// assert!(control_tower.get_learning_session().unwrap().is_timer_paused());
+    /// let _ = control_tower.update(Message::GoToPage(Page::Learning));
+    /// assert!(control_tower.is_timer_live());
+    /// assert!(!control_tower.get_learning_session().unwrap().is_timer_paused());
+    ///
+    /// let _ = control_tower.update(Message::SetReduceMotion(true));
+    /// assert!(control_tower.get_settings().get_reduce_motion());
+    /// assert!(!control_tower.is_timer_live());
+    /// ```
+    pub fn is_timer_live(&self) -> bool
+    {
+        !self.settings.get_reduce_motion()
+            && self.current_page == Page::Learning
+            && self.learning.as_ref().is_some_and(|session| !session.is_finished())
+    }
+
+    // pub fn title(&self) -> String
+    /// Returns the window title: the name of the loaded file, if any,
+    /// prefixed with `*` while there are unsaved changes. While a dialog
+    /// or a main menu button has the keyboard focus, its spoken name from
+    /// `get_focus_announcement` comes first, so that screen readers
+    /// announce it.
+    ///
+    /// # Output
+    /// The window title.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::{ ControlTower, Message };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert_eq!(control_tower.title(), "Qrate");
+    ///
+    /// control_tower.set_selected_file_path(PathBuf::from("/banks/math.qbdb"));
+    /// assert_eq!(control_tower.title(), "math.qbdb - Qrate");
+    ///
+    /// control_tower.set_dirty(true);
+    /// assert_eq!(control_tower.title(), "* math.qbdb - Qrate");
+    ///
+    /// control_tower.set_dirty(false);
+    /// assert!(!control_tower.title().contains('*'));
+    ///
+    /// // The focused element is announced through the title.
+    /// let _ = control_tower.update(Message::SetLocale("en".to_string()));
+    /// let _ = control_tower.update(Message::FocusNextMenu);
+    /// assert_eq!(control_tower.title(), "Question Bank Management, menu - math.qbdb - Qrate");
+    /// let _ = control_tower.update(Message::OpenStudentForm(None));
+    /// assert_eq!(control_tower.title(), "Add Student, dialog - math.qbdb - Qrate");
+    /// ```
+    pub fn title(&self) -> String
+    {
+        let marker = if self.dirty { "* " } else { "" };
+        let title = match self.selected_file_path.file_name()
+        {
+            Some(name) => format!("{}{} - Qrate", marker, name.to_string_lossy()),
+            None => format!("{}Qrate", marker),
+        };
+        match self.get_focus_announcement()
+        {
+            Some(announcement) => format!("{} - {}", announcement, title),
+            None => title,
+        }
+    }
+
+    // pub fn status_text(&self) -> String
+    /// Returns the text of the status bar: the selected file and, while
+    /// there are unsaved changes, a `●` marker.
+    ///
+    /// # Output
+    /// The status bar text in the current locale.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ControlTower;
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert!(!control_tower.status_text().contains('●'));
+    /// control_tower.set_dirty(true);
+    /// assert!(control_tower.status_text().contains('●'));
+    /// ```
+    pub fn status_text(&self) -> String
+    {
+        let file = if self.selected_file_path.as_os_str().is_empty()
+            { t!("no-file-selected").to_string() }
+        else
+            { t!("selected-file", path = &self.selected_file_path.to_string_lossy()).to_string() };
+        if self.dirty
+            { format!("{}  ● {}", file, t!("unsaved-changes")) }
+        else
+            { file }
+    }
+
+    // pub fn get_primary_font(&self) -> Font
+    /// Returns the primary font of the current locale.
+    ///
+    /// # Output
+    /// The `primary_font` of the current locale, which skips the families
+    /// whose bundled file failed to load.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::Font;
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// control_tower.update(Message::SetLocale("ko".to_string()));
+    /// assert_eq!(control_tower.get_primary_font(), Font::with_name("Noto Sans CJK KR"));
+    /// ```
+    pub fn get_primary_font(&self) -> Font
+    {
+        primary_font(&self.current_locale, &self.font_failures)
+    }
+
+    // pub fn set_font_failures(&mut self, failed: Vec<PathBuf>)
+    /// Records the bundled font files that failed to load at startup.
+    ///
+    /// # Arguments
+    /// * `failed` - The font files that could not be loaded.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use iced::Font;
+    /// use qrate_gui::{ ControlTower, Message };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// assert!(!control_tower.has_font_warning());
+    /// control_tower.set_font_failures(vec![PathBuf::from("fonts/NotoSansCJKkr-Regular.otf")]);
+    /// assert!(control_tower.has_font_warning());
+    /// control_tower.update(Message::SetLocale("ko".to_string()));
+    /// assert_eq!(control_tower.get_primary_font(), Font::with_name("Noto Sans KR"));
+    /// // Locales that do not use the failed font keep theirs.
+    /// control_tower.update(Message::SetLocale("en".to_string()));
+    /// assert_eq!(control_tower.get_primary_font(), Font::with_name("Noto Sans"));
+    /// ```
+    pub fn set_font_failures(&mut self, failed: Vec<PathBuf>)
+    {
+        self.font_failures = failed;
+    }
+
+    // pub fn has_font_warning(&self) -> bool
+    /// Returns whether a bundled font failed to load, so that the locales
+    /// using it fall back to the next font of their chain.
+    ///
+    /// # Examples
+    /// See `ControlTower::set_font_failures`.
+    pub fn has_font_warning(&self) -> bool
+    {
+        !self.font_failures.is_empty()
+    }
+
+    // pub(crate) fn warn_font_failures(&mut self)
+    /// Shows the fonts that failed to load in the error dialog, once at
+    /// startup of the main window.
+    pub(crate) fn warn_font_failures(&mut self)
+    {
+        if !self.has_font_warning()
+            { return; }
+        let files: Vec<String> = self.font_failures.iter().map(|path| path.display().to_string()).collect();
+        let _ = self.show_error(t!("font-load-failed", files = files.join(", ")).to_string());
+    }
+
+    // pub fn warn_self_check_failure(&mut self, error: &SelfCheckError)
+    /// Warns in the error dialog that the linked `qrate` did not read the
+    /// bundled fixtures as expected, once at startup of the main window. A
+    /// warning already shown, such as about fonts, is kept above it.
+    ///
+    /// # Arguments
+    /// * `error` - What the self-check found.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ BankFormat, ControlTower, SelfCheckError };
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// let error = SelfCheckError::Mismatch(BankFormat::Excel, "1 questions instead of 2".to_string());
+    /// control_tower.warn_self_check_failure(&error);
+    /// assert!(control_tower.get_error_message().unwrap().contains("1 questions instead of 2"));
+    /// ```
+    pub fn warn_self_check_failure(&mut self, error: &SelfCheckError)
+    {
+        let warning = t!("self-check-failed", reason = error).to_string();
+        let message = match self.error_message.take()
+        {
+            Some(shown) => format!("{}\n\n{}", shown, warning),
+            None => warning,
+        };
+        let _ = self.show_error(message);
+    }
+
+    fn menu_button(&self, key: &str, on_press: Option<Message>) -> MenuButton<Message>
+    {
+        localized_menu_button(key, on_press, self.menu_font_size_in_pixel, self.get_primary_font())
+            .reduce_motion(self.settings.get_reduce_motion())
+    }
+
+    // pub fn update(&mut self, message: Message) -> Task<Message>
+    /// Handles incoming messages and updates the state of the [ControlTower].
+    ///
+    /// # Arguments
+    /// * `message` - The [Message] to be processed.
+    ///
+    /// # Output
+    /// An [iced::Task] that may produce further messages.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::Task;
+    /// use qrate_gui::{ ControlTower, Message, Page };
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    ///
+    /// // Test Message::MenuClicked
+    /// control_tower.update(Message::MenuClicked("settings".to_string()));
+    /// assert_eq!(control_tower.get_current_menu_key(), "settings");
+    ///
+    /// control_tower.update(Message::MenuClicked("settings".to_string())); // Click again to close
+    /// assert!(control_tower.get_current_menu_key().is_empty());
+    ///
+    /// // Test Message::SetLocale
+    /// control_tower.update(Message::SetLocale("ko-KR".to_string()));
+    /// assert_eq!(control_tower.get_current_locale(), "ko-KR");
+    ///
+    /// // Test Message::GoToPage
+    /// control_tower.update(Message::GoToPage(Page::LanguageSettings));
+    /// assert_eq!(control_tower.get_current_page(), "language-settings");
+    /// ```
+    pub fn update(&mut self, message: Message) -> Task<Message>
+    {
+        if self.is_locked() && ControlTower::is_blocked_while_locked(&message)
+            { return Task::none(); }
+        // The ticks come from timers, not from the user.
+        if !matches!(message, Message::LearningTick(_) | Message::IdleTick(_) | Message::AutosaveTick(_) | Message::ToastTick(_) | Message::SystemThemeChanged(_) | Message::PollSystemTheme(_))
+            { self.last_activity = Instant::now(); }
+
+        match message
+        {
+            Message::MenuClicked(menu_key) => self.click_menu(menu_key),
+            Message::SubMenuClicked(sub_item_key) => self.click_submenu(sub_item_key),
+            Message::FileSelected(path) => if path.as_os_str().is_empty()
+                { self.select_file(path) }
+            else
+                { self.request_load(PendingLoad::File(path)) },
+            Message::QBankLoaded(result) => self.load_qbank(result),
+            Message::SetLocale(locale) => self.set_locale(locale),
+            Message::CycleLocale => self.cycle_locale(),
+            Message::CopyDiagnostics => iced::clipboard::write(diagnostics_text()),
+            Message::CopyQuestion(id) => self.copy_question(id),
+            Message::GoToPage(page) => self.go_to_page(page),
+            Message::SetNumberingStyle(style) => self.set_numbering_style(style),
+            Message::SetChoicesPerRow(count) => {
+                let locale = self.current_locale.clone();
+                let task = self.edit_settings(|settings| settings.set_choices_per_row(&locale, count));
+                self.apply_export_settings();
+                task
+            },
+            Message::SetAnswerSpaceLines(lines) => {
+                let task = self.edit_settings(|settings| {
+                    let mut space = settings.get_answer_space().clone();
+                    space.set_lines(lines);
+                    settings.set_answer_space(space);
+                });
+                self.apply_export_settings();
+                task
+            },
+            Message::SetKindAnswerSpace(kind, lines) => {
+                let task = self.edit_settings(|settings| {
+                    let mut space = settings.get_answer_space().clone();
+                    space.set_kind_lines(kind, lines);
+                    settings.set_answer_space(space);
+                });
+                self.apply_export_settings();
+                task
+            },
+            Message::SetPageHeader(header) => self.edit_page_template(|template| template.set_header(header)),
+            Message::SetPageFooter(footer) => self.edit_page_template(|template| template.set_footer(footer)),
+            Message::SetPageLogo(logo) => {
+                let logo = Some(logo).filter(|logo| !logo.trim().is_empty()).map(PathBuf::from);
+                self.edit_page_template(|template| template.set_logo(logo))
+            },
+            Message::SetPaperSize(paper_size) => self.edit_page_template(|template| template.set_paper_size(paper_size)),
+            Message::SetPageMargin(margin) => self.edit_page_template(|template| template.set_margin(margin)),
+            Message::SetExportLocale(locale) => {
+                let task = self.edit_settings(|settings| settings.set_export_locale(locale));
+                self.apply_export_settings();
+                task
+            },
+            Message::SetShuffleChoices(shuffle) => { self.exam_criteria.set_shuffle_choices(shuffle); Task::none() },
+            Message::SetAnswerKey(answer_key) => {
+                self.exam_criteria.set_answer_key(answer_key);
+                if let Some(exam) = &mut self.exam
+                    { exam.set_answer_key(answer_key); }
+                Task::none()
+            },
+            Message::SetQuestionOrder(order) => { self.exam_criteria.set_question_order(order); Task::none() },
+            Message::MoveCategory { from, to } => {
+                let mut order = self.get_arranged_categories();
+                if from < order.len() && to < order.len()
+                {
+                    let category = order.remove(from);
+                    order.insert(to, category);
+                    self.exam_criteria.set_category_order(order);
+                }
+                Task::none()
+            },
+            Message::SetPrintPaperCode(print) => { self.exam_criteria.set_print_paper_code(print); Task::none() },
+            Message::SetSeed(seed) => { self.exam_criteria.set_seed(seed); Task::none() },
+            Message::ExportExam(format) => self.export_exam(format),
+            Message::ExamExported(result) => self.exam_exported(result),
+            Message::ExportPathChosen(format, path) => self.export_path_chosen(format, path),
+            Message::ConfirmOverwrite => self.confirm_overwrite(),
+            Message::CancelOverwrite => {
+                self.pending_overwrite = None;
+                self.pending_batch = None;
+                Task::none()
+            },
+            Message::SetDontAskOverwrite(dont_ask) => { self.dont_ask_overwrite = dont_ask; Task::none() },
+            Message::SetVariantCount(count) => self.set_variant_count(count),
+            Message::ExportVariants(format) => self.export_variants(format),
+            Message::ExportStudentPapers(format) => self.export_student_papers(format),
+            Message::SetNamedPapers(named) => { self.named_papers = named; Task::none() },
+            Message::ExportBatchReady(batch) => self.start_export_batch(batch),
+            Message::ExportProgress(done, total) => self.advance_export_batch(done, total),
+            Message::SaveSummary => self.save_generation_summary(),
+            Message::SummarySaved(_) => Task::none(),
+            Message::AnkiDeckExported(_) => Task::none(),
+            Message::StudyGuideExported(_) => Task::none(),
+            Message::ItemAnalysisExported(_) => Task::none(),
+            Message::BankStatsExported(_) => Task::none(),
+            Message::QuestionsExported(result) => self.questions_exported(result),
+            Message::OpenStudentForm(index) => self.open_student_form(index),
+            Message::StudentNameChanged(name) => self.edit_student_form(|form| form.set_name(name)),
+            Message::StudentIdChanged(id) => self.edit_student_form(|form| form.set_id(id)),
+            Message::StudentGroupChanged(group) => self.edit_student_form(|form| form.set_group(group)),
+            Message::SubmitStudentForm => self.submit_student_form(),
+            Message::CancelStudentForm => { self.student_form = None; Task::none() },
+            Message::DeleteStudent(index) => self.delete_student(index),
+            Message::PickQuestionImage(id) => self.pick_file(BrowsePurpose::QuestionImage(id)),
+            Message::QuestionImagePicked(id, path) => self.attach_question_image(id, path),
+            Message::RemoveQuestionImage(id) => self.edit_question_meta(id, |meta| meta.set_image(None)),
+            Message::SetQuestionKind(id, kind) => self.edit_question_meta(id, |meta| meta.set_kind(kind)),
+            Message::SetGradingMode(mode) => self.set_grading_mode(mode),
+            Message::LearningTick(now) => self.edit_learning(|session| session.tick(now)),
+            Message::LearningGoTo(index) => self.learning_go_to(index),
+            Message::ToggleLearningChoice(choice) => self.edit_learning(|session| session.toggle_choice(choice)),
+            Message::SetLearningAnswer(answer) => self.edit_learning(|session| session.set_typed_answer(answer)),
+            Message::LearningEditorAction(action) => self.edit_answer(action),
+            Message::ToggleFlag(index) => self.edit_learning(|session| session.toggle_flag(index)),
+            Message::FinishLearning => self.finish_learning(),
+            Message::FocusNextMenu => self.move_focus(false),
+            Message::FocusPreviousMenu => self.move_focus(true),
+            Message::OpenContainingFolder => self.open_containing_folder(),
+            Message::RosterFileSelected(path) => if path.as_os_str().is_empty()
+                { Task::none() }
+            else
+                { LoadFile::perform_load_sbank_task(path) },
+            Message::SBankLoaded(result) => self.load_sbank(result),
+            Message::SortQuestions(column) => self.sort_questions(column),
+            Message::SetQuestionSearch(search) => self.filter_questions(|filter| filter.set_search(search)),
+            Message::SetQuestionCategoryFilter(category) => self.filter_questions(|filter| filter.set_category(category)),
+            Message::SetQuestionDifficultyFilter(level) => self.filter_questions(|filter| filter.set_difficulty(level)),
+            Message::ClearQuestionFilters => self.filter_questions(QuestionFilter::clear),
+            Message::ShowQuestion(id) => self.show_question(id),
+            Message::GoToQuestionPage(page) => {
+                let shown = self.question_filter.apply(&self.qbank, &self.question_metas).len();
+                self.question_page = page.min(page_count(shown, self.question_page_size) - 1);
+                Task::none()
+            },
+            Message::SetJumpText(text) => {
+                if text.chars().all(|c| c.is_ascii_digit())
+                    { self.jump_text = text; }
+                Task::none()
+            },
+            Message::JumpToQuestion => self.jump_to_question(),
+            Message::FocusJumpBox => match self.current_page
+            {
+                Page::QuestionBankEdit | Page::Learning => iced::widget::operation::focus(QUESTION_JUMP_INPUT),
+                _ => Task::none(),
+            },
+            Message::SetPromptLength(length) => self.edit_settings(|settings| settings.set_prompt_length(length)),
+            Message::SetQuestionPageSize(size) => {
+                // The first question on screen stays on screen.
+                let first = self.question_page * self.question_page_size;
+                self.question_page_size = size.max(1);
+                self.question_page = first / self.question_page_size;
+                Task::none()
+            },
+            Message::TaskFailed(message) => self.show_error(message),
+            Message::DismissError => { self.error_message = None; Task::none() },
+            Message::DismissNotice => {
+                if self.error_message.take().is_none()
+                    { self.toasts.dismiss(0); }
+                Task::none()
+            },
+            Message::DismissToast(index) => { self.toasts.dismiss(index); Task::none() },
+            Message::ToastTick(now) => { self.toasts.expire(now); Task::none() },
+            Message::SetRestoreLastPage(restore) => self.edit_settings(|settings| settings.set_restore_last_page(restore)),
+            Message::SetReduceMotion(reduce) => self.edit_settings(|settings| settings.set_reduce_motion(reduce)),
+            Message::SetKeepMenuOpen(keep) => self.edit_settings(|settings| settings.set_keep_menu_open(keep)),
+            Message::SetTheme(theme) => self.edit_settings(|settings| settings.set_theme(theme)),
+            Message::SetFileDialog(kind) => self.edit_settings(|settings| settings.set_file_dialog(kind)),
+            Message::BrowseDirectory(path) => self.browse(|browser| browser.open_directory(&path)),
+            Message::BrowseParent => self.browse(FileBrowser::open_parent),
+            Message::BrowsePick(path) => match self.file_browser.take()
+            {
+                Some(browser) => Task::done(browser.get_purpose().to_message(path)),
+                None => Task::none(),
+            },
+            Message::SetBrowserFileName(name) => {
+                if let Some(browser) = &mut self.file_browser
+                    { browser.set_file_name(name); }
+                Task::none()
+            },
+            Message::SavePathChosen(target, path) => self.save_path_chosen(target, path),
+            Message::CancelFileBrowser => { self.file_browser = None; Task::none() },
+            Message::SystemThemeChanged(mode) => { self.system_theme = mode; Task::none() },
+            Message::PollSystemTheme(_) => iced::system::theme().map(Message::SystemThemeChanged),
+            Message::IdleTick(now) => {
+                if let Some(minutes) = self.settings.get_idle_lock_minutes()
+                    && self.settings.is_idle_lock_enabled()
+                    && !self.is_locked()
+                    && is_idle(self.last_activity, now, minutes)
+                    { self.lock_screen(); }
+                Task::none()
+            },
+            Message::SetIdleLockMinutes(minutes) => self.edit_settings(|settings| settings.set_idle_lock_minutes(minutes)),
+            Message::AutosaveTick(_) => self.autosave(),
+            Message::SetAutosaveMinutes(minutes) => self.edit_settings(|settings| settings.set_autosave_minutes(minutes)),
+            Message::PinTextChanged(pin) => { self.pin_text = pin; Task::none() },
+            Message::SetIdleLockPin => {
+                let pin = std::mem::take(&mut self.pin_text);
+                self.edit_settings(|settings| settings.set_idle_lock_pin(&pin))
+            },
+            Message::Unlock => self.unlock_screen(),
+            Message::PickStoragePath => self.pick_file(BrowsePurpose::StorageFolder),
+            Message::StoragePathChosen(Some(path)) => self.edit_settings(|settings| settings.set_storage_path(Some(path))),
+            Message::StoragePathChosen(None) => Task::none(),
+            Message::ClearStoragePath => self.edit_settings(|settings| settings.set_storage_path(None)),
+            Message::SetQuestionDifficulty(id, level) => self.edit_question_meta(id, |meta| meta.set_difficulty(level)),
+            Message::MoveQuestion { from, to } => self.move_question(from, to),
+            Message::SelectQuestion(id, selected) => {
+                if selected
+                    { self.selected_questions.insert(id); }
+                else
+                    { self.selected_questions.remove(&id); }
+                Task::none()
+            },
+            Message::ClearQuestionSelection => { self.selected_questions.clear(); Task::none() },
+            Message::GenerateFromSelection => self.generate_from_selection(),
+            Message::SetExportSource(source) => { self.export_source = source; Task::none() },
+            Message::ExportQuestions => self.export_questions_as_bank(),
+            Message::DeleteSelectedQuestions => self.request_delete_questions(),
+            Message::ConfirmDeleteQuestions => match self.pending_delete.take()
+            {
+                Some(ids) => self.delete_questions(ids),
+                None => Task::none(),
+            },
+            Message::CancelDeleteQuestions => { self.pending_delete = None; Task::none() },
+            Message::SetDeleteConfirmThreshold(threshold) => self.edit_settings(|settings| settings.set_delete_confirm_threshold(threshold)),
+            Message::RecalibrateDifficulty => self.recalibrate_difficulty(),
+            Message::LoadSampleBank => self.request_load(PendingLoad::Sample),
+            Message::UnloadBank => self.request_load(PendingLoad::Unload),
+            Message::ConfirmReplaceBank => match self.pending_load.take()
+            {
+                Some(pending) => {
+                    self.dirty = false;
+                    self.run_load(pending)
+                },
+                None => Task::none(),
+            },
+            Message::CancelReplaceBank => { self.pending_load = None; Task::none() },
+            Message::RestoreSessionBanks => match std::mem::take(&mut self.session_banks).into_iter().next()
+            {
+                Some(bank) => self.request_load(PendingLoad::File(bank)),
+                None => Task::none(),
+            },
+            Message::DismissSessionBanks => { self.session_banks.clear(); Task::none() },
+            Message::ResumeLearning => self.resume_learning(),
+            Message::DiscardLearningProgress => self.discard_learning_progress(),
+            Message::DismissLearningProgress => { self.pending_resume = None; Task::none() },
+            Message::ResolveDuplicateStudents(resolution) => match self.pending_roster.take()
+            {
+                Some(pending) => {
+                    let (sbank, groups) = resolve_duplicate_students(pending.sbank, pending.groups, resolution);
+                    self.apply_sbank(sbank, groups)
+                },
+                None => Task::none(),
+            },
+            Message::CancelRosterImport => { self.pending_roster = None; Task::none() },
+            Message::SetQuestionTags(id, tags) => self.set_question_tags(id, tags),
+            Message::SetQuestionNote(id, note) => self.edit_question_meta(id, |meta| meta.set_note(note)),
+            Message::SetTagFilterText(tags) => {
+                self.tag_filter_text = tags;
+                self.update_tag_filter(self.exam_criteria.get_tag_filter().get_mode())
+            },
+            Message::SetTagMode(mode) => self.update_tag_filter(mode),
+            Message::SetPinnedText(text) => self.set_pinned_text(text),
+            Message::SetSectionsText(text) => self.set_sections_text(text),
+            Message::SetQuestionCount(count) => self.set_question_count(count),
+            Message::SetQuestionPercent(percent) => self.set_question_percent(percent),
+            Message::QuickExam => self.quick_exam(),
+            Message::OpenNewWindow => Task::none(),
+            Message::SetBankTitle(title) => self.edit_bank_metadata(|metadata| metadata.set_title(title)),
+            Message::SetBankAuthor(author) => self.edit_bank_metadata(|metadata| metadata.set_author(author)),
+            Message::SetBankSubject(subject) => self.edit_bank_metadata(|metadata| metadata.set_subject(subject)),
+            Message::SetBankDescription(description) => self.edit_bank_metadata(|metadata| metadata.set_description(description)),
+            Message::SaveBankMetadata => self.save_bank_metadata(),
+            Message::SelectCategoryToRename(category) => { self.category_to_rename = Some(category); Task::none() },
+            Message::SetCategoryNewName(name) => { self.category_new_name = name; Task::none() },
+            Message::RenameCategory => match self.category_to_rename.clone()
+            {
+                Some(from) => self.rename_category(from, self.category_new_name.clone(), false),
+                None => Task::none(),
+            },
+            Message::ConfirmMergeCategory => match self.pending_merge.take()
+            {
+                Some((from, to)) => self.rename_category(from, to, true),
+                None => Task::none(),
+            },
+            Message::CancelMergeCategory => { self.pending_merge = None; Task::none() },
+            Message::SetGradeDisplay(display) => self.edit_settings(|settings| settings.set_grade_display(display)),
+            Message::SetLetterScaleText(text) => self.set_letter_scale_text(text),
+            Message::SetAbsoluteTolerance(absolute) => {
+                let percent = self.settings.get_numeric_tolerance().get_percent();
+                self.set_numeric_tolerance(NumericTolerance::new(absolute, percent))
+            },
+            Message::SetPercentTolerance(percent) => {
+                let absolute = self.settings.get_numeric_tolerance().get_absolute();
+                self.set_numeric_tolerance(NumericTolerance::new(absolute, percent))
+            },
+            Message::SetCategoryIncluded(category, included) => {
+                self.exam_criteria.set_category_included(category, included);
+                Task::none()
+            },
+            Message::SetMinDifficulty(level) => {
+                let max = self.exam_criteria.get_difficulty_range().map(|(_, max)| max).unwrap_or(MAX_DIFFICULTY);
+                self.set_difficulty_range(level, max)
+            },
+            Message::SetMaxDifficulty(level) => {
+                let min = self.exam_criteria.get_difficulty_range().map(|(min, _)| min).unwrap_or(1);
+                self.set_difficulty_range(min, level)
+            },
+            Message::ClearDifficultyRange => {
+                self.difficulty_range_swapped = false;
+                self.exam_criteria.set_difficulty_range(None);
+                Task::none()
+            },
+            Message::SetDifficultyWeight(level, weight) => {
+                let mut target = self.exam_criteria.get_difficulty_target().unwrap_or_default();
+                if (1..=MAX_DIFFICULTY).contains(&level)
+                    { target[level as usize - 1] = weight; }
+                self.exam_criteria.set_difficulty_target(Some(target));
+                Task::none()
+            },
+            Message::ClearDifficultyTarget => { self.exam_criteria.set_difficulty_target(None); Task::none() },
+            Message::SetBalanceAttempts(attempts) => { self.exam_criteria.set_balance_attempts(attempts); Task::none() },
+            Message::SetAvoidRecent(exams) => { self.exam_criteria.set_avoid_recent(exams, &self.usage_history); Task::none() },
+            Message::ExamTitleChanged(title) => self.edit_exam_info(|info| info.set_title(title)),
+            Message::ExamCourseChanged(course) => self.edit_exam_info(|info| info.set_course(course)),
+            Message::ExamDateChanged(date) => self.edit_exam_info(|info| info.set_date(date)),
+            Message::ExamInstructionsChanged(instructions) => self.edit_exam_info(|info| info.set_instructions(instructions)),
+            Message::ActivateFocusedMenu => self.activate_focused(),
+        }
+    }
+
+    // fn set_difficulty_range(&mut self, min: u8, max: u8) -> Task<Message>
+    /// Restricts the drawn questions to the levels from `min` to `max`,
+    /// swapping bounds entered backwards and remembering to say so.
+    fn set_difficulty_range(&mut self, min: u8, max: u8) -> Task<Message>
+    {
+        self.difficulty_range_swapped = min > max;
+        self.exam_criteria.set_difficulty_range(Some((min, max)));
+        Task::none()
+    }
+
+    fn set_question_tags(&mut self, id: u16, tags: String) -> Task<Message>
+    {
+        let parsed = tags.split(',').map(|tag| tag.to_string()).collect();
+        // The typed text is kept as is, so that a trailing comma survives
+        // until the next tag is typed.
+        self.tag_drafts.insert(id, tags);
+        self.edit_question_meta(id, |meta| meta.set_tags(parsed))
+    }
+
+    fn update_tag_filter(&mut self, mode: TagMode) -> Task<Message>
+    {
+        self.exam_criteria.set_tag_filter(TagFilter::parse(&self.tag_filter_text, mode));
+        Task::none()
+    }
+
+    fn set_pinned_text(&mut self, text: String) -> Task<Message>
+    {
+        // Until the text parses again, the last valid pins stay in effect.
+        if let Some(pins) = parse_pins(&text)
+        {
+            self.exam_criteria.clear_pinned();
+            for (id, position) in pins
+                { self.exam_criteria.pin_question(id, position); }
+        }
+        self.pinned_text = text;
+        Task::none()
+    }
+
+    fn set_sections_text(&mut self, text: String) -> Task<Message>
+    {
+        // Until the text parses again, the last valid sections stay in effect.
+        if let Some(sections) = parse_sections(&text)
+            { self.exam_criteria.set_sections(sections); }
+        self.sections_text = text;
+        Task::none()
+    }
+
+    fn set_question_count(&mut self, count: String) -> Task<Message>
+    {
+        if let Ok(count) = count.trim().parse::<usize>()
+            && count > 0
+            { self.exam_criteria.set_selected(count); }
+        Task::none()
+    }
+
+    fn set_question_percent(&mut self, percent: u8) -> Task<Message>
+    {
+        let task = self.edit_settings(|settings| settings.set_question_percent(percent));
+        self.apply_default_question_count();
+        task
+    }
+
+    // fn apply_default_question_count(&mut self)
+    /// Derives the number of exam questions from the size of the loaded
+    /// bank and the share picked in the settings.
+    fn apply_default_question_count(&mut self)
+    {
+        let count = default_question_count(self.qbank.get_questions().len(), self.settings.get_question_percent());
+        self.exam_criteria.set_selected(count);
+        self.question_count_pending = false;
+    }
+
+    fn edit_exam_info(&mut self, edit: impl FnOnce(&mut ExamInfo)) -> Task<Message>
+    {
+        edit(self.exam_criteria.get_info_mut());
+        if let Some(exam) = &mut self.exam
+            { exam.set_info(self.exam_criteria.get_info().clone()); }
+        Task::none()
+    }
+
+    fn open_containing_folder(&mut self) -> Task<Message>
+    {
+        self.current_menu_key.clear();
+        if let Err(e) = open_containing_folder(&self.selected_file_path)
+            { log_event(&format!("Error opening containing folder: {}", e)); }
+        Task::none()
+    }
+
+    // fn get_modal_controls(&self) -> Option<Vec<ModalControl<Message>>>
+    /// Returns the focusable controls of the topmost open modal in tab
+    /// order, or `None` if no modal is open.
+    fn get_modal_controls(&self) -> Option<Vec<ModalControl<Message>>>
+    {
+        if self.error_message.is_some()
+            { return Some(vec![ModalControl::Button(Message::DismissError)]); }
+        if self.is_overwrite_pending()
+        {
+            return Some(vec![
+                ModalControl::Button(Message::SetDontAskOverwrite(!self.dont_ask_overwrite)),
+                ModalControl::Button(Message::ConfirmOverwrite),
+                ModalControl::Button(Message::CancelOverwrite),
+            ]);
+        }
+        if self.pending_roster.is_some()
+        {
+            return Some(vec![
+                ModalControl::Button(Message::ResolveDuplicateStudents(DuplicateResolution::Merge)),
+                ModalControl::Button(Message::ResolveDuplicateStudents(DuplicateResolution::KeepFirst)),
+                ModalControl::Button(Message::CancelRosterImport),
+            ]);
+        }
+        if self.pending_delete.is_some()
+            { return Some(vec![ModalControl::Button(Message::ConfirmDeleteQuestions), ModalControl::Button(Message::CancelDeleteQuestions)]); }
+        if self.pending_merge.is_some()
+            { return Some(vec![ModalControl::Button(Message::ConfirmMergeCategory), ModalControl::Button(Message::CancelMergeCategory)]); }
+        if self.pending_load.is_some()
+            { return Some(vec![ModalControl::Button(Message::ConfirmReplaceBank), ModalControl::Button(Message::CancelReplaceBank)]); }
+        if self.pending_resume.is_some()
+        {
+            return Some(vec![
+                ModalControl::Button(Message::DiscardLearningProgress),
+                ModalControl::Button(Message::ResumeLearning),
+                ModalControl::Button(Message::DismissLearningProgress),
+            ]);
+        }
+        if !self.session_banks.is_empty()
+            { return Some(vec![ModalControl::Button(Message::RestoreSessionBanks), ModalControl::Button(Message::DismissSessionBanks)]); }
+        if let Some(browser) = &self.file_browser
+        {
+            let mut controls = vec![ModalControl::Button(Message::BrowseParent)];
+            controls.extend(browser.get_entries().iter().map(|entry| ModalControl::Button(ControlTower::get_browser_message(browser, entry))));
+            if browser.is_saving()
+            {
+                controls.push(ModalControl::Input(FILE_NAME_INPUT));
+                if let Some(path) = browser.get_save_path()
+                    { controls.push(ModalControl::Button(Message::BrowsePick(path))); }
+            }
+            if browser.get_purpose().picks_folder()
+                { controls.push(ModalControl::Button(Message::BrowsePick(browser.get_directory().to_path_buf()))); }
+            controls.push(ModalControl::Button(Message::CancelFileBrowser));
+            return Some(controls);
+        }
+        self.student_form.as_ref().map(|_| vec![
+            ModalControl::Input(STUDENT_NAME_INPUT),
+            ModalControl::Input(STUDENT_ID_INPUT),
+            ModalControl::Input(STUDENT_GROUP_INPUT),
+            ModalControl::Button(Message::SubmitStudentForm),
+            ModalControl::Button(Message::CancelStudentForm),
+        ])
+    }
+
+    fn move_focus(&mut self, backwards: bool) -> Task<Message>
+    {
+        let Some(controls) = self.get_modal_controls()
+            else {
+                self.modal_focus = None;
+                let order = self.get_current_tab_order();
+                self.focus_stop = cycle_focus(self.focus_stop, order.len(), backwards);
+                return match self.focus_stop.and_then(|index| order.get(index))
+                {
+                    Some(FocusStop::Input(id)) => iced::widget::operation::focus(*id),
+                    _ => iced::widget::operation::focus(STATUS_BAR_FOCUS),
+                };
+            };
+        self.modal_focus = cycle_focus(self.modal_focus, controls.len(), backwards);
+        match self.modal_focus.and_then(|index| controls.into_iter().nth(index))
+        {
+            Some(ModalControl::Input(id)) => iced::widget::operation::focus(id),
+            // Buttons draw their own focus ring. Focusing an id that no widget
+            // has takes the caret away from every text input.
+            _ => iced::widget::operation::focus(MODAL_BUTTON_FOCUS),
+        }
+    }
+
+    fn activate_focused(&mut self) -> Task<Message>
+    {
+        let Some(controls) = self.get_modal_controls()
+            else {
+                return match self.get_focus_stop()
+                {
+                    Some(FocusStop::Menu(key)) => self.click_menu(key.to_string()),
+                    Some(FocusStop::Toast) => self.update(Message::DismissToast(0)),
+                    _ => Task::none(),
+                };
+            };
+        let task = match self.modal_focus.and_then(|index| controls.into_iter().nth(index))
+        {
+            Some(ModalControl::Button(message)) => self.update(message),
+            _ => Task::none(),
+        };
+        // A modal that closed releases the focus; the next one starts afresh.
+        if self.get_modal_controls().is_none()
+            { self.modal_focus = None; }
+        task
+    }
+
+    // pub(crate) fn get_focus_message(event: keyboard::Event) -> Option<Message>
+    /// Translates the keys used for keyboard navigation of the main menu.
+    pub(crate) fn get_focus_message(event: keyboard::Event) -> Option<Message>
+    {
+        match event
+        {
+            keyboard::Event::KeyPressed { key: Key::Character(c), modifiers, .. } if modifiers.command() && c.as_str() == "g"
+                => Some(Message::QuickExam),
+            keyboard::Event::KeyPressed { key: Key::Character(c), modifiers, .. } if modifiers.command() && c.as_str() == "l"
+                => Some(Message::CycleLocale),
+            keyboard::Event::KeyPressed { key: Key::Character(c), modifiers, .. } if modifiers.command() && c.as_str() == "j"
+                => Some(Message::FocusJumpBox),
+            keyboard::Event::KeyPressed { key: Key::Named(Named::Tab), modifiers, .. } if modifiers.shift()
+                => Some(Message::FocusPreviousMenu),
+            keyboard::Event::KeyPressed { key: Key::Named(Named::Tab), .. } => Some(Message::FocusNextMenu),
+            keyboard::Event::KeyPressed { key: Key::Named(Named::Enter | Named::Space), .. }
+                => Some(Message::ActivateFocusedMenu),
+            keyboard::Event::KeyPressed { key: Key::Named(Named::Escape), .. } => Some(Message::DismissNotice),
+            _ => None,
+        }
+    }
+
+    // fn is_blocked_while_locked(message: &Message) -> bool
+    /// Returns whether `message` leads away from the lock screen, like the
+    /// keyboard shortcuts, which keep working while the menus are hidden.
+    fn is_blocked_while_locked(message: &Message) -> bool
+    {
+        matches!(message, Message::MenuClicked(_) | Message::SubMenuClicked(_) | Message::GoToPage(_)
+                        | Message::QuickExam | Message::CycleLocale | Message::FocusJumpBox | Message::OpenNewWindow
+                        | Message::FocusNextMenu | Message::FocusPreviousMenu | Message::ActivateFocusedMenu)
+    }
+
+    fn lock_screen(&mut self)
+    {
+        self.locked_page = Some(self.current_page);
+        self.show_page(Page::Locked);
+        self.current_menu_key.clear();
+        self.pin_text.clear();
+        self.pin_rejected = false;
+        log_event("Screen locked after inactivity.");
+    }
+
+    fn unlock_screen(&mut self) -> Task<Message>
+    {
+        let pin = std::mem::take(&mut self.pin_text);
+        if !self.settings.check_idle_lock_pin(&pin)
+        {
+            self.pin_rejected = true;
+            return Task::none();
+        }
+        if let Some(page) = self.locked_page.take()
+            { self.show_page(page); }
+        self.pin_rejected = false;
+        Task::none()
+    }
+
+    fn click_menu(&mut self, menu_key: String) -> Task<Message>
+    {
+        if self.current_menu_key == menu_key
+            { self.current_menu_key.clear(); }
+        else
+            { self.current_menu_key = menu_key; }
+        Task::none()
+    }
+
+    fn click_submenu(&mut self, sub_item_key: String) -> Task<Message>
+    {
+        let menu_key = std::mem::take(&mut self.current_menu_key);
+        // The same key may name items of different menus, such as "export".
+        let task = match (menu_key.as_str(), sub_item_key.as_str())
+        {
+            (_, "load-question-bank") => self.pick_file(BrowsePurpose::QuestionBank),
+            (_, "criteria-for-question-extraction") => self.go_to_page(Page::Criteria),
+            (_, "export-exam-paper") => self.generate_exam(),
+            (_, "quick-exam") => self.quick_exam(),
+            (_, "open-in-new-window") => Task::done(Message::OpenNewWindow),
+            (_, "take-exam") => self.start_learning(),
+            (_, "grading-criteria") => self.go_to_page(Page::GradingCriteria),
+            (_, "bank-metadata") => self.go_to_page(Page::BankMetadata),
+            (_, "validate-bank") => self.go_to_page(Page::BankValidation),
+            (_, "software-info") => self.go_to_page(Page::SoftwareInfo),
+            (_, "diagnostics") => self.go_to_page(Page::Diagnostics),
+            (_, "export-anki-deck") => self.pick_save_path(SaveTarget::AnkiDeck),
+            (_, "export-study-guide") => self.pick_save_path(SaveTarget::StudyGuide),
+            (_, "export-item-analysis") => self.export_item_analysis(),
+            (_, "export-bank-stats") => self.pick_save_path(SaveTarget::BankStats),
+            ("question-bank-management", "export") => self.export_questions_as_bank(),
+            (_, "unload-bank") => self.request_load(PendingLoad::Unload),
+            ("student-list-management", "load") => self.pick_file(BrowsePurpose::Roster),
+            // (_, "create-new-question-bank") => Task::none(),
+            _ => Task::none(),
+        };
+        // Going to a page closes the menu as well, so it is reopened afterwards.
+        if self.settings.get_keep_menu_open()
+            { self.current_menu_key = menu_key; }
+        task
+    }
+
+    fn pick_file(&mut self, purpose: BrowsePurpose) -> Task<Message>
+    {
+        let start = self.settings.get_dialog_directory();
+        if self.settings.get_file_dialog() == FileDialogKind::Native
+        {
+            return match purpose
+            {
+                BrowsePurpose::QuestionBank => LoadFile::perform_pick_qbank_task(start),
+                BrowsePurpose::Roster => LoadFile::perform_pick_sbank_task(),
+                BrowsePurpose::QuestionImage(id) => LoadFile::perform_pick_question_image_task(id),
+                BrowsePurpose::StorageFolder => LoadFile::perform_pick_storage_folder_task(start),
+                BrowsePurpose::Save(target) => self.pick_save_path(target),
+            };
+        }
+        // The native dialogs of rosters and images start in the working directory.
+        let start = match purpose
+        {
+            BrowsePurpose::Roster | BrowsePurpose::QuestionImage(_) => PathBuf::from("."),
+            _ => start,
+        };
+        self.file_browser = Some(FileBrowser::new(purpose, &start));
+        Task::none()
+    }
+
+    // fn pick_save_path(&mut self, target: SaveTarget) -> Task<Message>
+    /// Asks where to write `target`, with the native save dialog or the
+    /// in-app file browser as the settings choose.
+    fn pick_save_path(&mut self, target: SaveTarget) -> Task<Message>
+    {
+        if self.settings.get_file_dialog() == FileDialogKind::InApp
+        {
+            // The native save dialogs start in the working directory.
+            self.file_browser = Some(FileBrowser::new(BrowsePurpose::Save(target), Path::new(".")));
+            return Task::none();
+        }
+        match target
+        {
+            SaveTarget::Exam(format) => SaveFile::perform_pick_export_path_task(format),
+            SaveTarget::Variants(format) => match self.build_variants()
+            {
+                Some(variants) => SaveFile::perform_prepare_batch_task(variants, format),
+                None => Task::none(),
+            },
+            SaveTarget::StudentPapers(format) => match self.build_student_papers()
+            {
+                Some(papers) => SaveFile::perform_prepare_batch_task(papers, format),
+                None => Task::none(),
+            },
+            SaveTarget::GenerationSummary => match &self.generation_summary
+            {
+                Some(summary) => SaveFile::perform_save_summary_task(summary.clone()),
+                None => Task::none(),
+            },
+            SaveTarget::AnkiDeck => SaveFile::perform_save_anki_deck_task(self.qbank.clone()),
+            SaveTarget::StudyGuide => SaveFile::perform_save_study_guide_task(self.qbank.clone(), self.settings.get_page_template().clone()),
+            SaveTarget::ItemAnalysis => SaveFile::perform_save_item_analysis_task(self.get_item_analysis()),
+            SaveTarget::BankStats => SaveFile::perform_save_bank_stats_task(bank_stats(&self.qbank, &self.question_metas)),
+            SaveTarget::Questions => SaveFile::perform_save_questions_task(self.build_export_bank(), self.question_metas.clone(), self.selected_file_path.clone()),
+        }
+    }
+
+    // fn save_path_chosen(&mut self, target: SaveTarget, path: PathBuf) -> Task<Message>
+    /// Writes `target` to `path`, or asks first if that would replace an
+    /// existing file.
+    fn save_path_chosen(&mut self, target: SaveTarget, path: PathBuf) -> Task<Message>
+    {
+        // An exported bank gets its name completed, so that is the file replaced.
+        let path = match target
+        {
+            SaveTarget::Questions => resolve_export_path(&path, &self.selected_file_path).unwrap_or(path),
+            _ => path,
+        };
+        if !target.picks_folder() && path.exists() && !self.skip_overwrite_confirm
+        {
+            self.dont_ask_overwrite = false;
+            self.pending_overwrite = Some((path, target));
+            return Task::none();
+        }
+        self.save_to(target, path)
+    }
+
+    // fn save_to(&mut self, target: SaveTarget, path: PathBuf) -> Task<Message>
+    /// Writes `target` to `path` without asking, reporting the outcome with
+    /// the message the native save dialog would.
+    fn save_to(&mut self, target: SaveTarget, path: PathBuf) -> Task<Message>
+    {
+        match target
+        {
+            SaveTarget::Exam(format) => self.write_exam(&path, format),
+            SaveTarget::Variants(format) => {
+                let batch = self.build_variants().map(|variants| ExportBatch::new(variants, path, format));
+                self.start_export_batch(batch)
+            },
+            SaveTarget::StudentPapers(format) => {
+                let batch = self.build_student_papers().map(|papers| ExportBatch::new(papers, path, format));
+                self.start_export_batch(batch)
+            },
+            SaveTarget::GenerationSummary => match &self.generation_summary
+            {
+                Some(summary) => Task::done(Message::SummarySaved(SaveFile::report_saved(save_summary(&path, summary), path, "Error saving generation summary"))),
+                None => Task::none(),
+            },
+            SaveTarget::AnkiDeck => Task::done(Message::AnkiDeckExported(SaveFile::report_saved(export_anki_deck(&self.qbank, &path), path, "Error exporting Anki deck"))),
+            SaveTarget::StudyGuide => {
+                let result = export_study_guide_to_pdf(&self.qbank, self.settings.get_page_template(), &path);
+                Task::done(Message::StudyGuideExported(SaveFile::report_saved(result, path, "Error exporting study guide")))
+            },
+            SaveTarget::ItemAnalysis => {
+                let result = export_item_analysis(&self.get_item_analysis(), &path);
+                Task::done(Message::ItemAnalysisExported(SaveFile::report_saved(result, path, "Error exporting item analysis")))
+            },
+            SaveTarget::BankStats => {
+                let result = export_bank_stats(&bank_stats(&self.qbank, &self.question_metas), &path);
+                Task::done(Message::BankStatsExported(SaveFile::report_saved(result, path, "Error exporting bank statistics")))
+            },
+            SaveTarget::Questions => {
+                let result = SaveFile::write_questions(&self.build_export_bank(), &self.question_metas, &path, &self.selected_file_path);
+                Task::done(Message::QuestionsExported(result))
+            },
+        }
+    }
+
+    fn browse(&mut self, f: impl FnOnce(&mut FileBrowser)) -> Task<Message>
+    {
+        if let Some(browser) = &mut self.file_browser
+        {
+            f(browser);
+            // The entries have changed, so the keyboard focus starts over.
+            self.modal_focus = None;
+        }
+        Task::none()
+    }
+
+    fn select_file(&mut self, path: PathBuf) -> Task<Message>
+    {
+        let previous = std::mem::replace(&mut self.selected_file_path, path.clone());
+        self.current_menu_key.clear();
+        if path.as_os_str().is_empty()
+            { return Task::none(); }
+        // The file becomes the last one only once it has been loaded.
+        self.previous_file_path.get_or_insert(previous);
+        LoadFile::perform_load_qbank_task(path)
+    }
+
+    fn load_qbank(&mut self, result: ResultLoadFile) -> Task<Message>
+    {
+        let key = match result
+        {
+            ResultLoadFile::Success(qbank) => {
+                self.qbank = qbank;     // TODO: Add a success message for the user.
+                self.previous_file_path = None;
+                if !self.selected_file_path.as_os_str().is_empty()
+                {
+                    let path = self.selected_file_path.clone();
+                    let _ = self.edit_settings(|settings| settings.set_last_file(Some(path)));
+                }
+                self.question_page = 0;
+                self.selected_questions.clear();
+                self.question_metas = load_question_metas(&self.selected_file_path);
+                self.attempts = load_attempts(&self.selected_file_path);
+                self.usage_history = load_usage_history(&self.selected_file_path);
+                let exams = self.exam_criteria.get_avoid_recent();
+                self.exam_criteria.set_avoid_recent(exams, &self.usage_history);
+                self.pending_resume = if self.selected_file_path.as_os_str().is_empty()
+                    { None }
+                else
+                    { load_learning_progress(&self.selected_file_path) };
+                self.bank_metadata = load_bank_metadata(&self.selected_file_path, self.qbank.get_header());
+                self.remember_saved_bank();
+                self.bank_changed = false;
+                self.autosave_paused = false;
+                self.tag_drafts.clear();
+                self.highlighted_question = None;
+                self.refresh_categories();
+                self.exam_criteria.include_all_categories();
+                self.exam_criteria.set_category_order(Vec::new());
+                // The question count follows the new bank once the criteria are opened.
+                self.question_count_pending = true;
+                log_event(&format!("Loaded question bank: {} ({} questions)", self.selected_file_path.display(), self.qbank.get_questions().len()));
+                return Task::none();
+            },
+            ResultLoadFile::FileNotFound => "load-error-not-found",
+            ResultLoadFile::FailedToOpenSQLite => "load-error-open-qbdb",
+            ResultLoadFile::FailedToReadSQLite => "load-error-read-qbdb",
+            ResultLoadFile::FailedToOpenExcel => "load-error-open-excel",
+            ResultLoadFile::FailedToReadExcel => "load-error-read-excel",
+            ResultLoadFile::InvalidExcelExtension => "load-error-excel-extension",
+            ResultLoadFile::UnsupportedExtension => "load-error-extension",
+        };
+        // The bank still in memory belongs to the file it came from, so
+        // saving and autosave must not write it to the file that failed.
+        let failed = match self.previous_file_path.take()
+        {
+            Some(previous) => std::mem::replace(&mut self.selected_file_path, previous),
+            None => self.selected_file_path.clone(),
+        };
+        self.show_error(t!("load-bank-failed", path = failed.display(), reason = t!(key)).to_string())
+    }
+
+    // fn request_load(&mut self, pending: PendingLoad) -> Task<Message>
+    /// Loads another bank, or asks first if that would discard unsaved
+    /// changes to the current one.
+    fn request_load(&mut self, pending: PendingLoad) -> Task<Message>
+    {
+        // The roster is not saved anywhere, and only unloading clears it.
+        let drops_roster = matches!(pending, PendingLoad::Unload) && self.roster_edited;
+        if drops_roster || self.has_real_changes()
+        {
+            self.pending_load = Some(pending);
+            return Task::none();
+        }
+        self.run_load(pending)
+    }
+
+    fn run_load(&mut self, pending: PendingLoad) -> Task<Message>
+    {
+        match pending
+        {
+            PendingLoad::File(path) => self.select_file(path),
+            PendingLoad::Sample => self.load_sample_bank(),
+            PendingLoad::Unload => self.unload_bank(),
+        }
+    }
+
+    fn unload_bank(&mut self) -> Task<Message>
+    {
+        log_event(&format!("Unloaded question bank: {}", self.selected_file_path.display()));
+        self.suspend_learning();
+        self.pending_resume = None;
+        self.qbank = QBank::new_empty();
+        self.sbank = SBank::new();
+        self.student_groups.clear();
+        self.roster_edited = false;
+        self.selected_file_path = PathBuf::new();
+        self.question_metas = QuestionMetas::new();
+        self.attempts.clear();
+        self.usage_history = UsageHistory::new();
+        self.exam_criteria.set_avoid_recent(0, &self.usage_history);
+        self.bank_metadata = BankMetadata::new();
+        self.saved_bank = None;
+        self.saved_fingerprint = None;
+        self.categories.clear();
+        self.tag_drafts.clear();
+        self.highlighted_question = None;
+        self.exam_criteria.include_all_categories();
+        self.exam_criteria.set_category_order(Vec::new());
+        self.exam = None;
+        self.balance_report = None;
+        self.learning = None;
+        self.variant_reports.clear();
+        self.question_page = 0;
+        self.selected_questions.clear();
+        self.dirty = false;
+        self.bank_changed = false;
+        // The closed bank should not come back on the next launch either.
+        let _ = self.edit_settings(|settings| settings.set_last_file(None));
+        self.go_to_page(Page::Main)
+    }
+
+    fn load_sample_bank(&mut self) -> Task<Message>
+    {
+        // The sample has no file, so nothing edited is written anywhere.
+        self.selected_file_path = PathBuf::new();
+        self.load_qbank(ResultLoadFile::Success(load_sample_bank()))
+    }
+
+    fn edit_bank_metadata(&mut self, edit: impl FnOnce(&mut BankMetadata)) -> Task<Message>
+    {
+        edit(&mut self.bank_metadata);
+        let mut header = self.qbank.get_header().clone();
+        self.bank_metadata.apply_to(&mut header);
+        self.qbank.set_header(header);
+        self.dirty = true;
+        self.bank_changed = self.has_real_changes();
+        Task::none()
+    }
+
+    // fn rename_category(&mut self, from: String, to: String, merge: bool) -> Task<Message>
+    /// Renames a category across the bank, or asks first if the new name
+    /// is taken and the two categories would be merged.
+    fn rename_category(&mut self, from: String, to: String, merge: bool) -> Task<Message>
+    {
+        match rename_category(&mut self.qbank, &from, &to, merge)
+        {
+            Ok(moved) => {
+                // An excluded category stays excluded under its new name.
+                let old = Category::Named(from.trim().to_string());
+                let new = Category::Named(to.trim().to_string());
+                if !merge && !self.exam_criteria.is_category_included(&old)
+                    { self.exam_criteria.set_category_included(new.clone(), false); }
+                if !merge
+                {
+                    let order = self.exam_criteria.get_category_order().iter()
+                                    .map(|category| if *category == old { new.clone() } else { category.clone() })
+                                    .collect();
+                    self.exam_criteria.set_category_order(order);
+                }
+                self.exam_criteria.set_category_included(old, true);
+                self.refresh_categories();
+                self.category_to_rename = Some(to.trim().to_string());
+                self.category_new_name.clear();
+                log_event(&format!("Renamed category {} to {} ({} questions).", from.trim(), to.trim(), moved));
+                self.dirty = true;
+                self.bank_changed = self.has_real_changes();
+                Task::none()
+            },
+            Err(CategoryRenameError::Collision(_)) if !merge => {
+                self.pending_merge = Some((from, to));
+                Task::none()
+            },
+            Err(e) => self.show_error(t!(e.get_key()).to_string()),
+        }
+    }
+
+    fn move_question(&mut self, from: usize, to: usize) -> Task<Message>
+    {
+        let mut questions = self.qbank.get_questions().clone();
+        if from == to || from >= questions.len() || to >= questions.len()
+            { return Task::none(); }
+        let question = questions.remove(from);
+        questions.insert(to, question);
+        self.qbank.set_questions(questions);
+        self.dirty = true;
+        self.bank_changed = self.has_real_changes();
+        Task::none()
+    }
+
+    fn autosave(&mut self) -> Task<Message>
+    {
+        if !self.dirty || self.autosave_paused || self.selected_file_path.as_os_str().is_empty()
+            { return Task::none(); }
+        log_event(&format!("Autosaving question bank: {}", self.selected_file_path.display()));
+        if let Err(e) = self.write_bank()
+        {
+            // A dialog on every tick would keep interrupting the user, so
+            // autosave stops until the bank is saved by hand or reloaded.
+            log_event(&format!("Autosave failed, paused: {}", e));
+            self.autosave_paused = true;
+            self.show_toast(t!("autosave-paused", error = e).to_string());
+        }
+        Task::none()
+    }
+
+    fn save_bank_metadata(&mut self) -> Task<Message>
+    {
+        match self.write_bank()
+        {
+            Ok(()) => {
+                self.autosave_paused = false;
+                Task::none()
+            },
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    /// Writes the whole bank to its file and clears the unsaved changes.
+    fn write_bank(&mut self) -> Result<(), String>
+    {
+        // Rewriting identical content would only bump the modification time
+        // and make file watchers reload the bank.
+        if !matches_disk(&self.selected_file_path, &self.qbank, &self.question_metas, &self.bank_metadata)
+            { save_bank(&self.selected_file_path, &self.qbank, &self.question_metas, &self.bank_metadata)?; }
+        self.remember_saved_bank();
+        self.dirty = false;
+        self.bank_changed = false;
+        Ok(())
+    }
+
+    /// Keeps what the files of the bank now hold, which `has_real_changes`
+    /// compares against.
+    fn remember_saved_bank(&mut self)
+    {
+        if self.selected_file_path.as_os_str().is_empty()
+        {
+            self.saved_bank = None;
+            self.saved_fingerprint = None;
+            return;
+        }
+        self.saved_fingerprint = Some(fingerprint_bank(&self.qbank, &self.question_metas, &self.bank_metadata));
+        self.saved_bank = Some((self.qbank.clone(), self.bank_metadata.clone()));
+    }
+
+    fn edit_settings(&mut self, edit: impl FnOnce(&mut AppSettings)) -> Task<Message>
+    {
+        edit(&mut self.settings);
+        if let Some(path) = &self.settings_path
+            && let Err(e) = save_settings(path, &self.settings)
+            { log_event(&format!("Error saving settings: {}", e)); }
+        Task::none()
+    }
+
+    fn set_letter_scale_text(&mut self, text: String) -> Task<Message>
+    {
+        let scale = LetterScale::parse(&text);
+        self.letter_scale_text = text;
+        match scale
+        {
+            Some(scale) => self.edit_settings(|settings| settings.set_letter_scale(scale)),
+            None => Task::none(),
+        }
+    }
+
+    fn set_numeric_tolerance(&mut self, tolerance: NumericTolerance) -> Task<Message>
+    {
+        if let Some(session) = &mut self.learning
+            { session.set_numeric_tolerance(tolerance); }
+        self.edit_settings(|settings| settings.set_numeric_tolerance(tolerance))
+    }
+
+    fn show_error(&mut self, message: String) -> Task<Message>
+    {
+        log_event(&format!("Error: {}", message));
+        self.error_message = Some(message);
+        Task::none()
+    }
+
+    fn show_toast(&mut self, message: String)
+    {
+        self.toasts.push(message, Instant::now());
+    }
+
+    fn sort_questions(&mut self, column: SortColumn) -> Task<Message>
+    {
+        let (sorted, order) = self.question_filter.get_sort();
+        let sort = if sorted == column
+            { (column, order.flipped()) }
+        else
+            { (column, SortOrder::Ascending) };
+        self.filter_questions(|filter| filter.set_sort(sort))
+    }
+
+    // fn filter_questions(&mut self, edit: impl FnOnce(&mut QuestionFilter)) -> Task<Message>
+    /// Changes what the questions table shows.
+    fn filter_questions(&mut self, edit: impl FnOnce(&mut QuestionFilter)) -> Task<Message>
+    {
+        edit(&mut self.question_filter);
+        // Other questions are shown on every page but the first.
+        self.question_page = 0;
+        Task::none()
+    }
+
+    // fn jump_to_question(&mut self) -> Task<Message>
+    /// Goes to the question numbered in the "go to" box: on the learning
+    /// page, to that question of the exam; in the questions table, to the
+    /// page of the filtered and sorted list holding the row labelled with
+    /// that id, or with the nearest id shown, which is highlighted and
+    /// scrolled into view.
+    fn jump_to_question(&mut self) -> Task<Message>
+    {
+        let Ok(number) = self.jump_text.parse::<usize>()
+            else { return Task::none(); };
+        if self.current_page == Page::Learning
+        {
+            let count = self.learning.as_ref().map(|session| session.get_exam().get_questions().len()).unwrap_or_default();
+            let Some(index) = clamp_question_number(number, count)
+                else { return Task::none(); };
+            self.jump_text = (index + 1).to_string();
+            return self.learning_go_to(index);
+        }
+        let shown = self.question_filter.apply(&self.qbank, &self.question_metas);
+        // Rows are labelled with the question ids, which have gaps after
+        // deletions and need not be in order; a tie goes to the lower id.
+        let Some(index) = (0..shown.len()).min_by_key(|&i| (shown[i].get_id().abs_diff(number.min(u16::MAX as usize) as u16), shown[i].get_id()))
+            else { return Task::none(); };
+        self.jump_text = shown[index].get_id().to_string();
+        self.highlighted_question = Some(shown[index].get_id());
+        self.question_page = index / self.question_page_size;
+        // Rows differ in height with their images, so the offset only
+        // brings the row close to the middle of the view.
+        let rows = page_slice(&shown, self.question_page, self.question_page_size).len();
+        let y = if rows > 1 { (index % self.question_page_size) as f32 / (rows - 1) as f32 } else { 0.0 };
+        iced::widget::operation::snap_to(QUESTION_LIST_SCROLL, RelativeOffset { x: 0.0, y })
+    }
+
+    fn show_question(&mut self, id: u16) -> Task<Message>
+    {
+        self.question_filter.clear();
+        let shown = self.question_filter.apply(&self.qbank, &self.question_metas);
+        self.question_page = shown.iter().position(|question| question.get_id() == id).unwrap_or(0) / self.question_page_size;
+        self.go_to_page(Page::QuestionBankEdit)
+    }
+
+    fn load_sbank(&mut self, result: ResultLoadSBank) -> Task<Message>
+    {
+        match result
+        {
+            ResultLoadSBank::Success(sbank, groups) => {
+                let duplicates = find_duplicate_students(&sbank, &groups);
+                if duplicates.is_empty()
+                    { return self.apply_sbank(sbank, groups); }
+                self.pending_roster = Some(PendingRoster { sbank, groups, duplicates });
+                Task::none()
+            },
+            failure => {
+                log_event(&format!("Error loading student list: {}", failure));
+                Task::none()
+            },
+        }
+    }
+
+    fn apply_sbank(&mut self, sbank: SBank, groups: HashMap<String, String>) -> Task<Message>
+    {
+        self.sbank = sbank;
+        self.student_groups = groups;
+        self.roster_edited = false;
+        self.go_to_page(Page::StudentList)
+    }
+
+    fn set_locale(&mut self, locale: String) -> Task<Message>
+    {
+        self.use_locale(locale.clone());
+        self.edit_settings(|settings| settings.set_locale(Some(locale)))
+    }
+
+    fn use_locale(&mut self, locale: String)
+    {
+        rust_i18n::set_locale(&locale);
+        self.current_locale = locale;
+        sort_categories(&mut self.categories, &self.current_locale);
+        self.apply_export_settings();
+    }
+
+    // fn refresh_categories(&mut self)
+    /// Collects the categories used by the question bank again, in the
+    /// alphabetical order of the current locale.
+    fn refresh_categories(&mut self)
+    {
+        self.categories = distinct_categories(&self.qbank);
+        sort_categories(&mut self.categories, &self.current_locale);
+    }
+
+    fn cycle_locale(&mut self) -> Task<Message>
+    {
+        match next_locale(&Self::get_available_locales(), &self.current_locale)
+        {
+            Some(locale) => self.set_locale(locale),
+            None => Task::none(),
+        }
+    }
+
+    // fn apply_export_settings(&mut self)
+    /// Lays out the answer choices of the next and the current exam with
+    /// the number of columns chosen for the current locale, leaves the
+    /// chosen answer space after each question, prints their labels in the
+    /// chosen export locale and their pages with the chosen header and
+    /// footer.
+    fn apply_export_settings(&mut self)
+    {
+        let count = self.settings.get_choices_per_row(&self.current_locale);
+        let locale = self.settings.get_export_locale().map(str::to_string);
+        let space = self.settings.get_answer_space().clone();
+        let template = self.settings.get_page_template().clone();
+        self.exam_criteria.set_choices_per_row(count);
+        self.exam_criteria.set_export_locale(locale.clone());
+        self.exam_criteria.set_answer_space(space.clone());
+        self.exam_criteria.set_page_template(template.clone());
+        if let Some(exam) = &mut self.exam
+        {
+            exam.set_choices_per_row(count);
+            exam.set_locale(locale);
+            exam.set_answer_space(space);
+            exam.set_page_template(template);
+        }
+    }
+
+    fn edit_page_template(&mut self, edit: impl FnOnce(&mut PageTemplate)) -> Task<Message>
+    {
+        let task = self.edit_settings(|settings| {
+            let mut template = settings.get_page_template().clone();
+            edit(&mut template);
+            settings.set_page_template(template);
+        });
+        self.apply_export_settings();
+        task
+    }
+
+    fn go_to_page(&mut self, page: Page) -> Task<Message>
+    {
+        // The menu bar is the same on every page, the other stops are not.
+        self.focus_stop = self.get_focused_menu();
+        if page == Page::Criteria && self.question_count_pending
+            { self.apply_default_question_count(); }
+        self.show_page(page);
+        self.edit_settings(|settings| settings.set_last_page(page.get_id().to_string()))
+    }
+
+    // fn show_page(&mut self, page: Page)
+    /// Shows `page`, stopping the clock of the self-study session while
+    /// the learning page is left, so that the time away is not charged to
+    /// the question shown when the learner comes back.
+    fn show_page(&mut self, page: Page)
+    {
+        if let Some(session) = &mut self.learning
+        {
+            if page == Page::Learning
+                { session.resume_timer(Instant::now()); }
+            else
+                { session.pause_timer(Instant::now()); }
+        }
+        self.current_page = page;
+    }
+
+    fn set_numbering_style(&mut self, style: NumberingStyle) -> Task<Message>
+    {
+        self.exam_criteria.set_numbering_style(style);
+        if let Some(exam) = &mut self.exam
+            { exam.set_numbering_style(style); }
+        Task::none()
+    }
+
+    fn generate_exam(&mut self) -> Task<Message>
+    {
+        match Exam::generate_balanced(&self.qbank, &self.exam_criteria, &self.question_metas)
+        {
+            Some((mut exam, report)) => {
+                exam.set_bank_metadata(self.bank_metadata.clone());
+                self.exam = Some(exam);
+                self.balance_report = Some(report);
+                self.go_to_page(Page::ExamPreview)
+            },
+            None => {
+                log_event("Error generating exam: Not enough questions for the criteria.");
+                // Say so if it is only the recently used questions that are missing.
+                let mut criteria = self.exam_criteria.clone();
+                criteria.set_avoid_recent(0, &self.usage_history);
+                if !self.exam_criteria.get_avoided().is_empty()
+                    && Exam::generate_with_metas(&self.qbank, &criteria, &self.question_metas).is_some()
+                    { return self.show_error(t!("too-few-unused-questions", count = self.exam_criteria.get_avoid_recent()).to_string()); }
+                Task::none()
+            },
+        }
+    }
+
+    // fn record_usage(&mut self, ids: BTreeSet<u16>)
+    /// Records the questions of an exported exam in the usage history of the
+    /// bank, and keeps them off the next exam if recent questions are avoided.
+    fn record_usage(&mut self, ids: BTreeSet<u16>)
+    {
+        self.usage_history.record(ids, SystemTime::now());
+        let exams = self.exam_criteria.get_avoid_recent();
+        self.exam_criteria.set_avoid_recent(exams, &self.usage_history);
+        if !self.selected_file_path.as_os_str().is_empty()
+            && let Err(e) = save_usage_history(&self.selected_file_path, &self.usage_history)
+            { log_event(&format!("Error saving question usage history: {}", e)); }
+    }
+
+    // fn request_delete_questions(&mut self) -> Task<Message>
+    /// Deletes the picked questions, or asks first if there are at least as
+    /// many as the threshold in the settings.
+    fn request_delete_questions(&mut self) -> Task<Message>
+    {
+        if self.selected_questions.is_empty()
+            { return Task::none(); }
+        let ids = self.selected_questions.clone();
+        if needs_delete_confirmation(ids.len(), self.settings.get_delete_confirm_threshold())
+        {
+            self.pending_delete = Some(ids);
+            return Task::none();
+        }
+        self.delete_questions(ids)
+    }
+
+    fn delete_questions(&mut self, ids: BTreeSet<u16>) -> Task<Message>
+    {
+        let removed = remove_questions(&mut self.qbank, &ids);
+        for id in &ids
+        {
+            self.question_metas.remove(id);
+            self.tag_drafts.remove(id);
+            self.selected_questions.remove(id);
+        }
+        log_event(&format!("Deleted {} questions from the question bank.", removed));
+        self.dirty = true;
+        self.bank_changed = self.has_real_changes();
+        Task::none()
+    }
+
+    fn copy_question(&self, id: u16) -> Task<Message>
+    {
+        match self.qbank.get_questions().iter().find(|question| question.get_id() == id)
+        {
+            Some(question) => iced::clipboard::write(question_clipboard_text(question)),
+            None => Task::none(),
+        }
+    }
+
+    fn generate_from_selection(&mut self) -> Task<Message>
+    {
+        let ids: Vec<u16> = self.selected_questions.iter().copied().collect();
+        match Exam::from_selection(&self.qbank, &ids, &self.exam_criteria, &self.question_metas)
+        {
+            Some(mut exam) => {
+                exam.set_bank_metadata(self.bank_metadata.clone());
+                self.exam = Some(exam);
+                self.balance_report = None;
+                self.go_to_page(Page::ExamPreview)
+            },
+            None => self.show_error(t!("no-questions-selected").to_string()),
+        }
+    }
+
+    fn export_questions_as_bank(&mut self) -> Task<Message>
+    {
+        if self.get_export_questions().is_empty()
+            { return self.show_error(t!("no-questions-to-export").to_string()); }
+        self.pick_save_path(SaveTarget::Questions)
+    }
+
+    // fn build_export_bank(&self) -> QBank
+    /// Returns the questions chosen by the export source as a bank with the
+    /// header of the open bank.
+    fn build_export_bank(&self) -> QBank
+    {
+        let mut qbank = QBank::new_with_header(self.qbank.get_header().clone());
+        for question in self.get_export_questions()
+            { qbank.push_question(question.clone()); }
+        qbank
+    }
+
+    fn questions_exported(&mut self, result: ResultSaveFile) -> Task<Message>
+    {
+        match result
+        {
+            ResultSaveFile::Success(path) | ResultSaveFile::Unverified(path) | ResultSaveFile::Unchecked(path) => {
+                log_event(&format!("Exported questions as a new bank: {}", path.display()));
+                self.show_toast(t!("questions-exported", path = path.display()).to_string());
+                Task::none()
+            },
+            ResultSaveFile::Cancelled => Task::none(),
+            ResultSaveFile::Failed(ExportError::PermissionDenied(path)) => self.show_error(t!("error-export-permission-denied", path = path).to_string()),
+            ResultSaveFile::Failed(e) => self.show_error(e.to_string()),
+        }
+    }
+
+    fn quick_exam(&mut self) -> Task<Message>
+    {
+        match generate_quick(&self.qbank, &self.question_metas)
+        {
+            Ok(mut exam) => {
+                exam.set_bank_metadata(self.bank_metadata.clone());
+                exam.set_choices_per_row(self.exam_criteria.get_choices_per_row());
+                exam.set_answer_key(self.exam_criteria.get_answer_key());
+                self.exam = Some(exam);
+                self.balance_report = None;
+                self.go_to_page(Page::ExamPreview)
+            },
+            Err(GenerateError::NoQuestionBank) => self.show_error(t!("no-question-bank").to_string()),
+            Err(e) => self.show_error(e.to_string()),
+        }
+    }
+
+    fn start_learning(&mut self) -> Task<Message>
+    {
+        match Exam::generate_balanced(&self.qbank, &self.exam_criteria, &self.question_metas).map(|(exam, _)| exam)
+        {
+            Some(exam) => {
+                let mut session = LearningSession::new(exam, Instant::now());
+                session.set_grading_mode(self.grading_mode);
+                session.set_numeric_tolerance(self.settings.get_numeric_tolerance());
+                self.learning = Some(session);
+                self.answer_editor = text_editor::Content::new();
+                self.go_to_page(Page::Learning)
+            },
+            None => {
+                log_event("Error starting self-study: Not enough questions for the criteria.");
+                Task::none()
+            },
+        }
+    }
+
+    fn edit_learning(&mut self, edit: impl FnOnce(&mut LearningSession)) -> Task<Message>
+    {
+        if let Some(session) = &mut self.learning
+            { edit(session); }
+        Task::none()
+    }
+
+    fn set_grading_mode(&mut self, mode: GradingMode) -> Task<Message>
+    {
+        self.grading_mode = mode;
+        if let Some(session) = &mut self.learning
+            { session.set_grading_mode(mode); }
+        Task::none()
+    }
+
+    fn learning_go_to(&mut self, index: usize) -> Task<Message>
+    {
+        if let Some(session) = &mut self.learning
+        {
+            session.go_to(index, Instant::now());
+            self.answer_editor = text_editor::Content::with_text(session.get_typed_answer(session.get_current()));
+        }
+        self.suspend_learning();
+        Task::none()
+    }
+
+    // pub(crate) fn suspend_learning(&mut self)
+    /// Saves the unfinished self-study session beside the loaded bank, so
+    /// that it can be resumed if the application closes.
+    pub(crate) fn suspend_learning(&mut self)
+    {
+        let Some(session) = &mut self.learning
+            else { return; };
+        if session.is_finished() || self.selected_file_path.as_os_str().is_empty()
+            { return; }
+        session.tick(Instant::now());
+        if let Err(e) = save_learning_progress(&self.selected_file_path, &session.to_progress())
+            { log_event(&format!("Error saving self-study progress: {}", e)); }
+    }
+
+    fn resume_learning(&mut self) -> Task<Message>
+    {
+        let Some(progress) = self.pending_resume.take()
+            else { return Task::none(); };
+        let numbering_style = self.exam_criteria.get_numbering_style();
+        match LearningSession::resume(progress, self.qbank.get_header().clone(), numbering_style, self.question_metas.clone(), Instant::now())
+        {
+            Some(mut session) => {
+                session.set_grading_mode(self.grading_mode);
+                session.set_numeric_tolerance(self.settings.get_numeric_tolerance());
+                self.answer_editor = text_editor::Content::with_text(session.get_typed_answer(session.get_current()));
+                self.learning = Some(session);
+                self.go_to_page(Page::Learning)
+            },
+            None => {
+                log_event("Error resuming self-study: The saved progress is damaged.");
+                self.discard_learning_progress()
+            },
+        }
+    }
+
+    fn discard_learning_progress(&mut self) -> Task<Message>
+    {
+        self.pending_resume = None;
+        if let Err(e) = discard_learning_progress(&self.selected_file_path)
+            { log_event(&format!("Error discarding self-study progress: {}", e)); }
+        Task::none()
+    }
+
+    fn edit_answer(&mut self, action: text_editor::Action) -> Task<Message>
+    {
+        let is_edit = action.is_edit();
+        self.answer_editor.perform(action);
+        if !is_edit
+            { return Task::none(); }
+        let answer = self.answer_editor.text();
+        self.edit_learning(|session| session.set_typed_answer(answer))
+    }
+
+    fn finish_learning(&mut self) -> Task<Message>
+    {
+        let Some(session) = &mut self.learning
+            else { return self.go_to_page(Page::GradeReport); };
+        if !session.is_finished()
+        {
+            session.finish(Instant::now());
+            // Every finished attempt feeds the statistics used to
+            // recalibrate the difficulty of its questions.
+            let report = session.grade();
+            for entry in report.get_entries()
+                { self.question_metas.entry(entry.id).or_default().record_attempt(entry.score); }
+            self.attempts.push(to_attempt(&report));
+            let _ = self.save_question_metas();
+            if !self.selected_file_path.as_os_str().is_empty()
+            {
+                if let Err(e) = save_attempts(&self.selected_file_path, &self.attempts)
+                    { log_event(&format!("Error saving graded attempts: {}", e)); }
+                let _ = self.discard_learning_progress();
+            }
+        }
+        self.go_to_page(Page::GradeReport)
+    }
+
+    fn export_item_analysis(&mut self) -> Task<Message>
+    {
+        if self.attempts.is_empty()
+            { return self.show_error(t!("no-graded-attempts").to_string()); }
+        self.pick_save_path(SaveTarget::ItemAnalysis)
+    }
+
+    fn recalibrate_difficulty(&mut self) -> Task<Message>
+    {
+        for meta in self.question_metas.values_mut()
+        {
+            if let Some(level) = meta.suggest_difficulty()
+                { meta.set_difficulty(level); }
+        }
+        self.save_question_metas()
+    }
+
+    fn set_variant_count(&mut self, count: String) -> Task<Message>
+    {
+        if let Ok(count) = count.trim().parse::<usize>()
+            && count > 0
+            { self.variant_count = count; }
+        Task::none()
+    }
+
+    fn export_variants(&mut self, format: ExportFormat) -> Task<Message>
+    {
+        self.pick_save_path(SaveTarget::Variants(format))
+    }
+
+    // fn build_variants(&self) -> Option<Vec<ExamVariant>>
+    /// Generates the exam variants to export, logging why if there are not
+    /// enough questions.
+    fn build_variants(&self) -> Option<Vec<ExamVariant>>
+    {
+        let Some(mut variants) = generate_variants_with_metas(&self.qbank, &self.exam_criteria, &self.question_metas, self.variant_count)
+            else {
+                log_event("Error generating exam variants: Not enough questions for the criteria.");
+                return None;
+            };
+        for variant in variants.iter_mut()
+            { variant.get_exam_mut().set_bank_metadata(self.bank_metadata.clone()); }
+        Some(variants)
+    }
+
+    fn export_student_papers(&mut self, format: ExportFormat) -> Task<Message>
+    {
+        if self.sbank.is_empty()
+            { return self.show_error(t!("no-students").to_string()); }
+        self.pick_save_path(SaveTarget::StudentPapers(format))
+    }
+
+    // fn build_student_papers(&self) -> Option<Vec<ExamVariant>>
+    /// Generates one paper per student, logging why if there are not enough
+    /// questions.
+    fn build_student_papers(&self) -> Option<Vec<ExamVariant>>
+    {
+        let Some(mut papers) = generate_student_papers(&self.qbank, &self.exam_criteria, &self.question_metas, &self.sbank, self.named_papers)
+            else {
+                log_event("Error generating student papers: Not enough questions for the criteria.");
+                return None;
+            };
+        for paper in papers.iter_mut()
+            { paper.get_exam_mut().set_bank_metadata(self.bank_metadata.clone()); }
+        Some(papers)
+    }
+
+    // fn start_export_batch(&mut self, batch: Option<ExportBatch>) -> Task<Message>
+    /// Starts writing the papers of `batch`, or asks first if that would
+    /// replace existing files.
+    fn start_export_batch(&mut self, batch: Option<ExportBatch>) -> Task<Message>
+    {
+        let Some(batch) = batch
+            else { return Task::none(); };
+        let existing = batch.get_existing_files();
+        if !existing.is_empty() && !self.skip_overwrite_confirm
+        {
+            self.dont_ask_overwrite = false;
+            self.pending_batch = Some((batch, existing));
+            return Task::none();
+        }
+        self.run_export_batch(batch)
+    }
+
+    fn run_export_batch(&mut self, batch: ExportBatch) -> Task<Message>
+    {
+        let (done, total) = batch.get_progress();
+        self.export_batch = Some(batch);
+        Task::done(Message::ExportProgress(done, total))
+    }
+
+    // fn advance_export_batch(&mut self, done: usize, total: usize) -> Task<Message>
+    /// Shows the progress and writes the next paper; each paper is written
+    /// in its own update so that the window is redrawn in between.
+    fn advance_export_batch(&mut self, done: usize, total: usize) -> Task<Message>
+    {
+        self.export_progress = Some((done, total));
+        let Some(batch) = &mut self.export_batch
+            else { return Task::none(); };
+        match batch.export_next()
+        {
+            Some((done, total)) => Task::done(Message::ExportProgress(done, total)),
+            None => {
+                let reports = self.export_batch.take().map(ExportBatch::into_reports);
+                self.variants_exported(reports)
+            },
+        }
+    }
+
+    fn variants_exported(&mut self, reports: Option<Vec<VariantReport>>) -> Task<Message>
+    {
+        if let Some(reports) = reports
+        {
+            for report in reports.iter()
+            {
+                if let Err(e) = &report.result
+                    { log_event(&format!("Error exporting variant {}: {}", report.name, e)); }
+            }
+            // The papers of one batch are handed out at the same sitting.
+            let ids: BTreeSet<u16> = reports.iter()
+                                        .filter(|report| matches!(report.result, Ok(()) | Err(ExportError::Unverified(_))))
+                                        .flat_map(|report| report.question_ids.iter().copied())
+                                        .collect();
+            self.record_usage(ids);
+            let summary = GenerationSummary::from_reports(&reports, SystemTime::now());
+            if let Some(dir) = reports.first().and_then(|report| report.path.parent())
+                && let Err(e) = save_summary(&get_summary_path(dir), &summary)
+                { log_event(&format!("Error saving generation summary: {}", e)); }
+            self.generation_summary = Some(summary);
+            self.variant_reports = reports;
+        }
+        Task::none()
+    }
+
+    fn save_generation_summary(&mut self) -> Task<Message>
+    {
+        match &self.generation_summary
+        {
+            Some(_) => self.pick_save_path(SaveTarget::GenerationSummary),
+            None => Task::none(),
+        }
+    }
+
+    fn export_exam(&mut self, format: ExportFormat) -> Task<Message>
+    {
+        match &self.exam
+        {
+            Some(_) => self.pick_save_path(SaveTarget::Exam(format)),
+            None => Task::none(),
+        }
+    }
+
+    fn export_path_chosen(&mut self, format: ExportFormat, path: Option<PathBuf>) -> Task<Message>
+    {
+        match path
+        {
+            Some(path) => self.save_path_chosen(SaveTarget::Exam(format), path),
+            None => Task::none(),
+        }
+    }
+
+    fn confirm_overwrite(&mut self) -> Task<Message>
+    {
+        if self.is_overwrite_pending() && self.dont_ask_overwrite
+            { self.skip_overwrite_confirm = true; }
+        if let Some((batch, _)) = self.pending_batch.take()
+            { return self.run_export_batch(batch); }
+        let Some((path, target)) = self.pending_overwrite.take()
+            else { return Task::none(); };
+        self.save_to(target, path)
+    }
+
+    fn write_exam(&mut self, path: &Path, format: ExportFormat) -> Task<Message>
+    {
+        match &self.exam
+        {
+            Some(exam) => {
+                let result = SaveFile::write_exam(exam, path, format);
+                self.exam_exported(result)
+            },
+            None => Task::none(),
+        }
+    }
+
+    fn open_student_form(&mut self, index: Option<usize>) -> Task<Message>
+    {
+        self.student_form = match index.and_then(|i| self.sbank.get(i).map(|student| (i, student)))
+        {
+            Some((i, student)) => Some(StudentForm::new_for_edit(i, student, self.get_student_group(student.get_id()))),
+            None => Some(StudentForm::new_for_add()),
+        };
+        Task::none()
+    }
+
+    fn edit_student_form(&mut self, edit: impl FnOnce(&mut StudentForm)) -> Task<Message>
+    {
+        if let Some(form) = &mut self.student_form
+            { edit(form); }
+        Task::none()
+    }
+
+    fn submit_student_form(&mut self) -> Task<Message>
+    {
+        let Some(form) = &mut self.student_form
+            else { return Task::none(); };
+        let student = form.to_student();
+        let previous_id = form.get_editing().and_then(|i| self.sbank.get(i)).map(|s| s.get_id().clone());
+        let result = match form.get_editing()
+        {
+            Some(index) => update_student(&mut self.sbank, index, student.clone()),
+            None => add_student(&mut self.sbank, student.clone()),
+        };
+        match result
+        {
+            Ok(()) => {
+                if let Some(previous_id) = previous_id
+                    { self.student_groups.remove(&previous_id); }
+                let group = form.get_group().trim().to_string();
+                if !group.is_empty()
+                    { self.student_groups.insert(student.get_id().clone(), group); }
+                self.student_form = None;
+                self.dirty = true;
+                self.roster_edited = true;
+            },
+            Err(e) => form.set_error(Some(e)),
+        }
+        Task::none()
+    }
+
+    fn delete_student(&mut self, index: usize) -> Task<Message>
+    {
+        if let Ok(student) = delete_student(&mut self.sbank, index)
+        {
+            self.student_groups.remove(student.get_id());
+            self.dirty = true;
+            self.roster_edited = true;
+        }
+        Task::none()
+    }
+
+    fn attach_question_image(&mut self, id: u16, path: PathBuf) -> Task<Message>
+    {
+        if path.as_os_str().is_empty()
+            { Task::none() }
+        else
+            { self.edit_question_meta(id, |meta| meta.set_image(Some(ImageRef::Path(path)))) }
+    }
+
+    fn edit_question_meta(&mut self, id: u16, edit: impl FnOnce(&mut QuestionMeta)) -> Task<Message>
+    {
+        edit(self.question_metas.entry(id).or_default());
+        self.save_question_metas()
+    }
+
+    fn save_question_metas(&mut self) -> Task<Message>
+    {
+        if self.selected_file_path.as_os_str().is_empty()
+            { return Task::none(); }
+        match save_question_metas(&self.selected_file_path, &self.question_metas)
+        {
+            // The question metadata is saved on its own, beside the rest of the bank as last saved.
+            Ok(()) => if let Some((qbank, metadata)) = &self.saved_bank
+                { self.saved_fingerprint = Some(fingerprint_bank(qbank, &self.question_metas, metadata)); },
+            Err(e) => log_event(&format!("Error saving question metadata: {}", e)),
+        }
+        Task::none()
+    }
+
+    fn exam_exported(&mut self, result: ResultSaveFile) -> Task<Message>
+    {
+        if let (ResultSaveFile::Success(_) | ResultSaveFile::Unverified(_) | ResultSaveFile::Unchecked(_), Some(exam)) = (&result, &self.exam)
+        {
+            let ids = exam.get_questions().iter().map(|question| question.get_id()).collect();
+            self.record_usage(ids);
+        }
+        match result
+        {
+            ResultSaveFile::Success(path) => {
+                log_event(&format!("Exported exam: {}", path.display()));
+                self.show_toast(t!("exam-exported", path = path.display()).to_string());
+            },
+            ResultSaveFile::Unverified(path) => {
+                log_event(&format!("Warning: the exported exam failed the integrity check: {}", path.display()));
+                self.show_toast(t!("exam-export-unverified", path = path.display()).to_string());
+            },
+            ResultSaveFile::Unchecked(path) => {
+                log_event(&format!("Exported exam, its questions not counted: {}", path.display()));
+                self.show_toast(t!("exam-export-unchecked", path = path.display()).to_string());
+            },
+            ResultSaveFile::Cancelled => {},
+            ResultSaveFile::Failed(ExportError::PermissionDenied(path)) => return self.show_error(t!("error-export-permission-denied", path = path).to_string()),
+            ResultSaveFile::Failed(e) => return self.show_error(e.to_string()),
+        }
+        Task::none()
+    }
+
+    // pub fn calculate_text_width_estimate(&self, name: &str) -> f32
+    /// Calculates the estimated width of a given string `name` based on character type and font size.
+    ///
+    /// The string is measured per grapheme cluster, so that a letter with
+    /// combining marks and an emoji sequence joined by zero-width joiners
+    /// each count as one character, and zero-width code points on their
+    /// own count as nothing.
+    ///
+    /// # Arguments
+    /// * `name` - The string whose width is to be estimated.
+    ///
+    /// # Output
+    /// The estimated width of the string as an `f32`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ControlTower;
+    ///
+    /// let (mut control_tower, _) = ControlTower::new();
+    /// control_tower.set_menu_font_size_in_pixel(20.0);
+    /// let width = |name: &str| -> f32 { control_tower.calculate_text_width_estimate(name) };
+    /// let about = |a: f32, b: f32| -> bool { (a - b).abs() < 0.01 };
+    ///
+    /// assert!(about(width("문제은행 관리"), 112.8));
+    /// assert!(about(width("File Menu"), 97.2));
+    /// assert!(about(width("한글 abc"), 77.2));
+    ///
+    /// // "e" followed by a combining acute accent is as wide as "é".
+    /// assert!(about(width("Caf\u{0065}\u{0301}"), width("Café")));
+    /// assert!(about(width("\u{0301}\u{200B}"), 0.0));
+    ///
+    /// // An emoji sequence counts once, however many code points it joins.
+    /// let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    /// assert!(about(width(family), width("\u{1F600}")));
+    /// assert!(about(width("\u{1F1F0}\u{1F1F7}"), width("\u{1F600}")));
+    /// assert!(about(width("\u{2764}\u{FE0F}"), width("\u{2764}")));
+    /// ```
+    pub fn calculate_text_width_estimate(&self, name: &str) -> f32
+    {
+        let mut width = 0.0;
+        for cluster in name.graphemes(true)
+        {
+            // A cluster is measured by its first code point; the marks and joiners after it take no room.
+            let Some(c) = cluster.chars().find(|&c| !Self::is_zero_width(c))
+                else { continue };
+            // Check for Korean (Hangul Syllables), Hanja (CJK Unified Ideographs), Hiragana, Katakana
+            if ('\u{AC00}'..='\u{D7AF}').contains(&c) || // Hangul Syllables
+               ('\u{4E00}'..='\u{9FFF}').contains(&c) || // CJK Unified Ideographs (Hanja)
+               ('\u{3040}'..='\u{30FF}').contains(&c) || // Hiragana & Katakana
+               ('\u{2600}'..='\u{27BF}').contains(&c) || // Miscellaneous Symbols & Dingbats
+               ('\u{1F000}'..='\u{1FAFF}').contains(&c)  // Emoji, including regional indicators
+            {
+                width += self.menu_font_size_in_pixel * 0.85;
+            }
+            else if ('\u{0400}'..='\u{04FF}').contains(&c) // Cyrillic
+            {
+                width += self.menu_font_size_in_pixel * 0.56;
+            }
+            else // Assume Basic Latin and others
+            {
+                width += self.menu_font_size_in_pixel * 0.54;
+            }
+        }
+        width
+    }
+
+    // fn is_zero_width(c: char) -> bool
+    /// Returns whether `c` is drawn without a width of its own: a combining
+    /// mark, a variation selector, a zero-width space or joiner, or a
+    /// byte order mark.
+    fn is_zero_width(c: char) -> bool
+    {
+        ('\u{0300}'..='\u{036F}').contains(&c) || // Combining Diacritical Marks
+        ('\u{1AB0}'..='\u{1AFF}').contains(&c) || // Combining Diacritical Marks Extended
+        ('\u{1DC0}'..='\u{1DFF}').contains(&c) || // Combining Diacritical Marks Supplement
+        ('\u{20D0}'..='\u{20FF}').contains(&c) || // Combining Diacritical Marks for Symbols
+        ('\u{FE20}'..='\u{FE2F}').contains(&c) || // Combining Half Marks
+        ('\u{FE00}'..='\u{FE0F}').contains(&c) || // Variation Selectors
+        ('\u{200B}'..='\u{200F}').contains(&c) || // Zero-width space, joiners and marks
+        ('\u{2060}'..='\u{2064}').contains(&c) || // Word joiner and invisible operators
+        c == '\u{FEFF}'                          // Byte order mark
+    }
+
+    // pub fn calculate_submenu_width<S: AsRef<str>>(&self, labels: &[S]) -> f32
+    /// Calculates the width of the submenu so that its widest label fits.
+    ///
+    /// The width of each label is estimated at the menu font size, the
+    /// button padding is added, and the result is clamped between
+    /// `SUBMENU_MIN_WIDTH` and `SUBMENU_MAX_WIDTH`.
+    ///
+    /// # Arguments
+    /// * `labels` - The translated labels of the submenu items.
+    ///
+    /// # Output
+    /// The width of the submenu column in logical pixels.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ControlTower;
+    /// let (control_tower, _) = ControlTower::new();
+    ///
+    /// let short = control_tower.calculate_submenu_width(&["Help"]);
+    /// assert_eq!(short, ControlTower::SUBMENU_MIN_WIDTH);
+    ///
+    /// let long = control_tower.calculate_submenu_width(&["Help", "Критерии отбора вопросов"]);
+    /// let longer = control_tower.calculate_submenu_width(&["Help", "Критерии отбора вопросов экзамена"]);
+    /// assert!(long > short);
+    /// assert!(longer > long);
+    ///
+    /// let huge = "Очень длинный пункт меню ".repeat(10);
+    /// assert_eq!(control_tower.calculate_submenu_width(&[huge]), ControlTower::SUBMENU_MAX_WIDTH);
+    /// ```
+    pub fn calculate_submenu_width<S: AsRef<str>>(&self, labels: &[S]) -> f32
+    {
+        let button_padding = 8.0;
+        let widest = labels.iter()
+                        .map(|label| self.calculate_text_width_estimate(label.as_ref()))
+                        .fold(0.0, f32::max);
+        (widest + button_padding * 2.0 + 2.0).clamp(Self::SUBMENU_MIN_WIDTH, Self::SUBMENU_MAX_WIDTH)
+    }
+
+    // pub fn view(&self) -> Element<'_, Message>
+    /// Returns the current view of the application as an [iced::Element].
+    ///
+    /// # Output
+    /// An [iced::Element] representing the user interface.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::widget::text;
+    /// use iced::Element;
+    /// use qrate_gui::{ ControlTower, Message };
+    ///
+    /// let (control_tower, _) = ControlTower::new();
+    /// let _view_element: Element<'_, Message> = control_tower.view();
+    /// // In a real application, you would inspect the generated Element.
+    /// // For this conceptual example, we just ensure it compiles and returns an Element.
+    /// // More detailed assertions would require inspecting the widget tree.
+    /// ```
+    pub fn view(&self) -> Element<'_, Message>
     {
+        // 화면이 잠겨 있으면 메뉴와 대화상자를 모두 숨기고 잠금 화면만 보여 줍니다.
+        if self.is_locked()
+            { return self.view_lock_screen(); }
+
         // Define menu keys, not translated strings
-        let menu_keys = vec![
-            "question-bank-management",
-            "generate-exam-paper",
-            "student-list-management",
-            "self-study",
-            "settings",
-            "information",
-        ];
+        let menu_keys = MENU_KEYS.to_vec();
         let menu_bar_spacing = 10.0; // Spacing for the menu bar
         let button_padding = 5.0; // Padding for each button
 
@@ -598,159 +5895,1131 @@ impl ControlTower
             }
         }
 
-        let menu_bar = row(menu_keys.into_iter().map(|key| {
-            button(text(t!(key)).size(self.menu_font_size_in_pixel))
-                .on_press(Message::MenuClicked(key.to_string()))
+        let menu_bar = row(menu_keys.into_iter().enumerate().map(|(index, key)| {
+            self.menu_button(key, Some(Message::MenuClicked(key.to_string())))
+                .focused(self.get_focused_menu() == Some(index))
+                .into_button()
                 .padding(button_padding as u16)
                 .width(Length::Shrink)
-                .style(|_theme: &Theme, status| {
-                    let mut style = button::Style::default();
-                    style.background = Some(Color::WHITE.into());
-                    style.text_color = Color::BLACK;
-
-                    match status
-                    {
-                        button::Status::Hovered => { style.background = Some(Color::from_rgb(0.9, 0.9, 0.9).into()); },
-                        button::Status::Pressed => { style.background = Some(Color::from_rgb(0.8, 0.8, 0.8).into()); },
-                        _ => {}
-                    }
-                    style
-                })
                 .into()
         }))
         .spacing(menu_bar_spacing)
         .padding(5);
 
-        // Submenu area
-        let sub_menu_area: Element<'_, Message> = if !self.current_menu_key.is_empty()
-        {
-            let items = match self.current_menu_key.as_str()
-            {
-                "question-bank-management" => vec![
-                    "create-new-question-bank",
-                    "load-question-bank",
-                    "edit",
-                    "export",
-                    "export-as",
-                    "optimize",
-                ],
-                "generate-exam-paper" => vec![
-                    "load-question-bank",
-                    "criteria-for-question-extraction",
-                    "load-student-list",
-                    "export-exam-paper",
-                ],
-                "student-list-management" => vec![
-                    "load",
-                    "edit",
-                    "export",
-                    "export-as",
-                ],
-                "self-study" => vec![
-                    "load-question-bank",
-                    "criteria-for-question-extraction",
-                    "grading-criteria",
-                    "take-exam",
-                ],
-                "settings" => vec![
-                    "storage-path",
-                    "atmosphere",
-                    "font",
-                    "language",
-                ],
-                "information" => vec![
-                    "help",
-                    "software-info",
-                    "copyright-info",
-                ],
-                _ => vec!["coming-soon"],
-            };
+        // Submenu area
+        let sub_menu_area: Element<'_, Message> = if !self.current_menu_key.is_empty()
+        {
+            let items = get_submenu_keys(&self.current_menu_key);
+
+            let labels: Vec<String> = items.iter().map(|item_key| t!(*item_key).to_string()).collect();
+            let submenu_width = self.calculate_submenu_width(&labels);
+
+            container(
+                column(items.iter().copied().map(|item_key| {
+                    let on_press_message = if self.current_menu_key == "settings" && item_key == "language"
+                        { Message::GoToPage(Page::LanguageSettings) }
+                    else if self.current_menu_key == "settings" && item_key == "startup"
+                        { Message::GoToPage(Page::StartupSettings) }
+                    else if self.current_menu_key == "settings" && item_key == "idle-lock"
+                        { Message::GoToPage(Page::IdleLockSettings) }
+                    else if self.current_menu_key == "settings" && item_key == "storage-path"
+                        { Message::GoToPage(Page::StorageSettings) }
+                    else if self.current_menu_key == "settings" && item_key == "atmosphere"
+                        { Message::GoToPage(Page::AtmosphereSettings) }
+                    else if self.current_menu_key == "student-list-management" && item_key == "edit"
+                        { Message::GoToPage(Page::StudentList) }
+                    else if self.current_menu_key == "question-bank-management" && item_key == "edit"
+                        { Message::GoToPage(Page::QuestionBankEdit) }
+                    else if item_key == "open-containing-folder"
+                        { Message::OpenContainingFolder }
+                    else
+                        { Message::SubMenuClicked(item_key.to_string()) };
+                    // 선택된 파일이 없으면 "파일 위치 열기"는 비활성화합니다.
+                    let enabled = item_key != "open-containing-folder" || self.can_open_containing_folder();
+
+                    self.menu_button(item_key, enabled.then_some(on_press_message))
+                        .into_button()
+                        .width(Length::Fill)
+                        .padding(8)
+                        .into()
+                }))
+                .spacing(2)
+                .width(submenu_width)
+            )
+            .padding(5)
+            .style(|theme: &Theme| {
+                container::Style {
+                    background: Some(theme.extended_palette().background.base.color.into()),
+                    ..Default::default()
+                }
+            })
+            .into()
+        }
+        else
+        {
+            iced::widget::Space::new().into() // 서브메뉴 없으면 빈 공간
+        };
+
+        // Render main content or specific page based on current_page
+        let main_content_area: Element<'_, Message> = match self.current_page {
+            Page::Main => {
+                // 3. 메인 화면
+                let path_text = if !self.selected_file_path.as_os_str().is_empty()
+                    { let path = &self.selected_file_path; t!("selected-file", path = &path.to_string_lossy()).to_string() }
+                else
+                    { t!("no-file-selected").to_string() };
+
+                let mut welcome = column![
+                    text(t!("welcome-message")).size(32),
+                    text(path_text).size(18),
+                ]
+                .spacing(20);
+                if self.qbank.get_questions().is_empty()
+                {
+                    welcome = welcome.push(button(text(t!("load-sample-data")).size(self.menu_font_size_in_pixel))
+                                                .on_press(Message::LoadSampleBank)
+                                                .padding(8));
+                }
+
+                center(welcome)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .into()
+            },
+            Page::LanguageSettings => {
+                // Language selection page
+                let available_locales = Self::get_available_locales();
+                let _current_i18n_locale = rust_i18n::locale(); // Get current i18n locale
+                // Only the fallback is left when the other locale files are missing.
+                let missing_note = (available_locales.len() <= 1)
+                                    .then(|| text(t!("other-locales-missing")).size(16).color(Color::from_rgb(0.8, 0.1, 0.1)));
+                let export_languages: Vec<ExportLanguage> = std::iter::once(ExportLanguage { name: t!("same-as-interface").to_string(), locale: None })
+                    .chain(available_locales.iter().map(|(name, locale)| ExportLanguage { name: name.clone(), locale: Some(locale.clone()) }))
+                    .collect();
+                let export_language = export_languages.iter()
+                                        .find(|language| language.locale.as_deref() == self.settings.get_export_locale())
+                                        .cloned();
+                let language_buttons = available_locales.into_iter().fold(
+                    column![].spacing(10),
+                    |col: iced::widget::Column<'_, Message>, (language_name, locale)| {
+                        col.push(
+                            button(text(language_name).size(self.menu_font_size_in_pixel))
+                                .on_press(Message::SetLocale(locale))
+                                .width(Length::Fill)
+                                .padding(8),
+                        )
+                    },
+                );
+
+                column![
+                    text(t!("language")).size(32),
+                    language_buttons,
+                ]
+                .push(missing_note)
+                .push(row![
+                    text(t!("export-language")).size(18),
+                    pick_list(export_languages, export_language, |language: ExportLanguage| Message::SetExportLocale(language.locale)),
+                ].spacing(10).align_y(iced::Alignment::Center))
+                .push(iced::widget::Space::new().height(Length::Fixed(20.0)))
+                .push(button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                        .on_press(Message::GoToPage(Page::Main))
+                        .width(Length::Fill)
+                        .padding(8))
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::AtmosphereSettings => {
+                // Atmosphere settings page
+                let theme = self.settings.get_theme();
+                let theme_radios = ThemeChoice::ALL.into_iter().fold(
+                    row![].spacing(20),
+                    |theme_row: iced::widget::Row<'_, Message>, option| {
+                        theme_row.push(radio(t!(option.get_key()), option, Some(theme), Message::SetTheme)
+                                        .size(self.menu_font_size_in_pixel))
+                    },
+                );
+                column![
+                    text(t!("atmosphere")).size(32),
+                    text(t!("theme")).size(self.menu_font_size_in_pixel),
+                    theme_radios,
+                    checkbox(self.settings.get_reduce_motion())
+                        .label(t!("reduce-motion"))
+                        .on_toggle(Message::SetReduceMotion)
+                        .size(self.menu_font_size_in_pixel),
+                    checkbox(self.settings.get_keep_menu_open())
+                        .label(t!("keep-menu-open"))
+                        .on_toggle(Message::SetKeepMenuOpen)
+                        .size(self.menu_font_size_in_pixel),
+                    iced::widget::Space::new().height(Length::Fixed(20.0)),
+                    button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                        .on_press(Message::GoToPage(Page::Main))
+                        .width(Length::Fill)
+                        .padding(8),
+                ]
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::IdleLockSettings => {
+                // Idle lock settings page
+                let periods: Vec<IdleLockPeriod> = std::iter::once(None)
+                    .chain(IDLE_LOCK_MINUTES.into_iter().map(Some))
+                    .map(|minutes| IdleLockPeriod { minutes })
+                    .collect();
+                let pin_note = if self.settings.has_idle_lock_pin() { t!("pin-set") } else { t!("pin-not-set") };
+                column![
+                    text(t!("idle-lock")).size(32),
+                    row![
+                        text(t!("idle-lock-period")).size(self.menu_font_size_in_pixel),
+                        pick_list(periods, Some(IdleLockPeriod { minutes: self.settings.get_idle_lock_minutes() }),
+                                  |period| Message::SetIdleLockMinutes(period.minutes))
+                            .text_size(self.menu_font_size_in_pixel),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                    row![
+                        text_input(&t!("pin-placeholder"), &self.pin_text)
+                            .id(IDLE_LOCK_PIN_INPUT)
+                            .secure(true)
+                            .on_input(Message::PinTextChanged)
+                            .on_submit(Message::SetIdleLockPin)
+                            .width(Length::Fixed(240.0))
+                            .padding(8),
+                        button(text(t!("set-pin")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::SetIdleLockPin)
+                            .padding(8),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                    text(pin_note).size(16),
+                    text(t!("idle-lock-deterrent")).size(16),
+                    iced::widget::Space::new().height(Length::Fixed(20.0)),
+                    button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                        .on_press(Message::GoToPage(Page::Main))
+                        .width(Length::Fill)
+                        .padding(8),
+                ]
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::StartupSettings => {
+                // Startup settings page
+                column![
+                    text(t!("startup")).size(32),
+                    checkbox(self.settings.get_restore_last_page())
+                        .label(t!("restore-last-page"))
+                        .on_toggle(Message::SetRestoreLastPage)
+                        .size(self.menu_font_size_in_pixel),
+                    iced::widget::Space::new().height(Length::Fixed(20.0)),
+                    button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                        .on_press(Message::GoToPage(Page::Main))
+                        .width(Length::Fill)
+                        .padding(8),
+                ]
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::StorageSettings => {
+                // Storage path page: where the question bank dialog starts
+                let current = match self.settings.get_storage_path()
+                {
+                    Some(path) => path.display().to_string(),
+                    None => t!("storage-path-unset").to_string(),
+                };
+                let dialog = self.settings.get_file_dialog();
+                let autosave_periods: Vec<AutosavePeriod> = std::iter::once(None)
+                    .chain(AUTOSAVE_MINUTES.into_iter().map(Some))
+                    .map(|minutes| AutosavePeriod { minutes })
+                    .collect();
+                let dialog_radios = FileDialogKind::ALL.into_iter().fold(
+                    row![].spacing(20),
+                    |dialog_row: iced::widget::Row<'_, Message>, option| {
+                        dialog_row.push(radio(t!(option.get_key()), option, Some(dialog), Message::SetFileDialog)
+                                        .size(self.menu_font_size_in_pixel))
+                    },
+                );
+                column![
+                    text(t!("storage-path")).size(32),
+                    text(current).size(18),
+                    text(t!("storage-path-hint", path = self.settings.get_dialog_directory().display())).size(14),
+                    row![
+                        button(text(t!("choose-folder")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::PickStoragePath)
+                            .padding(8),
+                        button(text(t!("clear")).size(self.menu_font_size_in_pixel))
+                            .on_press_maybe(self.settings.get_storage_path().is_some().then_some(Message::ClearStoragePath))
+                            .padding(8),
+                    ]
+                    .spacing(10),
+                    text(t!("file-dialog")).size(self.menu_font_size_in_pixel),
+                    dialog_radios,
+                    row![
+                        text(t!("autosave")).size(self.menu_font_size_in_pixel),
+                        pick_list(autosave_periods, Some(AutosavePeriod { minutes: self.settings.get_autosave_minutes() }),
+                                  |period| Message::SetAutosaveMinutes(period.minutes))
+                            .text_size(self.menu_font_size_in_pixel),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                    iced::widget::Space::new().height(Length::Fixed(20.0)),
+                    button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                        .on_press(Message::GoToPage(Page::Main))
+                        .width(Length::Fill)
+                        .padding(8),
+                ]
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::Criteria => {
+                // Criteria page: numbering style
+                let current_style = self.exam_criteria.get_numbering_style();
+                let numbering_radios = NumberingStyle::ALL.into_iter().fold(
+                    column![].spacing(10),
+                    |col: iced::widget::Column<'_, Message>, style| {
+                        col.push(radio(t!(style.get_key()), style, Some(current_style), Message::SetNumberingStyle)
+                                    .size(self.menu_font_size_in_pixel))
+                    },
+                );
+
+                // Criteria page: question order
+                let current_order = self.exam_criteria.get_question_order();
+                let order_radios = QuestionOrder::ALL.into_iter().fold(
+                    column![].spacing(10),
+                    |col: iced::widget::Column<'_, Message>, order| {
+                        col.push(radio(t!(order.get_key()), order, Some(current_order), Message::SetQuestionOrder)
+                                    .size(self.menu_font_size_in_pixel))
+                    },
+                );
+                // 분류별로 묶을 때는 분류의 순서를 위아래로 바꿀 수 있습니다.
+                let arranged = if current_order == QuestionOrder::ByCategory { self.get_arranged_categories() } else { Vec::new() };
+                let count = arranged.len();
+                let order_radios = arranged.iter().enumerate().fold(
+                    order_radios,
+                    |col: iced::widget::Column<'_, Message>, (position, category)| {
+                        col.push(row![
+                                    button(text("▲").size(16))
+                                        .on_press_maybe((position > 0).then(|| Message::MoveCategory { from: position, to: position - 1 }))
+                                        .padding(4),
+                                    button(text("▼").size(16))
+                                        .on_press_maybe((position + 1 < count).then(|| Message::MoveCategory { from: position, to: position + 1 }))
+                                        .padding(4),
+                                    text(category.get_label()).size(self.menu_font_size_in_pixel),
+                                ]
+                                .spacing(6)
+                                .padding(iced::Padding::default().left(30))
+                                .align_y(iced::Alignment::Center))
+                    },
+                );
+
+                let current_columns = self.exam_criteria.get_choices_per_row();
+                let column_radios = (1..=MAX_CHOICES_PER_ROW).fold(
+                    row![].spacing(10),
+                    |radio_row: iced::widget::Row<'_, Message>, count| {
+                        radio_row.push(radio(count.to_string(), count, Some(current_columns), Message::SetChoicesPerRow)
+                                        .size(self.menu_font_size_in_pixel))
+                    },
+                );
+
+                // 답안 공간: 모든 문항 공통 또는 문항 유형별 빈 줄 수
+                let space = self.exam_criteria.get_answer_space();
+                let line_counts: Vec<usize> = (0..=MAX_ANSWER_SPACE_LINES).collect();
+                let kind_line_counts: Vec<KindAnswerSpace> = std::iter::once(KindAnswerSpace { lines: None })
+                                                                .chain(line_counts.iter().map(|&lines| KindAnswerSpace { lines: Some(lines) }))
+                                                                .collect();
+                let answer_space = QuestionKind::ALL.into_iter().fold(
+                    column![
+                        row![
+                            text(t!("all-questions")).size(16).width(Length::Fixed(200.0)),
+                            pick_list(line_counts, Some(space.get_lines()), Message::SetAnswerSpaceLines).text_size(16),
+                        ]
+                        .spacing(10)
+                        .align_y(iced::Alignment::Center),
+                    ].spacing(6),
+                    |col: iced::widget::Column<'_, Message>, kind| {
+                        let current = KindAnswerSpace { lines: space.get_kind_lines(kind) };
+                        col.push(row![
+                                    text(t!(kind.get_key())).size(16).width(Length::Fixed(200.0)),
+                                    pick_list(kind_line_counts.clone(), Some(current), move |choice| Message::SetKindAnswerSpace(kind, choice.lines))
+                                        .text_size(16),
+                                ]
+                                .spacing(10)
+                                .align_y(iced::Alignment::Center))
+                    },
+                );
+
+                // 시드를 비워 두면 시험지마다 새로 뽑고, 숫자를 넣으면 같은 시험지를 다시 만듭니다.
+                // 숫자가 아닌 입력은 무시합니다.
+                let seed = self.exam_criteria.get_seed();
+
+                // 페이지 머리글과 바닥글: 자리 표시자는 쪽마다 채워집니다.
+                let template = self.exam_criteria.get_page_template();
+                let page_template = column![
+                    text_input(&t!("page-header"), template.get_header()).id(PAGE_HEADER_INPUT).on_input(Message::SetPageHeader).padding(6),
+                    text_input(&t!("page-footer"), template.get_footer()).id(PAGE_FOOTER_INPUT).on_input(Message::SetPageFooter).padding(6),
+                    text_input(&t!("page-logo"), &template.get_logo().map(|logo| logo.display().to_string()).unwrap_or_default())
+                        .id(PAGE_LOGO_INPUT)
+                        .on_input(Message::SetPageLogo)
+                        .padding(6),
+                    text(t!("page-template-hint", placeholders = PAGE_PLACEHOLDERS.join(", "))).size(14),
+                    PaperSize::ALL.into_iter().fold(
+                        row![ text(t!("paper-size")).size(16) ].spacing(10).align_y(iced::Alignment::Center),
+                        |row: iced::widget::Row<'_, Message>, paper_size| {
+                            row.push(radio(t!(paper_size.get_key()), paper_size, Some(template.get_paper_size()), Message::SetPaperSize)
+                                        .size(self.menu_font_size_in_pixel))
+                        },
+                    )
+                    .push(text(t!("page-margin")).size(16))
+                    .push(pick_list(PAGE_MARGINS.to_vec(), Some(template.get_margin()), Message::SetPageMargin).text_size(16)),
+                ]
+                .spacing(6);
+
+                let info = self.exam_criteria.get_info();
+                let info_fields = column![
+                    text_input(&t!("exam-title"), info.get_title()).id(EXAM_TITLE_INPUT).on_input(Message::ExamTitleChanged).padding(6),
+                    text_input(&t!("exam-course"), info.get_course()).id(EXAM_COURSE_INPUT).on_input(Message::ExamCourseChanged).padding(6),
+                    text_input(&t!("exam-date"), info.get_date()).id(EXAM_DATE_INPUT).on_input(Message::ExamDateChanged).padding(6),
+                    text_input(&t!("exam-instructions"), info.get_instructions()).id(EXAM_INSTRUCTIONS_INPUT).on_input(Message::ExamInstructionsChanged).padding(6),
+                ]
+                .spacing(6);
+
+                // 태그 필터: 모두(AND) 또는 하나 이상(OR)
+                let current_mode = self.exam_criteria.get_tag_filter().get_mode();
+                let tag_modes = TagMode::ALL.into_iter().fold(
+                    row![].spacing(10),
+                    |mode_row: iced::widget::Row<'_, Message>, mode| {
+                        mode_row.push(radio(t!(mode.get_key()), mode, Some(current_mode), Message::SetTagMode)
+                                        .size(self.menu_font_size_in_pixel))
+                    },
+                );
+
+                let category_checkboxes = self.categories.iter().fold(
+                    column![].spacing(6),
+                    |col: iced::widget::Column<'_, Message>, category| {
+                        let toggled = category.clone();
+                        col.push(checkbox(self.exam_criteria.is_category_included(category))
+                                    .label(category.get_label())
+                                    .on_toggle(move |included| Message::SetCategoryIncluded(toggled.clone(), included))
+                                    .size(self.menu_font_size_in_pixel))
+                    },
+                );
+
+                // 난이도 범위: 거꾸로 입력한 범위는 바로잡고 알려 줍니다.
+                let range = self.exam_criteria.get_difficulty_range();
+                let pinned_note = if parse_pins(&self.pinned_text).is_some() { t!("pinned-questions-hint") } else { t!("pinned-questions-invalid") };
+                let sections_note = if parse_sections(&self.sections_text).is_some() { t!("sections-hint") } else { t!("sections-invalid") };
+                let levels: Vec<u8> = (1..=MAX_DIFFICULTY).collect();
+                let mut difficulty_range = column![
+                    row![
+                        pick_list(levels.clone(), range.map(|(min, _)| min), Message::SetMinDifficulty)
+                            .placeholder(t!("any-difficulty"))
+                            .text_size(16),
+                        text("–").size(16),
+                        pick_list(levels, range.map(|(_, max)| max), Message::SetMaxDifficulty)
+                            .placeholder(t!("any-difficulty"))
+                            .text_size(16),
+                        button(text(t!("clear")).size(16))
+                            .on_press_maybe(range.map(|_| Message::ClearDifficultyRange))
+                            .padding(4),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                ].spacing(4);
+                if let Some((min, max)) = range.filter(|_| self.difficulty_range_swapped)
+                    { difficulty_range = difficulty_range.push(text(t!("difficulty-range-swapped", min = min, max = max)).size(14)); }
+
+                // 목표 난이도 분포: 수준마다 비중을 정하고, 맞을 때까지 여러 번 뽑아 가장 가까운 시험지를 고릅니다.
+                let target = self.exam_criteria.get_difficulty_target();
+                let weights: Vec<u8> = (0..=MAX_DIFFICULTY).collect();
+                let difficulty_target = (1..=MAX_DIFFICULTY).fold(
+                    row![].spacing(10).align_y(iced::Alignment::Center),
+                    |row: iced::widget::Row<'_, Message>, level| {
+                        row.push(text(t!("difficulty-weight", level = level)).size(16))
+                            .push(pick_list(weights.clone(), target.map(|target| target[level as usize - 1]), move |weight| Message::SetDifficultyWeight(level, weight))
+                                    .placeholder("0")
+                                    .text_size(16))
+                    },
+                )
+                .push(button(text(t!("clear")).size(16))
+                        .on_press_maybe(target.map(|_| Message::ClearDifficultyTarget))
+                        .padding(4))
+                .push(text(t!("balance-attempts")).size(16))
+                .push(pick_list(BALANCE_ATTEMPTS.to_vec(), Some(self.exam_criteria.get_balance_attempts()), Message::SetBalanceAttempts)
+                        .text_size(16));
+
+                // 최근 시험에 낸 문항 피하기
+                let avoid_entries: Vec<AvoidRecentEntry> = (0..=MAX_AVOID_RECENT).map(|exams| AvoidRecentEntry { exams }).collect();
+                let mut avoid_recent = column![
+                    row![
+                        text(t!("avoid-recent")).size(16),
+                        pick_list(avoid_entries, Some(AvoidRecentEntry { exams: self.exam_criteria.get_avoid_recent() }), |entry| Message::SetAvoidRecent(entry.exams))
+                            .text_size(16),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                ].spacing(4);
+                if self.exam_criteria.get_avoid_recent() > 0
+                    { avoid_recent = avoid_recent.push(text(t!("avoided-questions", count = self.exam_criteria.get_avoided().len())).size(14)); }
+
+                column![
+                    text(t!("criteria-for-question-extraction")).size(32),
+                    info_fields,
+                    text(t!("categories")).size(self.menu_font_size_in_pixel),
+                    category_checkboxes,
+                    text(t!("difficulty-range")).size(self.menu_font_size_in_pixel),
+                    difficulty_range,
+                    text(t!("difficulty-target")).size(self.menu_font_size_in_pixel),
+                    difficulty_target,
+                    avoid_recent,
+                    text(t!("tags")).size(self.menu_font_size_in_pixel),
+                    text_input(&t!("tags-placeholder"), &self.tag_filter_text)
+                        .id(TAG_FILTER_INPUT)
+                        .on_input(Message::SetTagFilterText)
+                        .padding(6),
+                    tag_modes,
+                    text(t!("pinned-questions")).size(self.menu_font_size_in_pixel),
+                    text_input(&t!("pinned-questions-placeholder"), &self.pinned_text)
+                        .id(PINNED_QUESTIONS_INPUT)
+                        .on_input(Message::SetPinnedText)
+                        .padding(6),
+                    text(pinned_note).size(14),
+                    text(t!("sections")).size(self.menu_font_size_in_pixel),
+                    text_input(&t!("sections-placeholder"), &self.sections_text)
+                        .id(SECTIONS_INPUT)
+                        .on_input(Message::SetSectionsText)
+                        .padding(6),
+                    text(sections_note).size(14),
+                    row![
+                        text(t!("question-count")).size(self.menu_font_size_in_pixel),
+                        text_input("10", &self.exam_criteria.get_selected().to_string())
+                            .id(QUESTION_COUNT_INPUT)
+                            .on_input(Message::SetQuestionCount)
+                            .width(Length::Fixed(80.0))
+                            .padding(6),
+                        text(t!("default-question-percent")).size(16),
+                        pick_list(QUESTION_PERCENTS.to_vec(), Some(self.settings.get_question_percent()), Message::SetQuestionPercent)
+                            .text_size(16),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                    text(t!("numbering-style")).size(self.menu_font_size_in_pixel),
+                    numbering_radios,
+                    text(t!("question-order")).size(self.menu_font_size_in_pixel),
+                    order_radios,
+                    text(t!("choices-per-row")).size(self.menu_font_size_in_pixel),
+                    column_radios,
+                    text(t!("answer-space")).size(self.menu_font_size_in_pixel),
+                    answer_space,
+                    text(t!("page-template")).size(self.menu_font_size_in_pixel),
+                    page_template,
+                    checkbox(self.exam_criteria.get_shuffle_choices())
+                        .label(t!("shuffle-choices"))
+                        .on_toggle(Message::SetShuffleChoices)
+                        .size(self.menu_font_size_in_pixel),
+                    checkbox(self.exam_criteria.get_answer_key())
+                        .label(t!("include-answer-key"))
+                        .on_toggle(Message::SetAnswerKey)
+                        .size(self.menu_font_size_in_pixel),
+                    checkbox(self.exam_criteria.get_print_paper_code())
+                        .label(t!("print-paper-code"))
+                        .on_toggle(Message::SetPrintPaperCode)
+                        .size(self.menu_font_size_in_pixel),
+                    row![
+                        text(t!("seed")).size(self.menu_font_size_in_pixel),
+                        text_input(&t!("seed-random"), &seed.map(|seed| seed.to_string()).unwrap_or_default())
+                            .id(SEED_INPUT)
+                            .on_input(move |text| Message::SetSeed(if text.trim().is_empty() { None } else { text.trim().parse().ok().or(seed) }))
+                            .width(Length::Fixed(240.0))
+                            .padding(6),
+                        text(t!("seed-hint")).size(14),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                    row![
+                        text(t!("variant-count")).size(self.menu_font_size_in_pixel),
+                        text_input("4", &self.variant_count.to_string())
+                            .id(VARIANT_COUNT_INPUT)
+                            .on_input(Message::SetVariantCount)
+                            .width(Length::Fixed(80.0))
+                            .padding(6),
+                    ]
+                    .spacing(10),
+                    checkbox(self.named_papers)
+                        .label(t!("named-papers"))
+                        .on_toggle(Message::SetNamedPapers)
+                        .size(self.menu_font_size_in_pixel),
+                    iced::widget::Space::new().height(Length::Fixed(20.0)),
+                    button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                        .on_press(Message::GoToPage(Page::Main))
+                        .width(Length::Fill)
+                        .padding(8),
+                ]
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::ExamPreview => {
+                // Preview of the generated exam, numbered as it will be exported
+                let lines = self.exam.as_ref().map(|exam| exam.layout()).unwrap_or_default();
+                let answer_key = self.exam.as_ref().map(|exam| exam.answer_key()).unwrap_or_default();
+                let preview = lines.into_iter().fold(
+                    column![].spacing(4),
+                    |col: iced::widget::Column<'_, Message>, line| col.push(text(line.to_text()).size(18)),
+                );
+                let preview = answer_key.into_iter().fold(
+                    preview.push(text(t!("answer-key")).size(24)),
+                    |col, (number, labels)| col.push(text(format!("{}. {}", number, labels.join(", "))).size(18)),
+                );
+                let variant_reports = match &self.generation_summary
+                {
+                    Some(summary) => summary.get_papers().iter().fold(
+                        column![
+                            text(t!("generation-summary")).size(24),
+                            text(t!("generated-at", time = format_timestamp(summary.get_generated_at()))).size(14),
+                        ].spacing(2),
+                        |col: iced::widget::Column<'_, Message>, paper| {
+                            let details = t!("paper-summary", seed = paper.get_seed(), count = paper.get_question_count());
+                            let line = match paper.get_error()
+                            {
+                                None => format!("✓ {}: {} ({})", paper.get_name(), paper.get_file().display(), details),
+                                Some(e) => format!("✗ {}: {} ({})", paper.get_name(), e, details),
+                            };
+                            col.push(text(line).size(14))
+                        },
+                    ).push(
+                        button(text(t!("save-summary")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::SaveSummary)
+                            .padding(8),
+                    ),
+                    None => column![],
+                };
+
+                // 내보내기 전에 난이도 분포를 보고 시험지가 너무 어렵거나 쉽지 않은지 판단합니다.
+                let difficulty = match &self.exam
+                {
+                    Some(exam) => {
+                        let summary = difficulty_summary(exam);
+                        let average = match summary.get_average()
+                        {
+                            Some(average) => t!("difficulty-average", average = format!("{:.1}", average)),
+                            None => t!("difficulty-no-ratings"),
+                        };
+                        let bands = (1..=MAX_DIFFICULTY).fold(
+                            row![ text(average).size(16) ].spacing(16),
+                            |bands: iced::widget::Row<'_, Message>, level| {
+                                bands.push(text(t!("difficulty-band", level = level, count = summary.get_count(level))).size(16))
+                            },
+                        );
+                        let bands = bands.push(text(t!("difficulty-unrated", count = summary.get_count(0))).size(16));
+                        // 목표 난이도 분포가 있으면 몇 번 뽑아서 얼마나 맞췄는지 보여 줍니다.
+                        let bands = match self.balance_report.as_ref().filter(|_| self.exam_criteria.get_difficulty_target().is_some())
+                        {
+                            Some(report) => bands.push(text(t!("balance-report", attempts = report.get_attempts(), percent = format!("{:.0}", (1.0 - report.get_distance()) * 100.0))).size(16)),
+                            None => bands,
+                        };
+                        row![
+                            text(t!("difficulty-profile")).size(16),
+                            bands,
+                        ]
+                        .spacing(16)
+                    },
+                    None => row![],
+                };
+
+                let export_progress = match self.export_progress
+                {
+                    Some((done, total)) => row![
+                        progress_bar(0.0..=total.max(1) as f32, done as f32).length(Length::Fixed(320.0)),
+                        text(t!("export-progress", done = done, total = total)).size(14),
+                    ].spacing(10),
+                    None => row![],
+                };
+
+                // 같은 시험지를 다시 만들 수 있도록 사용된 시드를 보여 줍니다.
+                let seed = match self.exam.as_ref().and_then(Exam::get_seed)
+                {
+                    Some(seed) => row![
+                        text(t!("seed-used", seed = seed)).size(16),
+                        button(text(t!("reuse-seed")).size(14))
+                            .on_press(Message::SetSeed(Some(seed)))
+                            .padding([2, 8]),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                    None => row![],
+                };
+
+                column![
+                    text(t!("exam-preview")).size(32),
+                    seed,
+                    difficulty,
+                    scrollable(preview).height(Length::Fill).width(Length::Fill),
+                    export_progress,
+                    variant_reports,
+                    row![
+                        button(text(t!("export-pdf")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::ExportExam(ExportFormat::Pdf))
+                            .padding(8),
+                        button(text(t!("export-excel")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::ExportExam(ExportFormat::Excel))
+                            .padding(8),
+                        button(text(t!("export-markdown")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::ExportExam(ExportFormat::Markdown))
+                            .padding(8),
+                        button(text(t!("export-docx")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::ExportExam(ExportFormat::Docx))
+                            .padding(8),
+                        button(text(t!("export-variants-pdf")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::ExportVariants(ExportFormat::Pdf))
+                            .padding(8),
+                        button(text(t!("export-variants-excel")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::ExportVariants(ExportFormat::Excel))
+                            .padding(8),
+                        button(text(t!("export-student-papers-pdf")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::ExportStudentPapers(ExportFormat::Pdf))
+                            .padding(8),
+                        button(text(t!("export-student-papers-excel")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::ExportStudentPapers(ExportFormat::Excel))
+                            .padding(8),
+                        button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::GoToPage(Page::Main))
+                            .padding(8),
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::StudentList => {
+                // Student list with add/edit/delete actions, sorted by name in the order of the current locale
+                let mut sorted: Vec<_> = self.sbank.iter().enumerate().collect();
+                locale_sort_by_key(&mut sorted, &self.current_locale, |(_, student)| student.get_name());
+                let students = sorted.into_iter().fold(
+                    column![].spacing(6),
+                    |col: iced::widget::Column<'_, Message>, (i, student)| {
+                        col.push(
+                            row![
+                                text(student.get_name().clone()).size(18).width(Length::FillPortion(3)),
+                                text(student.get_id().clone()).size(18).width(Length::FillPortion(2)),
+                                text(self.get_student_group(student.get_id()).to_string()).size(18).width(Length::FillPortion(2)),
+                                button(text(t!("edit")).size(18)).on_press(Message::OpenStudentForm(Some(i))).padding(4),
+                                button(text(t!("delete")).size(18)).on_press(Message::DeleteStudent(i)).padding(4),
+                            ]
+                            .spacing(10)
+                        )
+                    },
+                );
+
+                column![
+                    text(t!("student-list-management")).size(32),
+                    row![
+                        text(t!("student-name")).size(18).width(Length::FillPortion(3)),
+                        text(t!("student-id")).size(18).width(Length::FillPortion(2)),
+                        text(t!("student-group")).size(18).width(Length::FillPortion(2)),
+                    ]
+                    .spacing(10),
+                    scrollable(students).height(Length::Fill).width(Length::Fill),
+                    row![
+                        button(text(t!("add-student")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::OpenStudentForm(None))
+                            .padding(8),
+                        button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::GoToPage(Page::Main))
+                            .padding(8),
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::QuestionBankEdit => {
+                // 정렬 가능한 열 머리글: 같은 열을 다시 누르면 순서가 뒤집힙니다.
+                let headers = SortColumn::ALL.into_iter().fold(
+                    row![].spacing(10),
+                    |header_row: iced::widget::Row<'_, Message>, column| {
+                        let arrow = match self.question_filter.get_sort()
+                        {
+                            (sorted, SortOrder::Ascending) if sorted == column => " ▲",
+                            (sorted, SortOrder::Descending) if sorted == column => " ▼",
+                            _ => "",
+                        };
+                        header_row.push(button(text(format!("{}{}", t!(column.get_key()), arrow)).size(18))
+                                            .on_press(Message::SortQuestions(column))
+                                            .padding(4))
+                    },
+                );
+
+                // 검색, 분류, 난이도 필터: 문제은행은 바꾸지 않고 보이는 문항만 줄입니다.
+                let filter = &self.question_filter;
+                let category_entries: Vec<CategoryFilterEntry> = self.categories.iter()
+                                                                    .map(|category| CategoryFilterEntry { category: category.clone() })
+                                                                    .collect();
+                let filters = row![
+                    text_input(&t!("search-questions"), filter.get_search())
+                        .id(QUESTION_SEARCH_INPUT)
+                        .on_input(Message::SetQuestionSearch)
+                        .width(Length::Fixed(240.0))
+                        .padding(4),
+                    pick_list(category_entries, filter.get_category().map(|category| CategoryFilterEntry { category: category.clone() }),
+                              |entry| Message::SetQuestionCategoryFilter(Some(entry.category)))
+                        .placeholder(t!("all-categories"))
+                        .text_size(16),
+                    pick_list((1..=MAX_DIFFICULTY).collect::<Vec<u8>>(), filter.get_difficulty(), |level| Message::SetQuestionDifficultyFilter(Some(level)))
+                        .placeholder(t!("any-difficulty"))
+                        .text_size(16),
+                    button(text(t!("clear-filters")).size(16))
+                        .on_press_maybe((!filter.is_cleared()).then_some(Message::ClearQuestionFilters))
+                        .padding(4),
+                    self.view_jump_box(),
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center);
 
-            container(
-                column(items.into_iter().map(|item_key| {
-                    let on_press_message = if self.current_menu_key == "settings" && item_key == "language"
-                        { Message::GoToPage("language-settings".to_string()) }
-                    else
-                        { Message::SubMenuClicked(item_key.to_string()) };
+                // Question list with the image attached to each prompt
+                let visible = self.get_visible_questions();
+                let no_match = visible.is_empty() && !self.qbank.get_questions().is_empty();
+                let questions = visible.into_iter().fold(
+                    column![].spacing(10),
+                    |col: iced::widget::Column<'_, Message>, question| {
+                        let id = question.get_id();
+                        let attached = self.question_metas.get(&id).and_then(|meta| meta.get_image());
+                        let preview: Element<'_, Message> = match attached
+                        {
+                            Some(ImageRef::Path(path)) if path.is_file() => image(path.clone()).height(Length::Fixed(80.0)).into(),
+                            Some(ImageRef::Embedded(bytes)) => image(image::Handle::from_bytes(bytes.clone())).height(Length::Fixed(80.0)).into(),
+                            Some(_) => text(t!("image-missing")).size(18).into(),
+                            None => iced::widget::Space::new().into(),
+                        };
+                        let current_kind = self.question_metas.get(&id).map(|meta| meta.get_kind()).unwrap_or_default();
+                        let kinds = QuestionKind::ALL.into_iter().fold(
+                            row![].spacing(10),
+                            |kind_row: iced::widget::Row<'_, Message>, kind| {
+                                kind_row.push(radio(t!(kind.get_key()), kind, Some(current_kind), move |kind| Message::SetQuestionKind(id, kind)).size(16))
+                            },
+                        );
+                        let difficulty = self.question_metas.get(&id).map(|meta| meta.get_difficulty()).filter(|&level| level > 0);
+                        let note = self.question_metas.get(&id).map(|meta| meta.get_note()).unwrap_or_default();
+                        let tags = self.tag_drafts.get(&id).cloned()
+                                    .unwrap_or_else(|| self.question_metas.get(&id).map(|meta| meta.get_tags().join(", ")).unwrap_or_default());
+                        // 은행 순서로 보일 때만 위아래로 옮길 수 있습니다.
+                        let count = self.qbank.get_questions().len();
+                        let position = self.qbank.get_questions().iter().position(|q| q.get_id() == id).filter(|_| self.can_move_questions());
+                        let mut actions = row![
+                            checkbox(self.selected_questions.contains(&id))
+                                .on_toggle(move |selected| Message::SelectQuestion(id, selected)),
+                            button(text("▲").size(18))
+                                .on_press_maybe(position.filter(|&from| from > 0).map(|from| Message::MoveQuestion { from, to: from - 1 }))
+                                .padding(4),
+                            button(text("▼").size(18))
+                                .on_press_maybe(position.filter(|&from| from + 1 < count).map(|from| Message::MoveQuestion { from, to: from + 1 }))
+                                .padding(4),
+                            button(text(t!("attach-image")).size(18)).on_press(Message::PickQuestionImage(id)).padding(4),
+                            button(text(t!("copy-question")).size(18)).on_press(Message::CopyQuestion(id)).padding(4),
+                            pick_list((1..=MAX_DIFFICULTY).collect::<Vec<u8>>(), difficulty, move |level| Message::SetQuestionDifficulty(id, level))
+                                .placeholder(t!("column-difficulty"))
+                                .text_size(16),
+                            text_input(&t!("tags-placeholder"), &tags)
+                                .on_input(move |tags| Message::SetQuestionTags(id, tags))
+                                .width(Length::Fixed(240.0))
+                                .padding(4),
+                        ]
+                        .spacing(10);
+                        if attached.is_some()
+                            { actions = actions.push(button(text(t!("remove-image")).size(18)).on_press(Message::RemoveQuestionImage(id)).padding(4)); }
+                        if let Some(meta) = self.question_metas.get(&id)
+                            && let Some(level) = meta.suggest_difficulty()
+                            { actions = actions.push(text(t!("suggested-difficulty", level = level, attempts = meta.get_attempts())).size(14)); }
 
-                    button(text(t!(item_key)).size(self.menu_font_size_in_pixel))
-                        .on_press(on_press_message)
-                        .width(Length::Fill)
-                        .padding(8)
-                        .style(|_theme: &Theme, status| {
-                            let mut style = button::Style::default();
-                            style.background = Some(Color::WHITE.into());
-                            style.text_color = Color::BLACK;
+                        // 긴 문제는 설정한 길이에서 자르고, 전체 문장은 풍선 도움말로 보여 줍니다.
+                        let prompt: Element<'_, Message> = match self.get_prompt_preview(question.get_question())
+                        {
+                            Some(preview) => tooltip(text(format!("{}. [{}] {}", id, question.get_category(), preview)).size(18),
+                                                     container(text(question.get_question().clone()).size(16)).padding(6).style(container::rounded_box),
+                                                     tooltip::Position::Bottom).into(),
+                            None => text(format!("{}. [{}] {}", id, question.get_category(), question.get_question())).size(18).into(),
+                        };
+                        // 메모는 편집 화면에만 보이고 시험지에는 인쇄되지 않습니다.
+                        let note = text_input(&t!("note-placeholder"), note)
+                                    .on_input(move |note| Message::SetQuestionNote(id, note))
+                                    .size(14)
+                                    .padding(4);
+                        let entry = column![
+                            prompt,
+                            kinds,
+                            preview,
+                            actions,
+                            note,
+                        ]
+                        .spacing(4);
+                        // 번호로 찾아간 문항은 테두리로 표시합니다.
+                        if self.highlighted_question == Some(id)
+                            { col.push(container(entry).padding(4).style(container::bordered_box)) }
+                        else
+                            { col.push(entry) }
+                    },
+                );
 
-                            match status
-                            {
-                                button::Status::Hovered => { style.background = Some(Color::from_rgb(0.9, 0.9, 0.9).into()); },
-                                button::Status::Pressed => { style.background = Some(Color::from_rgb(0.8, 0.8, 0.8).into()); },
-                                _ => {},
-                            }
-                            style
-                        })
-                        .into()
-                }))
-                .spacing(2)
-                .width(220.0)
-            )
-            .padding(5)
-            .style(|_theme: &Theme| {
-                container::Style {
-                    background: Some(Color::WHITE.into()),
-                    ..Default::default()
-                }
-            })
-            .into()
-        }
-        else
-        {
-            iced::widget::Space::new().into() // 서브메뉴 없으면 빈 공간
-        };
+                let questions = if no_match { questions.push(text(t!("no-matching-questions")).size(16)) } else { questions };
 
-        // Render main content or specific page based on current_page
-        let main_content_area: Element<'_, Message> = match self.current_page.as_str() {
-            "main" => {
-                // 3. 메인 화면
-                let path_text = if !self.selected_file_path.as_os_str().is_empty()
-                    { let path = &self.selected_file_path; t!("selected-file", path = &path.to_string_lossy()).to_string() }
-                else
-                    { t!("no-file-selected").to_string() };
+                // 페이지 이동: 수천 개의 문항을 한꺼번에 그리지 않습니다.
+                let pages = page_count(self.question_filter.apply(&self.qbank, &self.question_metas).len(), self.question_page_size);
+                let page = self.question_page.min(pages - 1);
+                let page_controls = row![
+                    button(text(t!("previous")).size(16))
+                        .on_press_maybe((page > 0).then(|| Message::GoToQuestionPage(page - 1)))
+                        .padding(4),
+                    text(t!("page-of", page = page + 1, count = pages)).size(16),
+                    button(text(t!("next")).size(16))
+                        .on_press_maybe((page + 1 < pages).then(|| Message::GoToQuestionPage(page + 1)))
+                        .padding(4),
+                    text(t!("page-size")).size(16),
+                    pick_list(PAGE_SIZES.to_vec(), Some(self.question_page_size), Message::SetQuestionPageSize)
+                        .text_size(16),
+                    text(t!("prompt-length")).size(16),
+                    pick_list(PROMPT_LENGTHS.to_vec(), Some(self.settings.get_prompt_length()), Message::SetPromptLength)
+                        .text_size(16),
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center);
 
-                center(
-                    column![
-                        text(t!("welcome-message")).size(32),
-                        text(path_text).size(18),
+                column![
+                    text(t!("question-bank-management")).size(32),
+                    filters,
+                    headers,
+                    scrollable(questions).id(QUESTION_LIST_SCROLL).height(Length::Fill).width(Length::Fill),
+                    page_controls,
+                    row![
+                        button(text(t!("generate-from-selection", count = self.selected_questions.len())).size(self.menu_font_size_in_pixel))
+                            .on_press_maybe((!self.selected_questions.is_empty()).then_some(Message::GenerateFromSelection))
+                            .padding(8),
+                        button(text(t!("clear-selection")).size(self.menu_font_size_in_pixel))
+                            .on_press_maybe((!self.selected_questions.is_empty()).then_some(Message::ClearQuestionSelection))
+                            .padding(8),
+                        button(text(t!("delete-selected", count = self.selected_questions.len())).size(self.menu_font_size_in_pixel))
+                            .on_press_maybe((!self.selected_questions.is_empty()).then_some(Message::DeleteSelectedQuestions))
+                            .style(button::danger)
+                            .padding(8),
+                        text(t!("confirm-deletes-from")).size(16),
+                        pick_list(DELETE_CONFIRM_THRESHOLDS.to_vec(), Some(self.settings.get_delete_confirm_threshold()), Message::SetDeleteConfirmThreshold)
+                            .text_size(16),
+                        button(text(t!("recalibrate-difficulty")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::RecalibrateDifficulty)
+                            .padding(8),
                     ]
-                    .spacing(20)
-                )
-                .width(Length::Fill)
-                .height(Length::Fill)
+                    .spacing(10),
+                    row![
+                        text(t!("export-source")).size(16),
+                        ExportSource::ALL.into_iter().fold(
+                            row![].spacing(10),
+                            |row: iced::widget::Row<'_, Message>, source| {
+                                row.push(radio(t!(source.get_key()), source, Some(self.export_source), Message::SetExportSource)
+                                            .size(self.menu_font_size_in_pixel))
+                            },
+                        ),
+                        button(text(t!("export-questions", count = self.get_export_questions().len())).size(self.menu_font_size_in_pixel))
+                            .on_press_maybe((!self.get_export_questions().is_empty()).then_some(Message::ExportQuestions))
+                            .padding(8),
+                        button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::GoToPage(Page::Main))
+                            .padding(8),
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(10)
+                .padding(20)
                 .into()
             },
-            "language-settings" => {
-                // Language selection page
-                let available_locales = Self::get_available_locales();
-                let _current_i18n_locale = rust_i18n::locale(); // Get current i18n locale
-                let language_buttons = available_locales.into_iter().fold(
+            Page::BankMetadata => {
+                // Bank metadata page: stored with the bank and printed on exports
+                let fields = [
+                    ("bank-title", BANK_TITLE_INPUT, self.bank_metadata.get_title(), Message::SetBankTitle as fn(String) -> Message),
+                    ("bank-author", BANK_AUTHOR_INPUT, self.bank_metadata.get_author(), Message::SetBankAuthor),
+                    ("bank-subject", BANK_SUBJECT_INPUT, self.bank_metadata.get_subject(), Message::SetBankSubject),
+                    ("bank-description", BANK_DESCRIPTION_INPUT, self.bank_metadata.get_description(), Message::SetBankDescription),
+                ];
+                let fields = fields.into_iter().fold(
                     column![].spacing(10),
-                    |col: iced::widget::Column<'_, Message>, (language_name, locale)| {
+                    |col: iced::widget::Column<'_, Message>, (label, id, value, on_input)| {
+                        col.push(column![
+                                    text(t!(label)).size(self.menu_font_size_in_pixel),
+                                    text_input("", value).id(id).on_input(on_input).padding(6),
+                                 ]
+                                 .spacing(4))
+                    },
+                );
+                let can_save = !self.selected_file_path.as_os_str().is_empty();
+
+                // 분류 이름 바꾸기: 그 분류의 모든 문항에 한꺼번에 적용됩니다.
+                let named: Vec<String> = self.categories.iter()
+                                            .filter_map(|category| match category
+                                            {
+                                                Category::Named(name) => Some(name.clone()),
+                                                Category::Uncategorized => None,
+                                            })
+                                            .collect();
+                let can_rename = self.category_to_rename.is_some() && !self.category_new_name.trim().is_empty();
+                let rename = row![
+                    pick_list(named, self.category_to_rename.clone(), Message::SelectCategoryToRename)
+                        .placeholder(t!("category-to-rename"))
+                        .text_size(16),
+                    text_input(&t!("category-new-name"), &self.category_new_name)
+                        .id(CATEGORY_NAME_INPUT)
+                        .on_input(Message::SetCategoryNewName)
+                        .on_submit_maybe(can_rename.then_some(Message::RenameCategory))
+                        .padding(6),
+                    button(text(t!("rename-category")).size(16))
+                        .on_press_maybe(can_rename.then_some(Message::RenameCategory))
+                        .padding(6),
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center);
+
+                column![
+                    text(t!("bank-metadata")).size(32),
+                    fields,
+                    text(t!("rename-category")).size(self.menu_font_size_in_pixel),
+                    rename,
+                    text(if self.bank_changed { t!("bank-changed") } else { t!("bank-unchanged") }).size(14),
+                    iced::widget::Space::new().height(Length::Fixed(20.0)),
+                    row![
+                        button(text(t!("save")).size(self.menu_font_size_in_pixel))
+                            .on_press_maybe(can_save.then_some(Message::SaveBankMetadata))
+                            .padding(8),
+                        button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::GoToPage(Page::Main))
+                            .padding(8),
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::SoftwareInfo => {
+                // Software info page: the build details to quote in bug reports
+                column![
+                    text(t!("software-info")).size(32),
+                    text(about_text()).size(self.menu_font_size_in_pixel),
+                    text(t!("software-info-hint")).size(14),
+                    iced::widget::Space::new().height(Length::Fixed(20.0)),
+                    button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                        .on_press(Message::GoToPage(Page::Main))
+                        .padding(8),
+                ]
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::BankValidation => {
+                // 문제은행 점검: 고칠 문항으로 바로 이동할 수 있는 목록입니다.
+                let issues = self.get_validation_issues();
+                let checklist = issues.iter().fold(
+                    column![].spacing(6),
+                    |col: iced::widget::Column<'_, Message>, issue| {
+                        let description = match issue
+                        {
+                            ValidationIssue::DifficultyOutOfRange { difficulty, .. } => t!(issue.get_key(), difficulty = difficulty, max = MAX_DIFFICULTY),
+                            _ => t!(issue.get_key()),
+                        };
                         col.push(
-                            button(text(language_name).size(self.menu_font_size_in_pixel))
-                                .on_press(Message::SetLocale(locale))
-                                .width(Length::Fill)
-                                .padding(8),
+                            row![
+                                text(format!("☐ {}. {}", issue.get_id(), description)).size(18).width(Length::Fill),
+                                button(text(t!("go-to-question")).size(16))
+                                    .on_press(Message::ShowQuestion(issue.get_id()))
+                                    .padding(4),
+                            ]
+                            .spacing(10)
+                            .align_y(iced::Alignment::Center)
                         )
                     },
                 );
+                let summary = if issues.is_empty()
+                    { t!("no-validation-issues") }
+                else
+                    { t!("validation-issue-count", count = issues.len()) };
+                column![
+                    text(t!("validate-bank")).size(32),
+                    text(summary).size(self.menu_font_size_in_pixel),
+                    scrollable(checklist).height(Length::Fill).width(Length::Fill),
+                    button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                        .on_press(Message::GoToPage(Page::Main))
+                        .padding(8),
+                ]
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::Diagnostics => {
+                // Diagnostics page: the recent events to attach to bug reports
+                let events = diagnostics_text();
+                let events = if events.is_empty() { t!("no-diagnostics").to_string() } else { events };
+                column![
+                    text(t!("diagnostics")).size(32),
+                    scrollable(text(events).size(14)).height(Length::Fill).width(Length::Fill),
+                    row![
+                        button(text(t!("copy-to-clipboard")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::CopyDiagnostics)
+                            .padding(8),
+                        button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                            .on_press(Message::GoToPage(Page::Main))
+                            .padding(8),
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(10)
+                .padding(20)
+                .into()
+            },
+            Page::GradingCriteria => {
+                // Grading criteria page: scoring of multiple-select questions
+                let mode_radios = GradingMode::ALL.into_iter().fold(
+                    column![].spacing(10),
+                    |col: iced::widget::Column<'_, Message>, mode| {
+                        col.push(radio(t!(mode.get_key()), mode, Some(self.grading_mode), Message::SetGradingMode)
+                                    .size(self.menu_font_size_in_pixel))
+                    },
+                );
+
+                let display = self.settings.get_grade_display();
+                let tolerance = self.settings.get_numeric_tolerance();
+                let display_radios = GradeDisplay::ALL.into_iter().fold(
+                    column![].spacing(10),
+                    |col: iced::widget::Column<'_, Message>, option| {
+                        col.push(radio(t!(option.get_key()), option, Some(display), Message::SetGradeDisplay)
+                                    .size(self.menu_font_size_in_pixel))
+                    },
+                );
 
                 column![
-                    text(t!("language")).size(32),
-                    language_buttons,
+                    text(t!("grading-criteria")).size(32),
+                    mode_radios,
+                    text(t!("grade-display")).size(self.menu_font_size_in_pixel),
+                    display_radios,
+                    text(t!("letter-scale")).size(self.menu_font_size_in_pixel),
+                    text_input("A=90, B=80, C=70, D=60, F=0", &self.letter_scale_text)
+                        .id(LETTER_SCALE_INPUT)
+                        .on_input(Message::SetLetterScaleText)
+                        .padding(6),
+                    // 계산 문제는 정답과 조금 달라도 맞은 것으로 봅니다.
+                    text(t!("numeric-tolerance")).size(self.menu_font_size_in_pixel),
+                    row![
+                        text(t!("absolute-tolerance")).size(16),
+                        pick_list(ABSOLUTE_TOLERANCES.to_vec(), Some(tolerance.get_absolute()), Message::SetAbsoluteTolerance)
+                            .text_size(16),
+                        text(t!("percent-tolerance")).size(16),
+                        pick_list(PERCENT_TOLERANCES.to_vec(), Some(tolerance.get_percent()), Message::SetPercentTolerance)
+                            .text_size(16),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
                     iced::widget::Space::new().height(Length::Fixed(20.0)),
                     button(text(t!("back")).size(self.menu_font_size_in_pixel))
-                        .on_press(Message::GoToPage("main".to_string()))
+                        .on_press(Message::GoToPage(Page::Main))
                         .width(Length::Fill)
                         .padding(8),
                 ]
@@ -758,7 +7027,9 @@ impl ControlTower
                 .padding(20)
                 .into()
             },
-            _ => {
+            Page::Learning => self.view_learning(),
+            Page::GradeReport => self.view_grade_report(),
+            Page::Locked | Page::ComingSoon => {
                 // Default view for unknown pages
                 center(text(t!("coming-soon")).size(32)).into()
             }
@@ -769,16 +7040,37 @@ impl ControlTower
         let menu_bar_height_estimate = self.menu_font_size_in_pixel + (button_padding * 2.0) + (menu_bar_outer_padding * 2.0);
 
         // 기본 콘텐츠 (menu_bar + main_content_area)
+        // 상태 표시줄: 선택된 파일과 저장되지 않은 변경 사항 표시
+        // Tab 순서의 마지막 자리이므로 초점을 받으면 테두리를 그립니다.
+        let status_focused = self.get_focus_stop() == Some(FocusStop::StatusBar) && self.modal_focus.is_none();
+        let status_bar = container(text(self.status_text()).size(14))
+            .width(Length::Fill)
+            .padding([2, 8])
+            .style(move |theme: &Theme| {
+                let fill = theme.extended_palette().background.weak.color;
+                let border = if status_focused
+                    { Border { color: get_focus_ring_color(fill), width: FOCUS_RING_WIDTH, radius: 2.0.into() } }
+                else
+                    { Border::default() };
+                container::Style {
+                    background: Some(fill.into()),
+                    border,
+                    ..Default::default()
+                }
+            });
+
         let content: Element<'_, Message> = column![
             menu_bar,
-            main_content_area,
+            container(main_content_area).height(Length::Fill),
+            self.view_toasts(),
+            status_bar,
         ]
         .width(Length::Fill)
         .height(Length::Fill)
         .into();
 
         // 만약 메뉴가 열려있다면 stack을 사용하여 서브메뉴를 위에 표시합니다.
-        if !self.current_menu_key.is_empty()
+        let screen: Element<'_, Message> = if !self.current_menu_key.is_empty()
         {
             stack![
                 content,
@@ -795,7 +7087,543 @@ impl ControlTower
         else
         {
             content // overlay 없이 일반 콘텐츠 반환
+        };
+
+        // 학생 편집 양식이 열려 있으면 모달로 표시합니다.
+        let screen = match &self.student_form
+        {
+            Some(form) => modal(screen, self.view_student_form(form), Message::CancelStudentForm),
+            None => screen,
+        };
+
+        // 네이티브 대화 상자 대신 앱 안의 파일 탐색기를 쓰면 모달로 표시합니다.
+        let screen = match &self.file_browser
+        {
+            Some(browser) => modal(screen, self.view_file_browser(browser), Message::CancelFileBrowser),
+            None => screen,
+        };
+
+        // 지난번에 열려 있던 문제은행을 다시 열지 묻습니다.
+        let screen = if self.session_banks.is_empty()
+            { screen }
+        else
+        {
+            let banks: Vec<String> = self.session_banks.iter().map(|bank| bank.display().to_string()).collect();
+            confirm(screen, ConfirmDialog {
+                title: t!("restore-session-title").to_string(),
+                body: t!("restore-session-body", banks = banks.join("\n")).to_string(),
+                extra: None,
+                confirm_label: t!("reopen-banks").to_string(),
+                on_confirm: Message::RestoreSessionBanks,
+                on_cancel: Message::DismissSessionBanks,
+                focused: self.modal_focus.filter(|_| self.error_message.is_none() && !self.is_overwrite_pending()
+                                                     && self.pending_roster.is_none() && self.pending_load.is_none()
+                                                     && self.pending_delete.is_none() && self.pending_merge.is_none()
+                                                     && self.pending_resume.is_none()),
+            })
+        };
+
+        // 끝내지 못한 자기 학습을 이어서 할지 묻습니다.
+        let screen = match &self.pending_resume
+        {
+            Some(progress) => {
+                let focused = self.modal_focus.filter(|_| self.error_message.is_none() && !self.is_overwrite_pending()
+                                                          && self.pending_roster.is_none() && self.pending_load.is_none()
+                                                          && self.pending_delete.is_none() && self.pending_merge.is_none());
+                let discard_focused = focused == Some(0);
+                confirm(screen, ConfirmDialog {
+                    title: t!("resume-learning-title").to_string(),
+                    body: t!("resume-learning-body", current = progress.get_current() + 1, count = progress.get_count()).to_string(),
+                    extra: Some(button(text(t!("discard-learning")).size(18))
+                                    .on_press(Message::DiscardLearningProgress)
+                                    .padding(8)
+                                    .style(move |theme: &Theme, status| with_focus_ring(button::danger(theme, status), discard_focused))
+                                    .into()),
+                    confirm_label: t!("resume-learning").to_string(),
+                    on_confirm: Message::ResumeLearning,
+                    on_cancel: Message::DismissLearningProgress,
+                    focused,
+                })
+            },
+            None => screen,
+        };
+
+        // 저장하지 않은 변경을 버리고 다른 문제은행을 불러오기 전에 확인합니다.
+        let screen = match &self.pending_load
+        {
+            Some(pending) => {
+                let title = self.qbank.get_header().get_title();
+                let count = self.qbank.get_questions().len();
+                let (dialog_title, body, confirm_label) = match pending
+                {
+                    PendingLoad::File(path) => (t!("replace-bank-title"),
+                                                t!("replace-bank-body", title = title, count = count, source = path.display()),
+                                                t!("replace-bank")),
+                    PendingLoad::Sample => (t!("replace-bank-title"),
+                                            t!("replace-bank-body", title = title, count = count, source = t!("sample-bank-name")),
+                                            t!("replace-bank")),
+                    PendingLoad::Unload if !self.has_real_changes() => (t!("unload-bank-title"),
+                                                                         t!("unload-roster-body", count = self.sbank.len()),
+                                                                         t!("unload-bank")),
+                    PendingLoad::Unload => (t!("unload-bank-title"),
+                                            t!("unload-bank-body", title = title, count = count),
+                                            t!("unload-bank")),
+                };
+                confirm(screen, ConfirmDialog {
+                    title: dialog_title.to_string(),
+                    body: body.to_string(),
+                    extra: None,
+                    confirm_label: confirm_label.to_string(),
+                    on_confirm: Message::ConfirmReplaceBank,
+                    on_cancel: Message::CancelReplaceBank,
+                    focused: self.modal_focus.filter(|_| self.error_message.is_none() && !self.is_overwrite_pending()
+                                                         && self.pending_roster.is_none() && self.pending_delete.is_none()
+                                                         && self.pending_merge.is_none()),
+                })
+            },
+            None => screen,
+        };
+
+        // 같은 이름의 분류가 이미 있으면 두 분류를 합칠지 묻습니다.
+        let screen = match &self.pending_merge
+        {
+            Some((from, to)) => {
+                let count = self.qbank.get_questions().iter()
+                                .filter(|question| Category::of(self.qbank.get_header(), question) == Category::Named(from.trim().to_string()))
+                                .count();
+                confirm(screen, ConfirmDialog {
+                    title: t!("merge-category-title").to_string(),
+                    body: t!("merge-category-body", from = from.trim(), to = to.trim(), count = count).to_string(),
+                    extra: None,
+                    confirm_label: t!("merge-category").to_string(),
+                    on_confirm: Message::ConfirmMergeCategory,
+                    on_cancel: Message::CancelMergeCategory,
+                    focused: self.modal_focus.filter(|_| self.error_message.is_none() && !self.is_overwrite_pending()
+                                                         && self.pending_roster.is_none() && self.pending_delete.is_none()),
+                })
+            },
+            None => screen,
+        };
+
+        // 선택한 문제를 한꺼번에 많이 지우기 전에 확인합니다.
+        let screen = match &self.pending_delete
+        {
+            Some(ids) => confirm(screen, ConfirmDialog {
+                title: t!("delete-questions-title").to_string(),
+                body: t!("delete-questions-body", count = ids.len(), title = self.qbank.get_header().get_title()).to_string(),
+                extra: None,
+                confirm_label: t!("delete").to_string(),
+                on_confirm: Message::ConfirmDeleteQuestions,
+                on_cancel: Message::CancelDeleteQuestions,
+                focused: self.modal_focus.filter(|_| self.error_message.is_none() && !self.is_overwrite_pending() && self.pending_roster.is_none()),
+            }),
+            None => screen,
+        };
+
+        // 불러온 학생 명단에 중복된 학생이 있으면 처리 방법을 묻습니다.
+        let screen = match &self.pending_roster
+        {
+            Some(pending) => {
+                let focused = self.modal_focus.filter(|_| self.error_message.is_none() && !self.is_overwrite_pending());
+                let merge_focused = focused == Some(0);
+                confirm(screen, ConfirmDialog {
+                    title: t!("duplicate-students-title").to_string(),
+                    body: Self::describe_duplicates(pending),
+                    extra: Some(button(text(t!("merge-duplicates")).size(18))
+                                    .on_press(Message::ResolveDuplicateStudents(DuplicateResolution::Merge))
+                                    .padding(8)
+                                    .style(move |theme: &Theme, status| with_focus_ring(button::primary(theme, status), merge_focused))
+                                    .into()),
+                    confirm_label: t!("keep-first-duplicate").to_string(),
+                    on_confirm: Message::ResolveDuplicateStudents(DuplicateResolution::KeepFirst),
+                    on_cancel: Message::CancelRosterImport,
+                    focused,
+                })
+            },
+            None => screen,
+        };
+
+        // 덮어쓰기를 기다리는 내보내기가 있으면 확인 대화상자를 표시합니다.
+        let overwrite_body = match (&self.pending_overwrite, &self.pending_batch)
+        {
+            (Some((path, _)), _) => Some(t!("overwrite-body", path = path.display()).to_string()),
+            (None, Some((_, files))) => Some(t!("overwrite-files-body",
+                                                count = files.len(),
+                                                dir = files.first().and_then(|path| path.parent()).unwrap_or(Path::new("")).display()).to_string()),
+            (None, None) => None,
+        };
+        let screen = match overwrite_body
+        {
+            Some(body) => confirm(screen, ConfirmDialog {
+                title: t!("overwrite-title").to_string(),
+                body,
+                extra: Some(checkbox(self.dont_ask_overwrite)
+                                .label(t!("dont-ask-again-session"))
+                                .on_toggle(Message::SetDontAskOverwrite)
+                                .into()),
+                confirm_label: t!("overwrite").to_string(),
+                on_confirm: Message::ConfirmOverwrite,
+                on_cancel: Message::CancelOverwrite,
+                // The error dialog, when shown, holds the focus above this one.
+                focused: self.modal_focus.filter(|_| self.error_message.is_none()),
+            }),
+            None => screen,
+        };
+
+        // 오류가 있으면 가장 위에 오류 대화상자를 표시합니다.
+        match &self.error_message
+        {
+            Some(message) => modal(screen, self.view_error(message), Message::DismissError),
+            None => screen,
+        }
+    }
+
+    // fn describe_duplicates(pending: &PendingRoster) -> String
+    /// Lists every duplicate of an imported roster with the 1-based number
+    /// and the name of each of its students.
+    fn describe_duplicates(pending: &PendingRoster) -> String
+    {
+        let lines = pending.duplicates.iter().map(|duplicate| {
+            let key = match duplicate.get_key()
+            {
+                DuplicateKey::Id(id) => t!("duplicate-id", id = id).to_string(),
+                DuplicateKey::NameAndGroup(name, group) => t!("duplicate-name-and-group", name = name, group = group).to_string(),
+            };
+            let students: Vec<String> = duplicate.get_indices().iter()
+                .map(|&index| t!("duplicate-entry", number = index + 1, name = pending.sbank[index].get_name()).to_string())
+                .collect();
+            format!("{}: {}", key, students.join(", "))
+        });
+        std::iter::once(t!("duplicate-students-body", count = pending.duplicates.len()).to_string())
+            .chain(lines)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn view_lock_screen(&self) -> Element<'_, Message>
+    {
+        let mut lock = column![
+            text(t!("screen-locked")).size(32),
+            text_input(&t!("pin-placeholder"), &self.pin_text)
+                .secure(true)
+                .on_input(Message::PinTextChanged)
+                .on_submit(Message::Unlock)
+                .width(Length::Fixed(240.0))
+                .padding(8),
+            button(text(t!("unlock")).size(self.menu_font_size_in_pixel))
+                .on_press(Message::Unlock)
+                .padding(8),
+        ]
+        .spacing(20)
+        .align_x(iced::Alignment::Center);
+        if self.pin_rejected
+            { lock = lock.push(text(t!("wrong-pin")).size(16).color(Color::from_rgb(0.8, 0.1, 0.1))); }
+
+        center(lock)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    fn view_toasts(&self) -> Element<'_, Message>
+    {
+        // 가장 최근 알림이 맨 위에 오며, 그 닫기 버튼이 Tab 순서에 들어갑니다.
+        let top_focused = self.get_focus_stop() == Some(FocusStop::Toast) && self.modal_focus.is_none();
+        let toasts = self.toasts.get_messages().into_iter().enumerate().map(|(index, message)| {
+            let focused = top_focused && index == 0;
+            container(
+                row![
+                    text(message).size(14).width(Length::Fill),
+                    button(text(t!("dismiss")).size(14)).on_press(Message::DismissToast(index)).padding([2, 8])
+                        .style(move |theme: &Theme, status| with_focus_ring(button::secondary(theme, status), focused)),
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center)
+            )
+            .width(Length::Fill)
+            .padding([4, 8])
+            .style(container::rounded_box)
+            .into()
+        });
+        column(toasts).spacing(4).padding([0, 8]).into()
+    }
+
+    fn view_error<'a>(&self, message: &'a str) -> Element<'a, Message>
+    {
+        let focused = self.modal_focus == Some(0);
+        column![
+            text(t!("error")).size(24).color(Color::from_rgb(0.8, 0.0, 0.0)),
+            text(message).size(18),
+            button(text(t!("ok")).size(18)).on_press(Message::DismissError).padding(8)
+                .style(move |theme: &Theme, status| with_focus_ring(button::primary(theme, status), focused)),
+        ]
+        .spacing(10)
+        .width(Length::Fixed(420.0))
+        .into()
+    }
+
+    fn view_learning(&self) -> Element<'_, Message>
+    {
+        let Some(session) = &self.learning
+            else { return center(text(t!("coming-soon")).size(32)).into(); };
+        let questions = session.get_exam().get_questions();
+        let current = session.get_current();
+        let Some(question) = questions.get(current)
+            else { return center(text(t!("coming-soon")).size(32)).into(); };
+
+        let kind = session.get_kind(current);
+        let multiple = kind.allows_multiple();
+        let selected_choice = session.get_answer(current).first().copied();
+        let answer: Element<'_, Message> = match kind.get_input()
+        {
+            AnswerInput::Choices => question.get_choices().iter().enumerate().fold(
+            column![].spacing(6),
+            |col: iced::widget::Column<'_, Message>, (j, (choice_text, _))| {
+                let label = format!("({}) {}", (b'A' + (j % 26) as u8) as char, choice_text);
+                if multiple
+                {
+                    col.push(
+                        checkbox(session.get_answer(current).contains(&j))
+                            .label(label)
+                            .on_toggle(move |_| Message::ToggleLearningChoice(j))
+                            .size(18)
+                    )
+                }
+                else
+                {
+                    col.push(radio(label, j, selected_choice, Message::ToggleLearningChoice).size(18))
+                }
+            },
+            ).into(),
+            AnswerInput::SingleLine | AnswerInput::Numeric => text_input(&t!("answer-placeholder"), session.get_typed_answer(current))
+                                                                .id(LEARNING_ANSWER_INPUT)
+                                                                .on_input(Message::SetLearningAnswer)
+                                                                .padding(6)
+                                                                .width(Length::Fixed(if kind.get_input() == AnswerInput::Numeric { 200.0 } else { 480.0 }))
+                                                                .into(),
+            AnswerInput::TextArea => text_editor(&self.answer_editor)
+                                        .id(LEARNING_ANSWER_INPUT)
+                                        .placeholder(t!("answer-placeholder"))
+                                        .on_action(Message::LearningEditorAction)
+                                        .height(Length::Fixed(240.0))
+                                        .into(),
+        };
+
+        let mut navigation = row![].spacing(10);
+        if current > 0
+            { navigation = navigation.push(button(text(t!("previous"))).on_press(Message::LearningGoTo(current - 1)).padding(8)); }
+        if current + 1 < questions.len()
+            { navigation = navigation.push(button(text(t!("next"))).on_press(Message::LearningGoTo(current + 1)).padding(8)); }
+        let flag_label = if session.is_flagged(current) { t!("unflag-question") } else { t!("flag-question") };
+        navigation = navigation.push(button(text(flag_label)).on_press(Message::ToggleFlag(current)).padding(8));
+        navigation = navigation.push(button(text(t!("finish"))).on_press(Message::FinishLearning).padding(8));
+        navigation = navigation.push(self.view_jump_box()).align_y(iced::Alignment::Center);
+
+        // 검토하려고 표시한 문항으로 바로 이동하는 목록입니다.
+        let flagged = session.get_flagged().into_iter().fold(
+            column![ text(t!("flagged-questions")).size(18) ].spacing(6),
+            |col: iced::widget::Column<'_, Message>, index| {
+                col.push(button(text((index + 1).to_string()))
+                            .on_press_maybe((index != current).then_some(Message::LearningGoTo(index)))
+                            .width(Length::Fill)
+                            .padding(6))
+            },
+        );
+
+        row![
+            column![
+                text(format!("{} / {}", current + 1, questions.len())).size(18),
+                text(question.get_question().clone()).size(24),
+                answer,
+                navigation,
+            ]
+            .spacing(16)
+            .width(Length::Fill),
+            scrollable(flagged).width(Length::Fixed(120.0)),
+        ]
+        .spacing(16)
+        .padding(20)
+        .into()
+    }
+
+    // fn view_jump_box(&self) -> Element<'_, Message>
+    /// The "go to" box of the questions table and the learning page.
+    fn view_jump_box(&self) -> Element<'_, Message>
+    {
+        row![
+            text(t!("go-to-number")).size(16),
+            text_input("#", &self.jump_text)
+                .id(QUESTION_JUMP_INPUT)
+                .on_input(Message::SetJumpText)
+                .on_submit(Message::JumpToQuestion)
+                .width(Length::Fixed(80.0))
+                .padding(4),
+        ]
+        .spacing(6)
+        .align_y(iced::Alignment::Center)
+        .into()
+    }
+
+    fn view_grade_report(&self) -> Element<'_, Message>
+    {
+        let report = self.learning.as_ref().map(|session| session.grade());
+        let entries = report.iter().flat_map(|report| report.get_entries().iter().cloned()).collect::<Vec<_>>();
+        let total = report.as_ref().map(|report| report.get_total()).unwrap_or_default();
+
+        let rows = entries.iter().fold(
+            column![].spacing(4),
+            |col: iced::widget::Column<'_, Message>, entry| {
+                let seconds = entry.time_spent.as_secs();
+                let line = format!("{}. {}  {:.2}  {}:{:02}{}",
+                                   entry.number,
+                                   if entry.score >= 1.0 { "O" } else { "X" },
+                                   entry.score,
+                                   seconds / 60,
+                                   seconds % 60,
+                                   if entry.slow { "  ⚠" } else { "" });
+                let slow = entry.slow;
+                col.push(text(line).size(18).style(move |theme: &Theme| text::Style {
+                    color: Some(if slow { Color::from_rgb(0.8, 0.1, 0.1) } else { theme.extended_palette().background.base.text }),
+                }))
+            },
+        );
+
+        column![
+            text(t!("grade-report")).size(32),
+            text(t!("score", score = format_grade(total, entries.len(), self.settings.get_grade_display(), self.settings.get_letter_scale()))).size(24),
+            scrollable(rows).height(Length::Fill).width(Length::Fill),
+            button(text(t!("back")).size(self.menu_font_size_in_pixel))
+                .on_press(Message::GoToPage(Page::Main))
+                .padding(8),
+        ]
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+
+    fn view_student_form<'a>(&self, form: &'a StudentForm) -> Element<'a, Message>
+    {
+        let title = if form.get_editing().is_some() { t!("edit-student") } else { t!("add-student") };
+        let error_text = form.get_error().map(|e| t!(e.get_key()).to_string()).unwrap_or_default();
+        // A dialog stacked above the form holds the focus instead.
+        let focus = self.modal_focus.filter(|_| self.error_message.is_none() && !self.is_overwrite_pending() && self.pending_load.is_none()
+                                                && self.pending_roster.is_none() && self.pending_delete.is_none() && self.pending_merge.is_none()
+                                                && self.pending_resume.is_none() && self.file_browser.is_none());
+
+        column![
+            text(title).size(24),
+            text_input(&t!("student-name"), form.get_name()).id(STUDENT_NAME_INPUT).on_input(Message::StudentNameChanged).padding(6),
+            text_input(&t!("student-id"), form.get_id()).id(STUDENT_ID_INPUT).on_input(Message::StudentIdChanged).padding(6),
+            text_input(&t!("student-group"), form.get_group()).id(STUDENT_GROUP_INPUT).on_input(Message::StudentGroupChanged).padding(6),
+            text(error_text).size(16).color(Color::from_rgb(0.8, 0.1, 0.1)),
+            row![
+                button(text(t!("save"))).on_press(Message::SubmitStudentForm).padding(8)
+                    .style(move |theme: &Theme, status| with_focus_ring(button::primary(theme, status), focus == Some(3))),
+                button(text(t!("cancel"))).on_press(Message::CancelStudentForm).padding(8)
+                    .style(move |theme: &Theme, status| with_focus_ring(button::primary(theme, status), focus == Some(4))),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10)
+        .width(400)
+        .into()
+    }
+
+    fn view_file_browser<'a>(&self, browser: &'a FileBrowser) -> Element<'a, Message>
+    {
+        // A dialog stacked above the browser holds the focus instead.
+        let focus = self.modal_focus.filter(|_| self.error_message.is_none() && !self.is_overwrite_pending() && self.pending_load.is_none()
+                                                && self.pending_roster.is_none() && self.pending_delete.is_none() && self.pending_merge.is_none()
+                                                && self.pending_resume.is_none() && self.session_banks.is_empty());
+        let entries = browser.get_entries();
+        let listing: Element<'a, Message> = if !browser.is_readable()
+            { text(t!("folder-unreadable")).size(16).into() }
+        else if entries.is_empty()
+            { text(t!("folder-empty")).size(16).into() }
+        else
+        {
+            let list = entries.iter().enumerate().fold(column![].spacing(2), |list, (i, entry)| {
+                // 폴더는 이름 뒤에 '/'를 붙여 파일과 구별합니다.
+                let label = if entry.is_dir() { format!("{}/", entry.get_name()) } else { entry.get_name().to_string() };
+                list.push(button(text(label).size(16))
+                            .on_press(ControlTower::get_browser_message(browser, entry))
+                            .width(Length::Fill)
+                            .padding(4)
+                            .style(move |theme: &Theme, status| with_focus_ring(button::text(theme, status), focus == Some(i + 1))))
+            });
+            scrollable(list).height(Length::Fixed(300.0)).into()
+        };
+
+        let mut next = entries.len() + 1;
+        let mut buttons = row![].spacing(10);
+        let mut file_name = None;
+        if browser.is_saving()
+        {
+            let save = browser.get_save_path().map(Message::BrowsePick);
+            file_name = Some(text_input(&t!("file-name"), browser.get_file_name())
+                                .id(FILE_NAME_INPUT)
+                                .on_input(Message::SetBrowserFileName)
+                                .on_submit_maybe(save.clone())
+                                .padding(6));
+            // The file name box takes the first index, the save button the next.
+            let index = next + 1;
+            buttons = buttons.push(button(text(t!("save")))
+                                    .on_press_maybe(save)
+                                    .padding(8)
+                                    .style(move |theme: &Theme, status| with_focus_ring(button::primary(theme, status), focus == Some(index))));
+            next += 2;
         }
+        if browser.get_purpose().picks_folder()
+        {
+            let index = next;
+            buttons = buttons.push(button(text(t!("choose-this-folder")))
+                                    .on_press(Message::BrowsePick(browser.get_directory().to_path_buf()))
+                                    .padding(8)
+                                    .style(move |theme: &Theme, status| with_focus_ring(button::primary(theme, status), focus == Some(index))));
+            next += 1;
+        }
+        buttons = buttons.push(button(text(t!("cancel")))
+                                .on_press(Message::CancelFileBrowser)
+                                .padding(8)
+                                .style(move |theme: &Theme, status| with_focus_ring(button::primary(theme, status), focus == Some(next))));
+
+        let title = match browser.get_purpose()
+        {
+            BrowsePurpose::Save(_) => t!("save-file-title"),
+            _ => t!("file-browser-title"),
+        };
+        column![
+            text(title).size(24),
+            row![
+                button(text(t!("parent-folder")))
+                    .on_press_maybe(browser.has_parent().then_some(Message::BrowseParent))
+                    .padding(8)
+                    .style(move |theme: &Theme, status| with_focus_ring(button::primary(theme, status), focus == Some(0))),
+                text(browser.get_directory().display().to_string()).size(16),
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
+            listing,
+        ]
+        .push(file_name)
+        .push(buttons)
+        .spacing(10)
+        .width(500)
+        .into()
+    }
+
+    // fn get_browser_message(browser: &FileBrowser, entry: &BrowserEntry) -> Message
+    /// Returns the message of clicking `entry` in the in-app file browser:
+    /// directories are opened and files are picked, or while saving, give
+    /// their name to the file saved.
+    fn get_browser_message(browser: &FileBrowser, entry: &BrowserEntry) -> Message
+    {
+        if entry.is_dir()
+            { Message::BrowseDirectory(entry.get_path().to_path_buf()) }
+        else if browser.is_saving()
+            { Message::SetBrowserFileName(entry.get_name().to_string()) }
+        else
+            { Message::BrowsePick(entry.get_path().to_path_buf()) }
     }
 
     // fn get_available_locales() -> Vec<(String, String)>
@@ -803,39 +7631,17 @@ impl ControlTower
     ///
     /// # Output
     /// A `Vec<(String, String)>` where each tuple contains the language name and the locale code.
+    /// The fallback `en` is always included.
     ///
     /// # Examples
-    /// ```no_run
-    /// use crate::control_tower::ControlTower;
+    /// ```ignore
+    /// use qrate_gui::ControlTower;
     /// 
     /// let locales = ControlTower::get_available_locales();
     /// assert!(!locales.is_empty());
     /// ```
     fn get_available_locales() -> Vec<(String, String)>
     {
-        let mut locales = Vec::new();
-
-        for file in LOCALES_DIR.files()
-        {
-            if let Some(file_name_os) = file.path().file_name()
-            {
-                if let Some(file_name) = file_name_os.to_str()
-                {
-                    if file_name.ends_with(".yml")
-                    {
-                        let locale = file_name.trim_end_matches(".yml");
-                        let language_name = match locale 
-                        {
-                            "en" => "English".to_string(),
-                            "ko" => "한국어".to_string(),
-                            "ru" => "Русский".to_string(),
-                            _ => locale.to_string(),
-                        };
-                        locales.push((language_name.clone(), locale.to_string()));
-                    }
-                }
-            }
-        }
-        locales
+        locales_from_file_names(LOCALES_DIR.files().filter_map(|file| file.path().file_name()?.to_str()))
     }
 }
@@ -0,0 +1,128 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::VecDeque;
+use std::sync::{ Mutex, MutexGuard, PoisonError };
+use std::time::{ SystemTime, UNIX_EPOCH };
+
+use crate::format_timestamp;
+
+/// The number of recent events kept for the diagnostics page.
+pub const DIAGNOSTICS_CAPACITY: usize = 200;
+
+/// The events of the whole application, shared by every window.
+static DIAGNOSTICS: Mutex<DiagnosticLog> = Mutex::new(DiagnosticLog { entries: VecDeque::new(), capacity: DIAGNOSTICS_CAPACITY });
+
+/// A rolling buffer of the most recent events, oldest first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagnosticLog
+{
+    entries: VecDeque<String>,
+    capacity: usize,
+}
+
+impl DiagnosticLog
+{
+    // pub fn new(capacity: usize) -> Self
+    /// Creates an empty log keeping at most `capacity` entries.
+    ///
+    /// # Arguments
+    /// * `capacity` - The maximum number of entries; `0` is treated as `1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::DiagnosticLog;
+    /// assert!(DiagnosticLog::new(3).get_entries().is_empty());
+    /// ```
+    pub fn new(capacity: usize) -> Self
+    {
+        Self { entries: VecDeque::new(), capacity: capacity.max(1) }
+    }
+
+    // pub fn push(&mut self, entry: String)
+    /// Appends `entry`, dropping the oldest entry once the log is full.
+    ///
+    /// # Arguments
+    /// * `entry` - The event to record.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::DiagnosticLog;
+    /// let mut log = DiagnosticLog::new(2);
+    /// log.push("first".to_string());
+    /// log.push("second".to_string());
+    /// log.push("third".to_string());
+    /// assert_eq!(log.get_entries(), vec!["second", "third"]);
+    /// assert_eq!(log.to_text(), "second\nthird");
+    /// ```
+    pub fn push(&mut self, entry: String)
+    {
+        if self.entries.len() == self.capacity
+            { self.entries.pop_front(); }
+        self.entries.push_back(entry);
+    }
+
+    // pub fn get_entries(&self) -> Vec<&str>
+    /// Returns the recorded entries, oldest first.
+    ///
+    /// # Examples
+    /// See `DiagnosticLog::push`.
+    pub fn get_entries(&self) -> Vec<&str>
+    {
+        self.entries.iter().map(String::as_str).collect()
+    }
+
+    // pub fn to_text(&self) -> String
+    /// Returns the entries one per line, as shown on the diagnostics page
+    /// and copied for bug reports.
+    ///
+    /// # Examples
+    /// See `DiagnosticLog::push`.
+    pub fn to_text(&self) -> String
+    {
+        self.entries.iter().map(String::as_str).collect::<Vec<&str>>().join("\n")
+    }
+}
+
+fn lock() -> MutexGuard<'static, DiagnosticLog>
+{
+    // A panic while holding the lock leaves the log intact, so keep using it.
+    DIAGNOSTICS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// pub fn log_event(message: &str)
+/// Prints `message` to standard error and records it with the current
+/// time for the diagnostics page.
+///
+/// # Arguments
+/// * `message` - The event, such as an error message.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ diagnostics_text, log_event };
+/// log_event("Error loading QBank: File does not exist.");
+/// assert!(diagnostics_text().ends_with("UTC  Error loading QBank: File does not exist."));
+/// ```
+pub fn log_event(message: &str)
+{
+    eprintln!("{}", message);
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+    lock().push(format!("{}  {}", format_timestamp(seconds), message));
+}
+
+// pub fn diagnostics_text() -> String
+/// Returns the recent events of the application, one per line.
+///
+/// # Examples
+/// See `log_event`.
+pub fn diagnostics_text() -> String
+{
+    lock().to_text()
+}
@@ -0,0 +1,232 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use crate::{ Exam, MAX_DIFFICULTY };
+
+/// The numbers of draws offered for generating an exam until its
+/// difficulty matches the target; `1` draws once.
+pub const BALANCE_ATTEMPTS: [usize; 4] = [1, 5, 10, 20];
+
+/// The wanted difficulty mix of an exam: one relative weight per level from
+/// `1` to `MAX_DIFFICULTY`, so that `[1, 2, 2, 0, 0]` asks for a fifth of
+/// level 1 and two fifths each of levels 2 and 3.
+pub type DifficultyTarget = [u8; MAX_DIFFICULTY as usize];
+
+/// The difficulty profile of an exam: how many of its questions are rated
+/// with each level, shown before export so that an unbalanced paper is
+/// noticed before it is printed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DifficultySummary
+{
+    /// The number of questions per level; index `0` counts the unrated ones.
+    counts: [usize; MAX_DIFFICULTY as usize + 1],
+}
+
+impl DifficultySummary
+{
+    // pub fn get_count(&self, level: u8) -> usize
+    /// Returns the number of questions rated with `level`, or unrated ones
+    /// for `0`.
+    ///
+    /// # Arguments
+    /// * `level` - The difficulty level, from `0` to `MAX_DIFFICULTY`.
+    ///
+    /// # Output
+    /// The count, or `0` for levels above `MAX_DIFFICULTY`.
+    ///
+    /// # Examples
+    /// See `difficulty_summary`.
+    pub fn get_count(&self, level: u8) -> usize
+    {
+        self.counts.get(level as usize).copied().unwrap_or_default()
+    }
+
+    // pub fn get_total(&self) -> usize
+    /// Returns the number of questions of the exam, rated or not.
+    ///
+    /// # Examples
+    /// See `difficulty_summary`.
+    pub fn get_total(&self) -> usize
+    {
+        self.counts.iter().sum()
+    }
+
+    // pub fn get_average(&self) -> Option<f32>
+    /// Returns the average level of the rated questions.
+    ///
+    /// # Output
+    /// The average, or `None` if no question is rated.
+    ///
+    /// # Examples
+    /// See `difficulty_summary`.
+    pub fn get_average(&self) -> Option<f32>
+    {
+        let rated: usize = self.counts[1..].iter().sum();
+        if rated == 0
+            { return None; }
+        let levels: usize = self.counts.iter().enumerate().map(|(level, count)| level * count).sum();
+        Some(levels as f32 / rated as f32)
+    }
+
+    // pub fn distance_to(&self, target: &DifficultyTarget) -> f32
+    /// Measures how far the difficulty mix is from `target`: half the sum
+    /// of the differences between the share of each level and its share of
+    /// the target weights. Unrated questions count as off the target.
+    ///
+    /// # Arguments
+    /// * `target` - The weights of the levels from `1` to `MAX_DIFFICULTY`.
+    ///
+    /// # Output
+    /// `0.0` for a perfect match up to `1.0` for no overlap at all, or `0.0`
+    /// if the exam is empty or every weight is `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ difficulty_summary, Exam, NumberingStyle, QuestionMeta, QuestionMetas };
+    ///
+    /// let questions = (1..=4).map(|id| Question::new(id, id, 1, format!("Q{}", id), vec![])).collect();
+    /// let mut exam = Exam::new(Header::new_empty(), questions, NumberingStyle::Arabic);
+    /// let mut metas = QuestionMetas::new();
+    /// for (id, level) in [(1, 1), (2, 1), (3, 2), (4, 3)]
+    /// {
+    ///     let mut meta = QuestionMeta::new();
+    ///     meta.set_difficulty(level);
+    ///     metas.insert(id, meta);
+    /// }
+    /// exam.set_question_metas(metas);
+    /// let summary = difficulty_summary(&exam);
+    ///
+    /// assert_eq!(summary.distance_to(&[2, 1, 1, 0, 0]), 0.0);
+    /// assert_eq!(summary.distance_to(&[1, 1, 0, 0, 0]), 0.25);
+    /// assert_eq!(summary.distance_to(&[0, 0, 0, 0, 1]), 1.0);
+    /// assert_eq!(summary.distance_to(&[0; 5]), 0.0);
+    /// ```
+    pub fn distance_to(&self, target: &DifficultyTarget) -> f32
+    {
+        let total = self.get_total();
+        let weights: u32 = target.iter().map(|&weight| weight as u32).sum();
+        if total == 0 || weights == 0
+            { return 0.0; }
+        let unrated = self.counts[0] as f32 / total as f32;
+        let rated: f32 = target.iter()
+                            .zip(self.counts[1..].iter())
+                            .map(|(&weight, &count)| (count as f32 / total as f32 - weight as f32 / weights as f32).abs())
+                            .sum();
+        (unrated + rated) / 2.0
+    }
+}
+
+/// The outcome of generating an exam until its difficulty matches the
+/// target: how many draws it took and how close the kept one came.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceReport
+{
+    attempts: usize,
+    summary: DifficultySummary,
+    distance: f32,
+}
+
+impl BalanceReport
+{
+    // pub fn new(attempts: usize, summary: DifficultySummary, distance: f32) -> Self
+    /// Creates a report of `attempts` draws, the best of which has the
+    /// difficulty mix `summary`, `distance` away from the target.
+    ///
+    /// # Examples
+    /// See `Exam::generate_balanced`.
+    pub fn new(attempts: usize, summary: DifficultySummary, distance: f32) -> Self
+    {
+        Self { attempts, summary, distance }
+    }
+
+    // pub fn get_attempts(&self) -> usize
+    /// Returns the number of draws made.
+    ///
+    /// # Examples
+    /// See `Exam::generate_balanced`.
+    pub fn get_attempts(&self) -> usize
+    {
+        self.attempts
+    }
+
+    // pub fn get_summary(&self) -> &DifficultySummary
+    /// Returns the difficulty mix of the exam kept.
+    ///
+    /// # Examples
+    /// See `Exam::generate_balanced`.
+    pub fn get_summary(&self) -> &DifficultySummary
+    {
+        &self.summary
+    }
+
+    // pub fn get_distance(&self) -> f32
+    /// Returns how far the difficulty mix of the exam kept is from the
+    /// target, as measured by `DifficultySummary::distance_to`.
+    ///
+    /// # Examples
+    /// See `Exam::generate_balanced`.
+    pub fn get_distance(&self) -> f32
+    {
+        self.distance
+    }
+}
+
+// pub fn difficulty_summary(exam: &Exam) -> DifficultySummary
+/// Counts the questions of `exam` per difficulty level, using the question
+/// metadata carried by the exam.
+///
+/// # Arguments
+/// * `exam` - The generated exam.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, Question };
+/// use qrate_gui::{ difficulty_summary, Exam, NumberingStyle, QuestionMeta, QuestionMetas, MAX_DIFFICULTY };
+///
+/// let questions = (1..=6).map(|id| Question::new(id, id, 1, format!("Q{}", id), vec![])).collect();
+/// let mut exam = Exam::new(Header::new_empty(), questions, NumberingStyle::Arabic);
+/// let mut metas = QuestionMetas::new();
+/// for (id, level) in [(1, 2), (2, 2), (3, 5), (4, 3), (5, 0)]
+/// {
+///     let mut meta = QuestionMeta::new();
+///     meta.set_difficulty(level);
+///     metas.insert(id, meta);
+/// }
+/// exam.set_question_metas(metas);
+///
+/// let summary = difficulty_summary(&exam);
+/// assert_eq!(summary.get_count(2), 2);
+/// assert_eq!(summary.get_count(5), 1);
+/// assert_eq!(summary.get_count(1), 0);
+/// // Question 5 is rated 0 and question 6 has no metadata: both are unrated.
+/// assert_eq!(summary.get_count(0), 2);
+///
+/// // Every question falls in exactly one band.
+/// let bands: usize = (0..=MAX_DIFFICULTY).map(|level| summary.get_count(level)).sum();
+/// assert_eq!(bands, exam.get_questions().len());
+/// assert_eq!(summary.get_total(), 6);
+///
+/// // The average leaves the unrated questions out: (2 + 2 + 5 + 3) / 4.
+/// assert_eq!(summary.get_average(), Some(3.0));
+///
+/// let unrated = Exam::new(Header::new_empty(), vec![Question::new_empty()], NumberingStyle::Arabic);
+/// assert_eq!(difficulty_summary(&unrated).get_average(), None);
+/// ```
+pub fn difficulty_summary(exam: &Exam) -> DifficultySummary
+{
+    let mut counts = [0; MAX_DIFFICULTY as usize + 1];
+    for question in exam.get_questions().iter()
+    {
+        let level = exam.get_question_metas().get(&question.get_id()).map(|meta| meta.get_difficulty()).unwrap_or_default();
+        counts[level.min(MAX_DIFFICULTY) as usize] += 1;
+    }
+    DifficultySummary { counts }
+}
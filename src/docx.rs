@@ -0,0 +1,235 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fs::File;
+use std::path::Path;
+
+use docx_rs::{ AlignmentType, BreakType, Docx, Paragraph, Pic, Run, Style, StyleType, Table, TableCell, TableRow };
+use image::{ DynamicImage, GenericImageView, ImageOutputFormat };
+
+use crate::{ arrange_in_rows, Exam, ExamLine, ExportError, LoadedImage };
+
+/// The paragraph style of the question prompts, so that all of them can be
+/// restyled at once in the word processor.
+pub const DOCX_QUESTION_STYLE: &str = "Question";
+
+/// The paragraph style of the answer choices.
+pub const DOCX_CHOICE_STYLE: &str = "Choice";
+
+/// How far the answer choices are indented, in twentieths of a point.
+const CHOICE_INDENT: i32 = 720;
+
+/// The widest an image may be printed, in EMU: 6 inches.
+const MAX_IMAGE_WIDTH: u32 = 6 * 914_400;
+
+/// The EMU in one pixel at 96 DPI.
+const EMU_PER_PIXEL: u32 = 9_525;
+
+// pub fn to_docx(exam: &Exam) -> Docx
+/// Lays out `exam` as a Word document that can be edited further: the
+/// title, the header details, then one paragraph per question prompt and
+/// per answer choice, followed by the answer key on a page of its own
+/// unless `Exam::get_answer_key` leaves it out.
+///
+/// The questions keep the numbers of the exam's numbering style as
+/// ordinary text, and the answer space after each question is made of
+/// empty paragraphs, so both can be changed freely. Prompts and choices
+/// have the paragraph styles `DOCX_QUESTION_STYLE` and `DOCX_CHOICE_STYLE`.
+/// Choices printed several to a line are laid out in a table without
+/// borders. Images that are missing or cannot be decoded become a
+/// placeholder line.
+///
+/// # Arguments
+/// * `exam` - The exam.
+///
+/// # Examples
+/// See `export_exam_to_docx`.
+pub fn to_docx(exam: &Exam) -> Docx
+{
+    let mut docx = Docx::new()
+                    .add_style(Style::new(DOCX_QUESTION_STYLE, StyleType::Paragraph).name(DOCX_QUESTION_STYLE))
+                    .add_style(Style::new(DOCX_CHOICE_STYLE, StyleType::Paragraph).name(DOCX_CHOICE_STYLE));
+
+    // The paper code goes in the top right corner, above the title.
+    if let Some(pic) = exam.get_paper_code().and_then(|code| code.to_image()).and_then(|picture| to_pic(&picture))
+        { docx = docx.add_paragraph(Paragraph::new().add_run(Run::new().add_image(pic)).align(AlignmentType::Right)); }
+
+    // Consecutive choices are collected and written together, so that they
+    // can be laid out in columns.
+    let mut choices = Vec::new();
+    for line in exam.layout()
+    {
+        if let ExamLine::Choice { label, text } = line
+        {
+            choices.push(format!("({}) {}", label, text));
+            continue;
+        }
+        docx = push_choices(docx, &mut choices, exam.get_choices_per_row());
+        docx = match line
+        {
+            ExamLine::Title(title) => docx.add_paragraph(with_text(Paragraph::new(), &title, |run| run.bold().size(28)).align(AlignmentType::Center)),
+            ExamLine::Field { label, value } => docx.add_paragraph(with_text(Paragraph::new().add_run(Run::new().add_text(format!("{}: ", label)).bold()), &value, |run| run)),
+            ExamLine::Instructions(instructions) => docx.add_paragraph(with_text(Paragraph::new(), &instructions, |run| run)),
+            ExamLine::Question { number, text } => docx.add_paragraph(with_text(Paragraph::new().style(DOCX_QUESTION_STYLE), &format!("{}. {}", number, text), |run| run)),
+            ExamLine::Image(image) => {
+                let decoded = match image.load()
+                {
+                    LoadedImage::Bytes(bytes) => image::load_from_memory(&bytes).ok().and_then(|picture| to_pic(&picture)),
+                    LoadedImage::Placeholder => None,
+                };
+                match decoded
+                {
+                    Some(pic) => docx.add_paragraph(Paragraph::new().add_run(Run::new().add_image(pic)).align(AlignmentType::Center)),
+                    None => docx.add_paragraph(Paragraph::new().add_run(Run::new().add_text(ExamLine::Image(image).to_text().trim()))),
+                }
+            },
+            ExamLine::Blank => docx.add_paragraph(Paragraph::new()),
+            ExamLine::AnswerSpace(lines) => (0..lines).fold(docx, |docx, _| docx.add_paragraph(Paragraph::new())),
+            ExamLine::Choice { .. } => docx,
+        };
+    }
+    docx = push_choices(docx, &mut choices, exam.get_choices_per_row());
+
+    // The answer key follows on its own page.
+    if exam.get_answer_key()
+    {
+        docx = docx.add_paragraph(Paragraph::new().add_run(Run::new().add_text(exam.get_label("answer-key")).bold().size(28)).page_break_before(true));
+        for (number, labels) in exam.answer_key()
+            { docx = docx.add_paragraph(Paragraph::new().add_run(Run::new().add_text(format!("{}. {}", number, labels.join(", "))))); }
+    }
+    docx
+}
+
+/// Adds `text` to `paragraph`, one run per line with line breaks between
+/// them, each run styled by `style`.
+fn with_text(paragraph: Paragraph, text: &str, style: impl Fn(Run) -> Run) -> Paragraph
+{
+    text.split('\n').enumerate().fold(paragraph, |paragraph, (i, line)| {
+        let run = if i == 0 { Run::new() } else { Run::new().add_break(BreakType::TextWrapping) };
+        paragraph.add_run(style(run.add_text(line)))
+    })
+}
+
+/// Writes the collected `choices` of one question, `per_row` to a line, and
+/// empties the collection.
+fn push_choices(mut docx: Docx, choices: &mut Vec<String>, per_row: usize) -> Docx
+{
+    let paragraph = |choice: &str| with_text(Paragraph::new().style(DOCX_CHOICE_STYLE), choice, |run| run);
+    if per_row <= 1
+    {
+        for choice in choices.drain(..)
+            { docx = docx.add_paragraph(paragraph(&choice).indent(Some(CHOICE_INDENT), None, None, None)); }
+        return docx;
+    }
+    if choices.is_empty()
+        { return docx; }
+    let rows = arrange_in_rows(choices, per_row)
+                .into_iter()
+                .map(|row| {
+                    let mut cells: Vec<TableCell> = row.iter().map(|choice| TableCell::new().add_paragraph(paragraph(choice))).collect();
+                    while cells.len() < per_row
+                        { cells.push(TableCell::new().add_paragraph(Paragraph::new())); }
+                    TableRow::new(cells)
+                })
+                .collect();
+    choices.clear();
+    docx.add_table(Table::without_borders(rows).indent(CHOICE_INDENT))
+}
+
+/// Converts `picture` to a PNG picture for the document, scaled down to
+/// the page width if it is wider.
+fn to_pic(picture: &DynamicImage) -> Option<Pic>
+{
+    let (width, height) = picture.dimensions();
+    let mut png = Vec::new();
+    picture.write_to(&mut png, ImageOutputFormat::Png).ok()?;
+    let pic = Pic::new_with_dimensions(png, width, height);
+    let (width, height) = (width.saturating_mul(EMU_PER_PIXEL), height.saturating_mul(EMU_PER_PIXEL));
+    if width <= MAX_IMAGE_WIDTH
+        { return Some(pic); }
+    Some(pic.size(MAX_IMAGE_WIDTH, (height as u64 * MAX_IMAGE_WIDTH as u64 / width as u64) as u32))
+}
+
+// pub fn export_exam_to_docx(exam: &Exam, path: &Path) -> Result<(), ExportError>
+/// Writes `exam` to `path` as a Word document. See `to_docx`.
+///
+/// # Arguments
+/// * `exam` - The exam to export.
+/// * `path` - The target file path, usually ending in `.docx`.
+///
+/// # Output
+/// `Ok(())` on success, or `ExportError::PermissionDenied` or
+/// `ExportError::Write`.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, Question };
+/// use qrate_gui::{ export_exam_to_docx, verify_export, Exam, NumberingStyle };
+///
+/// let questions = vec![
+///     Question::new(1, 1, 1, "What is 2 + 3?".to_string(), vec![("4".to_string(), false), ("5".to_string(), true)]),
+///     Question::new(2, 2, 1, "Name a prime & explain <why>".to_string(), vec![]),
+/// ];
+/// let mut exam = Exam::new(Header::new_with_default(), questions, NumberingStyle::Roman);
+/// let path = std::env::temp_dir().join("qrate_gui_export_exam_to_docx.docx");
+/// export_exam_to_docx(&exam, &path).unwrap();
+///
+/// // A DOCX file is a ZIP archive holding word/document.xml.
+/// let bytes = std::fs::read(&path).unwrap();
+/// assert!(bytes.starts_with(b"PK"));
+/// assert!(bytes.windows(17).any(|name| name == b"word/document.xml"));
+///
+/// // It reads back with the prompts, numbered in the style of the exam.
+/// let docx = docx_rs::read_docx(&bytes).unwrap();
+/// let texts: Vec<String> = docx.document.children.iter()
+///                             .filter_map(|child| match child { docx_rs::DocumentChild::Paragraph(p) => Some(p.raw_text()), _ => None })
+///                             .collect();
+/// assert!(texts.contains(&"i. What is 2 + 3?".to_string()));
+/// assert!(texts.contains(&"ii. Name a prime & explain <why>".to_string()));
+/// assert!(texts.contains(&"(B) 5".to_string()));
+/// assert!(verify_export(&path, 2));
+/// assert!(!verify_export(&path, 3));
+///
+/// // Choices printed side by side go into a table, leaving the count alone.
+/// exam.set_choices_per_row(2);
+/// export_exam_to_docx(&exam, &path).unwrap();
+/// assert!(verify_export(&path, 2));
+/// ```
+pub fn export_exam_to_docx(exam: &Exam, path: &Path) -> Result<(), ExportError>
+{
+    let file = File::create(path).map_err(|e| ExportError::from_io(&e, path))?;
+    to_docx(exam).build().pack(file).map_err(|e| ExportError::Write(e.to_string()))
+}
+
+// pub fn count_docx_questions(bytes: &[u8]) -> Option<usize>
+/// Reads back a Word document written by `export_exam_to_docx` and counts
+/// its paragraphs in the `DOCX_QUESTION_STYLE` style.
+///
+/// # Arguments
+/// * `bytes` - The contents of the file.
+///
+/// # Output
+/// The number of question prompts, or `None` if the file is not a valid
+/// Word document.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::count_docx_questions;
+/// assert_eq!(count_docx_questions(b"not a zip"), None);
+/// ```
+pub fn count_docx_questions(bytes: &[u8]) -> Option<usize>
+{
+    let docx = docx_rs::read_docx(bytes).ok()?;
+    let count = docx.document.children.iter()
+                    .filter(|child| matches!(child, docx_rs::DocumentChild::Paragraph(paragraph)
+                                                if paragraph.property.style.as_ref().is_some_and(|style| style.val == DOCX_QUESTION_STYLE)))
+                    .count();
+    Some(count)
+}
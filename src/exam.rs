@@ -0,0 +1,2808 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::{ BTreeMap, BTreeSet };
+
+use qrate::{ QBank, Header, Question, Questions };
+use rust_i18n::t;
+
+use crate::{ difficulty_summary, BalanceReport, DifficultyTarget };
+use crate::{ matches, place_pinned, AnswerSpace, BankMetadata, PageTemplate, PaperCode, UsageHistory, MAX_AVOID_RECENT, Category, ImageRef, QuestionMetas, SeededRng, TagFilter, shuffle_choices };
+
+/// The largest number of answer choices printed side by side on one line.
+pub const MAX_CHOICES_PER_ROW: usize = 4;
+
+/// The blank printed for a name or id to be filled in by hand.
+pub const BLANK_FIELD: &str = "____________";
+
+/// The share of a freshly loaded bank drawn into an exam, in percent,
+/// unless the user picks another one.
+pub const DEFAULT_QUESTION_PERCENT: u8 = 20;
+
+/// The shares of the bank offered as the default question count.
+pub const QUESTION_PERCENTS: [u8; 6] = [10, 20, 25, 30, 50, 100];
+
+// pub fn default_question_count(bank_size: usize, percent: u8) -> usize
+/// Derives the default number of questions of an exam from the size of
+/// the bank, so that a small bank is not asked for more questions than it
+/// holds and a large one gives a full exam.
+///
+/// # Arguments
+/// * `bank_size` - The number of questions in the bank.
+/// * `percent` - The share of the bank to draw, in percent.
+///
+/// # Output
+/// The share rounded to the nearest count, at least `1` and at most
+/// `bank_size`.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ default_question_count, DEFAULT_QUESTION_PERCENT };
+///
+/// // The count scales with the bank.
+/// assert_eq!(default_question_count(50, DEFAULT_QUESTION_PERCENT), 10);
+/// assert_eq!(default_question_count(500, DEFAULT_QUESTION_PERCENT), 100);
+/// assert_eq!(default_question_count(12, DEFAULT_QUESTION_PERCENT), 2);
+/// assert_eq!(default_question_count(500, 50), 250);
+///
+/// // At least one question, and never more than the bank holds.
+/// assert_eq!(default_question_count(2, DEFAULT_QUESTION_PERCENT), 1);
+/// assert_eq!(default_question_count(0, DEFAULT_QUESTION_PERCENT), 1);
+/// assert_eq!(default_question_count(5, 100), 5);
+/// assert_eq!(default_question_count(5, 200), 5);
+/// ```
+pub fn default_question_count(bank_size: usize, percent: u8) -> usize
+{
+    let count = (bank_size * percent as usize + 50) / 100;
+    count.clamp(1, bank_size.max(1))
+}
+
+/// The numbering style used to label questions in previews and exports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberingStyle
+{
+    /// Arabic numerals: 1, 2, 3, ...
+    #[default]
+    Arabic,
+
+    /// Lower-case letters: a, b, c, ..., z, aa, ab, ...
+    Alphabetic,
+
+    /// Lower-case Roman numerals: i, ii, iii, iv, ...
+    Roman,
+}
+
+impl NumberingStyle
+{
+    /// All numbering styles, in the order they are offered to the user.
+    pub const ALL: [NumberingStyle; 3] = [ NumberingStyle::Arabic, NumberingStyle::Alphabetic, NumberingStyle::Roman ];
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key describing this numbering style.
+    ///
+    /// # Output
+    /// A `&'static str` usable with `t!`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::NumberingStyle;
+    /// assert_eq!(NumberingStyle::Roman.get_key(), "numbering-roman");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            NumberingStyle::Arabic => "numbering-arabic",
+            NumberingStyle::Alphabetic => "numbering-alphabetic",
+            NumberingStyle::Roman => "numbering-roman",
+        }
+    }
+}
+
+/// The order in which the drawn questions of an exam are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuestionOrder
+{
+    /// All drawn questions in one random order.
+    #[default]
+    Shuffled,
+
+    /// The questions grouped by category, in a random order within each
+    /// category. The categories come in the order chosen with
+    /// `ExamCriteria::set_category_order`, the others in the order the
+    /// header of the bank lists them and uncategorized questions last.
+    /// Suits an exam organized by chapter that still differs from student
+    /// to student.
+    ByCategory,
+}
+
+impl QuestionOrder
+{
+    /// All question orders, in the order they are offered to the user.
+    pub const ALL: [QuestionOrder; 2] = [ QuestionOrder::Shuffled, QuestionOrder::ByCategory ];
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key describing this question order.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::QuestionOrder;
+    /// assert_eq!(QuestionOrder::ByCategory.get_key(), "order-by-category");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            QuestionOrder::Shuffled => "order-shuffled",
+            QuestionOrder::ByCategory => "order-by-category",
+        }
+    }
+}
+
+// pub fn format_number(index: usize, style: NumberingStyle) -> String
+/// Formats a 1-based question number according to the given numbering style.
+///
+/// # Arguments
+/// * `index` - The 1-based number of the question.
+/// * `style` - The `NumberingStyle` to apply.
+///
+/// # Output
+/// The formatted label. For `Alphabetic` and `Roman`, an `index` of `0`
+/// yields an empty string because neither system can express zero.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ format_number, NumberingStyle };
+///
+/// assert_eq!(format_number(1, NumberingStyle::Arabic), "1");
+/// assert_eq!(format_number(9, NumberingStyle::Arabic), "9");
+/// assert_eq!(format_number(10, NumberingStyle::Arabic), "10");
+///
+/// assert_eq!(format_number(1, NumberingStyle::Alphabetic), "a");
+/// assert_eq!(format_number(26, NumberingStyle::Alphabetic), "z");
+/// assert_eq!(format_number(27, NumberingStyle::Alphabetic), "aa");
+/// assert_eq!(format_number(28, NumberingStyle::Alphabetic), "ab");
+/// assert_eq!(format_number(52, NumberingStyle::Alphabetic), "az");
+/// assert_eq!(format_number(53, NumberingStyle::Alphabetic), "ba");
+///
+/// assert_eq!(format_number(1, NumberingStyle::Roman), "i");
+/// assert_eq!(format_number(4, NumberingStyle::Roman), "iv");
+/// assert_eq!(format_number(9, NumberingStyle::Roman), "ix");
+/// assert_eq!(format_number(10, NumberingStyle::Roman), "x");
+/// assert_eq!(format_number(14, NumberingStyle::Roman), "xiv");
+/// assert_eq!(format_number(40, NumberingStyle::Roman), "xl");
+/// assert_eq!(format_number(1994, NumberingStyle::Roman), "mcmxciv");
+///
+/// assert_eq!(format_number(0, NumberingStyle::Roman), "");
+/// ```
+pub fn format_number(index: usize, style: NumberingStyle) -> String
+{
+    match style
+    {
+        NumberingStyle::Arabic => index.to_string(),
+        NumberingStyle::Alphabetic => {
+            // Bijective base-26: a..z, aa..az, ba..
+            let mut label = Vec::new();
+            let mut n = index;
+            while n > 0
+            {
+                n -= 1;
+                label.push(b'a' + (n % 26) as u8);
+                n /= 26;
+            }
+            label.reverse();
+            String::from_utf8(label).unwrap_or_default()
+        },
+        NumberingStyle::Roman => {
+            const TABLE: [(usize, &str); 13] = [
+                (1000, "m"), (900, "cm"), (500, "d"), (400, "cd"),
+                (100, "c"), (90, "xc"), (50, "l"), (40, "xl"),
+                (10, "x"), (9, "ix"), (5, "v"), (4, "iv"), (1, "i"),
+            ];
+            let mut label = String::new();
+            let mut n = index;
+            for (value, symbol) in TABLE
+            {
+                while n >= value
+                {
+                    label.push_str(symbol);
+                    n -= value;
+                }
+            }
+            label
+        },
+    }
+}
+
+/// The header details printed at the top of an exam paper.
+///
+/// Empty fields are left out of the paper; an empty title falls back to the
+/// title of the question bank.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExamInfo
+{
+    title: String,
+    course: String,
+    date: String,
+    instructions: String,
+    student: Option<(String, String)>,
+}
+
+impl ExamInfo
+{
+    // pub fn new() -> Self
+    /// Creates header details with every field empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamInfo;
+    /// let info = ExamInfo::new();
+    /// assert!(info.get_title().is_empty());
+    /// assert!(info.get_instructions().is_empty());
+    /// ```
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // pub fn get_title(&self) -> &str
+    /// Returns the title of the exam paper.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamInfo;
+    /// assert_eq!(ExamInfo::new().get_title(), "");
+    /// ```
+    pub fn get_title(&self) -> &str
+    {
+        &self.title
+    }
+
+    // pub fn set_title(&mut self, title: String)
+    /// Sets the title of the exam paper.
+    ///
+    /// # Arguments
+    /// * `title` - The title, or an empty string for the bank's title.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamInfo;
+    /// let mut info = ExamInfo::new();
+    /// info.set_title("Midterm".to_string());
+    /// assert_eq!(info.get_title(), "Midterm");
+    /// ```
+    pub fn set_title(&mut self, title: String)
+    {
+        self.title = title;
+    }
+
+    // pub fn get_course(&self) -> &str
+    /// Returns the course name.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamInfo;
+    /// assert_eq!(ExamInfo::new().get_course(), "");
+    /// ```
+    pub fn get_course(&self) -> &str
+    {
+        &self.course
+    }
+
+    // pub fn set_course(&mut self, course: String)
+    /// Sets the course name.
+    ///
+    /// # Arguments
+    /// * `course` - The course name.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamInfo;
+    /// let mut info = ExamInfo::new();
+    /// info.set_course("Math 101".to_string());
+    /// assert_eq!(info.get_course(), "Math 101");
+    /// ```
+    pub fn set_course(&mut self, course: String)
+    {
+        self.course = course;
+    }
+
+    // pub fn get_date(&self) -> &str
+    /// Returns the date of the exam as entered by the teacher.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamInfo;
+    /// assert_eq!(ExamInfo::new().get_date(), "");
+    /// ```
+    pub fn get_date(&self) -> &str
+    {
+        &self.date
+    }
+
+    // pub fn set_date(&mut self, date: String)
+    /// Sets the date of the exam. It is printed as entered.
+    ///
+    /// # Arguments
+    /// * `date` - The date, in any format.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamInfo;
+    /// let mut info = ExamInfo::new();
+    /// info.set_date("2026-10-16".to_string());
+    /// assert_eq!(info.get_date(), "2026-10-16");
+    /// ```
+    pub fn set_date(&mut self, date: String)
+    {
+        self.date = date;
+    }
+
+    // pub fn get_instructions(&self) -> &str
+    /// Returns the instructions printed below the header.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamInfo;
+    /// assert_eq!(ExamInfo::new().get_instructions(), "");
+    /// ```
+    pub fn get_instructions(&self) -> &str
+    {
+        &self.instructions
+    }
+
+    // pub fn set_instructions(&mut self, instructions: String)
+    /// Sets the instructions printed below the header.
+    ///
+    /// # Arguments
+    /// * `instructions` - The instruction text.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamInfo;
+    /// let mut info = ExamInfo::new();
+    /// info.set_instructions("Answer all questions.".to_string());
+    /// assert_eq!(info.get_instructions(), "Answer all questions.");
+    /// ```
+    pub fn set_instructions(&mut self, instructions: String)
+    {
+        self.instructions = instructions;
+    }
+
+    // pub fn get_student(&self) -> Option<(&str, &str)>
+    /// Returns the name and id of the student the paper is printed for, or
+    /// `None` if the paper has no name and id fields.
+    ///
+    /// # Examples
+    /// See `ExamInfo::set_student`.
+    pub fn get_student(&self) -> Option<(&str, &str)>
+    {
+        self.student.as_ref().map(|(name, id)| (name.as_str(), id.as_str()))
+    }
+
+    // pub fn set_student(&mut self, name: String, id: String)
+    /// Prints name and id fields in the header, pre-filled with `name` and
+    /// `id`. Empty values are printed as blanks to be filled in by hand.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the student.
+    /// * `id` - The id of the student.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamInfo;
+    /// let mut info = ExamInfo::new();
+    /// assert_eq!(info.get_student(), None);
+    /// info.set_student("Alice".to_string(), "s1".to_string());
+    /// assert_eq!(info.get_student(), Some(("Alice", "s1")));
+    /// info.clear_student();
+    /// assert_eq!(info.get_student(), None);
+    /// ```
+    pub fn set_student(&mut self, name: String, id: String)
+    {
+        self.student = Some((name, id));
+    }
+
+    // pub fn clear_student(&mut self)
+    /// Leaves the name and id fields out of the header.
+    ///
+    /// # Examples
+    /// See `ExamInfo::set_student`.
+    pub fn clear_student(&mut self)
+    {
+        self.student = None;
+    }
+}
+
+/// The criteria used to extract questions from a `QBank` into an `Exam`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExamCriteria
+{
+    start: u16,
+    end: u16,
+    selected: usize,
+    numbering_style: NumberingStyle,
+    choices_per_row: usize,
+    export_locale: Option<String>,
+    answer_space: AnswerSpace,
+    page_template: PageTemplate,
+    print_paper_code: bool,
+    shuffle_choices: bool,
+    answer_key: bool,
+    question_order: QuestionOrder,
+    category_order: Vec<Category>,
+    seed: Option<u64>,
+    info: ExamInfo,
+    tag_filter: TagFilter,
+    excluded_categories: BTreeSet<Category>,
+    difficulty_range: Option<(u8, u8)>,
+    difficulty_target: Option<DifficultyTarget>,
+    balance_attempts: usize,
+    avoid_recent: usize,
+    avoided: BTreeSet<u16>,
+    sections: Vec<SectionCriteria>,
+    pinned: BTreeMap<u16, usize>,
+}
+
+impl Default for ExamCriteria
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl ExamCriteria
+{
+    // pub fn new() -> Self
+    /// Creates new criteria covering the whole bank with ten questions and
+    /// Arabic numbering.
+    ///
+    /// # Output
+    /// A new `ExamCriteria` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExamCriteria, NumberingStyle };
+    /// let criteria = ExamCriteria::new();
+    /// assert_eq!(criteria.get_start(), 1);
+    /// assert_eq!(criteria.get_end(), 0);
+    /// assert_eq!(criteria.get_selected(), 10);
+    /// assert_eq!(criteria.get_numbering_style(), NumberingStyle::Arabic);
+    /// ```
+    pub fn new() -> Self
+    {
+        Self
+        {
+            start: 1,
+            end: 0,
+            selected: 10,
+            numbering_style: NumberingStyle::Arabic,
+            choices_per_row: 1,
+            export_locale: None,
+            answer_space: AnswerSpace::new(),
+            page_template: PageTemplate::new(),
+            print_paper_code: false,
+            shuffle_choices: false,
+            answer_key: true,
+            question_order: QuestionOrder::Shuffled,
+            category_order: Vec::new(),
+            seed: None,
+            info: ExamInfo::new(),
+            tag_filter: TagFilter::default(),
+            excluded_categories: BTreeSet::new(),
+            difficulty_range: None,
+            difficulty_target: None,
+            balance_attempts: 1,
+            avoid_recent: 0,
+            avoided: BTreeSet::new(),
+            sections: Vec::new(),
+            pinned: BTreeMap::new(),
+        }
+    }
+
+    // pub fn get_start(&self) -> u16
+    /// Returns the 1-based id of the first question to consider.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// assert_eq!(ExamCriteria::new().get_start(), 1);
+    /// ```
+    pub fn get_start(&self) -> u16
+    {
+        self.start
+    }
+
+    // pub fn set_start(&mut self, start: u16)
+    /// Sets the 1-based id of the first question to consider.
+    ///
+    /// # Arguments
+    /// * `start` - The first question id.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_start(3);
+    /// assert_eq!(criteria.get_start(), 3);
+    /// ```
+    pub fn set_start(&mut self, start: u16)
+    {
+        self.start = start;
+    }
+
+    // pub fn get_end(&self) -> u16
+    /// Returns the 1-based id of the last question to consider.
+    /// `0` means "up to the last question of the bank".
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// assert_eq!(ExamCriteria::new().get_end(), 0);
+    /// ```
+    pub fn get_end(&self) -> u16
+    {
+        self.end
+    }
+
+    // pub fn set_end(&mut self, end: u16)
+    /// Sets the 1-based id of the last question to consider.
+    ///
+    /// # Arguments
+    /// * `end` - The last question id, or `0` for the end of the bank.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_end(20);
+    /// assert_eq!(criteria.get_end(), 20);
+    /// ```
+    pub fn set_end(&mut self, end: u16)
+    {
+        self.end = end;
+    }
+
+    // pub fn get_selected(&self) -> usize
+    /// Returns how many questions should be selected.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// assert_eq!(ExamCriteria::new().get_selected(), 10);
+    /// ```
+    pub fn get_selected(&self) -> usize
+    {
+        self.selected
+    }
+
+    // pub fn set_selected(&mut self, selected: usize)
+    /// Sets how many questions should be selected.
+    ///
+    /// # Arguments
+    /// * `selected` - The number of questions.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(5);
+    /// assert_eq!(criteria.get_selected(), 5);
+    /// ```
+    pub fn set_selected(&mut self, selected: usize)
+    {
+        self.selected = selected;
+    }
+
+    // pub fn get_numbering_style(&self) -> NumberingStyle
+    /// Returns the numbering style used for the questions.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExamCriteria, NumberingStyle };
+    /// assert_eq!(ExamCriteria::new().get_numbering_style(), NumberingStyle::Arabic);
+    /// ```
+    pub fn get_numbering_style(&self) -> NumberingStyle
+    {
+        self.numbering_style
+    }
+
+    // pub fn set_numbering_style(&mut self, style: NumberingStyle)
+    /// Sets the numbering style used for the questions.
+    ///
+    /// # Arguments
+    /// * `style` - The new `NumberingStyle`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExamCriteria, NumberingStyle };
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_numbering_style(NumberingStyle::Roman);
+    /// assert_eq!(criteria.get_numbering_style(), NumberingStyle::Roman);
+    /// ```
+    pub fn set_numbering_style(&mut self, style: NumberingStyle)
+    {
+        self.numbering_style = style;
+    }
+
+    // pub fn get_choices_per_row(&self) -> usize
+    /// Returns how many answer choices are printed side by side on one line
+    /// of the exported PDF.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// assert_eq!(ExamCriteria::new().get_choices_per_row(), 1);
+    /// ```
+    pub fn get_choices_per_row(&self) -> usize
+    {
+        self.choices_per_row
+    }
+
+    // pub fn set_choices_per_row(&mut self, count: usize)
+    /// Sets how many answer choices are printed side by side on one line of
+    /// the exported PDF.
+    ///
+    /// # Arguments
+    /// * `count` - The number of columns, clamped to `1..=MAX_CHOICES_PER_ROW`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExamCriteria, MAX_CHOICES_PER_ROW };
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_choices_per_row(2);
+    /// assert_eq!(criteria.get_choices_per_row(), 2);
+    /// criteria.set_choices_per_row(0);
+    /// assert_eq!(criteria.get_choices_per_row(), 1);
+    /// criteria.set_choices_per_row(9);
+    /// assert_eq!(criteria.get_choices_per_row(), MAX_CHOICES_PER_ROW);
+    /// ```
+    pub fn set_choices_per_row(&mut self, count: usize)
+    {
+        self.choices_per_row = count.clamp(1, MAX_CHOICES_PER_ROW);
+    }
+
+    // pub fn get_export_locale(&self) -> Option<&str>
+    /// Returns the locale the labels of the exported exam are printed in,
+    /// or `None` for the locale of the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// assert_eq!(ExamCriteria::new().get_export_locale(), None);
+    /// ```
+    pub fn get_export_locale(&self) -> Option<&str>
+    {
+        self.export_locale.as_deref()
+    }
+
+    // pub fn set_export_locale(&mut self, locale: Option<String>)
+    /// Sets the locale the labels of the exported exam are printed in.
+    ///
+    /// # Arguments
+    /// * `locale` - The locale, such as `"ko"`, or `None` for the locale of
+    ///   the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_export_locale(Some("ko".to_string()));
+    /// assert_eq!(criteria.get_export_locale(), Some("ko"));
+    /// ```
+    pub fn set_export_locale(&mut self, locale: Option<String>)
+    {
+        self.export_locale = locale;
+    }
+
+    // pub fn get_answer_space(&self) -> &AnswerSpace
+    /// Returns the blank writing space left after each question.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AnswerSpace, ExamCriteria };
+    /// let mut criteria = ExamCriteria::new();
+    /// assert_eq!(criteria.get_answer_space(), &AnswerSpace::new());
+    /// let mut space = AnswerSpace::new();
+    /// space.set_lines(3);
+    /// criteria.set_answer_space(space.clone());
+    /// assert_eq!(criteria.get_answer_space(), &space);
+    /// ```
+    pub fn get_answer_space(&self) -> &AnswerSpace
+    {
+        &self.answer_space
+    }
+
+    // pub fn set_answer_space(&mut self, space: AnswerSpace)
+    /// Sets the blank writing space left after each question.
+    ///
+    /// # Arguments
+    /// * `space` - The number of lines, for all questions or by kind.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::get_answer_space`.
+    pub fn set_answer_space(&mut self, space: AnswerSpace)
+    {
+        self.answer_space = space;
+    }
+
+    // pub fn get_page_template(&self) -> &PageTemplate
+    /// Returns the header and footer printed on every page of the exam.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExamCriteria, PageTemplate };
+    /// let mut criteria = ExamCriteria::new();
+    /// assert!(criteria.get_page_template().is_empty());
+    /// let mut template = PageTemplate::new();
+    /// template.set_header("Springfield High School".to_string());
+    /// criteria.set_page_template(template.clone());
+    /// assert_eq!(criteria.get_page_template(), &template);
+    /// ```
+    pub fn get_page_template(&self) -> &PageTemplate
+    {
+        &self.page_template
+    }
+
+    // pub fn set_page_template(&mut self, template: PageTemplate)
+    /// Sets the header and footer printed on every page of the exam.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::get_page_template`.
+    pub fn set_page_template(&mut self, template: PageTemplate)
+    {
+        self.page_template = template;
+    }
+
+    // pub fn get_print_paper_code(&self) -> bool
+    /// Returns whether exported papers carry a QR code with their name and
+    /// seed.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::set_print_paper_code`.
+    pub fn get_print_paper_code(&self) -> bool
+    {
+        self.print_paper_code
+    }
+
+    // pub fn set_print_paper_code(&mut self, print: bool)
+    /// Sets whether exported papers carry a QR code with their name and
+    /// seed, so that each paper can be matched to its answer key. A single
+    /// exam is named by its title, a variant or a student paper by its own
+    /// name.
+    ///
+    /// # Arguments
+    /// * `print` - `true` to print the code in the top right corner.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ generate_variants, Exam, ExamCriteria, PaperCode };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=4
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(2);
+    /// assert!(!criteria.get_print_paper_code());
+    /// let variants = generate_variants(&qbank, &criteria, 2).unwrap();
+    /// assert_eq!(variants[0].get_exam().get_paper_code(), None);
+    ///
+    /// criteria.set_print_paper_code(true);
+    /// let variants = generate_variants(&qbank, &criteria, 2).unwrap();
+    /// let code = variants[1].get_exam().get_paper_code().unwrap();
+    /// assert_eq!(code, &PaperCode::new("B".to_string(), variants[1].get_seed()));
+    /// assert_eq!(PaperCode::parse(&code.to_payload()).unwrap().get_seed(), variants[1].get_seed());
+    ///
+    /// criteria.set_seed(Some(42));
+    /// criteria.get_info_mut().set_title("Quiz 3".to_string());
+    /// let exam = Exam::generate(&qbank, &criteria).unwrap();
+    /// assert_eq!(exam.get_paper_code(), Some(&PaperCode::new("Quiz 3".to_string(), 42)));
+    /// ```
+    pub fn set_print_paper_code(&mut self, print: bool)
+    {
+        self.print_paper_code = print;
+    }
+
+    // pub fn get_shuffle_choices(&self) -> bool
+    /// Returns whether the answer choices of each question are shuffled.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// assert!(!ExamCriteria::new().get_shuffle_choices());
+    /// ```
+    pub fn get_shuffle_choices(&self) -> bool
+    {
+        self.shuffle_choices
+    }
+
+    // pub fn set_shuffle_choices(&mut self, shuffle: bool)
+    /// Sets whether the answer choices of each question are shuffled.
+    ///
+    /// # Arguments
+    /// * `shuffle` - `true` to shuffle the choices.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_shuffle_choices(true);
+    /// assert!(criteria.get_shuffle_choices());
+    /// ```
+    pub fn set_shuffle_choices(&mut self, shuffle: bool)
+    {
+        self.shuffle_choices = shuffle;
+    }
+
+    // pub fn get_answer_key(&self) -> bool
+    /// Returns whether exported exams end with their answer key.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// assert!(ExamCriteria::new().get_answer_key());
+    /// ```
+    pub fn get_answer_key(&self) -> bool
+    {
+        self.answer_key
+    }
+
+    // pub fn set_answer_key(&mut self, answer_key: bool)
+    /// Sets whether exported exams end with their answer key.
+    ///
+    /// # Arguments
+    /// * `answer_key` - `false` to leave the answer key out, as for papers
+    ///   published to students.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_answer_key(false);
+    /// assert!(!criteria.get_answer_key());
+    /// ```
+    pub fn set_answer_key(&mut self, answer_key: bool)
+    {
+        self.answer_key = answer_key;
+    }
+
+    // pub fn get_question_order(&self) -> QuestionOrder
+    /// Returns the order in which the drawn questions are printed.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExamCriteria, QuestionOrder };
+    /// assert_eq!(ExamCriteria::new().get_question_order(), QuestionOrder::Shuffled);
+    /// ```
+    pub fn get_question_order(&self) -> QuestionOrder
+    {
+        self.question_order
+    }
+
+    // pub fn set_question_order(&mut self, order: QuestionOrder)
+    /// Sets the order in which the drawn questions are printed. The order
+    /// applies to the questions drawn from the range of the criteria;
+    /// sections keep their own order, and pinned questions their positions.
+    ///
+    /// # Arguments
+    /// * `order` - The question order.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, QBank, Question };
+    /// use qrate_gui::{ Exam, ExamCriteria, QuestionOrder };
+    ///
+    /// // Five questions in each of three chapters, listed out of order.
+    /// let mut header = Header::new_with_default();
+    /// header.set_categories(vec!["Sets".to_string(), "Logic".to_string(), "Graphs".to_string()]);
+    /// let mut qbank = QBank::new_with_header(header);
+    /// for id in 1..=15
+    ///     { qbank.push_question(Question::new(id, id, [3, 1, 2][id as usize % 3], format!("Q{}", id), vec![])); }
+    ///
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(15);
+    /// criteria.set_question_order(QuestionOrder::ByCategory);
+    /// assert_eq!(criteria.get_question_order(), QuestionOrder::ByCategory);
+    /// let paper = |criteria: &ExamCriteria| Exam::generate(&qbank, criteria).unwrap()
+    ///                                         .get_questions().iter()
+    ///                                         .map(|q| (q.get_category(), q.get_id()))
+    ///                                         .collect::<Vec<_>>();
+    /// let mut papers = Vec::new();
+    /// for seed in 0..5
+    /// {
+    ///     criteria.set_seed(Some(seed));
+    ///     papers.push(paper(&criteria));
+    /// }
+    /// for ids in papers.iter()
+    /// {
+    ///     // The chapters come in header order, five questions each.
+    ///     let categories: Vec<u8> = ids.iter().map(|(category, _)| *category).collect();
+    ///     assert_eq!(categories, [1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3]);
+    /// }
+    /// // Within the chapters, the order changes with the seed.
+    /// assert!(papers.iter().any(|ids| ids != &papers[0]));
+    /// criteria.set_seed(Some(3));
+    /// assert_eq!(paper(&criteria), papers[3]);
+    /// ```
+    pub fn set_question_order(&mut self, order: QuestionOrder)
+    {
+        self.question_order = order;
+    }
+
+    // pub fn get_category_order(&self) -> &[Category]
+    /// Returns the categories `QuestionOrder::ByCategory` prints first, in
+    /// this order.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::set_category_order`.
+    pub fn get_category_order(&self) -> &[Category]
+    {
+        &self.category_order
+    }
+
+    // pub fn set_category_order(&mut self, order: Vec<Category>)
+    /// Sets the order in which `QuestionOrder::ByCategory` prints the
+    /// categories. Categories missing from `order` follow in the order the
+    /// header of the bank lists them, uncategorized questions last.
+    ///
+    /// # Arguments
+    /// * `order` - The categories to print first, in this order.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, QBank, Question };
+    /// use qrate_gui::{ Category, Exam, ExamCriteria, QuestionOrder };
+    ///
+    /// let mut header = Header::new_with_default();
+    /// header.set_categories(vec!["Sets".to_string(), "Logic".to_string(), "Graphs".to_string()]);
+    /// let mut qbank = QBank::new_with_header(header.clone());
+    /// for id in 1..=6
+    ///     { qbank.push_question(Question::new(id, id, [3, 1, 2][id as usize % 3], format!("Q{}", id), vec![])); }
+    ///
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(6);
+    /// criteria.set_question_order(QuestionOrder::ByCategory);
+    /// criteria.set_category_order(vec![Category::Named("Graphs".to_string())]);
+    /// assert_eq!(criteria.get_category_order(), [Category::Named("Graphs".to_string())]);
+    /// let exam = Exam::generate(&qbank, &criteria).unwrap();
+    /// let categories: Vec<u8> = exam.get_questions().iter().map(|q| q.get_category()).collect();
+    /// assert_eq!(categories, [3, 3, 1, 1, 2, 2]);
+    ///
+    /// let all = [Category::Uncategorized, Category::Named("Logic".to_string()),
+    ///            Category::Named("Sets".to_string()), Category::Named("Graphs".to_string())];
+    /// assert_eq!(criteria.arrange_categories(&header, &all), [
+    ///     Category::Named("Graphs".to_string()),
+    ///     Category::Named("Sets".to_string()),
+    ///     Category::Named("Logic".to_string()),
+    ///     Category::Uncategorized,
+    /// ]);
+    /// ```
+    pub fn set_category_order(&mut self, order: Vec<Category>)
+    {
+        self.category_order = order;
+    }
+
+    // pub fn arrange_categories(&self, header: &Header, categories: &[Category]) -> Vec<Category>
+    /// Puts `categories` in the order `QuestionOrder::ByCategory` prints
+    /// them.
+    ///
+    /// # Arguments
+    /// * `header` - The header of the bank the categories belong to.
+    /// * `categories` - The categories to arrange.
+    ///
+    /// # Output
+    /// The categories in exam order.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::set_category_order`.
+    pub fn arrange_categories(&self, header: &Header, categories: &[Category]) -> Vec<Category>
+    {
+        let mut arranged = categories.to_vec();
+        arranged.sort_by_key(|category| category_position(header, category, &self.category_order));
+        arranged
+    }
+
+    // pub fn get_seed(&self) -> Option<u64>
+    /// Returns the seed used to draw the questions and shuffle the choices.
+    /// `None` means a new seed is taken from the clock for every paper.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// assert_eq!(ExamCriteria::new().get_seed(), None);
+    /// ```
+    pub fn get_seed(&self) -> Option<u64>
+    {
+        self.seed
+    }
+
+    // pub fn set_seed(&mut self, seed: Option<u64>)
+    /// Sets the seed used to draw the questions and shuffle the choices.
+    /// The same seed with the same criteria and bank reproduces the same
+    /// paper, so two classes can be given the identical exam.
+    ///
+    /// # Arguments
+    /// * `seed` - A fixed seed, or `None` for a clock-based seed.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ Exam, ExamCriteria };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=30
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// let ids = |exam: &Exam| exam.get_questions().iter().map(|q| q.get_id()).collect::<Vec<u16>>();
+    ///
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(10);
+    /// criteria.set_seed(Some(42));
+    /// assert_eq!(criteria.get_seed(), Some(42));
+    /// let first = Exam::generate(&qbank, &criteria).unwrap();
+    /// let second = Exam::generate(&qbank, &criteria).unwrap();
+    /// assert_eq!(ids(&first), ids(&second));
+    /// assert_eq!(first.get_seed(), Some(42));
+    ///
+    /// // Without a seed, every paper is drawn afresh, and the seed it was
+    /// // drawn with reproduces it.
+    /// criteria.set_seed(None);
+    /// let papers: Vec<Exam> = (0..5).map(|_| Exam::generate(&qbank, &criteria).unwrap()).collect();
+    /// assert!(papers.iter().any(|paper| ids(paper) != ids(&papers[0])));
+    /// criteria.set_seed(papers[3].get_seed());
+    /// assert_eq!(ids(&Exam::generate(&qbank, &criteria).unwrap()), ids(&papers[3]));
+    /// ```
+    pub fn set_seed(&mut self, seed: Option<u64>)
+    {
+        self.seed = seed;
+    }
+
+    // pub fn get_sections(&self) -> &[SectionCriteria]
+    /// Returns the sections the exam is drawn in, in exam order.
+    /// Without sections, the exam is drawn from the range of the criteria.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// assert!(ExamCriteria::new().get_sections().is_empty());
+    /// ```
+    pub fn get_sections(&self) -> &[SectionCriteria]
+    {
+        &self.sections
+    }
+
+    // pub fn set_sections(&mut self, sections: Vec<SectionCriteria>)
+    /// Sets the sections the exam is drawn in. Each section draws from its
+    /// own range and decides on its own whether its questions are shuffled;
+    /// the range and count of the criteria are then not used. A question
+    /// drawn by one section is left out of the ranges of the later ones.
+    ///
+    /// # Arguments
+    /// * `sections` - The sections, in exam order.
+    ///
+    /// # Examples
+    /// See `Exam::generate_with_metas`.
+    pub fn set_sections(&mut self, sections: Vec<SectionCriteria>)
+    {
+        self.sections = sections;
+    }
+
+    // pub fn get_pinned(&self) -> &BTreeMap<u16, usize>
+    /// Returns the questions that always appear, with the 1-based position
+    /// each one is put at, by question id.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::pin_question`.
+    pub fn get_pinned(&self) -> &BTreeMap<u16, usize>
+    {
+        &self.pinned
+    }
+
+    // pub fn pin_question(&mut self, id: u16, position: usize)
+    /// Pins a question, so that it appears in every exam at `position`
+    /// regardless of the random draw, which fills the other positions.
+    /// A question pinned at the same position before is unpinned.
+    ///
+    /// Pinned questions count towards the number of selected questions and
+    /// are placed even if the range or the filters leave them out. With
+    /// sections, they are placed in addition to the questions of the
+    /// sections.
+    ///
+    /// # Arguments
+    /// * `id` - The id of the question.
+    /// * `position` - The 1-based position in the exam; `0` is treated as `1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.pin_question(12, 1);
+    /// criteria.pin_question(7, 5);
+    /// assert_eq!(criteria.get_pinned().get(&12), Some(&1));
+    ///
+    /// // Taking the position of another pin replaces it.
+    /// criteria.pin_question(3, 5);
+    /// assert_eq!(criteria.get_pinned().get(&7), None);
+    /// assert_eq!(criteria.get_pinned().get(&3), Some(&5));
+    ///
+    /// criteria.unpin_question(12);
+    /// assert_eq!(criteria.get_pinned().len(), 1);
+    /// criteria.clear_pinned();
+    /// assert!(criteria.get_pinned().is_empty());
+    /// ```
+    pub fn pin_question(&mut self, id: u16, position: usize)
+    {
+        let position = position.max(1);
+        self.pinned.retain(|_, pinned_at| *pinned_at != position);
+        self.pinned.insert(id, position);
+    }
+
+    // pub fn unpin_question(&mut self, id: u16)
+    /// Lets a pinned question be drawn at random again.
+    ///
+    /// # Arguments
+    /// * `id` - The id of the question.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::pin_question`.
+    pub fn unpin_question(&mut self, id: u16)
+    {
+        self.pinned.remove(&id);
+    }
+
+    // pub fn clear_pinned(&mut self)
+    /// Unpins every question.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::pin_question`.
+    pub fn clear_pinned(&mut self)
+    {
+        self.pinned.clear();
+    }
+
+    // pub fn get_info(&self) -> &ExamInfo
+    /// Returns the header details given to generated exams.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExamCriteria, ExamInfo };
+    /// assert_eq!(ExamCriteria::new().get_info(), &ExamInfo::new());
+    /// ```
+    pub fn get_info(&self) -> &ExamInfo
+    {
+        &self.info
+    }
+
+    // pub fn get_info_mut(&mut self) -> &mut ExamInfo
+    /// Returns the header details given to generated exams for modification.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.get_info_mut().set_course("Physics".to_string());
+    /// assert_eq!(criteria.get_info().get_course(), "Physics");
+    /// ```
+    pub fn get_info_mut(&mut self) -> &mut ExamInfo
+    {
+        &mut self.info
+    }
+
+    // pub fn get_tag_filter(&self) -> &TagFilter
+    /// Returns the tags a question must carry to be drawn.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// assert!(ExamCriteria::new().get_tag_filter().is_empty());
+    /// ```
+    pub fn get_tag_filter(&self) -> &TagFilter
+    {
+        &self.tag_filter
+    }
+
+    // pub fn set_tag_filter(&mut self, tag_filter: TagFilter)
+    /// Sets the tags a question must carry to be drawn.
+    ///
+    /// # Arguments
+    /// * `tag_filter` - The filter, or an empty filter to draw from every question.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExamCriteria, TagFilter, TagMode };
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_tag_filter(TagFilter::parse("algebra", TagMode::Any));
+    /// assert_eq!(criteria.get_tag_filter().get_mode(), TagMode::Any);
+    /// ```
+    pub fn set_tag_filter(&mut self, tag_filter: TagFilter)
+    {
+        self.tag_filter = tag_filter;
+    }
+
+    // pub fn is_category_included(&self, category: &Category) -> bool
+    /// Returns whether questions of `category` may be drawn.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ Category, ExamCriteria };
+    /// assert!(ExamCriteria::new().is_category_included(&Category::Uncategorized));
+    /// ```
+    pub fn is_category_included(&self, category: &Category) -> bool
+    {
+        !self.excluded_categories.contains(category)
+    }
+
+    // pub fn set_category_included(&mut self, category: Category, included: bool)
+    /// Includes or excludes the questions of `category`.
+    ///
+    /// # Arguments
+    /// * `category` - The category to toggle.
+    /// * `included` - Whether its questions may be drawn.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ Category, ExamCriteria };
+    /// let mut criteria = ExamCriteria::new();
+    /// let algebra = Category::Named("Algebra".to_string());
+    /// criteria.set_category_included(algebra.clone(), false);
+    /// assert!(!criteria.is_category_included(&algebra));
+    /// criteria.set_category_included(algebra.clone(), true);
+    /// assert!(criteria.is_category_included(&algebra));
+    /// ```
+    pub fn set_category_included(&mut self, category: Category, included: bool)
+    {
+        if included
+            { self.excluded_categories.remove(&category); }
+        else
+            { self.excluded_categories.insert(category); }
+    }
+
+    // pub fn get_difficulty_range(&self) -> Option<(u8, u8)>
+    /// Returns the lowest and the highest difficulty level of the questions
+    /// that may be drawn, or `None` to draw regardless of difficulty.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::set_difficulty_range`.
+    pub fn get_difficulty_range(&self) -> Option<(u8, u8)>
+    {
+        self.difficulty_range
+    }
+
+    // pub fn set_difficulty_range(&mut self, range: Option<(u8, u8)>)
+    /// Restricts the questions that may be drawn to a range of difficulty
+    /// levels. Unrated questions are left out while a range is set.
+    ///
+    /// # Arguments
+    /// * `range` - The lowest and the highest level, in either order, or
+    ///   `None` to draw regardless of difficulty.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// let mut criteria = ExamCriteria::new();
+    /// assert_eq!(criteria.get_difficulty_range(), None);
+    /// assert!(criteria.is_difficulty_included(0));
+    ///
+    /// // Bounds entered backwards are put in order.
+    /// criteria.set_difficulty_range(Some((4, 2)));
+    /// assert_eq!(criteria.get_difficulty_range(), Some((2, 4)));
+    /// assert!(criteria.is_difficulty_included(3));
+    /// assert!(!criteria.is_difficulty_included(5));
+    /// assert!(!criteria.is_difficulty_included(0));
+    /// ```
+    pub fn set_difficulty_range(&mut self, range: Option<(u8, u8)>)
+    {
+        self.difficulty_range = range.map(|(min, max)| normalize_range(min, max));
+    }
+
+    // pub fn is_difficulty_included(&self, level: u8) -> bool
+    /// Returns whether questions of difficulty `level` may be drawn, where
+    /// `0` stands for unrated.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::set_difficulty_range`.
+    pub fn is_difficulty_included(&self, level: u8) -> bool
+    {
+        match self.difficulty_range
+        {
+            Some((min, max)) => level != 0 && (min..=max).contains(&level),
+            None => true,
+        }
+    }
+
+    fn matches_difficulty(&self, question: &Question, metas: &QuestionMetas) -> bool
+    {
+        self.is_difficulty_included(metas.get(&question.get_id()).map(|meta| meta.get_difficulty()).unwrap_or(0))
+    }
+
+    // pub fn get_difficulty_target(&self) -> Option<DifficultyTarget>
+    /// Returns the wanted difficulty mix of the exam, or `None` to take the
+    /// mix the draw happens to give.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::set_difficulty_target`.
+    pub fn get_difficulty_target(&self) -> Option<DifficultyTarget>
+    {
+        self.difficulty_target
+    }
+
+    // pub fn set_difficulty_target(&mut self, target: Option<DifficultyTarget>)
+    /// Sets the wanted difficulty mix that `Exam::generate_balanced` draws
+    /// towards. A target whose weights are all `0` asks for nothing and is
+    /// dropped.
+    ///
+    /// # Arguments
+    /// * `target` - The weights of the levels from `1` to `MAX_DIFFICULTY`,
+    ///   or `None` for no target.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// let mut criteria = ExamCriteria::new();
+    /// assert_eq!(criteria.get_difficulty_target(), None);
+    /// criteria.set_difficulty_target(Some([1, 2, 2, 0, 0]));
+    /// assert_eq!(criteria.get_difficulty_target(), Some([1, 2, 2, 0, 0]));
+    /// criteria.set_difficulty_target(Some([0; 5]));
+    /// assert_eq!(criteria.get_difficulty_target(), None);
+    /// ```
+    pub fn set_difficulty_target(&mut self, target: Option<DifficultyTarget>)
+    {
+        self.difficulty_target = target.filter(|target| target.iter().any(|&weight| weight > 0));
+    }
+
+    // pub fn get_balance_attempts(&self) -> usize
+    /// Returns how many draws `Exam::generate_balanced` may make to match
+    /// the difficulty target.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::set_balance_attempts`.
+    pub fn get_balance_attempts(&self) -> usize
+    {
+        self.balance_attempts
+    }
+
+    // pub fn set_balance_attempts(&mut self, attempts: usize)
+    /// Sets how many draws `Exam::generate_balanced` may make to match the
+    /// difficulty target, keeping the closest one.
+    ///
+    /// # Arguments
+    /// * `attempts` - The number of draws; `0` is raised to `1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamCriteria;
+    /// let mut criteria = ExamCriteria::new();
+    /// assert_eq!(criteria.get_balance_attempts(), 1);
+    /// criteria.set_balance_attempts(10);
+    /// assert_eq!(criteria.get_balance_attempts(), 10);
+    /// criteria.set_balance_attempts(0);
+    /// assert_eq!(criteria.get_balance_attempts(), 1);
+    /// ```
+    pub fn set_balance_attempts(&mut self, attempts: usize)
+    {
+        self.balance_attempts = attempts.max(1);
+    }
+
+    // pub fn include_all_categories(&mut self)
+    /// Includes every category again, as after loading another bank.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ Category, ExamCriteria };
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_category_included(Category::Uncategorized, false);
+    /// criteria.include_all_categories();
+    /// assert!(criteria.is_category_included(&Category::Uncategorized));
+    /// ```
+    pub fn include_all_categories(&mut self)
+    {
+        self.excluded_categories.clear();
+    }
+
+    // pub fn get_avoid_recent(&self) -> usize
+    /// Returns how many of the last exported exams the questions are kept
+    /// off the new exam for, or `0` if recently used questions may be
+    /// drawn again.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::set_avoid_recent`.
+    pub fn get_avoid_recent(&self) -> usize
+    {
+        self.avoid_recent
+    }
+
+    // pub fn get_avoided(&self) -> &BTreeSet<u16>
+    /// Returns the ids of the questions left out of the selection pool
+    /// because they appeared on a recent exam.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::set_avoid_recent`.
+    pub fn get_avoided(&self) -> &BTreeSet<u16>
+    {
+        &self.avoided
+    }
+
+    // pub fn set_avoid_recent(&mut self, exams: usize, history: &UsageHistory)
+    /// Leaves the questions of the last `exams` exported exams out of the
+    /// selection pool, so that students do not see them again right away.
+    /// Pinned questions are drawn even if they were used recently.
+    ///
+    /// # Arguments
+    /// * `exams` - How many exams to look back, capped at
+    ///   `MAX_AVOID_RECENT`; `0` turns the avoidance off.
+    /// * `history` - The usage history of the bank.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::SystemTime;
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ Exam, ExamCriteria, QuestionMetas, UsageHistory };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=10
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// let mut history = UsageHistory::new();
+    /// history.record([1, 2, 3], SystemTime::now());
+    /// history.record([4, 5], SystemTime::now());
+    ///
+    /// // With avoidance on, last week's questions are out of the pool.
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(8);
+    /// criteria.set_avoid_recent(1, &history);
+    /// assert_eq!(criteria.get_avoid_recent(), 1);
+    /// assert_eq!(criteria.get_avoided().iter().copied().collect::<Vec<_>>(), [4, 5]);
+    /// for seed in 0..10
+    /// {
+    ///     criteria.set_seed(Some(seed));
+    ///     let exam = Exam::generate_with_metas(&qbank, &criteria, &QuestionMetas::new()).unwrap();
+    ///     assert!(exam.get_questions().iter().all(|q| ![4, 5].contains(&q.get_id())));
+    /// }
+    ///
+    /// // Looking two exams back leaves only five questions to draw from.
+    /// criteria.set_avoid_recent(2, &history);
+    /// assert!(Exam::generate_with_metas(&qbank, &criteria, &QuestionMetas::new()).is_none());
+    /// criteria.set_selected(5);
+    /// let mut ids: Vec<u16> = Exam::generate(&qbank, &criteria).unwrap().get_questions().iter().map(|q| q.get_id()).collect();
+    /// ids.sort();
+    /// assert_eq!(ids, [6, 7, 8, 9, 10]);
+    ///
+    /// // Turning it off puts every question back.
+    /// criteria.set_avoid_recent(0, &history);
+    /// assert!(criteria.get_avoided().is_empty());
+    /// criteria.set_selected(10);
+    /// assert!(Exam::generate(&qbank, &criteria).is_some());
+    /// ```
+    pub fn set_avoid_recent(&mut self, exams: usize, history: &UsageHistory)
+    {
+        self.avoid_recent = exams.min(MAX_AVOID_RECENT);
+        self.avoided = history.recent_questions(self.avoid_recent);
+    }
+}
+
+/// A single line of a laid-out exam, shared by the preview and all exporters
+/// so that every output renders the same content in the same order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExamLine
+{
+    /// The exam title.
+    Title(String),
+
+    /// A labelled header detail, such as the course or the date.
+    Field { label: String, value: String },
+
+    /// The instructions for the students.
+    Instructions(String),
+
+    /// A question prompt with its formatted number.
+    Question { number: String, text: String },
+
+    /// An answer choice with its label.
+    Choice { label: String, text: String },
+
+    /// An image attached to the preceding question prompt.
+    Image(ImageRef),
+
+    /// An empty separator line.
+    Blank,
+
+    /// Blank writing space of the given number of lines after a question.
+    AnswerSpace(usize),
+}
+
+impl ExamLine
+{
+    // pub fn to_text(&self) -> String
+    /// Renders the line as plain text.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExamLine;
+    /// let line = ExamLine::Question { number: "ii".to_string(), text: "Why?".to_string() };
+    /// assert_eq!(line.to_text(), "ii. Why?");
+    ///
+    /// use std::path::PathBuf;
+    /// use qrate_gui::ImageRef;
+    /// let missing = ExamLine::Image(ImageRef::Path(PathBuf::from("/not/here.png")));
+    /// assert_eq!(missing.to_text(), "    [missing image: /not/here.png]");
+    /// ```
+    pub fn to_text(&self) -> String
+    {
+        match self
+        {
+            ExamLine::Title(title) => title.clone(),
+            ExamLine::Field { label, value } => format!("{}: {}", label, value),
+            ExamLine::Instructions(instructions) => instructions.clone(),
+            ExamLine::Question { number, text } => format!("{}. {}", number, text),
+            ExamLine::Choice { label, text } => format!("    ({}) {}", label, text),
+            ExamLine::Image(image) => match image
+            {
+                ImageRef::Path(path) if !image.is_available() => format!("    [missing image: {}]", path.display()),
+                ImageRef::Path(path) => format!("    [image: {}]", path.display()),
+                ImageRef::Embedded(_) => "    [image]".to_string(),
+            },
+            ExamLine::Blank => String::new(),
+            ExamLine::AnswerSpace(lines) => "\n".repeat(lines.saturating_sub(1)),
+        }
+    }
+}
+
+/// One section of a section-structured exam: how many questions are drawn
+/// from a range of question ids, and whether they are shuffled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionCriteria
+{
+    start: u16,
+    end: u16,
+    selected: usize,
+    shuffle: bool,
+}
+
+impl SectionCriteria
+{
+    // pub fn new(start: u16, end: u16, selected: usize) -> Self
+    /// Creates a shuffled section drawing `selected` questions from the ids
+    /// `start..=end`.
+    ///
+    /// # Arguments
+    /// * `start` - The first question id of the range.
+    /// * `end` - The last question id of the range, or `0` for the last
+    ///   question of the bank.
+    /// * `selected` - How many questions the section holds.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::SectionCriteria;
+    /// let section = SectionCriteria::new(1, 5, 3);
+    /// assert_eq!((section.get_start(), section.get_end(), section.get_selected()), (1, 5, 3));
+    /// assert!(section.get_shuffle());
+    /// ```
+    pub fn new(start: u16, end: u16, selected: usize) -> Self
+    {
+        Self { start, end, selected, shuffle: true }
+    }
+
+    // pub fn get_start(&self) -> u16
+    /// Returns the first question id of the range.
+    ///
+    /// # Examples
+    /// See `SectionCriteria::new`.
+    pub fn get_start(&self) -> u16
+    {
+        self.start
+    }
+
+    // pub fn get_end(&self) -> u16
+    /// Returns the last question id of the range, or `0` for the last
+    /// question of the bank.
+    ///
+    /// # Examples
+    /// See `SectionCriteria::new`.
+    pub fn get_end(&self) -> u16
+    {
+        self.end
+    }
+
+    // pub fn get_selected(&self) -> usize
+    /// Returns how many questions the section holds.
+    ///
+    /// # Examples
+    /// See `SectionCriteria::new`.
+    pub fn get_selected(&self) -> usize
+    {
+        self.selected
+    }
+
+    // pub fn get_shuffle(&self) -> bool
+    /// Returns whether the section draws its questions at random and in
+    /// random order. An unshuffled section takes the first questions of
+    /// its range in bank order.
+    ///
+    /// # Examples
+    /// See `SectionCriteria::set_shuffle`.
+    pub fn get_shuffle(&self) -> bool
+    {
+        self.shuffle
+    }
+
+    // pub fn set_shuffle(&mut self, shuffle: bool)
+    /// Sets whether the section is shuffled, independently of the other
+    /// sections.
+    ///
+    /// # Arguments
+    /// * `shuffle` - `false` to keep bank order.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::SectionCriteria;
+    /// let mut warm_up = SectionCriteria::new(1, 3, 3);
+    /// warm_up.set_shuffle(false);
+    /// assert!(!warm_up.get_shuffle());
+    /// ```
+    pub fn set_shuffle(&mut self, shuffle: bool)
+    {
+        self.shuffle = shuffle;
+    }
+
+    // fn draw(&self, questions: &[Question], rng: &mut SeededRng) -> Option<Questions>
+    /// Draws the questions of the section from `questions`, which are in
+    /// bank order and already filtered.
+    fn draw(&self, questions: &[Question], rng: &mut SeededRng) -> Option<Questions>
+    {
+        let end = if self.end == 0 { u16::MAX } else { self.end };
+        let mut candidates: Questions = questions.iter()
+                                            .filter(|question| (self.start..=end).contains(&question.get_id()))
+                                            .cloned()
+                                            .collect();
+        if candidates.len() < self.selected
+            { return None; }
+        if self.shuffle
+            { rng.shuffle(&mut candidates); }
+        candidates.truncate(self.selected);
+        Some(candidates)
+    }
+}
+
+// pub fn parse_sections(text: &str) -> Option<Vec<SectionCriteria>>
+/// Parses sections typed as `start-end:count`, separated by commas, such as
+/// `1-3:3!, 4-10:7` for three warm-up questions in bank order followed by
+/// seven shuffled ones. A trailing `!` keeps the bank order of the section.
+///
+/// # Arguments
+/// * `text` - The typed text; blank entries are skipped.
+///
+/// # Output
+/// The sections in exam order, or `None` if an entry is malformed, starts
+/// at `0`, ends before it starts or holds no questions.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::parse_sections;
+///
+/// let sections = parse_sections(" 1-3:3!, 4 - 0 : 7, ").unwrap();
+/// assert_eq!(sections.len(), 2);
+/// assert_eq!((sections[0].get_start(), sections[0].get_end(), sections[0].get_selected()), (1, 3, 3));
+/// assert!(!sections[0].get_shuffle());
+/// assert_eq!((sections[1].get_start(), sections[1].get_end(), sections[1].get_selected()), (4, 0, 7));
+/// assert!(sections[1].get_shuffle());
+/// assert_eq!(parse_sections(""), Some(vec![]));
+/// assert_eq!(parse_sections("1-3"), None);
+/// assert_eq!(parse_sections("0-3:2"), None);
+/// assert_eq!(parse_sections("5-3:2"), None);
+/// assert_eq!(parse_sections("1-3:0"), None);
+/// ```
+pub fn parse_sections(text: &str) -> Option<Vec<SectionCriteria>>
+{
+    let mut sections = Vec::new();
+    for entry in text.split(',').map(str::trim).filter(|entry| !entry.is_empty())
+    {
+        let (entry, ordered) = match entry.strip_suffix('!')
+        {
+            Some(entry) => (entry, true),
+            None => (entry, false),
+        };
+        let (range, selected) = entry.split_once(':')?;
+        let (start, end) = range.split_once('-')?;
+        let start: u16 = start.trim().parse().ok().filter(|start| *start > 0)?;
+        let end: u16 = end.trim().parse().ok().filter(|end| *end == 0 || *end >= start)?;
+        let selected: usize = selected.trim().parse().ok().filter(|selected| *selected > 0)?;
+        let mut section = SectionCriteria::new(start, end, selected);
+        section.set_shuffle(!ordered);
+        sections.push(section);
+    }
+    Some(sections)
+}
+
+/// A generated examination paper: the questions drawn from a `QBank`
+/// together with the presentation options taken from the `ExamCriteria`.
+#[derive(Debug, Clone)]
+pub struct Exam
+{
+    header: Header,
+    questions: Questions,
+    numbering_style: NumberingStyle,
+    choices_per_row: usize,
+    locale: Option<String>,
+    question_metas: QuestionMetas,
+    info: ExamInfo,
+    bank_metadata: BankMetadata,
+    answer_space: AnswerSpace,
+    page_template: PageTemplate,
+    paper_code: Option<PaperCode>,
+    seed: Option<u64>,
+    answer_key: bool,
+}
+
+impl Exam
+{
+    // pub fn new(header: Header, questions: Questions, numbering_style: NumberingStyle) -> Self
+    /// Creates an `Exam` from already-selected questions.
+    ///
+    /// # Arguments
+    /// * `header` - The header of the originating bank.
+    /// * `questions` - The questions, in exam order.
+    /// * `numbering_style` - How the questions are numbered.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty()], NumberingStyle::Arabic);
+    /// assert_eq!(exam.get_questions().len(), 1);
+    /// ```
+    pub fn new(header: Header, questions: Questions, numbering_style: NumberingStyle) -> Self
+    {
+        Self { header, questions, numbering_style, choices_per_row: 1, locale: None, question_metas: QuestionMetas::new(), info: ExamInfo::new(), bank_metadata: BankMetadata::new(), answer_space: AnswerSpace::new(), page_template: PageTemplate::new(), paper_code: None, seed: None, answer_key: true }
+    }
+
+    // pub fn generate(qbank: &QBank, criteria: &ExamCriteria) -> Option<Self>
+    /// Draws a shuffled set of questions from `qbank` according to `criteria`.
+    ///
+    /// No question metadata is consulted, so a non-empty tag filter in
+    /// `criteria` matches nothing; use `generate_with_metas` to filter by tags.
+    ///
+    /// # Arguments
+    /// * `qbank` - The question bank to draw from.
+    /// * `criteria` - The extraction criteria.
+    ///
+    /// # Output
+    /// `Some(Exam)` on success, or `None` if the range is invalid or the bank
+    /// does not contain enough question groups.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ Exam, ExamCriteria };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=5
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    ///
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(3);
+    /// let exam = Exam::generate(&qbank, &criteria).unwrap();
+    /// assert_eq!(exam.get_questions().len(), 3);
+    ///
+    /// criteria.set_selected(6);
+    /// assert!(Exam::generate(&qbank, &criteria).is_none());
+    /// ```
+    pub fn generate(qbank: &QBank, criteria: &ExamCriteria) -> Option<Self>
+    {
+        Self::generate_with_metas(qbank, criteria, &QuestionMetas::new())
+    }
+
+    // pub fn generate_with_metas(qbank: &QBank, criteria: &ExamCriteria, metas: &QuestionMetas) -> Option<Self>
+    /// Draws a shuffled set of questions from `qbank` according to `criteria`,
+    /// keeping only the questions of included categories whose tags in
+    /// `metas` pass the tag filter and that were not used recently (see
+    /// `ExamCriteria::set_avoid_recent`).
+    ///
+    /// The start and end of `criteria` still refer to question ids of the
+    /// whole bank; the category and tag filters are applied within that range. The exam is given
+    /// `metas` so that images and question kinds carry over.
+    ///
+    /// # Arguments
+    /// * `qbank` - The question bank to draw from.
+    /// * `criteria` - The extraction criteria.
+    /// * `metas` - The question metadata holding the tags.
+    ///
+    /// # Output
+    /// `Some(Exam)` on success, or `None` if the range is invalid or too few
+    /// question groups pass the filter.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ Category, Exam, ExamCriteria, QuestionMeta, QuestionMetas, TagFilter, TagMode };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// let mut metas = QuestionMetas::new();
+    /// for id in 1..=6
+    /// {
+    ///     qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![]));
+    ///     let mut meta = QuestionMeta::new();
+    ///     meta.set_tags(vec![if id % 2 == 0 { "even" } else { "odd" }.to_string()]);
+    ///     metas.insert(id, meta);
+    /// }
+    ///
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(3);
+    /// criteria.set_tag_filter(TagFilter::parse("even", TagMode::All));
+    /// let exam = Exam::generate_with_metas(&qbank, &criteria, &metas).unwrap();
+    /// assert!(exam.get_questions().iter().all(|q| q.get_id() % 2 == 0));
+    ///
+    /// // Only three questions are tagged "even".
+    /// criteria.set_selected(4);
+    /// assert!(Exam::generate_with_metas(&qbank, &criteria, &metas).is_none());
+    ///
+    /// criteria.set_tag_filter(TagFilter::parse("even, odd", TagMode::Any));
+    /// assert_eq!(Exam::generate_with_metas(&qbank, &criteria, &metas).unwrap().get_questions().len(), 4);
+    ///
+    /// // Every question is filed under the first category of the default header.
+    /// criteria.set_category_included(Category::of(qbank.get_header(), &qbank.get_questions()[0]), false);
+    /// assert!(Exam::generate_with_metas(&qbank, &criteria, &metas).is_none());
+    ///
+    /// // Sections: ordered warm-up questions, then shuffled main questions.
+    /// use qrate_gui::SectionCriteria;
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=10
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// let mut warm_up = SectionCriteria::new(1, 3, 3);
+    /// warm_up.set_shuffle(false);
+    /// let main = SectionCriteria::new(4, 10, 7);
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_seed(Some(7));
+    /// criteria.set_sections(vec![warm_up, main]);
+    /// let exam = Exam::generate_with_metas(&qbank, &criteria, &QuestionMetas::new()).unwrap();
+    /// let ids: Vec<u16> = exam.get_questions().iter().map(|q| q.get_id()).collect();
+    /// assert_eq!(ids[..3], [1, 2, 3]);
+    /// let mut main_ids = ids[3..].to_vec();
+    /// assert_ne!(main_ids, [4, 5, 6, 7, 8, 9, 10]);
+    /// main_ids.sort();
+    /// assert_eq!(main_ids, [4, 5, 6, 7, 8, 9, 10]);
+    ///
+    /// // Overlapping sections draw different questions.
+    /// criteria.set_sections(vec![SectionCriteria::new(1, 5, 3), SectionCriteria::new(1, 5, 2)]);
+    /// let exam = Exam::generate_with_metas(&qbank, &criteria, &QuestionMetas::new()).unwrap();
+    /// let mut ids: Vec<u16> = exam.get_questions().iter().map(|q| q.get_id()).collect();
+    /// ids.sort();
+    /// assert_eq!(ids, [1, 2, 3, 4, 5]);
+    /// criteria.set_sections(vec![SectionCriteria::new(1, 5, 3), SectionCriteria::new(1, 5, 3)]);
+    /// assert!(Exam::generate_with_metas(&qbank, &criteria, &QuestionMetas::new()).is_none());
+    ///
+    /// // A section asking for more questions than its range holds fails.
+    /// criteria.set_sections(vec![SectionCriteria::new(1, 3, 4)]);
+    /// assert!(Exam::generate_with_metas(&qbank, &criteria, &QuestionMetas::new()).is_none());
+    ///
+    /// // An open range reaches the largest id, even with gaps in the ids.
+    /// let mut gapped = QBank::new_with_default();
+    /// for id in [1, 2, 3, 20]
+    ///     { gapped.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(4);
+    /// let exam = Exam::generate_with_metas(&gapped, &criteria, &QuestionMetas::new()).unwrap();
+    /// assert!(exam.get_questions().iter().any(|q| q.get_id() == 20));
+    ///
+    /// // Pinned questions: question 10 always opens the exam and question 2
+    /// // is always third, even though question 10 is outside of the range.
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_end(5);
+    /// criteria.set_selected(5);
+    /// criteria.pin_question(10, 1);
+    /// criteria.pin_question(2, 3);
+    /// for seed in 0..10
+    /// {
+    ///     criteria.set_seed(Some(seed));
+    ///     let exam = Exam::generate_with_metas(&qbank, &criteria, &QuestionMetas::new()).unwrap();
+    ///     let ids: Vec<u16> = exam.get_questions().iter().map(|q| q.get_id()).collect();
+    ///     assert_eq!(ids.len(), 5);
+    ///     assert_eq!((ids[0], ids[2]), (10, 2));
+    ///     // The other positions are drawn from the rest of the range.
+    ///     let mut rest = vec![ids[1], ids[3], ids[4]];
+    ///     rest.sort();
+    ///     rest.dedup();
+    ///     assert_eq!(rest.len(), 3);
+    ///     assert!(rest.iter().all(|id| [1, 3, 4, 5].contains(id)));
+    /// }
+    /// ```
+    pub fn generate_with_metas(qbank: &QBank, criteria: &ExamCriteria, metas: &QuestionMetas) -> Option<Self>
+    {
+        // Ids have gaps once questions are deleted, so the range ends at the largest.
+        let last = qbank.get_questions().iter().map(|question| question.get_id()).max().unwrap_or(0);
+        let end = if criteria.end == 0 { last } else { criteria.end };
+        // Pinned questions missing from the bank, say after it was edited, are left out.
+        let pinned: Vec<(usize, Question)> = qbank.get_questions().iter()
+                                                .filter_map(|question| criteria.pinned.get(&question.get_id()).map(|position| (*position, question.clone())))
+                                                .collect();
+        let is_free = |question: &&Question| !criteria.pinned.contains_key(&question.get_id()) && !criteria.avoided.contains(&question.get_id());
+        let selected = criteria.selected.saturating_sub(pinned.len());
+        let seed = criteria.seed.unwrap_or_else(|| SeededRng::from_time().next_u64());
+        let mut rng = SeededRng::new(seed);
+        let drawn = if !criteria.sections.is_empty()
+        {
+            let mut candidates: Questions = qbank.get_questions().iter()
+                                            .filter(is_free)
+                                            .filter(|question| criteria.is_category_included(&Category::of(qbank.get_header(), question)))
+                                            .filter(|question| matches(question, metas, &criteria.tag_filter))
+                                            .filter(|question| criteria.matches_difficulty(question, metas))
+                                            .cloned()
+                                            .collect();
+            let mut drawn = Questions::new();
+            for section in criteria.sections.iter()
+            {
+                // Overlapping sections must not draw a question twice.
+                let section_drawn = section.draw(&candidates, &mut rng)?;
+                candidates.retain(|question| !section_drawn.iter().any(|taken| taken.get_id() == question.get_id()));
+                drawn.extend(section_drawn);
+            }
+            drawn
+        }
+        else if selected == 0 && !pinned.is_empty()
+            { Questions::new() }
+        else
+        {
+            if criteria.start == 0 || criteria.start > end || end > last
+                { return None; }
+            let candidates: Vec<&Question> = qbank.get_questions().iter()
+                                                .filter(|question| (criteria.start..=end).contains(&question.get_id()))
+                                                .filter(is_free)
+                                                .filter(|question| criteria.is_category_included(&Category::of(qbank.get_header(), question)))
+                                                .filter(|question| matches(question, metas, &criteria.tag_filter))
+                                                .filter(|question| criteria.matches_difficulty(question, metas))
+                                                .collect();
+            let mut drawn = draw_by_group(&candidates, selected, &mut rng)?;
+            if criteria.question_order == QuestionOrder::ByCategory
+                { drawn.sort_by_key(|question| category_position(qbank.get_header(), &Category::of(qbank.get_header(), question), &criteria.category_order)); }
+            drawn
+        };
+        let mut exam = Self::new(qbank.get_header().clone(), place_pinned(drawn, pinned), criteria.numbering_style);
+        exam.seed = Some(seed);
+        exam.apply_presentation(criteria, metas, seed);
+        Some(exam)
+    }
+
+    // pub fn generate_balanced(qbank: &QBank, criteria: &ExamCriteria, metas: &QuestionMetas) -> Option<(Self, BalanceReport)>
+    /// Draws exams as `generate_with_metas` does until one matches the
+    /// difficulty target of `criteria`, for at most the number of balance
+    /// attempts, and keeps the closest draw. Each draw uses the next seed
+    /// after the previous one, so that the seed of the exam kept still
+    /// reproduces it. Without a target, a single exam is drawn.
+    ///
+    /// # Arguments
+    /// * `qbank` - The question bank to draw from.
+    /// * `criteria` - The extraction criteria with the difficulty target.
+    /// * `metas` - The question metadata holding the difficulty levels.
+    ///
+    /// # Output
+    /// The exam kept with a `BalanceReport` of the draws, or `None` if no
+    /// exam can be drawn with `criteria`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ difficulty_summary, Exam, ExamCriteria, QuestionMeta, QuestionMetas };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// let mut metas = QuestionMetas::new();
+    /// for id in 1..=30
+    /// {
+    ///     qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![]));
+    ///     let mut meta = QuestionMeta::new();
+    ///     // Mostly easy questions, so that a random draw is rarely balanced.
+    ///     meta.set_difficulty(if id <= 20 { 1 } else { 5 });
+    ///     metas.insert(id, meta);
+    /// }
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(6);
+    /// criteria.set_difficulty_target(Some([1, 0, 0, 0, 1]));
+    /// let target = criteria.get_difficulty_target().unwrap();
+    ///
+    /// for seed in 0..10
+    /// {
+    ///     criteria.set_seed(Some(seed));
+    ///     criteria.set_balance_attempts(1);
+    ///     let first = Exam::generate_with_metas(&qbank, &criteria, &metas).unwrap();
+    ///     let first_distance = difficulty_summary(&first).distance_to(&target);
+    ///
+    ///     // Over several attempts, the exam kept is no worse than the first draw.
+    ///     criteria.set_balance_attempts(20);
+    ///     let (exam, report) = Exam::generate_balanced(&qbank, &criteria, &metas).unwrap();
+    ///     assert!(report.get_distance() <= first_distance);
+    ///     assert!((1..=20).contains(&report.get_attempts()));
+    ///     assert_eq!(report.get_summary(), &difficulty_summary(&exam));
+    ///     assert_eq!(report.get_distance(), difficulty_summary(&exam).distance_to(&target));
+    /// }
+    ///
+    /// // Without a target, one exam is drawn.
+    /// criteria.set_difficulty_target(None);
+    /// let (_, report) = Exam::generate_balanced(&qbank, &criteria, &metas).unwrap();
+    /// assert_eq!(report.get_attempts(), 1);
+    /// ```
+    pub fn generate_balanced(qbank: &QBank, criteria: &ExamCriteria, metas: &QuestionMetas) -> Option<(Self, BalanceReport)>
+    {
+        let seed = criteria.seed.unwrap_or_else(|| SeededRng::from_time().next_u64());
+        let allowed = if criteria.difficulty_target.is_some() { criteria.balance_attempts.max(1) } else { 1 };
+        let mut criteria = criteria.clone();
+        let mut best: Option<(Self, f32)> = None;
+        let mut attempts = 0;
+        for attempt in 0..allowed
+        {
+            criteria.seed = Some(seed.wrapping_add(attempt as u64));
+            let exam = Self::generate_with_metas(qbank, &criteria, metas)?;
+            attempts += 1;
+            let distance = criteria.difficulty_target.map(|target| difficulty_summary(&exam).distance_to(&target)).unwrap_or_default();
+            if best.as_ref().is_none_or(|(_, best_distance)| distance < *best_distance)
+                { best = Some((exam, distance)); }
+            if distance == 0.0
+                { break; }
+        }
+        let (exam, distance) = best?;
+        let summary = difficulty_summary(&exam);
+        Some((exam, BalanceReport::new(attempts, summary, distance)))
+    }
+
+    // pub fn from_selection(qbank: &QBank, ids: &[u16], criteria: &ExamCriteria, metas: &QuestionMetas) -> Option<Self>
+    /// Creates an exam of exactly the questions picked by hand, in the order
+    /// of the bank.
+    ///
+    /// The range, count and filters of `criteria` are ignored; only its
+    /// presentation options, such as the numbering style, the answer space
+    /// and choice shuffling, are applied.
+    ///
+    /// # Arguments
+    /// * `qbank` - The question bank holding the questions.
+    /// * `ids` - The ids of the picked questions, in any order.
+    /// * `criteria` - The criteria whose presentation options are used.
+    /// * `metas` - The question metadata carried over to the exam.
+    ///
+    /// # Output
+    /// `Some(Exam)` on success, or `None` if none of `ids` is in the bank.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ Exam, ExamCriteria, QuestionMetas };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=10
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    ///
+    /// // The criteria ask for three questions, but the selection wins.
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(3);
+    /// let exam = Exam::from_selection(&qbank, &[9, 2, 5, 7, 4], &criteria, &QuestionMetas::new()).unwrap();
+    /// let ids: Vec<u16> = exam.get_questions().iter().map(|q| q.get_id()).collect();
+    /// assert_eq!(ids, [2, 4, 5, 7, 9]);
+    ///
+    /// assert!(Exam::from_selection(&qbank, &[], &criteria, &QuestionMetas::new()).is_none());
+    /// assert!(Exam::from_selection(&qbank, &[42], &criteria, &QuestionMetas::new()).is_none());
+    /// ```
+    pub fn from_selection(qbank: &QBank, ids: &[u16], criteria: &ExamCriteria, metas: &QuestionMetas) -> Option<Self>
+    {
+        let picked: Questions = qbank.get_questions().iter()
+                                    .filter(|question| ids.contains(&question.get_id()))
+                                    .cloned()
+                                    .collect();
+        if picked.is_empty()
+            { return None; }
+        let mut exam = Self::new(qbank.get_header().clone(), picked, criteria.numbering_style);
+        exam.apply_presentation(criteria, metas, criteria.seed.unwrap_or_else(|| SeededRng::from_time().next_u64()));
+        Some(exam)
+    }
+
+    fn apply_presentation(&mut self, criteria: &ExamCriteria, metas: &QuestionMetas, seed: u64)
+    {
+        self.info = criteria.info.clone();
+        self.choices_per_row = criteria.choices_per_row;
+        self.locale = criteria.export_locale.clone();
+        self.answer_space = criteria.answer_space.clone();
+        self.page_template = criteria.page_template.clone();
+        self.answer_key = criteria.answer_key;
+        self.question_metas = metas.clone();
+        if criteria.print_paper_code
+            { self.paper_code = Some(PaperCode::new(criteria.info.get_title().to_string(), seed)); }
+        if criteria.shuffle_choices
+            { self.shuffle_choices(&mut SeededRng::new(seed)); }
+    }
+
+    // pub fn get_header(&self) -> &Header
+    /// Returns the header of the exam.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, NumberingStyle };
+    /// let exam = Exam::new(Header::new_with_default(), vec![], NumberingStyle::Arabic);
+    /// assert_eq!(exam.get_header().get_title(), "Examination");
+    /// ```
+    pub fn get_header(&self) -> &Header
+    {
+        &self.header
+    }
+
+    // pub fn get_questions(&self) -> &Questions
+    /// Returns the questions of the exam, in exam order.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// assert!(exam.get_questions().is_empty());
+    /// ```
+    pub fn get_questions(&self) -> &Questions
+    {
+        &self.questions
+    }
+
+    // pub fn get_numbering_style(&self) -> NumberingStyle
+    /// Returns the numbering style of the exam.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Roman);
+    /// assert_eq!(exam.get_numbering_style(), NumberingStyle::Roman);
+    /// ```
+    pub fn get_numbering_style(&self) -> NumberingStyle
+    {
+        self.numbering_style
+    }
+
+    // pub fn set_numbering_style(&mut self, style: NumberingStyle)
+    /// Sets the numbering style of the exam.
+    ///
+    /// # Arguments
+    /// * `style` - The new `NumberingStyle`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, NumberingStyle };
+    /// let mut exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// exam.set_numbering_style(NumberingStyle::Alphabetic);
+    /// assert_eq!(exam.get_numbering_style(), NumberingStyle::Alphabetic);
+    /// ```
+    pub fn set_numbering_style(&mut self, style: NumberingStyle)
+    {
+        self.numbering_style = style;
+    }
+
+    // pub fn get_question_metas(&self) -> &QuestionMetas
+    /// Returns the metadata used to lay out the questions, keyed by question id.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// assert!(exam.get_question_metas().is_empty());
+    /// ```
+    pub fn get_question_metas(&self) -> &QuestionMetas
+    {
+        &self.question_metas
+    }
+
+    // pub fn set_question_metas(&mut self, metas: QuestionMetas)
+    /// Sets the metadata used to lay out the questions, such as attached images.
+    ///
+    /// # Arguments
+    /// * `metas` - The `QuestionMetas` of the originating bank.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, ExamLine, ImageRef, NumberingStyle, QuestionMeta, QuestionMetas };
+    ///
+    /// let mut meta = QuestionMeta::new();
+    /// meta.set_image(Some(ImageRef::Embedded(vec![1, 2, 3])));
+    /// let mut metas = QuestionMetas::new();
+    /// metas.insert(4, meta);
+    ///
+    /// let question = Question::new(4, 4, 1, "Look".to_string(), vec![]);
+    /// let mut exam = Exam::new(Header::new_with_default(), vec![question], NumberingStyle::Arabic);
+    /// exam.set_question_metas(metas);
+    /// assert_eq!(exam.layout()[3], ExamLine::Image(ImageRef::Embedded(vec![1, 2, 3])));
+    /// ```
+    pub fn set_question_metas(&mut self, metas: QuestionMetas)
+    {
+        self.question_metas = metas;
+    }
+
+    // pub fn shuffle_choices(&mut self, rng: &mut SeededRng)
+    /// Reorders the answer choices of every question.
+    ///
+    /// Correctness flags move with their choices, so `answer_key` always
+    /// reflects the order printed on this paper.
+    ///
+    /// # Arguments
+    /// * `rng` - The generator deciding the new orders.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, NumberingStyle, SeededRng };
+    ///
+    /// let choices = (0..5).map(|i| (format!("C{}", i), i == 3)).collect();
+    /// let question = Question::new(1, 1, 1, "Pick C3".to_string(), choices);
+    /// let mut exam = Exam::new(Header::new_empty(), vec![question], NumberingStyle::Arabic);
+    /// exam.shuffle_choices(&mut SeededRng::new(9));
+    ///
+    /// let (_, labels) = &exam.answer_key()[0];
+    /// let index = (labels[0].as_bytes()[0] - b'A') as usize;
+    /// assert_eq!(exam.get_questions()[0].get_choices()[index].0, "C3");
+    /// ```
+    pub fn shuffle_choices(&mut self, rng: &mut SeededRng)
+    {
+        for question in self.questions.iter_mut()
+            { *question = shuffle_choices(question, rng).0; }
+    }
+
+    // pub fn get_info(&self) -> &ExamInfo
+    /// Returns the header details of the exam paper.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, ExamInfo, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// assert_eq!(exam.get_info(), &ExamInfo::new());
+    /// ```
+    pub fn get_info(&self) -> &ExamInfo
+    {
+        &self.info
+    }
+
+    // pub fn set_info(&mut self, info: ExamInfo)
+    /// Replaces the header details of the exam paper.
+    ///
+    /// # Arguments
+    /// * `info` - The new header details.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, ExamInfo, NumberingStyle };
+    /// let mut exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// let mut info = ExamInfo::new();
+    /// info.set_title("Final".to_string());
+    /// exam.set_info(info.clone());
+    /// assert_eq!(exam.get_info(), &info);
+    /// ```
+    pub fn set_info(&mut self, info: ExamInfo)
+    {
+        self.info = info;
+    }
+
+    // pub fn get_choices_per_row(&self) -> usize
+    /// Returns how many answer choices the PDF export prints side by side.
+    ///
+    /// # Examples
+    /// See `Exam::set_choices_per_row`.
+    pub fn get_choices_per_row(&self) -> usize
+    {
+        self.choices_per_row
+    }
+
+    // pub fn set_choices_per_row(&mut self, count: usize)
+    /// Sets how many answer choices the PDF export prints side by side.
+    ///
+    /// # Arguments
+    /// * `count` - The number of columns, clamped to `1..=MAX_CHOICES_PER_ROW`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, NumberingStyle };
+    /// let mut exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// assert_eq!(exam.get_choices_per_row(), 1);
+    /// exam.set_choices_per_row(3);
+    /// assert_eq!(exam.get_choices_per_row(), 3);
+    /// ```
+    pub fn set_choices_per_row(&mut self, count: usize)
+    {
+        self.choices_per_row = count.clamp(1, MAX_CHOICES_PER_ROW);
+    }
+
+    // pub fn get_answer_key(&self) -> bool
+    /// Returns whether the exports end with the answer key.
+    ///
+    /// # Examples
+    /// See `Exam::set_answer_key`.
+    pub fn get_answer_key(&self) -> bool
+    {
+        self.answer_key
+    }
+
+    // pub fn set_answer_key(&mut self, answer_key: bool)
+    /// Sets whether the exports end with the answer key.
+    ///
+    /// # Arguments
+    /// * `answer_key` - `false` to leave the answer key out.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, NumberingStyle };
+    /// let mut exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// assert!(exam.get_answer_key());
+    /// exam.set_answer_key(false);
+    /// assert!(!exam.get_answer_key());
+    /// ```
+    pub fn set_answer_key(&mut self, answer_key: bool)
+    {
+        self.answer_key = answer_key;
+    }
+
+    // pub fn get_locale(&self) -> Option<&str>
+    /// Returns the locale the labels of the exam are printed in, or `None`
+    /// for the locale of the interface.
+    ///
+    /// # Examples
+    /// See `Exam::set_locale`.
+    pub fn get_locale(&self) -> Option<&str>
+    {
+        self.locale.as_deref()
+    }
+
+    // pub fn set_locale(&mut self, locale: Option<String>)
+    /// Sets the locale the labels of the exam, such as the header fields and
+    /// the answer key, are printed in, independently of the interface.
+    ///
+    /// # Arguments
+    /// * `locale` - The locale, such as `"ko"`, or `None` for the locale of
+    ///   the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, ExamInfo, ExamLine, NumberingStyle };
+    /// let mut exam = Exam::new(Header::new_with_default(), vec![], NumberingStyle::Arabic);
+    /// let mut info = ExamInfo::new();
+    /// info.set_course("Math 101".to_string());
+    /// exam.set_info(info);
+    ///
+    /// exam.set_locale(Some("ko".to_string()));
+    /// assert_eq!(exam.get_locale(), Some("ko"));
+    /// assert_eq!(exam.layout()[1].to_text(), "과목: Math 101");
+    /// assert_eq!(exam.get_label("answer-key"), "정답표");
+    ///
+    /// exam.set_locale(Some("ru".to_string()));
+    /// assert_eq!(exam.get_label("answer-key"), "Ключ ответов");
+    /// ```
+    pub fn set_locale(&mut self, locale: Option<String>)
+    {
+        self.locale = locale;
+    }
+
+    // pub fn get_label(&self, key: &str) -> String
+    /// Returns the translation of `key` in the locale of the exam.
+    ///
+    /// # Arguments
+    /// * `key` - The localization key, such as `"answer-key"`.
+    ///
+    /// # Examples
+    /// See `Exam::set_locale`.
+    pub fn get_label(&self, key: &str) -> String
+    {
+        match &self.locale
+        {
+            Some(locale) => t!(key, locale = locale).to_string(),
+            None => t!(key).to_string(),
+        }
+    }
+
+    // pub fn get_answer_space(&self) -> &AnswerSpace
+    /// Returns the blank writing space left after each question.
+    ///
+    /// # Examples
+    /// See `Exam::get_answer_space_lines`.
+    pub fn get_answer_space(&self) -> &AnswerSpace
+    {
+        &self.answer_space
+    }
+
+    // pub fn set_answer_space(&mut self, space: AnswerSpace)
+    /// Sets the blank writing space left after each question, which the
+    /// layout reserves according to the kind of the question.
+    ///
+    /// # Arguments
+    /// * `space` - The number of lines, for all questions or by kind.
+    ///
+    /// # Examples
+    /// See `Exam::get_answer_space_lines`.
+    pub fn set_answer_space(&mut self, space: AnswerSpace)
+    {
+        self.answer_space = space;
+    }
+
+    // pub fn get_page_template(&self) -> &PageTemplate
+    /// Returns the header and footer printed on every page of the PDF
+    /// export.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, QBank, Question };
+    /// use qrate_gui::{ Exam, ExamCriteria, PageTemplate };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+    /// let mut template = PageTemplate::new();
+    /// template.set_footer("Page {page}".to_string());
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_end(1);
+    /// criteria.set_selected(1);
+    /// criteria.set_page_template(template.clone());
+    /// let mut exam = Exam::generate(&qbank, &criteria).unwrap();
+    /// assert_eq!(exam.get_page_template(), &template);
+    /// exam.set_page_template(PageTemplate::new());
+    /// assert!(exam.get_page_template().is_empty());
+    /// ```
+    pub fn get_page_template(&self) -> &PageTemplate
+    {
+        &self.page_template
+    }
+
+    // pub fn set_page_template(&mut self, template: PageTemplate)
+    /// Sets the header and footer printed on every page of the PDF export.
+    ///
+    /// # Arguments
+    /// * `template` - The header and footer templates and the logo.
+    ///
+    /// # Examples
+    /// See `Exam::get_page_template`.
+    pub fn set_page_template(&mut self, template: PageTemplate)
+    {
+        self.page_template = template;
+    }
+
+    // pub fn get_answer_space_lines(&self) -> usize
+    /// Returns the number of blank lines reserved for answers on the whole
+    /// paper, which adds to the height of the printed exam.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ AnswerSpace, Exam, ExamLine, NumberingStyle, QuestionKind, QuestionMeta, QuestionMetas };
+    ///
+    /// let questions = (1..=3).map(|id| Question::new(id, id, 1, format!("Q{}", id), vec![])).collect();
+    /// let mut exam = Exam::new(Header::new_with_default(), questions, NumberingStyle::Arabic);
+    /// let mut essay = QuestionMeta::new();
+    /// essay.set_kind(QuestionKind::MultiLine);
+    /// let mut metas = QuestionMetas::new();
+    /// metas.insert(2, essay);
+    /// exam.set_question_metas(metas);
+    /// assert_eq!(exam.get_answer_space_lines(), 0);
+    ///
+    /// let mut space = AnswerSpace::new();
+    /// space.set_lines(2);
+    /// space.set_kind_lines(QuestionKind::MultiLine, Some(10));
+    /// exam.set_answer_space(space.clone());
+    /// assert_eq!(exam.get_answer_space(), &space);
+    ///
+    /// let reserved: Vec<usize> = exam.layout().iter()
+    ///                             .filter_map(|line| match line { ExamLine::AnswerSpace(lines) => Some(*lines), _ => None })
+    ///                             .collect();
+    /// assert_eq!(reserved, vec![2, 10, 2]);
+    /// assert_eq!(exam.get_answer_space_lines(), 14);
+    ///
+    /// // Each reserved line adds to the plain-text height of the paper.
+    /// let height = |exam: &Exam| -> usize { exam.layout().iter().map(|line| line.to_text().split('\n').count()).sum() };
+    /// let with_space = height(&exam);
+    /// exam.set_answer_space(AnswerSpace::new());
+    /// assert_eq!(with_space - height(&exam), 14);
+    /// ```
+    pub fn get_answer_space_lines(&self) -> usize
+    {
+        self.questions.iter().map(|question| self.answer_lines(question)).sum()
+    }
+
+    fn answer_lines(&self, question: &Question) -> usize
+    {
+        let kind = self.question_metas.get(&question.get_id()).map(|meta| meta.get_kind()).unwrap_or_default();
+        self.answer_space.get_lines_for(kind)
+    }
+
+    // pub fn get_seed(&self) -> Option<u64>
+    /// Returns the seed the questions were drawn with. Entering it on the
+    /// criteria page draws the same paper again. Exams not drawn by
+    /// `Exam::generate_with_metas` have none.
+    ///
+    /// # Examples
+    /// See `ExamCriteria::set_seed`.
+    pub fn get_seed(&self) -> Option<u64>
+    {
+        self.seed
+    }
+
+    // pub fn get_paper_code(&self) -> Option<&PaperCode>
+    /// Returns the code printed as a QR code on the paper, if any.
+    ///
+    /// # Examples
+    /// See `Exam::set_paper_code`.
+    pub fn get_paper_code(&self) -> Option<&PaperCode>
+    {
+        self.paper_code.as_ref()
+    }
+
+    // pub fn set_paper_code(&mut self, code: Option<PaperCode>)
+    /// Sets the code printed as a QR code in the top right corner of the
+    /// PDF export.
+    ///
+    /// # Arguments
+    /// * `code` - The name and seed of the paper, or `None` for no QR code.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, NumberingStyle, PaperCode };
+    /// let mut exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// assert_eq!(exam.get_paper_code(), None);
+    /// exam.set_paper_code(Some(PaperCode::new("A".to_string(), 7)));
+    /// assert_eq!(exam.get_paper_code().unwrap().to_payload(), "QRATE:A:7");
+    /// ```
+    pub fn set_paper_code(&mut self, code: Option<PaperCode>)
+    {
+        self.paper_code = code;
+    }
+
+    // pub fn get_bank_metadata(&self) -> &BankMetadata
+    /// Returns the metadata of the bank the exam was drawn from.
+    ///
+    /// # Examples
+    /// See `Exam::set_bank_metadata`.
+    pub fn get_bank_metadata(&self) -> &BankMetadata
+    {
+        &self.bank_metadata
+    }
+
+    // pub fn set_bank_metadata(&mut self, metadata: BankMetadata)
+    /// Sets the metadata of the bank the exam was drawn from. Its author and
+    /// subject, when filled in, are printed below the title.
+    ///
+    /// # Arguments
+    /// * `metadata` - The bank metadata.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Header;
+    /// use qrate_gui::{ BankMetadata, Exam, ExamLine, NumberingStyle };
+    /// let mut exam = Exam::new(Header::new_with_default(), vec![], NumberingStyle::Arabic);
+    /// let mut metadata = BankMetadata::new();
+    /// metadata.set_subject("Mathematics".to_string());
+    /// exam.set_bank_metadata(metadata.clone());
+    /// assert_eq!(exam.get_bank_metadata(), &metadata);
+    /// assert!(matches!(&exam.layout()[1], ExamLine::Field { value, .. } if value == "Mathematics"));
+    /// assert_eq!(exam.layout()[2], ExamLine::Blank);
+    /// ```
+    pub fn set_bank_metadata(&mut self, metadata: BankMetadata)
+    {
+        self.bank_metadata = metadata;
+    }
+
+    // pub fn answer_key(&self) -> Vec<(String, Vec<String>)>
+    /// Returns the labels of the correct choices of each question.
+    ///
+    /// # Output
+    /// One `(number, labels)` pair per question, in exam order, numbered with
+    /// the exam's numbering style.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, NumberingStyle };
+    ///
+    /// let choices = vec![("No".to_string(), false), ("Yes".to_string(), true)];
+    /// let exam = Exam::new(Header::new_empty(),
+    ///                      vec![Question::new(1, 1, 1, "Q".to_string(), choices)],
+    ///                      NumberingStyle::Roman);
+    /// assert_eq!(exam.answer_key(), vec![("i".to_string(), vec!["B".to_string()])]);
+    /// ```
+    pub fn answer_key(&self) -> Vec<(String, Vec<String>)>
+    {
+        self.questions.iter()
+            .enumerate()
+            .map(|(i, question)| {
+                let labels = question.get_choices()
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, (_, correct))| *correct)
+                                .map(|(j, _)| choice_label(j))
+                                .collect();
+                (format_number(i + 1, self.numbering_style), labels)
+            })
+            .collect()
+    }
+
+    // pub fn layout(&self) -> Vec<ExamLine>
+    /// Lays the exam out as a sequence of lines, applying the numbering style.
+    ///
+    /// The preview page and every exporter render this same sequence. The
+    /// header details that are filled in follow the title, with their labels
+    /// in the locale of the exam.
+    ///
+    /// # Output
+    /// A `Vec<ExamLine>` starting with the title.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, ExamLine, NumberingStyle };
+    ///
+    /// let questions = vec![
+    ///     Question::new(1, 1, 1, "First".to_string(), vec![("Yes".to_string(), true)]),
+    ///     Question::new(2, 2, 1, "Second".to_string(), vec![]),
+    /// ];
+    /// let exam = Exam::new(Header::new_with_default(), questions, NumberingStyle::Alphabetic);
+    /// let lines = exam.layout();
+    /// assert_eq!(lines[0], ExamLine::Title("Examination".to_string()));
+    /// assert_eq!(lines[2], ExamLine::Question { number: "a".to_string(), text: "First".to_string() });
+    /// assert_eq!(lines[3], ExamLine::Choice { label: "A".to_string(), text: "Yes".to_string() });
+    /// assert_eq!(lines[5], ExamLine::Question { number: "b".to_string(), text: "Second".to_string() });
+    ///
+    /// use qrate_gui::ExamInfo;
+    /// let mut exam = exam;
+    /// let mut info = ExamInfo::new();
+    /// info.set_title("Midterm".to_string());
+    /// info.set_course("Math 101".to_string());
+    /// info.set_instructions("Answer all questions.".to_string());
+    /// exam.set_info(info);
+    /// let lines = exam.layout();
+    /// assert_eq!(lines[0], ExamLine::Title("Midterm".to_string()));
+    /// assert!(matches!(&lines[1], ExamLine::Field { value, .. } if value == "Math 101"));
+    /// assert_eq!(lines[2], ExamLine::Instructions("Answer all questions.".to_string()));
+    /// assert_eq!(lines[3], ExamLine::Blank);
+    /// ```
+    pub fn layout(&self) -> Vec<ExamLine>
+    {
+        let title = if self.info.title.is_empty()
+            { self.header.get_title().clone() }
+        else
+            { self.info.title.clone() };
+        let mut lines = vec![ ExamLine::Title(title) ];
+        if !self.info.course.is_empty()
+            { lines.push(ExamLine::Field { label: self.get_label("exam-course"), value: self.info.course.clone() }); }
+        if !self.info.date.is_empty()
+            { lines.push(ExamLine::Field { label: self.get_label("exam-date"), value: self.info.date.clone() }); }
+        if let Some((name, id)) = self.info.get_student()
+        {
+            let filled = |value: &str| if value.is_empty() { BLANK_FIELD.to_string() } else { value.to_string() };
+            lines.push(ExamLine::Field { label: self.get_label("student-name"), value: filled(name) });
+            lines.push(ExamLine::Field { label: self.get_label("student-id"), value: filled(id) });
+        }
+        if !self.bank_metadata.get_author().is_empty()
+            { lines.push(ExamLine::Field { label: self.get_label("bank-author"), value: self.bank_metadata.get_author().to_string() }); }
+        if !self.bank_metadata.get_subject().is_empty()
+            { lines.push(ExamLine::Field { label: self.get_label("bank-subject"), value: self.bank_metadata.get_subject().to_string() }); }
+        if !self.info.instructions.is_empty()
+            { lines.push(ExamLine::Instructions(self.info.instructions.clone())); }
+        lines.push(ExamLine::Blank);
+        for (i, question) in self.questions.iter().enumerate()
+        {
+            lines.push(ExamLine::Question {
+                number: format_number(i + 1, self.numbering_style),
+                text: question.get_question().clone(),
+            });
+            if let Some(image) = self.question_metas.get(&question.get_id()).and_then(|meta| meta.get_image())
+                { lines.push(ExamLine::Image(image.clone())); }
+            lines.extend(Self::choice_lines(question));
+            let reserved = self.answer_lines(question);
+            if reserved > 0
+                { lines.push(ExamLine::AnswerSpace(reserved)); }
+            lines.push(ExamLine::Blank);
+        }
+        lines
+    }
+
+    fn choice_lines(question: &Question) -> Vec<ExamLine>
+    {
+        question.get_choices()
+            .iter()
+            .enumerate()
+            .map(|(j, (choice_text, _))| ExamLine::Choice {
+                label: choice_label(j),
+                text: choice_text.clone(),
+            })
+            .collect()
+    }
+}
+
+// pub fn normalize_range(min: u8, max: u8) -> (u8, u8)
+/// Puts the bounds of a range in order, so that a range entered backwards
+/// selects the same levels instead of none.
+///
+/// # Arguments
+/// * `min` - The bound entered as the lower one.
+/// * `max` - The bound entered as the upper one.
+///
+/// # Output
+/// The smaller bound and the larger bound.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::normalize_range;
+/// assert_eq!(normalize_range(2, 4), (2, 4));
+/// assert_eq!(normalize_range(4, 2), (2, 4));
+/// assert_eq!(normalize_range(3, 3), (3, 3));
+/// ```
+pub fn normalize_range(min: u8, max: u8) -> (u8, u8)
+{
+    (min.min(max), min.max(max))
+}
+
+// fn draw_by_group(candidates: &[&Question], selected: usize, rng: &mut SeededRng) -> Option<Questions>
+/// Draws `selected` questions of different groups from `candidates`, one
+/// question per group, in a random order, like the `qrate` generator does
+/// but from `rng`, so that the seed of the exam reproduces the draw.
+fn draw_by_group(candidates: &[&Question], selected: usize, rng: &mut SeededRng) -> Option<Questions>
+{
+    let mut groups: BTreeMap<u16, Vec<&Question>> = BTreeMap::new();
+    for question in candidates
+        { groups.entry(question.get_group()).or_default().push(question); }
+    if selected == 0 || groups.len() < selected
+        { return None; }
+    let mut groups: Vec<Vec<&Question>> = groups.into_values().collect();
+    rng.shuffle(&mut groups);
+    let drawn = groups.into_iter()
+                    .take(selected)
+                    .map(|group| group[rng.next_below(group.len())].clone())
+                    .collect();
+    Some(drawn)
+}
+
+/// Returns where `category` comes in an exam ordered by category: the
+/// categories of `order` first, then the others as the header of the bank
+/// lists them, uncategorized questions after all categories.
+fn category_position(header: &Header, category: &Category, order: &[Category]) -> usize
+{
+    if let Some(position) = order.iter().position(|chosen| chosen == category)
+        { return position; }
+    match category
+    {
+        Category::Named(name) => header.get_categories().iter()
+                                    .position(|listed| listed.trim() == name)
+                                    .map_or(usize::MAX - 1, |position| order.len() + position),
+        Category::Uncategorized => usize::MAX,
+    }
+}
+
+// pub fn arrange_in_rows<T: Clone>(items: &[T], per_row: usize) -> Vec<Vec<T>>
+/// Distributes `items` row by row into lines of `per_row` columns, the way
+/// the PDF export lays out answer choices. Only the last row may be shorter.
+///
+/// # Arguments
+/// * `items` - The items, in reading order.
+/// * `per_row` - The number of columns; `0` is treated as `1`.
+///
+/// # Output
+/// `items.len().div_ceil(per_row)` rows.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::arrange_in_rows;
+/// let rows = arrange_in_rows(&["A", "B", "C", "D", "E"], 2);
+/// assert_eq!(rows.len(), 3);
+/// assert_eq!(rows, vec![vec!["A", "B"], vec!["C", "D"], vec!["E"]]);
+///
+/// assert_eq!(arrange_in_rows(&["A", "B", "C", "D"], 4).len(), 1);
+/// assert_eq!(arrange_in_rows(&["A", "B", "C"], 1).len(), 3);
+/// assert_eq!(arrange_in_rows(&["A", "B"], 0).len(), 2);
+/// assert!(arrange_in_rows::<&str>(&[], 3).is_empty());
+/// ```
+pub fn arrange_in_rows<T: Clone>(items: &[T], per_row: usize) -> Vec<Vec<T>>
+{
+    items.chunks(per_row.max(1)).map(|row| row.to_vec()).collect()
+}
+
+pub(crate) fn choice_label(index: usize) -> String
+{
+    ((b'A' + (index % 26) as u8) as char).to_string()
+}
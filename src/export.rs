@@ -0,0 +1,672 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fmt;
+use std::fs;
+use std::io::{ self, Cursor };
+use std::path::Path;
+
+use calamine::{ DataType, Reader, open_workbook_auto };
+use genpdf::{ Document, Element, Context, PageDecorator, Position, SimplePageDecorator, Alignment, elements, fonts, render, style };
+use rust_xlsxwriter::Workbook;
+
+use qrate::QBank;
+
+use crate::{ arrange_in_rows, Exam, ExamInfo, ExamLine, LoadedImage, PageTemplate };
+use crate::{ study_guide_layout, export_exam_to_markdown, count_docx_questions, export_exam_to_docx, StudyGuideLine };
+
+/// The directory from which PDF fonts are loaded. See the README.
+const FONT_DIR: &str = "./fonts";
+
+/// The file-name prefix of the PDF fonts (`font-Regular.ttf`, ...).
+const FONT_NAME: &str = "font";
+
+/// Describes why an export failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportError
+{
+    /// The fonts required for PDF rendering could not be loaded.
+    FontLoad(String),
+
+    /// The document could not be written to the target path.
+    Write(String),
+
+    /// The process may not write to the contained path, for example on a
+    /// locked shared drive or a read-only volume.
+    PermissionDenied(String),
+
+    /// The document was written but failed the integrity check.
+    Unverified(String),
+
+    /// The exporter panicked; contains the panic message.
+    Panicked(String),
+}
+
+impl fmt::Display for ExportError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            ExportError::FontLoad(reason) => write!(f, "Failed to load font: {}", reason),
+            ExportError::Write(reason) => write!(f, "Failed to write file: {}", reason),
+            ExportError::PermissionDenied(path) => write!(f, "No permission to write to: {}", path),
+            ExportError::Unverified(path) => write!(f, "The written file failed the integrity check: {}", path),
+            ExportError::Panicked(message) => write!(f, "Unexpected error while exporting: {}", message),
+        }
+    }
+}
+
+impl ExportError
+{
+    // pub fn from_io(error: &io::Error, path: &Path) -> Self
+    /// Describes a failure to write `path`. A missing permission and a
+    /// read-only volume are told apart from other write failures, so that
+    /// the user learns to pick another place rather than to retry.
+    ///
+    /// # Arguments
+    /// * `error` - The error of the failed file operation.
+    /// * `path` - The target file path.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io;
+    /// use std::path::Path;
+    /// use qrate_gui::ExportError;
+    ///
+    /// let path = Path::new("/mnt/shared/exam.pdf");
+    /// let denied = io::Error::from(io::ErrorKind::PermissionDenied);
+    /// assert_eq!(ExportError::from_io(&denied, path), ExportError::PermissionDenied("/mnt/shared/exam.pdf".to_string()));
+    /// let read_only = io::Error::from(io::ErrorKind::ReadOnlyFilesystem);
+    /// assert_eq!(ExportError::from_io(&read_only, path), ExportError::PermissionDenied("/mnt/shared/exam.pdf".to_string()));
+    /// let full = io::Error::from(io::ErrorKind::StorageFull);
+    /// assert!(matches!(ExportError::from_io(&full, path), ExportError::Write(_)));
+    /// ```
+    pub fn from_io(error: &io::Error, path: &Path) -> Self
+    {
+        match error.kind()
+        {
+            io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => ExportError::PermissionDenied(path.display().to_string()),
+            _ => ExportError::Write(error.to_string()),
+        }
+    }
+}
+
+// pub fn check_writable(path: &Path) -> Result<(), ExportError>
+/// Checks that `path` can be written before an export starts, so that a
+/// locked target is reported as such instead of as a failure deep inside
+/// a PDF or Excel writer. An existing file is left unchanged; a file
+/// created by the check is removed again.
+///
+/// # Arguments
+/// * `path` - The target file path.
+///
+/// # Output
+/// `Ok(())` if the file can be written, `ExportError::PermissionDenied` if
+/// the process lacks the permission or the volume is read-only, or
+/// `ExportError::Write` for any other failure.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ check_writable, ExportError };
+///
+/// let path = std::env::temp_dir().join("qrate_gui_check_writable.pdf");
+/// assert_eq!(check_writable(&path), Ok(()));
+/// assert!(!path.exists());
+///
+/// // A directory cannot be written as a file.
+/// assert!(check_writable(&std::env::temp_dir()).is_err());
+/// ```
+pub fn check_writable(path: &Path) -> Result<(), ExportError>
+{
+    let existed = path.exists();
+    fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+        .map_err(|e| ExportError::from_io(&e, path))?;
+    if !existed
+        { let _ = fs::remove_file(path); }
+    Ok(())
+}
+
+/// The file formats an `Exam` can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat
+{
+    /// A PDF document rendered with the fonts in `./fonts`.
+    Pdf,
+
+    /// An Excel workbook with one line of the exam per row.
+    Excel,
+
+    /// A Markdown document for wikis and learning management systems.
+    Markdown,
+
+    /// A Word document that can be edited before printing.
+    Docx,
+}
+
+impl ExportFormat
+{
+    // pub fn get_extension(&self) -> &'static str
+    /// Returns the file extension used for this format.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExportFormat;
+    /// assert_eq!(ExportFormat::Pdf.get_extension(), "pdf");
+    /// assert_eq!(ExportFormat::Excel.get_extension(), "xlsx");
+    /// assert_eq!(ExportFormat::Markdown.get_extension(), "md");
+    /// assert_eq!(ExportFormat::Docx.get_extension(), "docx");
+    /// ```
+    pub fn get_extension(&self) -> &'static str
+    {
+        match self
+        {
+            ExportFormat::Pdf => "pdf",
+            ExportFormat::Excel => "xlsx",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Docx => "docx",
+        }
+    }
+
+    // pub fn can_count_questions(&self) -> bool
+    /// Returns whether `verify_export` counts the questions of a file in
+    /// this format. A PDF stores its text as font glyphs, so only its
+    /// pages are checked.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ExportFormat;
+    /// assert!(!ExportFormat::Pdf.can_count_questions());
+    /// assert!(ExportFormat::Excel.can_count_questions());
+    /// assert!(ExportFormat::Markdown.can_count_questions());
+    /// assert!(ExportFormat::Docx.can_count_questions());
+    /// ```
+    pub fn can_count_questions(&self) -> bool
+    {
+        *self != ExportFormat::Pdf
+    }
+}
+
+// pub fn export_exam(exam: &Exam, path: &Path, format: ExportFormat) -> Result<(), ExportError>
+/// Exports `exam` to `path` in the given `format`.
+///
+/// # Arguments
+/// * `exam` - The exam to export.
+/// * `path` - The target file path.
+/// * `format` - The output format.
+///
+/// # Output
+/// `Ok(())` on success, or an `ExportError` describing the failure. A
+/// target the process may not write is reported as
+/// `ExportError::PermissionDenied` before anything is rendered.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, Question };
+/// use qrate_gui::{ export_exam, Exam, ExportFormat, NumberingStyle };
+///
+/// let exam = Exam::new(Header::new_with_default(),
+///                      vec![Question::new(1, 1, 1, "Q1".to_string(), vec![])],
+///                      NumberingStyle::Roman);
+/// let path = std::env::temp_dir().join("qrate_gui_export_exam.xlsx");
+/// assert!(export_exam(&exam, &path, ExportFormat::Excel).is_ok());
+/// assert!(path.exists());
+/// ```
+pub fn export_exam(exam: &Exam, path: &Path, format: ExportFormat) -> Result<(), ExportError>
+{
+    check_writable(path)?;
+    match format
+    {
+        ExportFormat::Pdf => export_exam_to_pdf(exam, path),
+        ExportFormat::Excel => export_exam_to_excel(exam, path),
+        ExportFormat::Markdown => export_exam_to_markdown(exam, path),
+        ExportFormat::Docx => export_exam_to_docx(exam, path),
+    }
+}
+
+// pub fn export_exam_to_pdf(exam: &Exam, path: &Path) -> Result<(), ExportError>
+/// Renders `exam` as a PDF document.
+///
+/// The fonts `font-Regular.ttf`, `font-Bold.ttf`, `font-Italic.ttf` and
+/// `font-BoldItalic.ttf` must exist in the `./fonts` directory.
+/// Attached images that are missing or cannot be decoded are replaced by a
+/// placeholder line instead of failing the export. The answer choices are
+/// printed `Exam::get_choices_per_row` to a line, and each question is
+/// followed by the blank lines of `Exam::get_answer_space`. A paper with a
+/// `PaperCode` carries it as a QR code in the top right corner. The
+/// header and footer of `Exam::get_page_template` are printed on every
+/// page, with their placeholders filled in, on the paper size and inside
+/// the margin of the template. Unless `Exam::get_answer_key` leaves it
+/// out, the answer key is printed on a separate last page.
+///
+/// # Arguments
+/// * `exam` - The exam to export.
+/// * `path` - The target file path.
+///
+/// # Output
+/// `Ok(())` on success, or an `ExportError` describing the failure.
+///
+/// # Examples
+/// ```no_run
+/// use std::path::Path;
+/// use qrate::Header;
+/// use qrate_gui::{ export_exam_to_pdf, Exam, NumberingStyle };
+///
+/// let exam = Exam::new(Header::new_with_default(), vec![], NumberingStyle::Arabic);
+/// export_exam_to_pdf(&exam, Path::new("exam.pdf")).unwrap();
+/// ```
+pub fn export_exam_to_pdf(exam: &Exam, path: &Path) -> Result<(), ExportError>
+{
+    let font_family = fonts::from_files(FONT_DIR, FONT_NAME, None)
+                        .map_err(|e| ExportError::FontLoad(e.to_string()))?;
+    // The page count is only known once the document has been laid out, so
+    // a template showing it renders the document twice.
+    let template = exam.get_page_template();
+    let mut total_pages = 0;
+    if template.needs_total_pages()
+    {
+        let mut draft = Vec::new();
+        build_exam_pdf(exam, font_family.clone(), 0)?
+            .render(&mut draft)
+            .map_err(|e| ExportError::Write(e.to_string()))?;
+        total_pages = lopdf::Document::load_mem(&draft).map(|doc| doc.get_pages().len())
+                        .map_err(|e| ExportError::Write(e.to_string()))?;
+    }
+    build_exam_pdf(exam, font_family, total_pages)?
+        .render_to_file(path)
+        .map_err(|e| ExportError::Write(e.to_string()))
+}
+
+// fn build_exam_pdf(exam: &Exam, font_family: fonts::FontFamily<fonts::FontData>, total_pages: usize) -> Result<Document, ExportError>
+/// Lays out `exam` as a PDF document, with `total_pages` standing for
+/// `{total_pages}` in the page header and footer.
+fn build_exam_pdf(exam: &Exam, font_family: fonts::FontFamily<fonts::FontData>, total_pages: usize) -> Result<Document, ExportError>
+{
+    let mut doc = Document::new(font_family);
+    if let Some(ExamLine::Title(title)) = exam.layout().first()
+        { doc.set_title(title.clone()); }
+    doc.set_paper_size(exam.get_page_template().get_paper_size().get_size());
+    doc.set_page_decorator(TemplatePageDecorator::new(exam, total_pages));
+
+    let mut title_style = style::Style::new();
+    title_style.set_font_size(14);
+    title_style.set_bold();
+
+    // The paper code goes in the top right corner, above the title.
+    if let Some(picture) = exam.get_paper_code().and_then(|code| code.to_image())
+    {
+        let picture = elements::Image::from_dynamic_image(picture).map_err(|e| ExportError::Write(e.to_string()))?;
+        doc.push(picture.with_alignment(Alignment::Right));
+    }
+
+    // Consecutive choices are collected and printed together, so that they
+    // can be laid out in columns.
+    let mut choices = Vec::new();
+    for line in exam.layout()
+    {
+        if let ExamLine::Choice { .. } = line
+        {
+            choices.push(line.to_text());
+            continue;
+        }
+        push_choices(&mut doc, &mut choices, exam.get_choices_per_row())?;
+        match line
+        {
+            ExamLine::Title(title) => {
+                let mut paragraph = elements::Paragraph::new(title);
+                paragraph.set_alignment(Alignment::Center);
+                doc.push(paragraph.styled(title_style));
+            },
+            ExamLine::Image(image) => {
+                let decoded = match image.load()
+                {
+                    LoadedImage::Bytes(bytes) => elements::Image::from_reader(Cursor::new(bytes)).ok(),
+                    LoadedImage::Placeholder => None,
+                };
+                match decoded
+                {
+                    Some(picture) => doc.push(picture.with_alignment(Alignment::Center)),
+                    None => doc.push(elements::Paragraph::new(ExamLine::Image(image).to_text())),
+                }
+            },
+            ExamLine::Blank => doc.push(elements::Paragraph::new("")),
+            ExamLine::AnswerSpace(lines) => doc.push(elements::Break::new(lines as f64)),
+            _ => doc.push(elements::Paragraph::new(line.to_text())),
+        }
+    }
+    push_choices(&mut doc, &mut choices, exam.get_choices_per_row())?;
+
+    // The answer key follows on its own page.
+    if exam.get_answer_key()
+    {
+        doc.push(elements::PageBreak::new());
+        doc.push(elements::Paragraph::new(exam.get_label("answer-key")).styled(title_style));
+        for (number, labels) in exam.answer_key()
+            { doc.push(elements::Paragraph::new(format!("{}. {}", number, labels.join(", ")))); }
+    }
+    Ok(doc)
+}
+
+/// Prints the `PageTemplate` of an exam on every page: the logo and the
+/// header at the top, the footer at the bottom, inside the page margins.
+struct TemplatePageDecorator
+{
+    page: usize,
+    total_pages: usize,
+    template: PageTemplate,
+    info: ExamInfo,
+    logo: Option<elements::Image>,
+}
+
+impl TemplatePageDecorator
+{
+    fn new(exam: &Exam, total_pages: usize) -> Self
+    {
+        let template = exam.get_page_template().clone();
+        // A logo that cannot be read is left out rather than failing the export.
+        let logo = template.get_logo()
+                        .and_then(|logo| elements::Image::from_path(logo).ok())
+                        .map(|logo| logo.with_alignment(Alignment::Center));
+        Self { page: 0, total_pages, template, info: exam.get_info().clone(), logo }
+    }
+
+    fn render_lines(context: &Context, area: &mut render::Area<'_>, text: &str, style: style::Style) -> Result<(), genpdf::error::Error>
+    {
+        for line in text.lines()
+        {
+            let mut paragraph = elements::Paragraph::new(line);
+            paragraph.set_alignment(Alignment::Center);
+            let result = paragraph.render(context, area.clone(), style)?;
+            area.add_offset(Position::new(0, result.size.height));
+        }
+        Ok(())
+    }
+}
+
+impl PageDecorator for TemplatePageDecorator
+{
+    fn decorate_page<'a>(&mut self, context: &Context, mut area: render::Area<'a>, style: style::Style) -> Result<render::Area<'a>, genpdf::error::Error>
+    {
+        self.page += 1;
+        area.add_margins(self.template.get_margin());
+
+        // The footer takes as many lines at the bottom as it has.
+        let footer = self.template.render_footer(&self.info, self.page, self.total_pages);
+        let footer_height = style.line_height(&context.font_cache) * footer.lines().count() as f64;
+        let mut footer_area = area.clone();
+        footer_area.add_offset(Position::new(0, area.size().height - footer_height));
+        Self::render_lines(context, &mut footer_area, &footer, style)?;
+        area.set_height(area.size().height - footer_height);
+
+        if let Some(logo) = &self.logo
+        {
+            let result = logo.clone().render(context, area.clone(), style)?;
+            area.add_offset(Position::new(0, result.size.height));
+        }
+        let header = self.template.render_header(&self.info, self.page, self.total_pages);
+        Self::render_lines(context, &mut area, &header, style)?;
+        if !header.is_empty() || self.logo.is_some()
+            { area.add_offset(Position::new(0, style.line_height(&context.font_cache))); }
+        Ok(area)
+    }
+}
+
+// pub fn export_study_guide_to_pdf(qbank: &QBank, template: &PageTemplate, path: &Path) -> Result<(), ExportError>
+/// Renders every question of `qbank` as a PDF study guide, laid out by
+/// `study_guide_layout`: a table of contents, then the questions of each
+/// category with their answers.
+///
+/// The fonts are loaded from `./fonts` as for `export_exam_to_pdf`. The
+/// pages take the paper size and the margin of `template`, but not its
+/// header and footer, which describe an exam.
+///
+/// # Arguments
+/// * `qbank` - The question bank.
+/// * `template` - The page template holding the paper size and margin.
+/// * `path` - The target file path.
+///
+/// # Output
+/// `Ok(())` on success, or an `ExportError` describing the failure.
+///
+/// # Examples
+/// ```no_run
+/// use std::path::Path;
+/// use qrate::QBank;
+/// use qrate_gui::{ export_study_guide_to_pdf, PageTemplate };
+/// export_study_guide_to_pdf(&QBank::new_with_default(), &PageTemplate::new(), Path::new("study-guide.pdf")).unwrap();
+/// ```
+pub fn export_study_guide_to_pdf(qbank: &QBank, template: &PageTemplate, path: &Path) -> Result<(), ExportError>
+{
+    let font_family = fonts::from_files(FONT_DIR, FONT_NAME, None)
+                        .map_err(|e| ExportError::FontLoad(e.to_string()))?;
+    let mut doc = Document::new(font_family);
+    doc.set_title(qbank.get_header().get_title().clone());
+    doc.set_paper_size(template.get_paper_size().get_size());
+    let mut decorator = SimplePageDecorator::new();
+    decorator.set_margins(template.get_margin());
+    doc.set_page_decorator(decorator);
+
+    let mut title_style = style::Style::new();
+    title_style.set_font_size(14);
+    title_style.set_bold();
+    let mut heading_style = style::Style::new();
+    heading_style.set_bold();
+
+    for line in study_guide_layout(qbank)
+    {
+        match &line
+        {
+            StudyGuideLine::Title(title) => {
+                let mut paragraph = elements::Paragraph::new(title);
+                paragraph.set_alignment(Alignment::Center);
+                doc.push(paragraph.styled(title_style));
+            },
+            StudyGuideLine::ContentsHeading => doc.push(elements::Paragraph::new(line.to_text()).styled(heading_style)),
+            // Every category starts on its own page.
+            StudyGuideLine::Category(_) => {
+                doc.push(elements::PageBreak::new());
+                doc.push(elements::Paragraph::new(line.to_text()).styled(title_style));
+            },
+            StudyGuideLine::Answer(_) => {
+                let mut answer_style = style::Style::new();
+                answer_style.set_italic();
+                doc.push(elements::Paragraph::new(line.to_text()).styled(answer_style));
+            },
+            _ => doc.push(elements::Paragraph::new(line.to_text())),
+        }
+    }
+    doc.render_to_file(path).map_err(|e| ExportError::Write(e.to_string()))
+}
+
+// fn push_choices(doc: &mut Document, choices: &mut Vec<String>, per_row: usize) -> Result<(), ExportError>
+/// Prints the collected `choices` of one question, `per_row` to a line, and
+/// empties the collection. The last line is padded with empty cells.
+fn push_choices(doc: &mut Document, choices: &mut Vec<String>, per_row: usize) -> Result<(), ExportError>
+{
+    if per_row <= 1
+    {
+        for choice in choices.drain(..)
+            { doc.push(elements::Paragraph::new(choice)); }
+        return Ok(());
+    }
+    if choices.is_empty()
+        { return Ok(()); }
+    let mut table = elements::TableLayout::new(vec![1; per_row]);
+    for row in arrange_in_rows(choices, per_row)
+    {
+        let mut cells: Vec<Box<dyn Element>> = row.into_iter()
+                                                .map(|choice| Box::new(elements::Paragraph::new(choice)) as Box<dyn Element>)
+                                                .collect();
+        while cells.len() < per_row
+            { cells.push(Box::new(elements::Paragraph::new(""))); }
+        table.push_row(cells).map_err(|e| ExportError::Write(e.to_string()))?;
+    }
+    doc.push(table);
+    choices.clear();
+    Ok(())
+}
+
+// pub fn export_exam_to_excel(exam: &Exam, path: &Path) -> Result<(), ExportError>
+/// Writes `exam` to an Excel workbook.
+///
+/// The title and the header details occupy one row each in the first column.
+/// Each question occupies one row with its number in the first column and its
+/// text in the second; each choice occupies a row with its label in the second
+/// column and its text in the third. A second worksheet holds the answer
+/// key unless `Exam::get_answer_key` leaves it out.
+///
+/// # Arguments
+/// * `exam` - The exam to export.
+/// * `path` - The target file path.
+///
+/// # Output
+/// `Ok(())` on success, or an `ExportError` describing the failure.
+///
+/// # Examples
+/// ```
+/// use qrate::Header;
+/// use qrate_gui::{ export_exam_to_excel, Exam, NumberingStyle };
+///
+/// let exam = Exam::new(Header::new_with_default(), vec![], NumberingStyle::Arabic);
+/// let path = std::env::temp_dir().join("qrate_gui_export_exam_to_excel.xlsx");
+/// assert!(export_exam_to_excel(&exam, &path).is_ok());
+///
+/// // The header details are written out.
+/// use calamine::{ Reader, open_workbook_auto };
+/// use qrate_gui::ExamInfo;
+/// let mut exam = exam;
+/// let mut info = ExamInfo::new();
+/// info.set_title("Midterm".to_string());
+/// info.set_instructions("Answer all questions.".to_string());
+/// exam.set_info(info);
+/// let path = std::env::temp_dir().join("qrate_gui_export_exam_info.xlsx");
+/// export_exam_to_excel(&exam, &path).unwrap();
+/// let mut workbook = open_workbook_auto(&path).unwrap();
+/// let range = workbook.worksheet_range_at(0).unwrap().unwrap();
+/// assert_eq!(range.get_value((0, 0)).unwrap().to_string(), "Midterm");
+/// assert_eq!(range.get_value((1, 0)).unwrap().to_string(), "Answer all questions.");
+///
+/// // The labels follow the locale of the exam rather than the interface.
+/// let mut info = ExamInfo::new();
+/// info.set_course("Math 101".to_string());
+/// exam.set_info(info);
+/// exam.set_locale(Some("ko".to_string()));
+/// let path = std::env::temp_dir().join("qrate_gui_export_exam_ko.xlsx");
+/// export_exam_to_excel(&exam, &path).unwrap();
+/// let mut workbook = open_workbook_auto(&path).unwrap();
+/// assert_eq!(workbook.sheet_names()[1], "정답표");
+/// let range = workbook.worksheet_range_at(0).unwrap().unwrap();
+/// assert_eq!(range.get_value((1, 0)).unwrap().to_string(), "과목: Math 101");
+/// ```
+pub fn export_exam_to_excel(exam: &Exam, path: &Path) -> Result<(), ExportError>
+{
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    for (row, line) in (0..).zip(exam.layout())
+    {
+        let result = match &line
+        {
+            ExamLine::Title(title) => worksheet.write_string(row, 0, title),
+            ExamLine::Field { .. } | ExamLine::Instructions(_) => worksheet.write_string(row, 0, line.to_text()),
+            ExamLine::Question { number, text } => worksheet.write_string(row, 0, number)
+                                                            .and_then(|ws| ws.write_string(row, 1, text)),
+            ExamLine::Choice { label, text } => worksheet.write_string(row, 1, label)
+                                                            .and_then(|ws| ws.write_string(row, 2, text)),
+            ExamLine::Image(_) => worksheet.write_string(row, 1, line.to_text().trim()),
+            ExamLine::Blank | ExamLine::AnswerSpace(_) => Ok(&mut *worksheet),
+        };
+        result.map_err(|e| ExportError::Write(e.to_string()))?;
+    }
+
+    if exam.get_answer_key()
+    {
+        let key_sheet = workbook.add_worksheet();
+        key_sheet.set_name(exam.get_label("answer-key")).map_err(|e| ExportError::Write(e.to_string()))?;
+        for (row, (number, labels)) in (0..).zip(exam.answer_key())
+        {
+            key_sheet.write_string(row, 0, number)
+                .and_then(|ws| ws.write_string(row, 1, labels.join(", ")))
+                .map_err(|e| ExportError::Write(e.to_string()))?;
+        }
+    }
+    workbook.save(path).map_err(|e| ExportError::Write(e.to_string()))
+}
+
+// pub fn verify_export(path: &Path, expected_count: usize) -> bool
+/// Reopens an exported file and checks that it is intact.
+///
+/// An Excel workbook is read back and the question rows of its first
+/// worksheet, the rows with both a number and a text, are counted against
+/// `expected_count`. A Markdown document is read back and the numbered
+/// question lines are counted the same way. A Word document is read back
+/// and its paragraphs in the question style are counted. The text of a PDF
+/// is stored as font glyphs and cannot be counted, so a PDF only has to
+/// parse and contain at least one page, and `expected_count` is ignored;
+/// see `ExportFormat::can_count_questions`.
+///
+/// # Arguments
+/// * `path` - The exported file; the format is taken from its extension.
+/// * `expected_count` - The number of questions the exam contains.
+///
+/// # Output
+/// `true` if the file could be reopened and matches, otherwise `false`.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, Question };
+/// use qrate_gui::{ export_exam_to_excel, verify_export, Exam, NumberingStyle };
+///
+/// let questions = (1..=3).map(|id| Question::new(id, id, 1, format!("Q{}", id), vec![("a".to_string(), true)]))
+///                        .collect();
+/// let exam = Exam::new(Header::new_with_default(), questions, NumberingStyle::Arabic);
+/// let path = std::env::temp_dir().join("qrate_gui_verify_export.xlsx");
+/// export_exam_to_excel(&exam, &path).unwrap();
+/// assert!(verify_export(&path, 3));
+/// assert!(!verify_export(&path, 4));
+///
+/// // A truncated file is rejected.
+/// let bytes = std::fs::read(&path).unwrap();
+/// std::fs::write(&path, &bytes[..bytes.len() / 2]).unwrap();
+/// assert!(!verify_export(&path, 3));
+/// ```
+pub fn verify_export(path: &Path, expected_count: usize) -> bool
+{
+    let extension = path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+    if extension == ExportFormat::Pdf.get_extension()
+        { return lopdf::Document::load(path).is_ok_and(|doc| !doc.get_pages().is_empty()); }
+    if extension == ExportFormat::Docx.get_extension()
+        { return fs::read(path).ok().and_then(|bytes| count_docx_questions(&bytes)) == Some(expected_count); }
+    if extension == ExportFormat::Markdown.get_extension()
+    {
+        return fs::read_to_string(path).is_ok_and(|markdown| {
+            let count = markdown.lines()
+                            .filter(|line| line.split_once(". ").is_some_and(|(number, _)| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())))
+                            .count();
+            count == expected_count
+        });
+    }
+
+    let Ok(mut workbook) = open_workbook_auto(path)
+        else { return false; };
+    match workbook.worksheet_range_at(0)
+    {
+        Some(Ok(range)) => {
+            let count = range.rows()
+                            .filter(|row| row.len() > 1 && !row[0].is_empty() && !row[1].is_empty())
+                            .count();
+            count == expected_count
+        },
+        _ => false,
+    }
+}
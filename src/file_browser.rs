@@ -0,0 +1,549 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fs;
+use std::io;
+use std::path::{ Path, PathBuf };
+
+use serde::{ Serialize, Deserialize };
+
+use crate::control_tower::Message;
+use crate::{ ExportFormat, IMAGE_EXTENSIONS, QUESTION_BANK_EXTENSIONS, ROSTER_EXTENSIONS };
+
+/// Which file dialog is used to open files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FileDialogKind
+{
+    /// The dialog of the operating system.
+    #[default]
+    Native,
+
+    /// The file browser drawn inside the window, for systems whose native
+    /// dialog does not work.
+    InApp,
+}
+
+impl FileDialogKind
+{
+    /// All kinds, in the order they are offered to the user.
+    pub const ALL: [FileDialogKind; 2] = [ FileDialogKind::Native, FileDialogKind::InApp ];
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key of the kind's display name.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::FileDialogKind;
+    /// assert_eq!(FileDialogKind::InApp.get_key(), "file-dialog-in-app");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            FileDialogKind::Native => "file-dialog-native",
+            FileDialogKind::InApp => "file-dialog-in-app",
+        }
+    }
+}
+
+/// What is written where the user picks in a save dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveTarget
+{
+    /// The previewed exam, in the contained format.
+    Exam(ExportFormat),
+
+    /// Exam variants, written into a folder in the contained format.
+    Variants(ExportFormat),
+
+    /// One paper per student, written into a folder in the contained format.
+    StudentPapers(ExportFormat),
+
+    /// The summary of the last batch of variants.
+    GenerationSummary,
+
+    /// The questions of the bank as an Anki deck.
+    AnkiDeck,
+
+    /// The questions of the bank as a study guide.
+    StudyGuide,
+
+    /// The item analysis of the graded attempts.
+    ItemAnalysis,
+
+    /// The statistics of the bank.
+    BankStats,
+
+    /// The exported questions as a new question bank.
+    Questions,
+}
+
+impl SaveTarget
+{
+    // pub fn get_file_name(&self) -> String
+    /// Returns the file name the save dialog suggests, the same as the
+    /// native dialog's, or an empty name when a folder is picked.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExportFormat, SaveTarget };
+    /// assert_eq!(SaveTarget::Exam(ExportFormat::Docx).get_file_name(), "exam.docx");
+    /// assert_eq!(SaveTarget::AnkiDeck.get_file_name(), "anki-deck.txt");
+    /// assert_eq!(SaveTarget::Variants(ExportFormat::Pdf).get_file_name(), "");
+    /// ```
+    pub fn get_file_name(&self) -> String
+    {
+        match self
+        {
+            SaveTarget::Exam(format) => format!("exam.{}", format.get_extension()),
+            SaveTarget::Variants(_) | SaveTarget::StudentPapers(_) => String::new(),
+            SaveTarget::GenerationSummary => "generation-summary.json".to_string(),
+            SaveTarget::AnkiDeck => "anki-deck.txt".to_string(),
+            SaveTarget::StudyGuide => "study-guide.pdf".to_string(),
+            SaveTarget::ItemAnalysis => "item-analysis.xlsx".to_string(),
+            SaveTarget::BankStats => "bank-stats.csv".to_string(),
+            SaveTarget::Questions => "questions.qbdb".to_string(),
+        }
+    }
+
+    // pub fn get_extensions(&self) -> Vec<&'static str>
+    /// Returns the extensions of the files listed next to the folders, the
+    /// same as the filters of the native dialog.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExportFormat, SaveTarget };
+    /// assert_eq!(SaveTarget::Exam(ExportFormat::Excel).get_extensions(), ["xlsx"]);
+    /// assert_eq!(SaveTarget::BankStats.get_extensions(), ["csv", "json"]);
+    /// assert!(SaveTarget::StudentPapers(ExportFormat::Pdf).get_extensions().is_empty());
+    /// ```
+    pub fn get_extensions(&self) -> Vec<&'static str>
+    {
+        match self
+        {
+            SaveTarget::Exam(format) => vec![format.get_extension()],
+            SaveTarget::Variants(_) | SaveTarget::StudentPapers(_) => Vec::new(),
+            SaveTarget::GenerationSummary => vec!["json"],
+            SaveTarget::AnkiDeck => vec!["txt"],
+            SaveTarget::StudyGuide => vec!["pdf"],
+            SaveTarget::ItemAnalysis => vec!["xlsx"],
+            SaveTarget::BankStats => vec!["csv", "json"],
+            SaveTarget::Questions => QUESTION_BANK_EXTENSIONS.to_vec(),
+        }
+    }
+
+    // pub fn picks_folder(&self) -> bool
+    /// Returns whether a folder is picked rather than a file name.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExportFormat, SaveTarget };
+    /// assert!(SaveTarget::Variants(ExportFormat::Pdf).picks_folder());
+    /// assert!(!SaveTarget::Questions.picks_folder());
+    /// ```
+    pub fn picks_folder(&self) -> bool
+    {
+        matches!(self, SaveTarget::Variants(_) | SaveTarget::StudentPapers(_))
+    }
+}
+
+/// What a file is being picked for, which decides the extensions shown and
+/// the message the picked file is reported with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowsePurpose
+{
+    /// A question bank to load.
+    QuestionBank,
+
+    /// A roster to import.
+    Roster,
+
+    /// An image to attach to the question with the contained id.
+    QuestionImage(u16),
+
+    /// The folder question banks are kept in, picked as a whole.
+    StorageFolder,
+
+    /// Where to write the contained target, in place of a save dialog.
+    Save(SaveTarget),
+}
+
+impl BrowsePurpose
+{
+    // pub fn get_extensions(&self) -> Vec<&'static str>
+    /// Returns the extensions of the files that can be picked, the same as
+    /// those of the native dialog.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::BrowsePurpose;
+    /// assert_eq!(BrowsePurpose::QuestionBank.get_extensions(), ["qbdb", "xlsx"]);
+    /// assert_eq!(BrowsePurpose::Roster.get_extensions(), ["txt"]);
+    /// assert!(BrowsePurpose::StorageFolder.get_extensions().is_empty());
+    /// ```
+    pub fn get_extensions(&self) -> Vec<&'static str>
+    {
+        match self
+        {
+            BrowsePurpose::QuestionBank => QUESTION_BANK_EXTENSIONS.to_vec(),
+            BrowsePurpose::Roster => ROSTER_EXTENSIONS.to_vec(),
+            BrowsePurpose::QuestionImage(_) => IMAGE_EXTENSIONS.to_vec(),
+            BrowsePurpose::StorageFolder => Vec::new(),
+            BrowsePurpose::Save(target) => target.get_extensions(),
+        }
+    }
+
+    // pub fn to_message(&self, path: PathBuf) -> Message
+    /// Returns the message the native dialog would report `path` with.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::{ BrowsePurpose, Message };
+    /// let path = PathBuf::from("/images/triangle.png");
+    /// assert!(matches!(BrowsePurpose::QuestionImage(3).to_message(path), Message::QuestionImagePicked(3, _)));
+    /// ```
+    pub fn to_message(&self, path: PathBuf) -> Message
+    {
+        match self
+        {
+            BrowsePurpose::QuestionBank => Message::FileSelected(path),
+            BrowsePurpose::Roster => Message::RosterFileSelected(path),
+            BrowsePurpose::QuestionImage(id) => Message::QuestionImagePicked(*id, path),
+            BrowsePurpose::StorageFolder => Message::StoragePathChosen(Some(path)),
+            BrowsePurpose::Save(target) => Message::SavePathChosen(*target, path),
+        }
+    }
+
+    // pub fn picks_folder(&self) -> bool
+    /// Returns whether a folder is picked rather than a file.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::BrowsePurpose;
+    /// assert!(BrowsePurpose::StorageFolder.picks_folder());
+    /// assert!(!BrowsePurpose::QuestionBank.picks_folder());
+    /// ```
+    pub fn picks_folder(&self) -> bool
+    {
+        match self
+        {
+            BrowsePurpose::StorageFolder => true,
+            BrowsePurpose::Save(target) => target.picks_folder(),
+            _ => false,
+        }
+    }
+}
+
+/// One line of the in-app file browser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrowserEntry
+{
+    name: String,
+    path: PathBuf,
+    is_dir: bool,
+}
+
+impl BrowserEntry
+{
+    // pub fn get_name(&self) -> &str
+    /// Returns the file name of the entry.
+    ///
+    /// # Examples
+    /// See `list_directory`.
+    pub fn get_name(&self) -> &str
+    {
+        &self.name
+    }
+
+    // pub fn get_path(&self) -> &Path
+    /// Returns the full path of the entry.
+    ///
+    /// # Examples
+    /// See `list_directory`.
+    pub fn get_path(&self) -> &Path
+    {
+        &self.path
+    }
+
+    // pub fn is_dir(&self) -> bool
+    /// Returns whether the entry is a directory to browse into.
+    ///
+    /// # Examples
+    /// See `list_directory`.
+    pub fn is_dir(&self) -> bool
+    {
+        self.is_dir
+    }
+}
+
+// pub fn list_directory(directory: &Path, extensions: &[&str]) -> io::Result<Vec<BrowserEntry>>
+/// Lists what the in-app file browser shows of `directory`: its
+/// subdirectories, then its files with one of `extensions`, each group
+/// sorted by name. Hidden entries, whose names start with a dot, are left
+/// out.
+///
+/// # Arguments
+/// * `directory` - The directory to list.
+/// * `extensions` - The extensions of the files to show, compared without
+///   regard to case.
+///
+/// # Output
+/// The entries, or the error of reading the directory.
+///
+/// # Examples
+/// ```
+/// use std::fs;
+/// use qrate_gui::list_directory;
+///
+/// let dir = std::env::temp_dir().join("qrate_gui_list_directory");
+/// let _ = fs::remove_dir_all(&dir);
+/// fs::create_dir_all(dir.join("physics")).unwrap();
+/// fs::create_dir_all(dir.join("chemistry")).unwrap();
+/// fs::create_dir_all(dir.join(".cache")).unwrap();
+/// for name in ["math.qbdb", "Algebra.XLSX", "notes.txt", "archive.qbdb.bak", ".hidden.qbdb", "qbdb"]
+///     { fs::write(dir.join(name), b"").unwrap(); }
+///
+/// let entries = list_directory(&dir, &["qbdb", "xlsx"]).unwrap();
+/// let names: Vec<&str> = entries.iter().map(|entry| entry.get_name()).collect();
+/// assert_eq!(names, ["chemistry", "physics", "Algebra.XLSX", "math.qbdb"]);
+/// assert!(entries[0].is_dir() && entries[1].is_dir());
+/// assert!(!entries[2].is_dir() && !entries[3].is_dir());
+/// assert_eq!(entries[3].get_path(), dir.join("math.qbdb"));
+///
+/// // Directories are always shown, whatever the extensions.
+/// let entries = list_directory(&dir, &["txt"]).unwrap();
+/// let names: Vec<&str> = entries.iter().map(|entry| entry.get_name()).collect();
+/// assert_eq!(names, ["chemistry", "physics", "notes.txt"]);
+///
+/// assert!(list_directory(&dir.join("missing"), &["txt"]).is_err());
+/// fs::remove_dir_all(&dir).unwrap();
+/// ```
+pub fn list_directory(directory: &Path, extensions: &[&str]) -> io::Result<Vec<BrowserEntry>>
+{
+    let mut dirs = Vec::new();
+    let mut files = Vec::new();
+    for entry in fs::read_dir(directory)?.flatten()
+    {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.')
+            { continue; }
+        let path = entry.path();
+        if path.is_dir()
+            { dirs.push(BrowserEntry { name, path, is_dir: true }); }
+        else if path.extension().and_then(|extension| extension.to_str())
+                    .is_some_and(|extension| extensions.iter().any(|wanted| wanted.eq_ignore_ascii_case(extension)))
+            { files.push(BrowserEntry { name, path, is_dir: false }); }
+    }
+    dirs.sort_by(|a, b| a.name.cmp(&b.name));
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    dirs.append(&mut files);
+    Ok(dirs)
+}
+
+/// The state of the in-app file browser while it is open.
+#[derive(Debug, Clone)]
+pub struct FileBrowser
+{
+    purpose: BrowsePurpose,
+    directory: PathBuf,
+    entries: Vec<BrowserEntry>,
+    readable: bool,
+    file_name: String,
+}
+
+impl FileBrowser
+{
+    // pub fn new(purpose: BrowsePurpose, start: &Path) -> Self
+    /// Opens the browser in `start`.
+    ///
+    /// # Arguments
+    /// * `purpose` - What the file is picked for.
+    /// * `start` - The directory shown first.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::fs;
+    /// use qrate_gui::{ BrowsePurpose, FileBrowser };
+    ///
+    /// let dir = std::env::temp_dir().join("qrate_gui_file_browser");
+    /// let _ = fs::remove_dir_all(&dir);
+    /// fs::create_dir_all(dir.join("banks")).unwrap();
+    /// fs::write(dir.join("banks").join("math.qbdb"), b"").unwrap();
+    ///
+    /// let mut browser = FileBrowser::new(BrowsePurpose::QuestionBank, &dir);
+    /// assert_eq!(browser.get_purpose(), BrowsePurpose::QuestionBank);
+    /// assert_eq!(browser.get_entries()[0].get_name(), "banks");
+    /// assert!(browser.has_parent());
+    ///
+    /// browser.open_directory(&dir.join("banks"));
+    /// assert_eq!(browser.get_entries()[0].get_name(), "math.qbdb");
+    ///
+    /// browser.open_parent();
+    /// assert_eq!(browser.get_directory(), dir.canonicalize().unwrap());
+    ///
+    /// browser.open_directory(&dir.join("missing"));
+    /// assert!(!browser.is_readable());
+    /// assert!(browser.get_entries().is_empty());
+    /// fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn new(purpose: BrowsePurpose, start: &Path) -> Self
+    {
+        let file_name = match purpose
+        {
+            BrowsePurpose::Save(target) => target.get_file_name(),
+            _ => String::new(),
+        };
+        let mut browser = FileBrowser { purpose, directory: PathBuf::new(), entries: Vec::new(), readable: true, file_name };
+        browser.open_directory(start);
+        browser
+    }
+
+    // pub fn open_directory(&mut self, directory: &Path)
+    /// Shows the contents of `directory`.
+    ///
+    /// # Arguments
+    /// * `directory` - The directory to show; relative paths are made
+    ///   absolute so that the parent can be browsed.
+    ///
+    /// # Examples
+    /// See `FileBrowser::new`.
+    pub fn open_directory(&mut self, directory: &Path)
+    {
+        self.directory = directory.canonicalize().unwrap_or_else(|_| directory.to_path_buf());
+        match list_directory(&self.directory, &self.purpose.get_extensions())
+        {
+            Ok(entries) => { self.entries = entries; self.readable = true; },
+            Err(_) => { self.entries.clear(); self.readable = false; },
+        }
+    }
+
+    // pub fn open_parent(&mut self)
+    /// Shows the parent of the current directory, if there is one.
+    ///
+    /// # Examples
+    /// See `FileBrowser::new`.
+    pub fn open_parent(&mut self)
+    {
+        if let Some(parent) = self.directory.parent().map(Path::to_path_buf)
+            { self.open_directory(&parent); }
+    }
+
+    // pub fn get_purpose(&self) -> BrowsePurpose
+    /// Returns what the file is picked for.
+    ///
+    /// # Examples
+    /// See `FileBrowser::new`.
+    pub fn get_purpose(&self) -> BrowsePurpose
+    {
+        self.purpose
+    }
+
+    // pub fn get_directory(&self) -> &Path
+    /// Returns the directory shown.
+    ///
+    /// # Examples
+    /// See `FileBrowser::new`.
+    pub fn get_directory(&self) -> &Path
+    {
+        &self.directory
+    }
+
+    // pub fn get_entries(&self) -> &[BrowserEntry]
+    /// Returns the subdirectories and matching files of the directory shown.
+    ///
+    /// # Examples
+    /// See `FileBrowser::new`.
+    pub fn get_entries(&self) -> &[BrowserEntry]
+    {
+        &self.entries
+    }
+
+    // pub fn has_parent(&self) -> bool
+    /// Returns whether the directory shown has a parent to go up to.
+    ///
+    /// # Examples
+    /// See `FileBrowser::new`.
+    pub fn has_parent(&self) -> bool
+    {
+        self.directory.parent().is_some()
+    }
+
+    // pub fn is_readable(&self) -> bool
+    /// Returns whether the directory shown could be read.
+    ///
+    /// # Examples
+    /// See `FileBrowser::new`.
+    pub fn is_readable(&self) -> bool
+    {
+        self.readable
+    }
+
+    // pub fn is_saving(&self) -> bool
+    /// Returns whether a file name is typed in, in place of a save dialog,
+    /// rather than an existing file picked.
+    ///
+    /// # Examples
+    /// See `FileBrowser::set_file_name`.
+    pub fn is_saving(&self) -> bool
+    {
+        matches!(self.purpose, BrowsePurpose::Save(target) if !target.picks_folder())
+    }
+
+    // pub fn get_file_name(&self) -> &str
+    /// Returns the file name typed in while saving.
+    ///
+    /// # Examples
+    /// See `FileBrowser::set_file_name`.
+    pub fn get_file_name(&self) -> &str
+    {
+        &self.file_name
+    }
+
+    // pub fn set_file_name(&mut self, file_name: String)
+    /// Sets the file name written to while saving.
+    ///
+    /// # Arguments
+    /// * `file_name` - The name typed in or that of a listed file.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::Path;
+    /// use qrate_gui::{ BrowsePurpose, FileBrowser, SaveTarget };
+    ///
+    /// let dir = std::env::temp_dir();
+    /// let mut browser = FileBrowser::new(BrowsePurpose::Save(SaveTarget::AnkiDeck), &dir);
+    /// assert!(browser.is_saving());
+    /// assert_eq!(browser.get_file_name(), "anki-deck.txt");
+    ///
+    /// browser.set_file_name("biology.txt".to_string());
+    /// assert_eq!(browser.get_save_path(), Some(dir.canonicalize().unwrap().join("biology.txt")));
+    /// browser.set_file_name("  ".to_string());
+    /// assert_eq!(browser.get_save_path(), None);
+    ///
+    /// assert!(!FileBrowser::new(BrowsePurpose::QuestionBank, &dir).is_saving());
+    /// ```
+    pub fn set_file_name(&mut self, file_name: String)
+    {
+        self.file_name = file_name;
+    }
+
+    // pub fn get_save_path(&self) -> Option<PathBuf>
+    /// Returns the path the typed file name is written to in the directory
+    /// shown, or `None` if no name is typed in.
+    ///
+    /// # Examples
+    /// See `FileBrowser::set_file_name`.
+    pub fn get_save_path(&self) -> Option<PathBuf>
+    {
+        let name = self.file_name.trim();
+        (!name.is_empty()).then(|| self.directory.join(name))
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::io;
+use std::path::{ Path, PathBuf };
+use std::process::Command;
+
+// pub fn get_containing_folder(path: &Path) -> Option<PathBuf>
+/// Returns the folder that contains the file at `path`.
+///
+/// # Arguments
+/// * `path` - The path of a file.
+///
+/// # Output
+/// The parent directory, `.` for a bare file name, or `None` if `path` is
+/// empty.
+///
+/// # Examples
+/// ```
+/// use std::path::{ Path, PathBuf };
+/// use qrate_gui::get_containing_folder;
+/// assert_eq!(get_containing_folder(Path::new("/banks/math.qbdb")), Some(PathBuf::from("/banks")));
+/// assert_eq!(get_containing_folder(Path::new("math.qbdb")), Some(PathBuf::from(".")));
+/// assert_eq!(get_containing_folder(Path::new("")), None);
+/// ```
+pub fn get_containing_folder(path: &Path) -> Option<PathBuf>
+{
+    if path.as_os_str().is_empty()
+        { return None; }
+    match path.parent()
+    {
+        Some(parent) if !parent.as_os_str().is_empty() => Some(parent.to_path_buf()),
+        _ => Some(PathBuf::from(".")),
+    }
+}
+
+// pub fn open_containing_folder(path: &Path) -> io::Result<()>
+/// Opens the folder that contains the file at `path` in the file manager of
+/// the operating system.
+///
+/// # Arguments
+/// * `path` - The path of a file.
+///
+/// # Output
+/// `Ok(())` if the file manager was launched, or the error that prevented it.
+/// An empty `path` is reported as `io::ErrorKind::NotFound`.
+///
+/// # Examples
+/// ```no_run
+/// use std::path::Path;
+/// use qrate_gui::open_containing_folder;
+/// open_containing_folder(Path::new("/banks/math.qbdb")).unwrap();
+/// ```
+pub fn open_containing_folder(path: &Path) -> io::Result<()>
+{
+    let folder = get_containing_folder(path)
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no file is selected"))?;
+    let program = if cfg!(target_os = "windows")
+        { "explorer" }
+    else if cfg!(target_os = "macos")
+        { "open" }
+    else
+        { "xdg-open" };
+    Command::new(program).arg(folder).spawn().map(|_| ())
+}
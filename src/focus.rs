@@ -0,0 +1,155 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use iced::{ Background, Border, Color };
+use iced::widget::button;
+
+/// The width of the focus ring in logical pixels.
+pub const FOCUS_RING_WIDTH: f32 = 3.0;
+
+// pub fn get_focus_ring_color(fill: Color) -> Color
+/// Returns a focus ring color that stands out against `fill`.
+///
+/// The ring is drawn along the inside edge of a button, over its fill, so it
+/// only has to contrast with that fill: a dark blue on light fills and a
+/// bright yellow on dark or high-contrast black fills.
+///
+/// # Arguments
+/// * `fill` - The background color of the focused button.
+///
+/// # Examples
+/// ```
+/// use iced::Color;
+/// use qrate_gui::get_focus_ring_color;
+/// assert_ne!(get_focus_ring_color(Color::WHITE), Color::WHITE);
+/// assert_ne!(get_focus_ring_color(Color::BLACK), Color::BLACK);
+/// assert_ne!(get_focus_ring_color(Color::WHITE), get_focus_ring_color(Color::BLACK));
+/// ```
+pub fn get_focus_ring_color(fill: Color) -> Color
+{
+    let luminance = 0.2126 * fill.r + 0.7152 * fill.g + 0.0722 * fill.b;
+    if luminance > 0.5
+        { Color::from_rgb(0.0, 0.31, 0.71) }
+    else
+        { Color::from_rgb(1.0, 0.83, 0.0) }
+}
+
+// pub fn menu_button_style(status: button::Status, focused: bool) -> button::Style
+/// Returns the style of a menu or submenu button.
+///
+/// The button is white with black text, darkens while hovered or pressed
+/// and greys out its text while disabled. When it holds the keyboard focus,
+/// a ring in `get_focus_ring_color` is drawn around it.
+///
+/// # Arguments
+/// * `status` - The interaction status reported by the button.
+/// * `focused` - Whether the button holds the keyboard focus.
+///
+/// # Examples
+/// ```
+/// use iced::Color;
+/// use iced::widget::button;
+/// use qrate_gui::{ get_focus_ring_color, menu_button_style, FOCUS_RING_WIDTH };
+///
+/// let focused = menu_button_style(button::Status::Active, true);
+/// assert_eq!(focused.border.width, FOCUS_RING_WIDTH);
+/// assert_eq!(focused.border.color, get_focus_ring_color(Color::WHITE));
+///
+/// // The ring stays while the focused button is hovered.
+/// assert_eq!(menu_button_style(button::Status::Hovered, true).border.width, FOCUS_RING_WIDTH);
+///
+/// assert_eq!(menu_button_style(button::Status::Active, false).border.width, 0.0);
+/// assert_ne!(menu_button_style(button::Status::Disabled, false).text_color, Color::BLACK);
+/// ```
+pub fn menu_button_style(status: button::Status, focused: bool) -> button::Style
+{
+    let fill = match status
+    {
+        button::Status::Hovered => Color::from_rgb(0.9, 0.9, 0.9),
+        button::Status::Pressed => Color::from_rgb(0.8, 0.8, 0.8),
+        _ => Color::WHITE,
+    };
+    let border = if focused
+        { Border { color: get_focus_ring_color(fill), width: FOCUS_RING_WIDTH, radius: 2.0.into() } }
+    else
+        { Border::default() };
+
+    let text_color = if status == button::Status::Disabled
+        { Color::from_rgb(0.6, 0.6, 0.6) }
+    else
+        { Color::BLACK };
+
+    button::Style {
+        background: Some(fill.into()),
+        text_color,
+        border,
+        ..Default::default()
+    }
+}
+
+// pub fn steady_status(status: button::Status, reduce_motion: bool) -> button::Status
+/// Treats a hovered button as an idle one while motion is reduced, so that
+/// moving the pointer across a menu does not make it flicker.
+///
+/// # Arguments
+/// * `status` - The status reported by the button.
+/// * `reduce_motion` - Whether the user asked for reduced motion.
+///
+/// # Examples
+/// ```
+/// use iced::widget::button;
+/// use qrate_gui::{ menu_button_style, steady_status };
+/// assert_eq!(steady_status(button::Status::Hovered, false), button::Status::Hovered);
+/// assert_eq!(steady_status(button::Status::Hovered, true), button::Status::Active);
+/// assert_eq!(steady_status(button::Status::Pressed, true), button::Status::Pressed);
+///
+/// let idle = menu_button_style(button::Status::Active, false);
+/// assert_eq!(menu_button_style(steady_status(button::Status::Hovered, true), false), idle);
+/// ```
+pub fn steady_status(status: button::Status, reduce_motion: bool) -> button::Status
+{
+    match status
+    {
+        button::Status::Hovered if reduce_motion => button::Status::Active,
+        _ => status,
+    }
+}
+
+// pub fn with_focus_ring(style: button::Style, focused: bool) -> button::Style
+/// Adds a focus ring to `style` while the button holds the keyboard focus,
+/// such as a button inside a modal.
+///
+/// # Arguments
+/// * `style` - The style the button would have without the focus.
+/// * `focused` - Whether the button holds the keyboard focus.
+///
+/// # Examples
+/// ```
+/// use iced::Theme;
+/// use iced::widget::button;
+/// use qrate_gui::{ with_focus_ring, FOCUS_RING_WIDTH };
+/// let style = button::primary(&Theme::Light, button::Status::Active);
+/// assert_eq!(with_focus_ring(style, true).border.width, FOCUS_RING_WIDTH);
+/// assert_eq!(with_focus_ring(style, false), style);
+/// ```
+pub fn with_focus_ring(style: button::Style, focused: bool) -> button::Style
+{
+    if !focused
+        { return style; }
+    let fill = match style.background
+    {
+        Some(Background::Color(color)) => color,
+        _ => Color::WHITE,
+    };
+    button::Style {
+        border: Border { color: get_focus_ring_color(fill), width: FOCUS_RING_WIDTH, radius: 2.0.into() },
+        ..style
+    }
+}
@@ -0,0 +1,257 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use iced::Font;
+
+use crate::log_event;
+
+/// Font families covering Hangul, Hanja and Kana, in order of preference.
+const CJK_FAMILIES: [&str; 5] = [
+    "Noto Sans CJK KR",
+    "Noto Sans KR",
+    "Malgun Gothic",
+    "Apple SD Gothic Neo",
+    "NanumGothic",
+];
+
+/// Font families covering Latin and Cyrillic, in order of preference.
+const LATIN_CYRILLIC_FAMILIES: [&str; 4] = [
+    "Noto Sans",
+    "DejaVu Sans",
+    "Segoe UI",
+    "Roboto",
+];
+
+// pub fn fonts_for_locale(locale: &str) -> Vec<Font>
+/// Returns the font fallback chain for `locale`.
+///
+/// The first font is the primary font of the locale. The remaining fonts
+/// cover the scripts of the other supported languages so that mixed-language
+/// text such as English questions with Korean choices still has glyphs.
+/// The chain always ends with `Font::DEFAULT`.
+///
+/// # Arguments
+/// * `locale` - A locale code such as `"en"`, `"ko"` or `"ru-RU"`.
+///
+/// # Output
+/// The fonts to try, in order.
+///
+/// # Examples
+/// ```
+/// use iced::Font;
+/// use qrate_gui::fonts_for_locale;
+///
+/// // Korean starts with a CJK-capable font.
+/// assert_eq!(fonts_for_locale("ko")[0], Font::with_name("Noto Sans CJK KR"));
+/// assert_eq!(fonts_for_locale("ko-KR")[0], Font::with_name("Noto Sans CJK KR"));
+///
+/// // Russian starts with a Cyrillic-capable font but can still show Hangul.
+/// let russian = fonts_for_locale("ru");
+/// assert_eq!(russian[0], Font::with_name("Noto Sans"));
+/// assert!(russian.contains(&Font::with_name("Noto Sans CJK KR")));
+///
+/// assert_eq!(fonts_for_locale("en").last(), Some(&Font::DEFAULT));
+/// ```
+pub fn fonts_for_locale(locale: &str) -> Vec<Font>
+{
+    let language = locale.split(['-', '_']).next().unwrap_or("");
+    let (primary, secondary) = match language
+    {
+        "ko" | "ja" | "zh" => (&CJK_FAMILIES[..], &LATIN_CYRILLIC_FAMILIES[..]),
+        _ => (&LATIN_CYRILLIC_FAMILIES[..], &CJK_FAMILIES[..]),
+    };
+    primary.iter()
+        .chain(secondary)
+        .map(|&family| Font::with_name(family))
+        .chain(std::iter::once(Font::DEFAULT))
+        .collect()
+}
+
+// pub fn primary_font(locale: &str, failed: &[PathBuf]) -> Font
+/// Returns the font `locale` is shown in: the first font of
+/// `fonts_for_locale` whose bundled file did not fail to load.
+///
+/// A failed file is matched to a family by the part of its name before the
+/// first `-`, ignoring case, spaces and punctuation, so that
+/// `NotoSansCJKkr-Regular.otf` stands for `Noto Sans CJK KR`. A failure
+/// that matches no family of the chain changes nothing.
+///
+/// # Arguments
+/// * `locale` - The locale code of the interface.
+/// * `failed` - The bundled font files that failed to load.
+///
+/// # Output
+/// The primary font, or `Font::DEFAULT` if every family of the chain failed.
+///
+/// # Examples
+/// ```
+/// use std::path::PathBuf;
+/// use iced::Font;
+/// use qrate_gui::primary_font;
+///
+/// let failed = vec![PathBuf::from("fonts/NotoSansCJKkr-Regular.otf")];
+/// // Korean moves on to the next CJK font; English keeps its own.
+/// assert_eq!(primary_font("ko", &failed), Font::with_name("Noto Sans KR"));
+/// assert_eq!(primary_font("en", &failed), Font::with_name("Noto Sans"));
+/// assert_eq!(primary_font("en", &[PathBuf::from("fonts/broken.ttf")]), Font::with_name("Noto Sans"));
+/// assert_eq!(primary_font("ru", &[PathBuf::from("fonts/NotoSans-Bold.ttf")]), Font::with_name("DejaVu Sans"));
+/// ```
+pub fn primary_font(locale: &str, failed: &[PathBuf]) -> Font
+{
+    let key = |name: &str| -> String {
+        name.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+    };
+    let failed: Vec<String> = failed.iter()
+                                .filter_map(|path| path.file_stem()?.to_str())
+                                .map(|stem| key(stem.split('-').next().unwrap_or(stem)))
+                                .collect();
+    let families = CJK_FAMILIES.iter().chain(LATIN_CYRILLIC_FAMILIES.iter());
+    fonts_for_locale(locale).into_iter()
+        .find(|font| !families.clone().any(|family| Font::with_name(family) == *font && failed.contains(&key(family))))
+        .unwrap_or(Font::DEFAULT)
+}
+
+// pub fn load_font_files(dir: &Path) -> Vec<Vec<u8>>
+/// Reads every `.ttf` and `.otf` file in `dir` so that it can be registered
+/// with `application(...).font(...)`.
+///
+/// # Arguments
+/// * `dir` - The directory to scan.
+///
+/// # Output
+/// The raw bytes of each font file. A missing directory yields an empty list.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use qrate_gui::load_font_files;
+/// assert!(load_font_files(Path::new("/definitely/not/here")).is_empty());
+/// ```
+pub fn load_font_files(dir: &Path) -> Vec<Vec<u8>>
+{
+    load_fonts(dir).files
+}
+
+/// The bundled fonts read at startup, and the ones that could not be used.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoadedFonts
+{
+    files: Vec<Vec<u8>>,
+    failed: Vec<PathBuf>,
+}
+
+impl LoadedFonts
+{
+    // pub fn get_files(&self) -> &[Vec<u8>]
+    /// Returns the raw bytes of each font that was read successfully.
+    ///
+    /// # Examples
+    /// See `load_fonts`.
+    pub fn get_files(&self) -> &[Vec<u8>]
+    {
+        &self.files
+    }
+
+    // pub fn get_failed(&self) -> &[PathBuf]
+    /// Returns the font files that could not be read or are no fonts.
+    ///
+    /// # Examples
+    /// See `load_fonts`.
+    pub fn get_failed(&self) -> &[PathBuf]
+    {
+        &self.failed
+    }
+
+    // pub fn get_default_font(&self, locale: &str) -> Font
+    /// Returns the font the application starts with.
+    ///
+    /// # Arguments
+    /// * `locale` - The locale code of the interface.
+    ///
+    /// # Output
+    /// The `primary_font` of `locale`, which skips the families whose
+    /// bundled file failed to load, so that text is never left without glyphs.
+    ///
+    /// # Examples
+    /// See `load_fonts`.
+    pub fn get_default_font(&self, locale: &str) -> Font
+    {
+        primary_font(locale, &self.failed)
+    }
+}
+
+// pub fn load_fonts(dir: &Path) -> LoadedFonts
+/// Reads every `.ttf` and `.otf` file in `dir` like `load_font_files`, but
+/// also reports the files that could not be read or do not start with a
+/// font signature. Each failure is logged.
+///
+/// # Arguments
+/// * `dir` - The directory to scan.
+///
+/// # Output
+/// The fonts read and the files that failed. A missing directory yields
+/// neither.
+///
+/// # Examples
+/// ```
+/// use iced::Font;
+/// use qrate_gui::load_fonts;
+///
+/// let dir = std::env::temp_dir().join("qrate_gui_load_fonts");
+/// let _ = std::fs::remove_dir_all(&dir);
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let fonts = load_fonts(&dir);
+/// assert!(fonts.get_failed().is_empty());
+/// assert_eq!(fonts.get_default_font("ko"), Font::with_name("Noto Sans CJK KR"));
+///
+/// // A broken font file only moves the locales using it to the next font.
+/// std::fs::write(dir.join("NotoSansCJKkr-Regular.otf"), b"not a font").unwrap();
+/// let fonts = load_fonts(&dir);
+/// assert!(fonts.get_files().is_empty());
+/// assert_eq!(fonts.get_failed(), &[dir.join("NotoSansCJKkr-Regular.otf")]);
+/// assert_eq!(fonts.get_default_font("ko"), Font::with_name("Noto Sans KR"));
+/// assert_eq!(fonts.get_default_font("en"), Font::with_name("Noto Sans"));
+/// ```
+pub fn load_fonts(dir: &Path) -> LoadedFonts
+{
+    let Ok(entries) = fs::read_dir(dir)
+        else { return LoadedFonts::default(); };
+    let mut paths: Vec<_> = entries.filter_map(|entry| entry.ok().map(|e| e.path()))
+                                   .filter(|path| {
+                                       let extension = path.extension().and_then(|s| s.to_str()).unwrap_or("");
+                                       extension.eq_ignore_ascii_case("ttf") || extension.eq_ignore_ascii_case("otf")
+                                   })
+                                   .collect();
+    paths.sort();
+    let mut fonts = LoadedFonts::default();
+    for path in paths
+    {
+        match fs::read(&path)
+        {
+            Ok(bytes) if is_font(&bytes) => fonts.files.push(bytes),
+            _ => {
+                log_event(&format!("Error loading font: {}", path.display()));
+                fonts.failed.push(path);
+            },
+        }
+    }
+    fonts
+}
+
+/// Returns whether `bytes` start with the signature of a TrueType or
+/// OpenType font or font collection.
+fn is_font(bytes: &[u8]) -> bool
+{
+    const SIGNATURES: [&[u8]; 4] = [ b"\x00\x01\x00\x00", b"OTTO", b"true", b"ttcf" ];
+    SIGNATURES.iter().any(|signature| bytes.starts_with(signature))
+}
@@ -0,0 +1,267 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fs;
+use std::io;
+use std::path::{ Path, PathBuf };
+use std::time::{ SystemTime, UNIX_EPOCH };
+
+use serde::{ Serialize, Deserialize };
+
+use crate::VariantReport;
+
+/// The name of the summary file written next to the exported papers.
+const SUMMARY_FILE: &str = "generation-summary.json";
+
+/// The record of one exported paper.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaperSummary
+{
+    name: String,
+    file: PathBuf,
+    seed: u64,
+    question_count: usize,
+
+    #[serde(default)]
+    error: Option<String>,
+}
+
+impl PaperSummary
+{
+    // pub fn get_name(&self) -> &str
+    /// Returns the name of the paper, such as `"A"`.
+    ///
+    /// # Examples
+    /// See `GenerationSummary::from_reports`.
+    pub fn get_name(&self) -> &str
+    {
+        &self.name
+    }
+
+    // pub fn get_file(&self) -> &Path
+    /// Returns the file the paper was written to, or was meant to be written to.
+    ///
+    /// # Examples
+    /// See `GenerationSummary::from_reports`.
+    pub fn get_file(&self) -> &Path
+    {
+        &self.file
+    }
+
+    // pub fn get_seed(&self) -> u64
+    /// Returns the seed the paper was generated with, which reproduces it.
+    ///
+    /// # Examples
+    /// See `GenerationSummary::from_reports`.
+    pub fn get_seed(&self) -> u64
+    {
+        self.seed
+    }
+
+    // pub fn get_question_count(&self) -> usize
+    /// Returns the number of questions on the paper.
+    ///
+    /// # Examples
+    /// See `GenerationSummary::from_reports`.
+    pub fn get_question_count(&self) -> usize
+    {
+        self.question_count
+    }
+
+    // pub fn get_error(&self) -> Option<&str>
+    /// Returns why the paper was not written, or `None` if it was.
+    ///
+    /// # Examples
+    /// See `GenerationSummary::from_reports`.
+    pub fn get_error(&self) -> Option<&str>
+    {
+        self.error.as_deref()
+    }
+}
+
+/// A manifest of a batch export: which papers were produced, from which
+/// seeds, with how many questions, and when.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenerationSummary
+{
+    generated_at: u64,
+    papers: Vec<PaperSummary>,
+}
+
+impl GenerationSummary
+{
+    // pub fn from_reports(reports: &[VariantReport], generated_at: SystemTime) -> Self
+    /// Creates the summary of a batch export from its reports.
+    ///
+    /// # Arguments
+    /// * `reports` - The outcome of exporting each paper.
+    /// * `generated_at` - When the batch was exported.
+    ///
+    /// # Output
+    /// The summary, listing the papers in the order of `reports`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::{ Duration, UNIX_EPOCH };
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ export_variants, generate_variants, ExamCriteria, ExportFormat, GenerationSummary };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=5
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(3);
+    /// criteria.set_seed(Some(11));
+    /// let variants = generate_variants(&qbank, &criteria, 2).unwrap();
+    ///
+    /// let dir = std::env::temp_dir().join("qrate_gui_generation_summary");
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// let reports = export_variants(&variants, &dir, ExportFormat::Excel);
+    /// let summary = GenerationSummary::from_reports(&reports, UNIX_EPOCH + Duration::from_secs(86_400));
+    ///
+    /// assert_eq!(summary.get_generated_at(), 86_400);
+    /// let papers = summary.get_papers();
+    /// assert_eq!(papers.len(), 2);
+    /// for (paper, variant) in papers.iter().zip(variants.iter())
+    /// {
+    ///     assert_eq!(paper.get_name(), variant.get_name());
+    ///     assert_eq!(paper.get_seed(), variant.get_seed());
+    ///     assert_eq!(paper.get_question_count(), 3);
+    ///     assert_eq!(paper.get_error(), None);
+    ///     assert!(paper.get_file().exists());
+    /// }
+    /// ```
+    pub fn from_reports(reports: &[VariantReport], generated_at: SystemTime) -> Self
+    {
+        let papers = reports.iter()
+                        .map(|report| PaperSummary {
+                            name: report.name.clone(),
+                            file: report.path.clone(),
+                            seed: report.seed,
+                            question_count: report.question_count,
+                            error: report.result.as_ref().err().map(|e| e.to_string()),
+                        })
+                        .collect();
+        let generated_at = generated_at.duration_since(UNIX_EPOCH).map(|since| since.as_secs()).unwrap_or(0);
+        Self { generated_at, papers }
+    }
+
+    // pub fn get_generated_at(&self) -> u64
+    /// Returns when the batch was exported, in seconds since the Unix epoch.
+    ///
+    /// # Examples
+    /// See `GenerationSummary::from_reports`.
+    pub fn get_generated_at(&self) -> u64
+    {
+        self.generated_at
+    }
+
+    // pub fn get_papers(&self) -> &[PaperSummary]
+    /// Returns the records of the exported papers.
+    ///
+    /// # Examples
+    /// See `GenerationSummary::from_reports`.
+    pub fn get_papers(&self) -> &[PaperSummary]
+    {
+        &self.papers
+    }
+}
+
+// pub fn format_timestamp(seconds: u64) -> String
+/// Formats seconds since the Unix epoch as a UTC date and time.
+///
+/// # Arguments
+/// * `seconds` - The seconds since 1970-01-01 00:00:00 UTC.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::format_timestamp;
+/// assert_eq!(format_timestamp(0), "1970-01-01 00:00:00 UTC");
+/// assert_eq!(format_timestamp(951_827_696), "2000-02-29 12:34:56 UTC");
+/// ```
+pub fn format_timestamp(seconds: u64) -> String
+{
+    let days = (seconds / 86_400) as i64;
+    let time = seconds % 86_400;
+
+    // Converts days since the epoch to a civil date (proleptic Gregorian).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC", year, month, day, time / 3_600, time % 3_600 / 60, time % 60)
+}
+
+// pub fn get_summary_path(dir: &Path) -> PathBuf
+/// Returns the path of the summary file in the export folder `dir`.
+///
+/// # Examples
+/// ```
+/// use std::path::{ Path, PathBuf };
+/// use qrate_gui::get_summary_path;
+/// assert_eq!(get_summary_path(Path::new("/out")), PathBuf::from("/out/generation-summary.json"));
+/// ```
+pub fn get_summary_path(dir: &Path) -> PathBuf
+{
+    dir.join(SUMMARY_FILE)
+}
+
+// pub fn save_summary(path: &Path, summary: &GenerationSummary) -> io::Result<()>
+/// Saves `summary` to `path` as JSON.
+///
+/// # Arguments
+/// * `path` - The summary file.
+/// * `summary` - The summary to store.
+///
+/// # Output
+/// An `io::Result` reporting write failures.
+///
+/// # Examples
+/// ```
+/// use std::time::SystemTime;
+/// use qrate_gui::{ load_summary, save_summary, GenerationSummary };
+/// let path = std::env::temp_dir().join("qrate_gui_save_summary.json");
+/// let summary = GenerationSummary::from_reports(&[], SystemTime::now());
+/// save_summary(&path, &summary).unwrap();
+/// assert_eq!(load_summary(&path), Some(summary));
+/// ```
+pub fn save_summary(path: &Path, summary: &GenerationSummary) -> io::Result<()>
+{
+    let json = serde_json::to_string_pretty(summary).map_err(io::Error::other)?;
+    fs::write(path, json)
+}
+
+// pub fn load_summary(path: &Path) -> Option<GenerationSummary>
+/// Loads a summary saved with `save_summary`.
+///
+/// # Arguments
+/// * `path` - The summary file.
+///
+/// # Output
+/// The summary, or `None` if the file is missing or cannot be parsed.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use qrate_gui::load_summary;
+/// assert_eq!(load_summary(Path::new("/definitely/not/here.json")), None);
+/// ```
+pub fn load_summary(path: &Path) -> Option<GenerationSummary>
+{
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
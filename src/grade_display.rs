@@ -0,0 +1,228 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use serde::{ Serialize, Deserialize };
+
+/// How a grade is shown in the grade report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GradeDisplay
+{
+    /// The points earned out of the points available, such as `8.50 / 10`.
+    #[default]
+    Points,
+
+    /// The share of the points earned, such as `85.0%`.
+    Percentage,
+
+    /// The letter of the `LetterScale` the percentage falls into, such as `B`.
+    Letter,
+}
+
+impl GradeDisplay
+{
+    /// All display modes, in the order they are offered to the user.
+    pub const ALL: [GradeDisplay; 3] = [ GradeDisplay::Points, GradeDisplay::Percentage, GradeDisplay::Letter ];
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key of the mode's display name.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::GradeDisplay;
+    /// assert_eq!(GradeDisplay::Letter.get_key(), "grade-display-letter");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            GradeDisplay::Points => "grade-display-points",
+            GradeDisplay::Percentage => "grade-display-percentage",
+            GradeDisplay::Letter => "grade-display-letter",
+        }
+    }
+}
+
+/// One letter of a `LetterScale` and the lowest percentage that earns it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LetterBand
+{
+    /// The lowest percentage, inclusive, that earns the letter.
+    pub min_percent: f32,
+
+    /// The letter, such as `"A"` or `"B+"`.
+    pub letter: String,
+}
+
+/// The letters grades are mapped to, kept sorted from the highest band down.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LetterScale
+{
+    bands: Vec<LetterBand>,
+}
+
+impl Default for LetterScale
+{
+    fn default() -> Self
+    {
+        Self::new(vec![
+            LetterBand { min_percent: 90.0, letter: "A".to_string() },
+            LetterBand { min_percent: 80.0, letter: "B".to_string() },
+            LetterBand { min_percent: 70.0, letter: "C".to_string() },
+            LetterBand { min_percent: 60.0, letter: "D".to_string() },
+            LetterBand { min_percent: 0.0, letter: "F".to_string() },
+        ])
+    }
+}
+
+impl LetterScale
+{
+    // pub fn new(bands: Vec<LetterBand>) -> Self
+    /// Creates a scale from bands given in any order.
+    ///
+    /// # Arguments
+    /// * `bands` - The letters and their lowest percentages.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ LetterBand, LetterScale };
+    /// let scale = LetterScale::new(vec![
+    ///     LetterBand { min_percent: 0.0, letter: "Fail".to_string() },
+    ///     LetterBand { min_percent: 50.0, letter: "Pass".to_string() },
+    /// ]);
+    /// assert_eq!(scale.get_bands()[0].letter, "Pass");
+    /// ```
+    pub fn new(mut bands: Vec<LetterBand>) -> Self
+    {
+        bands.sort_by(|a, b| b.min_percent.total_cmp(&a.min_percent));
+        Self { bands }
+    }
+
+    // pub fn parse(text: &str) -> Option<Self>
+    /// Parses a scale written as comma-separated `letter=percent` pairs, as
+    /// entered on the grading criteria page.
+    ///
+    /// # Arguments
+    /// * `text` - Such as `"A=90, B=80, C=70, D=60, F=0"`.
+    ///
+    /// # Output
+    /// The scale, or `None` if a pair is malformed or no pair is given.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::LetterScale;
+    /// assert_eq!(LetterScale::parse("A=90, B=80, C=70, D=60, F=0"), Some(LetterScale::default()));
+    /// assert_eq!(LetterScale::parse("A=ninety"), None);
+    /// assert_eq!(LetterScale::parse(" "), None);
+    /// ```
+    pub fn parse(text: &str) -> Option<Self>
+    {
+        let bands = text.split(',')
+                        .filter(|pair| !pair.trim().is_empty())
+                        .map(|pair| {
+                            let (letter, percent) = pair.split_once('=')?;
+                            let letter = letter.trim();
+                            let min_percent = percent.trim().parse::<f32>().ok().filter(|p| p.is_finite())?;
+                            (!letter.is_empty()).then(|| LetterBand { min_percent, letter: letter.to_string() })
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+        if bands.is_empty()
+            { None }
+        else
+            { Some(Self::new(bands)) }
+    }
+
+    // pub fn to_text(&self) -> String
+    /// Writes the scale in the form read by `LetterScale::parse`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::LetterScale;
+    /// assert_eq!(LetterScale::default().to_text(), "A=90, B=80, C=70, D=60, F=0");
+    /// ```
+    pub fn to_text(&self) -> String
+    {
+        self.bands.iter()
+            .map(|band| format!("{}={}", band.letter, band.min_percent))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    // pub fn get_bands(&self) -> &[LetterBand]
+    /// Returns the bands, from the highest down.
+    ///
+    /// # Examples
+    /// See `LetterScale::new`.
+    pub fn get_bands(&self) -> &[LetterBand]
+    {
+        &self.bands
+    }
+}
+
+// pub fn to_letter(percent: f32, scale: &LetterScale) -> String
+/// Returns the letter of the highest band of `scale` whose lowest
+/// percentage `percent` reaches.
+///
+/// # Arguments
+/// * `percent` - The grade as a percentage.
+/// * `scale` - The letter scale.
+///
+/// # Output
+/// The letter, or the lowest letter if `percent` is below every band.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ to_letter, LetterScale };
+/// let scale = LetterScale::default();
+/// assert_eq!(to_letter(100.0, &scale), "A");
+/// assert_eq!(to_letter(90.0, &scale), "A");
+/// assert_eq!(to_letter(89.9, &scale), "B");
+/// assert_eq!(to_letter(80.0, &scale), "B");
+/// assert_eq!(to_letter(79.99, &scale), "C");
+/// assert_eq!(to_letter(60.0, &scale), "D");
+/// assert_eq!(to_letter(59.9, &scale), "F");
+/// assert_eq!(to_letter(-5.0, &scale), "F");
+/// ```
+pub fn to_letter(percent: f32, scale: &LetterScale) -> String
+{
+    scale.bands.iter()
+        .find(|band| percent >= band.min_percent)
+        .or(scale.bands.last())
+        .map(|band| band.letter.clone())
+        .unwrap_or_default()
+}
+
+// pub fn format_grade(score: f32, max: usize, display: GradeDisplay, scale: &LetterScale) -> String
+/// Formats a grade for the grade report.
+///
+/// # Arguments
+/// * `score` - The points earned.
+/// * `max` - The points available.
+/// * `display` - How the grade is shown.
+/// * `scale` - The letter scale used by `GradeDisplay::Letter`.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ format_grade, GradeDisplay, LetterScale };
+/// let scale = LetterScale::default();
+/// assert_eq!(format_grade(8.5, 10, GradeDisplay::Points, &scale), "8.50 / 10");
+/// assert_eq!(format_grade(8.5, 10, GradeDisplay::Percentage, &scale), "85.0%");
+/// assert_eq!(format_grade(8.5, 10, GradeDisplay::Letter, &scale), "B");
+/// assert_eq!(format_grade(0.0, 0, GradeDisplay::Percentage, &scale), "0.0%");
+/// ```
+pub fn format_grade(score: f32, max: usize, display: GradeDisplay, scale: &LetterScale) -> String
+{
+    let percent = if max == 0 { 0.0 } else { score / max as f32 * 100.0 };
+    match display
+    {
+        GradeDisplay::Points => format!("{:.2} / {}", score, max),
+        GradeDisplay::Percentage => format!("{:.1}%", percent),
+        GradeDisplay::Letter => to_letter(percent, scale),
+    }
+}
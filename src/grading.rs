@@ -0,0 +1,490 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use qrate::Choices;
+use serde::{ Serialize, Deserialize };
+
+/// The answer format of a question.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub enum QuestionKind
+{
+    /// Exactly one of several choices is selected.
+    #[default]
+    SingleChoice,
+
+    /// The two choices "True" and "False"; exactly one is selected.
+    TrueFalse,
+
+    /// Any number of choices may be selected.
+    MultiSelect,
+
+    /// A short answer typed on one line; the correct choices hold the
+    /// accepted answers.
+    ShortText,
+
+    /// A number typed on one line, accepted within a `NumericTolerance` of
+    /// a correct choice.
+    Number,
+
+    /// A longer answer typed over several lines.
+    MultiLine,
+}
+
+/// The input widget a question is answered with in learning mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerInput
+{
+    /// Radio buttons or checkboxes for the choices.
+    Choices,
+
+    /// A single-line text field.
+    SingleLine,
+
+    /// A single-line text field that only accepts numbers.
+    Numeric,
+
+    /// A multi-line text area.
+    TextArea,
+}
+
+impl AnswerInput
+{
+    // pub fn accepts(&self, input: &str) -> bool
+    /// Returns whether `input` may be typed into the widget. A numeric field
+    /// rejects anything that cannot be part of a number.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::AnswerInput;
+    /// assert!(AnswerInput::Numeric.accepts("-3.5e2"));
+    /// assert!(!AnswerInput::Numeric.accepts("12 apples"));
+    /// assert!(AnswerInput::SingleLine.accepts("12 apples"));
+    /// ```
+    pub fn accepts(&self, input: &str) -> bool
+    {
+        match self
+        {
+            AnswerInput::Numeric => input.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '+' | 'e' | 'E')),
+            _ => true,
+        }
+    }
+}
+
+/// How far a typed number may be from the expected one by default, relative
+/// to the expected number once it exceeds one in magnitude.
+pub const NUMERIC_TOLERANCE: f64 = 1e-3;
+
+/// The absolute tolerances offered on the grading criteria page.
+pub const ABSOLUTE_TOLERANCES: [f64; 6] = [0.0, 0.0001, 0.001, 0.01, 0.1, 1.0];
+
+/// The percentage tolerances offered on the grading criteria page.
+pub const PERCENT_TOLERANCES: [f64; 7] = [0.0, 0.1, 0.5, 1.0, 2.0, 5.0, 10.0];
+
+/// How far a typed number may be from the expected one and still be
+/// accepted: within the absolute tolerance or within the percentage of the
+/// expected number, whichever is larger.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NumericTolerance
+{
+    absolute: f64,
+    percent: f64,
+}
+
+impl Default for NumericTolerance
+{
+    fn default() -> Self
+    {
+        Self { absolute: NUMERIC_TOLERANCE, percent: NUMERIC_TOLERANCE * 100.0 }
+    }
+}
+
+impl NumericTolerance
+{
+    // pub fn new(absolute: f64, percent: f64) -> Self
+    /// Creates a tolerance. Negative or non-finite values count as zero.
+    ///
+    /// # Arguments
+    /// * `absolute` - The largest accepted difference.
+    /// * `percent` - The largest accepted difference, as a percentage of the
+    ///   expected number.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::NumericTolerance;
+    /// let tolerance = NumericTolerance::new(0.01, -5.0);
+    /// assert_eq!(tolerance.get_absolute(), 0.01);
+    /// assert_eq!(tolerance.get_percent(), 0.0);
+    /// ```
+    pub fn new(absolute: f64, percent: f64) -> Self
+    {
+        let clean = |value: f64| if value.is_finite() { value.max(0.0) } else { 0.0 };
+        Self { absolute: clean(absolute), percent: clean(percent) }
+    }
+
+    // pub fn get_absolute(&self) -> f64
+    /// Returns the largest accepted difference.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ NumericTolerance, NUMERIC_TOLERANCE };
+    /// assert_eq!(NumericTolerance::default().get_absolute(), NUMERIC_TOLERANCE);
+    /// ```
+    pub fn get_absolute(&self) -> f64
+    {
+        self.absolute
+    }
+
+    // pub fn get_percent(&self) -> f64
+    /// Returns the largest accepted difference, as a percentage of the
+    /// expected number.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::NumericTolerance;
+    /// assert_eq!(NumericTolerance::new(0.0, 2.0).get_percent(), 2.0);
+    /// ```
+    pub fn get_percent(&self) -> f64
+    {
+        self.percent
+    }
+}
+
+impl QuestionKind
+{
+    /// All question kinds, in the order they are offered to the user.
+    pub const ALL: [QuestionKind; 6] = [
+        QuestionKind::SingleChoice, QuestionKind::TrueFalse, QuestionKind::MultiSelect,
+        QuestionKind::ShortText, QuestionKind::Number, QuestionKind::MultiLine,
+    ];
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key of the kind's display name.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::QuestionKind;
+    /// assert_eq!(QuestionKind::TrueFalse.get_key(), "kind-true-false");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            QuestionKind::SingleChoice => "kind-single-choice",
+            QuestionKind::TrueFalse => "kind-true-false",
+            QuestionKind::MultiSelect => "kind-multi-select",
+            QuestionKind::ShortText => "kind-short-text",
+            QuestionKind::Number => "kind-number",
+            QuestionKind::MultiLine => "kind-multi-line",
+        }
+    }
+
+    // pub fn get_input(&self) -> AnswerInput
+    /// Returns the input widget the question is answered with in learning mode.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ AnswerInput, QuestionKind };
+    /// assert_eq!(QuestionKind::TrueFalse.get_input(), AnswerInput::Choices);
+    /// assert_eq!(QuestionKind::Number.get_input(), AnswerInput::Numeric);
+    /// assert_eq!(QuestionKind::MultiLine.get_input(), AnswerInput::TextArea);
+    /// ```
+    pub fn get_input(&self) -> AnswerInput
+    {
+        match self
+        {
+            QuestionKind::SingleChoice | QuestionKind::TrueFalse | QuestionKind::MultiSelect => AnswerInput::Choices,
+            QuestionKind::ShortText => AnswerInput::SingleLine,
+            QuestionKind::Number => AnswerInput::Numeric,
+            QuestionKind::MultiLine => AnswerInput::TextArea,
+        }
+    }
+
+    // pub fn is_typed(&self) -> bool
+    /// Returns whether the answer is typed rather than picked from the choices.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::QuestionKind;
+    /// assert!(QuestionKind::ShortText.is_typed());
+    /// assert!(!QuestionKind::SingleChoice.is_typed());
+    /// ```
+    pub fn is_typed(&self) -> bool
+    {
+        self.get_input() != AnswerInput::Choices
+    }
+
+    // pub fn allows_multiple(&self) -> bool
+    /// Returns whether more than one choice may be selected.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::QuestionKind;
+    /// assert!(QuestionKind::MultiSelect.allows_multiple());
+    /// assert!(!QuestionKind::TrueFalse.allows_multiple());
+    /// ```
+    pub fn allows_multiple(&self) -> bool
+    {
+        matches!(self, QuestionKind::MultiSelect)
+    }
+}
+
+/// How a multiple-select question is scored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradingMode
+{
+    /// Full credit only when exactly the correct choices are selected.
+    #[default]
+    AllOrNothing,
+
+    /// Each correct selection earns a share of the credit and each wrong
+    /// selection cancels one share; the score never goes below zero.
+    PartialCredit,
+}
+
+impl GradingMode
+{
+    /// All grading modes, in the order they are offered to the user.
+    pub const ALL: [GradingMode; 2] = [ GradingMode::AllOrNothing, GradingMode::PartialCredit ];
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key of the mode's display name.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::GradingMode;
+    /// assert_eq!(GradingMode::PartialCredit.get_key(), "grading-partial-credit");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            GradingMode::AllOrNothing => "grading-all-or-nothing",
+            GradingMode::PartialCredit => "grading-partial-credit",
+        }
+    }
+}
+
+// pub fn true_false_choices(answer: bool) -> Choices
+/// Builds the choices of a true/false question.
+///
+/// # Arguments
+/// * `answer` - Whether the statement is true.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::true_false_choices;
+/// assert_eq!(true_false_choices(false), vec![("True".to_string(), false), ("False".to_string(), true)]);
+/// ```
+pub fn true_false_choices(answer: bool) -> Choices
+{
+    vec![ ("True".to_string(), answer), ("False".to_string(), !answer) ]
+}
+
+// pub fn grade_answer(kind: QuestionKind, choices: &Choices, selected: &[usize], mode: GradingMode) -> f32
+/// Scores the selected choices of one question.
+///
+/// Single-choice and true/false questions are always all-or-nothing;
+/// `mode` only affects multiple-select questions.
+///
+/// # Arguments
+/// * `kind` - The kind of the question.
+/// * `choices` - The choices with their correctness flags.
+/// * `selected` - The 0-based indices of the selected choices.
+/// * `mode` - How multiple-select questions are scored.
+///
+/// # Output
+/// A score from `0.0` to `1.0`.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ grade_answer, true_false_choices, GradingMode, QuestionKind };
+///
+/// // True/false
+/// let statement = true_false_choices(true);
+/// assert_eq!(grade_answer(QuestionKind::TrueFalse, &statement, &[0], GradingMode::PartialCredit), 1.0);
+/// assert_eq!(grade_answer(QuestionKind::TrueFalse, &statement, &[1], GradingMode::PartialCredit), 0.0);
+/// assert_eq!(grade_answer(QuestionKind::TrueFalse, &statement, &[], GradingMode::PartialCredit), 0.0);
+///
+/// // Multiple-select with three correct choices out of five
+/// let choices = vec![
+///     ("a".to_string(), true), ("b".to_string(), true), ("c".to_string(), true),
+///     ("d".to_string(), false), ("e".to_string(), false),
+/// ];
+/// assert_eq!(grade_answer(QuestionKind::MultiSelect, &choices, &[0, 1, 2], GradingMode::AllOrNothing), 1.0);
+/// assert_eq!(grade_answer(QuestionKind::MultiSelect, &choices, &[0, 1], GradingMode::AllOrNothing), 0.0);
+/// assert_eq!(grade_answer(QuestionKind::MultiSelect, &choices, &[0, 1], GradingMode::PartialCredit), 2.0 / 3.0);
+/// assert_eq!(grade_answer(QuestionKind::MultiSelect, &choices, &[0, 1, 3], GradingMode::PartialCredit), 1.0 / 3.0);
+/// assert_eq!(grade_answer(QuestionKind::MultiSelect, &choices, &[3, 4], GradingMode::PartialCredit), 0.0);
+/// ```
+pub fn grade_answer(kind: QuestionKind, choices: &Choices, selected: &[usize], mode: GradingMode) -> f32
+{
+    let correct: Vec<usize> = choices.iter()
+                                .enumerate()
+                                .filter(|(_, (_, answer))| *answer)
+                                .map(|(i, _)| i)
+                                .collect();
+    let mut chosen: Vec<usize> = selected.iter().copied().filter(|&i| i < choices.len()).collect();
+    chosen.sort_unstable();
+    chosen.dedup();
+
+    if kind.allows_multiple() && mode == GradingMode::PartialCredit
+    {
+        if correct.is_empty()
+            { return if chosen.is_empty() { 1.0 } else { 0.0 }; }
+        let hits = chosen.iter().filter(|i| correct.contains(i)).count() as f32;
+        let misses = chosen.len() as f32 - hits;
+        ((hits - misses) / correct.len() as f32).max(0.0)
+    }
+    else if chosen == correct
+        { 1.0 }
+    else
+        { 0.0 }
+}
+
+// pub fn numeric_match(expected: &str, given: &str, tol: NumericTolerance) -> bool
+/// Returns whether the typed number `given` is accepted for `expected`.
+///
+/// Both are trimmed and a decimal comma is read as a point. A tiny margin
+/// absorbs the rounding of decimal fractions, so that a difference equal to
+/// the tolerance is accepted.
+///
+/// # Arguments
+/// * `expected` - The correct answer.
+/// * `given` - The typed answer.
+/// * `tol` - How far `given` may be from `expected`.
+///
+/// # Output
+/// `false` if either is not a number.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ numeric_match, NumericTolerance };
+///
+/// // Within tolerance
+/// assert!(numeric_match("3.14159", "3.14", NumericTolerance::new(0.01, 0.0)));
+/// assert!(numeric_match("3.14159", "3.14", NumericTolerance::new(0.0, 1.0)));
+/// assert!(numeric_match("3.14", "3.15", NumericTolerance::new(0.01, 0.0)));
+/// assert!(numeric_match("9.81", " 9,81 ", NumericTolerance::new(0.0, 0.0)));
+/// assert!(numeric_match("3.14159", "3.1416", NumericTolerance::default()));
+///
+/// // Out of tolerance
+/// assert!(!numeric_match("3.14159", "3.14", NumericTolerance::new(0.001, 0.0)));
+/// assert!(!numeric_match("3.14159", "3.14", NumericTolerance::new(0.0, 0.01)));
+/// assert!(!numeric_match("1000", "1030", NumericTolerance::new(1.0, 2.0)));
+/// assert!(!numeric_match("3.14159", "3.15", NumericTolerance::default()));
+///
+/// // Not numbers
+/// assert!(!numeric_match("3.14159", "pi", NumericTolerance::new(1.0, 100.0)));
+/// assert!(!numeric_match("pi", "3.14", NumericTolerance::new(1.0, 100.0)));
+/// assert!(!numeric_match("3.14", "", NumericTolerance::new(1.0, 100.0)));
+/// ```
+pub fn numeric_match(expected: &str, given: &str, tol: NumericTolerance) -> bool
+{
+    match (parse_number(expected), parse_number(given))
+    {
+        (Some(expected), Some(given)) => {
+            let allowed = tol.absolute.max(tol.percent / 100.0 * expected.abs());
+            (expected - given).abs() <= allowed + 1e-9 * expected.abs().max(1.0)
+        },
+        _ => false,
+    }
+}
+
+// pub fn grade_typed_answer(kind: QuestionKind, choices: &Choices, answer: &str, tolerance: NumericTolerance) -> f32
+/// Scores a typed answer against the correct choices, which hold the
+/// accepted answers.
+///
+/// Short answers are compared trimmed and case-insensitively. Numbers are
+/// compared with `numeric_match`. Multi-line answers are compared line by line with surrounding
+/// blanks and empty lines ignored.
+///
+/// # Arguments
+/// * `kind` - The kind of the question; choice kinds always score `0.0`.
+/// * `choices` - The choices with their correctness flags.
+/// * `answer` - The typed answer.
+/// * `tolerance` - How far a typed number may be from a correct one.
+///
+/// # Output
+/// `1.0` if the answer matches a correct choice, otherwise `0.0`.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ grade_typed_answer, NumericTolerance, QuestionKind };
+///
+/// let tolerance = NumericTolerance::default();
+/// let pi = vec![("3.14159".to_string(), true)];
+/// assert_eq!(grade_typed_answer(QuestionKind::Number, &pi, "3.1416", tolerance), 1.0);
+/// assert_eq!(grade_typed_answer(QuestionKind::Number, &pi, " 3,1416 ", tolerance), 1.0);
+/// assert_eq!(grade_typed_answer(QuestionKind::Number, &pi, "3.15", tolerance), 0.0);
+/// assert_eq!(grade_typed_answer(QuestionKind::Number, &pi, "3.15", NumericTolerance::new(0.0, 1.0)), 1.0);
+/// assert_eq!(grade_typed_answer(QuestionKind::Number, &pi, "pi", tolerance), 0.0);
+///
+/// // A comma groups thousands where it can, and is a decimal comma otherwise.
+/// let thousand = vec![("1000".to_string(), true)];
+/// assert_eq!(grade_typed_answer(QuestionKind::Number, &thousand, "1,000", tolerance), 1.0);
+/// assert_eq!(grade_typed_answer(QuestionKind::Number, &vec![("1.0".to_string(), true)], "1,000", tolerance), 0.0);
+/// assert_eq!(grade_typed_answer(QuestionKind::Number, &vec![("1000000.5".to_string(), true)], "1,000,000.5", tolerance), 1.0);
+/// assert_eq!(grade_typed_answer(QuestionKind::Number, &pi, "3,14", tolerance), 1.0);
+/// assert_eq!(grade_typed_answer(QuestionKind::Number, &pi, "3,14.1", tolerance), 0.0);
+///
+/// let capital = vec![("Seoul".to_string(), true), ("Busan".to_string(), false)];
+/// assert_eq!(grade_typed_answer(QuestionKind::ShortText, &capital, "  seoul ", tolerance), 1.0);
+/// assert_eq!(grade_typed_answer(QuestionKind::ShortText, &capital, "Busan", tolerance), 0.0);
+///
+/// let poem = vec![("Roses are red\nViolets are blue".to_string(), true)];
+/// assert_eq!(grade_typed_answer(QuestionKind::MultiLine, &poem, "\n  Roses are red \n\nViolets are blue\n", tolerance), 1.0);
+/// ```
+pub fn grade_typed_answer(kind: QuestionKind, choices: &Choices, answer: &str, tolerance: NumericTolerance) -> f32
+{
+    let matches_choice = |expected: &str| match kind
+    {
+        QuestionKind::ShortText => expected.trim().to_lowercase() == answer.trim().to_lowercase(),
+        QuestionKind::Number => numeric_match(expected, answer, tolerance),
+        QuestionKind::MultiLine => normalize_lines(expected) == normalize_lines(answer),
+        _ => false,
+    };
+    if choices.iter().any(|(text, correct)| *correct && matches_choice(text))
+        { 1.0 }
+    else
+        { 0.0 }
+}
+
+// fn parse_number(text: &str) -> Option<f64>
+/// Parses a typed number. Commas grouping thousands, as in `1,000` or
+/// `1,000.5`, are dropped; otherwise a single comma without a point is read
+/// as a decimal comma, as in `3,14`.
+fn parse_number(text: &str) -> Option<f64>
+{
+    let text = text.trim();
+    let (whole, fraction) = match text.split_once('.')
+    {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (text, None),
+    };
+    let digits = whole.strip_prefix(['-', '+']).unwrap_or(whole);
+    let mut groups = digits.split(',');
+    let grouped = digits.contains(',')
+                    && groups.next().is_some_and(|first| (1..=3).contains(&first.len()) && first.bytes().all(|b| b.is_ascii_digit()))
+                    && groups.all(|group| group.len() == 3 && group.bytes().all(|b| b.is_ascii_digit()));
+    let number = if grouped
+        { text.replace(',', "") }
+    else if fraction.is_none() && text.matches(',').count() == 1
+        { text.replace(',', ".") }
+    else
+        { text.to_string() };
+    number.parse::<f64>().ok().filter(|number| number.is_finite())
+}
+
+// fn normalize_lines(text: &str) -> Vec<&str>
+/// Splits `text` into trimmed, non-empty lines.
+fn normalize_lines(text: &str) -> Vec<&str>
+{
+    text.lines().map(|line| line.trim()).filter(|line| !line.is_empty()).collect()
+}
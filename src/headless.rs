@@ -0,0 +1,158 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::Path;
+
+use qrate::QBank;
+
+use crate::{ LoadFile, ResultLoadFile, Exam, ExamCriteria, ExportError, ExportFormat, export_exam, load_question_metas };
+use crate::{ load_bank_metadata, QuestionMetas };
+
+/// The number of questions drawn by `generate_quick` when the bank has enough.
+pub const QUICK_EXAM_SIZE: usize = 10;
+
+/// Describes why a headless generation failed.
+#[derive(Debug, Clone)]
+pub enum GenerateError
+{
+    /// The question bank could not be loaded; contains the reason.
+    Load(String),
+
+    /// No question bank has been loaded, or the loaded bank is empty.
+    NoQuestionBank,
+
+    /// The bank does not contain enough questions for the criteria.
+    NotEnoughQuestions,
+
+    /// The exam could not be exported.
+    Export(ExportError),
+}
+
+impl fmt::Display for GenerateError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            GenerateError::Load(reason) => write!(f, "Failed to load question bank: {}", reason),
+            GenerateError::NoQuestionBank => write!(f, "No question bank is loaded."),
+            GenerateError::NotEnoughQuestions => write!(f, "Not enough questions for the criteria."),
+            GenerateError::Export(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+// pub fn generate(bank_path: &Path, criteria: &ExamCriteria, out_path: &Path, format: ExportFormat) -> Result<(), GenerateError>
+/// Loads a question bank, generates an exam and exports it, without a GUI.
+///
+/// This runs the same pipeline as the application: the bank and its
+/// question metadata are loaded, an `Exam` is drawn with `criteria`, retried
+/// for its difficulty target as `Exam::generate_balanced` does, and
+/// written to `out_path` in `format`.
+///
+/// # Arguments
+/// * `bank_path` - The question bank file (`.qbdb` or `.qb.xlsx`).
+/// * `criteria` - The extraction criteria.
+/// * `out_path` - The file to write.
+/// * `format` - The output format.
+///
+/// # Output
+/// `Ok(())` on success, or the `GenerateError` that stopped the pipeline.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Excel, QBDB, QBank, Question };
+/// use qrate_gui::{ generate, ExamCriteria, ExportFormat, GenerateError };
+///
+/// // Fixture bank with five questions.
+/// let dir = std::env::temp_dir();
+/// let bank_path = dir.join("qrate_gui_generate_fixture.qb.xlsx");
+/// let bank_base = dir.join("qrate_gui_generate_fixture");
+/// let mut qbank = QBank::new_with_default();
+/// for id in 1..=5
+/// {
+///     let choices = vec![("Yes".to_string(), true), ("No".to_string(), false)];
+///     qbank.push_question(Question::new(id, id, 1, format!("Question {}", id), choices));
+/// }
+/// let _ = std::fs::remove_file(&bank_path);
+/// let mut excel = Excel::open(bank_base.to_string_lossy().into_owned()).unwrap();
+/// excel.write_qbank(&qbank).unwrap();
+///
+/// let mut criteria = ExamCriteria::new();
+/// criteria.set_selected(3);
+/// let out_path = dir.join("qrate_gui_generate_output.xlsx");
+/// let _ = std::fs::remove_file(&out_path);
+/// generate(&bank_path, &criteria, &out_path, ExportFormat::Excel).unwrap();
+/// assert!(out_path.exists());
+///
+/// // Asking for more questions than the bank holds is reported.
+/// criteria.set_selected(9);
+/// let result = generate(&bank_path, &criteria, &out_path, ExportFormat::Excel);
+/// assert!(matches!(result, Err(GenerateError::NotEnoughQuestions)));
+/// ```
+pub fn generate(bank_path: &Path, criteria: &ExamCriteria, out_path: &Path, format: ExportFormat) -> Result<(), GenerateError>
+{
+    let qbank = match LoadFile::read_qbank_from_path(bank_path)
+    {
+        ResultLoadFile::Success(qbank) => qbank,
+        failure => return Err(GenerateError::Load(failure.to_string())),
+    };
+    let (mut exam, _) = Exam::generate_balanced(&qbank, criteria, &load_question_metas(bank_path)).ok_or(GenerateError::NotEnoughQuestions)?;
+    exam.set_bank_metadata(load_bank_metadata(bank_path, qbank.get_header()));
+    export_exam(&exam, out_path, format).map_err(GenerateError::Export)
+}
+
+// pub fn generate_quick(qbank: &QBank, metas: &QuestionMetas) -> Result<Exam, GenerateError>
+/// Draws an exam with default criteria, for a quick random quiz.
+///
+/// Every category and the whole id range are used, choices are not
+/// shuffled and the draw is random. `QUICK_EXAM_SIZE` questions are drawn,
+/// or one per question group if the bank has fewer groups.
+///
+/// # Arguments
+/// * `qbank` - The loaded question bank.
+/// * `metas` - The question metadata carried over to the exam.
+///
+/// # Output
+/// The exam, or `GenerateError::NoQuestionBank` if `qbank` holds no questions.
+///
+/// # Examples
+/// ```
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::{ generate_quick, GenerateError, QuestionMetas, QUICK_EXAM_SIZE };
+///
+/// let mut qbank = QBank::new_with_default();
+/// for id in 1..=4
+///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+/// let exam = generate_quick(&qbank, &QuestionMetas::new()).unwrap();
+/// assert_eq!(exam.get_questions().len(), 4);
+///
+/// for id in 5..=30
+///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+/// let exam = generate_quick(&qbank, &QuestionMetas::new()).unwrap();
+/// assert_eq!(exam.get_questions().len(), QUICK_EXAM_SIZE);
+///
+/// let result = generate_quick(&QBank::new_empty(), &QuestionMetas::new());
+/// assert!(matches!(result, Err(GenerateError::NoQuestionBank)));
+/// ```
+pub fn generate_quick(qbank: &QBank, metas: &QuestionMetas) -> Result<Exam, GenerateError>
+{
+    let groups = qbank.get_questions().iter()
+                    .map(|question| question.get_group())
+                    .collect::<BTreeSet<_>>()
+                    .len();
+    if groups == 0
+        { return Err(GenerateError::NoQuestionBank); }
+    let mut criteria = ExamCriteria::new();
+    criteria.set_selected(QUICK_EXAM_SIZE.min(groups));
+    Exam::generate_balanced(qbank, &criteria, metas).map(|(exam, _)| exam).ok_or(GenerateError::NotEnoughQuestions)
+}
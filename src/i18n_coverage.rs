@@ -0,0 +1,251 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::HashMap;
+
+use crate::control_tower::LOCALES_DIR;
+
+/// The locale every other locale is compared against.
+pub const BASELINE_LOCALE: &str = "en";
+
+/// The translations of one locale, keyed by message key.
+pub type LocaleMap = HashMap<String, String>;
+
+// pub fn parse_locale(text: &str) -> Option<LocaleMap>
+/// Parses the contents of a locale `.yml` file.
+///
+/// # Arguments
+/// * `text` - The YAML text, one `key: value` pair per line.
+///
+/// # Output
+/// The translations, or `None` if `text` is not a YAML mapping. Values
+/// that are not strings, such as `~`, are read as empty.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::parse_locale;
+/// let map = parse_locale("back: Back\nnext: \"Next\"\nfinish: ~\n").unwrap();
+/// assert_eq!(map["next"], "Next");
+/// assert_eq!(map["finish"], "");
+/// assert!(parse_locale("- not\n- a mapping\n").is_none());
+/// ```
+pub fn parse_locale(text: &str) -> Option<LocaleMap>
+{
+    let mapping: HashMap<String, serde_yaml::Value> = serde_yaml::from_str(text).ok()?;
+    Some(mapping.into_iter()
+            .map(|(key, value)| {
+                let value = match value
+                {
+                    serde_yaml::Value::String(s) => s,
+                    serde_yaml::Value::Number(n) => n.to_string(),
+                    serde_yaml::Value::Bool(b) => b.to_string(),
+                    _ => String::new(),
+                };
+                (key, value)
+            })
+            .collect())
+}
+
+// pub fn find_missing_keys(baseline: &LocaleMap, locale: &LocaleMap) -> Vec<String>
+/// Lists the keys of `baseline` that `locale` lacks or leaves blank.
+///
+/// # Arguments
+/// * `baseline` - The reference translations.
+/// * `locale` - The translations to check.
+///
+/// # Output
+/// The missing keys, sorted.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ find_missing_keys, LocaleMap };
+///
+/// let en: LocaleMap = [("back", "Back"), ("next", "Next"), ("finish", "Finish")]
+///                         .into_iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+/// let complete: LocaleMap = [("back", "Atrás"), ("next", "Siguiente"), ("finish", "Terminar")]
+///                         .into_iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+/// let partial: LocaleMap = [("next", "  "), ("back", "Atrás")]
+///                         .into_iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+///
+/// assert!(find_missing_keys(&en, &complete).is_empty());
+/// assert_eq!(find_missing_keys(&en, &partial), vec!["finish".to_string(), "next".to_string()]);
+/// ```
+pub fn find_missing_keys(baseline: &LocaleMap, locale: &LocaleMap) -> Vec<String>
+{
+    let mut missing: Vec<String> = baseline.keys()
+                                    .filter(|key| locale.get(*key).is_none_or(|value| value.trim().is_empty()))
+                                    .cloned()
+                                    .collect();
+    missing.sort();
+    missing
+}
+
+// pub fn coverage_of(locales: &HashMap<String, LocaleMap>) -> HashMap<String, Vec<String>>
+/// Checks every locale against the `BASELINE_LOCALE` among `locales`.
+///
+/// # Arguments
+/// * `locales` - The translations of each locale, keyed by locale code.
+///
+/// # Output
+/// The missing keys of each locale other than the baseline. If the baseline
+/// itself is absent, nothing can be compared and the result is empty.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use qrate_gui::{ coverage_of, LocaleMap };
+///
+/// let map = |pairs: &[(&str, &str)]| -> LocaleMap {
+///     pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+/// };
+/// let mut locales = HashMap::new();
+/// locales.insert("en".to_string(), map(&[("back", "Back"), ("next", "Next")]));
+/// locales.insert("ko".to_string(), map(&[("back", "뒤로"), ("next", "다음")]));
+/// locales.insert("es".to_string(), map(&[("back", "Atrás")]));
+///
+/// let coverage = coverage_of(&locales);
+/// assert_eq!(coverage.len(), 2);
+/// assert!(coverage["ko"].is_empty());
+/// assert_eq!(coverage["es"], vec!["next".to_string()]);
+/// ```
+pub fn coverage_of(locales: &HashMap<String, LocaleMap>) -> HashMap<String, Vec<String>>
+{
+    let Some(baseline) = locales.get(BASELINE_LOCALE)
+        else { return HashMap::new(); };
+    locales.iter()
+        .filter(|(code, _)| code.as_str() != BASELINE_LOCALE)
+        .map(|(code, locale)| (code.clone(), find_missing_keys(baseline, locale)))
+        .collect()
+}
+
+// pub fn i18n_coverage() -> HashMap<String, Vec<String>>
+/// Reports the keys each bundled locale still lacks relative to `en`.
+///
+/// The locale files bundled from `assets/locales` are checked. A file that
+/// cannot be parsed is reported as missing every key.
+///
+/// # Output
+/// The missing keys of each locale other than `en`, sorted.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::i18n_coverage;
+/// let coverage = i18n_coverage();
+/// assert!(coverage.contains_key("ko"));
+/// assert!(coverage.contains_key("ru"));
+/// for (locale, missing) in &coverage
+///     { assert!(missing.is_empty(), "{} lacks {:?}", locale, missing); }
+/// ```
+pub fn i18n_coverage() -> HashMap<String, Vec<String>>
+{
+    let locales = LOCALES_DIR.files()
+                    .filter_map(|file| {
+                        let code = file.path().file_name()?.to_str()?.strip_suffix(".yml")?.to_string();
+                        let map = file.contents_utf8().and_then(parse_locale).unwrap_or_default();
+                        Some((code, map))
+                    })
+                    .collect();
+    coverage_of(&locales)
+}
+
+// pub fn get_language_name(locale: &str) -> String
+/// Returns the name of a language in that language, as listed on the
+/// language page.
+///
+/// # Arguments
+/// * `locale` - The locale code.
+///
+/// # Output
+/// The language name, or the code itself for an unknown locale.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::get_language_name;
+/// assert_eq!(get_language_name("ko"), "한국어");
+/// assert_eq!(get_language_name("xx"), "xx");
+/// ```
+pub fn get_language_name(locale: &str) -> String
+{
+    match locale
+    {
+        "en" => "English".to_string(),
+        "ko" => "한국어".to_string(),
+        "ru" => "Русский".to_string(),
+        _ => locale.to_string(),
+    }
+}
+
+// pub fn locales_from_file_names<'a>(file_names: impl IntoIterator<Item = &'a str>) -> Vec<(String, String)>
+/// Lists the locales offered on the language page from the names of the
+/// locale files found.
+///
+/// The `BASELINE_LOCALE` is always offered, because `rust-i18n` falls back
+/// to it, so that a language can be picked even if no file was found.
+///
+/// # Arguments
+/// * `file_names` - The file names in the locale directory; names not ending
+///   in `.yml` are skipped.
+///
+/// # Output
+/// The language name and the locale code of each locale.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::locales_from_file_names;
+/// assert_eq!(locales_from_file_names([]), vec![("English".to_string(), "en".to_string())]);
+///
+/// let locales = locales_from_file_names(["ko.yml", "README.md", "en.yml"]);
+/// assert_eq!(locales, vec![("한국어".to_string(), "ko".to_string()), ("English".to_string(), "en".to_string())]);
+///
+/// let locales = locales_from_file_names(["ru.yml"]);
+/// assert_eq!(locales.len(), 2);
+/// assert!(locales.iter().any(|(_, code)| code == "en"));
+/// ```
+pub fn locales_from_file_names<'a>(file_names: impl IntoIterator<Item = &'a str>) -> Vec<(String, String)>
+{
+    let mut locales: Vec<(String, String)> = file_names.into_iter()
+                                                .filter_map(|file_name| file_name.strip_suffix(".yml"))
+                                                .map(|locale| (get_language_name(locale), locale.to_string()))
+                                                .collect();
+    if !locales.iter().any(|(_, locale)| locale == BASELINE_LOCALE)
+        { locales.push((get_language_name(BASELINE_LOCALE), BASELINE_LOCALE.to_string())); }
+    locales
+}
+
+// pub fn next_locale(locales: &[(String, String)], current: &str) -> Option<String>
+/// Returns the locale after `current` in `locales`, wrapping around after
+/// the last one, for switching the language with a shortcut.
+///
+/// # Arguments
+/// * `locales` - The language names and locale codes, as listed by
+///   `locales_from_file_names`.
+/// * `current` - The locale code in use; an unlisted one starts the cycle
+///   at the first locale.
+///
+/// # Output
+/// The next locale code, or `None` if `locales` is empty.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ locales_from_file_names, next_locale };
+/// let locales = locales_from_file_names(["en.yml", "ko.yml", "ru.yml"]);
+/// assert_eq!(next_locale(&locales, "en").as_deref(), Some("ko"));
+/// assert_eq!(next_locale(&locales, "ko").as_deref(), Some("ru"));
+/// assert_eq!(next_locale(&locales, "ru").as_deref(), Some("en"));
+/// assert_eq!(next_locale(&locales, "xx").as_deref(), Some("en"));
+/// assert_eq!(next_locale(&[], "en"), None);
+/// ```
+pub fn next_locale(locales: &[(String, String)], current: &str) -> Option<String>
+{
+    let next = locales.iter()
+                    .position(|(_, locale)| locale == current)
+                    .map_or(0, |index| (index + 1) % locales.len());
+    locales.get(next).map(|(_, locale)| locale.clone())
+}
@@ -0,0 +1,71 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use iced::time::{ Duration, Instant };
+
+/// The idle periods offered before the screen locks, in minutes.
+pub const IDLE_LOCK_MINUTES: [u64; 5] = [ 1, 5, 10, 15, 30 ];
+
+// pub fn hash_pin(pin: &str, salt: Option<u64>) -> u64
+/// Hashes a PIN so that the settings file does not hold it in plain text.
+///
+/// The hash is FNV-1a over the salt and the PIN, which stays the same
+/// across builds and platforms, so a PIN set with one version of the
+/// program still unlocks the next. The salt keeps equal PINs from having
+/// equal hashes in different settings files.
+///
+/// The idle lock is a deterrent against a passer-by, not protection of the
+/// data: a short PIN is found from its hash in moments, and anyone who may
+/// edit the settings file may remove the PIN altogether.
+///
+/// # Arguments
+/// * `pin` - The PIN as typed.
+/// * `salt` - The salt kept with the hash, or `None` for a PIN set before
+///   salts were kept.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::hash_pin;
+/// assert_eq!(hash_pin("1234", Some(7)), hash_pin("1234", Some(7)));
+/// assert_ne!(hash_pin("1234", Some(7)), hash_pin("4321", Some(7)));
+/// assert_ne!(hash_pin("1234", Some(7)), hash_pin("1234", Some(8)));
+/// assert_eq!(hash_pin("", None), 0xcbf29ce484222325);
+/// ```
+pub fn hash_pin(pin: &str, salt: Option<u64>) -> u64
+{
+    salt.map(u64::to_le_bytes)
+        .into_iter()
+        .flatten()
+        .chain(pin.bytes())
+        .fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+// pub fn is_idle(last_activity: Instant, now: Instant, minutes: u64) -> bool
+/// Returns whether nothing happened for `minutes` minutes up to `now`.
+///
+/// # Arguments
+/// * `last_activity` - When the user last did something.
+/// * `now` - The current time.
+/// * `minutes` - The idle period.
+///
+/// # Examples
+/// ```
+/// use iced::time::{ Duration, Instant };
+/// use qrate_gui::is_idle;
+/// let start = Instant::now();
+/// assert!(!is_idle(start, start + Duration::from_secs(299), 5));
+/// assert!(is_idle(start, start + Duration::from_secs(300), 5));
+/// // A clock that went backwards is never idle.
+/// assert!(!is_idle(start + Duration::from_secs(600), start, 5));
+/// ```
+pub fn is_idle(last_activity: Instant, now: Instant, minutes: u64) -> bool
+{
+    now.saturating_duration_since(last_activity) >= Duration::from_secs(minutes * 60)
+}
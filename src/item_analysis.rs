@@ -0,0 +1,286 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{ Path, PathBuf };
+
+use rust_i18n::t;
+use rust_xlsxwriter::Workbook;
+
+use crate::{ ExportError, GradeReport };
+
+/// The discrimination index below which a question is flagged for review.
+pub const LOW_DISCRIMINATION: f32 = 0.2;
+
+/// The scores of one graded sitting, from `0.0` to `1.0`, keyed by question id.
+pub type Attempt = BTreeMap<u16, f32>;
+
+// pub fn to_attempt(report: &GradeReport) -> Attempt
+/// Keeps the score of every question of a graded sitting for item analysis.
+///
+/// # Arguments
+/// * `report` - The graded sitting.
+///
+/// # Examples
+/// ```
+/// use iced::time::Instant;
+/// use qrate::{ Header, Question };
+/// use qrate_gui::{ to_attempt, Exam, LearningSession, NumberingStyle };
+///
+/// let questions = (1..=2).map(|id| Question::new(id, id, 1, format!("Q{}", id), vec![("right".to_string(), true)])).collect();
+/// let exam = Exam::new(Header::new_empty(), questions, NumberingStyle::Arabic);
+/// let mut session = LearningSession::new(exam, Instant::now());
+/// session.toggle_choice(0);
+/// let attempt = to_attempt(&session.grade());
+/// assert_eq!(attempt.get(&1), Some(&1.0));
+/// assert_eq!(attempt.get(&2), Some(&0.0));
+/// ```
+pub fn to_attempt(report: &GradeReport) -> Attempt
+{
+    report.get_entries().iter().map(|entry| (entry.id, entry.score)).collect()
+}
+
+/// How one question performed over the recorded sittings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemStats
+{
+    /// The id of the question in its bank.
+    pub id: u16,
+
+    /// The number of sittings that included the question.
+    pub attempts: usize,
+
+    /// The average score, from `0.0` when nobody got it right to `1.0`
+    /// when everybody did; the classical P-value.
+    pub difficulty_index: f32,
+
+    /// The correlation of the question score with the score on the rest of
+    /// the sitting, from `-1.0` to `1.0`. Good questions are answered right
+    /// mostly by those who do well overall.
+    pub discrimination_index: f32,
+}
+
+impl ItemStats
+{
+    // pub fn is_poorly_discriminating(&self) -> bool
+    /// Returns whether the question tells strong and weak students apart
+    /// too little, which often means it is too easy, too hard or flawed.
+    ///
+    /// # Examples
+    /// See `item_analysis`.
+    pub fn is_poorly_discriminating(&self) -> bool
+    {
+        self.discrimination_index < LOW_DISCRIMINATION
+    }
+}
+
+// pub fn item_analysis(attempts: &[Attempt]) -> Vec<ItemStats>
+/// Computes the difficulty and discrimination indices of every question
+/// that appears in `attempts`.
+///
+/// The discrimination index is the corrected item-total correlation: the
+/// Pearson correlation of the question score with the total of the other
+/// questions of each sitting, which is the point-biserial correlation for
+/// questions scored right or wrong. It is `0.0` when either score does not
+/// vary, such as for a question everybody got right.
+///
+/// # Arguments
+/// * `attempts` - The graded sittings.
+///
+/// # Output
+/// The statistics of each question, by ascending id.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ item_analysis, Attempt };
+///
+/// let sittings = [
+///     [1.0, 1.0, 1.0, 1.0, 0.0],
+///     [1.0, 1.0, 1.0, 1.0, 0.0],
+///     [1.0, 1.0, 0.0, 1.0, 1.0],
+///     [1.0, 0.0, 0.0, 0.0, 1.0],
+///     [1.0, 0.0, 1.0, 0.0, 1.0],
+///     [1.0, 0.0, 0.0, 0.0, 1.0],
+/// ];
+/// let attempts: Vec<Attempt> = sittings.iter()
+///     .map(|scores| (1..).zip(scores.iter().copied()).collect())
+///     .collect();
+/// let stats = item_analysis(&attempts);
+/// assert_eq!(stats.len(), 5);
+/// assert_eq!(stats[0].attempts, 6);
+///
+/// // Everybody got the first question right: easy, and telling nobody apart.
+/// assert_eq!(stats[0].difficulty_index, 1.0);
+/// assert_eq!(stats[0].discrimination_index, 0.0);
+/// assert!(stats[0].is_poorly_discriminating());
+///
+/// // The second is answered right by the strong students.
+/// assert_eq!(stats[1].difficulty_index, 0.5);
+/// assert!(stats[1].discrimination_index > 0.7);
+/// assert!(!stats[1].is_poorly_discriminating());
+///
+/// // The third is answered right regardless of how students did otherwise.
+/// assert!(stats[2].discrimination_index.abs() < 1e-6);
+/// assert!(stats[2].is_poorly_discriminating());
+///
+/// // The last favors the weak students, which hints at a wrong answer key.
+/// assert!(stats[4].discrimination_index < -0.8);
+/// assert!(stats[4].is_poorly_discriminating());
+///
+/// assert!(item_analysis(&[]).is_empty());
+/// ```
+pub fn item_analysis(attempts: &[Attempt]) -> Vec<ItemStats>
+{
+    let mut pairs: BTreeMap<u16, Vec<(f32, f32)>> = BTreeMap::new();
+    for attempt in attempts
+    {
+        let total: f32 = attempt.values().sum();
+        for (&id, &score) in attempt
+            { pairs.entry(id).or_default().push((score, total - score)); }
+    }
+    pairs.into_iter()
+        .map(|(id, pairs)| {
+            let count = pairs.len() as f32;
+            let difficulty_index = pairs.iter().map(|&(score, _)| score).sum::<f32>() / count;
+            ItemStats { id, attempts: pairs.len(), difficulty_index, discrimination_index: correlation(&pairs) }
+        })
+        .collect()
+}
+
+/// The Pearson correlation of the pairs, or `0.0` if either side is constant.
+fn correlation(pairs: &[(f32, f32)]) -> f32
+{
+    let count = pairs.len() as f32;
+    let mean_x = pairs.iter().map(|&(x, _)| x).sum::<f32>() / count;
+    let mean_y = pairs.iter().map(|&(_, y)| y).sum::<f32>() / count;
+    let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+    for &(x, y) in pairs
+    {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x) * (x - mean_x);
+        variance_y += (y - mean_y) * (y - mean_y);
+    }
+    if variance_x <= f32::EPSILON || variance_y <= f32::EPSILON
+        { return 0.0; }
+    covariance / (variance_x * variance_y).sqrt()
+}
+
+// pub fn get_attempts_path(bank_path: &Path) -> PathBuf
+/// Returns the path of the file keeping the graded sittings of a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+///
+/// # Examples
+/// ```
+/// use std::path::{ Path, PathBuf };
+/// use qrate_gui::get_attempts_path;
+/// assert_eq!(get_attempts_path(Path::new("/banks/math.qbdb")), PathBuf::from("/banks/math.qbdb.attempts.json"));
+/// ```
+pub fn get_attempts_path(bank_path: &Path) -> PathBuf
+{
+    let mut name = bank_path.as_os_str().to_owned();
+    name.push(".attempts.json");
+    PathBuf::from(name)
+}
+
+// pub fn load_attempts(bank_path: &Path) -> Vec<Attempt>
+/// Loads the graded sittings of a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+///
+/// # Output
+/// The stored sittings, or none if the file is missing or cannot be parsed.
+///
+/// # Examples
+/// See `save_attempts`.
+pub fn load_attempts(bank_path: &Path) -> Vec<Attempt>
+{
+    fs::read_to_string(get_attempts_path(bank_path))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+// pub fn save_attempts(bank_path: &Path, attempts: &[Attempt]) -> io::Result<()>
+/// Saves the graded sittings of a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+/// * `attempts` - The sittings to store.
+///
+/// # Output
+/// An `io::Result` reporting write failures.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use qrate_gui::{ load_attempts, save_attempts, Attempt };
+///
+/// let attempt: Attempt = [(1, 1.0), (2, 0.5)].into_iter().collect();
+/// let bank_path = std::env::temp_dir().join("qrate_gui_save_attempts.qbdb");
+/// save_attempts(&bank_path, &[attempt.clone()]).unwrap();
+/// assert_eq!(load_attempts(&bank_path), vec![attempt]);
+/// assert!(load_attempts(Path::new("/definitely/not/here.qbdb")).is_empty());
+/// ```
+pub fn save_attempts(bank_path: &Path, attempts: &[Attempt]) -> io::Result<()>
+{
+    let json = serde_json::to_string_pretty(attempts).map_err(io::Error::other)?;
+    fs::write(get_attempts_path(bank_path), json)
+}
+
+// pub fn export_item_analysis(stats: &[ItemStats], path: &Path) -> Result<(), ExportError>
+/// Writes the item-analysis report to an Excel workbook, one question per
+/// row, with the questions that discriminate poorly flagged.
+///
+/// # Arguments
+/// * `stats` - The statistics from `item_analysis`.
+/// * `path` - The target file path, usually ending in `.xlsx`.
+///
+/// # Output
+/// `Ok(())` on success, or `ExportError::Write`.
+///
+/// # Examples
+/// ```
+/// use calamine::{ Reader, open_workbook_auto };
+/// use qrate_gui::{ export_item_analysis, ItemStats };
+///
+/// rust_i18n::set_locale("en");
+/// let stats = [ItemStats { id: 3, attempts: 10, difficulty_index: 0.95, discrimination_index: 0.05 }];
+/// let path = std::env::temp_dir().join("qrate_gui_item_analysis.xlsx");
+/// export_item_analysis(&stats, &path).unwrap();
+/// let mut workbook = open_workbook_auto(&path).unwrap();
+/// let range = workbook.worksheet_range_at(0).unwrap().unwrap();
+/// assert_eq!(range.get_value((0, 0)).unwrap().to_string(), "ID");
+/// assert_eq!(range.get_value((1, 0)).unwrap().to_string(), "3");
+/// assert_eq!(range.get_value((1, 4)).unwrap().to_string(), "Review");
+/// ```
+pub fn export_item_analysis(stats: &[ItemStats], path: &Path) -> Result<(), ExportError>
+{
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    let headers = [ "column-id", "column-attempts", "difficulty-index", "discrimination-index", "item-flag" ];
+    for (column, key) in (0..).zip(headers)
+        { worksheet.write_string(0, column, t!(key)).map_err(|e| ExportError::Write(e.to_string()))?; }
+    for (row, item) in (1..).zip(stats)
+    {
+        let flag = if item.is_poorly_discriminating() { t!("item-review") } else { t!("item-ok") };
+        worksheet.write_number(row, 0, item.id)
+            .and_then(|ws| ws.write_number(row, 1, item.attempts as f64))
+            .and_then(|ws| ws.write_number(row, 2, item.difficulty_index))
+            .and_then(|ws| ws.write_number(row, 3, item.discrimination_index))
+            .and_then(|ws| ws.write_string(row, 4, flag))
+            .map_err(|e| ExportError::Write(e.to_string()))?;
+    }
+    workbook.save(path).map_err(|e| ExportError::Write(e.to_string()))
+}
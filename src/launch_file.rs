@@ -0,0 +1,45 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+// pub fn get_launch_file(args: impl IntoIterator<Item = OsString>) -> Option<PathBuf>
+/// Finds the file the application was asked to open on the command line,
+/// as when a question bank associated with it is double-clicked.
+///
+/// # Arguments
+/// * `args` - The command line, starting with the program name, as given by
+///   `std::env::args_os`.
+///
+/// # Output
+/// The first argument after the program name that is not an option, or
+/// `None` if there is none. Options, which start with `-`, are skipped since
+/// some desktops add their own.
+///
+/// # Examples
+/// ```
+/// use std::ffi::OsString;
+/// use std::path::PathBuf;
+/// use qrate_gui::get_launch_file;
+///
+/// let args = |line: &[&str]| line.iter().map(OsString::from).collect::<Vec<_>>();
+/// assert_eq!(get_launch_file(args(&["qrate-gui", "/banks/math.qbdb"])), Some(PathBuf::from("/banks/math.qbdb")));
+/// assert_eq!(get_launch_file(args(&["qrate-gui", "-psn_0_1234", "math.qbdb"])), Some(PathBuf::from("math.qbdb")));
+/// assert_eq!(get_launch_file(args(&["qrate-gui"])), None);
+/// assert_eq!(get_launch_file(args(&[])), None);
+/// ```
+pub fn get_launch_file(args: impl IntoIterator<Item = OsString>) -> Option<PathBuf>
+{
+    args.into_iter()
+        .skip(1)
+        .find(|arg| !arg.to_string_lossy().starts_with('-'))
+        .map(PathBuf::from)
+}
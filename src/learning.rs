@@ -0,0 +1,928 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::{ Path, PathBuf };
+use std::time::Duration;
+
+use iced::time::Instant;
+use qrate::{ Header, Question };
+use serde::{ Serialize, Deserialize };
+
+use crate::{ Exam, GradingMode, NumberingStyle, NumericTolerance, QuestionKind, QuestionMetas, format_number, grade_answer, grade_typed_answer };
+
+/// A self-study session in which a student answers the questions of an
+/// `Exam` one at a time.
+#[derive(Debug, Clone)]
+pub struct LearningSession
+{
+    exam: Exam,
+    current: usize,
+    answers: Vec<Vec<usize>>,
+    typed_answers: Vec<String>,
+    time_spent: Vec<Duration>,
+    last_tick: Option<Instant>,
+    finished: bool,
+    grading_mode: GradingMode,
+    numeric_tolerance: NumericTolerance,
+    flagged: BTreeSet<usize>,
+}
+
+impl LearningSession
+{
+    // pub fn new(exam: Exam, now: Instant) -> Self
+    /// Starts a session on the first question of `exam`.
+    ///
+    /// # Arguments
+    /// * `exam` - The questions to answer.
+    /// * `now` - The time the session starts.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty()], NumberingStyle::Arabic);
+    /// let session = LearningSession::new(exam, Instant::now());
+    /// assert_eq!(session.get_current(), 0);
+    /// assert!(!session.is_finished());
+    /// ```
+    pub fn new(exam: Exam, now: Instant) -> Self
+    {
+        let count = exam.get_questions().len();
+        Self
+        {
+            exam,
+            current: 0,
+            answers: vec![Vec::new(); count],
+            typed_answers: vec![String::new(); count],
+            time_spent: vec![Duration::ZERO; count],
+            last_tick: Some(now),
+            finished: false,
+            grading_mode: GradingMode::default(),
+            numeric_tolerance: NumericTolerance::default(),
+            flagged: BTreeSet::new(),
+        }
+    }
+
+    // pub fn get_exam(&self) -> &Exam
+    /// Returns the exam being studied.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// let session = LearningSession::new(exam, Instant::now());
+    /// assert!(session.get_exam().get_questions().is_empty());
+    /// ```
+    pub fn get_exam(&self) -> &Exam
+    {
+        &self.exam
+    }
+
+    // pub fn get_grading_mode(&self) -> GradingMode
+    /// Returns how multiple-select questions are scored.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, GradingMode, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// let session = LearningSession::new(exam, Instant::now());
+    /// assert_eq!(session.get_grading_mode(), GradingMode::AllOrNothing);
+    /// ```
+    pub fn get_grading_mode(&self) -> GradingMode
+    {
+        self.grading_mode
+    }
+
+    // pub fn set_grading_mode(&mut self, mode: GradingMode)
+    /// Sets how multiple-select questions are scored.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, GradingMode, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// let mut session = LearningSession::new(exam, Instant::now());
+    /// session.set_grading_mode(GradingMode::PartialCredit);
+    /// assert_eq!(session.get_grading_mode(), GradingMode::PartialCredit);
+    /// ```
+    pub fn set_grading_mode(&mut self, mode: GradingMode)
+    {
+        self.grading_mode = mode;
+    }
+
+    // pub fn get_numeric_tolerance(&self) -> NumericTolerance
+    /// Returns how far typed numbers may be from the correct ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle, NumericTolerance, QuestionKind, QuestionMeta, QuestionMetas };
+    ///
+    /// let question = Question::new(1, 1, 1, "g = ?".to_string(), vec![("9.81".to_string(), true)]);
+    /// let mut meta = QuestionMeta::new();
+    /// meta.set_kind(QuestionKind::Number);
+    /// let mut exam = Exam::new(Header::new_empty(), vec![question], NumberingStyle::Arabic);
+    /// exam.set_question_metas(QuestionMetas::from([(1, meta)]));
+    ///
+    /// let mut session = LearningSession::new(exam, Instant::now());
+    /// assert_eq!(session.get_numeric_tolerance(), NumericTolerance::default());
+    /// session.set_typed_answer("9.8".to_string());
+    /// assert_eq!(session.grade().get_total(), 0.0);
+    /// session.set_numeric_tolerance(NumericTolerance::new(0.0, 1.0));
+    /// assert_eq!(session.grade().get_total(), 1.0);
+    /// ```
+    pub fn get_numeric_tolerance(&self) -> NumericTolerance
+    {
+        self.numeric_tolerance
+    }
+
+    // pub fn set_numeric_tolerance(&mut self, tolerance: NumericTolerance)
+    /// Sets how far typed numbers may be from the correct ones.
+    ///
+    /// # Arguments
+    /// * `tolerance` - The absolute and percentage tolerance.
+    ///
+    /// # Examples
+    /// See `LearningSession::get_numeric_tolerance`.
+    pub fn set_numeric_tolerance(&mut self, tolerance: NumericTolerance)
+    {
+        self.numeric_tolerance = tolerance;
+    }
+
+    // pub fn get_kind(&self, index: usize) -> QuestionKind
+    /// Returns the answer format of the question at `index`.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle, QuestionKind };
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty()], NumberingStyle::Arabic);
+    /// let session = LearningSession::new(exam, Instant::now());
+    /// assert_eq!(session.get_kind(0), QuestionKind::SingleChoice);
+    /// ```
+    pub fn get_kind(&self, index: usize) -> QuestionKind
+    {
+        self.exam.get_questions()
+            .get(index)
+            .and_then(|question| self.exam.get_question_metas().get(&question.get_id()))
+            .map(|meta| meta.get_kind())
+            .unwrap_or_default()
+    }
+
+    // pub fn get_current(&self) -> usize
+    /// Returns the 0-based index of the question being answered.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty(), Question::new_empty()], NumberingStyle::Arabic);
+    /// let mut session = LearningSession::new(exam, Instant::now());
+    /// session.go_to(1, Instant::now());
+    /// assert_eq!(session.get_current(), 1);
+    /// ```
+    pub fn get_current(&self) -> usize
+    {
+        self.current
+    }
+
+    // pub fn get_answer(&self, index: usize) -> &[usize]
+    /// Returns the 0-based choice indices selected for the question at `index`.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty()], NumberingStyle::Arabic);
+    /// let session = LearningSession::new(exam, Instant::now());
+    /// assert!(session.get_answer(0).is_empty());
+    /// ```
+    pub fn get_answer(&self, index: usize) -> &[usize]
+    {
+        self.answers.get(index).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    // pub fn get_typed_answer(&self, index: usize) -> &str
+    /// Returns the answer typed for the question at `index`.
+    ///
+    /// # Examples
+    /// See `LearningSession::set_typed_answer`.
+    pub fn get_typed_answer(&self, index: usize) -> &str
+    {
+        self.typed_answers.get(index).map(String::as_str).unwrap_or("")
+    }
+
+    // pub fn set_typed_answer(&mut self, answer: String)
+    /// Replaces the typed answer of the current question, unless the
+    /// input widget of its kind rejects it.
+    ///
+    /// # Arguments
+    /// * `answer` - The whole text of the input widget.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ AnswerInput, Exam, LearningSession, NumberingStyle, QuestionKind, QuestionMeta, QuestionMetas };
+    ///
+    /// let question = Question::new(1, 1, 1, "Pi to four decimals?".to_string(), vec![("3.1416".to_string(), true)]);
+    /// let mut meta = QuestionMeta::new();
+    /// meta.set_kind(QuestionKind::Number);
+    /// let mut metas = QuestionMetas::new();
+    /// metas.insert(1, meta);
+    /// let mut exam = Exam::new(Header::new_empty(), vec![question], NumberingStyle::Arabic);
+    /// exam.set_question_metas(metas);
+    ///
+    /// let mut session = LearningSession::new(exam, Instant::now());
+    /// assert_eq!(session.get_kind(0).get_input(), AnswerInput::Numeric);
+    ///
+    /// session.set_typed_answer("3.14159".to_string());
+    /// session.set_typed_answer("3.14159x".to_string());
+    /// assert_eq!(session.get_typed_answer(0), "3.14159");
+    /// assert_eq!(session.grade().get_total(), 1.0);
+    ///
+    /// session.set_typed_answer("3.2".to_string());
+    /// assert_eq!(session.grade().get_total(), 0.0);
+    /// ```
+    pub fn set_typed_answer(&mut self, answer: String)
+    {
+        if self.finished || !self.get_kind(self.current).get_input().accepts(&answer)
+            { return; }
+        if let Some(typed) = self.typed_answers.get_mut(self.current)
+            { *typed = answer; }
+    }
+
+    // pub fn get_time_spent(&self, index: usize) -> Duration
+    /// Returns the time accumulated on the question at `index`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty()], NumberingStyle::Arabic);
+    /// let session = LearningSession::new(exam, Instant::now());
+    /// assert_eq!(session.get_time_spent(0), Duration::ZERO);
+    /// ```
+    pub fn get_time_spent(&self, index: usize) -> Duration
+    {
+        self.time_spent.get(index).copied().unwrap_or_default()
+    }
+
+    // pub fn is_finished(&self) -> bool
+    /// Returns whether the session has been handed in.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// let mut session = LearningSession::new(exam, Instant::now());
+    /// session.finish(Instant::now());
+    /// assert!(session.is_finished());
+    /// ```
+    pub fn is_finished(&self) -> bool
+    {
+        self.finished
+    }
+
+    // pub fn tick(&mut self, now: Instant)
+    /// Adds the time elapsed since the previous tick to the current question.
+    ///
+    /// # Arguments
+    /// * `now` - The time of this tick.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    ///
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty(), Question::new_empty()], NumberingStyle::Arabic);
+    /// let start = Instant::now();
+    /// let mut session = LearningSession::new(exam, start);
+    ///
+    /// // Ticks accrue to the current question.
+    /// session.tick(start + Duration::from_secs(3));
+    /// session.tick(start + Duration::from_secs(5));
+    /// assert_eq!(session.get_time_spent(0), Duration::from_secs(5));
+    ///
+    /// // After navigating away, question 0 stops accruing.
+    /// session.go_to(1, start + Duration::from_secs(6));
+    /// session.tick(start + Duration::from_secs(10));
+    /// assert_eq!(session.get_time_spent(0), Duration::from_secs(6));
+    /// assert_eq!(session.get_time_spent(1), Duration::from_secs(4));
+    ///
+    /// // Coming back accumulates instead of restarting.
+    /// session.go_to(0, start + Duration::from_secs(11));
+    /// session.tick(start + Duration::from_secs(13));
+    /// assert_eq!(session.get_time_spent(0), Duration::from_secs(8));
+    /// assert_eq!(session.get_time_spent(1), Duration::from_secs(5));
+    /// ```
+    pub fn tick(&mut self, now: Instant)
+    {
+        if self.finished
+            { return; }
+        let Some(last) = self.last_tick
+            else { return; };
+        if let Some(spent) = self.time_spent.get_mut(self.current)
+            { *spent += now.saturating_duration_since(last); }
+        self.last_tick = Some(now);
+    }
+
+    // pub fn pause_timer(&mut self, now: Instant)
+    /// Stops the clock while the learner is away from the session, charging
+    /// the time until `now` to the current question. Ticks and navigation
+    /// add no time until `resume_timer`.
+    ///
+    /// # Arguments
+    /// * `now` - The time the learner leaves.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    ///
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty(), Question::new_empty()], NumberingStyle::Arabic);
+    /// let start = Instant::now();
+    /// let mut session = LearningSession::new(exam, start);
+    /// session.pause_timer(start + Duration::from_secs(2));
+    /// assert!(session.is_timer_paused());
+    ///
+    /// // The time away is not charged to any question.
+    /// session.tick(start + Duration::from_secs(60));
+    /// session.go_to(1, start + Duration::from_secs(61));
+    /// session.resume_timer(start + Duration::from_secs(100));
+    /// session.tick(start + Duration::from_secs(103));
+    /// assert_eq!(session.get_time_spent(0), Duration::from_secs(2));
+    /// assert_eq!(session.get_time_spent(1), Duration::from_secs(3));
+    /// ```
+    pub fn pause_timer(&mut self, now: Instant)
+    {
+        self.tick(now);
+        self.last_tick = None;
+    }
+
+    // pub fn resume_timer(&mut self, now: Instant)
+    /// Starts the clock again from `now` after `pause_timer`.
+    ///
+    /// # Arguments
+    /// * `now` - The time the learner comes back.
+    ///
+    /// # Examples
+    /// See `LearningSession::pause_timer`.
+    pub fn resume_timer(&mut self, now: Instant)
+    {
+        if self.last_tick.is_none()
+            { self.last_tick = Some(now); }
+    }
+
+    // pub fn is_timer_paused(&self) -> bool
+    /// Returns whether the clock is stopped by `pause_timer`.
+    ///
+    /// # Examples
+    /// See `LearningSession::pause_timer`.
+    pub fn is_timer_paused(&self) -> bool
+    {
+        self.last_tick.is_none()
+    }
+
+    // pub fn go_to(&mut self, index: usize, now: Instant)
+    /// Moves to the question at `index`, charging the elapsed time to the
+    /// question being left. Out-of-range indices are ignored.
+    ///
+    /// # Arguments
+    /// * `index` - The 0-based index of the next question.
+    /// * `now` - The time of the navigation.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty()], NumberingStyle::Arabic);
+    /// let mut session = LearningSession::new(exam, Instant::now());
+    /// session.go_to(5, Instant::now());
+    /// assert_eq!(session.get_current(), 0);
+    /// ```
+    pub fn go_to(&mut self, index: usize, now: Instant)
+    {
+        if index >= self.answers.len()
+            { return; }
+        self.tick(now);
+        self.current = index;
+    }
+
+    // pub fn toggle_choice(&mut self, choice: usize)
+    /// Selects or deselects a choice of the current question. Unless the
+    /// question is multiple-select, selecting a choice replaces the previous one.
+    ///
+    /// # Arguments
+    /// * `choice` - The 0-based index of the choice.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty()], NumberingStyle::Arabic);
+    /// let mut session = LearningSession::new(exam, Instant::now());
+    /// session.toggle_choice(2);
+    /// assert_eq!(session.get_answer(0), &[2]);
+    /// session.toggle_choice(2);
+    /// assert!(session.get_answer(0).is_empty());
+    /// session.toggle_choice(1);
+    /// session.toggle_choice(3);
+    /// assert_eq!(session.get_answer(0), &[3]);
+    /// ```
+    pub fn toggle_choice(&mut self, choice: usize)
+    {
+        if self.finished
+            { return; }
+        let multiple = self.get_kind(self.current).allows_multiple();
+        if let Some(answer) = self.answers.get_mut(self.current)
+        {
+            match answer.iter().position(|&c| c == choice)
+            {
+                Some(position) => { answer.remove(position); },
+                None if multiple => { answer.push(choice); answer.sort_unstable(); },
+                None => *answer = vec![choice],
+            }
+        }
+    }
+
+    // pub fn toggle_flag(&mut self, index: usize)
+    /// Flags the question at `index` for review, or removes its flag.
+    /// Out-of-range indices are ignored.
+    ///
+    /// # Arguments
+    /// * `index` - The 0-based index of the question.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty(); 3], NumberingStyle::Arabic);
+    /// let mut session = LearningSession::new(exam, Instant::now());
+    /// session.toggle_flag(2);
+    /// session.toggle_flag(0);
+    /// session.toggle_flag(7);
+    /// assert!(session.is_flagged(2));
+    /// assert_eq!(session.get_flagged(), vec![0, 2]);
+    /// session.toggle_flag(2);
+    /// assert!(!session.is_flagged(2));
+    /// assert_eq!(session.get_flagged(), vec![0]);
+    /// ```
+    pub fn toggle_flag(&mut self, index: usize)
+    {
+        if index >= self.answers.len()
+            { return; }
+        if !self.flagged.remove(&index)
+            { self.flagged.insert(index); }
+    }
+
+    // pub fn is_flagged(&self, index: usize) -> bool
+    /// Returns whether the question at `index` is flagged for review.
+    ///
+    /// # Examples
+    /// See `LearningSession::toggle_flag`.
+    pub fn is_flagged(&self, index: usize) -> bool
+    {
+        self.flagged.contains(&index)
+    }
+
+    // pub fn get_flagged(&self) -> Vec<usize>
+    /// Returns the 0-based indices of the questions flagged for review, in
+    /// exam order.
+    ///
+    /// # Examples
+    /// See `LearningSession::toggle_flag`.
+    pub fn get_flagged(&self) -> Vec<usize>
+    {
+        self.flagged.iter().copied().collect()
+    }
+
+    // pub fn finish(&mut self, now: Instant)
+    /// Hands the session in; time stops accruing.
+    ///
+    /// # Arguments
+    /// * `now` - The time the session ends.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![Question::new_empty()], NumberingStyle::Arabic);
+    /// let start = Instant::now();
+    /// let mut session = LearningSession::new(exam, start);
+    /// session.finish(start + Duration::from_secs(2));
+    /// session.tick(start + Duration::from_secs(9));
+    /// assert_eq!(session.get_time_spent(0), Duration::from_secs(2));
+    /// ```
+    pub fn finish(&mut self, now: Instant)
+    {
+        self.tick(now);
+        self.finished = true;
+    }
+
+    // pub fn grade(&self) -> GradeReport
+    /// Grades the answers given so far.
+    ///
+    /// Each question is scored with `grade_answer` using its kind and the
+    /// session's grading mode, or with `grade_typed_answer` and the session's
+    /// numeric tolerance if its answer is typed. Questions that took more
+    /// than twice the median time are marked slow.
+    ///
+    /// # Output
+    /// A `GradeReport` with one entry per question.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    ///
+    /// let choices = || vec![("right".to_string(), true), ("wrong".to_string(), false)];
+    /// let questions = (1..=3).map(|id| Question::new(id, id, 1, format!("Q{}", id), choices())).collect();
+    /// let exam = Exam::new(Header::new_empty(), questions, NumberingStyle::Arabic);
+    /// let start = Instant::now();
+    /// let mut session = LearningSession::new(exam, start);
+    ///
+    /// session.toggle_choice(0);
+    /// session.go_to(1, start + Duration::from_secs(10));
+    /// session.toggle_choice(1);
+    /// session.go_to(2, start + Duration::from_secs(60));
+    /// session.finish(start + Duration::from_secs(70));
+    ///
+    /// let report = session.grade();
+    /// assert_eq!(report.get_total(), 1.0);
+    /// assert_eq!(report.get_entries()[0].score, 1.0);
+    /// assert_eq!(report.get_entries()[1].score, 0.0);
+    /// assert!(report.get_entries()[1].slow);
+    /// assert!(!report.get_entries()[0].slow);
+    /// ```
+    pub fn grade(&self) -> GradeReport
+    {
+        let mut sorted = self.time_spent.clone();
+        sorted.sort_unstable();
+        let median = sorted.get(sorted.len() / 2).copied().unwrap_or_default();
+        let entries = self.exam.get_questions()
+                        .iter()
+                        .enumerate()
+                        .map(|(i, question)| {
+                            let kind = self.get_kind(i);
+                            let score = if kind.is_typed()
+                                { grade_typed_answer(kind, question.get_choices(), self.get_typed_answer(i), self.numeric_tolerance) }
+                            else
+                                { grade_answer(kind, question.get_choices(), self.get_answer(i), self.grading_mode) };
+                            let time_spent = self.get_time_spent(i);
+                            GradeEntry
+                            {
+                                id: question.get_id(),
+                                number: format_number(i + 1, self.exam.get_numbering_style()),
+                                score,
+                                time_spent,
+                                slow: !median.is_zero() && time_spent > median * 2,
+                            }
+                        })
+                        .collect();
+        GradeReport { entries }
+    }
+
+    // pub fn to_progress(&self) -> LearningProgress
+    /// Captures the questions, answers, time spent, flags and position of
+    /// the session, so that it can be resumed after the application closes.
+    ///
+    /// # Examples
+    /// See `LearningSession::resume`.
+    pub fn to_progress(&self) -> LearningProgress
+    {
+        let questions = self.exam.get_questions().iter()
+                            .map(|question| SavedQuestion
+                            {
+                                id: question.get_id(),
+                                group: question.get_group(),
+                                category: question.get_category(),
+                                prompt: question.get_question().clone(),
+                                choices: question.get_choices().clone(),
+                            })
+                            .collect();
+        LearningProgress
+        {
+            questions,
+            answers: self.answers.clone(),
+            typed_answers: self.typed_answers.clone(),
+            time_spent: self.time_spent.clone(),
+            current: self.current,
+            flagged: self.flagged.clone(),
+        }
+    }
+
+    // pub fn resume(progress: LearningProgress, header: Header, numbering_style: NumberingStyle, metas: QuestionMetas, now: Instant) -> Option<Self>
+    /// Continues a session captured by `LearningSession::to_progress`, with
+    /// the questions in the same order and their choices as they were shown.
+    ///
+    /// # Arguments
+    /// * `progress` - The saved progress.
+    /// * `header` - The header of the bank the questions come from.
+    /// * `numbering_style` - How the questions are numbered.
+    /// * `metas` - The question metadata of the bank, for the question kinds.
+    /// * `now` - The time the session continues.
+    ///
+    /// # Output
+    /// The session, or `None` if the progress has no questions or does not
+    /// hold together.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use iced::time::Instant;
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle, QuestionMetas };
+    ///
+    /// let choices = || vec![("right".to_string(), true), ("wrong".to_string(), false)];
+    /// let questions = (1..=3).map(|id| Question::new(id, id, 1, format!("Q{}", id), choices())).collect();
+    /// let exam = Exam::new(Header::new_empty(), questions, NumberingStyle::Arabic);
+    /// let start = Instant::now();
+    /// let mut session = LearningSession::new(exam, start);
+    /// session.toggle_choice(0);
+    /// session.go_to(1, start + Duration::from_secs(10));
+    /// session.toggle_choice(1);
+    /// session.toggle_flag(1);
+    /// session.go_to(2, start + Duration::from_secs(25));
+    ///
+    /// let progress = session.to_progress();
+    /// let later = Instant::now();
+    /// let resumed = LearningSession::resume(progress, Header::new_empty(), NumberingStyle::Arabic, QuestionMetas::new(), later).unwrap();
+    /// assert_eq!(resumed.get_current(), 2);
+    /// assert_eq!(resumed.get_answer(0), &[0]);
+    /// assert_eq!(resumed.get_answer(1), &[1]);
+    /// assert!(resumed.get_answer(2).is_empty());
+    /// assert!(resumed.is_flagged(1));
+    /// assert_eq!(resumed.get_time_spent(1), Duration::from_secs(15));
+    /// assert_eq!(resumed.get_exam().get_questions()[1].get_question(), "Q2");
+    /// assert!(!resumed.is_finished());
+    /// ```
+    pub fn resume(progress: LearningProgress, header: Header, numbering_style: NumberingStyle, metas: QuestionMetas, now: Instant) -> Option<Self>
+    {
+        let count = progress.questions.len();
+        if count == 0 || progress.answers.len() != count || progress.typed_answers.len() != count
+            || progress.time_spent.len() != count || progress.current >= count
+            { return None; }
+        let questions = progress.questions.into_iter()
+                            .map(|saved| Question::new(saved.id, saved.group, saved.category, saved.prompt, saved.choices))
+                            .collect();
+        let mut exam = Exam::new(header, questions, numbering_style);
+        exam.set_question_metas(metas);
+        let mut session = Self::new(exam, now);
+        session.answers = progress.answers;
+        session.typed_answers = progress.typed_answers;
+        session.time_spent = progress.time_spent;
+        session.current = progress.current;
+        session.flagged = progress.flagged.into_iter().filter(|&index| index < count).collect();
+        Some(session)
+    }
+}
+
+/// One question of a saved `LearningProgress`, with its choices in the
+/// order they were shown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SavedQuestion
+{
+    id: u16,
+    group: u16,
+    category: u8,
+    prompt: String,
+    choices: Vec<(String, bool)>,
+}
+
+/// The state of an unfinished `LearningSession`, kept beside its question
+/// bank while the application is closed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LearningProgress
+{
+    questions: Vec<SavedQuestion>,
+    answers: Vec<Vec<usize>>,
+    typed_answers: Vec<String>,
+    time_spent: Vec<Duration>,
+    current: usize,
+    flagged: BTreeSet<usize>,
+}
+
+impl LearningProgress
+{
+    // pub fn get_current(&self) -> usize
+    /// Returns the 0-based index of the question the student was on.
+    ///
+    /// # Examples
+    /// See `save_learning_progress`.
+    pub fn get_current(&self) -> usize
+    {
+        self.current
+    }
+
+    // pub fn get_count(&self) -> usize
+    /// Returns the number of questions of the session.
+    ///
+    /// # Examples
+    /// See `save_learning_progress`.
+    pub fn get_count(&self) -> usize
+    {
+        self.questions.len()
+    }
+}
+
+// pub fn get_learning_progress_path(bank_path: &Path) -> PathBuf
+/// Returns the path of the file keeping the unfinished self-study session
+/// of a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+///
+/// # Examples
+/// ```
+/// use std::path::{ Path, PathBuf };
+/// use qrate_gui::get_learning_progress_path;
+/// assert_eq!(get_learning_progress_path(Path::new("/banks/math.qbdb")), PathBuf::from("/banks/math.qbdb.progress.json"));
+/// ```
+pub fn get_learning_progress_path(bank_path: &Path) -> PathBuf
+{
+    let mut name = bank_path.as_os_str().to_owned();
+    name.push(".progress.json");
+    PathBuf::from(name)
+}
+
+// pub fn load_learning_progress(bank_path: &Path) -> Option<LearningProgress>
+/// Loads the unfinished self-study session of a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+///
+/// # Output
+/// The saved progress, or `None` if the file is missing or cannot be parsed.
+///
+/// # Examples
+/// See `save_learning_progress`.
+pub fn load_learning_progress(bank_path: &Path) -> Option<LearningProgress>
+{
+    fs::read_to_string(get_learning_progress_path(bank_path))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+// pub fn save_learning_progress(bank_path: &Path, progress: &LearningProgress) -> io::Result<()>
+/// Saves the unfinished self-study session of a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+/// * `progress` - The progress to store.
+///
+/// # Output
+/// An `io::Result` reporting write failures.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use iced::time::Instant;
+/// use qrate::{ Header, Question };
+/// use qrate_gui::{ discard_learning_progress, load_learning_progress, save_learning_progress, Exam, LearningSession, NumberingStyle, QuestionMetas };
+///
+/// let questions = (1..=4).map(|id| Question::new(id, id, 1, format!("Q{}", id), vec![("a".to_string(), true), ("b".to_string(), false)])).collect();
+/// let start = Instant::now();
+/// let mut session = LearningSession::new(Exam::new(Header::new_empty(), questions, NumberingStyle::Arabic), start);
+/// session.toggle_choice(1);
+/// session.go_to(2, start + Duration::from_secs(30));
+/// session.toggle_choice(0);
+///
+/// let bank_path = std::env::temp_dir().join("qrate_gui_save_learning_progress.qbdb");
+/// save_learning_progress(&bank_path, &session.to_progress()).unwrap();
+/// let progress = load_learning_progress(&bank_path).unwrap();
+/// assert_eq!((progress.get_current(), progress.get_count()), (2, 4));
+///
+/// let resumed = LearningSession::resume(progress, Header::new_empty(), NumberingStyle::Arabic, QuestionMetas::new(), Instant::now()).unwrap();
+/// assert_eq!(resumed.get_current(), 2);
+/// assert_eq!(resumed.get_answer(0), &[1]);
+/// assert_eq!(resumed.get_answer(2), &[0]);
+/// assert_eq!(resumed.get_time_spent(0), Duration::from_secs(30));
+///
+/// discard_learning_progress(&bank_path).unwrap();
+/// assert!(load_learning_progress(&bank_path).is_none());
+/// discard_learning_progress(&bank_path).unwrap();
+/// ```
+pub fn save_learning_progress(bank_path: &Path, progress: &LearningProgress) -> io::Result<()>
+{
+    let json = serde_json::to_string(progress).map_err(io::Error::other)?;
+    fs::write(get_learning_progress_path(bank_path), json)
+}
+
+// pub fn discard_learning_progress(bank_path: &Path) -> io::Result<()>
+/// Deletes the unfinished self-study session of a question bank, once it
+/// is finished or the student declines to resume it.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+///
+/// # Output
+/// An `io::Result` reporting failures other than a missing file.
+///
+/// # Examples
+/// See `save_learning_progress`.
+pub fn discard_learning_progress(bank_path: &Path) -> io::Result<()>
+{
+    match fs::remove_file(get_learning_progress_path(bank_path))
+    {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+/// The result of grading one question.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradeEntry
+{
+    /// The id of the question in its bank.
+    pub id: u16,
+
+    /// The formatted question number.
+    pub number: String,
+
+    /// The score from `0.0` to `1.0`.
+    pub score: f32,
+
+    /// The time spent on the question.
+    pub time_spent: Duration,
+
+    /// Whether the question took unusually long.
+    pub slow: bool,
+}
+
+/// The graded result of a `LearningSession`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradeReport
+{
+    entries: Vec<GradeEntry>,
+}
+
+impl GradeReport
+{
+    // pub fn get_entries(&self) -> &[GradeEntry]
+    /// Returns the graded questions, in exam order.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// assert!(LearningSession::new(exam, Instant::now()).grade().get_entries().is_empty());
+    /// ```
+    pub fn get_entries(&self) -> &[GradeEntry]
+    {
+        &self.entries
+    }
+
+    // pub fn get_total(&self) -> f32
+    /// Returns the sum of the scores.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, LearningSession, NumberingStyle };
+    /// let exam = Exam::new(Header::new_empty(), vec![], NumberingStyle::Arabic);
+    /// assert_eq!(LearningSession::new(exam, Instant::now()).grade().get_total(), 0.0);
+    /// ```
+    pub fn get_total(&self) -> f32
+    {
+        self.entries.iter().map(|entry| entry.score).sum()
+    }
+}
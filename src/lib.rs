@@ -25,10 +25,246 @@ rust_i18n::i18n!("assets/locales", fallback = "en");
 /// The core logic and state management for the Qrate-GUI application.
 mod control_tower;
 
+/// The pages of the main content area and their string ids.
+mod page;
+
 mod load_file;
 
+/// Reading bundled fixtures at startup to catch an incompatible `qrate`.
+mod self_check;
+
+/// Saving generated exams through the native file dialog.
+mod save_file;
+
+/// Exam criteria, generation and layout.
+mod exam;
+
+/// Exporters turning an `Exam` into PDF or Excel files.
+mod export;
+
+/// Study guides listing the questions of a bank by category with answers.
+mod study_guide;
+
+/// Validated editing of the student list.
+mod roster;
+
+/// A reusable modal overlay.
+mod modal;
+
+/// Question kinds and answer grading.
+mod grading;
+
+/// Self-study sessions with per-question timing and grading.
+mod learning;
+
+/// Difficulty and discrimination of questions over graded sittings.
+mod item_analysis;
+
+/// Generation without the GUI, for scripts and tests.
+mod headless;
+
+/// Batch generation and export of exam variants.
+mod variants;
+
+/// Seeded shuffling of answer choices.
+mod shuffle;
+
+/// Blank writing space after the questions of exported exams.
+mod answer_space;
+
+/// QR codes identifying printed papers and their answer keys.
+mod paper_code;
+
+/// Locale-aware font fallback chains.
+mod font_fallback;
+
+/// Keyboard focus styling for buttons.
+mod focus;
+
+/// The order in which Tab moves the keyboard focus through the window.
+mod tab_order;
+
+/// Localized buttons of the main menu and the submenus.
+mod menu_button;
+
+/// Opening folders in the file manager of the operating system.
+mod file_manager;
+
+/// View-only sorting of the questions table.
+mod question_sort;
+
+/// Splitting the questions table into pages.
+mod pagination;
+
+/// Shortened prompts in the questions table.
+mod prompt_preview;
+
+/// Deleting several questions at once, confirmed above a threshold.
+mod bulk_delete;
+
+/// Completeness checks of the locale files for translators.
+mod i18n_coverage;
+
+/// Turning panics in background tasks into error messages.
+mod panic_guard;
+
+/// Recent events and errors shown on the diagnostics page.
+mod diagnostics;
+
+/// User preferences persisted between launches.
+mod app_settings;
+
+/// Light and dark themes, optionally following the operating system.
+mod theme_choice;
+
+/// Locking the screen of an unattended computer after a period of inactivity.
+mod idle_lock;
+
+/// Tag-based filtering of the questions drawn into an exam.
+mod tag_filter;
+
+/// The categories of a bank offered as filters on the criteria page.
+mod category_filter;
+
+/// GUI-side question metadata, such as attached images, kept beside the bank.
+mod question_meta;
+
+/// Manifests of batch exports kept for record keeping.
+mod generation_summary;
+
+/// Several windows, each with its own state.
+mod workspace;
+
+/// Showing grades as points, percentages or letters.
+mod grade_display;
+
+/// Title, author, subject and description of a question bank.
+mod bank_metadata;
+
+/// Hashing a question bank to tell real edits from no-op ones.
+mod bank_fingerprint;
+
+/// Flashcard decks for Anki made from a question bank.
+mod anki;
+
+/// Version, git commit and build date shown on the software info page.
+mod build_info;
+
+/// The sample question bank offered to first-time users.
+mod sample_bank;
+
+/// One question as plain text for the clipboard.
+mod question_clipboard;
+
+/// The number of questions per difficulty level of a generated exam.
+mod difficulty_summary;
+
+/// A file browser drawn inside the window, for when the native dialog fails.
+mod file_browser;
+
+/// The question bank to open given on the command line.
+mod launch_file;
+
+/// Questions that always appear in an exam at a fixed position.
+mod pinned_questions;
+
+/// Localized names and roles of the interactive elements for screen readers.
+mod accessibility;
+
+/// Exam export to Markdown.
+mod markdown;
+
+/// Renaming and merging the categories of a bank.
+mod category_rename;
+
+/// The search, category, difficulty and sort of the questions table.
+mod question_filter;
+
+/// The header and footer printed on every page of a PDF exam.
+mod page_template;
+
+/// Which questions appeared on the exams exported from a bank.
+mod usage_history;
+
+/// Exam export to an editable Word document.
+mod docx;
+
+/// Short notices that go away by themselves or when dismissed.
+mod toast;
+
+/// Checking a question bank for malformed questions.
+mod bank_validation;
+
+/// Sorting strings in the alphabetical order of a locale.
+mod collation;
+
+/// Counting the questions of a bank per category and difficulty.
+mod bank_stats;
+
+/// Exporting all, the filtered or the selected questions as a new bank.
+mod bank_export;
+
 /// Re-exports the main application components for external use.
 pub use control_tower::{ ControlTower, Message };
+pub use page::Page;
+pub use workspace::{ WindowRegistry, Workspace, WorkspaceMessage };
 
 
-pub use load_file::{ LoadFile, ResultLoadFile };
\ No newline at end of file
+pub use load_file::{ detect_format, BankFormat, DialogBuilder, LoadFile, ResultLoadFile, ResultLoadSBank, IMAGE_EXTENSIONS, QUESTION_BANK_EXTENSIONS, ROSTER_EXTENSIONS, SQLITE_SIGNATURE, ZIP_SIGNATURE };
+pub use self_check::{ check_fixture, run_self_check, SelfCheckError, SELF_CHECK_EXCEL, SELF_CHECK_SQLITE };
+pub use save_file::{ SaveFile, ResultSaveFile };
+pub use exam::{ arrange_in_rows, default_question_count, format_number, normalize_range, parse_sections, NumberingStyle, QuestionOrder, ExamCriteria, ExamInfo, ExamLine, Exam, SectionCriteria, BLANK_FIELD, DEFAULT_QUESTION_PERCENT, MAX_CHOICES_PER_ROW, QUESTION_PERCENTS };
+pub use study_guide::{ study_guide_layout, StudyGuideLine };
+pub use export::{ check_writable, export_exam, export_exam_to_pdf, export_exam_to_excel, export_study_guide_to_pdf, verify_export, ExportError, ExportFormat };
+pub use roster::{ add_student, update_student, delete_student, find_duplicate_students, resolve_duplicate_students, DuplicateKey, DuplicateResolution, DuplicateStudents, RosterError, StudentForm };
+pub use grading::{ grade_answer, grade_typed_answer, numeric_match, true_false_choices, AnswerInput, GradingMode, NumericTolerance, QuestionKind, ABSOLUTE_TOLERANCES, NUMERIC_TOLERANCE, PERCENT_TOLERANCES };
+pub use learning::{ discard_learning_progress, get_learning_progress_path, load_learning_progress, save_learning_progress, LearningSession, LearningProgress, GradeEntry, GradeReport };
+pub use item_analysis::{ export_item_analysis, get_attempts_path, item_analysis, load_attempts, save_attempts, to_attempt, Attempt, ItemStats, LOW_DISCRIMINATION };
+pub use headless::{ generate, generate_quick, GenerateError, QUICK_EXAM_SIZE };
+pub use variants::{ generate_student_papers, generate_variants, generate_variants_with_metas, export_variant, export_variants, get_student_paper_name, get_variant_name, ExamVariant, ExportBatch, VariantReport };
+pub use shuffle::{ shuffle_choices, SeededRng };
+pub use answer_space::{ AnswerSpace, MAX_ANSWER_SPACE_LINES };
+pub use paper_code::{ PaperCode, PAPER_CODE_PREFIX };
+pub use font_fallback::{ fonts_for_locale, load_font_files, load_fonts, primary_font, LoadedFonts };
+pub use bank_metadata::{ get_bank_metadata_path, load_bank_metadata, save_bank, BankMetadata };
+pub use bank_fingerprint::{ fingerprint_bank, fingerprint_on_disk, matches_disk };
+pub use question_clipboard::question_clipboard_text;
+pub use difficulty_summary::{ difficulty_summary, BalanceReport, DifficultySummary, DifficultyTarget, BALANCE_ATTEMPTS };
+pub use launch_file::get_launch_file;
+pub use pinned_questions::{ parse_pins, place_pinned };
+pub use accessibility::{ menu_bar_labels, submenu_labels, AccessibleLabel, AccessibleRole };
+pub use markdown::{ escape_markdown, export_exam_to_markdown, to_markdown };
+pub use category_rename::{ rename_category, CategoryRenameError };
+pub use question_filter::QuestionFilter;
+pub use page_template::{ fill_placeholders, PageTemplate, PaperSize, DEFAULT_PAGE_MARGIN, PAGE_MARGINS, PAGE_PLACEHOLDERS };
+pub use usage_history::{ get_usage_history_path, load_usage_history, save_usage_history, UsageHistory, UsageRecord, MAX_AVOID_RECENT };
+pub use docx::{ count_docx_questions, export_exam_to_docx, to_docx, DOCX_CHOICE_STYLE, DOCX_QUESTION_STYLE };
+pub use toast::{ Toasts, TOAST_SECONDS };
+pub use bank_validation::{ validate_qbank, validate_qbank_with_metas, ValidationIssue };
+pub use collation::{ locale_sort, locale_sort_by_key };
+pub use bank_stats::{ bank_stats, export_bank_stats, QBankStats };
+pub use bank_export::{ export_questions, resolve_export_path, ExportSource };
+pub use file_browser::{ list_directory, BrowsePurpose, BrowserEntry, FileBrowser, FileDialogKind, SaveTarget };
+pub use anki::{ escape_anki_field, export_anki_deck, get_anki_tag, to_anki_deck, to_anki_note, ANKI_DECK_HEADER };
+pub use build_info::{ about_text, get_build_date, get_git_commit, PACKAGE_VERSION, UNKNOWN_BUILD_FIELD };
+pub use sample_bank::{ load_sample_bank, parse_sample_bank, SAMPLE_BANK };
+pub use question_meta::{ get_meta_path, load_question_metas, save_question_metas, ImageRef, LoadedImage, QuestionMeta, QuestionMetas, MAX_DIFFICULTY, MIN_CALIBRATION_ATTEMPTS };
+pub use focus::{ get_focus_ring_color, menu_button_style, steady_status, with_focus_ring, FOCUS_RING_WIDTH };
+pub use tab_order::{ get_content_focus_ids, get_submenu_keys, get_tab_order, FocusStop, MENU_KEYS, STATUS_BAR_FOCUS, TOAST_FOCUS };
+pub use menu_button::{ localized_menu_button, MenuButton };
+pub use modal::{ cycle_focus, ModalControl };
+pub use file_manager::{ get_containing_folder, open_containing_folder };
+pub use question_sort::{ sort_questions, SortColumn, SortOrder };
+pub use pagination::{ clamp_question_number, page_count, page_slice, DEFAULT_PAGE_SIZE, PAGE_SIZES };
+pub use prompt_preview::{ truncate_prompt, DEFAULT_PROMPT_LENGTH, ELLIPSIS, PROMPT_LENGTHS };
+pub use bulk_delete::{ needs_delete_confirmation, remove_questions, DEFAULT_DELETE_CONFIRM_THRESHOLD, DELETE_CONFIRM_THRESHOLDS };
+pub use i18n_coverage::{ coverage_of, find_missing_keys, get_language_name, i18n_coverage, locales_from_file_names, next_locale, parse_locale, LocaleMap, BASELINE_LOCALE };
+pub use panic_guard::catch_panic;
+pub use diagnostics::{ diagnostics_text, log_event, DiagnosticLog, DIAGNOSTICS_CAPACITY };
+pub use grade_display::{ format_grade, to_letter, GradeDisplay, LetterBand, LetterScale };
+pub use app_settings::{ get_settings_path, load_settings, save_settings, AppSettings, AUTOSAVE_MINUTES };
+pub use theme_choice::{ ThemeChoice, THEME_POLL_SECONDS };
+pub use idle_lock::{ hash_pin, is_idle, IDLE_LOCK_MINUTES };
+pub use tag_filter::{ matches, TagFilter, TagMode };
+pub use category_filter::{ distinct_categories, sort_categories, Category };
+pub use generation_summary::{ format_timestamp, get_summary_path, load_summary, save_summary, GenerationSummary, PaperSummary };
@@ -8,14 +8,113 @@
 ///////////////////////////////////////////////////////////////////////////////
 
 
-use std::path::PathBuf;
+use std::fmt;
+use std::fs::{ self, File };
+use std::io::Read;
+use std::path::{ Path, PathBuf };
 use std::convert::identity;
+use std::collections::{ HashMap, HashSet };
 
-use qrate::{ QBank, QBDB, SQLiteDB, Excel };
+use qrate::{ QBank, QBDB, SQLiteDB, Excel, SBank, Student };
 use rfd::FileDialog;
 use iced::Task;
+use rust_i18n::t;
 
 use crate::control_tower::Message;
+use crate::{ catch_panic, log_event };
+
+/// The first bytes of every SQLite database, such as a `.qbdb` file.
+pub const SQLITE_SIGNATURE: &[u8] = b"SQLite format 3\0";
+
+/// The first bytes of every ZIP archive, such as an `.xlsx` workbook.
+pub const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+
+/// The extensions offered when picking a question bank.
+pub const QUESTION_BANK_EXTENSIONS: [&str; 2] = ["qbdb", "xlsx"];
+
+/// The extensions offered when picking a roster.
+pub const ROSTER_EXTENSIONS: [&str; 1] = ["txt"];
+
+/// The extensions offered when picking an image to attach to a question.
+pub const IMAGE_EXTENSIONS: [&str; 5] = ["png", "jpg", "jpeg", "bmp", "gif"];
+
+/// The storage format of a question bank file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BankFormat
+{
+    /// A SQLite database, normally named `*.qbdb`.
+    SQLite,
+
+    /// An Excel workbook, normally named `*.qb.xlsx`.
+    Excel,
+}
+
+impl BankFormat
+{
+    // pub fn from_extension(extension: &str) -> Option<Self>
+    /// Returns the format a file extension claims.
+    ///
+    /// # Arguments
+    /// * `extension` - The extension without the dot, such as `"qbdb"`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::BankFormat;
+    /// assert_eq!(BankFormat::from_extension("qbdb"), Some(BankFormat::SQLite));
+    /// assert_eq!(BankFormat::from_extension("xlsx"), Some(BankFormat::Excel));
+    /// assert_eq!(BankFormat::from_extension("txt"), None);
+    /// ```
+    pub fn from_extension(extension: &str) -> Option<Self>
+    {
+        match extension
+        {
+            "qbdb" => Some(BankFormat::SQLite),
+            "xlsx" => Some(BankFormat::Excel),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BankFormat
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            BankFormat::SQLite => write!(f, "QBDB"),
+            BankFormat::Excel => write!(f, "Excel"),
+        }
+    }
+}
+
+// pub fn detect_format(bytes: &[u8]) -> Option<BankFormat>
+/// Tells the format of a question bank file from its first bytes, whatever
+/// the file is named.
+///
+/// # Arguments
+/// * `bytes` - The beginning of the file; 16 bytes are enough.
+///
+/// # Output
+/// The format, or `None` if the bytes match neither signature.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ detect_format, BankFormat };
+/// assert_eq!(detect_format(b"SQLite format 3\0\x10\x00"), Some(BankFormat::SQLite));
+/// assert_eq!(detect_format(b"PK\x03\x04\x14\x00\x06\x00"), Some(BankFormat::Excel));
+/// assert_eq!(detect_format(b"Name,Id\nAlice,1\n"), None);
+/// assert_eq!(detect_format(b"PK"), None);
+/// assert_eq!(detect_format(&[]), None);
+/// ```
+pub fn detect_format(bytes: &[u8]) -> Option<BankFormat>
+{
+    if bytes.starts_with(SQLITE_SIGNATURE)
+        { Some(BankFormat::SQLite) }
+    else if bytes.starts_with(ZIP_SIGNATURE)
+        { Some(BankFormat::Excel) }
+    else
+        { None }
+}
 
 /// Represents the result of an attempt to load a `QBank`.
 ///
@@ -49,6 +148,82 @@ pub enum ResultLoadFile
     UnsupportedExtension,
 }
 
+impl fmt::Display for ResultLoadFile
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            ResultLoadFile::Success(_) => write!(f, "QBank loaded."),
+            ResultLoadFile::FileNotFound => write!(f, "File does not exist."),
+            ResultLoadFile::FailedToOpenSQLite => write!(f, "Failed to open QBDB file."),
+            ResultLoadFile::FailedToReadSQLite => write!(f, "Failed to read QBank from QBDB."),
+            ResultLoadFile::FailedToOpenExcel => write!(f, "Failed to open Excel file."),
+            ResultLoadFile::FailedToReadExcel => write!(f, "Failed to read QBank from Excel."),
+            ResultLoadFile::InvalidExcelExtension => write!(f, "Not a valid *.qb.xlsx file. Expecting .qb.xlsx extension for Excel QBank."),
+            ResultLoadFile::UnsupportedExtension => write!(f, "Unsupported file extension."),
+        }
+    }
+}
+
+/// Represents the result of an attempt to load a student roster.
+#[derive(Debug, Clone)]
+pub enum ResultLoadSBank
+{
+    /// The roster was loaded: the students, and the group of each student
+    /// keyed by student id.
+    Success(SBank, HashMap<String, String>),
+
+    /// The specified file was not found.
+    FileNotFound,
+
+    /// The file could not be read as text.
+    FailedToRead,
+
+    /// The file extension is not supported.
+    UnsupportedExtension,
+}
+
+impl fmt::Display for ResultLoadSBank
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            ResultLoadSBank::Success(sbank, _) => write!(f, "{} students loaded.", sbank.len()),
+            ResultLoadSBank::FileNotFound => write!(f, "File does not exist."),
+            ResultLoadSBank::FailedToRead => write!(f, "Failed to read the roster file."),
+            ResultLoadSBank::UnsupportedExtension => write!(f, "Unsupported file extension."),
+        }
+    }
+}
+
+/// The parts of a file dialog builder that `LoadFile` configures.
+///
+/// It is implemented for `rfd::FileDialog`; other implementations allow the
+/// configuration to be inspected without opening a native dialog.
+pub trait DialogBuilder: Sized
+{
+    /// Adds a file filter with a user-visible `name`.
+    fn add_filter(self, name: &str, extensions: &[&str]) -> Self;
+
+    /// Sets the directory the dialog starts in.
+    fn set_directory(self, path: &Path) -> Self;
+}
+
+impl DialogBuilder for FileDialog
+{
+    fn add_filter(self, name: &str, extensions: &[&str]) -> Self
+    {
+        FileDialog::add_filter(self, name, extensions)
+    }
+
+    fn set_directory(self, path: &Path) -> Self
+    {
+        FileDialog::set_directory(self, path)
+    }
+}
+
 /// Provides utility functions for file-related operations in the application,
 /// specifically for picking and loading `QBank` files.
 #[derive(Debug, Clone)]
@@ -62,6 +237,9 @@ impl LoadFile
     /// This function is designed to be called within an `iced::Task`. It presents
     /// a native file dialog filtered for question bank file types (`.qbdb`, `.xlsx`).
     ///
+    /// # Arguments
+    /// * `start` - The directory the dialog starts in.
+    ///
     /// # Output
     /// An `Option<PathBuf>` representing the path to the selected file,
     /// or `None` if no file was selected.
@@ -73,19 +251,87 @@ impl LoadFile
     /// // but here's how you would typically call it in an Iced application:
     /// async fn example_usage() {
     ///     use std::path::PathBuf;
-    ///     use crate::load_file::LoadFile;
+    ///     use qrate_gui::LoadFile;
     ///
-    ///     let selected_path: Option<PathBuf> = LoadFile::pick_question_bank().await;
+    ///     let selected_path: Option<PathBuf> = LoadFile::pick_question_bank(PathBuf::from(".")).await;
     ///     match selected_path {
     ///         Some(path) => println!("File selected: {:?}", path),
     ///         None => println!("No file selected."),
     ///     }
     /// }
     /// ```
-    pub async fn pick_question_bank() -> Option<PathBuf>
+    pub async fn pick_question_bank(start: PathBuf) -> Option<PathBuf>
+    {
+        LoadFile::configure_question_bank_dialog(FileDialog::new(), &start).pick_file()
+    }
+
+    // pub fn configure_question_bank_dialog<B: DialogBuilder>(builder: B, start: &Path) -> B
+    /// Configures a dialog builder for picking a question bank, with the
+    /// filter label translated into the current locale.
+    ///
+    /// # Arguments
+    /// * `builder` - The dialog builder to configure.
+    /// * `start` - The directory the dialog starts in.
+    ///
+    /// # Output
+    /// The configured builder.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::{ Path, PathBuf };
+    /// use qrate_gui::{ DialogBuilder, LoadFile };
+    ///
+    /// #[derive(Default)]
+    /// struct Recorder { labels: Vec<String>, directory: PathBuf }
+    ///
+    /// impl DialogBuilder for Recorder
+    /// {
+    ///     fn add_filter(mut self, name: &str, _extensions: &[&str]) -> Self
+    ///     {
+    ///         self.labels.push(name.to_string());
+    ///         self
+    ///     }
+    ///
+    ///     fn set_directory(mut self, path: &Path) -> Self
+    ///     {
+    ///         self.directory = path.to_path_buf();
+    ///         self
+    ///     }
+    /// }
+    ///
+    /// rust_i18n::set_locale("ko");
+    /// let recorder = LoadFile::configure_question_bank_dialog(Recorder::default(), Path::new("."));
+    /// assert_eq!(recorder.labels, vec!["문제은행".to_string()]);
+    ///
+    /// rust_i18n::set_locale("en");
+    /// let recorder = LoadFile::configure_question_bank_dialog(Recorder::default(), Path::new("/banks/math"));
+    /// assert_eq!(recorder.labels, vec!["Question Bank".to_string()]);
+    /// assert_eq!(recorder.directory, PathBuf::from("/banks/math"));
+    /// ```
+    pub fn configure_question_bank_dialog<B: DialogBuilder>(builder: B, start: &Path) -> B
+    {
+        builder.add_filter(&t!("file-filter-question-bank"), &QUESTION_BANK_EXTENSIONS)
+               .set_directory(start)
+    }
+
+    // pub async fn pick_question_image() -> Option<PathBuf>
+    /// Asynchronously opens a file dialog for the user to pick an image to
+    /// attach to a question.
+    ///
+    /// # Output
+    /// An `Option<PathBuf>` with the selected image, or `None` if cancelled.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use qrate_gui::LoadFile;
+    ///     let image_path = LoadFile::pick_question_image().await;
+    /// }
+    /// ```
+    pub async fn pick_question_image() -> Option<PathBuf>
     {
         FileDialog::new()
-            .add_filter("Question Bank", &["qbdb", "xlsx"])
+            .add_filter(t!("file-filter-image"), &IMAGE_EXTENSIONS)
             .set_directory(".")
             .pick_file()
     }
@@ -110,7 +356,7 @@ impl LoadFile
     /// // but here's how you would typically call it in an Iced application:
     /// async fn example_usage() {
     ///     use std::path::PathBuf;
-    ///     use crate::load_file::{LoadFile, ResultLoadFile};
+    ///     use qrate_gui::{ LoadFile, ResultLoadFile };
     ///     // For a real test, you would create dummy files.
     ///     // Assume "dummy.qbdb" is a valid qbdb file in the current directory
     ///     // and "invalid.txt" is an unsupported file.
@@ -135,37 +381,81 @@ impl LoadFile
     /// }
     /// ```
     pub async fn load_qbank_from_path(path: PathBuf) -> ResultLoadFile
+    {
+        LoadFile::read_qbank_from_path(&path)
+    }
+
+    // pub fn read_qbank_from_path(path: &Path) -> ResultLoadFile
+    /// Synchronously loads a `QBank` from the given file path.
+    ///
+    /// This is the blocking counterpart of `load_qbank_from_path`, for use
+    /// outside of the GUI such as in scripts and tests.
+    ///
+    /// The format is told from the content of the file by `detect_format`,
+    /// so that a mislabeled file, such as a workbook named `*.qbdb`, still
+    /// loads; a warning is logged when the extension disagrees. The
+    /// extension decides only if the content is not recognized.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the file to load.
+    ///
+    /// # Output
+    /// A `ResultLoadFile` enum, as for `load_qbank_from_path`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::Path;
+    /// use qrate_gui::{ LoadFile, ResultLoadFile };
+    /// let result = LoadFile::read_qbank_from_path(Path::new("/definitely/not/here.qbdb"));
+    /// assert!(matches!(result, ResultLoadFile::FileNotFound));
+    ///
+    /// // An Excel question bank renamed to `.qbdb` still loads.
+    /// use qrate::{ Excel, QBank, QBDB, Question };
+    /// let dir = std::env::temp_dir();
+    /// let base = dir.join("qrate_gui_mislabeled");
+    /// let workbook = dir.join("qrate_gui_mislabeled.qb.xlsx");
+    /// let mislabeled = dir.join("qrate_gui_mislabeled.qbdb");
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![("Yes".to_string(), true)]));
+    /// let _ = std::fs::remove_file(&workbook);
+    /// Excel::open(base.to_string_lossy().into_owned()).unwrap().write_qbank(&qbank).unwrap();
+    /// std::fs::rename(&workbook, &mislabeled).unwrap();
+    /// match LoadFile::read_qbank_from_path(&mislabeled)
+    /// {
+    ///     ResultLoadFile::Success(loaded) => assert_eq!(loaded.get_questions().len(), 1),
+    ///     other => panic!("unexpected result: {}", other),
+    /// }
+    /// ```
+    pub fn read_qbank_from_path(path: &Path) -> ResultLoadFile
     {
         if !path.exists()
             { return ResultLoadFile::FileNotFound; }
 
         let path_str = path.to_string_lossy().into_owned(); // Convert PathBuf to String for QBDB::open
         let extension = path.extension().and_then(|s| s.to_str()).unwrap_or("");
+        let claimed = BankFormat::from_extension(extension);
+        let actual = LoadFile::sniff_format(path);
+
+        // Without an extension, qrate would append its own and open another file.
+        if let Some(actual) = actual.filter(|&actual| Some(actual) != claimed && !extension.is_empty())
+        {
+            log_event(&format!("Warning: {} is a {} file despite its extension; loading it as {}.", path.display(), actual, actual));
+            return match actual
+            {
+                // qrate keeps a path that already has the given extension as it is.
+                BankFormat::SQLite => LoadFile::read_sqlite(SQLiteDB::open_with_ext(path_str, extension)),
+                BankFormat::Excel => LoadFile::read_excel(Excel::open_with_ext(path_str, extension)),
+            };
+        }
 
         match extension
         {
-            "qbdb" => {
-                match SQLiteDB::open(path_str) { // Use QBDB::open for SQLiteDB
-                    Some(db) => {
-                        match db.read_qbank() { // Then read_qbank
-                            Some(qbank) => ResultLoadFile::Success(qbank),
-                            None => ResultLoadFile::FailedToReadSQLite,
-                        }
-                    },
-                    None => ResultLoadFile::FailedToOpenSQLite,
-                }
-            },
+            "qbdb" => LoadFile::read_sqlite(SQLiteDB::open(path_str)), // Use QBDB::open for SQLiteDB
             "xlsx" => {
                 if path_str.contains(".qb.xlsx") { // Still check for .qb.xlsx as per original logic
-                    match Excel::open(path_str) { // Use QBDB::open for Excel
-                        Some(excel) => {
-                            match excel.read_qbank() { // Then read_qbank
-                                Some(qbank) => ResultLoadFile::Success(qbank),
-                                None => ResultLoadFile::FailedToReadExcel,
-                            }
-                        },
-                        None => ResultLoadFile::FailedToOpenExcel,
-                    }
+                    // Excel::open appends ".qb.xlsx" itself, so pass the path without it.
+                    let base = path_str.strip_suffix(".qb.xlsx").unwrap_or(&path_str).to_string();
+                    LoadFile::read_excel(Excel::open(base)) // Use QBDB::open for Excel
                 }
                 else
                 {
@@ -176,36 +466,279 @@ impl LoadFile
         }
     }
 
-    // pub fn perform_pick_qbank_task() -> Task<Message>
+    /// Reads the first bytes of the file at `path` for `detect_format`.
+    pub(crate) fn sniff_format(path: &Path) -> Option<BankFormat>
+    {
+        let mut head = Vec::with_capacity(SQLITE_SIGNATURE.len());
+        File::open(path).ok()?.take(SQLITE_SIGNATURE.len() as u64).read_to_end(&mut head).ok()?;
+        detect_format(&head)
+    }
+
+    fn read_sqlite(db: Option<SQLiteDB>) -> ResultLoadFile
+    {
+        match db
+        {
+            Some(db) => match db.read_qbank()
+            {
+                Some(qbank) => ResultLoadFile::Success(qbank),
+                None => ResultLoadFile::FailedToReadSQLite,
+            },
+            None => ResultLoadFile::FailedToOpenSQLite,
+        }
+    }
+
+    fn read_excel(excel: Option<Excel>) -> ResultLoadFile
+    {
+        match excel
+        {
+            Some(excel) => match excel.read_qbank()
+            {
+                Some(qbank) => ResultLoadFile::Success(qbank),
+                None => ResultLoadFile::FailedToReadExcel,
+            },
+            None => ResultLoadFile::FailedToOpenExcel,
+        }
+    }
+
+    // pub async fn pick_student_list() -> Option<PathBuf>
+    /// Asynchronously opens a file dialog for the user to pick a roster file.
+    ///
+    /// # Output
+    /// An `Option<PathBuf>` with the selected file, or `None` if cancelled.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use qrate_gui::LoadFile;
+    ///     let roster_path = LoadFile::pick_student_list().await;
+    /// }
+    /// ```
+    pub async fn pick_student_list() -> Option<PathBuf>
+    {
+        FileDialog::new()
+            .add_filter(t!("file-filter-roster"), &ROSTER_EXTENSIONS)
+            .set_directory(".")
+            .pick_file()
+    }
+
+    // pub fn parse_roster_text(text: &str) -> (SBank, HashMap<String, String>)
+    /// Parses a plain-text roster with one student per line.
+    ///
+    /// Each line holds a name, optionally followed by `,id` and `,group`.
+    /// Whitespace around every field is trimmed and blank lines are skipped.
+    /// A student without an id is given its 1-based position in the roster,
+    /// or the next number after it that no other student uses as an id.
+    ///
+    /// # Arguments
+    /// * `text` - The contents of the roster file.
+    ///
+    /// # Output
+    /// The students in file order, and the group of each student that has
+    /// one, keyed by student id.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::LoadFile;
+    /// let (sbank, groups) = LoadFile::parse_roster_text("Alice, s1, A\nBob\n");
+    /// assert_eq!(sbank[0].get_id(), "s1");
+    /// assert_eq!(sbank[1].get_name(), "Bob");
+    /// assert_eq!(sbank[1].get_id(), "2");
+    /// assert_eq!(groups.get("s1").map(String::as_str), Some("A"));
+    /// assert!(!groups.contains_key("2"));
+    ///
+    /// let (sbank, _) = LoadFile::parse_roster_text("Alice, 2\nBob\nCarol\nDave, 4");
+    /// assert_eq!(sbank[0].get_id(), "2");
+    /// assert_eq!(sbank[1].get_id(), "3");
+    /// assert_eq!(sbank[2].get_id(), "5");
+    /// assert_eq!(sbank[3].get_id(), "4");
+    /// ```
+    pub fn parse_roster_text(text: &str) -> (SBank, HashMap<String, String>)
+    {
+        let rows: Vec<Vec<&str>> = text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| line.split(',').map(str::trim).collect())
+            .collect();
+        let mut used: HashSet<String> = rows.iter()
+            .filter_map(|fields| fields.get(1).filter(|id| !id.is_empty()))
+            .map(|id| id.to_string())
+            .collect();
+
+        let mut sbank = SBank::new();
+        let mut groups = HashMap::new();
+        for fields in rows
+        {
+            let name = fields[0].to_string();
+            let id = match fields.get(1)
+            {
+                Some(id) if !id.is_empty() => id.to_string(),
+                _ => {
+                    let mut number = sbank.len() + 1;
+                    while used.contains(&number.to_string())
+                        { number += 1; }
+                    used.insert(number.to_string());
+                    number.to_string()
+                },
+            };
+            if let Some(group) = fields.get(2).filter(|group| !group.is_empty())
+                { groups.insert(id.clone(), group.to_string()); }
+            sbank.push(Student::new(name, id));
+        }
+        (sbank, groups)
+    }
+
+    // pub fn read_sbank_from_path(path: &Path) -> ResultLoadSBank
+    /// Synchronously loads a student roster from a `.txt` file.
+    ///
+    /// See `parse_roster_text` for the format of the file.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the file to load.
+    ///
+    /// # Output
+    /// A `ResultLoadSBank` describing the outcome.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ LoadFile, ResultLoadSBank };
+    ///
+    /// let path = std::env::temp_dir().join("qrate_gui_roster.txt");
+    /// std::fs::write(&path, "  Alice  \n\nBob, s2\n   \nCarol, s3, B\n").unwrap();
+    /// match LoadFile::read_sbank_from_path(&path)
+    /// {
+    ///     ResultLoadSBank::Success(sbank, groups) => {
+    ///         assert_eq!(sbank.len(), 3);
+    ///         assert_eq!(sbank[0].get_name(), "Alice");
+    ///         assert_eq!(sbank[2].get_id(), "s3");
+    ///         assert_eq!(groups.get("s3").map(String::as_str), Some("B"));
+    ///     },
+    ///     other => panic!("unexpected result: {}", other),
+    /// }
+    ///
+    /// let upper = std::env::temp_dir().join("QRATE_GUI_CLASS.TXT");
+    /// std::fs::write(&upper, "Dave\n").unwrap();
+    /// assert!(matches!(LoadFile::read_sbank_from_path(&upper), ResultLoadSBank::Success(..)));
+    /// ```
+    pub fn read_sbank_from_path(path: &Path) -> ResultLoadSBank
+    {
+        if !path.exists()
+            { return ResultLoadSBank::FileNotFound; }
+        let is_txt = path.extension()
+            .and_then(|s| s.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("txt"));
+        if !is_txt
+            { return ResultLoadSBank::UnsupportedExtension; }
+
+        match fs::read_to_string(path)
+        {
+            Ok(text) => {
+                let (sbank, groups) = LoadFile::parse_roster_text(&text);
+                ResultLoadSBank::Success(sbank, groups)
+            },
+            Err(_) => ResultLoadSBank::FailedToRead,
+        }
+    }
+
+    // pub async fn load_sbank_from_path(path: PathBuf) -> ResultLoadSBank
+    /// Asynchronously loads a student roster from a `.txt` file.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the file to load.
+    ///
+    /// # Output
+    /// A `ResultLoadSBank`, as for `read_sbank_from_path`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use std::path::PathBuf;
+    ///     use qrate_gui::LoadFile;
+    ///     let result = LoadFile::load_sbank_from_path(PathBuf::from("class.txt")).await;
+    /// }
+    /// ```
+    pub async fn load_sbank_from_path(path: PathBuf) -> ResultLoadSBank
+    {
+        LoadFile::read_sbank_from_path(&path)
+    }
+
+    // pub async fn pick_storage_folder(start: PathBuf) -> Option<PathBuf>
+    /// Asynchronously opens a folder dialog for the user to choose where the
+    /// question banks are kept.
+    ///
+    /// # Arguments
+    /// * `start` - The directory the dialog starts in.
+    ///
+    /// # Output
+    /// The chosen folder, or `None` if the dialog was cancelled.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use std::path::PathBuf;
+    ///     use qrate_gui::LoadFile;
+    ///     let folder = LoadFile::pick_storage_folder(PathBuf::from(".")).await;
+    /// }
+    /// ```
+    pub async fn pick_storage_folder(start: PathBuf) -> Option<PathBuf>
+    {
+        FileDialog::new()
+            .set_directory(start)
+            .pick_folder()
+    }
+
+    // pub fn perform_pick_storage_folder_task(start: PathBuf) -> Task<Message>
+    /// Creates a [Task] that opens the folder dialog of `pick_storage_folder`
+    /// and reports the choice with a `Message::StoragePathChosen`.
+    ///
+    /// # Arguments
+    /// * `start` - The directory the dialog starts in.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::path::PathBuf;
+    /// use qrate_gui::LoadFile;
+    /// let _task = LoadFile::perform_pick_storage_folder_task(PathBuf::from("."));
+    /// ```
+    #[inline]
+    pub fn perform_pick_storage_folder_task(start: PathBuf) -> Task<Message>
+    {
+        Task::perform(LoadFile::pick_storage_folder(start), Message::StoragePathChosen)
+    }
+
+    // pub fn perform_pick_qbank_task(start: PathBuf) -> Task<Message>
     /// Creates a [Task] to perform the asynchronous operation of picking a question bank file.
     ///
     /// This function encapsulates the `Task::perform` call, which spawns an asynchronous
     /// operation to open a file dialog and then wraps the result in a `Message::FileSelected`.
     ///
+    /// # Arguments
+    /// * `start` - The directory the dialog starts in.
+    ///
     /// # Output
     /// A [Task] that, when run, will eventually produce a `Message::FileSelected`.
     ///
     /// # Examples
     /// ```no_run
     /// use iced::Task;
-    /// use crate::load_file::LoadFile;
-    /// use crate::control_tower::Message; // Assuming Message is public
+    /// use qrate_gui::LoadFile;
+    /// use qrate_gui::Message;
     ///
     /// // In an `iced` update function:
-    /// // let task: Task<Message> = LoadFile::perform_pick_qbank_task();
+    /// // let task: Task<Message> = LoadFile::perform_pick_qbank_task(std::path::PathBuf::from("."));
     /// // return task;
     /// ```
     #[inline]
-    pub fn perform_pick_qbank_task() -> Task<Message>
+    pub fn perform_pick_qbank_task(start: PathBuf) -> Task<Message>
     {
-        Task::perform(async { Message::FileSelected(LoadFile::pick_question_bank().await.unwrap_or_default()) }, identity)
+        Task::perform(async { Message::FileSelected(LoadFile::pick_question_bank(start).await.unwrap_or_default()) }, identity)
     }
 
     // pub fn perform_load_qbank_task(path: PathBuf) -> Task<Message>
     /// Creates a [Task] to perform the asynchronous operation of loading a `QBank` from a specified path.
     ///
     /// This function encapsulates the `Task::perform` call, which spawns an asynchronous
-    /// operation to load the QBank and then wraps the result in a `Message::QBankLoaded`.
+    /// operation to load the QBank and then wraps the result in a `Message::QBankLoaded`,
+    /// or in a `Message::TaskFailed` if loading panicked.
     ///
     /// # Arguments
     /// * `path` - The `PathBuf` of the file to load the QBank from.
@@ -216,8 +749,8 @@ impl LoadFile
     /// # Examples
     /// ```no_run
     /// use iced::Task;
-    /// use crate::load_file::LoadFile;
-    /// use crate::control_tower::Message; // Assuming Message is public
+    /// use qrate_gui::LoadFile;
+    /// use qrate_gui::Message;
     /// use std::path::PathBuf;
     ///
     /// // In an `iced` update function:
@@ -228,7 +761,70 @@ impl LoadFile
     #[inline]
     pub fn perform_load_qbank_task(path: PathBuf) -> Task<Message>
     {
-        Task::perform(LoadFile::load_qbank_from_path(path), Message::QBankLoaded)
+        Task::perform(async move { catch_panic(|| LoadFile::read_qbank_from_path(&path)) },
+                      |result| result.map_or_else(Message::TaskFailed, Message::QBankLoaded))
     }
-}
 
+    // pub fn perform_pick_question_image_task(id: u16) -> Task<Message>
+    /// Creates a [Task] that lets the user pick an image for the question
+    /// with the given id and reports it with a `Message::QuestionImagePicked`.
+    ///
+    /// # Arguments
+    /// * `id` - The id of the question the image is attached to.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::QuestionImagePicked`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use qrate_gui::LoadFile;
+    /// let _task = LoadFile::perform_pick_question_image_task(1);
+    /// ```
+    #[inline]
+    pub fn perform_pick_question_image_task(id: u16) -> Task<Message>
+    {
+        Task::perform(async move { Message::QuestionImagePicked(id, LoadFile::pick_question_image().await.unwrap_or_default()) }, identity)
+    }
+
+    // pub fn perform_pick_sbank_task() -> Task<Message>
+    /// Creates a [Task] that lets the user pick a roster file and reports it
+    /// with a `Message::RosterFileSelected`.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::RosterFileSelected`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use qrate_gui::LoadFile;
+    /// let _task = LoadFile::perform_pick_sbank_task();
+    /// ```
+    #[inline]
+    pub fn perform_pick_sbank_task() -> Task<Message>
+    {
+        Task::perform(async { Message::RosterFileSelected(LoadFile::pick_student_list().await.unwrap_or_default()) }, identity)
+    }
+
+    // pub fn perform_load_sbank_task(path: PathBuf) -> Task<Message>
+    /// Creates a [Task] that loads a roster from `path` and reports it with a
+    /// `Message::SBankLoaded`, or with a `Message::TaskFailed` if loading
+    /// panicked.
+    ///
+    /// # Arguments
+    /// * `path` - The roster file to load.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::SBankLoaded`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::path::PathBuf;
+    /// use qrate_gui::LoadFile;
+    /// let _task = LoadFile::perform_load_sbank_task(PathBuf::from("class.txt"));
+    /// ```
+    #[inline]
+    pub fn perform_load_sbank_task(path: PathBuf) -> Task<Message>
+    {
+        Task::perform(async move { catch_panic(|| LoadFile::read_sbank_from_path(&path)) },
+                      |result| result.map_or_else(Message::TaskFailed, Message::SBankLoaded))
+    }
+}
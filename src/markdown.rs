@@ -0,0 +1,204 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fs;
+use std::path::Path;
+
+use crate::{ Exam, ExamLine, ExportError, ImageRef };
+
+/// The characters that mark up Markdown wherever they appear in a line.
+const MARKDOWN_SPECIALS: &str = "\\`*_[]<>#|~";
+
+// pub fn escape_markdown(text: &str) -> String
+/// Escapes `text` so that Markdown shows it as written.
+///
+/// Emphasis, code, link, HTML, heading, table and strikethrough characters
+/// are escaped with a backslash wherever they appear. A `-`, `+` or `1.`
+/// at the start of a line is escaped too, so that it does not start a list.
+/// Line breaks become hard line breaks.
+///
+/// # Arguments
+/// * `text` - The raw text, such as a question prompt.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::escape_markdown;
+/// assert_eq!(escape_markdown("2 * 3 = x_1"), "2 \\* 3 = x\\_1");
+/// assert_eq!(escape_markdown("[a](b) <br> #1"), "\\[a\\](b) \\<br\\> \\#1");
+/// assert_eq!(escape_markdown("- item\n1. step"), "\\- item\\\n1\\. step");
+/// assert_eq!(escape_markdown("3.14 - e"), "3.14 - e");
+/// ```
+pub fn escape_markdown(text: &str) -> String
+{
+    text.lines()
+        .map(escape_markdown_line)
+        .collect::<Vec<_>>()
+        .join("\\\n")
+}
+
+fn escape_markdown_line(line: &str) -> String
+{
+    let mut escaped = String::with_capacity(line.len());
+    let body = line.trim_start();
+    escaped.push_str(&line[..line.len() - body.len()]);
+
+    // A list marker at the start of the line.
+    let digits = body.chars().take_while(|c| c.is_ascii_digit()).count();
+    let marker = match body[digits..].chars().next()
+    {
+        Some('-') | Some('+') if digits == 0 => Some(0),
+        Some('.') | Some(')') if digits > 0 => Some(digits),
+        _ => None,
+    };
+    let marker = marker.filter(|&at| body[at + 1..].is_empty() || body[at + 1..].starts_with(char::is_whitespace));
+
+    for (i, c) in body.char_indices()
+    {
+        if MARKDOWN_SPECIALS.contains(c) || marker == Some(i)
+            { escaped.push('\\'); }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// pub fn to_markdown(exam: &Exam, with_answers: bool) -> String
+/// Writes `exam` as a Markdown document for wikis and learning management
+/// systems: the title as a heading, the header details, then the questions
+/// as a numbered list with their choices as a bulleted list under each.
+///
+/// Markdown numbers lists with Arabic numerals only, so the questions are
+/// numbered `1.`, `2.`, ... whatever the numbering style of the exam.
+/// Attached image files are linked by path; embedded and missing images
+/// become a placeholder line.
+///
+/// # Arguments
+/// * `exam` - The exam.
+/// * `with_answers` - Whether the answer key follows the questions, in a
+///   collapsible `<details>` section so that it stays hidden until opened.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, Question };
+/// use qrate_gui::{ to_markdown, Exam, NumberingStyle };
+///
+/// let questions = vec![
+///     Question::new(1, 1, 1, "Is 2 * 3 = 6?".to_string(), vec![("No".to_string(), false), ("Yes".to_string(), true)]),
+///     Question::new(2, 2, 1, "Define x_1".to_string(), vec![]),
+///     Question::new(3, 3, 1, "Pick *all*".to_string(), vec![("a_b".to_string(), true), ("c".to_string(), true)]),
+/// ];
+/// let exam = Exam::new(Header::new_with_default(), questions, NumberingStyle::Roman);
+/// let markdown = to_markdown(&exam, true);
+///
+/// // The questions are numbered in order, with the special characters escaped.
+/// let items: Vec<&str> = markdown.lines().filter(|line| line.starts_with(|c: char| c.is_ascii_digit())).collect();
+/// assert_eq!(items, ["1. Is 2 \\* 3 = 6?", "2. Define x\\_1", "3. Pick \\*all\\*"]);
+/// assert!(markdown.starts_with("# Examination\n"));
+/// assert!(markdown.contains("\n1. Is 2 \\* 3 = 6?\n   - (A) No\n   - (B) Yes\n"));
+/// assert!(markdown.contains("   - (A) a\\_b\n"));
+///
+/// // The answer key is collapsed.
+/// assert!(markdown.contains("<details>\n<summary>Answer Key</summary>\n\n- 1: B\n- 2:\n- 3: A, B\n\n</details>\n"));
+/// assert!(!to_markdown(&exam, false).contains("<details>"));
+/// ```
+pub fn to_markdown(exam: &Exam, with_answers: bool) -> String
+{
+    let mut blocks: Vec<String> = Vec::new();
+    let mut number = 0;
+    let mut indent = String::new();
+    for line in exam.layout()
+    {
+        match &line
+        {
+            ExamLine::Title(title) => blocks.push(format!("# {}", escape_markdown(&title.lines().collect::<Vec<_>>().join(" ")))),
+            ExamLine::Field { label, value } => blocks.push(format!("**{}:** {}", escape_markdown(label), escape_markdown(value))),
+            ExamLine::Instructions(instructions) => blocks.push(escape_markdown(instructions)),
+            ExamLine::Question { text, .. } => {
+                number += 1;
+                let marker = format!("{}. ", number);
+                indent = " ".repeat(marker.len());
+                blocks.push(format!("{}{}", marker, indent_lines(&escape_markdown(text), &indent)));
+            },
+            ExamLine::Choice { label, text } => {
+                let item = format!("{}- ({}) {}", indent, label, indent_lines(&escape_markdown(text), &format!("{}  ", indent)));
+                match blocks.last_mut()
+                {
+                    Some(block) => { block.push('\n'); block.push_str(&item); },
+                    None => blocks.push(item),
+                }
+            },
+            ExamLine::Image(image) => {
+                let picture = match image
+                {
+                    ImageRef::Path(path) if image.is_available() => format!("![](<{}>)", path.display()),
+                    _ => escape_markdown(line.to_text().trim()),
+                };
+                blocks.push(format!("{}{}", indent, picture));
+            },
+            ExamLine::Blank | ExamLine::AnswerSpace(_) => {},
+        }
+    }
+
+    if with_answers
+    {
+        let key = exam.answer_key()
+                    .into_iter()
+                    .zip(1..)
+                    .map(|((_, labels), number)| format!("- {}: {}", number, labels.join(", ")).trim_end().to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+        blocks.push(format!("<details>\n<summary>{}</summary>\n\n{}\n\n</details>", exam.get_label("answer-key"), key));
+    }
+
+    let mut markdown = blocks.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+fn indent_lines(text: &str, indent: &str) -> String
+{
+    text.replace('\n', &format!("\n{}", indent))
+}
+
+// pub fn export_exam_to_markdown(exam: &Exam, path: &Path) -> Result<(), ExportError>
+/// Writes `exam` to `path` as Markdown, with the answer key collapsed at
+/// the end unless `Exam::get_answer_key` leaves it out. See `to_markdown`.
+///
+/// # Arguments
+/// * `exam` - The exam to export.
+/// * `path` - The target file path, usually ending in `.md`.
+///
+/// # Output
+/// `Ok(())` on success, or `ExportError::PermissionDenied` or
+/// `ExportError::Write`.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, Question };
+/// use qrate_gui::{ export_exam_to_markdown, verify_export, Exam, NumberingStyle };
+///
+/// let questions = (1..=12).map(|id| Question::new(id, id, 1, format!("{}. Q", id), vec![])).collect();
+/// let exam = Exam::new(Header::new_with_default(), questions, NumberingStyle::Arabic);
+/// let path = std::env::temp_dir().join("qrate_gui_export_exam.md");
+/// export_exam_to_markdown(&exam, &path).unwrap();
+/// let markdown = std::fs::read_to_string(&path).unwrap();
+/// assert!(markdown.contains("\n10. 10\\. Q\n"));
+/// assert!(verify_export(&path, 12));
+/// assert!(!verify_export(&path, 11));
+/// assert!(markdown.contains("<details>"));
+///
+/// let mut exam = exam;
+/// exam.set_answer_key(false);
+/// export_exam_to_markdown(&exam, &path).unwrap();
+/// assert!(!std::fs::read_to_string(&path).unwrap().contains("<details>"));
+/// ```
+pub fn export_exam_to_markdown(exam: &Exam, path: &Path) -> Result<(), ExportError>
+{
+    fs::write(path, to_markdown(exam, exam.get_answer_key())).map_err(|e| ExportError::from_io(&e, path))
+}
@@ -0,0 +1,174 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use iced::{ Element, Font, Theme };
+use iced::widget::{ button, text, Button };
+use rust_i18n::t;
+
+use crate::{ menu_button_style, steady_status };
+
+/// A button of the main menu or of a submenu, labelled with the translation
+/// of a locale key and drawn with `menu_button_style`.
+///
+/// Build one with `localized_menu_button`, then turn it into a `Button` to
+/// set its width and padding, or straight into an `Element`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuButton<Message>
+{
+    label: String,
+    on_press: Option<Message>,
+    font_size: f32,
+    font: Font,
+    focused: bool,
+    reduce_motion: bool,
+}
+
+// pub fn localized_menu_button<Message>(key: &str, on_press: Option<Message>, font_size: f32, font: Font) -> MenuButton<Message>
+/// Creates a menu button labelled with the translation of `key` in the
+/// current locale.
+///
+/// # Arguments
+/// * `key` - The locale key of the label.
+/// * `on_press` - The message sent when the button is pressed, or `None`
+///   for a disabled button.
+/// * `font_size` - The size of the label in pixels.
+/// * `font` - The font of the label, usually the primary font of the locale.
+///
+/// # Examples
+/// ```
+/// use iced::Font;
+/// use qrate_gui::{ localized_menu_button, Message };
+///
+/// rust_i18n::set_locale("en");
+/// let menu = localized_menu_button("settings", Some(Message::MenuClicked("settings".to_string())), 16.0, Font::DEFAULT);
+/// assert!(menu.is_pressable());
+/// assert_eq!(menu.get_label(), "Settings");
+/// assert!(!menu.is_focused());
+///
+/// rust_i18n::set_locale("ko");
+/// assert_eq!(localized_menu_button::<Message>("settings", None, 16.0, Font::DEFAULT).get_label(), "설정");
+///
+/// let disabled = localized_menu_button::<Message>("open-containing-folder", None, 16.0, Font::DEFAULT);
+/// assert!(!disabled.is_pressable());
+/// ```
+pub fn localized_menu_button<Message>(key: &str, on_press: Option<Message>, font_size: f32, font: Font) -> MenuButton<Message>
+{
+    MenuButton
+    {
+        label: t!(key).to_string(),
+        on_press,
+        font_size,
+        font,
+        focused: false,
+        reduce_motion: false,
+    }
+}
+
+impl<Message> MenuButton<Message>
+{
+    // pub fn focused(self, focused: bool) -> Self
+    /// Draws the focus ring of `menu_button_style` while `focused` is `true`.
+    ///
+    /// # Arguments
+    /// * `focused` - Whether the button holds the keyboard focus.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::Font;
+    /// use qrate_gui::{ localized_menu_button, Message };
+    /// let menu = localized_menu_button::<Message>("help", None, 16.0, Font::DEFAULT).focused(true);
+    /// assert!(menu.is_focused());
+    /// ```
+    pub fn focused(self, focused: bool) -> Self
+    {
+        Self { focused, ..self }
+    }
+
+    // pub fn reduce_motion(self, reduce_motion: bool) -> Self
+    /// Leaves out the hover highlight while `reduce_motion` is `true`.
+    ///
+    /// # Arguments
+    /// * `reduce_motion` - Whether the user asked for reduced motion.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::Font;
+    /// use qrate_gui::{ localized_menu_button, Message };
+    /// let menu = localized_menu_button::<Message>("help", None, 16.0, Font::DEFAULT);
+    /// assert!(!menu.is_motion_reduced());
+    /// assert!(menu.reduce_motion(true).is_motion_reduced());
+    /// ```
+    pub fn reduce_motion(self, reduce_motion: bool) -> Self
+    {
+        Self { reduce_motion, ..self }
+    }
+
+    // pub fn get_label(&self) -> &str
+    /// Returns the translated label.
+    ///
+    /// # Examples
+    /// See `localized_menu_button`.
+    pub fn get_label(&self) -> &str
+    {
+        &self.label
+    }
+
+    // pub fn is_pressable(&self) -> bool
+    /// Returns whether pressing the button sends a message.
+    ///
+    /// # Examples
+    /// See `localized_menu_button`.
+    pub fn is_pressable(&self) -> bool
+    {
+        self.on_press.is_some()
+    }
+
+    // pub fn is_focused(&self) -> bool
+    /// Returns whether the button draws the focus ring.
+    ///
+    /// # Examples
+    /// See `MenuButton::focused`.
+    pub fn is_focused(&self) -> bool
+    {
+        self.focused
+    }
+
+    // pub fn is_motion_reduced(&self) -> bool
+    /// Returns whether the button leaves out the hover highlight.
+    ///
+    /// # Examples
+    /// See `MenuButton::reduce_motion`.
+    pub fn is_motion_reduced(&self) -> bool
+    {
+        self.reduce_motion
+    }
+
+    // pub fn into_button<'a>(self) -> Button<'a, Message>
+    /// Builds the styled button, which can still be given a width and a
+    /// padding.
+    pub fn into_button<'a>(self) -> Button<'a, Message>
+    where Message: Clone + 'a
+    {
+        let focused = self.focused;
+        let reduce_motion = self.reduce_motion;
+        button(text(self.label).size(self.font_size).font(self.font))
+            .on_press_maybe(self.on_press)
+            .style(move |_theme: &Theme, status| menu_button_style(steady_status(status, reduce_motion), focused))
+    }
+}
+
+impl<'a, Message> From<MenuButton<Message>> for Element<'a, Message>
+where Message: Clone + 'a
+{
+    fn from(menu: MenuButton<Message>) -> Self
+    {
+        menu.into_button().into()
+    }
+}
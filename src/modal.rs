@@ -0,0 +1,160 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use iced::{ Element, Color, Theme, Length };
+use iced::widget::{ button, center, column, container, mouse_area, opaque, row, stack, text };
+use rust_i18n::t;
+
+use crate::with_focus_ring;
+
+// pub(crate) fn modal<'a, Message>(base, content, on_blur) -> Element<'a, Message>
+/// Shows `content` centered above a dimmed `base`.
+///
+/// Clicks outside of `content` produce `on_blur`, and the underlying widgets
+/// do not receive any mouse interaction while the modal is open.
+pub(crate) fn modal<'a, Message>(base: impl Into<Element<'a, Message>>,
+                                 content: impl Into<Element<'a, Message>>,
+                                 on_blur: Message) -> Element<'a, Message>
+where Message: Clone + 'a
+{
+    let dialog = container(content)
+        .padding(20)
+        .style(|theme: &Theme| {
+            let palette = theme.extended_palette();
+            container::Style {
+                background: Some(palette.background.base.color.into()),
+                text_color: Some(palette.background.base.text),
+                ..Default::default()
+            }
+        });
+
+    stack![
+        base.into(),
+        opaque(
+            mouse_area(
+                center(opaque(dialog))
+                    .style(|_theme: &Theme| {
+                        container::Style {
+                            background: Some(Color { a: 0.6, ..Color::BLACK }.into()),
+                            ..Default::default()
+                        }
+                    })
+            )
+            .on_press(on_blur)
+        )
+    ]
+    .into()
+}
+
+/// One control of an open modal that the keyboard focus stops on.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModalControl<Message>
+{
+    /// A text input, focused through the contained widget id.
+    Input(&'static str),
+
+    /// A button or checkbox, which produces the contained message when it
+    /// is activated with Enter or Space.
+    Button(Message),
+}
+
+// pub fn cycle_focus(current: Option<usize>, count: usize, backwards: bool) -> Option<usize>
+/// Moves the focus of a focus trap to the next or previous of its `count`
+/// controls, wrapping around at both ends so that it never leaves them.
+///
+/// # Arguments
+/// * `current` - The focused control, or `None` if none is focused yet.
+/// * `count` - The number of controls in the trap.
+/// * `backwards` - `true` for Shift+Tab.
+///
+/// # Output
+/// The newly focused control, or `None` if there are no controls.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::cycle_focus;
+/// assert_eq!(cycle_focus(None, 3, false), Some(0));
+/// assert_eq!(cycle_focus(None, 3, true), Some(2));
+/// assert_eq!(cycle_focus(Some(2), 3, false), Some(0));
+/// assert_eq!(cycle_focus(Some(0), 3, true), Some(2));
+/// assert_eq!(cycle_focus(Some(7), 3, false), Some(0));
+/// assert_eq!(cycle_focus(Some(0), 0, false), None);
+/// ```
+pub fn cycle_focus(current: Option<usize>, count: usize, backwards: bool) -> Option<usize>
+{
+    if count == 0
+        { return None; }
+    Some(match current.filter(|&index| index < count)
+    {
+        Some(index) if backwards => (index + count - 1) % count,
+        Some(index) => (index + 1) % count,
+        None if backwards => count - 1,
+        None => 0,
+    })
+}
+
+/// The contents of a confirmation dialog shown with `confirm`.
+pub(crate) struct ConfirmDialog<'a, Message>
+{
+    pub title: String,
+    pub body: String,
+
+    /// Shown between the body and the buttons, such as a "don't ask again"
+    /// checkbox. It comes first in the tab order.
+    pub extra: Option<Element<'a, Message>>,
+
+    pub confirm_label: String,
+    pub on_confirm: Message,
+    pub on_cancel: Message,
+
+    /// The control holding the keyboard focus, in tab order.
+    pub focused: Option<usize>,
+}
+
+// pub(crate) fn confirm<'a, Message>(base, dialog) -> Element<'a, Message>
+/// Shows a confirmation dialog above `base` with a confirm and a cancel
+/// button. Clicking outside of the dialog cancels it.
+pub(crate) fn confirm<'a, Message>(base: impl Into<Element<'a, Message>>,
+                                   dialog: ConfirmDialog<'a, Message>) -> Element<'a, Message>
+where Message: Clone + 'a
+{
+    let offset = usize::from(dialog.extra.is_some());
+    let is_focused = |index: usize| dialog.focused == Some(index);
+    let extra = dialog.extra.map(|extra| {
+        let focused = is_focused(0);
+        container(extra)
+            .padding(2)
+            .style(move |_theme: &Theme| container::Style {
+                border: with_focus_ring(button::Style::default(), focused).border,
+                ..Default::default()
+            })
+    });
+    let confirm_focused = is_focused(offset);
+    let cancel_focused = is_focused(offset + 1);
+    let content = column![
+        text(dialog.title).size(24),
+        text(dialog.body).size(18),
+    ]
+    .push(extra)
+    .push(row![
+        button(text(dialog.confirm_label).size(18))
+            .on_press(dialog.on_confirm)
+            .padding(8)
+            .style(move |theme: &Theme, status| with_focus_ring(button::primary(theme, status), confirm_focused)),
+        button(text(t!("cancel")).size(18))
+            .on_press(dialog.on_cancel.clone())
+            .padding(8)
+            .style(move |theme: &Theme, status| with_focus_ring(button::primary(theme, status), cancel_focused)),
+    ].spacing(10))
+    .spacing(10)
+    .width(Length::Fixed(420.0));
+
+    modal(base, content, dialog.on_cancel)
+}
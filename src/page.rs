@@ -0,0 +1,174 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fmt;
+
+/// A page of the main content area.
+///
+/// Every page has a string id, which is what the settings file stores as
+/// the last page. Ids that name no page, such as those of a newer version,
+/// map to `Page::ComingSoon`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Page
+{
+    /// The welcome page.
+    #[default]
+    Main,
+
+    /// The interface and export languages.
+    LanguageSettings,
+
+    /// Motion and other look-and-feel settings.
+    AtmosphereSettings,
+
+    /// The idle period and PIN of the screen lock.
+    IdleLockSettings,
+
+    /// What is restored on launch.
+    StartupSettings,
+
+    /// Where question banks are kept.
+    StorageSettings,
+
+    /// The criteria for drawing the questions of an exam.
+    Criteria,
+
+    /// The generated exam before it is exported.
+    ExamPreview,
+
+    /// The students of the loaded roster.
+    StudentList,
+
+    /// The questions table of the loaded bank.
+    QuestionBankEdit,
+
+    /// The title, author, subject and description of the loaded bank.
+    BankMetadata,
+
+    /// The malformed questions of the loaded bank.
+    BankValidation,
+
+    /// The version and build of the program.
+    SoftwareInfo,
+
+    /// Recent events and errors.
+    Diagnostics,
+
+    /// How answers of self-study sessions are graded.
+    GradingCriteria,
+
+    /// A self-study session in progress.
+    Learning,
+
+    /// The grades of the last self-study session.
+    GradeReport,
+
+    /// The lock screen shown after a period of inactivity.
+    Locked,
+
+    /// A placeholder for features that are not there yet.
+    ComingSoon,
+}
+
+impl Page
+{
+    /// Every page, in no particular order.
+    pub const ALL: [Page; 19] = [
+        Page::Main, Page::LanguageSettings, Page::AtmosphereSettings, Page::IdleLockSettings,
+        Page::StartupSettings, Page::StorageSettings, Page::Criteria, Page::ExamPreview,
+        Page::StudentList, Page::QuestionBankEdit, Page::BankMetadata, Page::BankValidation, Page::SoftwareInfo,
+        Page::Diagnostics, Page::GradingCriteria, Page::Learning, Page::GradeReport,
+        Page::Locked, Page::ComingSoon,
+    ];
+
+    // pub fn get_id(&self) -> &'static str
+    /// Returns the string id of the page, as stored in the settings.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::Page;
+    /// assert_eq!(Page::QuestionBankEdit.get_id(), "question-bank-edit");
+    ///
+    /// // Every page comes back from its id.
+    /// for page in Page::ALL
+    ///     { assert_eq!(Page::from_id(page.get_id()), page); }
+    /// ```
+    pub fn get_id(&self) -> &'static str
+    {
+        match self
+        {
+            Page::Main => "main",
+            Page::LanguageSettings => "language-settings",
+            Page::AtmosphereSettings => "atmosphere-settings",
+            Page::IdleLockSettings => "idle-lock-settings",
+            Page::StartupSettings => "startup-settings",
+            Page::StorageSettings => "storage-settings",
+            Page::Criteria => "criteria-for-question-extraction",
+            Page::ExamPreview => "exam-preview",
+            Page::StudentList => "student-list",
+            Page::QuestionBankEdit => "question-bank-edit",
+            Page::BankMetadata => "bank-metadata",
+            Page::BankValidation => "validate-bank",
+            Page::SoftwareInfo => "software-info",
+            Page::Diagnostics => "diagnostics",
+            Page::GradingCriteria => "grading-criteria",
+            Page::Learning => "learning",
+            Page::GradeReport => "grade-report",
+            Page::Locked => "locked",
+            Page::ComingSoon => "coming-soon",
+        }
+    }
+
+    // pub fn from_id(id: &str) -> Self
+    /// Returns the page with the string id `id`.
+    ///
+    /// # Arguments
+    /// * `id` - The string id, such as `"student-list"`.
+    ///
+    /// # Output
+    /// The page, or `Page::ComingSoon` if no page has that id.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::Page;
+    /// assert_eq!(Page::from_id("student-list"), Page::StudentList);
+    /// assert_eq!(Page::from_id("studnet-list"), Page::ComingSoon);
+    /// assert_eq!(Page::from_id(""), Page::ComingSoon);
+    /// ```
+    pub fn from_id(id: &str) -> Self
+    {
+        Page::ALL.into_iter().find(|page| page.get_id() == id).unwrap_or(Page::ComingSoon)
+    }
+
+    // pub fn is_transient(&self) -> bool
+    /// Returns whether the page only makes sense within the session that
+    /// opened it, such as an exam in progress, and is therefore never
+    /// restored on launch.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::Page;
+    /// assert!(Page::Learning.is_transient());
+    /// assert!(Page::Locked.is_transient());
+    /// assert!(!Page::QuestionBankEdit.is_transient());
+    /// ```
+    pub fn is_transient(&self) -> bool
+    {
+        matches!(self, Page::Learning | Page::GradeReport | Page::ExamPreview | Page::Locked)
+    }
+}
+
+impl fmt::Display for Page
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.get_id())
+    }
+}
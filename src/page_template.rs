@@ -0,0 +1,411 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::path::{ Path, PathBuf };
+
+use serde::{ Serialize, Deserialize };
+
+use crate::ExamInfo;
+
+/// The placeholders a header or footer template may contain.
+pub const PAGE_PLACEHOLDERS: [&str; 5] = ["{page}", "{total_pages}", "{title}", "{course}", "{date}"];
+
+/// The page margins offered for PDF export, in millimetres.
+pub const PAGE_MARGINS: [u8; 6] = [5, 10, 15, 20, 25, 30];
+
+/// The page margin of a PDF export unless another one is chosen, in
+/// millimetres.
+pub const DEFAULT_PAGE_MARGIN: u8 = 10;
+
+/// The paper a PDF export is laid out for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PaperSize
+{
+    /// ISO A4, 210 × 297 mm, used outside of North America.
+    #[default]
+    A4,
+
+    /// US Letter, 8.5 × 11 in.
+    Letter,
+}
+
+impl PaperSize
+{
+    /// All paper sizes, in the order they are offered to the user.
+    pub const ALL: [PaperSize; 2] = [ PaperSize::A4, PaperSize::Letter ];
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key describing this paper size.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::PaperSize;
+    /// assert_eq!(PaperSize::Letter.get_key(), "paper-letter");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            PaperSize::A4 => "paper-a4",
+            PaperSize::Letter => "paper-letter",
+        }
+    }
+
+    // pub fn get_size(&self) -> (f32, f32)
+    /// Returns the width and the height of the paper in millimetres, in
+    /// portrait orientation.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::PaperSize;
+    /// assert_eq!(PaperSize::A4.get_size(), (210.0, 297.0));
+    /// assert_eq!(PaperSize::Letter.get_size(), (215.9, 279.4));
+    /// ```
+    pub fn get_size(&self) -> (f32, f32)
+    {
+        match self
+        {
+            PaperSize::A4 => (210.0, 297.0),
+            PaperSize::Letter => (215.9, 279.4),
+        }
+    }
+}
+
+/// The header and footer printed on every page of an exported PDF exam,
+/// such as the school name and logo at the top and the page number at the
+/// bottom.
+///
+/// Both are plain text with placeholders that are filled in for each page:
+/// `{page}` and `{total_pages}` with the page number and the page count,
+/// `{title}`, `{course}` and `{date}` with the details of the exam.
+///
+/// The template also holds the paper size and the margin of the pages,
+/// which every PDF export is laid out with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageTemplate
+{
+    #[serde(default)]
+    header: String,
+
+    #[serde(default)]
+    footer: String,
+
+    #[serde(default)]
+    logo: Option<PathBuf>,
+
+    #[serde(default)]
+    paper_size: PaperSize,
+
+    /// In millimetres, on all four sides.
+    #[serde(default = "default_page_margin")]
+    margin: u8,
+}
+
+impl Default for PageTemplate
+{
+    fn default() -> Self
+    {
+        Self
+        {
+            header: String::new(),
+            footer: String::new(),
+            logo: None,
+            paper_size: PaperSize::default(),
+            margin: DEFAULT_PAGE_MARGIN,
+        }
+    }
+}
+
+/// Gives settings saved before the margin could be chosen the margin that
+/// was used then.
+fn default_page_margin() -> u8
+{
+    DEFAULT_PAGE_MARGIN
+}
+
+impl PageTemplate
+{
+    // pub fn new() -> Self
+    /// Creates a template that prints neither a header nor a footer, on A4
+    /// paper with a margin of `DEFAULT_PAGE_MARGIN`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::PageTemplate;
+    /// assert!(PageTemplate::new().is_empty());
+    /// ```
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // pub fn get_header(&self) -> &str
+    /// Returns the header template, printed at the top of every page.
+    ///
+    /// # Examples
+    /// See `PageTemplate::render_footer`.
+    pub fn get_header(&self) -> &str
+    {
+        &self.header
+    }
+
+    // pub fn set_header(&mut self, header: String)
+    /// Sets the header template. Each line of it is printed centered.
+    ///
+    /// # Examples
+    /// See `PageTemplate::render_footer`.
+    pub fn set_header(&mut self, header: String)
+    {
+        self.header = header;
+    }
+
+    // pub fn get_footer(&self) -> &str
+    /// Returns the footer template, printed at the bottom of every page.
+    ///
+    /// # Examples
+    /// See `PageTemplate::render_footer`.
+    pub fn get_footer(&self) -> &str
+    {
+        &self.footer
+    }
+
+    // pub fn set_footer(&mut self, footer: String)
+    /// Sets the footer template. Each line of it is printed centered.
+    ///
+    /// # Examples
+    /// See `PageTemplate::render_footer`.
+    pub fn set_footer(&mut self, footer: String)
+    {
+        self.footer = footer;
+    }
+
+    // pub fn get_logo(&self) -> Option<&Path>
+    /// Returns the image printed above the header, if any.
+    ///
+    /// # Examples
+    /// See `PageTemplate::set_logo`.
+    pub fn get_logo(&self) -> Option<&Path>
+    {
+        self.logo.as_deref()
+    }
+
+    // pub fn set_logo(&mut self, logo: Option<PathBuf>)
+    /// Sets the image printed above the header, such as the school logo.
+    /// A logo that cannot be read is left out of the export.
+    ///
+    /// # Arguments
+    /// * `logo` - The path of the image file, or `None` for no logo.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::{ Path, PathBuf };
+    /// use qrate_gui::PageTemplate;
+    /// let mut template = PageTemplate::new();
+    /// template.set_logo(Some(PathBuf::from("logo.png")));
+    /// assert_eq!(template.get_logo(), Some(Path::new("logo.png")));
+    /// assert!(!template.is_empty());
+    /// template.set_logo(None);
+    /// assert!(template.is_empty());
+    /// ```
+    pub fn set_logo(&mut self, logo: Option<PathBuf>)
+    {
+        self.logo = logo;
+    }
+
+    // pub fn get_paper_size(&self) -> PaperSize
+    /// Returns the paper the pages are laid out for.
+    ///
+    /// # Examples
+    /// See `PageTemplate::get_printable_area`.
+    pub fn get_paper_size(&self) -> PaperSize
+    {
+        self.paper_size
+    }
+
+    // pub fn set_paper_size(&mut self, paper_size: PaperSize)
+    /// Sets the paper the pages are laid out for.
+    ///
+    /// # Examples
+    /// See `PageTemplate::get_printable_area`.
+    pub fn set_paper_size(&mut self, paper_size: PaperSize)
+    {
+        self.paper_size = paper_size;
+    }
+
+    // pub fn get_margin(&self) -> u8
+    /// Returns the margin left blank on all four sides of a page, in
+    /// millimetres.
+    ///
+    /// # Examples
+    /// See `PageTemplate::get_printable_area`.
+    pub fn get_margin(&self) -> u8
+    {
+        self.margin
+    }
+
+    // pub fn set_margin(&mut self, margin: u8)
+    /// Sets the margin left blank on all four sides of a page.
+    ///
+    /// # Arguments
+    /// * `margin` - The margin in millimetres, at most the largest of
+    ///   `PAGE_MARGINS`.
+    ///
+    /// # Examples
+    /// See `PageTemplate::get_printable_area`.
+    pub fn set_margin(&mut self, margin: u8)
+    {
+        self.margin = margin.min(PAGE_MARGINS[PAGE_MARGINS.len() - 1]);
+    }
+
+    // pub fn get_printable_area(&self) -> (f32, f32)
+    /// Returns the width and the height of the area inside the margins, in
+    /// millimetres, which the header, the questions and the footer share.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ PageTemplate, PaperSize, DEFAULT_PAGE_MARGIN };
+    ///
+    /// let mut template = PageTemplate::new();
+    /// assert_eq!(template.get_paper_size(), PaperSize::A4);
+    /// assert_eq!(template.get_margin(), DEFAULT_PAGE_MARGIN);
+    /// assert_eq!(template.get_printable_area(), (190.0, 277.0));
+    ///
+    /// template.set_margin(20);
+    /// assert_eq!(template.get_printable_area(), (170.0, 257.0));
+    ///
+    /// // Letter is wider but shorter than A4.
+    /// template.set_paper_size(PaperSize::Letter);
+    /// let (width, height) = template.get_printable_area();
+    /// assert!((width - 175.9).abs() < 0.01);
+    /// assert!((height - 239.4).abs() < 0.01);
+    ///
+    /// template.set_margin(200);
+    /// assert_eq!(template.get_margin(), 30);
+    /// ```
+    pub fn get_printable_area(&self) -> (f32, f32)
+    {
+        let (width, height) = self.paper_size.get_size();
+        let margins = 2.0 * self.margin as f32;
+        (width - margins, height - margins)
+    }
+
+    // pub fn is_empty(&self) -> bool
+    /// Returns whether the template prints nothing at all.
+    ///
+    /// # Examples
+    /// See `PageTemplate::set_logo`.
+    pub fn is_empty(&self) -> bool
+    {
+        self.header.trim().is_empty() && self.footer.trim().is_empty() && self.logo.is_none()
+    }
+
+    // pub fn needs_total_pages(&self) -> bool
+    /// Returns whether the header or the footer shows the page count, which
+    /// is only known once the whole document has been laid out.
+    ///
+    /// # Examples
+    /// See `PageTemplate::render_footer`.
+    pub fn needs_total_pages(&self) -> bool
+    {
+        self.header.contains("{total_pages}") || self.footer.contains("{total_pages}")
+    }
+
+    // pub fn render_header(&self, info: &ExamInfo, page: usize, total_pages: usize) -> String
+    /// Returns the header of page `page`, with its placeholders filled in.
+    ///
+    /// # Examples
+    /// See `PageTemplate::render_footer`.
+    pub fn render_header(&self, info: &ExamInfo, page: usize, total_pages: usize) -> String
+    {
+        fill_placeholders(&self.header, info, page, total_pages)
+    }
+
+    // pub fn render_footer(&self, info: &ExamInfo, page: usize, total_pages: usize) -> String
+    /// Returns the footer of page `page`, with its placeholders filled in.
+    ///
+    /// # Arguments
+    /// * `info` - The details of the exam.
+    /// * `page` - The 1-based number of the page.
+    /// * `total_pages` - The number of pages of the document.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ExamInfo, PageTemplate };
+    ///
+    /// let mut info = ExamInfo::new();
+    /// info.set_title("Midterm".to_string());
+    /// info.set_course("Math 101".to_string());
+    /// let mut template = PageTemplate::new();
+    /// template.set_header("Springfield High School\n{course} — {title}".to_string());
+    /// template.set_footer("Page {page} of {total_pages}\nGood luck!".to_string());
+    /// assert_eq!(template.get_footer(), "Page {page} of {total_pages}\nGood luck!");
+    /// assert!(template.needs_total_pages());
+    ///
+    /// assert_eq!(template.render_header(&info, 1, 3), "Springfield High School\nMath 101 — Midterm");
+    /// assert_eq!(template.render_footer(&info, 2, 3), "Page 2 of 3\nGood luck!");
+    /// assert_eq!(template.render_footer(&info, 10, 12), "Page 10 of 12\nGood luck!");
+    ///
+    /// template.set_footer("{page}".to_string());
+    /// assert!(!template.needs_total_pages());
+    /// assert_eq!(template.get_header(), "Springfield High School\n{course} — {title}");
+    /// ```
+    pub fn render_footer(&self, info: &ExamInfo, page: usize, total_pages: usize) -> String
+    {
+        fill_placeholders(&self.footer, info, page, total_pages)
+    }
+}
+
+// pub fn fill_placeholders(template: &str, info: &ExamInfo, page: usize, total_pages: usize) -> String
+/// Fills in the placeholders of a header or footer template. Braces that
+/// do not form a known placeholder are printed as written.
+///
+/// # Arguments
+/// * `template` - The template text.
+/// * `info` - The details of the exam, for `{title}`, `{course}` and
+///   `{date}`.
+/// * `page` - The 1-based number of the page, for `{page}`.
+/// * `total_pages` - The number of pages, for `{total_pages}`.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ fill_placeholders, ExamInfo };
+/// let mut info = ExamInfo::new();
+/// info.set_date("2026-10-17".to_string());
+/// assert_eq!(fill_placeholders("{date} · {page}/{total_pages}", &info, 4, 5), "2026-10-17 · 4/5");
+/// assert_eq!(fill_placeholders("{pages} {page", &info, 4, 5), "{pages} {page");
+/// assert_eq!(fill_placeholders("{title}", &info, 1, 1), "");
+/// ```
+pub fn fill_placeholders(template: &str, info: &ExamInfo, page: usize, total_pages: usize) -> String
+{
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{')
+    {
+        filled.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match PAGE_PLACEHOLDERS.into_iter().find(|placeholder| rest.starts_with(placeholder))
+        {
+            Some(placeholder) => {
+                match placeholder
+                {
+                    "{page}" => filled.push_str(&page.to_string()),
+                    "{total_pages}" => filled.push_str(&total_pages.to_string()),
+                    "{title}" => filled.push_str(info.get_title()),
+                    "{course}" => filled.push_str(info.get_course()),
+                    _ => filled.push_str(info.get_date()),
+                }
+                rest = &rest[placeholder.len()..];
+            },
+            None => { filled.push('{'); rest = &rest[1..]; },
+        }
+    }
+    filled.push_str(rest);
+    filled
+}
@@ -0,0 +1,97 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+/// The page sizes offered for the questions table.
+pub const PAGE_SIZES: [usize; 4] = [ 25, 50, 100, 200 ];
+
+/// The number of questions shown per page until the user picks another size.
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+
+// pub fn page_count(total: usize, page_size: usize) -> usize
+/// Returns the number of pages needed to show `total` items.
+///
+/// # Arguments
+/// * `total` - The number of items.
+/// * `page_size` - The number of items per page; `0` is treated as `1`.
+///
+/// # Output
+/// The number of pages, at least `1` so that an empty list still has a page.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::page_count;
+/// assert_eq!(page_count(0, 25), 1);
+/// assert_eq!(page_count(25, 25), 1);
+/// assert_eq!(page_count(26, 25), 2);
+/// assert_eq!(page_count(3000, 50), 60);
+/// ```
+pub fn page_count(total: usize, page_size: usize) -> usize
+{
+    total.div_ceil(page_size.max(1)).max(1)
+}
+
+// pub fn page_slice<T>(items: &[T], page: usize, page_size: usize) -> &[T]
+/// Returns the items shown on one page.
+///
+/// # Arguments
+/// * `items` - All items, already filtered and sorted.
+/// * `page` - The 0-based page; pages past the end show the last page.
+/// * `page_size` - The number of items per page; `0` is treated as `1`.
+///
+/// # Output
+/// The items of the page. Only the last page may hold fewer than
+/// `page_size` items.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::page_slice;
+/// let items: Vec<u16> = (1..=60).collect();
+/// assert_eq!(page_slice(&items, 0, 25), &items[0..25]);
+/// assert_eq!(page_slice(&items, 1, 25), &items[25..50]);
+///
+/// // The last page holds the remaining ten.
+/// assert_eq!(page_slice(&items, 2, 25), &items[50..60]);
+/// assert_eq!(page_slice(&items, 9, 25), &items[50..60]);
+/// assert!(page_slice::<u16>(&[], 0, 25).is_empty());
+/// ```
+pub fn page_slice<T>(items: &[T], page: usize, page_size: usize) -> &[T]
+{
+    let page_size = page_size.max(1);
+    let page = page.min(page_count(items.len(), page_size) - 1);
+    let start = (page * page_size).min(items.len());
+    let end = (start + page_size).min(items.len());
+    &items[start..end]
+}
+
+// pub fn clamp_question_number(number: usize, count: usize) -> Option<usize>
+/// Turns a question number typed into a "go to" box into the 0-based index
+/// of a question of the list, taking numbers past either end to the first
+/// or the last question.
+///
+/// # Arguments
+/// * `number` - The 1-based number as typed.
+/// * `count` - The number of questions in the list.
+///
+/// # Output
+/// The index, or `None` if the list is empty.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::clamp_question_number;
+/// assert_eq!(clamp_question_number(237, 300), Some(236));
+/// assert_eq!(clamp_question_number(1, 300), Some(0));
+/// assert_eq!(clamp_question_number(0, 300), Some(0));
+/// assert_eq!(clamp_question_number(999, 300), Some(299));
+/// assert_eq!(clamp_question_number(5, 0), None);
+/// ```
+pub fn clamp_question_number(number: usize, count: usize) -> Option<usize>
+{
+    (count > 0).then(|| number.clamp(1, count) - 1)
+}
@@ -0,0 +1,54 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::any::Any;
+use std::panic::{ self, AssertUnwindSafe };
+
+// pub fn catch_panic<T>(task: impl FnOnce() -> T) -> Result<T, String>
+/// Runs `task`, turning a panic inside it into an error message.
+///
+/// Background tasks are run through this function so that an unexpected
+/// panic while parsing or exporting is reported to the user instead of
+/// taking the whole application down. The panic is still printed to
+/// standard error by the panic hook.
+///
+/// # Arguments
+/// * `task` - The work to run.
+///
+/// # Output
+/// `Ok` with the result of `task`, or `Err` with the panic message.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::catch_panic;
+///
+/// assert_eq!(catch_panic(|| 6 * 7), Ok(42));
+///
+/// let result: Result<(), String> = catch_panic(|| panic!("corrupt row {}", 3));
+/// assert_eq!(result, Err("corrupt row 3".to_string()));
+///
+/// let result: Result<u8, String> = catch_panic(|| std::panic::panic_any(7_u8));
+/// assert_eq!(result, Err("unknown panic".to_string()));
+/// ```
+pub fn catch_panic<T>(task: impl FnOnce() -> T) -> Result<T, String>
+{
+    // The task's state is dropped on panic and never observed again.
+    panic::catch_unwind(AssertUnwindSafe(task)).map_err(|payload| get_panic_message(payload.as_ref()))
+}
+
+fn get_panic_message(payload: &(dyn Any + Send)) -> String
+{
+    if let Some(message) = payload.downcast_ref::<&str>()
+        { message.to_string() }
+    else if let Some(message) = payload.downcast_ref::<String>()
+        { message.clone() }
+    else
+        { "unknown panic".to_string() }
+}
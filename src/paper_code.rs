@@ -0,0 +1,152 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use image::{ DynamicImage, Rgb, RgbImage };
+use qrcode::{ Color, QrCode };
+
+/// The prefix of every paper code, telling it apart from other QR codes.
+pub const PAPER_CODE_PREFIX: &str = "QRATE";
+
+/// The number of pixels per module of a printed QR code.
+const MODULE_PIXELS: u32 = 8;
+
+/// The blank margin around a printed QR code, in modules.
+const QUIET_ZONE: u32 = 4;
+
+/// Identifies one printed paper, so that its answer key can be found again
+/// by scanning the QR code in its corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaperCode
+{
+    name: String,
+    seed: u64,
+}
+
+impl PaperCode
+{
+    // pub fn new(name: String, seed: u64) -> Self
+    /// Creates the code of the paper `name` generated with `seed`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the paper, such as the variant name `"A"`.
+    /// * `seed` - The seed the paper was generated with.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::PaperCode;
+    /// let code = PaperCode::new("B".to_string(), 42);
+    /// assert_eq!(code.get_name(), "B");
+    /// assert_eq!(code.get_seed(), 42);
+    /// ```
+    pub fn new(name: String, seed: u64) -> Self
+    {
+        Self { name, seed }
+    }
+
+    // pub fn get_name(&self) -> &str
+    /// Returns the name of the paper.
+    ///
+    /// # Examples
+    /// See `PaperCode::new`.
+    pub fn get_name(&self) -> &str
+    {
+        &self.name
+    }
+
+    // pub fn get_seed(&self) -> u64
+    /// Returns the seed the paper was generated with.
+    ///
+    /// # Examples
+    /// See `PaperCode::new`.
+    pub fn get_seed(&self) -> u64
+    {
+        self.seed
+    }
+
+    // pub fn to_payload(&self) -> String
+    /// Returns the text encoded in the QR code, such as `"QRATE:A:42"`.
+    ///
+    /// # Examples
+    /// See `PaperCode::parse`.
+    pub fn to_payload(&self) -> String
+    {
+        format!("{}:{}:{}", PAPER_CODE_PREFIX, self.name, self.seed)
+    }
+
+    // pub fn parse(payload: &str) -> Option<Self>
+    /// Reads the code back from the text of a scanned QR code.
+    ///
+    /// # Arguments
+    /// * `payload` - The scanned text.
+    ///
+    /// # Output
+    /// The code, or `None` if `payload` is not a paper code.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::PaperCode;
+    /// let code = PaperCode::new("Variant: A".to_string(), u64::MAX);
+    /// assert_eq!(PaperCode::parse(&code.to_payload()), Some(code));
+    ///
+    /// assert_eq!(PaperCode::parse("QRATE:A:42"), Some(PaperCode::new("A".to_string(), 42)));
+    /// assert_eq!(PaperCode::parse("QRATE:A:forty-two"), None);
+    /// assert_eq!(PaperCode::parse("https://example.com"), None);
+    /// ```
+    pub fn parse(payload: &str) -> Option<Self>
+    {
+        // The name may contain colons itself, so the seed is split off the end.
+        let rest = payload.strip_prefix(PAPER_CODE_PREFIX)?.strip_prefix(':')?;
+        let (name, seed) = rest.rsplit_once(':')?;
+        Some(Self::new(name.to_string(), seed.parse().ok()?))
+    }
+
+    // pub fn to_modules(&self) -> Option<Vec<Vec<bool>>>
+    /// Encodes the payload as a QR code.
+    ///
+    /// # Output
+    /// The rows of the code, `true` for dark modules, or `None` if the
+    /// payload is too long for a QR code.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::PaperCode;
+    /// let modules = PaperCode::new("A".to_string(), 42).to_modules().unwrap();
+    /// assert!(modules.len() >= 21);
+    /// assert!(modules.iter().all(|row| row.len() == modules.len()));
+    /// // Every QR code starts with a dark finder pattern in its corner.
+    /// assert!(modules[0][0]);
+    /// ```
+    pub fn to_modules(&self) -> Option<Vec<Vec<bool>>>
+    {
+        let code = QrCode::new(self.to_payload().as_bytes()).ok()?;
+        let width = code.width();
+        let colors = code.to_colors();
+        Some(colors.chunks(width)
+                .map(|row| row.iter().map(|&color| color == Color::Dark).collect())
+                .collect())
+    }
+
+    // pub(crate) fn to_image(&self) -> Option<DynamicImage>
+    /// Draws the QR code in black on white with its quiet zone, to be
+    /// printed on the paper.
+    pub(crate) fn to_image(&self) -> Option<DynamicImage>
+    {
+        let modules = self.to_modules()?;
+        let size = (modules.len() as u32 + 2 * QUIET_ZONE) * MODULE_PIXELS;
+        let picture = RgbImage::from_fn(size, size, |x, y| {
+            let dark = (x / MODULE_PIXELS).checked_sub(QUIET_ZONE)
+                        .zip((y / MODULE_PIXELS).checked_sub(QUIET_ZONE))
+                        .and_then(|(column, row)| modules.get(row as usize)?.get(column as usize).copied())
+                        .unwrap_or(false);
+            if dark { Rgb([0, 0, 0]) } else { Rgb([255, 255, 255]) }
+        });
+        Some(DynamicImage::ImageRgb8(picture))
+    }
+}
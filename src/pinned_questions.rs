@@ -0,0 +1,83 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::{ BTreeMap, BTreeSet };
+
+use qrate::{ Question, Questions };
+
+// pub fn parse_pins(text: &str) -> Option<BTreeMap<u16, usize>>
+/// Parses pinned questions typed as `id@position`, separated by commas,
+/// such as `12@1, 7@5` for question 12 first and question 7 fifth.
+///
+/// # Arguments
+/// * `text` - The typed text; blank entries are skipped.
+///
+/// # Output
+/// The 1-based position of each pinned question by id, or `None` if an
+/// entry is malformed, uses `0`, or repeats a question or a position.
+///
+/// # Examples
+/// ```
+/// use std::collections::BTreeMap;
+/// use qrate_gui::parse_pins;
+///
+/// assert_eq!(parse_pins(" 12@1, 7 @ 5, "), Some(BTreeMap::from([(12, 1), (7, 5)])));
+/// assert_eq!(parse_pins(""), Some(BTreeMap::new()));
+/// assert_eq!(parse_pins("12"), None);
+/// assert_eq!(parse_pins("12@0"), None);
+/// assert_eq!(parse_pins("12@1, 12@2"), None);
+/// assert_eq!(parse_pins("12@1, 7@1"), None);
+/// ```
+pub fn parse_pins(text: &str) -> Option<BTreeMap<u16, usize>>
+{
+    let mut pins = BTreeMap::new();
+    let mut positions = BTreeSet::new();
+    for entry in text.split(',').map(str::trim).filter(|entry| !entry.is_empty())
+    {
+        let (id, position) = entry.split_once('@')?;
+        let id: u16 = id.trim().parse().ok().filter(|id| *id > 0)?;
+        let position: usize = position.trim().parse().ok().filter(|position| *position > 0)?;
+        if !positions.insert(position) || pins.insert(id, position).is_some()
+            { return None; }
+    }
+    Some(pins)
+}
+
+// pub fn place_pinned(drawn: Questions, pinned: Vec<(usize, Question)>) -> Questions
+/// Puts pinned questions at their positions among the drawn ones, which
+/// fill the remaining positions in the order they were drawn.
+///
+/// # Arguments
+/// * `drawn` - The questions drawn at random.
+/// * `pinned` - The pinned questions with their 1-based positions. Positions
+///   past the end of the exam put the question last.
+///
+/// # Examples
+/// ```
+/// use qrate::Question;
+/// use qrate_gui::place_pinned;
+///
+/// let question = |id: u16| Question::new(id, id, 1, format!("Q{}", id), vec![]);
+/// let drawn = vec![question(4), question(9), question(2)];
+/// let pinned = vec![(3, question(7)), (1, question(12)), (10, question(5))];
+/// let ids: Vec<u16> = place_pinned(drawn, pinned).iter().map(|q| q.get_id()).collect();
+/// assert_eq!(ids, [12, 4, 7, 9, 2, 5]);
+/// ```
+pub fn place_pinned(drawn: Questions, mut pinned: Vec<(usize, Question)>) -> Questions
+{
+    pinned.sort_by_key(|(position, _)| *position);
+    let mut questions = drawn;
+    for (position, question) in pinned
+    {
+        let index = position.saturating_sub(1).min(questions.len());
+        questions.insert(index, question);
+    }
+    questions
+}
@@ -0,0 +1,50 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+/// The prompt lengths offered for the questions table, in characters.
+pub const PROMPT_LENGTHS: [usize; 5] = [ 40, 80, 120, 200, 400 ];
+
+/// The number of prompt characters shown in the questions table until the
+/// user picks another length.
+pub const DEFAULT_PROMPT_LENGTH: usize = 80;
+
+/// The mark appended to a prompt that was cut short.
+pub const ELLIPSIS: char = '…';
+
+// pub fn truncate_prompt(prompt: &str, max_chars: usize) -> String
+/// Shortens a prompt for one row of the questions table.
+///
+/// # Arguments
+/// * `prompt` - The full prompt.
+/// * `max_chars` - The number of characters kept; `0` is treated as `1`.
+///
+/// # Output
+/// The first `max_chars` characters of `prompt` followed by `ELLIPSIS`, or
+/// `prompt` unchanged if it is not longer than `max_chars`.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ truncate_prompt, ELLIPSIS };
+/// assert_eq!(truncate_prompt("What is 2 + 2?", 6), "What i…");
+/// assert_eq!(truncate_prompt("What is 2 + 2?", 14), "What is 2 + 2?");
+/// assert_eq!(truncate_prompt("What is 2 + 2?", 200), "What is 2 + 2?");
+///
+/// // Characters are counted, not bytes.
+/// assert_eq!(truncate_prompt("대한민국의 수도는?", 5), format!("대한민국의{}", ELLIPSIS));
+/// assert_eq!(truncate_prompt("", 5), "");
+/// ```
+pub fn truncate_prompt(prompt: &str, max_chars: usize) -> String
+{
+    match prompt.char_indices().nth(max_chars.max(1))
+    {
+        Some((end, _)) => format!("{}{}", &prompt[..end], ELLIPSIS),
+        None => prompt.to_string(),
+    }
+}
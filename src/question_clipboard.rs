@@ -0,0 +1,55 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use qrate::Question;
+use rust_i18n::t;
+
+use crate::exam::choice_label;
+
+// pub fn question_clipboard_text(question: &Question) -> String
+/// Formats one question as plain text for pasting into an email or another
+/// tool: the labeled prompt, its lettered choices, then the labeled answer.
+///
+/// # Arguments
+/// * `question` - The question to copy.
+///
+/// # Output
+/// The text, one line per choice, in the current locale.
+///
+/// # Examples
+/// ```
+/// use qrate::Question;
+/// use qrate_gui::question_clipboard_text;
+///
+/// rust_i18n::set_locale("en");
+/// let choices = vec![("3".to_string(), false), ("4".to_string(), true), ("four".to_string(), true)];
+/// let question = Question::new(7, 1, 1, "Sides of a square?".to_string(), choices);
+/// let text = question_clipboard_text(&question);
+/// assert_eq!(text, "Question 7: Sides of a square?\n    (A) 3\n    (B) 4\n    (C) four\nAnswer: B, C");
+///
+/// // A question without a correct choice still says so.
+/// let question = Question::new(8, 1, 1, "Open question".to_string(), vec![]);
+/// assert_eq!(question_clipboard_text(&question), "Question 8: Open question\nAnswer: -");
+/// ```
+pub fn question_clipboard_text(question: &Question) -> String
+{
+    let mut lines = vec![ format!("{}: {}", t!("question-number", id = question.get_id()), question.get_question()) ];
+    let mut answer = Vec::new();
+    for (j, (text, correct)) in question.get_choices().iter().enumerate()
+    {
+        let label = choice_label(j);
+        lines.push(format!("    ({}) {}", label, text));
+        if *correct
+            { answer.push(label); }
+    }
+    let answer = if answer.is_empty() { "-".to_string() } else { answer.join(", ") };
+    lines.push(format!("{}: {}", t!("answer"), answer));
+    lines.join("\n")
+}
@@ -0,0 +1,209 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use qrate::{ QBank, Question };
+
+use crate::{ sort_questions, Category, QuestionMetas, SortColumn, SortOrder };
+
+/// What the questions table of the edit page shows: a search text, a
+/// category, a difficulty level and the sort. None of them changes the bank.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuestionFilter
+{
+    search: String,
+    category: Option<Category>,
+    difficulty: Option<u8>,
+    sort: (SortColumn, SortOrder),
+}
+
+impl QuestionFilter
+{
+    // pub fn new() -> Self
+    /// Creates a filter that shows every question in bank order.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::QuestionFilter;
+    /// assert!(QuestionFilter::new().is_cleared());
+    /// ```
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // pub fn get_search(&self) -> &str
+    /// Returns the text the prompts are searched for.
+    ///
+    /// # Examples
+    /// See `QuestionFilter::apply`.
+    pub fn get_search(&self) -> &str
+    {
+        &self.search
+    }
+
+    // pub fn set_search(&mut self, search: String)
+    /// Shows only the questions whose prompt contains `search`, ignoring
+    /// case. A blank text shows every question.
+    ///
+    /// # Examples
+    /// See `QuestionFilter::apply`.
+    pub fn set_search(&mut self, search: String)
+    {
+        self.search = search;
+    }
+
+    // pub fn get_category(&self) -> Option<&Category>
+    /// Returns the category shown, or `None` for all of them.
+    ///
+    /// # Examples
+    /// See `QuestionFilter::apply`.
+    pub fn get_category(&self) -> Option<&Category>
+    {
+        self.category.as_ref()
+    }
+
+    // pub fn set_category(&mut self, category: Option<Category>)
+    /// Shows only the questions of `category`, or all of them for `None`.
+    ///
+    /// # Examples
+    /// See `QuestionFilter::apply`.
+    pub fn set_category(&mut self, category: Option<Category>)
+    {
+        self.category = category;
+    }
+
+    // pub fn get_difficulty(&self) -> Option<u8>
+    /// Returns the difficulty level shown, or `None` for all of them.
+    ///
+    /// # Examples
+    /// See `QuestionFilter::apply`.
+    pub fn get_difficulty(&self) -> Option<u8>
+    {
+        self.difficulty
+    }
+
+    // pub fn set_difficulty(&mut self, difficulty: Option<u8>)
+    /// Shows only the questions rated `difficulty`, or all of them for
+    /// `None`.
+    ///
+    /// # Examples
+    /// See `QuestionFilter::apply`.
+    pub fn set_difficulty(&mut self, difficulty: Option<u8>)
+    {
+        self.difficulty = difficulty;
+    }
+
+    // pub fn get_sort(&self) -> (SortColumn, SortOrder)
+    /// Returns the column and direction the table is sorted by.
+    ///
+    /// # Examples
+    /// See `QuestionFilter::apply`.
+    pub fn get_sort(&self) -> (SortColumn, SortOrder)
+    {
+        self.sort
+    }
+
+    // pub fn set_sort(&mut self, sort: (SortColumn, SortOrder))
+    /// Sorts the table by the given column and direction.
+    ///
+    /// # Examples
+    /// See `QuestionFilter::apply`.
+    pub fn set_sort(&mut self, sort: (SortColumn, SortOrder))
+    {
+        self.sort = sort;
+    }
+
+    // pub fn is_cleared(&self) -> bool
+    /// Returns whether every question is shown in bank order.
+    ///
+    /// # Examples
+    /// See `QuestionFilter::apply`.
+    pub fn is_cleared(&self) -> bool
+    {
+        *self == Self::default()
+    }
+
+    // pub fn clear(&mut self)
+    /// Resets the search, the category, the difficulty and the sort at
+    /// once, so that every question is shown in bank order again.
+    ///
+    /// # Examples
+    /// See `QuestionFilter::apply`.
+    pub fn clear(&mut self)
+    {
+        *self = Self::default();
+    }
+
+    // pub fn matches(&self, qbank: &QBank, question: &Question, metas: &QuestionMetas) -> bool
+    /// Returns whether `question` passes the search, the category and the
+    /// difficulty. Unrated questions have difficulty `0`.
+    ///
+    /// # Examples
+    /// See `QuestionFilter::apply`.
+    pub fn matches(&self, qbank: &QBank, question: &Question, metas: &QuestionMetas) -> bool
+    {
+        let search = self.search.trim().to_lowercase();
+        let difficulty = metas.get(&question.get_id()).map(|meta| meta.get_difficulty()).unwrap_or(0);
+        (search.is_empty() || question.get_question().to_lowercase().contains(&search))
+            && self.category.as_ref().is_none_or(|category| *category == Category::of(qbank.get_header(), question))
+            && self.difficulty.is_none_or(|level| level == difficulty)
+    }
+
+    // pub fn apply<'a>(&self, qbank: &'a QBank, metas: &QuestionMetas) -> Vec<&'a Question>
+    /// Returns the questions of `qbank` that pass the filter, in the order
+    /// of the sort.
+    ///
+    /// # Arguments
+    /// * `qbank` - The bank shown in the table.
+    /// * `metas` - The question metadata holding the difficulty levels.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, QBank, Question };
+    /// use qrate_gui::{ Category, QuestionFilter, QuestionMeta, QuestionMetas, SortColumn, SortOrder };
+    ///
+    /// let mut header = Header::new_empty();
+    /// header.set_categories(vec!["Algebra".to_string(), "Geometry".to_string()]);
+    /// let mut qbank = QBank::new_with_header(header);
+    /// for (id, category, prompt) in [(1, 1, "Solve x"), (2, 2, "Area of a circle"), (3, 1, "Factor x^2"), (4, 2, "Solve for the angle")]
+    ///     { qbank.push_question(Question::new(id, id, category, prompt.to_string(), vec![])); }
+    /// let mut metas = QuestionMetas::new();
+    /// let mut meta = QuestionMeta::new();
+    /// meta.set_difficulty(3);
+    /// metas.insert(4, meta);
+    /// let ids = |filter: &QuestionFilter| -> Vec<u16> { filter.apply(&qbank, &metas).iter().map(|q| q.get_id()).collect() };
+    ///
+    /// let mut filter = QuestionFilter::new();
+    /// filter.set_search("SOLVE".to_string());
+    /// assert_eq!(ids(&filter), [1, 4]);
+    /// filter.set_category(Some(Category::Named("Geometry".to_string())));
+    /// assert_eq!(ids(&filter), [4]);
+    /// filter.set_difficulty(Some(2));
+    /// assert!(ids(&filter).is_empty());
+    /// filter.set_sort((SortColumn::Prompt, SortOrder::Descending));
+    /// assert!(!filter.is_cleared());
+    ///
+    /// // Clearing shows the whole bank in bank order again.
+    /// filter.clear();
+    /// assert!(filter.is_cleared());
+    /// assert_eq!(filter.get_search(), "");
+    /// assert_eq!(filter.get_category(), None);
+    /// assert_eq!(filter.get_difficulty(), None);
+    /// assert_eq!(filter.get_sort(), (SortColumn::Id, SortOrder::Ascending));
+    /// assert_eq!(ids(&filter), [1, 2, 3, 4]);
+    /// ```
+    pub fn apply<'a>(&self, qbank: &'a QBank, metas: &QuestionMetas) -> Vec<&'a Question>
+    {
+        sort_questions(qbank.get_questions(), metas, self.sort)
+            .into_iter()
+            .filter(|question| self.matches(qbank, question, metas))
+            .collect()
+    }
+}
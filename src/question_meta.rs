@@ -0,0 +1,520 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::HashMap;
+use std::path::{ Path, PathBuf };
+use std::fs;
+use std::io;
+
+use serde::{ Serialize, Deserialize };
+
+use crate::QuestionKind;
+
+/// A reference to an image shown with a question prompt.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ImageRef
+{
+    /// The image is stored in a file at the contained path.
+    Path(PathBuf),
+
+    /// The image bytes are embedded in the metadata itself.
+    Embedded(Vec<u8>),
+}
+
+/// The outcome of loading an `ImageRef`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoadedImage
+{
+    /// The encoded image bytes.
+    Bytes(Vec<u8>),
+
+    /// The image could not be read; a placeholder should be shown instead.
+    Placeholder,
+}
+
+impl LoadedImage
+{
+    // pub fn is_placeholder(&self) -> bool
+    /// Returns `true` if the image could not be loaded.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::LoadedImage;
+    /// assert!(LoadedImage::Placeholder.is_placeholder());
+    /// assert!(!LoadedImage::Bytes(vec![1, 2, 3]).is_placeholder());
+    /// ```
+    pub fn is_placeholder(&self) -> bool
+    {
+        matches!(self, LoadedImage::Placeholder)
+    }
+}
+
+impl ImageRef
+{
+    // pub fn load(&self) -> LoadedImage
+    /// Reads the image bytes, falling back to a placeholder when the file is
+    /// missing or unreadable.
+    ///
+    /// # Output
+    /// A `LoadedImage`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::ImageRef;
+    ///
+    /// let missing = ImageRef::Path(PathBuf::from("/definitely/not/here.png"));
+    /// assert!(missing.load().is_placeholder());
+    ///
+    /// let embedded = ImageRef::Embedded(vec![0x89, 0x50, 0x4E, 0x47]);
+    /// assert!(!embedded.load().is_placeholder());
+    /// ```
+    pub fn load(&self) -> LoadedImage
+    {
+        match self
+        {
+            ImageRef::Path(path) => match fs::read(path)
+            {
+                Ok(bytes) => LoadedImage::Bytes(bytes),
+                Err(_) => LoadedImage::Placeholder,
+            },
+            ImageRef::Embedded(bytes) => LoadedImage::Bytes(bytes.clone()),
+        }
+    }
+
+    // pub fn is_available(&self) -> bool
+    /// Returns whether the image can currently be shown, without reading it.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::ImageRef;
+    /// assert!(!ImageRef::Path(PathBuf::from("/definitely/not/here.png")).is_available());
+    /// assert!(ImageRef::Embedded(vec![1]).is_available());
+    /// ```
+    pub fn is_available(&self) -> bool
+    {
+        match self
+        {
+            ImageRef::Path(path) => path.is_file(),
+            ImageRef::Embedded(bytes) => !bytes.is_empty(),
+        }
+    }
+}
+
+/// The highest difficulty level a question can be rated with.
+pub const MAX_DIFFICULTY: u8 = 5;
+
+/// The fewest graded attempts a question needs before a difficulty is
+/// suggested from its success rate.
+pub const MIN_CALIBRATION_ATTEMPTS: u32 = 5;
+
+/// Extra, GUI-side information attached to a question.
+///
+/// `qrate`'s `Question` only stores an id, group, category, prompt and
+/// choices, so everything else lives here, keyed by question id, and is
+/// saved in a sidecar file next to the question bank.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QuestionMeta
+{
+    #[serde(default)]
+    image: Option<ImageRef>,
+
+    #[serde(default)]
+    kind: QuestionKind,
+
+    #[serde(default)]
+    difficulty: u8,
+
+    #[serde(default)]
+    tags: Vec<String>,
+
+    /// Private notes of the teacher, shown only in the editor.
+    #[serde(default)]
+    note: String,
+
+    #[serde(default)]
+    attempts: u32,
+
+    #[serde(default)]
+    score_sum: f32,
+}
+
+impl QuestionMeta
+{
+    // pub fn new() -> Self
+    /// Creates empty metadata.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::QuestionMeta;
+    /// assert!(QuestionMeta::new().get_image().is_none());
+    /// ```
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // pub fn get_image(&self) -> Option<&ImageRef>
+    /// Returns the image referenced by the prompt, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ImageRef, QuestionMeta };
+    /// let mut meta = QuestionMeta::new();
+    /// meta.set_image(Some(ImageRef::Embedded(vec![1])));
+    /// assert_eq!(meta.get_image(), Some(&ImageRef::Embedded(vec![1])));
+    /// ```
+    pub fn get_image(&self) -> Option<&ImageRef>
+    {
+        self.image.as_ref()
+    }
+
+    // pub fn set_image(&mut self, image: Option<ImageRef>)
+    /// Sets or clears the image referenced by the prompt.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ ImageRef, QuestionMeta };
+    /// let mut meta = QuestionMeta::new();
+    /// meta.set_image(Some(ImageRef::Embedded(vec![1])));
+    /// meta.set_image(None);
+    /// assert!(meta.get_image().is_none());
+    /// ```
+    pub fn set_image(&mut self, image: Option<ImageRef>)
+    {
+        self.image = image;
+    }
+
+    // pub fn get_kind(&self) -> QuestionKind
+    /// Returns the answer format of the question.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ QuestionKind, QuestionMeta };
+    /// assert_eq!(QuestionMeta::new().get_kind(), QuestionKind::SingleChoice);
+    /// ```
+    pub fn get_kind(&self) -> QuestionKind
+    {
+        self.kind
+    }
+
+    // pub fn set_kind(&mut self, kind: QuestionKind)
+    /// Sets the answer format of the question.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ QuestionKind, QuestionMeta };
+    /// let mut meta = QuestionMeta::new();
+    /// meta.set_kind(QuestionKind::MultiSelect);
+    /// assert_eq!(meta.get_kind(), QuestionKind::MultiSelect);
+    ///
+    /// // Older sidecars without a kind still load.
+    /// let old: QuestionMeta = serde_json::from_str("{\"image\":null}").unwrap();
+    /// assert_eq!(old.get_kind(), QuestionKind::SingleChoice);
+    /// ```
+    pub fn set_kind(&mut self, kind: QuestionKind)
+    {
+        self.kind = kind;
+    }
+
+    // pub fn get_difficulty(&self) -> u8
+    /// Returns the difficulty level of the question, from `1` (easiest) to
+    /// `MAX_DIFFICULTY`, or `0` if it has not been rated.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::QuestionMeta;
+    /// assert_eq!(QuestionMeta::new().get_difficulty(), 0);
+    /// ```
+    pub fn get_difficulty(&self) -> u8
+    {
+        self.difficulty
+    }
+
+    // pub fn set_difficulty(&mut self, difficulty: u8)
+    /// Sets the difficulty level of the question, capped at `MAX_DIFFICULTY`.
+    ///
+    /// # Arguments
+    /// * `difficulty` - The level, or `0` for unrated.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ QuestionMeta, MAX_DIFFICULTY };
+    /// let mut meta = QuestionMeta::new();
+    /// meta.set_difficulty(4);
+    /// assert_eq!(meta.get_difficulty(), 4);
+    /// meta.set_difficulty(99);
+    /// assert_eq!(meta.get_difficulty(), MAX_DIFFICULTY);
+    /// ```
+    pub fn set_difficulty(&mut self, difficulty: u8)
+    {
+        self.difficulty = difficulty.min(MAX_DIFFICULTY);
+    }
+
+    // pub fn get_tags(&self) -> &[String]
+    /// Returns the tags of the question, such as topics or chapters.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::QuestionMeta;
+    /// assert!(QuestionMeta::new().get_tags().is_empty());
+    /// ```
+    pub fn get_tags(&self) -> &[String]
+    {
+        &self.tags
+    }
+
+    // pub fn set_tags(&mut self, tags: Vec<String>)
+    /// Sets the tags of the question. Blank tags and repeated tags are dropped
+    /// and the others are trimmed.
+    ///
+    /// # Arguments
+    /// * `tags` - The tags.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::QuestionMeta;
+    /// let mut meta = QuestionMeta::new();
+    /// meta.set_tags(vec![" algebra ".to_string(), "".to_string(), "algebra".to_string(), "proofs".to_string()]);
+    /// assert_eq!(meta.get_tags(), ["algebra".to_string(), "proofs".to_string()]);
+    /// ```
+    pub fn set_tags(&mut self, tags: Vec<String>)
+    {
+        self.tags.clear();
+        for tag in tags.iter().map(|tag| tag.trim()).filter(|tag| !tag.is_empty())
+        {
+            if !self.tags.iter().any(|existing| existing == tag)
+                { self.tags.push(tag.to_string()); }
+        }
+    }
+
+    // pub fn get_note(&self) -> &str
+    /// Returns the private note attached to the question, such as where it
+    /// came from or what to rewrite. Notes are shown in the editor only and
+    /// never printed on exams or answer keys.
+    ///
+    /// # Examples
+    /// ```
+    /// use calamine::{ Reader, open_workbook_auto };
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ export_exam_to_excel, load_question_metas, save_question_metas, Exam, ExamCriteria, QuestionMeta, QuestionMetas };
+    ///
+    /// let mut meta = QuestionMeta::new();
+    /// assert_eq!(meta.get_note(), "");
+    /// meta.set_note("from the 2023 final; rewrite".to_string());
+    /// let mut metas = QuestionMetas::new();
+    /// metas.insert(1, meta);
+    ///
+    /// // Notes survive saving and loading the sidecar.
+    /// let bank_path = std::env::temp_dir().join("qrate_gui_question_note.qb.xlsx");
+    /// save_question_metas(&bank_path, &metas).unwrap();
+    /// let metas = load_question_metas(&bank_path);
+    /// assert_eq!(metas[&1].get_note(), "from the 2023 final; rewrite");
+    ///
+    /// // They appear nowhere in the exported exam or its answer key.
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![("Yes".to_string(), true), ("No".to_string(), false)]));
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(1);
+    /// let exam = Exam::generate_with_metas(&qbank, &criteria, &metas).unwrap();
+    /// assert!(exam.layout().iter().all(|line| !line.to_text().contains("rewrite")));
+    /// let path = std::env::temp_dir().join("qrate_gui_question_note_export.xlsx");
+    /// export_exam_to_excel(&exam, &path).unwrap();
+    /// let mut workbook = open_workbook_auto(&path).unwrap();
+    /// assert_eq!(workbook.sheet_names().len(), 2);
+    /// for sheet in 0..2
+    /// {
+    ///     let range = workbook.worksheet_range_at(sheet).unwrap().unwrap();
+    ///     assert!(range.cells().all(|(_, _, cell)| !cell.to_string().contains("rewrite")));
+    /// }
+    /// ```
+    pub fn get_note(&self) -> &str
+    {
+        &self.note
+    }
+
+    // pub fn set_note(&mut self, note: String)
+    /// Sets the private note attached to the question.
+    ///
+    /// # Arguments
+    /// * `note` - The note, or an empty string for none.
+    ///
+    /// # Examples
+    /// See `QuestionMeta::get_note`.
+    pub fn set_note(&mut self, note: String)
+    {
+        self.note = note;
+    }
+
+    // pub fn record_attempt(&mut self, score: f32)
+    /// Adds one graded attempt at the question to its statistics.
+    ///
+    /// # Arguments
+    /// * `score` - The score of the attempt, clamped to `0.0..=1.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::QuestionMeta;
+    /// let mut meta = QuestionMeta::new();
+    /// meta.record_attempt(1.0);
+    /// meta.record_attempt(0.0);
+    /// assert_eq!(meta.get_attempts(), 2);
+    /// assert_eq!(meta.get_success_rate(), Some(0.5));
+    /// ```
+    pub fn record_attempt(&mut self, score: f32)
+    {
+        self.attempts += 1;
+        self.score_sum += score.clamp(0.0, 1.0);
+    }
+
+    // pub fn get_attempts(&self) -> u32
+    /// Returns how many graded attempts have been recorded.
+    ///
+    /// # Examples
+    /// See `QuestionMeta::record_attempt`.
+    pub fn get_attempts(&self) -> u32
+    {
+        self.attempts
+    }
+
+    // pub fn get_success_rate(&self) -> Option<f32>
+    /// Returns the average score of the recorded attempts.
+    ///
+    /// # Output
+    /// The rate from `0.0` to `1.0`, or `None` without any attempt.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::QuestionMeta;
+    /// assert_eq!(QuestionMeta::new().get_success_rate(), None);
+    /// ```
+    pub fn get_success_rate(&self) -> Option<f32>
+    {
+        (self.attempts > 0).then(|| self.score_sum / self.attempts as f32)
+    }
+
+    // pub fn suggest_difficulty(&self) -> Option<u8>
+    /// Suggests a difficulty level from the observed success rate: `1` when
+    /// every attempt succeeded up to `MAX_DIFFICULTY` when none did.
+    ///
+    /// # Output
+    /// The level, or `None` with fewer than `MIN_CALIBRATION_ATTEMPTS`
+    /// attempts, so that a single answer does not recalibrate the question.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ QuestionMeta, MAX_DIFFICULTY };
+    ///
+    /// // Rated easy, but missed by eight students out of ten.
+    /// let mut meta = QuestionMeta::new();
+    /// meta.set_difficulty(1);
+    /// for score in [0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]
+    ///     { meta.record_attempt(score); }
+    /// let suggested = meta.suggest_difficulty().unwrap();
+    /// assert!(suggested > meta.get_difficulty());
+    /// assert_eq!(suggested, 4);
+    ///
+    /// // One data point is not enough.
+    /// let mut once = QuestionMeta::new();
+    /// once.record_attempt(0.0);
+    /// assert_eq!(once.suggest_difficulty(), None);
+    ///
+    /// // Answered right every time.
+    /// let mut easy = QuestionMeta::new();
+    /// for _ in 0..5
+    ///     { easy.record_attempt(1.0); }
+    /// assert_eq!(easy.suggest_difficulty(), Some(1));
+    /// ```
+    pub fn suggest_difficulty(&self) -> Option<u8>
+    {
+        if self.attempts < MIN_CALIBRATION_ATTEMPTS
+            { return None; }
+        let failure = 1.0 - self.get_success_rate()?;
+        Some(1 + (failure * (MAX_DIFFICULTY - 1) as f32).round() as u8)
+    }
+}
+
+/// Question metadata keyed by question id.
+pub type QuestionMetas = HashMap<u16, QuestionMeta>;
+
+// pub fn get_meta_path(bank_path: &Path) -> PathBuf
+/// Returns the path of the metadata sidecar file for a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+///
+/// # Examples
+/// ```
+/// use std::path::{ Path, PathBuf };
+/// use qrate_gui::get_meta_path;
+/// assert_eq!(get_meta_path(Path::new("/banks/math.qbdb")), PathBuf::from("/banks/math.qbdb.meta.json"));
+/// ```
+pub fn get_meta_path(bank_path: &Path) -> PathBuf
+{
+    let mut name = bank_path.as_os_str().to_owned();
+    name.push(".meta.json");
+    PathBuf::from(name)
+}
+
+// pub fn load_question_metas(bank_path: &Path) -> QuestionMetas
+/// Loads the metadata sidecar of a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+///
+/// # Output
+/// The stored `QuestionMetas`, or an empty map if the sidecar is missing
+/// or cannot be parsed.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use qrate_gui::load_question_metas;
+/// assert!(load_question_metas(Path::new("/definitely/not/here.qbdb")).is_empty());
+/// ```
+pub fn load_question_metas(bank_path: &Path) -> QuestionMetas
+{
+    fs::read_to_string(get_meta_path(bank_path))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+// pub fn save_question_metas(bank_path: &Path, metas: &QuestionMetas) -> io::Result<()>
+/// Saves the metadata sidecar of a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+/// * `metas` - The metadata to store.
+///
+/// # Output
+/// An `io::Result` reporting write failures.
+///
+/// # Examples
+/// ```
+/// use std::path::PathBuf;
+/// use qrate_gui::{ load_question_metas, save_question_metas, ImageRef, QuestionMeta, QuestionMetas };
+///
+/// let mut meta = QuestionMeta::new();
+/// meta.set_image(Some(ImageRef::Path(PathBuf::from("figures/triangle.png"))));
+/// let mut metas = QuestionMetas::new();
+/// metas.insert(7, meta.clone());
+///
+/// let bank_path = std::env::temp_dir().join("qrate_gui_save_question_metas.qbdb");
+/// save_question_metas(&bank_path, &metas).unwrap();
+/// let reloaded = load_question_metas(&bank_path);
+/// assert_eq!(reloaded.get(&7), Some(&meta));
+/// ```
+pub fn save_question_metas(bank_path: &Path, metas: &QuestionMetas) -> io::Result<()>
+{
+    let json = serde_json::to_string_pretty(metas).map_err(io::Error::other)?;
+    fs::write(get_meta_path(bank_path), json)
+}
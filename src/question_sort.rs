@@ -0,0 +1,152 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use qrate::Question;
+
+use crate::QuestionMetas;
+
+/// A column of the questions table that can be sorted on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortColumn
+{
+    /// The order of the bank, which is also the default order of exams
+    /// that are not shuffled.
+    #[default]
+    Id,
+
+    /// The prompt text.
+    Prompt,
+
+    /// The category number.
+    Category,
+
+    /// The difficulty level from the question metadata.
+    Difficulty,
+}
+
+impl SortColumn
+{
+    /// All sortable columns, in the order they are shown.
+    pub const ALL: [SortColumn; 4] = [ SortColumn::Id, SortColumn::Prompt, SortColumn::Category, SortColumn::Difficulty ];
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key of the column header.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::SortColumn;
+    /// assert_eq!(SortColumn::Difficulty.get_key(), "column-difficulty");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            SortColumn::Id => "column-id",
+            SortColumn::Prompt => "column-prompt",
+            SortColumn::Category => "column-category",
+            SortColumn::Difficulty => "column-difficulty",
+        }
+    }
+}
+
+/// The direction of a sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder
+{
+    /// Smallest first.
+    #[default]
+    Ascending,
+
+    /// Largest first.
+    Descending,
+}
+
+impl SortOrder
+{
+    // pub fn flipped(&self) -> Self
+    /// Returns the opposite direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::SortOrder;
+    /// assert_eq!(SortOrder::Ascending.flipped(), SortOrder::Descending);
+    /// assert_eq!(SortOrder::Descending.flipped(), SortOrder::Ascending);
+    /// ```
+    pub fn flipped(&self) -> Self
+    {
+        match self
+        {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+}
+
+// pub fn sort_questions<'a>(questions: &'a [Question], metas: &QuestionMetas, sort: (SortColumn, SortOrder)) -> Vec<&'a Question>
+/// Returns the questions in the order of `sort`, leaving `questions` as it is.
+///
+/// The sort is stable, so questions that compare equal keep their bank
+/// order. Unrated questions have difficulty `0`.
+///
+/// # Arguments
+/// * `questions` - The questions to order.
+/// * `metas` - The question metadata holding the difficulty levels.
+/// * `sort` - The column and direction to sort by.
+///
+/// # Output
+/// References to the questions, in sorted order.
+///
+/// # Examples
+/// ```
+/// use qrate::Question;
+/// use qrate_gui::{ sort_questions, QuestionMeta, QuestionMetas, SortColumn, SortOrder };
+///
+/// let questions: Vec<Question> = (1..=5).map(|id| Question::new(id, id, (id % 3) as u8, format!("Q{}", id), vec![]))
+///                                       .collect();
+/// let mut metas = QuestionMetas::new();
+/// for (id, level) in [(1, 2), (2, 5), (3, 1), (5, 4)]
+/// {
+///     let mut meta = QuestionMeta::new();
+///     meta.set_difficulty(level);
+///     metas.insert(id, meta);
+/// }
+///
+/// let hardest_first = sort_questions(&questions, &metas, (SortColumn::Difficulty, SortOrder::Descending));
+/// let levels: Vec<u8> = hardest_first.iter()
+///                         .map(|q| metas.get(&q.get_id()).map(|m| m.get_difficulty()).unwrap_or(0))
+///                         .collect();
+/// assert_eq!(levels, vec![5, 4, 2, 1, 0]);
+/// assert!(levels.windows(2).all(|pair| pair[0] >= pair[1]));
+///
+/// // The underlying order is untouched.
+/// let ids: Vec<u16> = questions.iter().map(|q| q.get_id()).collect();
+/// assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn sort_questions<'a>(questions: &'a [Question], metas: &QuestionMetas, sort: (SortColumn, SortOrder)) -> Vec<&'a Question>
+{
+    let difficulty = |question: &Question| metas.get(&question.get_id()).map(|meta| meta.get_difficulty()).unwrap_or(0);
+    let mut sorted: Vec<(usize, &Question)> = questions.iter().enumerate().collect();
+    let (column, order) = sort;
+    sorted.sort_by(|(a_position, a), (b_position, b)| {
+        let ordering = match column
+        {
+            SortColumn::Id => a_position.cmp(b_position),
+            SortColumn::Prompt => a.get_question().to_lowercase().cmp(&b.get_question().to_lowercase()),
+            SortColumn::Category => a.get_category().cmp(&b.get_category()),
+            SortColumn::Difficulty => difficulty(a).cmp(&difficulty(b)),
+        };
+        match order
+        {
+            SortOrder::Ascending => ordering,
+            SortOrder::Descending => ordering.reverse(),
+        }
+    });
+    sorted.into_iter().map(|(_, question)| question).collect()
+}
@@ -0,0 +1,556 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use qrate::{ SBank, Student };
+
+/// Describes why a change to the student list was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RosterError
+{
+    /// The student name is empty.
+    EmptyName,
+
+    /// The student id is empty.
+    EmptyId,
+
+    /// Another student already uses the contained id.
+    DuplicateId(String),
+
+    /// There is no student at the contained 0-based index.
+    OutOfRange(usize),
+}
+
+impl RosterError
+{
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key describing this error.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::RosterError;
+    /// assert_eq!(RosterError::DuplicateId("s1".to_string()).get_key(), "error-duplicate-student-id");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            RosterError::EmptyName => "error-empty-student-name",
+            RosterError::EmptyId => "error-empty-student-id",
+            RosterError::DuplicateId(_) => "error-duplicate-student-id",
+            RosterError::OutOfRange(_) => "error-student-not-found",
+        }
+    }
+}
+
+/// The editable fields of the add/edit student modal.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StudentForm
+{
+    editing: Option<usize>,
+    name: String,
+    id: String,
+    group: String,
+    error: Option<RosterError>,
+}
+
+impl StudentForm
+{
+    // pub fn new_for_add() -> Self
+    /// Creates an empty form for adding a new student.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::StudentForm;
+    /// let form = StudentForm::new_for_add();
+    /// assert!(form.get_editing().is_none());
+    /// assert!(form.get_name().is_empty());
+    /// ```
+    pub fn new_for_add() -> Self
+    {
+        Self::default()
+    }
+
+    // pub fn new_for_edit(index: usize, student: &Student, group: &str) -> Self
+    /// Creates a form pre-filled with an existing student.
+    ///
+    /// # Arguments
+    /// * `index` - The 0-based index of the student in the `SBank`.
+    /// * `student` - The student being edited.
+    /// * `group` - The student's group.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::Student;
+    /// use qrate_gui::StudentForm;
+    /// let student = Student::new("Alice".to_string(), "s1".to_string());
+    /// let form = StudentForm::new_for_edit(0, &student, "A");
+    /// assert_eq!(form.get_editing(), Some(0));
+    /// assert_eq!(form.get_name(), "Alice");
+    /// assert_eq!(form.get_group(), "A");
+    /// ```
+    pub fn new_for_edit(index: usize, student: &Student, group: &str) -> Self
+    {
+        Self
+        {
+            editing: Some(index),
+            name: student.get_name().clone(),
+            id: student.get_id().clone(),
+            group: group.to_string(),
+            error: None,
+        }
+    }
+
+    // pub fn get_editing(&self) -> Option<usize>
+    /// Returns the index of the student being edited, or `None` when adding.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::StudentForm;
+    /// assert!(StudentForm::new_for_add().get_editing().is_none());
+    /// ```
+    pub fn get_editing(&self) -> Option<usize>
+    {
+        self.editing
+    }
+
+    // pub fn get_name(&self) -> &str
+    /// Returns the name entered in the form.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::StudentForm;
+    /// let mut form = StudentForm::new_for_add();
+    /// form.set_name("Bob".to_string());
+    /// assert_eq!(form.get_name(), "Bob");
+    /// ```
+    pub fn get_name(&self) -> &str
+    {
+        &self.name
+    }
+
+    // pub fn set_name(&mut self, name: String)
+    /// Sets the name entered in the form.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::StudentForm;
+    /// let mut form = StudentForm::new_for_add();
+    /// form.set_name("Bob".to_string());
+    /// assert_eq!(form.get_name(), "Bob");
+    /// ```
+    pub fn set_name(&mut self, name: String)
+    {
+        self.name = name;
+    }
+
+    // pub fn get_id(&self) -> &str
+    /// Returns the id entered in the form.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::StudentForm;
+    /// let mut form = StudentForm::new_for_add();
+    /// form.set_id("s2".to_string());
+    /// assert_eq!(form.get_id(), "s2");
+    /// ```
+    pub fn get_id(&self) -> &str
+    {
+        &self.id
+    }
+
+    // pub fn set_id(&mut self, id: String)
+    /// Sets the id entered in the form.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::StudentForm;
+    /// let mut form = StudentForm::new_for_add();
+    /// form.set_id("s2".to_string());
+    /// assert_eq!(form.get_id(), "s2");
+    /// ```
+    pub fn set_id(&mut self, id: String)
+    {
+        self.id = id;
+    }
+
+    // pub fn get_group(&self) -> &str
+    /// Returns the group entered in the form.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::StudentForm;
+    /// let mut form = StudentForm::new_for_add();
+    /// form.set_group("B".to_string());
+    /// assert_eq!(form.get_group(), "B");
+    /// ```
+    pub fn get_group(&self) -> &str
+    {
+        &self.group
+    }
+
+    // pub fn set_group(&mut self, group: String)
+    /// Sets the group entered in the form.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::StudentForm;
+    /// let mut form = StudentForm::new_for_add();
+    /// form.set_group("B".to_string());
+    /// assert_eq!(form.get_group(), "B");
+    /// ```
+    pub fn set_group(&mut self, group: String)
+    {
+        self.group = group;
+    }
+
+    // pub fn get_error(&self) -> Option<&RosterError>
+    /// Returns the validation error of the last submission, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::StudentForm;
+    /// assert!(StudentForm::new_for_add().get_error().is_none());
+    /// ```
+    pub fn get_error(&self) -> Option<&RosterError>
+    {
+        self.error.as_ref()
+    }
+
+    // pub fn set_error(&mut self, error: Option<RosterError>)
+    /// Sets the validation error shown in the form.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ RosterError, StudentForm };
+    /// let mut form = StudentForm::new_for_add();
+    /// form.set_error(Some(RosterError::EmptyId));
+    /// assert_eq!(form.get_error(), Some(&RosterError::EmptyId));
+    /// ```
+    pub fn set_error(&mut self, error: Option<RosterError>)
+    {
+        self.error = error;
+    }
+
+    // pub fn to_student(&self) -> Student
+    /// Builds a `Student` from the trimmed name and id of the form.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::StudentForm;
+    /// let mut form = StudentForm::new_for_add();
+    /// form.set_name(" Carol ".to_string());
+    /// form.set_id("s3".to_string());
+    /// let student = form.to_student();
+    /// assert_eq!(student.get_name(), "Carol");
+    /// assert_eq!(student.get_id(), "s3");
+    /// ```
+    pub fn to_student(&self) -> Student
+    {
+        Student::new(self.name.trim().to_string(), self.id.trim().to_string())
+    }
+}
+
+fn validate(sbank: &SBank, student: &Student, skip: Option<usize>) -> Result<(), RosterError>
+{
+    if student.get_name().is_empty()
+        { return Err(RosterError::EmptyName); }
+    if student.get_id().is_empty()
+        { return Err(RosterError::EmptyId); }
+    let duplicated = sbank.iter()
+                        .enumerate()
+                        .any(|(i, other)| Some(i) != skip && other.get_id() == student.get_id());
+    if duplicated
+        { Err(RosterError::DuplicateId(student.get_id().clone())) }
+    else
+        { Ok(()) }
+}
+
+// pub fn add_student(sbank: &mut SBank, student: Student) -> Result<(), RosterError>
+/// Appends `student` to `sbank` after checking that its id is unique.
+///
+/// # Arguments
+/// * `sbank` - The student bank to modify.
+/// * `student` - The student to add.
+///
+/// # Output
+/// `Ok(())` if added, or the `RosterError` that prevented it.
+///
+/// # Examples
+/// ```
+/// use qrate::{ SBank, Student };
+/// use qrate_gui::{ add_student, RosterError };
+///
+/// let mut sbank = SBank::new();
+/// assert!(add_student(&mut sbank, Student::new("Alice".to_string(), "s1".to_string())).is_ok());
+/// assert_eq!(sbank.len(), 1);
+///
+/// let duplicate = Student::new("Alicia".to_string(), "s1".to_string());
+/// assert_eq!(add_student(&mut sbank, duplicate), Err(RosterError::DuplicateId("s1".to_string())));
+/// assert_eq!(sbank.len(), 1);
+/// ```
+pub fn add_student(sbank: &mut SBank, student: Student) -> Result<(), RosterError>
+{
+    validate(sbank, &student, None)?;
+    sbank.push(student);
+    Ok(())
+}
+
+// pub fn update_student(sbank: &mut SBank, index: usize, student: Student) -> Result<(), RosterError>
+/// Replaces the student at `index` after checking that the new id is unique.
+///
+/// # Arguments
+/// * `sbank` - The student bank to modify.
+/// * `index` - The 0-based index of the student to replace.
+/// * `student` - The new student data.
+///
+/// # Output
+/// `Ok(())` if replaced, or the `RosterError` that prevented it.
+///
+/// # Examples
+/// ```
+/// use qrate::{ SBank, Student };
+/// use qrate_gui::{ update_student, RosterError };
+///
+/// let mut sbank = vec![
+///     Student::new("Alice".to_string(), "s1".to_string()),
+///     Student::new("Bob".to_string(), "s2".to_string()),
+/// ];
+/// assert!(update_student(&mut sbank, 0, Student::new("Alicia".to_string(), "s1".to_string())).is_ok());
+/// assert_eq!(sbank[0].get_name(), "Alicia");
+/// assert!(update_student(&mut sbank, 0, Student::new("Alicia".to_string(), "s2".to_string())).is_err());
+/// assert_eq!(update_student(&mut sbank, 5, Student::new_empty()), Err(RosterError::OutOfRange(5)));
+/// ```
+pub fn update_student(sbank: &mut SBank, index: usize, student: Student) -> Result<(), RosterError>
+{
+    if index >= sbank.len()
+        { return Err(RosterError::OutOfRange(index)); }
+    validate(sbank, &student, Some(index))?;
+    sbank[index] = student;
+    Ok(())
+}
+
+// pub fn delete_student(sbank: &mut SBank, index: usize) -> Result<Student, RosterError>
+/// Removes the student at `index`.
+///
+/// # Arguments
+/// * `sbank` - The student bank to modify.
+/// * `index` - The 0-based index of the student to remove.
+///
+/// # Output
+/// The removed `Student`, or `RosterError::OutOfRange`.
+///
+/// # Examples
+/// ```
+/// use qrate::{ SBank, Student };
+/// use qrate_gui::delete_student;
+///
+/// let mut sbank = vec![ Student::new("Alice".to_string(), "s1".to_string()) ];
+/// assert_eq!(delete_student(&mut sbank, 0).unwrap().get_id(), "s1");
+/// assert!(sbank.is_empty());
+/// assert!(delete_student(&mut sbank, 0).is_err());
+/// ```
+pub fn delete_student(sbank: &mut SBank, index: usize) -> Result<Student, RosterError>
+{
+    if index < sbank.len()
+        { Ok(sbank.remove(index)) }
+    else
+        { Err(RosterError::OutOfRange(index)) }
+}
+
+/// What the students of a `DuplicateStudents` have in common.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DuplicateKey
+{
+    /// The students share the contained id.
+    Id(String),
+
+    /// The students have different ids but share the contained name and group.
+    NameAndGroup(String, String),
+}
+
+/// Students of an imported roster that appear to be the same person.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateStudents
+{
+    key: DuplicateKey,
+    indices: Vec<usize>,
+}
+
+impl DuplicateStudents
+{
+    // pub fn get_key(&self) -> &DuplicateKey
+    /// Returns what the students have in common.
+    ///
+    /// # Examples
+    /// See `find_duplicate_students`.
+    pub fn get_key(&self) -> &DuplicateKey
+    {
+        &self.key
+    }
+
+    // pub fn get_indices(&self) -> &[usize]
+    /// Returns the 0-based indices of the students in roster order, so
+    /// that the first one is the student that is kept.
+    ///
+    /// # Examples
+    /// See `find_duplicate_students`.
+    pub fn get_indices(&self) -> &[usize]
+    {
+        &self.indices
+    }
+}
+
+/// How the duplicates of an imported roster are resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateResolution
+{
+    /// Keeps the first occurrence as it is and drops the later ones.
+    KeepFirst,
+
+    /// Keeps one student at the place of the first occurrence, updated with
+    /// the name and id of the last one, as a later class list is newer.
+    Merge,
+}
+
+fn group_indices<K: Eq + Hash + Clone>(keys: impl Iterator<Item = (usize, K)>) -> Vec<(K, Vec<usize>)>
+{
+    let mut positions: HashMap<K, usize> = HashMap::new();
+    let mut found: Vec<(K, Vec<usize>)> = Vec::new();
+    for (index, key) in keys
+    {
+        match positions.get(&key)
+        {
+            Some(&position) => found[position].1.push(index),
+            None => {
+                positions.insert(key.clone(), found.len());
+                found.push((key, vec![index]));
+            },
+        }
+    }
+    found
+}
+
+// pub fn find_duplicate_students(sbank: &SBank, groups: &HashMap<String, String>) -> Vec<DuplicateStudents>
+/// Finds the students of an imported roster that share an id, or that have
+/// different ids but the same name and group.
+///
+/// # Arguments
+/// * `sbank` - The imported students.
+/// * `groups` - The group of each student that has one, keyed by student id.
+///
+/// # Output
+/// The duplicates ordered by their first occurrence, or an empty `Vec` if
+/// every student is unique.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ find_duplicate_students, DuplicateKey, LoadFile };
+///
+/// let (sbank, groups) = LoadFile::parse_roster_text("Alice, s1, A\nBob, s2, A\nAlicia, s1, A\nBob, s4, A\n");
+/// let duplicates = find_duplicate_students(&sbank, &groups);
+/// assert_eq!(duplicates.len(), 2);
+/// assert_eq!(duplicates[0].get_key(), &DuplicateKey::Id("s1".to_string()));
+/// assert_eq!(duplicates[0].get_indices(), &[0, 2]);
+/// assert_eq!(duplicates[1].get_key(), &DuplicateKey::NameAndGroup("Bob".to_string(), "A".to_string()));
+/// assert_eq!(duplicates[1].get_indices(), &[1, 3]);
+///
+/// let (sbank, groups) = LoadFile::parse_roster_text("Alice, s1, A\nAlice, s2, B\n");
+/// assert!(find_duplicate_students(&sbank, &groups).is_empty());
+/// ```
+pub fn find_duplicate_students(sbank: &SBank, groups: &HashMap<String, String>) -> Vec<DuplicateStudents>
+{
+    let by_id = group_indices(sbank.iter().enumerate().map(|(i, student)| (i, student.get_id().as_str())));
+    // Students sharing an id are reported once, so only the first of each id
+    // is compared by name and group.
+    let by_name = group_indices(by_id.iter().map(|(id, indices)| {
+        let first = indices[0];
+        let group = groups.get(*id).map(String::as_str).unwrap_or("");
+        (first, (sbank[first].get_name().as_str(), group))
+    }));
+    let mut duplicates: Vec<DuplicateStudents> = by_id.into_iter()
+        .filter(|(_, indices)| indices.len() > 1)
+        .map(|(id, indices)| DuplicateStudents { key: DuplicateKey::Id(id.to_string()), indices })
+        .chain(by_name.into_iter()
+                    .filter(|(_, indices)| indices.len() > 1)
+                    .map(|((name, group), indices)| DuplicateStudents {
+                        key: DuplicateKey::NameAndGroup(name.to_string(), group.to_string()),
+                        indices,
+                    }))
+        .collect();
+    duplicates.sort_by_key(|duplicate| duplicate.indices[0]);
+    duplicates
+}
+
+// pub fn resolve_duplicate_students(sbank: SBank, groups: HashMap<String, String>, resolution: DuplicateResolution) -> (SBank, HashMap<String, String>)
+/// Collapses every duplicate found by `find_duplicate_students` into a
+/// single student.
+///
+/// # Arguments
+/// * `sbank` - The imported students.
+/// * `groups` - The group of each student that has one, keyed by student id.
+/// * `resolution` - Which of the duplicates is kept.
+///
+/// # Output
+/// The students without duplicates and their groups.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ find_duplicate_students, resolve_duplicate_students, DuplicateResolution, LoadFile };
+///
+/// let roster = "Alice, s1, A\nBob, s2, A\nAlicia, s1, A\nBob, s4, A\n";
+/// let (sbank, groups) = LoadFile::parse_roster_text(roster);
+/// let (kept, kept_groups) = resolve_duplicate_students(sbank, groups, DuplicateResolution::KeepFirst);
+/// assert_eq!(kept.len(), 2);
+/// assert_eq!((kept[0].get_name().as_str(), kept[0].get_id().as_str()), ("Alice", "s1"));
+/// assert_eq!((kept[1].get_name().as_str(), kept[1].get_id().as_str()), ("Bob", "s2"));
+/// assert!(!kept_groups.contains_key("s4"));
+/// assert!(find_duplicate_students(&kept, &kept_groups).is_empty());
+///
+/// let (sbank, groups) = LoadFile::parse_roster_text(roster);
+/// let (merged, merged_groups) = resolve_duplicate_students(sbank, groups, DuplicateResolution::Merge);
+/// assert_eq!(merged.len(), 2);
+/// assert_eq!((merged[0].get_name().as_str(), merged[0].get_id().as_str()), ("Alicia", "s1"));
+/// assert_eq!((merged[1].get_name().as_str(), merged[1].get_id().as_str()), ("Bob", "s4"));
+/// assert_eq!(merged_groups.get("s4").map(String::as_str), Some("A"));
+/// assert!(!merged_groups.contains_key("s2"));
+/// ```
+pub fn resolve_duplicate_students(sbank: SBank, mut groups: HashMap<String, String>, resolution: DuplicateResolution) -> (SBank, HashMap<String, String>)
+{
+    let duplicates = find_duplicate_students(&sbank, &groups);
+    let mut kept: Vec<Option<Student>> = sbank.into_iter().map(Some).collect();
+    for duplicate in duplicates
+    {
+        let (first, later) = (duplicate.indices[0], &duplicate.indices[1..]);
+        // A student that already went into another duplicate takes the
+        // rest of this one along.
+        let Some(mut student) = kept[first].take()
+            else {
+                later.iter().for_each(|&index| kept[index] = None);
+                continue;
+            };
+        let group = groups.remove(student.get_id());
+        for other in later.iter().filter_map(|&index| kept[index].take())
+        {
+            if resolution == DuplicateResolution::Merge
+            {
+                let name = if other.get_name().is_empty() { student.get_name().clone() } else { other.get_name().clone() };
+                student = Student::new(name, other.get_id().clone());
+            }
+            groups.remove(other.get_id());
+        }
+        if let Some(group) = group
+            { groups.insert(student.get_id().clone(), group); }
+        kept[first] = Some(student);
+    }
+    (kept.into_iter().flatten().collect(), groups)
+}
@@ -0,0 +1,82 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use qrate::{ Choices, Header, QBank, Question };
+use serde::Deserialize;
+
+/// The sample question bank bundled with the program, offered on the main
+/// page to first-time users who have no bank of their own.
+pub const SAMPLE_BANK: &[u8] = include_bytes!("../assets/samples/sample-bank.json");
+
+#[derive(Deserialize)]
+struct SampleBank
+{
+    title: String,
+    name: String,
+    id: String,
+    categories: Vec<String>,
+    notice: String,
+    questions: Vec<SampleQuestion>,
+}
+
+#[derive(Deserialize)]
+struct SampleQuestion
+{
+    id: u16,
+    group: u16,
+    category: u8,
+    question: String,
+    choices: Choices,
+}
+
+// pub fn parse_sample_bank(bytes: &[u8]) -> Option<QBank>
+/// Parses a question bank written in the JSON layout of `SAMPLE_BANK`.
+///
+/// # Arguments
+/// * `bytes` - The JSON text.
+///
+/// # Output
+/// The bank, or `None` if the text is not a valid sample bank.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::parse_sample_bank;
+/// let json = br#"{ "title": "T", "name": "N", "id": "I", "categories": ["A"], "notice": "",
+///                  "questions": [{ "id": 1, "group": 1, "category": 1, "question": "Q", "choices": [["Yes", true]] }] }"#;
+/// let qbank = parse_sample_bank(json).unwrap();
+/// assert_eq!(qbank.get_header().get_title(), "T");
+/// assert_eq!(qbank.get_questions()[0].get_choices()[0], ("Yes".to_string(), true));
+/// assert!(parse_sample_bank(b"not json").is_none());
+/// ```
+pub fn parse_sample_bank(bytes: &[u8]) -> Option<QBank>
+{
+    let sample: SampleBank = serde_json::from_slice(bytes).ok()?;
+    let header = Header::new(sample.title, sample.name, sample.id, sample.categories, sample.notice);
+    let mut qbank = QBank::new_with_header(header);
+    for question in sample.questions
+        { qbank.push_question(Question::new(question.id, question.group, question.category, question.question, question.choices)); }
+    Some(qbank)
+}
+
+// pub fn load_sample_bank() -> QBank
+/// Returns the bundled sample question bank, or an empty bank should the
+/// bundled file be broken.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::load_sample_bank;
+/// let qbank = load_sample_bank();
+/// assert!(!qbank.get_questions().is_empty());
+/// assert!(qbank.get_questions().iter().all(|q| q.get_choices().iter().any(|(_, correct)| *correct)));
+/// ```
+pub fn load_sample_bank() -> QBank
+{
+    parse_sample_bank(SAMPLE_BANK).unwrap_or_else(QBank::new_empty)
+}
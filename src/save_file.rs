@@ -0,0 +1,640 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fmt::Display;
+use std::path::{ Path, PathBuf };
+
+use qrate::QBank;
+use rfd::FileDialog;
+use iced::Task;
+
+use crate::control_tower::Message;
+use crate::{ Exam, ExportError, ExportFormat, export_exam, verify_export, catch_panic };
+use crate::{ ExamVariant, ExportBatch };
+use crate::{ GenerationSummary, save_summary };
+use crate::{ export_anki_deck, export_study_guide_to_pdf, PageTemplate };
+use crate::{ export_item_analysis, ItemStats };
+use crate::{ export_bank_stats, QBankStats };
+use crate::{ export_questions, resolve_export_path, QuestionMetas };
+use crate::log_event;
+
+/// Represents the result of an attempt to save a generated exam.
+#[derive(Debug, Clone)]
+pub enum ResultSaveFile
+{
+    /// The exam was written to the contained path.
+    Success(PathBuf),
+
+    /// The exam was written to the contained path, but reopening it did not
+    /// find the expected questions.
+    Unverified(PathBuf),
+
+    /// The exam was written to the contained path and reopens, but its
+    /// format does not let the questions be counted, as with a PDF.
+    Unchecked(PathBuf),
+
+    /// The user closed the file dialog without choosing a path.
+    Cancelled,
+
+    /// The export failed.
+    Failed(ExportError),
+}
+
+/// Provides utility functions for saving generated exams to files.
+#[derive(Debug, Clone)]
+pub struct SaveFile;
+
+impl SaveFile
+{
+    // pub async fn pick_export_path(format: ExportFormat) -> Option<PathBuf>
+    /// Asynchronously opens a save dialog for the user to choose an export path.
+    ///
+    /// # Arguments
+    /// * `format` - The `ExportFormat` whose extension is used as the filter.
+    ///
+    /// # Output
+    /// An `Option<PathBuf>` with the chosen path, or `None` if cancelled.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use qrate_gui::{ ExportFormat, SaveFile };
+    ///     let path = SaveFile::pick_export_path(ExportFormat::Pdf).await;
+    /// }
+    /// ```
+    pub async fn pick_export_path(format: ExportFormat) -> Option<PathBuf>
+    {
+        let extension = format.get_extension();
+        FileDialog::new()
+            .add_filter(extension, &[extension])
+            .set_directory(".")
+            .set_file_name(format!("exam.{}", extension))
+            .save_file()
+    }
+
+    // pub async fn save_exam(exam: Exam, format: ExportFormat) -> ResultSaveFile
+    /// Asks the user for a path and exports `exam` there, checking the
+    /// written file as `SaveFile::write_exam` does.
+    ///
+    /// # Arguments
+    /// * `exam` - The exam to export.
+    /// * `format` - The output format.
+    ///
+    /// # Output
+    /// A `ResultSaveFile` describing the outcome.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use qrate::Header;
+    ///     use qrate_gui::{ Exam, ExportFormat, NumberingStyle, SaveFile };
+    ///     let exam = Exam::new(Header::new_with_default(), vec![], NumberingStyle::Arabic);
+    ///     let result = SaveFile::save_exam(exam, ExportFormat::Excel).await;
+    /// }
+    /// ```
+    pub async fn save_exam(exam: Exam, format: ExportFormat) -> ResultSaveFile
+    {
+        match SaveFile::pick_export_path(format).await
+        {
+            Some(path) => SaveFile::write_exam(&exam, &path, format),
+            None => ResultSaveFile::Cancelled,
+        }
+    }
+
+    // pub fn write_exam(exam: &Exam, path: &Path, format: ExportFormat) -> ResultSaveFile
+    /// Exports `exam` to `path` without asking, overwriting any file there.
+    ///
+    /// The written file is reopened with `verify_export` afterwards; a PDF,
+    /// whose questions cannot be counted, is reported as `Unchecked` rather
+    /// than `Success`. A panic in the exporter is reported as
+    /// `ExportError::Panicked`.
+    ///
+    /// # Arguments
+    /// * `exam` - The exam to export.
+    /// * `path` - Where the exam is written.
+    /// * `format` - The output format.
+    ///
+    /// # Output
+    /// A `ResultSaveFile` describing the outcome; never `Cancelled`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ Header, Question };
+    /// use qrate_gui::{ Exam, ExportFormat, NumberingStyle, ResultSaveFile, SaveFile };
+    /// let questions = vec![Question::new(1, 1, 1, "Q1".to_string(), vec![])];
+    /// let exam = Exam::new(Header::new_with_default(), questions, NumberingStyle::Arabic);
+    /// let path = std::env::temp_dir().join("qrate_gui_write_exam.xlsx");
+    /// assert!(matches!(SaveFile::write_exam(&exam, &path, ExportFormat::Excel), ResultSaveFile::Success(_)));
+    /// ```
+    pub fn write_exam(exam: &Exam, path: &Path, format: ExportFormat) -> ResultSaveFile
+    {
+        match catch_panic(|| export_exam(exam, path, format)).unwrap_or_else(|message| Err(ExportError::Panicked(message)))
+        {
+            Ok(()) if !verify_export(path, exam.get_questions().len()) => ResultSaveFile::Unverified(path.to_path_buf()),
+            Ok(()) if !format.can_count_questions() => ResultSaveFile::Unchecked(path.to_path_buf()),
+            Ok(()) => ResultSaveFile::Success(path.to_path_buf()),
+            Err(e) => ResultSaveFile::Failed(e),
+        }
+    }
+
+    // pub fn perform_save_exam_task(exam: Exam, format: ExportFormat) -> Task<Message>
+    /// Creates a [Task] that asks for a path, exports the exam and reports
+    /// the outcome with a `Message::ExamExported`.
+    ///
+    /// # Arguments
+    /// * `exam` - The exam to export.
+    /// * `format` - The output format.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::ExamExported`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use qrate::Header;
+    /// use qrate_gui::{ Exam, ExportFormat, NumberingStyle, SaveFile };
+    /// let exam = Exam::new(Header::new_with_default(), vec![], NumberingStyle::Arabic);
+    /// let _task = SaveFile::perform_save_exam_task(exam, ExportFormat::Pdf);
+    /// ```
+    #[inline]
+    pub fn perform_save_exam_task(exam: Exam, format: ExportFormat) -> Task<Message>
+    {
+        Task::perform(SaveFile::save_exam(exam, format), Message::ExamExported)
+    }
+
+    // pub fn perform_pick_export_path_task(format: ExportFormat) -> Task<Message>
+    /// Creates a [Task] that asks for an export path and hands it over with
+    /// a `Message::ExportPathChosen`, so that an existing file can be
+    /// confirmed before it is overwritten.
+    ///
+    /// # Arguments
+    /// * `format` - The `ExportFormat` whose extension is used as the filter.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::ExportPathChosen`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use qrate_gui::{ ExportFormat, SaveFile };
+    /// let _task = SaveFile::perform_pick_export_path_task(ExportFormat::Pdf);
+    /// ```
+    #[inline]
+    pub fn perform_pick_export_path_task(format: ExportFormat) -> Task<Message>
+    {
+        Task::perform(SaveFile::pick_export_path(format), move |path| Message::ExportPathChosen(format, path))
+    }
+
+    // pub async fn pick_export_folder() -> Option<PathBuf>
+    /// Asynchronously opens a folder dialog for the user to choose where
+    /// exam variants are written.
+    ///
+    /// # Output
+    /// An `Option<PathBuf>` with the chosen folder, or `None` if cancelled.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use qrate_gui::SaveFile;
+    ///     let folder = SaveFile::pick_export_folder().await;
+    /// }
+    /// ```
+    pub async fn pick_export_folder() -> Option<PathBuf>
+    {
+        FileDialog::new()
+            .set_directory(".")
+            .pick_folder()
+    }
+
+    // pub async fn prepare_batch(variants: Vec<ExamVariant>, format: ExportFormat) -> Option<ExportBatch>
+    /// Asks the user for a folder and prepares the export of the variants
+    /// into it, without writing anything yet.
+    ///
+    /// # Arguments
+    /// * `variants` - The variants to export.
+    /// * `format` - The output format.
+    ///
+    /// # Output
+    /// The `ExportBatch`, or `None` if the dialog was cancelled.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use qrate_gui::{ ExportFormat, SaveFile };
+    ///     let batch = SaveFile::prepare_batch(vec![], ExportFormat::Pdf).await;
+    /// }
+    /// ```
+    pub async fn prepare_batch(variants: Vec<ExamVariant>, format: ExportFormat) -> Option<ExportBatch>
+    {
+        let dir = SaveFile::pick_export_folder().await?;
+        Some(ExportBatch::new(variants, dir, format))
+    }
+
+    // pub fn perform_prepare_batch_task(variants: Vec<ExamVariant>, format: ExportFormat) -> Task<Message>
+    /// Creates a [Task] that asks for a folder and hands the prepared batch
+    /// over with a `Message::ExportBatchReady`.
+    ///
+    /// # Arguments
+    /// * `variants` - The variants to export.
+    /// * `format` - The output format.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::ExportBatchReady`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use qrate_gui::{ ExportFormat, SaveFile };
+    /// let _task = SaveFile::perform_prepare_batch_task(vec![], ExportFormat::Excel);
+    /// ```
+    #[inline]
+    pub fn perform_prepare_batch_task(variants: Vec<ExamVariant>, format: ExportFormat) -> Task<Message>
+    {
+        Task::perform(SaveFile::prepare_batch(variants, format), Message::ExportBatchReady)
+    }
+
+    // pub async fn save_summary_as(summary: GenerationSummary) -> Option<PathBuf>
+    /// Asks the user for a path and saves `summary` there as JSON.
+    ///
+    /// # Arguments
+    /// * `summary` - The summary of a batch export.
+    ///
+    /// # Output
+    /// The path the summary was written to, or `None` if the dialog was
+    /// cancelled or writing failed.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use std::time::SystemTime;
+    ///     use qrate_gui::{ GenerationSummary, SaveFile };
+    ///     let summary = GenerationSummary::from_reports(&[], SystemTime::now());
+    ///     let path = SaveFile::save_summary_as(summary).await;
+    /// }
+    /// ```
+    pub async fn save_summary_as(summary: GenerationSummary) -> Option<PathBuf>
+    {
+        let path = FileDialog::new()
+                        .add_filter("json", &["json"])
+                        .set_directory(".")
+                        .set_file_name("generation-summary.json")
+                        .save_file()?;
+        SaveFile::report_saved(save_summary(&path, &summary), path, "Error saving generation summary")
+    }
+
+    // pub fn perform_save_summary_task(summary: GenerationSummary) -> Task<Message>
+    /// Creates a [Task] that asks for a path, saves the summary and reports
+    /// the outcome with a `Message::SummarySaved`.
+    ///
+    /// # Arguments
+    /// * `summary` - The summary of a batch export.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::SummarySaved`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::time::SystemTime;
+    /// use qrate_gui::{ GenerationSummary, SaveFile };
+    /// let summary = GenerationSummary::from_reports(&[], SystemTime::now());
+    /// let _task = SaveFile::perform_save_summary_task(summary);
+    /// ```
+    #[inline]
+    pub fn perform_save_summary_task(summary: GenerationSummary) -> Task<Message>
+    {
+        Task::perform(SaveFile::save_summary_as(summary), Message::SummarySaved)
+    }
+
+    // pub async fn save_anki_deck(qbank: QBank) -> Option<PathBuf>
+    /// Asks the user for a path and writes the Anki deck of `qbank` there.
+    ///
+    /// # Arguments
+    /// * `qbank` - The question bank.
+    ///
+    /// # Output
+    /// The path the deck was written to, or `None` if the dialog was
+    /// cancelled or writing failed.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use qrate::QBank;
+    ///     use qrate_gui::SaveFile;
+    ///     let path = SaveFile::save_anki_deck(QBank::new_with_default()).await;
+    /// }
+    /// ```
+    pub async fn save_anki_deck(qbank: QBank) -> Option<PathBuf>
+    {
+        let path = FileDialog::new()
+                        .add_filter("txt", &["txt"])
+                        .set_directory(".")
+                        .set_file_name("anki-deck.txt")
+                        .save_file()?;
+        SaveFile::report_saved(export_anki_deck(&qbank, &path), path, "Error exporting Anki deck")
+    }
+
+    // pub fn perform_save_anki_deck_task(qbank: QBank) -> Task<Message>
+    /// Creates a [Task] that asks for a path, writes the Anki deck and
+    /// reports the outcome with a `Message::AnkiDeckExported`.
+    ///
+    /// # Arguments
+    /// * `qbank` - The question bank.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::AnkiDeckExported`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use qrate::QBank;
+    /// use qrate_gui::SaveFile;
+    /// let _task = SaveFile::perform_save_anki_deck_task(QBank::new_with_default());
+    /// ```
+    #[inline]
+    pub fn perform_save_anki_deck_task(qbank: QBank) -> Task<Message>
+    {
+        Task::perform(SaveFile::save_anki_deck(qbank), Message::AnkiDeckExported)
+    }
+
+    // pub async fn save_study_guide(qbank: QBank, template: PageTemplate) -> Option<PathBuf>
+    /// Asks the user for a path and writes the PDF study guide of `qbank`
+    /// there.
+    ///
+    /// # Arguments
+    /// * `qbank` - The question bank.
+    /// * `template` - The page template holding the paper size and margin.
+    ///
+    /// # Output
+    /// The path the guide was written to, or `None` if the dialog was
+    /// cancelled or writing failed.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use qrate::QBank;
+    ///     use qrate_gui::{ PageTemplate, SaveFile };
+    ///     let path = SaveFile::save_study_guide(QBank::new_with_default(), PageTemplate::new()).await;
+    /// }
+    /// ```
+    pub async fn save_study_guide(qbank: QBank, template: PageTemplate) -> Option<PathBuf>
+    {
+        let path = FileDialog::new()
+                        .add_filter("pdf", &["pdf"])
+                        .set_directory(".")
+                        .set_file_name("study-guide.pdf")
+                        .save_file()?;
+        SaveFile::report_saved(export_study_guide_to_pdf(&qbank, &template, &path), path, "Error exporting study guide")
+    }
+
+    // pub fn perform_save_study_guide_task(qbank: QBank, template: PageTemplate) -> Task<Message>
+    /// Creates a [Task] that asks for a path, writes the study guide and
+    /// reports the outcome with a `Message::StudyGuideExported`.
+    ///
+    /// # Arguments
+    /// * `qbank` - The question bank.
+    /// * `template` - The page template holding the paper size and margin.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::StudyGuideExported`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use qrate::QBank;
+    /// use qrate_gui::{ PageTemplate, SaveFile };
+    /// let _task = SaveFile::perform_save_study_guide_task(QBank::new_with_default(), PageTemplate::new());
+    /// ```
+    #[inline]
+    pub fn perform_save_study_guide_task(qbank: QBank, template: PageTemplate) -> Task<Message>
+    {
+        Task::perform(SaveFile::save_study_guide(qbank, template), Message::StudyGuideExported)
+    }
+
+    // pub async fn save_item_analysis(stats: Vec<ItemStats>) -> Option<PathBuf>
+    /// Asks the user for a path and writes the item-analysis report there.
+    ///
+    /// # Arguments
+    /// * `stats` - The statistics from `item_analysis`.
+    ///
+    /// # Output
+    /// The path the report was written to, or `None` if the dialog was
+    /// cancelled or writing failed.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use qrate_gui::SaveFile;
+    ///     let path = SaveFile::save_item_analysis(vec![]).await;
+    /// }
+    /// ```
+    pub async fn save_item_analysis(stats: Vec<ItemStats>) -> Option<PathBuf>
+    {
+        let path = FileDialog::new()
+                        .add_filter("xlsx", &["xlsx"])
+                        .set_directory(".")
+                        .set_file_name("item-analysis.xlsx")
+                        .save_file()?;
+        SaveFile::report_saved(export_item_analysis(&stats, &path), path, "Error exporting item analysis")
+    }
+
+    // pub fn perform_save_item_analysis_task(stats: Vec<ItemStats>) -> Task<Message>
+    /// Creates a [Task] that asks for a path, writes the item-analysis
+    /// report and reports the outcome with a `Message::ItemAnalysisExported`.
+    ///
+    /// # Arguments
+    /// * `stats` - The statistics from `item_analysis`.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::ItemAnalysisExported`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use qrate_gui::SaveFile;
+    /// let _task = SaveFile::perform_save_item_analysis_task(vec![]);
+    /// ```
+    #[inline]
+    pub fn perform_save_item_analysis_task(stats: Vec<ItemStats>) -> Task<Message>
+    {
+        Task::perform(SaveFile::save_item_analysis(stats), Message::ItemAnalysisExported)
+    }
+
+    // pub async fn save_bank_stats(stats: QBankStats) -> Option<PathBuf>
+    /// Asks the user for a path and writes the statistics of a question
+    /// bank there, as CSV or JSON by the extension chosen.
+    ///
+    /// # Arguments
+    /// * `stats` - The statistics from `bank_stats`.
+    ///
+    /// # Output
+    /// The path the statistics were written to, or `None` if the dialog was
+    /// cancelled or writing failed.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use qrate::QBank;
+    ///     use qrate_gui::{ bank_stats, QuestionMetas, SaveFile };
+    ///     let path = SaveFile::save_bank_stats(bank_stats(&QBank::new_with_default(), &QuestionMetas::new())).await;
+    /// }
+    /// ```
+    pub async fn save_bank_stats(stats: QBankStats) -> Option<PathBuf>
+    {
+        let path = FileDialog::new()
+                        .add_filter("csv", &["csv"])
+                        .add_filter("json", &["json"])
+                        .set_directory(".")
+                        .set_file_name("bank-stats.csv")
+                        .save_file()?;
+        SaveFile::report_saved(export_bank_stats(&stats, &path), path, "Error exporting bank statistics")
+    }
+
+    // pub fn perform_save_bank_stats_task(stats: QBankStats) -> Task<Message>
+    /// Creates a [Task] that asks for a path, writes the statistics of a
+    /// question bank and reports the outcome with a `Message::BankStatsExported`.
+    ///
+    /// # Arguments
+    /// * `stats` - The statistics from `bank_stats`.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::BankStatsExported`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use qrate::QBank;
+    /// use qrate_gui::{ bank_stats, QuestionMetas, SaveFile };
+    /// let _task = SaveFile::perform_save_bank_stats_task(bank_stats(&QBank::new_with_default(), &QuestionMetas::new()));
+    /// ```
+    #[inline]
+    pub fn perform_save_bank_stats_task(stats: QBankStats) -> Task<Message>
+    {
+        Task::perform(SaveFile::save_bank_stats(stats), Message::BankStatsExported)
+    }
+
+    // pub async fn save_questions(qbank: QBank, metas: QuestionMetas, open_bank: PathBuf) -> ResultSaveFile
+    /// Asks the user for a path and writes `qbank` there as a new question
+    /// bank, as SQLite or Excel by the extension chosen. The path is
+    /// completed by `resolve_export_path`, which also refuses the file of
+    /// the open bank.
+    ///
+    /// # Arguments
+    /// * `qbank` - The questions to export with the header of the open bank.
+    /// * `metas` - The question metadata of the open bank.
+    /// * `open_bank` - The file of the open bank.
+    ///
+    /// # Output
+    /// A `ResultSaveFile` with the path the bank was written to.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// async fn example_usage() {
+    ///     use std::path::PathBuf;
+    ///     use qrate::QBank;
+    ///     use qrate_gui::{ QuestionMetas, SaveFile };
+    ///     let result = SaveFile::save_questions(QBank::new_with_default(), QuestionMetas::new(), PathBuf::new()).await;
+    /// }
+    /// ```
+    pub async fn save_questions(qbank: QBank, metas: QuestionMetas, open_bank: PathBuf) -> ResultSaveFile
+    {
+        let Some(chosen) = FileDialog::new()
+                                .add_filter("qbdb", &["qbdb"])
+                                .add_filter("qb.xlsx", &["xlsx"])
+                                .set_directory(".")
+                                .set_file_name("questions.qbdb")
+                                .save_file()
+            else { return ResultSaveFile::Cancelled; };
+        SaveFile::write_questions(&qbank, &metas, &chosen, &open_bank)
+    }
+
+    // pub fn write_questions(qbank: &QBank, metas: &QuestionMetas, chosen: &Path, open_bank: &Path) -> ResultSaveFile
+    /// Writes `qbank` as a new question bank to the path completed from
+    /// `chosen` by `resolve_export_path`, without asking.
+    ///
+    /// # Arguments
+    /// * `qbank` - The questions to export with the header of the open bank.
+    /// * `metas` - The question metadata of the open bank.
+    /// * `chosen` - The path picked by the user.
+    /// * `open_bank` - The file of the open bank, which is not overwritten.
+    ///
+    /// # Output
+    /// A `ResultSaveFile` with the path the bank was written to; never
+    /// `Cancelled`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::Path;
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ QuestionMetas, ResultSaveFile, SaveFile };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![("a".to_string(), true)]));
+    /// let chosen = std::env::temp_dir().join("qrate_gui_write_questions.xlsx");
+    /// let result = SaveFile::write_questions(&qbank, &QuestionMetas::new(), &chosen, Path::new(""));
+    /// assert!(matches!(result, ResultSaveFile::Success(path) if path.ends_with("qrate_gui_write_questions.qb.xlsx")));
+    /// ```
+    pub fn write_questions(qbank: &QBank, metas: &QuestionMetas, chosen: &Path, open_bank: &Path) -> ResultSaveFile
+    {
+        let questions: Vec<_> = qbank.get_questions().iter().collect();
+        match resolve_export_path(chosen, open_bank)
+                .and_then(|path| export_questions(qbank.get_header(), &questions, metas, &path).map(|()| path))
+        {
+            Ok(path) => ResultSaveFile::Success(path),
+            Err(e) => {
+                log_event(&format!("Error exporting questions: {}", e));
+                ResultSaveFile::Failed(e)
+            },
+        }
+    }
+
+    // pub fn report_saved<E: Display>(result: Result<(), E>, path: PathBuf, failure: &str) -> Option<PathBuf>
+    /// Turns the result of writing `path` into what the `*Exported`
+    /// messages carry, logging a failure after `failure`.
+    ///
+    /// # Output
+    /// `path` on success, `None` on failure.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use qrate_gui::SaveFile;
+    /// assert_eq!(SaveFile::report_saved(Ok::<(), String>(()), PathBuf::from("deck.txt"), "Error exporting Anki deck"), Some(PathBuf::from("deck.txt")));
+    /// assert_eq!(SaveFile::report_saved(Err("disk full"), PathBuf::from("deck.txt"), "Error exporting Anki deck"), None);
+    /// ```
+    pub fn report_saved<E: Display>(result: Result<(), E>, path: PathBuf, failure: &str) -> Option<PathBuf>
+    {
+        match result
+        {
+            Ok(()) => Some(path),
+            Err(e) => {
+                log_event(&format!("{}: {}", failure, e));
+                None
+            },
+        }
+    }
+
+    // pub fn perform_save_questions_task(qbank: QBank, metas: QuestionMetas, open_bank: PathBuf) -> Task<Message>
+    /// Creates a [Task] that asks for a path, writes the questions as a new
+    /// question bank and reports the outcome with a `Message::QuestionsExported`.
+    ///
+    /// # Arguments
+    /// * `qbank` - The questions to export with the header of the open bank.
+    /// * `metas` - The question metadata of the open bank.
+    /// * `open_bank` - The file of the open bank, which is not overwritten.
+    ///
+    /// # Output
+    /// A [Task] that, when run, will eventually produce a `Message::QuestionsExported`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::path::PathBuf;
+    /// use qrate::QBank;
+    /// use qrate_gui::{ QuestionMetas, SaveFile };
+    /// let _task = SaveFile::perform_save_questions_task(QBank::new_with_default(), QuestionMetas::new(), PathBuf::new());
+    /// ```
+    #[inline]
+    pub fn perform_save_questions_task(qbank: QBank, metas: QuestionMetas, open_bank: PathBuf) -> Task<Message>
+    {
+        Task::perform(SaveFile::save_questions(qbank, metas, open_bank), Message::QuestionsExported)
+    }
+}
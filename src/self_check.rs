@@ -0,0 +1,138 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::fmt;
+use std::fs;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+
+use qrate::QBank;
+
+use crate::{ BankFormat, LoadFile, ResultLoadFile };
+
+/// A tiny Excel question bank written by a known good version of `qrate`.
+pub const SELF_CHECK_EXCEL: &[u8] = include_bytes!("../assets/fixtures/self-check.qb.xlsx");
+
+/// The same question bank as `SELF_CHECK_EXCEL`, as a SQLite database.
+pub const SELF_CHECK_SQLITE: &[u8] = include_bytes!("../assets/fixtures/self-check.qbdb");
+
+/// The title of the fixtures.
+const FIXTURE_TITLE: &str = "Self-check";
+
+/// The id, prompt and choices of a question of the fixtures.
+type FixtureQuestion = (u16, &'static str, [(&'static str, bool); 2]);
+
+/// The questions of the fixtures, in order.
+const FIXTURE_QUESTIONS: [FixtureQuestion; 2] = [
+    (1, "1 + 1 = ?", [("2", true), ("3", false)]),
+    (2, "2 × 3 = ?", [("5", false), ("6", true)]),
+];
+
+/// Tells apart the temporary copies of checks running at the same time.
+static CHECK_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Describes how the linked `qrate` failed to read a fixture.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelfCheckError
+{
+    /// The fixture could not be read at all; contains the reason.
+    Unreadable(BankFormat, String),
+
+    /// The fixture was read, but differs from what was written; contains
+    /// the first difference.
+    Mismatch(BankFormat, String),
+}
+
+impl fmt::Display for SelfCheckError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            SelfCheckError::Unreadable(format, reason) => write!(f, "The {} fixture could not be read: {}", format, reason),
+            SelfCheckError::Mismatch(format, difference) => write!(f, "The {} fixture was read differently: {}", format, difference),
+        }
+    }
+}
+
+// pub fn check_fixture(bytes: &[u8], format: BankFormat) -> Result<(), SelfCheckError>
+/// Reads a fixture through the same readers as the question banks of the
+/// user and compares it with what it is known to contain.
+///
+/// # Arguments
+/// * `bytes` - The content of the fixture.
+/// * `format` - The format of the fixture.
+///
+/// # Output
+/// `Ok(())` if the fixture reads back as written, or why it does not.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ check_fixture, BankFormat, SelfCheckError, SELF_CHECK_EXCEL, SELF_CHECK_SQLITE };
+/// assert_eq!(check_fixture(SELF_CHECK_EXCEL, BankFormat::Excel), Ok(()));
+/// assert_eq!(check_fixture(SELF_CHECK_SQLITE, BankFormat::SQLite), Ok(()));
+///
+/// // A fixture that no reader understands is reported, not panicked on.
+/// assert!(matches!(check_fixture(b"not a bank", BankFormat::Excel), Err(SelfCheckError::Unreadable(BankFormat::Excel, _))));
+/// ```
+pub fn check_fixture(bytes: &[u8], format: BankFormat) -> Result<(), SelfCheckError>
+{
+    let stem = format!("qrate_gui_self_check_{}_{}", std::process::id(), CHECK_COUNTER.fetch_add(1, Ordering::Relaxed));
+    let file_name = match format
+    {
+        BankFormat::SQLite => format!("{}.qbdb", stem),
+        BankFormat::Excel => format!("{}.qb.xlsx", stem),
+    };
+    let path = std::env::temp_dir().join(file_name);
+    fs::write(&path, bytes).map_err(|e| SelfCheckError::Unreadable(format, e.to_string()))?;
+    let result = LoadFile::read_qbank_from_path(&path);
+    let _ = fs::remove_file(&path);
+    match result
+    {
+        ResultLoadFile::Success(qbank) => compare_with_fixture(&qbank).map_err(|difference| SelfCheckError::Mismatch(format, difference)),
+        other => Err(SelfCheckError::Unreadable(format, other.to_string())),
+    }
+}
+
+// pub fn run_self_check() -> Result<(), SelfCheckError>
+/// Confirms at startup that the linked `qrate` still reads the question
+/// bank formats as expected, by reading both bundled fixtures.
+///
+/// # Output
+/// `Ok(())`, or the first fixture that did not read back as written.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::run_self_check;
+/// assert_eq!(run_self_check(), Ok(()));
+/// ```
+pub fn run_self_check() -> Result<(), SelfCheckError>
+{
+    check_fixture(SELF_CHECK_EXCEL, BankFormat::Excel)?;
+    check_fixture(SELF_CHECK_SQLITE, BankFormat::SQLite)
+}
+
+fn compare_with_fixture(qbank: &QBank) -> Result<(), String>
+{
+    let title = qbank.get_header().get_title();
+    if title != FIXTURE_TITLE
+        { return Err(format!("title {:?} instead of {:?}", title, FIXTURE_TITLE)); }
+    let questions = qbank.get_questions();
+    if questions.len() != FIXTURE_QUESTIONS.len()
+        { return Err(format!("{} questions instead of {}", questions.len(), FIXTURE_QUESTIONS.len())); }
+    for (question, (id, prompt, choices)) in questions.iter().zip(FIXTURE_QUESTIONS)
+    {
+        if question.get_id() != id || question.get_question() != prompt
+            { return Err(format!("question {} {:?} instead of {} {:?}", question.get_id(), question.get_question(), id, prompt)); }
+        let read: Vec<(&str, bool)> = question.get_choices().iter().map(|(text, correct)| (text.as_str(), *correct)).collect();
+        if read != choices
+            { return Err(format!("choices {:?} of question {} instead of {:?}", read, id, choices)); }
+    }
+    Ok(())
+}
@@ -0,0 +1,169 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::time::{ SystemTime, UNIX_EPOCH };
+
+use qrate::Question;
+
+/// A small deterministic pseudo-random number generator (SplitMix64).
+///
+/// The same seed always yields the same sequence, so a paper can be
+/// regenerated exactly from its seed. It is not suitable for cryptography.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeededRng
+{
+    state: u64,
+}
+
+impl SeededRng
+{
+    // pub fn new(seed: u64) -> Self
+    /// Creates a generator starting from `seed`.
+    ///
+    /// # Arguments
+    /// * `seed` - The seed of the sequence.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::SeededRng;
+    /// let mut a = SeededRng::new(42);
+    /// let mut b = SeededRng::new(42);
+    /// assert_eq!(a.next_u64(), b.next_u64());
+    /// ```
+    pub fn new(seed: u64) -> Self
+    {
+        Self { state: seed }
+    }
+
+    // pub fn from_time() -> Self
+    /// Creates a generator seeded from the current time.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::SeededRng;
+    /// let mut rng = SeededRng::from_time();
+    /// assert!(rng.next_below(6) < 6);
+    /// ```
+    pub fn from_time() -> Self
+    {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or_default();
+        Self::new(nanos)
+    }
+
+    // pub fn next_u64(&mut self) -> u64
+    /// Returns the next pseudo-random number.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::SeededRng;
+    /// let mut rng = SeededRng::new(0);
+    /// assert_ne!(rng.next_u64(), rng.next_u64());
+    /// ```
+    pub fn next_u64(&mut self) -> u64
+    {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // pub fn next_below(&mut self, bound: usize) -> usize
+    /// Returns a pseudo-random number in `0..bound`, or `0` if `bound` is `0`.
+    ///
+    /// # Arguments
+    /// * `bound` - The exclusive upper bound.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::SeededRng;
+    /// let mut rng = SeededRng::new(7);
+    /// assert!((0..100).all(|_| rng.next_below(4) < 4));
+    /// assert_eq!(rng.next_below(0), 0);
+    /// ```
+    pub fn next_below(&mut self, bound: usize) -> usize
+    {
+        if bound == 0
+            { 0 }
+        else
+            { (self.next_u64() % bound as u64) as usize }
+    }
+
+    // pub fn shuffle<T>(&mut self, items: &mut [T])
+    /// Shuffles `items` in place with the Fisher-Yates algorithm.
+    ///
+    /// # Arguments
+    /// * `items` - The slice to shuffle.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::SeededRng;
+    /// let mut items = vec![1, 2, 3, 4, 5];
+    /// SeededRng::new(1).shuffle(&mut items);
+    /// let mut sorted = items.clone();
+    /// sorted.sort();
+    /// assert_eq!(sorted, vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn shuffle<T>(&mut self, items: &mut [T])
+    {
+        for i in (1..items.len()).rev()
+        {
+            let j = self.next_below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+// pub fn shuffle_choices(question: &Question, rng: &mut SeededRng) -> (Question, Vec<usize>)
+/// Returns a copy of `question` with its answer choices reordered.
+///
+/// Each choice keeps its correctness flag, so the correct answer stays the
+/// same choice wherever it moves.
+///
+/// # Arguments
+/// * `question` - The question whose choices are shuffled.
+/// * `rng` - The generator deciding the new order.
+///
+/// # Output
+/// The shuffled question and the remap, where `order[new_index]` is the
+/// original index of the choice now at `new_index`.
+///
+/// # Examples
+/// ```
+/// use qrate::Question;
+/// use qrate_gui::{ shuffle_choices, SeededRng };
+///
+/// let choices = vec![
+///     ("Paris".to_string(), true),
+///     ("Rome".to_string(), false),
+///     ("Berlin".to_string(), false),
+///     ("Madrid".to_string(), false),
+/// ];
+/// let question = Question::new(1, 1, 1, "Capital of France?".to_string(), choices.clone());
+///
+/// for seed in 0..20
+/// {
+///     let (shuffled, order) = shuffle_choices(&question, &mut SeededRng::new(seed));
+///     let correct = shuffled.get_choices().iter().position(|(_, answer)| *answer).unwrap();
+///     assert_eq!(shuffled.get_choices()[correct].0, "Paris");
+///     assert_eq!(order[correct], 0);
+///     for (new_index, &old_index) in order.iter().enumerate()
+///         { assert_eq!(shuffled.get_choices()[new_index], choices[old_index]); }
+/// }
+/// ```
+pub fn shuffle_choices(question: &Question, rng: &mut SeededRng) -> (Question, Vec<usize>)
+{
+    let choices = question.get_choices();
+    let mut order: Vec<usize> = (0..choices.len()).collect();
+    rng.shuffle(&mut order);
+    let mut shuffled = question.clone();
+    shuffled.set_choices(order.iter().map(|&i| choices[i].clone()).collect());
+    (shuffled, order)
+}
@@ -0,0 +1,159 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use qrate::{ QBank, Question };
+use rust_i18n::t;
+
+use crate::{ distinct_categories, Category };
+use crate::exam::choice_label;
+
+/// A single line of a laid-out study guide.
+///
+/// Unlike an exam, a study guide groups the questions by category and shows
+/// the answer right below each question, for revision handouts.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StudyGuideLine
+{
+    /// The title of the guide.
+    Title(String),
+
+    /// The heading of the table of contents.
+    ContentsHeading,
+
+    /// One entry of the table of contents: a category and its number of
+    /// questions.
+    ContentsEntry { category: String, count: usize },
+
+    /// The header that starts the questions of a category.
+    Category(String),
+
+    /// A question prompt with its number within the guide.
+    Question { number: usize, text: String },
+
+    /// An answer choice with its label, marked if it is correct.
+    Choice { label: String, text: String, correct: bool },
+
+    /// The correct choices of the preceding question.
+    Answer(Vec<String>),
+
+    /// An empty separator line.
+    Blank,
+}
+
+impl StudyGuideLine
+{
+    // pub fn to_text(&self) -> String
+    /// Renders the line as plain text in the current locale.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::StudyGuideLine;
+    /// let line = StudyGuideLine::Choice { label: "B".to_string(), text: "4".to_string(), correct: true };
+    /// assert_eq!(line.to_text(), "    (B) 4 *");
+    /// let line = StudyGuideLine::ContentsEntry { category: "Algebra".to_string(), count: 3 };
+    /// assert!(line.to_text().contains("Algebra"));
+    /// ```
+    pub fn to_text(&self) -> String
+    {
+        match self
+        {
+            StudyGuideLine::Title(title) => title.clone(),
+            StudyGuideLine::ContentsHeading => t!("contents").to_string(),
+            StudyGuideLine::ContentsEntry { category, count } => t!("contents-entry", category = category, count = count).to_string(),
+            StudyGuideLine::Category(category) => category.clone(),
+            StudyGuideLine::Question { number, text } => format!("{}. {}", number, text),
+            StudyGuideLine::Choice { label, text, correct: true } => format!("    ({}) {} *", label, text),
+            StudyGuideLine::Choice { label, text, correct: false } => format!("    ({}) {}", label, text),
+            StudyGuideLine::Answer(labels) => format!("{}: {}", t!("answer"), labels.join(", ")),
+            StudyGuideLine::Blank => String::new(),
+        }
+    }
+}
+
+// pub fn study_guide_layout(qbank: &QBank) -> Vec<StudyGuideLine>
+/// Lays out every question of `qbank` as a study guide: the title, a table
+/// of contents by category, then the questions of each category under its
+/// header with their answers.
+///
+/// Categories appear in the order of `distinct_categories`, and questions
+/// keep the order of the bank within their category. They are numbered
+/// through the whole guide.
+///
+/// # Arguments
+/// * `qbank` - The question bank.
+///
+/// # Examples
+/// ```
+/// use qrate::{ Header, QBank, Question };
+/// use qrate_gui::{ study_guide_layout, StudyGuideLine };
+///
+/// let mut header = Header::new_empty();
+/// header.set_title("Review".to_string());
+/// header.set_categories(vec!["Geometry".to_string(), "Algebra".to_string()]);
+/// let mut qbank = QBank::new_with_header(header);
+/// qbank.push_question(Question::new(1, 1, 1, "Sides of a square?".to_string(),
+///                                   vec![("3".to_string(), false), ("4".to_string(), true)]));
+/// qbank.push_question(Question::new(2, 2, 2, "2 + 2?".to_string(), vec![("4".to_string(), true)]));
+/// qbank.push_question(Question::new(3, 3, 1, "Sides of a triangle?".to_string(), vec![("3".to_string(), true)]));
+///
+/// let lines = study_guide_layout(&qbank);
+/// assert_eq!(lines[0], StudyGuideLine::Title("Review".to_string()));
+/// assert_eq!(lines[2], StudyGuideLine::ContentsEntry { category: "Algebra".to_string(), count: 1 });
+/// assert_eq!(lines[3], StudyGuideLine::ContentsEntry { category: "Geometry".to_string(), count: 2 });
+///
+/// // Each category header is followed by its own questions and their answers.
+/// let algebra = lines.iter().position(|line| *line == StudyGuideLine::Category("Algebra".to_string())).unwrap();
+/// let geometry = lines.iter().position(|line| *line == StudyGuideLine::Category("Geometry".to_string())).unwrap();
+/// assert!(algebra < geometry);
+/// assert_eq!(lines[algebra + 1], StudyGuideLine::Question { number: 1, text: "2 + 2?".to_string() });
+/// assert!(lines[algebra..geometry].contains(&StudyGuideLine::Answer(vec!["A".to_string()])));
+/// assert_eq!(lines[geometry + 1], StudyGuideLine::Question { number: 2, text: "Sides of a square?".to_string() });
+/// assert!(lines[geometry..].contains(&StudyGuideLine::Answer(vec!["B".to_string()])));
+/// assert!(lines[geometry..].contains(&StudyGuideLine::Question { number: 3, text: "Sides of a triangle?".to_string() }));
+/// ```
+pub fn study_guide_layout(qbank: &QBank) -> Vec<StudyGuideLine>
+{
+    let header = qbank.get_header();
+    let groups: Vec<(Category, Vec<&Question>)> = distinct_categories(qbank).into_iter()
+        .map(|category| {
+            let questions = qbank.get_questions().iter()
+                                .filter(|question| Category::of(header, question) == category)
+                                .collect();
+            (category, questions)
+        })
+        .collect();
+
+    let mut lines = vec![ StudyGuideLine::Title(header.get_title().clone()), StudyGuideLine::ContentsHeading ];
+    for (category, questions) in groups.iter()
+        { lines.push(StudyGuideLine::ContentsEntry { category: category.get_label(), count: questions.len() }); }
+    lines.push(StudyGuideLine::Blank);
+
+    let mut number = 0;
+    for (category, questions) in groups
+    {
+        lines.push(StudyGuideLine::Category(category.get_label()));
+        for question in questions
+        {
+            number += 1;
+            lines.push(StudyGuideLine::Question { number, text: question.get_question().clone() });
+            let mut answer = Vec::new();
+            for (j, (text, correct)) in question.get_choices().iter().enumerate()
+            {
+                let label = choice_label(j);
+                if *correct
+                    { answer.push(label.clone()); }
+                lines.push(StudyGuideLine::Choice { label, text: text.clone(), correct: *correct });
+            }
+            lines.push(StudyGuideLine::Answer(answer));
+            lines.push(StudyGuideLine::Blank);
+        }
+    }
+    lines
+}
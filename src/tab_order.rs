@@ -0,0 +1,238 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use crate::Page;
+
+/// The keys of the main menu buttons, in the order they are shown.
+pub const MENU_KEYS: [&str; 6] = [
+    "question-bank-management",
+    "generate-exam-paper",
+    "student-list-management",
+    "self-study",
+    "settings",
+    "information",
+];
+
+// pub fn get_submenu_keys(menu_key: &str) -> &'static [&'static str]
+/// Returns the keys of the items of the submenu opened by the main menu
+/// button `menu_key`, from top to bottom.
+///
+/// # Arguments
+/// * `menu_key` - The key of the main menu button, one of `MENU_KEYS`.
+///
+/// # Output
+/// The item keys, or only `coming-soon` for an unknown menu.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ get_submenu_keys, MENU_KEYS };
+/// assert_eq!(get_submenu_keys("information"), ["help", "software-info", "diagnostics", "copyright-info"]);
+/// assert_eq!(get_submenu_keys("nothing"), ["coming-soon"]);
+/// assert!(MENU_KEYS.iter().all(|key| !get_submenu_keys(key).is_empty()));
+/// ```
+pub fn get_submenu_keys(menu_key: &str) -> &'static [&'static str]
+{
+    match menu_key
+    {
+        "question-bank-management" => &[
+            "create-new-question-bank",
+            "load-question-bank",
+            "edit",
+            "bank-metadata",
+            "validate-bank",
+            "export",
+            "export-as",
+            "export-anki-deck",
+            "export-study-guide",
+            "export-item-analysis",
+            "export-bank-stats",
+            "optimize",
+            "open-containing-folder",
+            "open-in-new-window",
+            "unload-bank",
+        ],
+        "generate-exam-paper" => &[
+            "load-question-bank",
+            "criteria-for-question-extraction",
+            "load-student-list",
+            "export-exam-paper",
+            "quick-exam",
+        ],
+        "student-list-management" => &[
+            "load",
+            "edit",
+            "export",
+            "export-as",
+        ],
+        "self-study" => &[
+            "load-question-bank",
+            "criteria-for-question-extraction",
+            "grading-criteria",
+            "take-exam",
+        ],
+        "settings" => &[
+            "storage-path",
+            "atmosphere",
+            "font",
+            "language",
+            "startup",
+            "idle-lock",
+        ],
+        "information" => &[
+            "help",
+            "software-info",
+            "diagnostics",
+            "copyright-info",
+        ],
+        _ => &["coming-soon"],
+    }
+}
+
+/// The widget ids of the text fields of the content pages, which Tab moves
+/// the keyboard focus to.
+pub(crate) const EXAM_TITLE_INPUT: &str = "exam-title";
+pub(crate) const EXAM_COURSE_INPUT: &str = "exam-course";
+pub(crate) const EXAM_DATE_INPUT: &str = "exam-date";
+pub(crate) const EXAM_INSTRUCTIONS_INPUT: &str = "exam-instructions";
+pub(crate) const TAG_FILTER_INPUT: &str = "tag-filter";
+pub(crate) const PINNED_QUESTIONS_INPUT: &str = "pinned-questions";
+pub(crate) const SECTIONS_INPUT: &str = "sections";
+pub(crate) const QUESTION_COUNT_INPUT: &str = "question-count";
+pub(crate) const PAGE_HEADER_INPUT: &str = "page-header";
+pub(crate) const PAGE_FOOTER_INPUT: &str = "page-footer";
+pub(crate) const PAGE_LOGO_INPUT: &str = "page-logo";
+pub(crate) const SEED_INPUT: &str = "seed";
+pub(crate) const VARIANT_COUNT_INPUT: &str = "variant-count";
+pub(crate) const IDLE_LOCK_PIN_INPUT: &str = "idle-lock-pin";
+pub(crate) const BANK_TITLE_INPUT: &str = "bank-title";
+pub(crate) const BANK_AUTHOR_INPUT: &str = "bank-author";
+pub(crate) const BANK_SUBJECT_INPUT: &str = "bank-subject";
+pub(crate) const BANK_DESCRIPTION_INPUT: &str = "bank-description";
+pub(crate) const CATEGORY_NAME_INPUT: &str = "category-name";
+pub(crate) const QUESTION_SEARCH_INPUT: &str = "question-search";
+pub(crate) const LETTER_SCALE_INPUT: &str = "letter-scale";
+pub(crate) const LEARNING_ANSWER_INPUT: &str = "learning-answer";
+pub(crate) const QUESTION_JUMP_INPUT: &str = "question-jump";
+
+/// The focus id of the status bar, the last stop of the Tab order.
+pub const STATUS_BAR_FOCUS: &str = "status-bar";
+
+/// The focus id of the close button of the top toast.
+pub const TOAST_FOCUS: &str = "toast-close";
+
+/// One place the keyboard focus stops at outside of modals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusStop
+{
+    /// A main menu button, named by its key in `MENU_KEYS`. Buttons draw
+    /// their own focus ring.
+    Menu(&'static str),
+
+    /// A text field of the current page, focused through its widget id.
+    Input(&'static str),
+
+    /// The close button of the top toast, while one is shown. Buttons draw
+    /// their own focus ring.
+    Toast,
+
+    /// The status bar at the bottom of the window.
+    StatusBar,
+}
+
+impl FocusStop
+{
+    // pub fn get_id(&self) -> String
+    /// Returns the focus id of the stop: `menu-` and the key for menu
+    /// buttons, the widget id for text fields, `TOAST_FOCUS` for the close
+    /// button of the top toast and `STATUS_BAR_FOCUS` for the status bar.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::FocusStop;
+    /// assert_eq!(FocusStop::Menu("settings").get_id(), "menu-settings");
+    /// assert_eq!(FocusStop::Input("exam-title").get_id(), "exam-title");
+    /// assert_eq!(FocusStop::Toast.get_id(), "toast-close");
+    /// assert_eq!(FocusStop::StatusBar.get_id(), "status-bar");
+    /// ```
+    pub fn get_id(&self) -> String
+    {
+        match self
+        {
+            FocusStop::Menu(key) => format!("menu-{}", key),
+            FocusStop::Input(id) => id.to_string(),
+            FocusStop::Toast => TOAST_FOCUS.to_string(),
+            FocusStop::StatusBar => STATUS_BAR_FOCUS.to_string(),
+        }
+    }
+}
+
+// pub fn get_content_focus_ids(page: Page) -> &'static [&'static str]
+/// Returns the widget ids of the text fields of `page`, from top to bottom
+/// as they are laid out.
+///
+/// # Arguments
+/// * `page` - The page shown in the content area.
+///
+/// # Output
+/// The ids, or none for pages without fixed text fields.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ get_content_focus_ids, Page };
+/// assert_eq!(get_content_focus_ids(Page::GradingCriteria), ["letter-scale"]);
+/// assert!(get_content_focus_ids(Page::Main).is_empty());
+/// ```
+pub fn get_content_focus_ids(page: Page) -> &'static [&'static str]
+{
+    match page
+    {
+        Page::Criteria => &[EXAM_TITLE_INPUT, EXAM_COURSE_INPUT, EXAM_DATE_INPUT, EXAM_INSTRUCTIONS_INPUT, TAG_FILTER_INPUT, PINNED_QUESTIONS_INPUT, SECTIONS_INPUT, QUESTION_COUNT_INPUT, PAGE_HEADER_INPUT, PAGE_FOOTER_INPUT, PAGE_LOGO_INPUT, SEED_INPUT, VARIANT_COUNT_INPUT],
+        Page::IdleLockSettings => &[IDLE_LOCK_PIN_INPUT],
+        Page::BankMetadata => &[BANK_TITLE_INPUT, BANK_AUTHOR_INPUT, BANK_SUBJECT_INPUT, BANK_DESCRIPTION_INPUT, CATEGORY_NAME_INPUT],
+        Page::QuestionBankEdit => &[QUESTION_SEARCH_INPUT, QUESTION_JUMP_INPUT],
+        Page::GradingCriteria => &[LETTER_SCALE_INPUT],
+        Page::Learning => &[LEARNING_ANSWER_INPUT, QUESTION_JUMP_INPUT],
+        _ => &[],
+    }
+}
+
+// pub fn get_tab_order(page: Page) -> Vec<FocusStop>
+/// Returns where Tab moves the keyboard focus while `page` is shown: the
+/// menu bar from left to right, then the text fields of the page, then the
+/// status bar. Shift+Tab walks the same stops backwards.
+///
+/// # Arguments
+/// * `page` - The page shown in the content area.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::{ get_tab_order, FocusStop, Page, MENU_KEYS };
+///
+/// let ids: Vec<String> = get_tab_order(Page::BankMetadata).iter().map(|stop| stop.get_id()).collect();
+/// assert_eq!(ids, [
+///     "menu-question-bank-management", "menu-generate-exam-paper", "menu-student-list-management",
+///     "menu-self-study", "menu-settings", "menu-information",
+///     "bank-title", "bank-author", "bank-subject", "bank-description", "category-name",
+///     "status-bar",
+/// ]);
+///
+/// // Every page starts with the menu bar and ends with the status bar.
+/// let order = get_tab_order(Page::Main);
+/// assert_eq!(order.len(), MENU_KEYS.len() + 1);
+/// assert_eq!(order[0], FocusStop::Menu("question-bank-management"));
+/// assert_eq!(order.last(), Some(&FocusStop::StatusBar));
+/// ```
+pub fn get_tab_order(page: Page) -> Vec<FocusStop>
+{
+    MENU_KEYS.into_iter().map(FocusStop::Menu)
+        .chain(get_content_focus_ids(page).iter().copied().map(FocusStop::Input))
+        .chain([FocusStop::StatusBar])
+        .collect()
+}
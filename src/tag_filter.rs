@@ -0,0 +1,215 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use qrate::Question;
+
+use crate::QuestionMetas;
+
+/// How the tags of a `TagFilter` are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagMode
+{
+    /// A question must carry every tag (AND).
+    #[default]
+    All,
+
+    /// A question must carry at least one of the tags (OR).
+    Any,
+}
+
+impl TagMode
+{
+    /// All modes, in the order they are shown.
+    pub const ALL: [TagMode; 2] = [ TagMode::All, TagMode::Any ];
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key of the mode.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::TagMode;
+    /// assert_eq!(TagMode::Any.get_key(), "tag-mode-any");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            TagMode::All => "tag-mode-all",
+            TagMode::Any => "tag-mode-any",
+        }
+    }
+}
+
+/// The tags a question must carry to be drawn into an exam.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TagFilter
+{
+    tags: Vec<String>,
+    mode: TagMode,
+}
+
+impl TagFilter
+{
+    // pub fn new(tags: Vec<String>, mode: TagMode) -> Self
+    /// Creates a filter. Blank tags are dropped and the others are trimmed.
+    ///
+    /// # Arguments
+    /// * `tags` - The tags to look for.
+    /// * `mode` - How the tags are combined.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ TagFilter, TagMode };
+    /// let filter = TagFilter::new(vec![" algebra ".to_string(), " ".to_string()], TagMode::Any);
+    /// assert_eq!(filter.get_tags(), ["algebra".to_string()]);
+    /// assert_eq!(filter.get_mode(), TagMode::Any);
+    /// ```
+    pub fn new(tags: Vec<String>, mode: TagMode) -> Self
+    {
+        let tags = tags.iter()
+                    .map(|tag| tag.trim())
+                    .filter(|tag| !tag.is_empty())
+                    .map(|tag| tag.to_string())
+                    .collect();
+        Self { tags, mode }
+    }
+
+    // pub fn parse(text: &str, mode: TagMode) -> Self
+    /// Creates a filter from comma-separated text, as typed on the criteria page.
+    ///
+    /// # Arguments
+    /// * `text` - The tags, separated by commas.
+    /// * `mode` - How the tags are combined.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ TagFilter, TagMode };
+    /// let filter = TagFilter::parse("algebra, proofs,,", TagMode::All);
+    /// assert_eq!(filter.get_tags(), ["algebra".to_string(), "proofs".to_string()]);
+    /// assert!(TagFilter::parse(" , ", TagMode::All).is_empty());
+    /// ```
+    pub fn parse(text: &str, mode: TagMode) -> Self
+    {
+        Self::new(text.split(',').map(|tag| tag.to_string()).collect(), mode)
+    }
+
+    // pub fn get_tags(&self) -> &[String]
+    /// Returns the tags to look for.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::TagFilter;
+    /// assert!(TagFilter::default().get_tags().is_empty());
+    /// ```
+    pub fn get_tags(&self) -> &[String]
+    {
+        &self.tags
+    }
+
+    // pub fn get_mode(&self) -> TagMode
+    /// Returns how the tags are combined.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ TagFilter, TagMode };
+    /// assert_eq!(TagFilter::default().get_mode(), TagMode::All);
+    /// ```
+    pub fn get_mode(&self) -> TagMode
+    {
+        self.mode
+    }
+
+    // pub fn set_mode(&mut self, mode: TagMode)
+    /// Sets how the tags are combined.
+    ///
+    /// # Arguments
+    /// * `mode` - The new mode.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ TagFilter, TagMode };
+    /// let mut filter = TagFilter::default();
+    /// filter.set_mode(TagMode::Any);
+    /// assert_eq!(filter.get_mode(), TagMode::Any);
+    /// ```
+    pub fn set_mode(&mut self, mode: TagMode)
+    {
+        self.mode = mode;
+    }
+
+    // pub fn is_empty(&self) -> bool
+    /// Returns whether the filter has no tags and so lets every question through.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::TagFilter;
+    /// assert!(TagFilter::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool
+    {
+        self.tags.is_empty()
+    }
+}
+
+// pub fn matches(question: &Question, metas: &QuestionMetas, tag_filter: &TagFilter) -> bool
+/// Checks whether `question` passes `tag_filter`.
+///
+/// The tags of a question are kept in its `QuestionMeta`, so `metas` is
+/// consulted; a question without metadata has no tags. Tags are compared
+/// without regard to case. An empty filter matches every question.
+///
+/// # Arguments
+/// * `question` - The question to check.
+/// * `metas` - The question metadata holding the tags.
+/// * `tag_filter` - The tags to look for and how to combine them.
+///
+/// # Output
+/// `true` if the question carries all (`TagMode::All`) or any
+/// (`TagMode::Any`) of the filter's tags.
+///
+/// # Examples
+/// ```
+/// use qrate::Question;
+/// use qrate_gui::{ matches, QuestionMeta, QuestionMetas, TagFilter, TagMode };
+///
+/// let mut metas = QuestionMetas::new();
+/// for (id, tags) in [(1, vec!["algebra", "proofs"]), (2, vec!["Algebra"]), (3, vec!["geometry"])]
+/// {
+///     let mut meta = QuestionMeta::new();
+///     meta.set_tags(tags.iter().map(|tag| tag.to_string()).collect());
+///     metas.insert(id, meta);
+/// }
+/// let questions: Vec<Question> = (1..=4).map(|id| Question::new(id, id, 1, format!("Q{}", id), vec![])).collect();
+/// let passing = |filter: &TagFilter| -> Vec<u16> {
+///     questions.iter().filter(|q| matches(q, &metas, filter)).map(|q| q.get_id()).collect()
+/// };
+///
+/// // AND: both tags are required.
+/// assert_eq!(passing(&TagFilter::parse("algebra, proofs", TagMode::All)), vec![1]);
+///
+/// // OR: either tag is enough, regardless of case.
+/// assert_eq!(passing(&TagFilter::parse("ALGEBRA, geometry", TagMode::Any)), vec![1, 2, 3]);
+///
+/// // An empty filter matches everything, even questions without tags.
+/// assert_eq!(passing(&TagFilter::default()), vec![1, 2, 3, 4]);
+/// assert_eq!(passing(&TagFilter::new(vec![], TagMode::Any)), vec![1, 2, 3, 4]);
+/// ```
+pub fn matches(question: &Question, metas: &QuestionMetas, tag_filter: &TagFilter) -> bool
+{
+    if tag_filter.is_empty()
+        { return true; }
+    let tags = metas.get(&question.get_id()).map(|meta| meta.get_tags()).unwrap_or_default();
+    let has_tag = |wanted: &String| tags.iter().any(|tag| tag.to_lowercase() == wanted.to_lowercase());
+    match tag_filter.mode
+    {
+        TagMode::All => tag_filter.tags.iter().all(has_tag),
+        TagMode::Any => tag_filter.tags.iter().any(has_tag),
+    }
+}
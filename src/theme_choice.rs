@@ -0,0 +1,83 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use iced::Theme;
+use iced::theme::Mode;
+use serde::{ Serialize, Deserialize };
+
+/// How often the appearance of the operating system is asked for again,
+/// in case it changes without the window being told.
+pub const THEME_POLL_SECONDS: u64 = 60;
+
+/// The theme picked by the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ThemeChoice
+{
+    /// Follows the light or dark appearance of the operating system.
+    #[default]
+    System,
+
+    /// Always light.
+    Light,
+
+    /// Always dark.
+    Dark,
+}
+
+impl ThemeChoice
+{
+    /// All choices, in the order they are offered to the user.
+    pub const ALL: [ThemeChoice; 3] = [ ThemeChoice::System, ThemeChoice::Light, ThemeChoice::Dark ];
+
+    // pub fn get_key(&self) -> &'static str
+    /// Returns the localization key of the choice's display name.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::ThemeChoice;
+    /// assert_eq!(ThemeChoice::System.get_key(), "theme-system");
+    /// ```
+    pub fn get_key(&self) -> &'static str
+    {
+        match self
+        {
+            ThemeChoice::System => "theme-system",
+            ThemeChoice::Light => "theme-light",
+            ThemeChoice::Dark => "theme-dark",
+        }
+    }
+
+    // pub fn resolve(&self, system: Mode) -> Theme
+    /// Returns the theme to draw with.
+    ///
+    /// # Arguments
+    /// * `system` - The appearance of the operating system; `Mode::None`,
+    ///   when it is unknown, counts as light.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::Theme;
+    /// use iced::theme::Mode;
+    /// use qrate_gui::ThemeChoice;
+    /// assert_eq!(ThemeChoice::System.resolve(Mode::Dark), Theme::Dark);
+    /// assert_eq!(ThemeChoice::System.resolve(Mode::Light), Theme::Light);
+    /// assert_eq!(ThemeChoice::System.resolve(Mode::None), Theme::Light);
+    /// assert_eq!(ThemeChoice::Light.resolve(Mode::Dark), Theme::Light);
+    /// assert_eq!(ThemeChoice::Dark.resolve(Mode::Light), Theme::Dark);
+    /// ```
+    pub fn resolve(&self, system: Mode) -> Theme
+    {
+        match (self, system)
+        {
+            (ThemeChoice::System, Mode::Dark) | (ThemeChoice::Dark, _) => Theme::Dark,
+            _ => Theme::Light,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use iced::time::{ Duration, Instant };
+
+/// How long a toast stays on screen unless it is dismissed, in seconds.
+pub const TOAST_SECONDS: u64 = 6;
+
+/// Short notices shown above the status bar, such as a finished export,
+/// which go away by themselves after `TOAST_SECONDS`. The newest toast is
+/// the top one.
+#[derive(Debug, Clone, Default)]
+pub struct Toasts
+{
+    // Oldest first, with the time each toast was shown.
+    toasts: Vec<(String, Instant)>,
+}
+
+impl Toasts
+{
+    // pub fn new() -> Self
+    /// Creates an empty stack of toasts.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::Toasts;
+    /// assert!(Toasts::new().is_empty());
+    /// ```
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // pub fn is_empty(&self) -> bool
+    /// Returns whether no toast is shown.
+    ///
+    /// # Examples
+    /// See `Toasts::dismiss`.
+    pub fn is_empty(&self) -> bool
+    {
+        self.toasts.is_empty()
+    }
+
+    // pub fn get_messages(&self) -> Vec<&str>
+    /// Returns the messages of the toasts, the top one first.
+    ///
+    /// # Examples
+    /// See `Toasts::dismiss`.
+    pub fn get_messages(&self) -> Vec<&str>
+    {
+        self.toasts.iter().rev().map(|(message, _)| message.as_str()).collect()
+    }
+
+    // pub fn push(&mut self, message: String, now: Instant)
+    /// Shows `message` as the new top toast.
+    ///
+    /// # Examples
+    /// See `Toasts::dismiss`.
+    pub fn push(&mut self, message: String, now: Instant)
+    {
+        self.toasts.push((message, now));
+    }
+
+    // pub fn dismiss(&mut self, index: usize) -> bool
+    /// Removes a toast.
+    ///
+    /// # Arguments
+    /// * `index` - The position of the toast in `get_messages`, `0` being
+    ///   the top one.
+    ///
+    /// # Output
+    /// `true` if there was such a toast.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::Instant;
+    /// use qrate_gui::Toasts;
+    ///
+    /// let now = Instant::now();
+    /// let mut toasts = Toasts::new();
+    /// toasts.push("first".to_string(), now);
+    /// toasts.push("second".to_string(), now);
+    /// toasts.push("third".to_string(), now);
+    /// assert_eq!(toasts.get_messages(), ["third", "second", "first"]);
+    ///
+    /// assert!(toasts.dismiss(1));
+    /// assert_eq!(toasts.get_messages(), ["third", "first"]);
+    /// assert!(!toasts.dismiss(2));
+    /// assert!(toasts.dismiss(0));
+    /// assert!(toasts.dismiss(0));
+    /// assert!(toasts.is_empty());
+    /// assert!(!toasts.dismiss(0));
+    /// ```
+    pub fn dismiss(&mut self, index: usize) -> bool
+    {
+        if index >= self.toasts.len()
+            { return false; }
+        self.toasts.remove(self.toasts.len() - 1 - index);
+        true
+    }
+
+    // pub fn expire(&mut self, now: Instant)
+    /// Removes the toasts that have been shown for `TOAST_SECONDS` by `now`.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::time::{ Duration, Instant };
+    /// use qrate_gui::{ Toasts, TOAST_SECONDS };
+    ///
+    /// let start = Instant::now();
+    /// let mut toasts = Toasts::new();
+    /// toasts.push("old".to_string(), start);
+    /// toasts.push("new".to_string(), start + Duration::from_secs(2));
+    /// toasts.expire(start + Duration::from_secs(TOAST_SECONDS - 1));
+    /// assert_eq!(toasts.get_messages(), ["new", "old"]);
+    /// toasts.expire(start + Duration::from_secs(TOAST_SECONDS));
+    /// assert_eq!(toasts.get_messages(), ["new"]);
+    /// ```
+    pub fn expire(&mut self, now: Instant)
+    {
+        self.toasts.retain(|(_, shown_at)| now.saturating_duration_since(*shown_at) < Duration::from_secs(TOAST_SECONDS));
+    }
+}
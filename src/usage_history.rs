@@ -0,0 +1,202 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::{ Path, PathBuf };
+use std::time::{ SystemTime, UNIX_EPOCH };
+
+use serde::{ Serialize, Deserialize };
+
+/// The most exams back that recently used questions can be avoided for.
+pub const MAX_AVOID_RECENT: usize = 20;
+
+/// The questions that appeared on one exported exam, with the time of the
+/// export. The papers of one batch of variants count as one exam.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageRecord
+{
+    /// The seconds since the Unix epoch at which the exam was exported.
+    pub exported_at: u64,
+
+    /// The ids of the questions on the exam.
+    pub ids: BTreeSet<u16>,
+}
+
+/// Which questions of a bank have appeared on the exams exported from it
+/// over a term, oldest exam first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UsageHistory
+{
+    records: Vec<UsageRecord>,
+}
+
+impl UsageHistory
+{
+    // pub fn new() -> Self
+    /// Creates a history in which no question has been used.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::UsageHistory;
+    /// assert!(UsageHistory::new().get_records().is_empty());
+    /// ```
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // pub fn get_records(&self) -> &[UsageRecord]
+    /// Returns the exported exams, oldest first.
+    ///
+    /// # Examples
+    /// See `UsageHistory::recent_questions`.
+    pub fn get_records(&self) -> &[UsageRecord]
+    {
+        &self.records
+    }
+
+    // pub fn record(&mut self, ids: impl IntoIterator<Item = u16>, exported_at: SystemTime)
+    /// Records the questions of an exported exam. An exam without questions
+    /// is not recorded.
+    ///
+    /// # Arguments
+    /// * `ids` - The ids of the questions on the exam.
+    /// * `exported_at` - When the exam was exported.
+    ///
+    /// # Examples
+    /// See `UsageHistory::recent_questions`.
+    pub fn record(&mut self, ids: impl IntoIterator<Item = u16>, exported_at: SystemTime)
+    {
+        let ids: BTreeSet<u16> = ids.into_iter().collect();
+        if ids.is_empty()
+            { return; }
+        let exported_at = exported_at.duration_since(UNIX_EPOCH).map(|since| since.as_secs()).unwrap_or(0);
+        self.records.push(UsageRecord { exported_at, ids });
+    }
+
+    // pub fn count_uses(&self, id: u16) -> usize
+    /// Returns on how many recorded exams the question `id` appeared.
+    ///
+    /// # Examples
+    /// See `UsageHistory::recent_questions`.
+    pub fn count_uses(&self, id: u16) -> usize
+    {
+        self.records.iter().filter(|record| record.ids.contains(&id)).count()
+    }
+
+    // pub fn recent_questions(&self, exams: usize) -> BTreeSet<u16>
+    /// Returns the questions that appeared on any of the last `exams`
+    /// exported exams.
+    ///
+    /// # Arguments
+    /// * `exams` - How many exams to look back; `0` returns none.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::{ Duration, UNIX_EPOCH };
+    /// use qrate_gui::UsageHistory;
+    ///
+    /// let week = |n: u64| UNIX_EPOCH + Duration::from_secs(n * 7 * 86_400);
+    /// let mut history = UsageHistory::new();
+    /// history.record([1, 2, 3], week(1));
+    /// history.record([3, 4], week(2));
+    /// history.record([5, 6], week(3));
+    /// history.record([], week(4));
+    /// assert_eq!(history.get_records().len(), 3);
+    /// assert_eq!(history.get_records()[2].exported_at, 3 * 7 * 86_400);
+    ///
+    /// assert_eq!(history.recent_questions(1).into_iter().collect::<Vec<_>>(), [5, 6]);
+    /// assert_eq!(history.recent_questions(2).into_iter().collect::<Vec<_>>(), [3, 4, 5, 6]);
+    /// assert_eq!(history.recent_questions(10).len(), 6);
+    /// assert!(history.recent_questions(0).is_empty());
+    /// assert_eq!(history.count_uses(3), 2);
+    /// assert_eq!(history.count_uses(7), 0);
+    /// ```
+    pub fn recent_questions(&self, exams: usize) -> BTreeSet<u16>
+    {
+        self.records.iter()
+            .rev()
+            .take(exams)
+            .flat_map(|record| record.ids.iter().copied())
+            .collect()
+    }
+}
+
+// pub fn get_usage_history_path(bank_path: &Path) -> PathBuf
+/// Returns the path of the file keeping which questions of a question bank
+/// appeared on exported exams.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+///
+/// # Examples
+/// ```
+/// use std::path::{ Path, PathBuf };
+/// use qrate_gui::get_usage_history_path;
+/// assert_eq!(get_usage_history_path(Path::new("/banks/math.qbdb")), PathBuf::from("/banks/math.qbdb.usage.json"));
+/// ```
+pub fn get_usage_history_path(bank_path: &Path) -> PathBuf
+{
+    let mut name = bank_path.as_os_str().to_owned();
+    name.push(".usage.json");
+    PathBuf::from(name)
+}
+
+// pub fn load_usage_history(bank_path: &Path) -> UsageHistory
+/// Loads the usage history of a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+///
+/// # Output
+/// The stored history, or an empty one if the file is missing or cannot
+/// be parsed.
+///
+/// # Examples
+/// See `save_usage_history`.
+pub fn load_usage_history(bank_path: &Path) -> UsageHistory
+{
+    fs::read_to_string(get_usage_history_path(bank_path))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+// pub fn save_usage_history(bank_path: &Path, history: &UsageHistory) -> io::Result<()>
+/// Saves the usage history of a question bank.
+///
+/// # Arguments
+/// * `bank_path` - The path of the question bank file.
+/// * `history` - The history to store.
+///
+/// # Output
+/// An `io::Result` reporting write failures.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use std::time::SystemTime;
+/// use qrate_gui::{ load_usage_history, save_usage_history, UsageHistory };
+///
+/// let mut history = UsageHistory::new();
+/// history.record([2, 7], SystemTime::now());
+/// let bank_path = std::env::temp_dir().join("qrate_gui_save_usage_history.qbdb");
+/// save_usage_history(&bank_path, &history).unwrap();
+/// assert_eq!(load_usage_history(&bank_path), history);
+/// assert_eq!(load_usage_history(Path::new("/definitely/not/here.qbdb")), UsageHistory::new());
+/// ```
+pub fn save_usage_history(bank_path: &Path, history: &UsageHistory) -> io::Result<()>
+{
+    let json = serde_json::to_string_pretty(history).map_err(io::Error::other)?;
+    fs::write(get_usage_history_path(bank_path), json)
+}
@@ -0,0 +1,660 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::HashSet;
+use std::path::{ Path, PathBuf };
+
+use qrate::{ QBank, Student };
+
+use crate::{ Exam, ExamCriteria, ExportError, ExportFormat, PaperCode, QuestionMetas, SeededRng, export_exam, verify_export, catch_panic };
+
+/// One of several versions of the same exam, generated with its own seed.
+#[derive(Debug, Clone)]
+pub struct ExamVariant
+{
+    name: String,
+    seed: u64,
+    exam: Exam,
+}
+
+impl ExamVariant
+{
+    // pub fn get_name(&self) -> &str
+    /// Returns the name of the variant, such as `"A"`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ generate_variants, ExamCriteria };
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(1);
+    /// let variants = generate_variants(&qbank, &criteria, 2).unwrap();
+    /// assert_eq!(variants[1].get_name(), "B");
+    /// ```
+    pub fn get_name(&self) -> &str
+    {
+        &self.name
+    }
+
+    // pub fn get_seed(&self) -> u64
+    /// Returns the seed the variant was generated with.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ generate_variants, ExamCriteria };
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(1);
+    /// let variants = generate_variants(&qbank, &criteria, 2).unwrap();
+    /// assert_ne!(variants[0].get_seed(), variants[1].get_seed());
+    /// ```
+    pub fn get_seed(&self) -> u64
+    {
+        self.seed
+    }
+
+    // pub fn get_exam(&self) -> &Exam
+    /// Returns the generated exam of the variant.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ generate_variants, ExamCriteria };
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(1);
+    /// let variants = generate_variants(&qbank, &criteria, 1).unwrap();
+    /// assert_eq!(variants[0].get_exam().get_questions().len(), 1);
+    /// ```
+    pub fn get_exam(&self) -> &Exam
+    {
+        &self.exam
+    }
+
+    // pub fn get_exam_mut(&mut self) -> &mut Exam
+    /// Returns the generated exam of the variant for modification,
+    /// e.g. to attach question metadata.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ generate_variants, ExamCriteria, NumberingStyle };
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(1);
+    /// let mut variants = generate_variants(&qbank, &criteria, 1).unwrap();
+    /// variants[0].get_exam_mut().set_numbering_style(NumberingStyle::Roman);
+    /// assert_eq!(variants[0].get_exam().get_numbering_style(), NumberingStyle::Roman);
+    /// ```
+    pub fn get_exam_mut(&mut self) -> &mut Exam
+    {
+        &mut self.exam
+    }
+
+    // pub fn get_file_path(&self, dir: &Path, format: ExportFormat) -> PathBuf
+    /// Returns the path the variant is exported to inside `dir`.
+    ///
+    /// # Arguments
+    /// * `dir` - The output folder.
+    /// * `format` - The output format.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::{ Path, PathBuf };
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ generate_variants, ExamCriteria, ExportFormat };
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(1);
+    /// let variants = generate_variants(&qbank, &criteria, 3).unwrap();
+    /// assert_eq!(variants[2].get_file_path(Path::new("/out"), ExportFormat::Pdf), PathBuf::from("/out/exam-C.pdf"));
+    /// ```
+    pub fn get_file_path(&self, dir: &Path, format: ExportFormat) -> PathBuf
+    {
+        dir.join(format!("exam-{}.{}", self.name, format.get_extension()))
+    }
+}
+
+/// The outcome of exporting one variant.
+#[derive(Debug, Clone)]
+pub struct VariantReport
+{
+    /// The name of the variant.
+    pub name: String,
+
+    /// The path the variant was written to, or was meant to be written to.
+    pub path: PathBuf,
+
+    /// The seed the variant was generated with.
+    pub seed: u64,
+
+    /// The number of questions on the paper.
+    pub question_count: usize,
+
+    /// The ids of the questions on the paper.
+    pub question_ids: Vec<u16>,
+
+    /// `Ok(())` if the variant was written, or why it was not.
+    pub result: Result<(), ExportError>,
+}
+
+// pub fn get_variant_name(index: usize) -> String
+/// Returns the name of the variant at the 0-based `index`: `A`, `B`, ...,
+/// `Z`, `AA`, `AB`, ...
+///
+/// # Arguments
+/// * `index` - The 0-based variant index.
+///
+/// # Examples
+/// ```
+/// use qrate_gui::get_variant_name;
+/// assert_eq!(get_variant_name(0), "A");
+/// assert_eq!(get_variant_name(3), "D");
+/// assert_eq!(get_variant_name(26), "AA");
+/// ```
+pub fn get_variant_name(index: usize) -> String
+{
+    let mut name = Vec::new();
+    let mut n = index + 1;
+    while n > 0
+    {
+        n -= 1;
+        name.push(b'A' + (n % 26) as u8);
+        n /= 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+// pub fn generate_variants(qbank: &QBank, criteria: &ExamCriteria, count: usize) -> Option<Vec<ExamVariant>>
+/// Generates `count` variants of an exam, each with its own seed.
+///
+/// The seeds are derived from the seed of `criteria`, or from the clock when
+/// it has none, so a fixed seed reproduces the same set of seeds.
+///
+/// # Arguments
+/// * `qbank` - The question bank to draw from.
+/// * `criteria` - The extraction criteria shared by all variants.
+/// * `count` - The number of variants.
+///
+/// # Output
+/// The variants named `A`, `B`, ..., or `None` if the criteria cannot be met.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use std::path::Path;
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::{ generate_variants, ExamCriteria, ExportFormat };
+///
+/// let mut qbank = QBank::new_with_default();
+/// for id in 1..=6
+///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![("a".to_string(), true), ("b".to_string(), false)])); }
+/// let mut criteria = ExamCriteria::new();
+/// criteria.set_selected(4);
+/// criteria.set_shuffle_choices(true);
+///
+/// let variants = generate_variants(&qbank, &criteria, 4).unwrap();
+/// assert_eq!(variants.len(), 4);
+/// let seeds: HashSet<u64> = variants.iter().map(|v| v.get_seed()).collect();
+/// assert_eq!(seeds.len(), 4);
+/// let paths: HashSet<_> = variants.iter().map(|v| v.get_file_path(Path::new("out"), ExportFormat::Excel)).collect();
+/// assert_eq!(paths.len(), 4);
+/// ```
+pub fn generate_variants(qbank: &QBank, criteria: &ExamCriteria, count: usize) -> Option<Vec<ExamVariant>>
+{
+    generate_variants_with_metas(qbank, criteria, &QuestionMetas::new(), count)
+}
+
+// pub fn generate_variants_with_metas(qbank: &QBank, criteria: &ExamCriteria, metas: &QuestionMetas, count: usize) -> Option<Vec<ExamVariant>>
+/// Generates `count` variants of an exam like `generate_variants`, drawing
+/// only the questions whose tags in `metas` pass the tag filter of `criteria`.
+/// Each variant is drawn with `Exam::generate_balanced`, so that it meets
+/// the difficulty target of `criteria` as closely as its attempts allow.
+///
+/// # Arguments
+/// * `qbank` - The question bank to draw from.
+/// * `criteria` - The extraction criteria shared by all variants.
+/// * `metas` - The question metadata holding the tags.
+/// * `count` - The number of variants.
+///
+/// # Output
+/// The variants named `A`, `B`, ..., or `None` if the criteria cannot be met.
+///
+/// # Examples
+/// ```
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::{ generate_variants_with_metas, ExamCriteria, QuestionMeta, QuestionMetas, TagFilter, TagMode };
+///
+/// let mut qbank = QBank::new_with_default();
+/// let mut metas = QuestionMetas::new();
+/// for id in 1..=6
+/// {
+///     qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![]));
+///     let mut meta = QuestionMeta::new();
+///     meta.set_tags(vec![if id <= 3 { "week1" } else { "week2" }.to_string()]);
+///     metas.insert(id, meta);
+/// }
+/// let mut criteria = ExamCriteria::new();
+/// criteria.set_selected(2);
+/// criteria.set_tag_filter(TagFilter::parse("week2", TagMode::All));
+///
+/// let variants = generate_variants_with_metas(&qbank, &criteria, &metas, 3).unwrap();
+/// assert!(variants.iter().all(|v| v.get_exam().get_questions().iter().all(|q| q.get_id() > 3)));
+///
+/// // With a difficulty target, retries leave no variant worse than its first draw.
+/// use qrate_gui::{ difficulty_summary, Exam };
+/// let mut qbank = QBank::new_with_default();
+/// let mut metas = QuestionMetas::new();
+/// for id in 1..=30
+/// {
+///     qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![]));
+///     let mut meta = QuestionMeta::new();
+///     meta.set_difficulty(if id <= 20 { 1 } else { 5 });
+///     metas.insert(id, meta);
+/// }
+/// let mut criteria = ExamCriteria::new();
+/// criteria.set_selected(6);
+/// criteria.set_seed(Some(3));
+/// criteria.set_difficulty_target(Some([1, 0, 0, 0, 1]));
+/// let target = criteria.get_difficulty_target().unwrap();
+/// criteria.set_balance_attempts(1);
+/// let first = generate_variants_with_metas(&qbank, &criteria, &metas, 5).unwrap();
+/// criteria.set_balance_attempts(20);
+/// let balanced = generate_variants_with_metas(&qbank, &criteria, &metas, 5).unwrap();
+/// for (first, balanced) in first.iter().zip(&balanced)
+/// {
+///     let distance = |variant: &qrate_gui::ExamVariant| difficulty_summary(variant.get_exam()).distance_to(&target);
+///     assert!(distance(balanced) <= distance(first));
+///     // The seed of the variant still reproduces it.
+///     let mut reproduce = criteria.clone();
+///     reproduce.set_seed(Some(balanced.get_seed()));
+///     let exam = Exam::generate_with_metas(&qbank, &reproduce, &metas).unwrap();
+///     let ids = |exam: &Exam| exam.get_questions().iter().map(|q| q.get_id()).collect::<Vec<u16>>();
+///     assert_eq!(ids(&exam), ids(balanced.get_exam()));
+/// }
+/// ```
+pub fn generate_variants_with_metas(qbank: &QBank, criteria: &ExamCriteria, metas: &QuestionMetas, count: usize) -> Option<Vec<ExamVariant>>
+{
+    let mut seeds = criteria.get_seed().map(SeededRng::new).unwrap_or_else(SeededRng::from_time);
+    (0..count).map(|index| {
+            let seed = seeds.next_u64();
+            let mut variant_criteria = criteria.clone();
+            variant_criteria.set_seed(Some(seed));
+            let name = get_variant_name(index);
+            // A retry for balance moves on to the next seed, which the exam keeps.
+            let (mut exam, _) = Exam::generate_balanced(qbank, &variant_criteria, metas)?;
+            let seed = exam.get_seed().unwrap_or(seed);
+            if criteria.get_print_paper_code()
+                { exam.set_paper_code(Some(PaperCode::new(name.clone(), seed))); }
+            Some(ExamVariant { name, seed, exam })
+        })
+        .collect()
+}
+
+// pub fn get_student_paper_name(student: &Student) -> String
+/// Returns the name of the paper printed for `student`, made of the id and
+/// the name of the student so that it can be used in a file name.
+///
+/// # Arguments
+/// * `student` - The student.
+///
+/// # Examples
+/// ```
+/// use qrate::Student;
+/// use qrate_gui::get_student_paper_name;
+/// assert_eq!(get_student_paper_name(&Student::new("Alice".to_string(), "s1".to_string())), "s1-Alice");
+/// assert_eq!(get_student_paper_name(&Student::new("박영호".to_string(), String::new())), "박영호");
+/// assert_eq!(get_student_paper_name(&Student::new("A/B Lee".to_string(), "7".to_string())), "7-A_B_Lee");
+/// ```
+pub fn get_student_paper_name(student: &Student) -> String
+{
+    let name = [student.get_id(), student.get_name()].iter()
+                    .filter(|part| !part.is_empty())
+                    .map(|part| part.as_str())
+                    .collect::<Vec<&str>>()
+                    .join("-");
+    name.chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+// pub fn generate_student_papers(qbank: &QBank, criteria: &ExamCriteria, metas: &QuestionMetas, students: &[Student], named: bool) -> Option<Vec<ExamVariant>>
+/// Generates one paper per student, each with its own seed like the
+/// variants of `generate_variants_with_metas`, and with name and id fields
+/// in its header.
+///
+/// # Arguments
+/// * `qbank` - The question bank to draw from.
+/// * `criteria` - The extraction criteria shared by all papers.
+/// * `metas` - The question metadata holding the tags.
+/// * `students` - The students to print a paper for, in order.
+/// * `named` - `true` to pre-fill the name and id of each student and name
+///   the papers after them, or `false` for anonymous papers named `A`,
+///   `B`, ... with blank fields. A student with neither name nor id gets
+///   the 1-based number of the paper, and a name already taken gets `-2`,
+///   `-3`, ... appended, so that no paper overwrites another.
+///
+/// # Output
+/// The papers, or `None` if the criteria cannot be met.
+///
+/// # Examples
+/// ```
+/// use qrate::{ QBank, Question, Student };
+/// use qrate_gui::{ generate_student_papers, ExamCriteria, ExamLine, QuestionMetas, BLANK_FIELD };
+///
+/// let mut qbank = QBank::new_with_default();
+/// for id in 1..=4
+///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+/// let mut criteria = ExamCriteria::new();
+/// criteria.set_selected(2);
+/// let students = vec![
+///     Student::new("Alice".to_string(), "s1".to_string()),
+///     Student::new("Bob".to_string(), "s2".to_string()),
+/// ];
+///
+/// let papers = generate_student_papers(&qbank, &criteria, &QuestionMetas::new(), &students, true).unwrap();
+/// assert_eq!(papers.len(), 2);
+/// for (paper, student) in papers.iter().zip(&students)
+/// {
+///     let header = paper.get_exam().layout();
+///     assert!(header.contains(&ExamLine::Field { label: "Name".to_string(), value: student.get_name().clone() }));
+///     assert!(header.contains(&ExamLine::Field { label: "ID".to_string(), value: student.get_id().clone() }));
+/// }
+/// assert_eq!(papers[1].get_name(), "s2-Bob");
+///
+/// // Each exported paper carries its own student in the header.
+/// use calamine::{ Reader, open_workbook_auto };
+/// use qrate_gui::{ export_variants, ExportFormat };
+/// let dir = std::env::temp_dir().join("qrate_gui_student_papers");
+/// std::fs::create_dir_all(&dir).unwrap();
+/// for (report, student) in export_variants(&papers, &dir, ExportFormat::Excel).iter().zip(&students)
+/// {
+///     assert!(report.result.is_ok());
+///     let mut workbook = open_workbook_auto(&report.path).unwrap();
+///     let range = workbook.worksheet_range_at(0).unwrap().unwrap();
+///     assert_eq!(range.get_value((1, 0)).unwrap().to_string(), format!("Name: {}", student.get_name()));
+/// }
+///
+/// // Students of the same name still get a file each.
+/// let namesakes = vec![
+///     Student::new("Kim".to_string(), String::new()),
+///     Student::new("Kim".to_string(), String::new()),
+///     Student::new(String::new(), String::new()),
+/// ];
+/// let mut criteria_one = criteria.clone();
+/// criteria_one.set_selected(1);
+/// let papers = generate_student_papers(&qbank, &criteria_one, &QuestionMetas::new(), &namesakes, true).unwrap();
+/// let names: Vec<&str> = papers.iter().map(|paper| paper.get_name()).collect();
+/// assert_eq!(names, ["Kim", "Kim-2", "3"]);
+/// let dir = std::env::temp_dir().join("qrate_gui_namesake_papers");
+/// let _ = std::fs::remove_dir_all(&dir);
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let reports = export_variants(&papers, &dir, ExportFormat::Excel);
+/// assert!(reports.iter().all(|report| report.result.is_ok()));
+/// assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 3);
+///
+/// // Anonymous papers leave the fields blank.
+/// let papers = generate_student_papers(&qbank, &criteria, &QuestionMetas::new(), &students, false).unwrap();
+/// assert_eq!(papers[1].get_name(), "B");
+/// let header = papers[1].get_exam().layout();
+/// assert!(header.contains(&ExamLine::Field { label: "Name".to_string(), value: BLANK_FIELD.to_string() }));
+/// assert!(!header.iter().any(|line| line.to_text().contains("Bob")));
+/// ```
+pub fn generate_student_papers(qbank: &QBank, criteria: &ExamCriteria, metas: &QuestionMetas, students: &[Student], named: bool) -> Option<Vec<ExamVariant>>
+{
+    let mut papers = generate_variants_with_metas(qbank, criteria, metas, students.len())?;
+    let mut taken = HashSet::new();
+    for (index, (paper, student)) in papers.iter_mut().zip(students).enumerate()
+    {
+        let mut info = paper.exam.get_info().clone();
+        if named
+        {
+            let mut name = get_student_paper_name(student);
+            if name.is_empty()
+                { name = (index + 1).to_string(); }
+            let base = name.clone();
+            let mut suffix = 2;
+            while !taken.insert(name.clone())
+            {
+                name = format!("{}-{}", base, suffix);
+                suffix += 1;
+            }
+            paper.name = name;
+            info.set_student(student.get_name().clone(), student.get_id().clone());
+            if criteria.get_print_paper_code()
+                { paper.exam.set_paper_code(Some(PaperCode::new(paper.name.clone(), paper.seed))); }
+        }
+        else
+            { info.set_student(String::new(), String::new()); }
+        paper.exam.set_info(info);
+    }
+    Some(papers)
+}
+
+// pub fn export_variants(variants: &[ExamVariant], dir: &Path, format: ExportFormat) -> Vec<VariantReport>
+/// Exports every variant into `dir`, continuing after failures.
+///
+/// Each written file is reopened with `verify_export`; a file that does not
+/// pass is reported as `ExportError::Unverified`, and a panic while writing
+/// as `ExportError::Panicked`.
+///
+/// # Arguments
+/// * `variants` - The variants to export.
+/// * `dir` - The output folder.
+/// * `format` - The output format.
+///
+/// # Output
+/// One `VariantReport` per variant, in order.
+///
+/// # Examples
+/// ```
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::{ export_variants, generate_variants, ExamCriteria, ExportFormat };
+///
+/// let mut qbank = QBank::new_with_default();
+/// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+/// let mut criteria = ExamCriteria::new();
+/// criteria.set_selected(1);
+/// let variants = generate_variants(&qbank, &criteria, 2).unwrap();
+///
+/// let dir = std::env::temp_dir();
+/// let reports = export_variants(&variants, &dir, ExportFormat::Excel);
+/// assert_eq!(reports.len(), 2);
+/// assert!(reports.iter().all(|report| report.result.is_ok() && report.path.exists()));
+/// ```
+pub fn export_variants(variants: &[ExamVariant], dir: &Path, format: ExportFormat) -> Vec<VariantReport>
+{
+    variants.iter()
+        .map(|variant| export_variant(variant, dir, format))
+        .collect()
+}
+
+// pub fn export_variant(variant: &ExamVariant, dir: &Path, format: ExportFormat) -> VariantReport
+/// Exports one variant into `dir` and verifies the written file, as
+/// `export_variants` does for each of its variants.
+///
+/// # Arguments
+/// * `variant` - The variant to export.
+/// * `dir` - The output folder.
+/// * `format` - The output format.
+///
+/// # Output
+/// The `VariantReport` of the variant.
+///
+/// # Examples
+/// ```
+/// use qrate::{ QBank, Question };
+/// use qrate_gui::{ export_variant, generate_variants, ExamCriteria, ExportFormat };
+///
+/// let mut qbank = QBank::new_with_default();
+/// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+/// let mut criteria = ExamCriteria::new();
+/// criteria.set_selected(1);
+/// let variants = generate_variants(&qbank, &criteria, 1).unwrap();
+///
+/// let report = export_variant(&variants[0], &std::env::temp_dir(), ExportFormat::Excel);
+/// assert_eq!(report.name, "A");
+/// assert_eq!(report.question_ids, [1]);
+/// assert!(report.result.is_ok());
+/// ```
+pub fn export_variant(variant: &ExamVariant, dir: &Path, format: ExportFormat) -> VariantReport
+{
+    let path = variant.get_file_path(dir, format);
+    let expected = variant.get_exam().get_questions().len();
+    let result = catch_panic(|| export_exam(variant.get_exam(), &path, format))
+                    .unwrap_or_else(|message| Err(ExportError::Panicked(message)))
+                    .and_then(|()| {
+                        if verify_export(&path, expected)
+                            { Ok(()) }
+                        else
+                            { Err(ExportError::Unverified(path.display().to_string())) }
+                    });
+    let question_ids = variant.get_exam().get_questions().iter().map(|question| question.get_id()).collect();
+    VariantReport { name: variant.name.clone(), path, seed: variant.seed, question_count: expected, question_ids, result }
+}
+
+/// A batch export that writes one variant per step, so that the caller can
+/// report progress and stay responsive between the steps.
+#[derive(Debug, Clone)]
+pub struct ExportBatch
+{
+    variants: Vec<ExamVariant>,
+    dir: PathBuf,
+    format: ExportFormat,
+    reports: Vec<VariantReport>,
+}
+
+impl ExportBatch
+{
+    // pub fn new(variants: Vec<ExamVariant>, dir: PathBuf, format: ExportFormat) -> Self
+    /// Prepares the export of `variants` into `dir`; nothing is written yet.
+    ///
+    /// # Arguments
+    /// * `variants` - The variants to export.
+    /// * `dir` - The output folder.
+    /// * `format` - The output format.
+    ///
+    /// # Examples
+    /// See `ExportBatch::export_next`.
+    pub fn new(variants: Vec<ExamVariant>, dir: PathBuf, format: ExportFormat) -> Self
+    {
+        Self { variants, dir, format, reports: Vec::new() }
+    }
+
+    // pub fn export_next(&mut self) -> Option<(usize, usize)>
+    /// Exports the next variant.
+    ///
+    /// # Output
+    /// The progress `(done, total)` after the step, or `None` if every
+    /// variant had already been exported.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ generate_variants, ExamCriteria, ExportBatch, ExportFormat };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// for id in 1..=3
+    ///     { qbank.push_question(Question::new(id, id, 1, format!("Q{}", id), vec![])); }
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(2);
+    /// let variants = generate_variants(&qbank, &criteria, 4).unwrap();
+    ///
+    /// let dir = std::env::temp_dir().join("qrate_gui_export_batch");
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// let mut batch = ExportBatch::new(variants, dir, ExportFormat::Excel);
+    /// assert_eq!(batch.get_progress(), (0, 4));
+    ///
+    /// let mut progress = Vec::new();
+    /// while let Some(step) = batch.export_next()
+    ///     { progress.push(step); }
+    /// assert_eq!(progress, [(1, 4), (2, 4), (3, 4), (4, 4)]);
+    /// assert!(batch.is_done());
+    /// assert!(batch.into_reports().iter().all(|report| report.result.is_ok()));
+    /// ```
+    pub fn export_next(&mut self) -> Option<(usize, usize)>
+    {
+        let variant = self.variants.get(self.reports.len())?;
+        self.reports.push(export_variant(variant, &self.dir, self.format));
+        Some(self.get_progress())
+    }
+
+    // pub fn get_existing_files(&self) -> Vec<PathBuf>
+    /// Returns the existing files the variants not exported yet would
+    /// overwrite.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ generate_variants, ExamCriteria, ExportBatch, ExportFormat };
+    ///
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+    /// let mut criteria = ExamCriteria::new();
+    /// criteria.set_selected(1);
+    /// let variants = generate_variants(&qbank, &criteria, 2).unwrap();
+    ///
+    /// let dir = std::env::temp_dir().join("qrate_gui_existing_files");
+    /// let _ = std::fs::remove_dir_all(&dir);
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// std::fs::write(dir.join("exam-B.xlsx"), "last week's paper").unwrap();
+    /// let mut batch = ExportBatch::new(variants, dir.clone(), ExportFormat::Excel);
+    /// assert_eq!(batch.get_existing_files(), [dir.join("exam-B.xlsx")]);
+    /// batch.export_next();
+    /// batch.export_next();
+    /// assert!(batch.get_existing_files().is_empty());
+    /// ```
+    pub fn get_existing_files(&self) -> Vec<PathBuf>
+    {
+        self.variants.iter()
+            .skip(self.reports.len())
+            .map(|variant| variant.get_file_path(&self.dir, self.format))
+            .filter(|path| path.exists())
+            .collect()
+    }
+
+    // pub fn get_progress(&self) -> (usize, usize)
+    /// Returns how many variants have been exported, out of how many.
+    ///
+    /// # Examples
+    /// See `ExportBatch::export_next`.
+    pub fn get_progress(&self) -> (usize, usize)
+    {
+        (self.reports.len(), self.variants.len())
+    }
+
+    // pub fn is_done(&self) -> bool
+    /// Returns whether every variant has been exported.
+    ///
+    /// # Examples
+    /// See `ExportBatch::export_next`.
+    pub fn is_done(&self) -> bool
+    {
+        self.reports.len() >= self.variants.len()
+    }
+
+    // pub fn into_reports(self) -> Vec<VariantReport>
+    /// Returns the reports of the exported variants, in order.
+    ///
+    /// # Examples
+    /// See `ExportBatch::export_next`.
+    pub fn into_reports(self) -> Vec<VariantReport>
+    {
+        self.reports
+    }
+}
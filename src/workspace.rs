@@ -0,0 +1,583 @@
+// Copyright 2026 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+///////////////////////////////////////////////////////////////////////////////
+
+
+use std::collections::BTreeMap;
+use std::path::{ Path, PathBuf };
+
+use iced::{ daemon, event, window, Element, Event, Subscription, Task, Theme };
+use iced::widget::text;
+
+use crate::{ ControlTower, Message };
+use crate::{ get_launch_file, get_settings_path, load_fonts, run_self_check };
+
+/// Keeps the state of every open window: each window has its own
+/// `ControlTower`, and the first window opened is the main window.
+#[derive(Debug, Clone, Default)]
+pub struct WindowRegistry
+{
+    main: Option<window::Id>,
+    towers: BTreeMap<window::Id, ControlTower>,
+}
+
+impl WindowRegistry
+{
+    // pub fn new() -> Self
+    /// Creates a registry without windows.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::WindowRegistry;
+    /// assert!(WindowRegistry::new().is_empty());
+    /// ```
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // pub fn insert(&mut self, id: window::Id, tower: ControlTower)
+    /// Registers the state of a newly opened window. The first window
+    /// registered becomes the main window.
+    ///
+    /// # Arguments
+    /// * `id` - The id of the window.
+    /// * `tower` - The state of the window.
+    ///
+    /// # Examples
+    /// See `WindowRegistry::remove`.
+    pub fn insert(&mut self, id: window::Id, tower: ControlTower)
+    {
+        if self.main.is_none()
+            { self.main = Some(id); }
+        self.towers.insert(id, tower);
+    }
+
+    // pub fn remove(&mut self, id: window::Id) -> Option<ControlTower>
+    /// Drops the state of a closed window.
+    ///
+    /// # Arguments
+    /// * `id` - The id of the closed window.
+    ///
+    /// # Output
+    /// The state of the window, or `None` if it was not registered.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::window;
+    /// use qrate::{ QBank, Question };
+    /// use qrate_gui::{ ControlTower, WindowRegistry };
+    ///
+    /// let mut registry = WindowRegistry::new();
+    /// let main = window::Id::unique();
+    /// let secondary = window::Id::unique();
+    /// registry.insert(main, ControlTower::new().0);
+    /// registry.insert(secondary, ControlTower::new().0);
+    /// assert_eq!(registry.len(), 2);
+    /// assert!(registry.is_main(main));
+    /// assert!(!registry.is_main(secondary));
+    ///
+    /// // Each window edits its own bank.
+    /// let mut qbank = QBank::new_with_default();
+    /// qbank.push_question(Question::new(1, 1, 1, "Q1".to_string(), vec![]));
+    /// registry.get_mut(secondary).unwrap().set_qbank(qbank);
+    /// assert!(registry.get(main).unwrap().get_qbank().get_questions().is_empty());
+    ///
+    /// let dropped = registry.remove(secondary).unwrap();
+    /// assert_eq!(dropped.get_qbank().get_questions().len(), 1);
+    /// assert!(registry.get(secondary).is_none());
+    /// assert!(registry.remove(secondary).is_none());
+    /// assert_eq!(registry.len(), 1);
+    /// assert!(registry.is_main(main));
+    /// ```
+    pub fn remove(&mut self, id: window::Id) -> Option<ControlTower>
+    {
+        if self.main == Some(id)
+            { self.main = None; }
+        self.towers.remove(&id)
+    }
+
+    // pub fn get(&self, id: window::Id) -> Option<&ControlTower>
+    /// Returns the state of the window `id`.
+    ///
+    /// # Examples
+    /// See `WindowRegistry::remove`.
+    pub fn get(&self, id: window::Id) -> Option<&ControlTower>
+    {
+        self.towers.get(&id)
+    }
+
+    // pub fn get_mut(&mut self, id: window::Id) -> Option<&mut ControlTower>
+    /// Returns the mutable state of the window `id`.
+    ///
+    /// # Examples
+    /// See `WindowRegistry::remove`.
+    pub fn get_mut(&mut self, id: window::Id) -> Option<&mut ControlTower>
+    {
+        self.towers.get_mut(&id)
+    }
+
+    // pub fn is_main(&self, id: window::Id) -> bool
+    /// Returns whether `id` is the main window, whose closing quits the application.
+    ///
+    /// # Examples
+    /// See `WindowRegistry::remove`.
+    pub fn is_main(&self, id: window::Id) -> bool
+    {
+        self.main == Some(id)
+    }
+
+    // pub fn get_main(&self) -> Option<window::Id>
+    /// Returns the id of the main window, if it is open.
+    ///
+    /// # Examples
+    /// See `Workspace::update`.
+    pub fn get_main(&self) -> Option<window::Id>
+    {
+        self.main
+    }
+
+    // pub fn get_ids(&self) -> Vec<window::Id>
+    /// Returns the ids of the open windows, in the order they were opened.
+    ///
+    /// # Examples
+    /// See `Workspace::update`.
+    pub fn get_ids(&self) -> Vec<window::Id>
+    {
+        self.towers.keys().copied().collect()
+    }
+
+    // pub fn len(&self) -> usize
+    /// Returns the number of open windows.
+    ///
+    /// # Examples
+    /// See `WindowRegistry::remove`.
+    pub fn len(&self) -> usize
+    {
+        self.towers.len()
+    }
+
+    // pub fn is_empty(&self) -> bool
+    /// Returns whether no window is open.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::WindowRegistry;
+    /// assert!(WindowRegistry::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool
+    {
+        self.towers.is_empty()
+    }
+}
+
+/// Defines the messages of the multi-window application.
+#[derive(Debug, Clone)]
+pub enum WorkspaceMessage
+{
+    /// A message for the `ControlTower` of the given window.
+    Window(window::Id, Message),
+
+    /// Triggered when the given window has been closed.
+    WindowClosed(window::Id),
+}
+
+/// Runs one `ControlTower` per window. Windows share nothing but the
+/// user preferences, which are kept in step whenever one window changes them.
+#[derive(Debug, Clone)]
+pub struct Workspace
+{
+    registry: WindowRegistry,
+    settings_path: PathBuf,
+    font_failures: Vec<PathBuf>,
+}
+
+impl Workspace
+{
+    // pub fn run() -> iced::Result
+    /// Runs the application with its main window, taking over the main
+    /// thread until the main window is closed. A question bank given on the
+    /// command line is opened in the main window.
+    ///
+    /// # Output
+    /// Returns an `iced::Result` indicating
+    /// the success or failure of the application execution.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use qrate_gui::Workspace;
+    ///
+    /// fn main() -> iced::Result
+    /// {
+    ///     Workspace::run()
+    /// }
+    /// ```
+    pub fn run() -> iced::Result
+    {
+        // Fonts found in ./fonts are registered so that they can join the fallback chain.
+        let fonts = load_fonts(Path::new("./fonts"));
+        let failed = fonts.get_failed().to_vec();
+        let launch_file = get_launch_file(std::env::args_os());
+        fonts.get_files().iter().cloned().fold(
+            daemon(move || Workspace::new_with_launch_file(get_settings_path(), failed.clone(), launch_file.clone()), Workspace::update, Workspace::view)
+                .title(Workspace::title)
+                .subscription(Workspace::subscription)
+                .theme(Workspace::theme)
+                .default_font(fonts.get_default_font(&rust_i18n::locale())),
+            |app, bytes| app.font(bytes),
+        )
+        .run()
+    }
+
+    // pub fn new(settings_path: PathBuf) -> (Self, Task<WorkspaceMessage>)
+    /// Opens the main window, restored from the settings in `settings_path`.
+    ///
+    /// # Arguments
+    /// * `settings_path` - The settings file shared by all windows.
+    ///
+    /// # Output
+    /// The workspace and the [Task] opening its main window.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::Workspace;
+    /// let path = std::env::temp_dir().join("qrate_gui_workspace_settings.json");
+    /// let (workspace, _) = Workspace::new(path);
+    /// assert_eq!(workspace.get_registry().len(), 1);
+    ///
+    /// // The banks of the last session that still exist are offered again,
+    /// // each in a window of its own.
+    /// use qrate_gui::{ save_settings, AppSettings, Message, WorkspaceMessage };
+    /// let banks: Vec<_> = ["a", "b"].iter().map(|name| std::env::temp_dir().join(format!("qrate_gui_workspace_{}.qbdb", name))).collect();
+    /// for bank in banks.iter()
+    ///     { std::fs::write(bank, b"").unwrap(); }
+    /// let path = std::env::temp_dir().join("qrate_gui_workspace_session.json");
+    /// let mut settings = AppSettings::new();
+    /// settings.set_open_banks(vec![banks[0].clone(), "/definitely/not/here.qbdb".into(), banks[1].clone()]);
+    /// save_settings(&path, &settings).unwrap();
+    ///
+    /// let (mut workspace, _) = Workspace::new(path);
+    /// let main = workspace.get_registry().get_main().unwrap();
+    /// assert_eq!(workspace.get_registry().get(main).unwrap().get_session_banks(), banks.as_slice());
+    /// let _ = workspace.update(WorkspaceMessage::Window(main, Message::RestoreSessionBanks));
+    /// let opened: Vec<_> = workspace.get_registry().get_ids().into_iter()
+    ///                         .map(|id| workspace.get_registry().get(id).unwrap().get_selected_file_path().clone())
+    ///                         .collect();
+    /// assert_eq!(opened, banks);
+    ///
+    /// // Closing the main window remembers the open banks for next time.
+    /// let _ = workspace.update(WorkspaceMessage::WindowClosed(main));
+    /// let secondary = workspace.get_registry().get_ids()[0];
+    /// assert_eq!(workspace.get_registry().get(secondary).unwrap().get_settings().get_open_banks(), banks.as_slice());
+    /// ```
+    pub fn new(settings_path: PathBuf) -> (Self, Task<WorkspaceMessage>)
+    {
+        Self::new_with_font_failures(settings_path, Vec::new())
+    }
+
+    // pub fn new_with_font_failures(settings_path: PathBuf, font_failures: Vec<PathBuf>) -> (Self, Task<WorkspaceMessage>)
+    /// Opens the main window like `Workspace::new`, where the bundled fonts
+    /// in `font_failures` could not be loaded. Every window then uses the
+    /// default font, and the main window warns about it once.
+    ///
+    /// # Arguments
+    /// * `settings_path` - The settings file shared by all windows.
+    /// * `font_failures` - The font files that failed to load.
+    ///
+    /// # Output
+    /// The workspace and the [Task] opening its main window.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::PathBuf;
+    /// use iced::Font;
+    /// use qrate_gui::{ primary_font, Message, Workspace, WorkspaceMessage };
+    ///
+    /// let path = std::env::temp_dir().join("qrate_gui_workspace_font_failures.json");
+    /// let failed = vec![PathBuf::from("fonts/NotoSans-Regular.ttf"), PathBuf::from("fonts/NotoSansCJKkr-Regular.otf")];
+    /// let (mut workspace, _) = Workspace::new_with_font_failures(path, failed.clone());
+    /// let main = workspace.get_registry().get_main().unwrap();
+    /// let tower = workspace.get_registry().get(main).unwrap();
+    /// assert!(tower.has_font_warning());
+    /// assert!(tower.get_error_message().is_some());
+    /// assert_eq!(tower.get_primary_font(), primary_font(tower.get_current_locale(), &failed));
+    /// assert!(![Font::with_name("Noto Sans"), Font::with_name("Noto Sans CJK KR")].contains(&tower.get_primary_font()));
+    /// let main_font = tower.get_primary_font();
+    ///
+    /// // Other windows skip the failed fonts too, without warning again.
+    /// let _ = workspace.update(WorkspaceMessage::Window(main, Message::OpenNewWindow));
+    /// let secondary = workspace.get_registry().get(workspace.get_registry().get_ids()[1]).unwrap();
+    /// assert_eq!(secondary.get_primary_font(), main_font);
+    /// assert!(secondary.get_error_message().is_none());
+    /// ```
+    pub fn new_with_font_failures(settings_path: PathBuf, font_failures: Vec<PathBuf>) -> (Self, Task<WorkspaceMessage>)
+    {
+        Self::new_with_launch_file(settings_path, font_failures, None)
+    }
+
+    // pub fn new_with_launch_file(settings_path: PathBuf, font_failures: Vec<PathBuf>, launch_file: Option<PathBuf>) -> (Self, Task<WorkspaceMessage>)
+    /// Opens the main window like `Workspace::new_with_font_failures`, with
+    /// `launch_file` opened in it instead of the last question bank.
+    ///
+    /// # Arguments
+    /// * `settings_path` - The settings file shared by all windows.
+    /// * `font_failures` - The font files that failed to load.
+    /// * `launch_file` - The question bank given on the command line, if any.
+    ///
+    /// # Output
+    /// The workspace and the [Task] opening its main window.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::Workspace;
+    ///
+    /// let path = std::env::temp_dir().join("qrate_gui_workspace_launch.json");
+    /// let bank = std::env::temp_dir().join("qrate_gui_workspace_launch.qbdb");
+    /// std::fs::write(&bank, b"").unwrap();
+    /// let (workspace, _) = Workspace::new_with_launch_file(path, Vec::new(), Some(bank.clone()));
+    /// let main = workspace.get_registry().get_main().unwrap();
+    /// assert_eq!(workspace.get_registry().get(main).unwrap().get_selected_file_path(), &bank);
+    /// ```
+    pub fn new_with_launch_file(settings_path: PathBuf, font_failures: Vec<PathBuf>, launch_file: Option<PathBuf>) -> (Self, Task<WorkspaceMessage>)
+    {
+        let (id, open) = window::open(window::Settings::default());
+        let (mut tower, task) = ControlTower::new_with_launch_file(settings_path.clone(), launch_file);
+        tower.set_font_failures(font_failures.clone());
+        tower.warn_font_failures();
+        if let Err(e) = run_self_check()
+            { tower.warn_self_check_failure(&e); }
+        let mut registry = WindowRegistry::new();
+        registry.insert(id, tower);
+        let task = Task::batch([ open.discard(), task.map(move |message| WorkspaceMessage::Window(id, message)) ]);
+        (Self { registry, settings_path, font_failures }, task)
+    }
+
+    // pub fn get_registry(&self) -> &WindowRegistry
+    /// Returns the states of the open windows.
+    ///
+    /// # Examples
+    /// See `Workspace::new`.
+    pub fn get_registry(&self) -> &WindowRegistry
+    {
+        &self.registry
+    }
+
+    // pub fn update(&mut self, message: WorkspaceMessage) -> Task<WorkspaceMessage>
+    /// Forwards `message` to the window it belongs to, opens a secondary
+    /// window on `Message::OpenNewWindow` and drops the state of closed windows.
+    ///
+    /// # Arguments
+    /// * `message` - The message to handle.
+    ///
+    /// # Output
+    /// A [Task] for the runtime to execute.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::{ Message, Page, Workspace, WorkspaceMessage };
+    /// let path = std::env::temp_dir().join("qrate_gui_workspace_update.json");
+    /// let (mut workspace, _) = Workspace::new(path);
+    /// let main = workspace.get_registry().get_main().unwrap();
+    ///
+    /// let _ = workspace.update(WorkspaceMessage::Window(main, Message::OpenNewWindow));
+    /// assert_eq!(workspace.get_registry().len(), 2);
+    /// let secondary = workspace.get_registry().get_ids()[1];
+    /// assert_eq!(workspace.get_registry().get(secondary).unwrap().get_current_page(), "main");
+    ///
+    /// // Moving around in the secondary window leaves the main window alone.
+    /// let _ = workspace.update(WorkspaceMessage::Window(secondary, Message::GoToPage(Page::LanguageSettings)));
+    /// assert_eq!(workspace.get_registry().get(secondary).unwrap().get_current_page(), "language-settings");
+    /// assert_eq!(workspace.get_registry().get(main).unwrap().get_current_page(), "main");
+    ///
+    /// let _ = workspace.update(WorkspaceMessage::WindowClosed(secondary));
+    /// assert_eq!(workspace.get_registry().get_ids(), [main]);
+    /// ```
+    pub fn update(&mut self, message: WorkspaceMessage) -> Task<WorkspaceMessage>
+    {
+        match message
+        {
+            WorkspaceMessage::Window(_, Message::OpenNewWindow) => self.open_window().1,
+            WorkspaceMessage::Window(id, Message::RestoreSessionBanks) => self.restore_session_banks(id),
+            WorkspaceMessage::Window(id, message) => self.update_window(id, message),
+            WorkspaceMessage::WindowClosed(id) => self.close_window(id),
+        }
+    }
+
+    // pub fn view(&self, id: window::Id) -> Element<'_, WorkspaceMessage>
+    /// Returns the view of the window `id`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::Workspace;
+    /// let (workspace, _) = Workspace::new(std::env::temp_dir().join("qrate_gui_workspace_view.json"));
+    /// let main = workspace.get_registry().get_main().unwrap();
+    /// let _view = workspace.view(main);
+    /// ```
+    pub fn view(&self, id: window::Id) -> Element<'_, WorkspaceMessage>
+    {
+        match self.registry.get(id)
+        {
+            Some(tower) => tower.view().map(move |message| WorkspaceMessage::Window(id, message)),
+            None => text("").into(),
+        }
+    }
+
+    // pub fn title(&self, id: window::Id) -> String
+    /// Returns the title of the window `id`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::Workspace;
+    /// let (workspace, _) = Workspace::new(std::env::temp_dir().join("qrate_gui_workspace_title.json"));
+    /// let main = workspace.get_registry().get_main().unwrap();
+    /// assert!(workspace.title(main).ends_with("Qrate"));
+    /// ```
+    pub fn title(&self, id: window::Id) -> String
+    {
+        self.registry.get(id).map(|tower| tower.title()).unwrap_or_else(|| "Qrate".to_string())
+    }
+
+    // pub fn theme(&self, id: window::Id) -> Theme
+    /// Returns the theme of the window `id`.
+    ///
+    /// # Examples
+    /// ```
+    /// use iced::Theme;
+    /// use qrate_gui::{ Message, ThemeChoice, Workspace, WorkspaceMessage };
+    /// let (mut workspace, _) = Workspace::new(std::env::temp_dir().join("qrate_gui_workspace_theme.json"));
+    /// let main = workspace.get_registry().get_main().unwrap();
+    /// let _ = workspace.update(WorkspaceMessage::Window(main, Message::SetTheme(ThemeChoice::Dark)));
+    /// assert_eq!(workspace.theme(main), Theme::Dark);
+    /// ```
+    pub fn theme(&self, id: window::Id) -> Theme
+    {
+        self.registry.get(id).map(|tower| tower.get_theme()).unwrap_or(Theme::Light)
+    }
+
+    // pub fn subscription(&self) -> Subscription<WorkspaceMessage>
+    /// Returns the subscriptions of every window, routes keyboard events
+    /// to the window they happened in and reports closed windows.
+    ///
+    /// # Examples
+    /// ```
+    /// use qrate_gui::Workspace;
+    /// let (workspace, _) = Workspace::new(std::env::temp_dir().join("qrate_gui_workspace_subscription.json"));
+    /// let _subscription = workspace.subscription();
+    /// ```
+    pub fn subscription(&self) -> Subscription<WorkspaceMessage>
+    {
+        let ticks = self.registry.towers.iter().map(|(&id, tower)| {
+            tower.tick_subscription()
+                .with(id)
+                .map(|(id, message)| WorkspaceMessage::Window(id, message))
+        });
+        Subscription::batch(
+            ticks.chain([
+                event::listen_with(Workspace::route_event),
+                window::close_events().map(WorkspaceMessage::WindowClosed),
+            ])
+        )
+    }
+
+    // fn remember_open_banks(&mut self)
+    /// Saves the banks open in every window, main window first, so that the
+    /// next launch can offer to open them again.
+    fn remember_open_banks(&mut self)
+    {
+        let mut ids = self.registry.get_ids();
+        ids.sort_by_key(|&id| !self.registry.is_main(id));
+        let mut banks: Vec<PathBuf> = Vec::new();
+        for bank in ids.iter().filter_map(|&id| self.registry.get(id)).map(|tower| tower.get_selected_file_path())
+        {
+            if !bank.as_os_str().is_empty() && !banks.contains(bank)
+                { banks.push(bank.clone()); }
+        }
+        let Some(tower) = ids.first().and_then(|&id| self.registry.get_mut(id))
+            else { return; };
+        tower.remember_open_banks(banks);
+        let settings = tower.get_settings().clone();
+        for id in ids.into_iter().skip(1)
+        {
+            if let Some(other) = self.registry.get_mut(id)
+                { other.sync_settings(settings.clone()); }
+        }
+    }
+
+    // fn route_event(event: Event, status: event::Status, id: window::Id) -> Option<WorkspaceMessage>
+    /// Sends the keys used for keyboard navigation to the window they were pressed in.
+    fn route_event(event: Event, status: event::Status, id: window::Id) -> Option<WorkspaceMessage>
+    {
+        match (event, status)
+        {
+            (Event::Keyboard(event), event::Status::Ignored)
+                => ControlTower::get_focus_message(event).map(|message| WorkspaceMessage::Window(id, message)),
+            _ => None,
+        }
+    }
+
+    fn open_window(&mut self) -> (window::Id, Task<WorkspaceMessage>)
+    {
+        let (id, open) = window::open(window::Settings::default());
+        let mut tower = ControlTower::new_secondary(self.settings_path.clone());
+        tower.set_font_failures(self.font_failures.clone());
+        if let Some(main) = self.registry.get_main().and_then(|main| self.registry.get(main))
+            { tower.set_system_theme(main.get_system_theme()); }
+        self.registry.insert(id, tower);
+        (id, open.discard())
+    }
+
+    // fn restore_session_banks(&mut self, id: window::Id) -> Task<WorkspaceMessage>
+    /// Opens the first bank of the last session in the window `id` and
+    /// every other one in a window of its own.
+    fn restore_session_banks(&mut self, id: window::Id) -> Task<WorkspaceMessage>
+    {
+        let banks = self.registry.get(id).map(|tower| tower.get_session_banks().to_vec()).unwrap_or_default();
+        let mut tasks = vec![ self.update_window(id, Message::RestoreSessionBanks) ];
+        for bank in banks.into_iter().skip(1)
+        {
+            let (other, open) = self.open_window();
+            tasks.push(open);
+            tasks.push(self.update_window(other, Message::FileSelected(bank)));
+        }
+        Task::batch(tasks)
+    }
+
+    fn update_window(&mut self, id: window::Id, message: Message) -> Task<WorkspaceMessage>
+    {
+        let Some(tower) = self.registry.get_mut(id)
+            else { return Task::none(); };
+        let settings = tower.get_settings().clone();
+        let task = tower.update(message).map(move |message| WorkspaceMessage::Window(id, message));
+        if *tower.get_settings() != settings
+        {
+            let settings = tower.get_settings().clone();
+            for (_, other) in self.registry.towers.iter_mut().filter(|(other, _)| **other != id)
+                { other.sync_settings(settings.clone()); }
+        }
+        task
+    }
+
+    fn close_window(&mut self, id: window::Id) -> Task<WorkspaceMessage>
+    {
+        let was_main = self.registry.is_main(id);
+        if was_main || self.registry.len() == 1
+            { self.remember_open_banks(); }
+        // Closing the main window closes every other one with it.
+        let closing = if was_main { self.registry.get_ids() } else { vec![id] };
+        for closing_id in closing
+        {
+            if let Some(tower) = self.registry.get_mut(closing_id)
+                { tower.suspend_learning(); }
+        }
+        self.registry.remove(id);
+        if was_main || self.registry.is_empty()
+            { iced::exit() }
+        else
+            { Task::none() }
+    }
+}